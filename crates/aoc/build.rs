@@ -0,0 +1,97 @@
+//! Hashes the source code shared between solutions for the [answer cache](src/cache.rs).
+//!
+//! Each day's own source file is hashed by [`utils::year!`], so only code shared between days is
+//! hashed here: the `utils` crate and any modules in the year crates other than the days and
+//! `lib.rs`. This means adding or editing a day doesn't invalidate the cached answers for every
+//! other day, while changes to shared code do.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut files = Vec::new();
+
+    let utils_dir = crates_dir.join("utils").join("src");
+    println!("cargo::rerun-if-changed={}", utils_dir.display());
+    collect_files(&utils_dir, &mut files);
+
+    for entry in fs::read_dir(crates_dir).unwrap() {
+        let path = entry.unwrap().path();
+        if !path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("year"))
+        {
+            continue;
+        }
+
+        let src_dir = path.join("src");
+        println!("cargo::rerun-if-changed={}", src_dir.display());
+        let mut year_files = Vec::new();
+        collect_files(&src_dir, &mut year_files);
+        files.extend(year_files.into_iter().filter(|file| {
+            let name = file.strip_prefix(&src_dir).unwrap().to_string_lossy();
+            name != "lib.rs" && !is_day_file(&name)
+        }));
+    }
+
+    // Sort the relative paths so the hash doesn't depend on directory iteration order or where the
+    // workspace is checked out
+    let mut files: Vec<(String, PathBuf)> = files
+        .into_iter()
+        .map(|file| {
+            let relative = file.strip_prefix(crates_dir).unwrap();
+            (relative.to_string_lossy().replace('\\', "/"), file)
+        })
+        .collect();
+    files.sort_unstable();
+
+    let mut hash = Fnv1a::default();
+    for (relative, file) in files {
+        hash.write(relative.as_bytes());
+        hash.write(&[0]);
+        hash.write(&fs::read(&file).unwrap());
+        hash.write(&[0]);
+    }
+
+    println!("cargo::rustc-env=AOC_SHARED_SOURCE_HASH={:016x}", hash.0);
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Matches `dayNN.rs`, the files hashed individually by [`utils::year!`].
+fn is_day_file(name: &str) -> bool {
+    name.strip_prefix("day")
+        .and_then(|n| n.strip_suffix(".rs"))
+        .is_some_and(|n| n.len() == 2 && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// 64-bit FNV-1a, matching [`utils::source_hash`].
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
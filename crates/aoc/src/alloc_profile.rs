@@ -0,0 +1,216 @@
+//! Allocation profiling, enabled with the `alloc-profile` feature and `--alloc-profile` option.
+//!
+//! Every allocation made while profiling is enabled is counted, and every [`SAMPLE_RATE`]th
+//! allocation captures a backtrace. The solution frames from the backtrace are hashed to group
+//! allocations by call site, and the counts for each site are scaled by the sample rate, so the
+//! per-site numbers are estimates.
+//!
+//! Live heap usage is tracked for every allocation, and the peak usage above the live usage when
+//! profiling was enabled is reported, so the peak excludes the input and any earlier solutions. A
+//! report is printed after each puzzle, covering only the allocations made by that puzzle.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use utils::date::{Day, Year};
+
+/// Capture a backtrace for one in every `SAMPLE_RATE` allocations.
+const SAMPLE_RATE: u64 = 256;
+/// Maximum number of frames used to identify each call site.
+const SITE_FRAMES: usize = 4;
+
+#[global_allocator]
+static ALLOCATOR: ProfilingAllocator = ProfilingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static BASELINE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static SITES: Mutex<Option<HashMap<u64, Site>>> = Mutex::new(None);
+
+thread_local! {
+    static IN_PROFILER: Cell<bool> = const { Cell::new(false) };
+}
+
+struct Site {
+    frames: Vec<String>,
+    allocations: u64,
+    bytes: u64,
+}
+
+struct ProfilingAllocator;
+
+// SAFETY: All calls are forwarded to the system allocator unchanged
+unsafe impl GlobalAlloc for ProfilingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        add_live(layout.size());
+        record(layout.size());
+        // SAFETY: Caller upholds the GlobalAlloc contract
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        add_live(layout.size());
+        record(layout.size());
+        // SAFETY: Caller upholds the GlobalAlloc contract
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        // SAFETY: Caller upholds the GlobalAlloc contract
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        add_live(new_size);
+        record(new_size);
+        // SAFETY: Caller upholds the GlobalAlloc contract
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Enable or disable recording allocations.
+pub fn set_enabled(enabled: bool) {
+    if enabled {
+        BASELINE.store(LIVE.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn add_live(size: usize) {
+    let live = LIVE
+        .fetch_add(size as u64, Ordering::Relaxed)
+        .wrapping_add(size as u64);
+    if ENABLED.load(Ordering::Relaxed) && !IN_PROFILER.try_with(Cell::get).unwrap_or(true) {
+        let baseline = BASELINE.load(Ordering::Relaxed);
+        PEAK.fetch_max(live.saturating_sub(baseline), Ordering::Relaxed);
+    }
+}
+
+fn record(size: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // Capturing and resolving backtraces allocates, so ignore allocations made by the profiler
+    let _ = IN_PROFILER.try_with(|in_profiler| {
+        if in_profiler.get() {
+            return;
+        }
+
+        let n = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        if n % SAMPLE_RATE == 0 {
+            in_profiler.set(true);
+            // Memory kept by the profiler, such as cached debug info, shouldn't count towards the
+            // peak heap usage of the solution
+            let live = LIVE.load(Ordering::Relaxed);
+            record_site(size);
+            BASELINE.fetch_add(
+                LIVE.load(Ordering::Relaxed).wrapping_sub(live),
+                Ordering::Relaxed,
+            );
+            in_profiler.set(false);
+        }
+    });
+}
+
+fn record_site(size: usize) {
+    let backtrace = Backtrace::force_capture().to_string();
+    let frames: Vec<String> = backtrace
+        .lines()
+        .filter_map(|line| {
+            let (index, symbol) = line.trim_start().split_once(": ")?;
+            index.parse::<usize>().ok()?;
+            Some(symbol)
+        })
+        .filter(|symbol| {
+            ![
+                "std::",
+                "core::",
+                "alloc::",
+                "__rustc",
+                "aoc::alloc_profile",
+                "<aoc::alloc_profile",
+                "<alloc::",
+                "<core::",
+                "<std::",
+            ]
+            .iter()
+            .any(|prefix| symbol.starts_with(prefix))
+        })
+        .take(SITE_FRAMES)
+        .map(str::to_string)
+        .collect();
+
+    let mut hasher = DefaultHasher::new();
+    frames.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let mut sites = SITES.lock().unwrap_or_else(PoisonError::into_inner);
+    let entry = sites
+        .get_or_insert_with(HashMap::new)
+        .entry(hash)
+        .or_insert_with(|| Site {
+            frames,
+            allocations: 0,
+            bytes: 0,
+        });
+    entry.allocations += SAMPLE_RATE;
+    entry.bytes += size as u64 * SAMPLE_RATE;
+}
+
+/// Print the total number of allocations, the peak heap usage and the top allocation sites by bytes
+/// allocated to stderr, keeping the results separate from machine-readable output on stdout.
+///
+/// The recorded allocations and peak are reset, so each report only covers the allocations since
+/// the previous report. The peak heap usage line is also parsed by `cargo xtask test` to enforce
+/// memory limits.
+pub fn print_report(year: Year, day: Day, limit: usize) {
+    set_enabled(false);
+
+    let sites = SITES.lock().unwrap_or_else(PoisonError::into_inner).take();
+    let mut sites: Vec<Site> = sites.unwrap_or_default().into_values().collect();
+    sites.sort_unstable_by_key(|s| std::cmp::Reverse(s.bytes));
+
+    eprintln!();
+    eprintln!("{year:#} {day:#} allocation profile:");
+    eprintln!(
+        "Allocations: {} ({})",
+        ALLOCATIONS.swap(0, Ordering::Relaxed),
+        format_bytes(BYTES.swap(0, Ordering::Relaxed)),
+    );
+    let peak = PEAK.swap(0, Ordering::Relaxed);
+    eprintln!("Peak heap usage: {peak} bytes ({})", format_bytes(peak));
+    eprintln!("Top allocation sites (estimated from 1 in {SAMPLE_RATE} allocations):");
+    for site in sites.iter().take(limit) {
+        eprintln!(
+            "{:>10} allocs {:>10}  {}",
+            site.allocations,
+            format_bytes(site.bytes),
+            site.frames.first().map_or("<unknown>", String::as_str),
+        );
+        for frame in site.frames.iter().skip(1) {
+            eprintln!("{:33}<- {frame}", "");
+        }
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / (1024. * 1024.))
+    }
+}
@@ -0,0 +1,88 @@
+//! Puzzle answers returned by the runner API.
+
+use std::fmt::{self, Display, Formatter};
+use utils::ocr::Letters;
+
+/// Answer to one part of a puzzle.
+///
+/// Numeric answers are stored without formatting them, so callers which only need to run
+/// solutions, such as benchmarks, avoid allocating strings. Use the [`Display`] implementation to
+/// format answers when the text is needed.
+///
+/// # Examples
+/// ```
+/// # use aoc::Answer;
+/// assert_eq!(Answer::from(42u32), Answer::U64(42));
+/// assert_eq!(Answer::from(-7i32).to_string(), "-7");
+/// assert_eq!(Answer::from("abc"), Answer::String("abc".to_string()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    String(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U64(x) => Display::fmt(x, f),
+            Answer::I64(x) => Display::fmt(x, f),
+            Answer::U128(x) => Display::fmt(x, f),
+            Answer::String(s) => Display::fmt(s, f),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident: $($t:ty),+) => {$(
+        impl From<$t> for Answer {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Answer::$variant(value.into())
+            }
+        }
+    )+};
+}
+from_integer!(U64: u8, u16, u32, u64);
+from_integer!(I64: i8, i16, i32, i64);
+from_integer!(U128: u128);
+
+impl From<usize> for Answer {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<isize> for Answer {
+    #[inline]
+    fn from(value: isize) -> Self {
+        Answer::I64(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    #[inline]
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+/// Borrowed answers are copied, as they usually borrow from the solution. Answers can't be kept
+/// borrowed without tying the [`Answer`] to the solution's lifetime.
+impl From<&str> for Answer {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+/// Letters are stored as their displayed text, which is the grid if any letter wasn't recognized.
+impl From<Letters> for Answer {
+    #[inline]
+    fn from(value: Letters) -> Self {
+        Answer::String(value.to_string())
+    }
+}
@@ -0,0 +1,147 @@
+//! Persistent answer cache, enabled with `--cache`.
+//!
+//! Answers are stored in `answers.cache` in the inputs directory, keyed by the SHA-256 hash of the
+//! crate version, the hash of the source code shared between solutions, the hash of the day's
+//! source file and the input. Editing a solution only invalidates the cached answers for that day,
+//! so unrelated edits don't cause every day to be recomputed, while changes to shared code such as
+//! the `utils` crate invalidate every cached answer.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use utils::date::{Day, Year};
+use utils::sha256;
+
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: HashMap<(Year, Day), Entry>,
+    modified: bool,
+}
+
+struct Entry {
+    key: String,
+    part1: String,
+    part2: String,
+}
+
+impl AnswerCache {
+    /// Load the cache from the provided inputs directory.
+    pub fn load(inputs_dir: &Path) -> io::Result<Self> {
+        let mut cache = Self {
+            path: inputs_dir.join("answers.cache"),
+            entries: HashMap::new(),
+            modified: false,
+        };
+
+        let contents = match fs::read_to_string(&cache.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(cache),
+            Err(err) => return Err(err),
+        };
+
+        // Invalid lines, including the binary hash stored by previous versions, are skipped
+        for line in contents.lines() {
+            let mut fields = line.split('\t');
+            let (Some(year), Some(day), Some(key), Some(part1), Some(part2), None) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                continue;
+            };
+            let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                continue;
+            };
+
+            cache.entries.insert(
+                (year, day),
+                Entry {
+                    key: key.to_string(),
+                    part1: part1.to_string(),
+                    part2: part2.to_string(),
+                },
+            );
+        }
+
+        Ok(cache)
+    }
+
+    /// Returns the cached answers for the provided input, if present.
+    pub fn get(&self, year: Year, day: Day, input: &str) -> Option<(String, String)> {
+        let entry = self.entries.get(&(year, day))?;
+        if entry.key == Self::key(year, day, input) {
+            Some((entry.part1.clone(), entry.part2.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Store the answers for the provided input.
+    ///
+    /// Answers containing tabs or newlines are not cached.
+    pub fn insert(&mut self, year: Year, day: Day, input: &str, part1: &str, part2: &str) {
+        if [part1, part2]
+            .iter()
+            .any(|s| s.contains(['\t', '\n', '\r']))
+        {
+            return;
+        }
+
+        self.entries.insert(
+            (year, day),
+            Entry {
+                key: Self::key(year, day, input),
+                part1: part1.to_string(),
+                part2: part2.to_string(),
+            },
+        );
+        self.modified = true;
+    }
+
+    /// Write the cache back to disk if it has been modified.
+    pub fn save(&self) -> io::Result<()> {
+        if !self.modified {
+            return Ok(());
+        }
+
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_unstable_by_key(|&(&date, _)| date);
+
+        let mut contents = String::new();
+        for ((year, day), entry) in entries {
+            writeln!(
+                &mut contents,
+                "{year:#}\t{day:#}\t{}\t{}\t{}",
+                entry.key, entry.part1, entry.part2
+            )
+            .unwrap();
+        }
+
+        fs::write(&self.path, contents)
+    }
+
+    fn key(year: Year, day: Day, input: &str) -> String {
+        let source_hash = aoc::puzzles()
+            .find(|p| (p.year, p.day) == (year, day))
+            .map_or(0, |p| p.source_hash);
+
+        let mut buf = Vec::with_capacity(input.len() + 48);
+        buf.extend_from_slice(env!("CARGO_PKG_VERSION").as_bytes());
+        buf.push(0);
+        buf.extend_from_slice(env!("AOC_SHARED_SOURCE_HASH").as_bytes());
+        buf.push(0);
+        buf.extend_from_slice(&source_hash.to_le_bytes());
+        buf.extend_from_slice(input.as_bytes());
+        sha256_hex(&buf)
+    }
+}
+
+fn sha256_hex(buf: &[u8]) -> String {
+    String::from_utf8(sha256::to_hex(sha256::hash(buf)).to_vec())
+        .expect("hex should be valid UTF-8")
+}
@@ -0,0 +1,245 @@
+//! Optional config file providing defaults for CLI options.
+//!
+//! The config file uses a small subset of TOML: each line is either blank, a `#` comment, or a
+//! `key = value` pair where the key is the name of a long option and the value is a string,
+//! integer or array of strings. Arrays are passed to the option as a comma separated list.
+//!
+//! ```toml
+//! inputs-dir = "/home/user/aoc-inputs"
+//! threads = 4
+//! skip = ["2016-05", "2016-14"]
+//! ```
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Long options which can be set in the config file.
+pub const CONFIG_OPTIONS: &[&str] = &[
+    "answer-encoding",
+    "format",
+    "inputs-dir",
+    "max-input-size",
+    "multiversion",
+    "skip",
+    "threads",
+];
+
+/// Config file entry, storing the line number, option name and value.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub line: usize,
+    pub key: String,
+    pub value: String,
+}
+
+/// Read the config file, returning its path and contents.
+///
+/// If no path is provided, `aoc.toml` in the current directory is used, falling back to
+/// `aoc/config.toml` in `$XDG_CONFIG_HOME` or `~/.config`. Returns [`None`] if no path was provided
+/// and none of the default files exist.
+pub fn read(path: Option<&str>) -> Result<Option<(PathBuf, String)>, String> {
+    if let Some(path) = path {
+        return match fs::read_to_string(path) {
+            Ok(contents) => Ok(Some((PathBuf::from(path), contents))),
+            Err(err) => Err(format!("failed to read config {path:?}: {err}")),
+        };
+    }
+
+    for path in default_paths() {
+        match fs::read_to_string(&path) {
+            Ok(contents) => return Ok(Some((path, contents))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(format!("failed to read config {}: {err}", path.display()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn default_paths() -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from("aoc.toml")];
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()) {
+        paths.push(
+            [dir.as_os_str(), "aoc".as_ref(), "config.toml".as_ref()]
+                .iter()
+                .collect(),
+        );
+    } else if let Some(home) = env::var_os("HOME").filter(|d| !d.is_empty()) {
+        paths.push(
+            [
+                home.as_os_str(),
+                ".config".as_ref(),
+                "aoc".as_ref(),
+                "config.toml".as_ref(),
+            ]
+            .iter()
+            .collect(),
+        );
+    }
+    paths
+}
+
+/// Parse the contents of a config file.
+pub fn parse(contents: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {line_number}: expected key = value"));
+        };
+        let key = key.trim();
+        if key.is_empty()
+            || !key
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            return Err(format!("line {line_number}: invalid key {key:?}"));
+        }
+
+        let value = parse_value(value.trim()).map_err(|e| format!("line {line_number}: {e}"))?;
+        entries.push(Entry {
+            line: line_number,
+            key: key.replace('_', "-"),
+            value,
+        });
+    }
+    Ok(entries)
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(array) = value.strip_prefix('[') {
+        let mut rest = array.trim_start();
+        let mut items = Vec::new();
+        loop {
+            if let Some(after) = rest.strip_prefix(']') {
+                return trailing(after).map(|()| items.join(","));
+            }
+            let (item, after) = parse_string(rest)?;
+            items.push(item);
+            rest = after.trim_start();
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after.trim_start();
+            } else if !rest.starts_with(']') {
+                return Err("expected , or ] in array".to_string());
+            }
+        }
+    }
+
+    if value.starts_with('"') {
+        let (string, after) = parse_string(value)?;
+        return trailing(after).map(|()| string);
+    }
+
+    let integer = value.split_once('#').map_or(value, |(v, _)| v).trim_end();
+    if !integer.is_empty() && integer.bytes().all(|b| b.is_ascii_digit() || b == b'_') {
+        return Ok(integer.replace('_', ""));
+    }
+
+    Err("expected string, integer or array of strings".to_string())
+}
+
+/// Parse a basic TOML string, returning the unescaped string and the remaining input.
+fn parse_string(input: &str) -> Result<(String, &str), String> {
+    let Some(input) = input.strip_prefix('"') else {
+        return Err("expected string".to_string());
+    };
+
+    let mut result = String::new();
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((result, &input[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, '"')) => result.push('"'),
+                Some((_, '\\')) => result.push('\\'),
+                Some((_, 'n')) => result.push('\n'),
+                Some((_, 't')) => result.push('\t'),
+                _ => return Err("invalid escape sequence in string".to_string()),
+            },
+            c => result.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+/// Check only whitespace or a comment follows a value.
+fn trailing(rest: &str) -> Result<(), String> {
+    let rest = rest.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(())
+    } else {
+        Err(format!("unexpected {rest:?} after value"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(line: usize, key: &str, value: &str) -> Entry {
+        Entry {
+            line,
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_values() {
+        let config = r#"
+# Comment
+inputs_dir = "C:\\aoc\\inputs" # Trailing comment
+threads = 4
+max_input_size = 33_554_432
+skip = [ "2016-05", "2016-14",]
+format = "json"
+"#;
+        assert_eq!(
+            parse(config),
+            Ok(vec![
+                entry(3, "inputs-dir", "C:\\aoc\\inputs"),
+                entry(4, "threads", "4"),
+                entry(5, "max-input-size", "33554432"),
+                entry(6, "skip", "2016-05,2016-14"),
+                entry(7, "format", "json"),
+            ])
+        );
+        assert_eq!(parse("skip = []"), Ok(vec![entry(1, "skip", "")]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("\nthreads"),
+            Err("line 2: expected key = value".to_string())
+        );
+        assert_eq!(
+            parse("format = json"),
+            Err("line 1: expected string, integer or array of strings".to_string())
+        );
+        assert_eq!(
+            parse("format = \"json"),
+            Err("line 1: unterminated string".to_string())
+        );
+        assert_eq!(
+            parse("format = \"json\" x"),
+            Err("line 1: unexpected \"x\" after value".to_string())
+        );
+        assert_eq!(
+            parse("skip = [\"2016-05\" \"2016-14\"]"),
+            Err("line 1: expected , or ] in array".to_string())
+        );
+        assert_eq!(
+            parse("a b = 1"),
+            Err("line 1: invalid key \"a b\"".to_string())
+        );
+    }
+}
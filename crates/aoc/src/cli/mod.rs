@@ -1,3 +1,5 @@
+mod config;
+pub mod mode;
 mod options;
 
 pub use options::Options;
@@ -0,0 +1,249 @@
+use crate::cli::Options;
+use crate::{exit_code, load_input};
+use aoc::output::{environment_csv, environment_json, format_duration, Format};
+use aoc::{BenchFn, PuzzleError, PuzzleFn};
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::process::exit;
+use std::time::{Duration, Instant};
+use utils::budget::Budget;
+use utils::date::{Day, Year};
+use utils::env_info::EnvInfo;
+
+mod criterion;
+
+const DEFAULT_TIME: Duration = Duration::from_secs(1);
+const WARMUP_ITERATIONS: usize = 3;
+/// Limit on iterations when only a time is provided, to bound memory used storing samples.
+const MAX_ITERATIONS: usize = 1_000_000;
+const STAGES: [&str; 3] = ["parse", "part 1", "part 2"];
+/// Stage names without spaces, used in benchmark IDs.
+const STAGE_IDS: [&str; 3] = ["parse", "part1", "part2"];
+const HEADER: &str = "Puzzle  │ Stage  │ Mean       │ Median     │ Std dev    │ Iterations";
+const SEPARATOR: &str = "────────┼────────┼────────────┼────────────┼────────────┼───────────";
+
+/// Run each solution repeatedly, printing timing statistics for parsing and each part.
+///
+/// With `--format json` or `--format csv`, each stage is output as a separate object or row,
+/// followed by the environment the benchmarks were run in. `--bench-libtest` replaces the output
+/// with libtest's bench format, and `--bench-criterion` also saves results in Criterion's
+/// directory layout, so existing benchmark tooling can consume the results.
+pub fn run_benchmarks(puzzles: &[(Year, Day, PuzzleFn)], args: &Options) {
+    let start = Instant::now();
+    if args.bench_libtest {
+        println!("\nrunning {} tests", puzzles.len() * STAGES.len());
+    } else {
+        match args.format {
+            Format::Table => {
+                println!("{HEADER}");
+                println!("{SEPARATOR}");
+            }
+            Format::Csv => println!("year,day,stage,mean_ns,median_ns,std_dev_ns,iterations"),
+            Format::Json | Format::Quiet => {}
+        }
+    }
+
+    for &(year, day, _) in puzzles {
+        let input = match load_input(year, day, args) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{year:#} {day:#}: {err}");
+                exit(1);
+            }
+        };
+        let f = aoc::puzzle_bench(year, day).expect("bench function should exist");
+
+        match bench(f, &input, args) {
+            Ok(mut samples) => {
+                let group = format!("{year:#}-{day:#}");
+                for (i, samples) in samples.iter_mut().enumerate() {
+                    let stats = Stats::new(samples);
+                    if args.bench_libtest {
+                        print_libtest(&group, STAGE_IDS[i], samples);
+                    } else {
+                        print_stats(args.format, year, day, i, STAGES[i], &stats, samples.len());
+                    }
+
+                    if let Some(dir) = &args.bench_criterion_dir {
+                        let dir = Path::new(dir);
+                        if let Err(err) =
+                            criterion::save(dir, &group, STAGE_IDS[i], samples, &stats)
+                        {
+                            eprintln!(
+                                "failed to save criterion results to {}: {err}",
+                                dir.display()
+                            );
+                            exit(1);
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                println!("{year:#} {day:#}: {err}");
+                exit(exit_code(&err));
+            }
+        }
+    }
+
+    if args.bench_libtest {
+        println!(
+            "\ntest result: ok. 0 passed; 0 failed; 0 ignored; {} measured; 0 filtered out; finished in {:.2}s\n",
+            puzzles.len() * STAGES.len(),
+            start.elapsed().as_secs_f64(),
+        );
+        return;
+    }
+
+    match args.format {
+        Format::Table => println!("{SEPARATOR}"),
+        Format::Json => println!(
+            r#"{{"environment":{}}}"#,
+            environment_json(&EnvInfo::capture())
+        ),
+        Format::Csv => print!("{}", environment_csv(&EnvInfo::capture())),
+        Format::Quiet => {}
+    }
+}
+
+/// Print a result in libtest's bench format, which reports the median and the range of the
+/// samples after discarding the fastest and slowest 5%.
+///
+/// `samples` must be sorted.
+fn print_libtest(group: &str, stage: &str, samples: &[Duration]) {
+    let outliers = samples.len() / 20;
+    let range = samples[samples.len() - 1 - outliers].saturating_sub(samples[outliers]);
+    let median = samples[samples.len() / 2];
+    println!(
+        "test {group}/{stage} ... bench: {:>11} ns/iter (+/- {})",
+        thousands(median.as_nanos()),
+        thousands(range.as_nanos()),
+    );
+}
+
+fn thousands(n: u128) -> String {
+    let digits = n.to_string();
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn print_stats(
+    format: Format,
+    year: Year,
+    day: Day,
+    index: usize,
+    stage: &str,
+    stats: &Stats,
+    iterations: usize,
+) {
+    match format {
+        Format::Table => {
+            let puzzle = if index == 0 {
+                format!("{year:#} {day:#}")
+            } else {
+                String::new()
+            };
+            println!(
+                "{puzzle:<7} │ {stage:<6} │ {} │ {} │ {} │ {iterations}",
+                format_duration(stats.mean),
+                format_duration(stats.median),
+                format_duration(stats.std_dev),
+            );
+        }
+        Format::Json => println!(
+            r#"{{"year":{},"day":{},"stage":"{stage}","mean_ns":{},"median_ns":{},"std_dev_ns":{},"iterations":{iterations}}}"#,
+            year.to_u16(),
+            day.to_u8(),
+            stats.mean.as_nanos(),
+            stats.median.as_nanos(),
+            stats.std_dev.as_nanos(),
+        ),
+        Format::Csv => println!(
+            "{},{},{stage},{},{},{},{iterations}",
+            year.to_u16(),
+            day.to_u8(),
+            stats.mean.as_nanos(),
+            stats.median.as_nanos(),
+            stats.std_dev.as_nanos(),
+        ),
+        Format::Quiet => {}
+    }
+}
+
+/// Time the solution, stopping after the requested number of iterations or time.
+///
+/// When only `--bench-iterations` is provided, the time limit is ignored.
+fn bench(f: BenchFn, input: &str, args: &Options) -> Result<[Vec<Duration>; 3], PuzzleError> {
+    let time = args.bench_time.unwrap_or(DEFAULT_TIME);
+    let iterations = args
+        .bench_iterations
+        .map_or(MAX_ITERATIONS, NonZeroUsize::get);
+    let time_limit = if args.bench_iterations.is_some() && args.bench_time.is_none() {
+        None
+    } else {
+        Some(time)
+    };
+
+    // Warm up caches, the branch predictor and multiversion microbenchmarks, without spending
+    // too long on slow solutions
+    let start = Instant::now();
+    for _ in 0..WARMUP_ITERATIONS {
+        Budget::start(args.budget);
+        f(input)?;
+        if start.elapsed() >= time / 10 {
+            break;
+        }
+    }
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    let start = Instant::now();
+    while samples[0].len() < iterations {
+        Budget::start(args.budget);
+        for (samples, time) in samples.iter_mut().zip(f(input)?) {
+            samples.push(time);
+        }
+        if time_limit.is_some_and(|t| start.elapsed() >= t) {
+            break;
+        }
+    }
+    Ok(samples)
+}
+
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    std_dev: Duration,
+}
+
+impl Stats {
+    #[expect(clippy::cast_precision_loss)]
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 0 {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
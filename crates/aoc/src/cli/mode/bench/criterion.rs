@@ -0,0 +1,92 @@
+//! Save benchmark results in [Criterion](https://github.com/bheisler/criterion.rs)'s directory
+//! layout, so tools such as [critcmp](https://github.com/BurntSushi/critcmp) can compare runs.
+
+use super::Stats;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Write the results for one benchmark to `$dir/$group/$function/new`.
+///
+/// Like Criterion, any existing results in `new` are first moved to `base`, so consecutive runs
+/// can be compared using `critcmp base new`.
+pub fn save(
+    dir: &Path,
+    group: &str,
+    function: &str,
+    samples: &[Duration],
+    stats: &Stats,
+) -> io::Result<()> {
+    let benchmark_dir = dir.join(group).join(function);
+    let new = benchmark_dir.join("new");
+    let base = benchmark_dir.join("base");
+    if new.exists() {
+        if base.exists() {
+            fs::remove_dir_all(&base)?;
+        }
+        fs::rename(&new, &base)?;
+    }
+    fs::create_dir_all(&new)?;
+
+    let id = format!("{group}/{function}");
+    fs::write(
+        new.join("benchmark.json"),
+        format!(
+            r#"{{"group_id":"{group}","function_id":"{function}","value_str":null,"throughput":null,"full_id":"{id}","directory_name":"{id}","title":"{id}"}}"#
+        ),
+    )?;
+    fs::write(new.join("estimates.json"), estimates(samples, stats))?;
+    fs::write(new.join("sample.json"), sample(samples))?;
+    Ok(())
+}
+
+/// Criterion's estimates, with a confidence interval for the mean from its standard error.
+///
+/// The other estimates use the point estimate as both bounds, as bootstrapping their confidence
+/// intervals would be slower than most solutions.
+#[expect(clippy::cast_precision_loss)]
+fn estimates(samples: &[Duration], stats: &Stats) -> String {
+    let std_dev = nanos(stats.std_dev);
+    let standard_error = std_dev / (samples.len() as f64).sqrt();
+
+    let mut deviations: Vec<Duration> = samples.iter().map(|&s| s.abs_diff(stats.median)).collect();
+    deviations.sort_unstable();
+    let median_abs_dev = nanos(deviations[deviations.len() / 2]);
+
+    format!(
+        r#"{{"mean":{},"median":{},"median_abs_dev":{},"slope":null,"std_dev":{}}}"#,
+        estimate(nanos(stats.mean), standard_error, 1.96 * standard_error),
+        estimate(nanos(stats.median), 0.0, 0.0),
+        estimate(median_abs_dev, 0.0, 0.0),
+        estimate(std_dev, 0.0, 0.0),
+    )
+}
+
+fn estimate(point: f64, standard_error: f64, margin: f64) -> String {
+    format!(
+        r#"{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{:?},"upper_bound":{:?}}},"point_estimate":{point:?},"standard_error":{standard_error:?}}}"#,
+        point - margin,
+        point + margin,
+    )
+}
+
+/// Each sample is a single iteration, as the bench mode times every iteration separately.
+fn sample(samples: &[Duration]) -> String {
+    let mut iters = String::new();
+    let mut times = String::new();
+    for (i, &s) in samples.iter().enumerate() {
+        if i > 0 {
+            iters.push(',');
+            times.push(',');
+        }
+        iters.push_str("1.0");
+        write!(times, "{:?}", nanos(s)).unwrap();
+    }
+    format!(r#"{{"sampling_mode":"Flat","iters":[{iters}],"times":[{times}]}}"#)
+}
+
+fn nanos(d: Duration) -> f64 {
+    d.as_secs_f64() * 1e9
+}
@@ -0,0 +1,51 @@
+use aoc::output::{CsvField, Format, JsonString};
+use aoc::PuzzleFn;
+use utils::date::{Day, Year};
+
+const HEADER: &str = "Puzzle  │ Examples │ Title";
+const SEPARATOR: &str =
+    "────────┼──────────┼──────────────────────────────────────────────────────";
+
+/// Print each puzzle with its title, taken from the solution's doc comment, and whether it has
+/// example inputs.
+///
+/// With `--format json` each puzzle is output as an object with `year`, `day`, `title` and
+/// `examples` fields, and with `--format csv` as a row with the same columns.
+pub fn list_puzzles(puzzles: &[(Year, Day, PuzzleFn)], format: Format) {
+    match format {
+        Format::Table => {
+            println!("{HEADER}");
+            println!("{SEPARATOR}");
+        }
+        Format::Csv => println!("year,day,title,examples"),
+        Format::Json | Format::Quiet => {}
+    }
+
+    for &(year, day, _) in puzzles {
+        let title = aoc::puzzles()
+            .find(|p| (p.year, p.day) == (year, day))
+            .map_or("", |p| p.title);
+        let examples =
+            aoc::puzzle_examples(year, day).is_some_and(|(inputs, _)| !inputs.is_empty());
+
+        match format {
+            Format::Table => println!(
+                "{year:#} {day:#} │ {:<8} │ {title}",
+                if examples { "yes" } else { "no" },
+            ),
+            Format::Json => println!(
+                r#"{{"year":{},"day":{},"title":{},"examples":{examples}}}"#,
+                year.to_u16(),
+                day.to_u8(),
+                JsonString(title),
+            ),
+            Format::Csv => println!(
+                "{},{},{},{examples}",
+                year.to_u16(),
+                day.to_u8(),
+                CsvField(title),
+            ),
+            Format::Quiet => {}
+        }
+    }
+}
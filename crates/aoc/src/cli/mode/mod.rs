@@ -0,0 +1,7 @@
+//! Alternative modes of running solutions, selected using command line options.
+
+mod bench;
+mod list;
+
+pub use bench::run_benchmarks;
+pub use list::list_puzzles;
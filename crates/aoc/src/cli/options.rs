@@ -1,16 +1,50 @@
-use aoc::{PuzzleFn, PUZZLES};
+use super::config::{self, CONFIG_OPTIONS};
+use aoc::output::{AnswerEncoding, Format};
+use aoc::{input_generator, PuzzleFn, PUZZLES};
 use std::collections::VecDeque;
 use std::error::Error;
 use std::num::NonZeroUsize;
+use std::time::Duration;
 use utils::date::{Day, Year};
 use utils::multiversion::{Version, VERSIONS};
 
 #[derive(Debug, Default)]
+#[allow(clippy::allow_attributes, clippy::struct_excessive_bools)]
 pub struct Options {
     program_name: Option<String>,
+    /// Long names of the options provided on the command line, which take precedence over the
+    /// config file.
+    provided: Vec<String>,
     pub help: bool,
+    pub config_path: Option<String>,
+    pub no_config: bool,
     pub version_override: Option<Version>,
     pub threads_override: Option<NonZeroUsize>,
+    pub budget: Option<Duration>,
+    pub bench: bool,
+    pub bench_criterion_dir: Option<String>,
+    pub bench_iterations: Option<NonZeroUsize>,
+    pub bench_libtest: bool,
+    pub bench_time: Option<Duration>,
+    pub cache: bool,
+    pub no_cache: bool,
+    pub no_progress: bool,
+    pub verbose: bool,
+    pub clipboard: bool,
+    pub input_path: Option<String>,
+    pub inputs_dir: Option<String>,
+    pub max_input_size: Option<usize>,
+    pub generate_seed: Option<u64>,
+    pub explain: bool,
+    pub list: bool,
+    pub record_path: Option<String>,
+    pub replay_path: Option<String>,
+    pub dump_graph_path: Option<String>,
+    pub format: Format,
+    pub answer_encoding: AnswerEncoding,
+    pub skip: Option<Vec<(Year, Day)>>,
+    #[cfg(feature = "alloc-profile")]
+    pub alloc_profile: bool,
     pub year: Option<Year>,
     pub day: Option<Day>,
 }
@@ -83,12 +117,82 @@ impl Options {
             }
         }
 
+        if result.config_path.is_some() && result.no_config {
+            return Err("options --config and --no-config cannot be used together".to_string());
+        }
+        if !result.no_config && !result.help {
+            result.apply_config()?;
+        }
+
+        result.validate()?;
         Ok(result)
     }
 
+    /// Check options which can't be used together or require other options.
+    fn validate(&self) -> Result<(), String> {
+        let input_sources = [
+            self.clipboard,
+            self.generate_seed.is_some(),
+            self.input_path.is_some(),
+        ];
+        if input_sources.iter().filter(|&&x| x).count() > 1 {
+            return Err(
+                "options --clipboard, --generate and --input cannot be used together".to_string(),
+            );
+        }
+        if self.clipboard && self.day.is_none() {
+            return Err("option --clipboard: year and day must be provided".to_string());
+        }
+        if self.input_path.is_some() && self.day.is_none() {
+            return Err("option --input: year and day must be provided".to_string());
+        }
+        if self.explain && input_sources.contains(&true) {
+            return Err(
+                "option --explain cannot be used with --clipboard, --generate or --input"
+                    .to_string(),
+            );
+        }
+        if self.explain && self.day.is_none() {
+            return Err("option --explain: year and day must be provided".to_string());
+        }
+        if self.record_path.is_some() && self.replay_path.is_some() {
+            return Err("options --record and --replay cannot be used together".to_string());
+        }
+        if self.record_path.is_some() && self.day.is_none() {
+            return Err("option --record: year and day must be provided".to_string());
+        }
+        if self.replay_path.is_some() && self.day.is_none() {
+            return Err("option --replay: year and day must be provided".to_string());
+        }
+        if self.dump_graph_path.is_some() && self.day.is_none() {
+            return Err("option --dump-graph: year and day must be provided".to_string());
+        }
+        if self.bench && self.explain {
+            return Err("options --bench and --explain cannot be used together".to_string());
+        }
+        if self.list && (self.bench || self.explain) {
+            return Err("option --list cannot be used with --bench or --explain".to_string());
+        }
+        if !self.bench && self.bench_iterations.is_some() {
+            return Err("option --bench-iterations: requires --bench".to_string());
+        }
+        if !self.bench && self.bench_time.is_some() {
+            return Err("option --bench-time: requires --bench".to_string());
+        }
+        if !self.bench && self.bench_libtest {
+            return Err("option --bench-libtest: requires --bench".to_string());
+        }
+        if !self.bench && self.bench_criterion_dir.is_some() {
+            return Err("option --bench-criterion: requires --bench".to_string());
+        }
+
+        Ok(())
+    }
+
+    #[expect(clippy::too_many_lines, reason = "mostly the help text")]
     pub fn help(&self) -> String {
         format!(
-            r#"Usage:
+            r"Usage:
     {program_name}
         Run all solutions
 
@@ -106,32 +210,381 @@ Options:
     --threads/-t $threads
         Override the number of threads to use for multithreaded solutions.
 
+    --budget $duration
+        Soft time limit for each solution, such as 500ms or 2s. Solutions using anytime
+        algorithms return the best answer found within the limit. Disables the answer cache.
+
+    --bench
+        Run each solution repeatedly after warming up, printing the mean, median and standard
+        deviation of the time taken to parse the input and solve each part. Disables the answer
+        cache. The json and csv formats also include the CPU model, compiler version, git commit,
+        thread count and selected multiversion implementations.
+
+    --bench-iterations $count
+        Number of iterations to time for each solution with --bench. If --bench-time is also
+        provided, stops after whichever limit is reached first.
+
+    --bench-time $duration
+        Time to spend benchmarking each solution with --bench, such as 500ms or 2s. Defaults to
+        1s, with at least one iteration always timed.
+
+    --bench-libtest
+        Print --bench results in the libtest bench format used by cargo bench instead of the
+        selected format, for tools which parse it such as CI benchmark actions.
+
+    --bench-criterion $path
+        Also save --bench results to the provided directory in the layout used by Criterion,
+        such as target/criterion, so runs can be compared using tools such as critcmp. Previous
+        results are kept as the base baseline.
+
+    --cache
+        Reuse answers stored in inputs/answers.cache when the input is unchanged, and store new
+        answers. Cached answers for a day are invalidated when its solution's source file or code
+        shared between solutions, such as the utils crate, changes.
+
+    --no-cache
+        Disable the answer cache, overriding --cache.
+
+    --no-progress
+        Don't show progress bars on stderr for slow solutions which report their progress, such
+        as MD5 brute-force searches. Progress bars are only shown when stderr is a terminal.
+
+    --clipboard
+        Read the input from the clipboard instead of the inputs directory. Requires a year and day,
+        and the binary to be built with the clipboard feature.
+
+    --input $path
+        Read the input from the provided file instead of the inputs directory. Requires a year
+        and day.
+
+    --inputs-dir $path
+        Directory containing inputs, stored as year$year/day$day.txt, and the answer cache.
+        Alternative inputs stored as year$year/day$day-$name.txt are also run. Answers are checked
+        against $input.answers files stored next to each input when present. Defaults to inputs.
+
+    --max-input-size $bytes
+        Maximum input size in bytes, defaulting to 16 MiB. Larger inputs are rejected to catch
+        reading the wrong file, but generated inputs may need a higher limit.
+
+    --generate $seed
+        Run solutions using inputs generated from the provided seed instead of the inputs
+        directory. Only solutions which support generating inputs are run.
+
+    --explain
+        Run the solution on its example inputs, printing a walkthrough of the algorithm for
+        solutions which support it. Requires a year and day.
+
+    --list
+        List the matching solutions instead of running them, along with each puzzle's title and
+        whether the solution has example inputs. The json and csv formats are also supported.
+
+    --record $path
+        Record the decisions made by randomized or interactive solutions to the provided file.
+        Requires a year and day, and disables the answer cache.
+
+    --replay $path
+        Replay decisions previously saved using --record, failing if the run diverges. Requires
+        a year and day, and disables the answer cache.
+
+    --dump-graph $path
+        Write graphs built by solutions which support it to the provided file in Graphviz DOT
+        format. Requires a year and day, and disables the answer cache.
+
+    --format $format
+        Output format for results. Supported formats: table (default), json (one object per
+        line), csv and quiet. Errors loading inputs or running solutions are also reported in
+        the selected format.
+
+    --answer-encoding $encoding
+        Encoding for printed answers, to avoid spoilers in shared output while keeping answers
+        comparable. Supported encodings: plain (default), hex, base64 and sha256.
+
+    --skip $puzzles
+        Comma separated list of puzzles to skip when running multiple solutions, such as
+        2016-05,2016-14. Puzzles are always run when a year and day are provided.
+
+    --config $path
+        Read default options from the provided file instead of aoc.toml in the current directory
+        or aoc/config.toml in the user config directory. Options provided on the command line take
+        precedence. Each line of the file sets an option using TOML syntax, such as threads = 4,
+        and the answer-encoding, format, inputs-dir, max-input-size, multiversion, skip and
+        threads options are supported.
+
+    --no-config
+        Ignore the config file.
+
+    --verbose/-v
+        Print warnings about input formatting problems, such as trailing whitespace, and which
+        implementation of multiversioned functions each solution selected.
+{extra_options}
     --help/-h
         Print this help
 
-{cargo_repo}"#,
+{cargo_repo}",
             program_name = self.program_name.as_ref().map_or("aoc", String::as_str),
             multiversion_options = *VERSIONS,
+            extra_options = if cfg!(feature = "alloc-profile") {
+                r"
+    --alloc-profile
+        Record allocations made by the solutions and print the peak heap usage and the top
+        allocation sites of each puzzle to stderr.
+"
+            } else {
+                ""
+            },
             cargo_repo = env!("CARGO_PKG_REPOSITORY"),
         )
     }
 
+    /// Apply options from the config file which weren't provided on the command line.
+    fn apply_config(&mut self) -> Result<(), String> {
+        let Some((path, contents)) = config::read(self.config_path.as_deref())? else {
+            return Ok(());
+        };
+        let path = path.display();
+
+        for entry in config::parse(&contents).map_err(|e| format!("config {path}: {e}"))? {
+            let context = format!("config {path}: line {}", entry.line);
+            if !CONFIG_OPTIONS.contains(&entry.key.as_str()) {
+                return Err(format!("{context}: unsupported option {:?}", entry.key));
+            }
+            if self.provided.contains(&entry.key) {
+                continue;
+            }
+            self.handle_long(&entry.key, ArgumentValue::Provided(entry.value))
+                .map_err(|e| format!("{context}: option {}: {e}", entry.key))?;
+        }
+        Ok(())
+    }
+
     fn handle_long(&mut self, name: &str, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        self.provided.push(name.to_string());
         match name {
+            #[cfg(feature = "alloc-profile")]
+            "alloc-profile" => self.option_alloc_profile(value),
+            "answer-encoding" => self.option_answer_encoding(value),
+            "bench" => self.option_bench(value),
+            "bench-criterion" => self.option_bench_criterion(value),
+            "bench-iterations" => self.option_bench_iterations(value),
+            "bench-libtest" => self.option_bench_libtest(value),
+            "bench-time" => self.option_bench_time(value),
+            "budget" => self.option_budget(value),
+            "cache" => self.option_cache(value),
+            "clipboard" => self.option_clipboard(value),
+            "config" => self.option_config(value),
+            "dump-graph" => self.option_dump_graph(value),
+            "explain" => self.option_explain(value),
+            "format" => self.option_format(value),
+            "generate" => self.option_generate(value),
             "help" => self.option_help(value),
+            "input" => self.option_input(value),
+            "inputs-dir" => self.option_inputs_dir(value),
+            "list" => self.option_list(value),
+            "max-input-size" => self.option_max_input_size(value),
             "multiversion" => self.option_multiversion(value),
+            "no-cache" => self.option_no_cache(value),
+            "no-config" => self.option_no_config(value),
+            "no-progress" => self.option_no_progress(value),
+            "record" => self.option_record(value),
+            "replay" => self.option_replay(value),
+            "skip" => self.option_skip(value),
             "threads" => self.option_threads(value),
+            "verbose" => self.option_verbose(value),
             _ => Err("unknown option".into()),
         }
     }
 
     fn handle_short(&mut self, name: char, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
-        match name {
-            'h' => self.option_help(value),
-            'm' => self.option_multiversion(value),
-            't' => self.option_threads(value),
-            _ => Err("unknown option".into()),
+        let long = match name {
+            'h' => "help",
+            'm' => "multiversion",
+            't' => "threads",
+            'v' => "verbose",
+            _ => return Err("unknown option".into()),
+        };
+        self.handle_long(long, value)
+    }
+
+    #[cfg(feature = "alloc-profile")]
+    fn option_alloc_profile(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.alloc_profile = true;
+        Ok(())
+    }
+
+    fn option_answer_encoding(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        self.answer_encoding = value.required()?.parse()?;
+        Ok(())
+    }
+
+    fn option_bench(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.bench = true;
+        Ok(())
+    }
+
+    fn option_bench_criterion(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.bench_criterion_dir.is_some() {
+            return Err("option provided more than once".into());
         }
+        self.bench_criterion_dir = Some(value);
+        Ok(())
+    }
+
+    fn option_bench_iterations(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.bench_iterations.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.bench_iterations = Some(value.parse()?);
+        Ok(())
+    }
+
+    fn option_bench_libtest(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.bench_libtest = true;
+        Ok(())
+    }
+
+    fn option_bench_time(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.bench_time.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.bench_time = Some(parse_duration(&value)?);
+        Ok(())
+    }
+
+    fn option_budget(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.budget.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.budget = Some(parse_duration(&value)?);
+        Ok(())
+    }
+
+    fn option_cache(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.cache = true;
+        Ok(())
+    }
+
+    fn option_clipboard(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        if !cfg!(feature = "clipboard") {
+            return Err("clipboard support not enabled, rebuild with --features clipboard".into());
+        }
+        self.clipboard = true;
+        Ok(())
+    }
+
+    fn option_config(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.config_path.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.config_path = Some(value);
+        Ok(())
+    }
+
+    fn option_dump_graph(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.dump_graph_path.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.dump_graph_path = Some(value);
+        Ok(())
+    }
+
+    fn option_explain(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.explain = true;
+        Ok(())
+    }
+
+    fn option_format(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        self.format = value.required()?.parse()?;
+        Ok(())
+    }
+
+    fn option_generate(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.generate_seed.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.generate_seed = Some(value.parse()?);
+        Ok(())
+    }
+
+    fn option_input(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.input_path.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.input_path = Some(value);
+        Ok(())
+    }
+
+    fn option_inputs_dir(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.inputs_dir.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.inputs_dir = Some(value);
+        Ok(())
+    }
+
+    fn option_list(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.list = true;
+        Ok(())
+    }
+
+    fn option_max_input_size(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.max_input_size.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.max_input_size = Some(value.parse()?);
+        Ok(())
+    }
+
+    fn option_record(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.record_path.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.record_path = Some(value);
+        Ok(())
+    }
+
+    fn option_replay(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.replay_path.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.replay_path = Some(value);
+        Ok(())
+    }
+
+    fn option_no_cache(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.no_cache = true;
+        Ok(())
+    }
+
+    fn option_no_config(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.no_config = true;
+        Ok(())
+    }
+
+    fn option_no_progress(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.no_progress = true;
+        Ok(())
     }
 
     fn option_help(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
@@ -149,6 +602,26 @@ Options:
         Ok(())
     }
 
+    fn option_skip(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        let value = value.required()?;
+        if self.skip.is_some() {
+            return Err("option provided more than once".into());
+        }
+        self.skip = Some(
+            value
+                .split(',')
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    let (year, day) = s
+                        .split_once('-')
+                        .ok_or_else(|| format!("expected $year-$day, found {s:?}"))?;
+                    Ok((year.parse()?, day.parse()?))
+                })
+                .collect::<Result<_, Box<dyn Error>>>()?,
+        );
+        Ok(())
+    }
+
     fn option_threads(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
         let value = value.required()?;
         if self.threads_override.is_some() {
@@ -158,15 +631,40 @@ Options:
         Ok(())
     }
 
+    fn option_verbose(&mut self, value: ArgumentValue) -> Result<(), Box<dyn Error>> {
+        value.none()?;
+        self.verbose = true;
+        Ok(())
+    }
+
+    /// Returns the directory containing inputs and the answer cache.
+    pub fn inputs_dir(&self) -> &str {
+        self.inputs_dir.as_deref().unwrap_or("inputs")
+    }
+
     pub fn matching_puzzles(&self) -> Vec<(Year, Day, PuzzleFn)> {
         PUZZLES
             .iter()
             .copied()
             .filter(|&(y, d, ..)| self.year.unwrap_or(y) == y && self.day.unwrap_or(d) == d)
+            .filter(|&(y, d, ..)| self.generate_seed.is_none() || input_generator(y, d).is_some())
+            .filter(|&(y, d, ..)| {
+                self.day.is_some() || self.skip.as_ref().is_none_or(|s| !s.contains(&(y, d)))
+            })
             .collect()
     }
 }
 
+fn parse_duration(value: &str) -> Result<Duration, Box<dyn Error>> {
+    if let Some(ms) = value.strip_suffix("ms") {
+        Ok(Duration::from_millis(ms.parse()?))
+    } else if let Some(secs) = value.strip_suffix('s') {
+        Ok(Duration::try_from_secs_f64(secs.parse()?)?)
+    } else {
+        Err("duration must end with ms or s".into())
+    }
+}
+
 #[must_use]
 enum ArgumentValue<'a> {
     // Used with --long=value options
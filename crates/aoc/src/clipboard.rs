@@ -0,0 +1,72 @@
+//! Clipboard input, enabled with the `clipboard` feature and `--clipboard` option.
+//!
+//! The clipboard is read using the platform's command line tools to avoid linking against
+//! platform-specific libraries. Each supported tool is tried in order until one succeeds.
+
+use std::io;
+use std::process::{Command, Stdio};
+
+#[cfg(target_os = "macos")]
+const COMMANDS: &[(&str, &[&str])] = &[("pbpaste", &[])];
+#[cfg(windows)]
+const COMMANDS: &[(&str, &[&str])] = &[(
+    "powershell",
+    &[
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        "[Console]::OutputEncoding = [Text.Encoding]::UTF8; Get-Clipboard -Raw",
+    ],
+)];
+#[cfg(all(unix, not(target_os = "macos")))]
+const COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-out"]),
+    ("xsel", &["--clipboard", "--output"]),
+];
+#[cfg(not(any(unix, windows)))]
+const COMMANDS: &[(&str, &[&str])] = &[];
+
+/// Read the contents of the clipboard as text.
+pub fn read() -> Result<String, String> {
+    let mut errors = Vec::new();
+
+    for &(program, args) in COMMANDS {
+        let output = match Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+        {
+            Ok(output) => output,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                errors.push(format!("{program}: {err}"));
+                continue;
+            }
+        };
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            errors.push(format!("{program}: {}", stderr.trim()));
+            continue;
+        }
+
+        return String::from_utf8(output.stdout)
+            .map_err(|_| format!("{program}: clipboard contents are not valid UTF-8"));
+    }
+
+    if errors.is_empty() {
+        let tools: Vec<_> = COMMANDS.iter().map(|c| c.0).collect();
+        if tools.is_empty() {
+            Err("reading the clipboard is not supported on this platform".to_string())
+        } else {
+            Err(format!(
+                "no supported clipboard tool found (tried {})",
+                tools.join(", ")
+            ))
+        }
+    } else {
+        Err(format!("failed to read clipboard: {}", errors.join("; ")))
+    }
+}
@@ -0,0 +1,56 @@
+//! Regression tests using inputs stored in each year crate's `corpus` directory.
+//!
+//! Cases are added using `cargo xtask corpus add`, which stores the input as
+//! `crates/$year/corpus/$day/$name.txt` and the expected answers in `$name.answers`, using the
+//! format described in [`utils::answers`].
+
+use crate::PUZZLES;
+use std::fs::read_to_string;
+use std::path::Path;
+use utils::answers::ExpectedAnswers;
+
+#[test]
+fn corpus() {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let mut failures = Vec::new();
+    for &(year, day, f) in PUZZLES {
+        let dir = crates_dir
+            .join(format!("year{year:#}"))
+            .join("corpus")
+            .join(format!("day{day:#}"));
+        let Ok(entries) = dir.read_dir() else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|e| e != "txt") {
+                continue;
+            }
+
+            let input = read_to_string(&path).unwrap();
+            let answers = read_to_string(path.with_extension("answers"))
+                .unwrap_or_else(|err| panic!("failed to read answers for {path:?}: {err}"));
+
+            let case = format!("{year:#} {day:#} {:?}", path.file_name().unwrap());
+            let expected = ExpectedAnswers::parse(&answers, &dir)
+                .unwrap_or_else(|err| panic!("{case}: {err}"));
+            match f(input.trim_ascii_end()) {
+                Ok((part1, part2)) => {
+                    for (part, expected, actual) in [
+                        ("part1", &expected.part1, &part1),
+                        ("part2", &expected.part2, &part2),
+                    ] {
+                        if let Err(err) = expected.check(&actual.to_string(), &path) {
+                            failures.push(format!("{case}: {part} {err}"));
+                        }
+                    }
+                }
+                Err(err) => failures.push(format!("{case}: {err}")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
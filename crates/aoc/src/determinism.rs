@@ -0,0 +1,179 @@
+//! End-to-end determinism test, which solves every puzzle's examples using different thread counts
+//! and multiversion overrides, checking the answers are identical.
+//!
+//! Examples are first solved using a single thread. Examples which are cheap to solve are then
+//! solved again using multiple threads, and once per supported multiversion [`Version`]. Set the
+//! `AOC_DETERMINISM_FULL` environment variable to repeat every example, which is slow in debug
+//! builds.
+//!
+//! By default only a small sample of the examples is checked, as solving every example takes
+//! minutes in debug builds. The full test is ignored by default, and can be run with
+//! `cargo test -p aoc determinism -- --ignored`.
+//!
+//! Inputs generated by each [`input_generator`] are also included when the
+//! `AOC_DETERMINISM_SEED` environment variable is set to a seed.
+//!
+//! The global multiversion override can only be set once per process, so each version is tested
+//! by re-running this test binary with `AOC_DETERMINISM_VERSION` set.
+
+use crate::{input_generator, puzzle_examples, ExampleFn, PuzzleFn, PUZZLES};
+use std::env;
+use std::num::NonZeroUsize;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use utils::multithreading::{get_thread_count, set_thread_count};
+use utils::multiversion::{Version, VERSIONS};
+
+/// Maximum time taken by the first run for an example to be repeated.
+const CHEAP: Duration = Duration::from_secs(1);
+
+/// Distance between the cases checked by the default sample.
+const SAMPLE_STEP: usize = 25;
+
+const PREFIX: &str = "determinism: ";
+
+enum Solver {
+    Example(ExampleFn, bool, bool),
+    Real(PuzzleFn),
+}
+
+fn cases() -> Vec<(String, String, Solver)> {
+    let seed = env::var("AOC_DETERMINISM_SEED")
+        .ok()
+        .map(|s| s.parse::<u64>().expect("invalid AOC_DETERMINISM_SEED"));
+
+    let mut cases = Vec::new();
+    for &(year, day, real_fn) in PUZZLES {
+        if let Some((inputs, example_fn)) = puzzle_examples(year, day) {
+            for (i, (input, part1, part2)) in inputs.into_iter().enumerate() {
+                let name = format!("{year:#} {day:#} example {i}");
+                let solver = Solver::Example(example_fn, part1, part2);
+                cases.push((name, input.to_string(), solver));
+            }
+        }
+        if let (Some(seed), Some(generator)) = (seed, input_generator(year, day)) {
+            let name = format!("{year:#} {day:#} seed {seed}");
+            cases.push((name, generator(seed), Solver::Real(real_fn)));
+        }
+    }
+    cases
+}
+
+fn solve((name, input, solver): &(String, String, Solver)) -> String {
+    let result = match *solver {
+        Solver::Example(f, part1, part2) => f(input, part1, part2)
+            .map(|answers| format!("{answers:?}"))
+            .map_err(|err| err.to_string()),
+        Solver::Real(f) => f(input)
+            .map(|answers| format!("{answers:?}"))
+            .map_err(|err| err.to_string()),
+    };
+    format!("{name}: {result:?}")
+}
+
+fn assert_same(expected: &[&String], run_name: &str, actual: &[String]) {
+    assert_eq!(
+        expected.len(),
+        actual.len(),
+        "{run_name}: number of cases differ"
+    );
+    let failures: Vec<String> = expected
+        .iter()
+        .zip(actual)
+        .filter(|(a, b)| **a != *b)
+        .map(|(a, b)| format!("expected: {a}\n{run_name}: {b}"))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn sample_is_deterministic() {
+    check((0..cases().len()).step_by(SAMPLE_STEP));
+}
+
+#[test]
+#[ignore = "slow, solves every example"]
+fn examples_are_deterministic() {
+    check(0..cases().len());
+}
+
+fn check(selected: impl Iterator<Item = usize>) {
+    let cases = cases();
+    let full = env::var_os("AOC_DETERMINISM_FULL").is_some();
+    let max_threads = get_thread_count().max(NonZeroUsize::new(4).unwrap());
+
+    set_thread_count(NonZeroUsize::new(1).unwrap());
+    let mut repeated = Vec::new();
+    let mut expected = Vec::new();
+    for i in selected {
+        let start = Instant::now();
+        let result = solve(&cases[i]);
+        if full || start.elapsed() <= CHEAP {
+            repeated.push(i);
+            expected.push(result);
+        }
+    }
+    let expected: Vec<&String> = expected.iter().collect();
+
+    // Start each version first so they run concurrently with the multithreaded run
+    let indexes = repeated.iter().map(usize::to_string).collect::<Vec<_>>();
+    let children: Vec<_> = VERSIONS
+        .iter()
+        .map(|&version| {
+            let child = Command::new(env::current_exe().unwrap())
+                .args(["--exact", "determinism::version", "--ignored"])
+                .arg("--nocapture")
+                .env("AOC_DETERMINISM_VERSION", format!("{version:?}"))
+                .env("AOC_DETERMINISM_CASES", indexes.join(","))
+                .env("AOC_DETERMINISM_THREADS", max_threads.to_string())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap();
+            (version, child)
+        })
+        .collect();
+
+    set_thread_count(max_threads);
+    let multi: Vec<String> = repeated.iter().map(|&i| solve(&cases[i])).collect();
+    assert_same(&expected, &format!("{max_threads} threads"), &multi);
+
+    for (version, child) in children {
+        let output = child.wait_with_output().unwrap();
+        assert!(
+            output.status.success(),
+            "{version:?} run failed:\n{}",
+            String::from_utf8_lossy(&output.stderr),
+        );
+
+        let actual: Vec<String> = String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            // The first line follows the test name printed by libtest
+            .filter_map(|l| Some(l.split_once(PREFIX)?.1.to_string()))
+            .collect();
+        assert_same(&expected, &format!("{version:?}"), &actual);
+    }
+}
+
+#[test]
+#[ignore = "run by examples_are_deterministic with AOC_DETERMINISM_VERSION set"]
+fn version() {
+    let Ok(version) = env::var("AOC_DETERMINISM_VERSION") else {
+        return;
+    };
+    Version::set_override(version.parse().unwrap());
+    set_thread_count(
+        env::var("AOC_DETERMINISM_THREADS")
+            .unwrap()
+            .parse()
+            .unwrap(),
+    );
+
+    let cases = cases();
+    for index in env::var("AOC_DETERMINISM_CASES").unwrap().split(',') {
+        if let Ok(index) = index.parse::<usize>() {
+            println!("{PREFIX}{}", solve(&cases[index]));
+        }
+    }
+}
@@ -1,6 +1,17 @@
+mod answer;
+#[cfg(test)]
+mod corpus;
+#[cfg(test)]
+mod determinism;
+pub mod output;
 mod puzzles;
 mod years;
 
 pub use ::utils;
-pub use puzzles::{PuzzleFn, PUZZLES};
+pub use answer::Answer;
+pub use puzzles::{
+    input_generator, puzzle_bench, puzzle_examples, puzzles, run, run_example, run_example_timed,
+    run_timed, BenchFn, ExampleFn, Parts, PuzzleError, PuzzleFn, PuzzleInfo, RunError, RunOutput,
+    Timings, PUZZLES,
+};
 pub use years::*;
@@ -1,15 +1,31 @@
-use crate::cli::Options;
-use std::fs::read_to_string;
+use crate::cache::AnswerCache;
+use crate::cli::{mode, Options};
+use aoc::output::PuzzleResult;
+use aoc::PuzzleError;
+use std::fs::{read_dir, read_to_string, write};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::time::{Duration, Instant};
+use utils::answers::ExpectedAnswers;
+use utils::budget::Budget;
 use utils::date::{Day, Year};
+use utils::explain;
+use utils::graph;
+use utils::input::{check_size, validate, InputWarning, DEFAULT_MAX_SIZE};
 use utils::multithreading::set_thread_count;
-use utils::multiversion::Version;
+use utils::multiversion::{selected_versions, Version};
+use utils::replay::{self, Replay};
 
+#[cfg(feature = "alloc-profile")]
+mod alloc_profile;
+mod cache;
 mod cli;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod progress_bar;
 
+#[expect(clippy::too_many_lines)]
 fn main() {
     let args = match Options::parse() {
         Ok(x) => x,
@@ -28,6 +44,7 @@ fn main() {
     if let Some(threads) = args.threads_override {
         set_thread_count(threads);
     }
+    graph::set_dump_path(args.dump_graph_path.as_ref().map(PathBuf::from));
 
     let puzzles = args.matching_puzzles();
     if puzzles.is_empty() {
@@ -35,74 +52,359 @@ fn main() {
         exit(1);
     }
 
-    // FIXME support 80 character wide output (without time?)
-    println!(
-        "Puzzle  │ Part 1               │ Part 2                                 │ Time      "
-    );
-    println!(
-        "────────┼──────────────────────┼────────────────────────────────────────┼───────────"
-    );
+    if args.list {
+        mode::list_puzzles(&puzzles, args.format);
+        return;
+    }
+
+    if args.explain {
+        for &(year, day, _) in &puzzles {
+            explain_examples(year, day);
+        }
+        return;
+    }
+
+    if args.bench {
+        mode::run_benchmarks(&puzzles, &args);
+        return;
+    }
+
+    let mut cache = load_cache(&args);
+
+    let mut sink = args.answer_encoding.wrap(args.format.sink());
+    sink.start();
     let mut total = Duration::default();
+    let mut selected_count = 0;
     for (year, day, f) in puzzles {
-        let input = match read_input(year, day) {
-            Ok(input) => input,
-            Err((path, err)) => {
-                println!("{year:#} {day:#}: failed to read {path:?}: {err}");
+        for path in input_paths(year, day, &args) {
+            let (input, warnings) = match load_input_from(year, day, path.as_deref(), &args) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    sink.error(year, day, &err);
+                    exit(1);
+                }
+            };
+
+            let cached = cache.as_ref().and_then(|c| c.get(year, day, &input));
+            let start = Instant::now();
+            let is_cached = cached.is_some();
+            #[cfg(feature = "alloc-profile")]
+            alloc_profile::set_enabled(args.alloc_profile);
+            start_recording(&args);
+            Budget::start(args.budget);
+            let result = cached.map_or_else(
+                || {
+                    progress_bar::run(&args, year, day, || f(&input))
+                        .map(|(part1, part2)| (part1.to_string(), part2.to_string()))
+                },
+                Ok,
+            );
+            let recording_result = finish_recording(&args);
+            #[cfg(feature = "alloc-profile")]
+            if args.alloc_profile {
+                alloc_profile::print_report(year, day, 10);
+            }
+            if args.verbose {
+                print_selected_versions(year, day, &mut selected_count);
+            }
+            match result {
+                Ok((part1, part2)) => {
+                    let elapsed = start.elapsed();
+                    if let Err(err) = recording_result {
+                        sink.error(year, day, &err);
+                        exit(1);
+                    }
+
+                    let time = if is_cached {
+                        None
+                    } else {
+                        total += elapsed;
+                        if let Some(cache) = &mut cache {
+                            cache.insert(year, day, &input, &part1, &part2);
+                        }
+                        Some(elapsed)
+                    };
+
+                    if let Some(path) = &path {
+                        if let Err(err) = check_answers(path, &part1, &part2) {
+                            sink.error(year, day, &err);
+                            exit(1);
+                        }
+                    }
+
+                    sink.result(&PuzzleResult {
+                        year,
+                        day,
+                        part1: &part1,
+                        part2: &part2,
+                        time,
+                    });
+                }
+                Err(err) => {
+                    sink.error(year, day, &err.to_string());
+                    // The solution only sees the normalized input, so formatting problems removed
+                    // by normalization are reported here instead of as notes on the error. Missing
+                    // final newlines are skipped, matching InputError.
+                    if matches!(err, PuzzleError::Input(_)) && !args.verbose {
+                        for warning in warnings
+                            .into_iter()
+                            .filter(|&w| w != InputWarning::MissingFinalNewline)
+                        {
+                            eprintln!("{year:#} {day:#}: note: {warning}");
+                        }
+                    }
+                    exit(exit_code(&err));
+                }
+            }
+        }
+    }
+    sink.finish(total);
+
+    if let Some(cache) = cache {
+        if let Err(err) = cache.save() {
+            eprintln!("failed to save answer cache: {err}");
+        }
+    }
+}
+
+/// Load the answer cache if enabled.
+///
+/// The cache is disabled when recording, replaying or dumping graphs, as these rely on the
+/// solutions running, and when using a time budget, as answers may depend on the budget.
+fn load_cache(args: &Options) -> Option<AnswerCache> {
+    let recording = args.record_path.is_some() || args.replay_path.is_some();
+    let budget = args.budget.is_some();
+    if !args.cache || args.no_cache || recording || args.dump_graph_path.is_some() || budget {
+        return None;
+    }
+
+    match AnswerCache::load(Path::new(args.inputs_dir())) {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            eprintln!("failed to load answer cache: {err}");
+            None
+        }
+    }
+}
+
+/// Start recording or replaying decisions if requested.
+fn start_recording(args: &Options) {
+    if let Some(path) = &args.replay_path {
+        let replay = read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|s| s.parse::<Replay>());
+        match replay {
+            Ok(replay) => replay::start_replay(replay),
+            Err(err) => {
+                eprintln!("failed to read replay {path:?}: {err}");
                 exit(1);
             }
-        };
+        }
+    } else if args.record_path.is_some() {
+        replay::start_recording();
+    }
+}
+
+/// Finish recording or replaying, saving recorded decisions.
+fn finish_recording(args: &Options) -> Result<(), String> {
+    let recorded = replay::finish()?;
+    if let (Some(path), Some(recorded)) = (&args.record_path, recorded) {
+        write(path, recorded.to_string())
+            .map_err(|err| format!("failed to write replay {path:?}: {err}"))?;
+    }
+    Ok(())
+}
+
+/// Print the multiversion implementations selected while running the previous solution.
+fn print_selected_versions(year: Year, day: Day, previous_count: &mut usize) {
+    let selected = selected_versions();
+    for (module, version) in &selected[*previous_count..] {
+        eprintln!("{year:#} {day:#}: multiversion {module} selected {version:?}");
+    }
+    *previous_count = selected.len();
+}
+
+/// Run the solution on each example input with explanations enabled, only solving the parts with
+/// known answers.
+fn explain_examples(year: Year, day: Day) {
+    let (inputs, f) = aoc::puzzle_examples(year, day).expect("examples should exist");
+    if inputs.is_empty() {
+        if cfg!(feature = "compact") {
+            println!("{year:#} {day:#}: no examples, as examples are removed in compact builds");
+        } else {
+            println!("{year:#} {day:#}: no examples");
+        }
+        return;
+    }
 
-        let start = Instant::now();
-        match f(&input) {
+    for (i, &(input, part1, part2)) in inputs.iter().enumerate() {
+        println!("{year:#} {day:#} example {i}:");
+        for line in input.lines() {
+            println!("{}", format!("  │ {line}").trim_end());
+        }
+        println!();
+
+        explain::set_enabled(true);
+        let result = f(input, part1, part2);
+        explain::set_enabled(false);
+
+        match result {
             Ok((part1, part2)) => {
-                let elapsed = start.elapsed();
-                total += elapsed;
-
-                // Hack to treat "🎄" as two characters wide
-                // ("🎄" is 1 wide in Unicode 8 but 2 wide in Unicode 9+)
-                let part1_width = if part1 == "🎄" { 19 } else { 20 };
-                let part2_width = if part2 == "🎄" { 37 } else { 38 };
-
-                println!(
-                    "{year:#} {day:#} │ {part1:<part1_width$} │ {part2:<part2_width$} │ {}",
-                    format_duration(elapsed)
-                );
+                println!();
+                if let Some(part1) = part1 {
+                    println!("Part 1: {part1}");
+                }
+                if let Some(part2) = part2 {
+                    println!("Part 2: {part2}");
+                }
+                println!();
             }
-            Err(input_err) => {
-                println!("{year:#} {day:#}: {input_err}");
-                exit(1);
+            Err(err) => {
+                println!("{year:#} {day:#}: {err}");
+                exit(exit_code(&err));
             }
         }
     }
+}
+
+/// Exit with 1 for invalid inputs, and 101 for solution panics to match uncaught panics.
+fn exit_code(err: &PuzzleError) -> i32 {
+    match err {
+        PuzzleError::Input(_) => 1,
+        PuzzleError::Solution(_) => 101,
+    }
+}
+
+/// Load, check and normalize the input for a puzzle, returning an error message on failure.
+fn load_input(year: Year, day: Day, args: &Options) -> Result<String, String> {
+    load_input_from(year, day, None, args).map(|(input, _)| input)
+}
+
+/// Load, check and normalize the input for a puzzle, reading the provided input file instead of
+/// the selected input source if provided.
+///
+/// Also returns the formatting problems found in the input before it was normalized, which are
+/// printed immediately when running with `--verbose`.
+fn load_input_from(
+    year: Year,
+    day: Day,
+    path: Option<&Path>,
+    args: &Options,
+) -> Result<(String, Vec<InputWarning>), String> {
+    let input = if let Some(path) = path {
+        read_to_string(path)
+            .map_err(|err| format!("failed to read {:?}: {err}", path.to_string_lossy()))?
+    } else if let Some(seed) = args.generate_seed {
+        let generator = aoc::input_generator(year, day).expect("generator should exist");
+        generator(seed)
+    } else {
+        read_input_source(year, day, args)?
+    };
+
+    let limit = args.max_input_size.unwrap_or(DEFAULT_MAX_SIZE);
+    check_size(&input, limit).map_err(|err| {
+        format!(
+            "{err}, rerun with --max-input-size {} to allow it",
+            err.size
+        )
+    })?;
+
+    if args.generate_seed.is_some() {
+        return Ok((input, Vec::new()));
+    }
+
+    // Validate the raw input, as normalizing removes trailing whitespace and blank lines
+    let warnings = validate(&input);
+    if args.verbose {
+        for warning in &warnings {
+            eprintln!("{year:#} {day:#}: warning: {warning}");
+        }
+    }
+
+    Ok((normalize_input(&input), warnings))
+}
+
+/// Returns the input files to run for a puzzle, or a single [`None`] to use the selected input
+/// source.
+///
+/// When reading from the inputs directory, alternative inputs stored next to the primary input as
+/// `day$day-$name.txt` are also returned, sorted by name after the primary input. Missing inputs
+/// are returned so that the error is reported when loading the input.
+fn input_paths(year: Year, day: Day, args: &Options) -> Vec<Option<PathBuf>> {
+    if args.generate_seed.is_some() || args.clipboard || args.input_path.is_some() {
+        return vec![None];
+    }
+
+    let dir = Path::new(args.inputs_dir()).join(format!("year{year:#}"));
+    let mut alternatives: Vec<PathBuf> = read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let alternative = name
+                .strip_prefix(&format!("day{day:#}-"))?
+                .strip_suffix(".txt")?;
+            (!alternative.is_empty()).then_some(path)
+        })
+        .collect();
+    alternatives.sort_unstable();
 
-    println!(
-        "────────┼──────────────────────┼────────────────────────────────────────┼───────────"
-    );
-    println!(
-        "                                                                        │ {}",
-        format_duration(total),
-    );
+    std::iter::once(dir.join(format!("day{day:#}.txt")))
+        .chain(alternatives)
+        .map(Some)
+        .collect()
 }
 
-pub fn read_input(year: Year, day: Day) -> Result<String, (String, io::Error)> {
-    let mut path = PathBuf::new();
-    path.push("inputs");
-    path.push(format!("year{year:#}"));
-    path.push(format!("day{day:#}.txt"));
-    match read_to_string(&path) {
-        Ok(s) => Ok(s.trim_ascii_end().replace("\r\n", "\n")),
-        Err(err) => Err((path.to_string_lossy().to_string(), err)),
+/// Check the answers against the answers file stored next to the input, if present.
+fn check_answers(input_path: &Path, part1: &str, part2: &str) -> Result<(), String> {
+    let answers_path = input_path.with_extension("answers");
+    let Ok(contents) = read_to_string(&answers_path) else {
+        return Ok(());
+    };
+
+    let dir = answers_path.parent().unwrap_or(Path::new("."));
+    let expected = ExpectedAnswers::parse(&contents, dir)
+        .map_err(|err| format!("failed to parse {}: {err}", answers_path.display()))?;
+    for (part, expected, actual) in [
+        ("part1", &expected.part1, part1),
+        ("part2", &expected.part2, part2),
+    ] {
+        expected
+            .check(actual, input_path)
+            .map_err(|err| format!("{}: {part} {err}", input_path.display()))?;
     }
+    Ok(())
 }
 
-fn format_duration(d: Duration) -> String {
-    let (unit, multiplier) = if d.as_micros() < 1000 {
-        ("µ", 1_000_000.)
+fn read_input_source(year: Year, day: Day, args: &Options) -> Result<String, String> {
+    #[cfg(feature = "clipboard")]
+    if args.clipboard {
+        return clipboard::read();
+    }
+
+    read_input(year, day, args.inputs_dir(), args.input_path.as_deref())
+        .map_err(|(path, err)| format!("failed to read {path:?}: {err}"))
+}
+
+pub fn read_input(
+    year: Year,
+    day: Day,
+    inputs_dir: &str,
+    path_override: Option<&str>,
+) -> Result<String, (String, io::Error)> {
+    let path = if let Some(path) = path_override {
+        PathBuf::from(path)
     } else {
-        ("m", 1_000.)
+        let mut path = PathBuf::from(inputs_dir);
+        path.push(format!("year{year:#}"));
+        path.push(format!("day{day:#}.txt"));
+        path
     };
+    read_to_string(&path).map_err(|err| (path.to_string_lossy().to_string(), err))
+}
 
-    let float = d.as_secs_f64() * multiplier;
-    let precision = if float < 1000. { 3 } else { 0 };
-    format!("{float:7.precision$} {unit}s")
+#[must_use]
+pub fn normalize_input(input: &str) -> String {
+    input.trim_ascii_end().replace("\r\n", "\n")
 }
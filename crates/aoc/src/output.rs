@@ -0,0 +1,387 @@
+//! Output sinks for puzzle results.
+//!
+//! Running puzzles and formatting their results are decoupled using the [`OutputSink`] trait, so
+//! results can be reported without writing to stdout. Library users can either use one of the
+//! provided sinks or implement their own.
+
+mod encoding;
+pub use encoding::{AnswerEncoding, EncodedSink};
+
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::io::{self, Write};
+use std::time::Duration;
+use utils::date::{Day, Year};
+use utils::env_info::EnvInfo;
+
+/// Result of running a single puzzle solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleResult<'a> {
+    pub year: Year,
+    pub day: Day,
+    pub part1: &'a str,
+    pub part2: &'a str,
+    /// Time taken to solve the puzzle, or [`None`] if the answers were cached.
+    pub time: Option<Duration>,
+}
+
+/// Destination for puzzle results.
+///
+/// The runner calls [`start`](Self::start) once, then [`result`](Self::result) or
+/// [`error`](Self::error) for each puzzle, then [`finish`](Self::finish) with the total time
+/// spent solving puzzles.
+pub trait OutputSink {
+    /// Called before any puzzles are run.
+    fn start(&mut self) {}
+
+    /// Called after a puzzle is solved successfully.
+    fn result(&mut self, result: &PuzzleResult);
+
+    /// Called when a puzzle fails to run, for example because the input is invalid.
+    fn error(&mut self, year: Year, day: Day, error: &str);
+
+    /// Called after all puzzles have been run.
+    fn finish(&mut self, _total: Duration) {}
+}
+
+/// Supported output formats, which can be converted into a sink using [`Format::sink`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Quiet,
+}
+
+impl Format {
+    pub const ALL: &'static [(&'static str, Format)] = &[
+        ("table", Format::Table),
+        ("json", Format::Json),
+        ("csv", Format::Csv),
+        ("quiet", Format::Quiet),
+    ];
+
+    /// Create a sink for this format which writes to stdout.
+    #[must_use]
+    pub fn sink(self) -> Box<dyn OutputSink> {
+        match self {
+            Format::Table => Box::new(TableSink::new(io::stdout())),
+            Format::Json => Box::new(JsonSink::new(io::stdout())),
+            Format::Csv => Box::new(CsvSink::new(io::stdout())),
+            Format::Quiet => Box::new(QuietSink),
+        }
+    }
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|&&(name, _)| name == s)
+            .map(|&(_, format)| format)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|&(name, _)| name).collect();
+                format!("unknown format, supported formats: {}", names.join(", "))
+            })
+    }
+}
+
+/// Human-readable table, as printed by the CLI by default.
+#[derive(Debug)]
+pub struct TableSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TableSink<W> {
+    const SEPARATOR: &'static str =
+        "────────┼──────────────────────┼────────────────────────────────────────┼───────────";
+
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> OutputSink for TableSink<W> {
+    fn start(&mut self) {
+        // FIXME support 80 character wide output (without time?)
+        let _ = writeln!(
+            self.writer,
+            "Puzzle  │ Part 1               │ Part 2                                 │ Time      "
+        );
+        let _ = writeln!(self.writer, "{}", Self::SEPARATOR);
+    }
+
+    fn result(&mut self, result: &PuzzleResult) {
+        let PuzzleResult {
+            year,
+            day,
+            part1,
+            part2,
+            time,
+        } = *result;
+        let time = time.map_or_else(|| format!("{:>10}", "cached"), format_duration);
+
+        // Hack to treat "🎄" as two characters wide
+        // ("🎄" is 1 wide in Unicode 8 but 2 wide in Unicode 9+)
+        let part1_width = if part1 == "🎄" { 19 } else { 20 };
+        let part2_width = if part2 == "🎄" { 37 } else { 38 };
+
+        let _ = writeln!(
+            self.writer,
+            "{year:#} {day:#} │ {part1:<part1_width$} │ {part2:<part2_width$} │ {time}"
+        );
+    }
+
+    fn error(&mut self, year: Year, day: Day, error: &str) {
+        let _ = writeln!(self.writer, "{year:#} {day:#}: {error}");
+    }
+
+    fn finish(&mut self, total: Duration) {
+        let _ = writeln!(self.writer, "{}", Self::SEPARATOR);
+        let _ = writeln!(
+            self.writer,
+            "                                                                        │ {}",
+            format_duration(total),
+        );
+    }
+}
+
+/// Newline-delimited JSON, writing one object per puzzle.
+///
+/// Successful results have `year`, `day`, `part1`, `part2`, `time_ns` and `cached` fields, with
+/// `time_ns` set to `null` for cached results. Failures have `year`, `day` and `error` fields.
+///
+/// The final line contains `total_time_ns` and an `environment` object describing the machine and
+/// build, as returned by [`environment_json`].
+#[derive(Debug)]
+pub struct JsonSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> OutputSink for JsonSink<W> {
+    fn result(&mut self, result: &PuzzleResult) {
+        let time = result
+            .time
+            .map_or_else(|| "null".to_string(), |t| t.as_nanos().to_string());
+        let _ = writeln!(
+            self.writer,
+            r#"{{"year":{},"day":{},"part1":{},"part2":{},"time_ns":{time},"cached":{}}}"#,
+            result.year.to_u16(),
+            result.day.to_u8(),
+            JsonString(result.part1),
+            JsonString(result.part2),
+            result.time.is_none(),
+        );
+    }
+
+    fn error(&mut self, year: Year, day: Day, error: &str) {
+        let _ = writeln!(
+            self.writer,
+            r#"{{"year":{},"day":{},"error":{}}}"#,
+            year.to_u16(),
+            day.to_u8(),
+            JsonString(error),
+        );
+    }
+
+    fn finish(&mut self, total: Duration) {
+        let _ = writeln!(
+            self.writer,
+            r#"{{"total_time_ns":{},"environment":{}}}"#,
+            total.as_nanos(),
+            environment_json(&EnvInfo::capture()),
+        );
+    }
+}
+
+/// Format the environment as a JSON object.
+///
+/// Contains `cpu_model`, `cores`, `threads`, `os`, `arch`, `rustc_version`, `git_hash` and
+/// `multiversion` fields, with `multiversion` mapping each dynamic dispatch site to the selected
+/// version. Unknown values are `null`.
+#[must_use]
+pub fn environment_json(info: &EnvInfo) -> String {
+    let optional =
+        |s: Option<&str>| s.map_or_else(|| "null".to_string(), |s| JsonString(s).to_string());
+    let multiversion = info
+        .multiversion
+        .iter()
+        .map(|(module, version)| {
+            format!(
+                "{}:{}",
+                JsonString(module),
+                JsonString(&format!("{version:?}"))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        r#"{{"cpu_model":{},"cores":{},"threads":{},"os":{},"arch":{},"rustc_version":{},"git_hash":{},"multiversion":{{{multiversion}}}}}"#,
+        optional(info.cpu_model.as_deref()),
+        info.cores,
+        info.threads,
+        JsonString(info.os),
+        JsonString(info.arch),
+        optional(info.rustc_version),
+        optional(info.git_hash),
+    )
+}
+
+/// Format the environment as CSV comment lines, each starting with `# ` and containing a key and
+/// value separated by a comma.
+#[must_use]
+pub fn environment_csv(info: &EnvInfo) -> String {
+    let mut rows = vec![
+        ("cpu_model", info.cpu_model.clone().unwrap_or_default()),
+        ("cores", info.cores.to_string()),
+        ("threads", info.threads.to_string()),
+        ("os", info.os.to_string()),
+        ("arch", info.arch.to_string()),
+        (
+            "rustc_version",
+            info.rustc_version.unwrap_or_default().to_string(),
+        ),
+        ("git_hash", info.git_hash.unwrap_or_default().to_string()),
+    ];
+    for (module, version) in &info.multiversion {
+        rows.push(("multiversion", format!("{module}={version:?}")));
+    }
+
+    rows.iter().fold(String::new(), |mut s, (key, value)| {
+        let _ = writeln!(s, "# {key},{}", CsvField(value));
+        s
+    })
+}
+
+/// JSON string literal, escaped while formatting so long answers are written in chunks directly
+/// to the output instead of being copied.
+pub struct JsonString<'a>(pub &'a str);
+
+impl Display for JsonString<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        let mut start = 0;
+        for (i, c) in self.0.char_indices() {
+            if c >= ' ' && c != '"' && c != '\\' {
+                continue;
+            }
+
+            f.write_str(&self.0[start..i])?;
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c => write!(f, "\\u{:04x}", u32::from(c))?,
+            }
+            // All escaped characters are ASCII
+            start = i + 1;
+        }
+        f.write_str(&self.0[start..])?;
+        f.write_char('"')
+    }
+}
+
+/// Comma-separated values with a header row.
+///
+/// Columns are `year`, `day`, `part1`, `part2`, `time_ns`, `cached` and `error`, with unused
+/// columns left empty. The rows are followed by comment lines describing the machine and build, as
+/// returned by [`environment_csv`].
+#[derive(Debug)]
+pub struct CsvSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CsvSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> OutputSink for CsvSink<W> {
+    fn start(&mut self) {
+        let _ = writeln!(self.writer, "year,day,part1,part2,time_ns,cached,error");
+    }
+
+    fn result(&mut self, result: &PuzzleResult) {
+        let _ = writeln!(
+            self.writer,
+            "{},{},{},{},{},{},",
+            result.year.to_u16(),
+            result.day.to_u8(),
+            CsvField(result.part1),
+            CsvField(result.part2),
+            result
+                .time
+                .map_or(String::new(), |t| t.as_nanos().to_string()),
+            result.time.is_none(),
+        );
+    }
+
+    fn error(&mut self, year: Year, day: Day, error: &str) {
+        let _ = writeln!(
+            self.writer,
+            "{},{},,,,,{}",
+            year.to_u16(),
+            day.to_u8(),
+            CsvField(error),
+        );
+    }
+
+    fn finish(&mut self, _total: Duration) {
+        let _ = write!(self.writer, "{}", environment_csv(&EnvInfo::capture()));
+    }
+}
+
+/// CSV field, quoted and escaped while formatting if it contains special characters.
+pub struct CsvField<'a>(pub &'a str);
+
+impl Display for CsvField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.0.contains([',', '"', '\n', '\r']) {
+            return f.write_str(self.0);
+        }
+
+        f.write_char('"')?;
+        let mut parts = self.0.split('"');
+        f.write_str(parts.next().unwrap_or_default())?;
+        for part in parts {
+            f.write_str("\"\"")?;
+            f.write_str(part)?;
+        }
+        f.write_char('"')
+    }
+}
+
+/// Sink which discards all output, used by `--format quiet`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct QuietSink;
+
+impl OutputSink for QuietSink {
+    fn result(&mut self, _: &PuzzleResult) {}
+
+    fn error(&mut self, _: Year, _: Day, _: &str) {}
+}
+
+/// Format a duration as the CLI does, using microseconds or milliseconds.
+#[must_use]
+pub fn format_duration(d: Duration) -> String {
+    let (unit, multiplier) = if d.as_micros() < 1000 {
+        ("µ", 1_000_000.)
+    } else {
+        ("m", 1_000.)
+    };
+
+    let float = d.as_secs_f64() * multiplier;
+    let precision = if float < 1000. { 3 } else { 0 };
+    format!("{float:7.precision$} {unit}s")
+}
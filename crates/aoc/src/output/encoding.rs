@@ -0,0 +1,147 @@
+use super::{OutputSink, PuzzleResult};
+use std::fmt::Write as _;
+use std::time::Duration;
+use utils::date::{Day, Year};
+use utils::sha256;
+
+/// Encodings for printed answers, which can hide answers in screenshots and shared output while
+/// still allowing them to be compared.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AnswerEncoding {
+    #[default]
+    Plain,
+    Hex,
+    Base64,
+    Sha256,
+}
+
+impl AnswerEncoding {
+    pub const ALL: &'static [(&'static str, AnswerEncoding)] = &[
+        ("plain", AnswerEncoding::Plain),
+        ("hex", AnswerEncoding::Hex),
+        ("base64", AnswerEncoding::Base64),
+        ("sha256", AnswerEncoding::Sha256),
+    ];
+
+    /// Encode the provided answer.
+    ///
+    /// Empty answers, used for parts which weren't run, are left empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use aoc::output::AnswerEncoding;
+    /// assert_eq!(AnswerEncoding::Plain.encode("1234"), "1234");
+    /// assert_eq!(AnswerEncoding::Hex.encode("1234"), "31323334");
+    /// assert_eq!(AnswerEncoding::Base64.encode("1234"), "MTIzNA==");
+    /// assert_eq!(
+    ///     AnswerEncoding::Sha256.encode("1234"),
+    ///     "03ac674216f3e15c761ee1a5e255f067953623c8b388b4459e13f978d7c846f4",
+    /// );
+    /// assert_eq!(AnswerEncoding::Sha256.encode(""), "");
+    /// ```
+    #[must_use]
+    pub fn encode(self, answer: &str) -> String {
+        if answer.is_empty() {
+            return String::new();
+        }
+        match self {
+            AnswerEncoding::Plain => answer.to_string(),
+            AnswerEncoding::Hex => hex(answer.as_bytes()),
+            AnswerEncoding::Base64 => base64(answer.as_bytes()),
+            AnswerEncoding::Sha256 => {
+                String::from_utf8(sha256::to_hex(sha256::hash(answer.as_bytes())).to_vec())
+                    .expect("hex should be valid UTF-8")
+            }
+        }
+    }
+
+    /// Wrap the provided sink so answers are encoded before being output.
+    ///
+    /// Returns the sink unchanged for [`AnswerEncoding::Plain`].
+    #[must_use]
+    pub fn wrap(self, sink: Box<dyn OutputSink>) -> Box<dyn OutputSink> {
+        if self == AnswerEncoding::Plain {
+            sink
+        } else {
+            Box::new(EncodedSink {
+                inner: sink,
+                encoding: self,
+            })
+        }
+    }
+}
+
+impl std::str::FromStr for AnswerEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|&&(name, _)| name == s)
+            .map(|&(_, encoding)| encoding)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|&(name, _)| name).collect();
+                format!(
+                    "unknown encoding, supported encodings: {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Sink which encodes answers before passing them to another sink.
+///
+/// Created using [`AnswerEncoding::wrap`]. Errors are passed through unchanged.
+pub struct EncodedSink {
+    inner: Box<dyn OutputSink>,
+    encoding: AnswerEncoding,
+}
+
+impl OutputSink for EncodedSink {
+    fn start(&mut self) {
+        self.inner.start();
+    }
+
+    fn result(&mut self, result: &PuzzleResult) {
+        self.inner.result(&PuzzleResult {
+            part1: &self.encoding.encode(result.part1),
+            part2: &self.encoding.encode(result.part2),
+            ..*result
+        });
+    }
+
+    fn error(&mut self, year: Year, day: Day, error: &str) {
+        self.inner.error(year, day, error);
+    }
+
+    fn finish(&mut self, total: Duration) {
+        self.inner.finish(total);
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,81 @@
+//! Progress bar for long-running solutions, drawn on stderr while the solution runs.
+
+use crate::cli::Options;
+use std::io::{self, IsTerminal};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use utils::date::{Day, Year};
+use utils::progress::Progress;
+
+/// Delay before drawing the progress bar, to avoid flickering for fast solutions.
+const DELAY: Duration = Duration::from_millis(500);
+/// Interval between redrawing the progress bar.
+const INTERVAL: Duration = Duration::from_millis(100);
+const WIDTH: u64 = 30;
+
+/// Run the provided function, drawing a progress bar on stderr if it reports progress.
+///
+/// The progress bar is only drawn when stderr is a terminal, and is cleared before returning so it
+/// doesn't interleave with other output.
+pub fn run<T>(args: &Options, year: Year, day: Day, f: impl FnOnce() -> T) -> T {
+    Progress::start();
+    if args.no_progress || !io::stderr().is_terminal() {
+        return f();
+    }
+
+    let (sender, receiver) = mpsc::channel::<()>();
+    thread::scope(|scope| {
+        scope.spawn(move || draw(year, day, &receiver));
+        let result = f();
+        drop(sender);
+        result
+    })
+}
+
+fn draw(year: Year, day: Day, finished: &Receiver<()>) {
+    let start = Instant::now();
+    if finished.recv_timeout(DELAY) != Err(RecvTimeoutError::Timeout) {
+        return;
+    }
+
+    let mut drawn = false;
+    loop {
+        let progress = Progress::current();
+        if progress.completed > 0 {
+            let elapsed = start.elapsed().as_secs_f64();
+            if let Some(total) = progress.total {
+                let completed = progress.completed.min(total);
+                let filled = completed * WIDTH / total;
+                eprint!(
+                    "\r{year:#} {day:#} [{}{}] {:>3}% {elapsed:.1}s ETA {:.1}s\x1b[K",
+                    "#".repeat(usize::try_from(filled).unwrap()),
+                    "-".repeat(usize::try_from(WIDTH - filled).unwrap()),
+                    completed * 100 / total,
+                    eta(elapsed, completed, total),
+                );
+            } else {
+                eprint!(
+                    "\r{year:#} {day:#} {} completed {elapsed:.1}s\x1b[K",
+                    progress.completed,
+                );
+            }
+            drawn = true;
+        }
+
+        if finished.recv_timeout(INTERVAL) != Err(RecvTimeoutError::Timeout) {
+            break;
+        }
+    }
+
+    if drawn {
+        eprint!("\r\x1b[K");
+    }
+}
+
+/// Estimate the remaining seconds, assuming the remaining work progresses at the average rate so
+/// far.
+#[expect(clippy::cast_precision_loss)]
+fn eta(elapsed: f64, completed: u64, total: u64) -> f64 {
+    elapsed * (total - completed) as f64 / completed as f64
+}
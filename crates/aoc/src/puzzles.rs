@@ -1,17 +1,74 @@
-use crate::all_puzzles;
+use crate::{all_puzzles, Answer};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 use utils::date::{Day, Year};
+use utils::input::InputError;
+use utils::SolutionError;
 
 // These imports are unused if none of the year features are enabled
 #[allow(clippy::allow_attributes, unused_imports)]
+use std::{hint::black_box, time::Instant};
+#[allow(clippy::allow_attributes, unused_imports)]
 use utils::{
-    input::{InputError, InputType},
-    Puzzle,
+    catch_panic, input::InputType, GeneratorProbe, NoPuzzleGenerator as _, Puzzle, PuzzleExamples,
 };
 
 /// Represents a wrapper function around a puzzle solution.
 ///
 /// See [`PUZZLES`].
-pub type PuzzleFn = fn(&str) -> Result<(String, String), InputError>;
+pub type PuzzleFn = fn(&str) -> Result<(Answer, Answer), PuzzleError>;
+
+/// Represents a wrapper function around a puzzle solution for example inputs.
+///
+/// Takes an input string and whether to solve each part, returning the requested solutions as
+/// [`Answer`]s, or a [`PuzzleError`]. See [`puzzle_examples`].
+pub type ExampleFn = fn(&str, bool, bool) -> Result<(Option<Answer>, Option<Answer>), PuzzleError>;
+
+/// Represents a wrapper function which times each stage of a puzzle solution.
+///
+/// Takes an input string and returns the time taken to parse the input, solve part 1 and solve
+/// part 2, or a [`PuzzleError`]. See [`puzzle_bench`].
+pub type BenchFn = fn(&str) -> Result<[Duration; 3], PuzzleError>;
+
+/// Error returned by a [`PuzzleFn`], [`ExampleFn`] or [`BenchFn`].
+#[derive(Debug)]
+pub enum PuzzleError {
+    /// The solution rejected the input.
+    Input(InputError),
+    /// The solution panicked while solving the input, for example because no solution was found.
+    Solution(SolutionError),
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Input(err) => Display::fmt(err, f),
+            PuzzleError::Solution(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PuzzleError::Input(err) => Some(err),
+            PuzzleError::Solution(err) => Some(err),
+        }
+    }
+}
+
+impl From<InputError> for PuzzleError {
+    fn from(err: InputError) -> Self {
+        PuzzleError::Input(err)
+    }
+}
+
+impl From<SolutionError> for PuzzleError {
+    fn from(err: SolutionError) -> Self {
+        PuzzleError::Solution(err)
+    }
+}
 
 macro_rules! matcher {
     ($(
@@ -22,17 +79,341 @@ macro_rules! matcher {
         /// Constant containing each puzzle solution.
         ///
         /// Each puzzle is represented by a tuple of [`Year`], [`Day`] and [`PuzzleFn`], which takes
-        /// a input string and returns the part 1 and 2 solutions as strings, or an [`InputError`].
+        /// a input string and returns the part 1 and 2 solutions as [`Answer`]s, or a [`PuzzleError`].
+        /// Panics are caught using [`catch_panic`] where supported.
         ///
         /// Generated from [`all_puzzles!`].
         pub const PUZZLES: &[(Year, Day, PuzzleFn)] = &[$($(
             (crate::$year::$day::YEAR, crate::$year::$day::DAY, |input: &str| {
-                let solution = crate::$year::$day::new(input, InputType::Real)?;
-                let part1 = solution.part1();
-                let part2 = solution.part2();
-                Ok((part1.to_string(), part2.to_string()))
+                catch_panic(|| {
+                    let solution = crate::$year::$day::new(input, InputType::Real)?;
+                    let part1 = solution.part1();
+                    let part2 = solution.part2();
+                    Ok((Answer::from(part1), Answer::from(part2)))
+                })?
             }),
         )*)*];
     };
 }
 all_puzzles!(matcher);
+
+macro_rules! generator_matcher {
+    ($(
+        $y:literal => $year:ident{$(
+            $d:literal => $day:ident,
+        )*}
+    )*) => {
+        /// Returns the input generator for the provided puzzle, if it implements
+        /// [`PuzzleGenerator`](utils::PuzzleGenerator).
+        ///
+        /// Generated from [`all_puzzles!`].
+        #[must_use]
+        pub fn input_generator(year: Year, day: Day) -> Option<fn(u64) -> String> {
+            $($(
+                if (year, day) == (crate::$year::$day::YEAR, crate::$year::$day::DAY) {
+                    return GeneratorProbe::<crate::$year::$day>::new().generator();
+                }
+            )*)*
+            None
+        }
+    };
+}
+all_puzzles!(generator_matcher);
+
+macro_rules! examples_matcher {
+    ($(
+        $y:literal => $year:ident{$(
+            $d:literal => $day:ident,
+        )*}
+    )*) => {
+        /// Returns the example inputs for the provided puzzle, along with an [`ExampleFn`] which
+        /// solves inputs using [`InputType::Example`].
+        ///
+        /// Each input is returned with flags indicating whether the example has part 1 and part 2
+        /// answers, as solutions may not terminate when solving the other part of an example.
+        ///
+        /// Generated from [`all_puzzles!`].
+        #[must_use]
+        pub fn puzzle_examples(
+            year: Year,
+            day: Day,
+        ) -> Option<(Vec<(&'static str, bool, bool)>, ExampleFn)> {
+            $($(
+                if (year, day) == (crate::$year::$day::YEAR, crate::$year::$day::DAY) {
+                    let inputs = <crate::$year::$day as PuzzleExamples<_, _>>::EXAMPLES
+                        .iter()
+                        .map(|(input, part1, part2)| (*input, part1.is_some(), part2.is_some()))
+                        .collect();
+                    return Some((inputs, |input: &str, run_part1: bool, run_part2: bool| {
+                        catch_panic(|| {
+                            let solution = crate::$year::$day::new(input, InputType::Example)?;
+                            let part1 = run_part1.then(|| Answer::from(solution.part1()));
+                            let part2 = run_part2.then(|| Answer::from(solution.part2()));
+                            Ok((part1, part2))
+                        })?
+                    }));
+                }
+            )*)*
+            None
+        }
+    };
+}
+all_puzzles!(examples_matcher);
+
+macro_rules! bench_matcher {
+    ($(
+        $y:literal => $year:ident{$(
+            $d:literal => $day:ident,
+        )*}
+    )*) => {
+        /// Returns a [`BenchFn`] for the provided puzzle, which times parsing the input and
+        /// solving each part separately.
+        ///
+        /// Generated from [`all_puzzles!`].
+        #[must_use]
+        pub fn puzzle_bench(year: Year, day: Day) -> Option<BenchFn> {
+            $($(
+                if (year, day) == (crate::$year::$day::YEAR, crate::$year::$day::DAY) {
+                    return Some(|input: &str| {
+                        catch_panic(|| {
+                            let start = Instant::now();
+                            let solution = crate::$year::$day::new(black_box(input), InputType::Real)?;
+                            let parsed = Instant::now();
+                            black_box(solution.part1());
+                            let part1 = Instant::now();
+                            black_box(solution.part2());
+                            let part2 = Instant::now();
+                            Ok([parsed - start, part1 - parsed, part2 - part1])
+                        })?
+                    });
+                }
+            )*)*
+            None
+        }
+    };
+}
+all_puzzles!(bench_matcher);
+
+/// Metadata for a puzzle solution, returned by [`puzzles`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleInfo {
+    pub year: Year,
+    pub day: Day,
+    /// Short description of the puzzle, taken from the first line of the solution's doc comment.
+    pub title: &'static str,
+    /// Hash of the solution's source file, which changes whenever the solution is edited.
+    pub source_hash: u64,
+}
+
+/// Which parts of a puzzle to solve with [`run`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Parts {
+    Part1,
+    Part2,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    #[must_use]
+    pub fn part1(self) -> bool {
+        matches!(self, Parts::Part1 | Parts::Both)
+    }
+
+    #[must_use]
+    pub fn part2(self) -> bool {
+        matches!(self, Parts::Part2 | Parts::Both)
+    }
+}
+
+/// Answers returned by [`run`], with [`None`] for parts which weren't requested.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOutput {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// Time taken by each stage of a puzzle solution, returned by [`run_timed`].
+///
+/// Stages which weren't run are zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Error returned by [`run`].
+#[derive(Debug)]
+pub enum RunError {
+    /// No solution exists for the provided date, or it was disabled using crate features.
+    UnknownPuzzle { year: u16, day: u8 },
+    /// The solution rejected the input or panicked.
+    Puzzle(PuzzleError),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::UnknownPuzzle { year, day } => write!(f, "unsupported puzzle {year} {day}"),
+            RunError::Puzzle(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunError::UnknownPuzzle { .. } => None,
+            RunError::Puzzle(err) => Some(err),
+        }
+    }
+}
+
+impl From<PuzzleError> for RunError {
+    fn from(err: PuzzleError) -> Self {
+        RunError::Puzzle(err)
+    }
+}
+
+/// Solve the requested parts of the puzzle for the provided date.
+///
+/// This is the simplest way to run solutions from other programs, only requiring the date as
+/// integers. Panics are caught using [`catch_panic`] where supported.
+///
+/// # Examples
+/// ```
+/// # use aoc::{run, Answer, Parts, RunError};
+/// let output = run(2015, 1, "(()))", Parts::Both).unwrap();
+/// assert_eq!(output.part1, Some(Answer::I64(-1)));
+/// assert_eq!(output.part2.unwrap().to_string(), "5");
+///
+/// let output = run(2015, 1, "(()))", Parts::Part2).unwrap();
+/// assert_eq!(output.part1, None);
+///
+/// assert!(matches!(run(2015, 1, "(x", Parts::Both), Err(RunError::Puzzle(_))));
+/// assert!(matches!(run(2000, 1, "", Parts::Both), Err(RunError::UnknownPuzzle { .. })));
+/// ```
+pub fn run(year: u16, day: u8, input: &str, parts: Parts) -> Result<RunOutput, RunError> {
+    run_input(year, day, input, parts, InputType::Real, || Duration::ZERO).map(|(output, _)| output)
+}
+
+/// Solve the requested parts of the puzzle like [`run`], also timing each stage of the solution.
+///
+/// The clock returns the time elapsed since an arbitrary fixed point. Taking the clock as a
+/// parameter allows timing solutions on targets without [`Instant`](std::time::Instant) support,
+/// such as `wasm32-unknown-unknown`.
+///
+/// # Examples
+/// ```
+/// # use aoc::{run_timed, Answer, Parts};
+/// # use std::time::{Duration, Instant};
+/// # use std::sync::LazyLock;
+/// static START: LazyLock<Instant> = LazyLock::new(Instant::now);
+/// let (output, timings) = run_timed(2015, 1, "(()))", Parts::Part1, || START.elapsed()).unwrap();
+/// assert_eq!(output.part1, Some(Answer::I64(-1)));
+/// assert_eq!(timings.part2, Duration::ZERO);
+/// ```
+pub fn run_timed(
+    year: u16,
+    day: u8,
+    input: &str,
+    parts: Parts,
+    clock: fn() -> Duration,
+) -> Result<(RunOutput, Timings), RunError> {
+    run_input(year, day, input, parts, InputType::Real, clock)
+}
+
+/// Solve the requested parts of the puzzle for the provided date using an example input.
+///
+/// Example inputs may use different parameters to real inputs, such as smaller grid sizes, and
+/// should only be solved for parts which have example answers.
+pub fn run_example(year: u16, day: u8, input: &str, parts: Parts) -> Result<RunOutput, RunError> {
+    run_input(year, day, input, parts, InputType::Example, || {
+        Duration::ZERO
+    })
+    .map(|(output, _)| output)
+}
+
+/// Solve the requested parts of the puzzle using an example input like [`run_example`], also
+/// timing each stage of the solution.
+///
+/// See [`run_timed`].
+pub fn run_example_timed(
+    year: u16,
+    day: u8,
+    input: &str,
+    parts: Parts,
+    clock: fn() -> Duration,
+) -> Result<(RunOutput, Timings), RunError> {
+    run_input(year, day, input, parts, InputType::Example, clock)
+}
+
+macro_rules! run_matcher {
+    ($(
+        $y:literal => $year:ident{$(
+            $d:literal => $day:ident,
+        )*}
+    )*) => {
+        /// Returns metadata for each puzzle solution, in date order.
+        ///
+        /// Generated from [`all_puzzles!`].
+        ///
+        /// # Examples
+        /// ```
+        /// let info = aoc::puzzles().next().unwrap();
+        /// assert_eq!((info.year.to_u16(), info.day.to_u8()), (2015, 1));
+        /// assert!(!info.title.is_empty());
+        /// ```
+        pub fn puzzles() -> impl Iterator<Item = PuzzleInfo> {
+            const INFO: &[PuzzleInfo] = &[$($(
+                PuzzleInfo {
+                    year: crate::$year::$day::YEAR,
+                    day: crate::$year::$day::DAY,
+                    title: crate::$year::$day::DESCRIPTION,
+                    source_hash: crate::$year::$day::SOURCE_HASH,
+                },
+            )*)*];
+            INFO.iter().copied()
+        }
+
+        fn run_input(
+            year: u16,
+            day: u8,
+            input: &str,
+            parts: Parts,
+            input_type: InputType,
+            clock: fn() -> Duration,
+        ) -> Result<(RunOutput, Timings), RunError> {
+            match (year, day) {
+                $($(
+                    ($y, $d) => {
+                        let solve = || -> Result<(RunOutput, Timings), PuzzleError> {
+                            let mut timings = Timings::default();
+                            let start = clock();
+                            let solution = crate::$year::$day::new(input, input_type)?;
+                            let parsed = clock();
+                            timings.parse = parsed - start;
+
+                            let part1 = parts.part1().then(|| Answer::from(solution.part1()));
+                            let part1_end = clock();
+                            if part1.is_some() {
+                                timings.part1 = part1_end - parsed;
+                            }
+
+                            let part2 = parts.part2().then(|| Answer::from(solution.part2()));
+                            if part2.is_some() {
+                                timings.part2 = clock() - part1_end;
+                            }
+
+                            Ok((RunOutput { part1, part2 }, timings))
+                        };
+                        Ok(catch_panic(solve).map_err(PuzzleError::from)??)
+                    }
+                )*)*
+                _ => Err(RunError::UnknownPuzzle { year, day }),
+            }
+        }
+    };
+}
+all_puzzles!(run_matcher);
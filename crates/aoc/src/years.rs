@@ -23,6 +23,22 @@ pub mod year2016 {
 pub mod year2017 {
     pub use ::utils::puzzles_noop as puzzles;
 }
+#[cfg(not(feature = "year2020"))]
+pub mod year2020 {
+    pub use ::utils::puzzles_noop as puzzles;
+}
+#[cfg(not(feature = "year2021"))]
+pub mod year2021 {
+    pub use ::utils::puzzles_noop as puzzles;
+}
+#[cfg(not(feature = "year2022"))]
+pub mod year2022 {
+    pub use ::utils::puzzles_noop as puzzles;
+}
+#[cfg(not(feature = "year2023"))]
+pub mod year2023 {
+    pub use ::utils::puzzles_noop as puzzles;
+}
 #[cfg(not(feature = "year2024"))]
 pub mod year2024 {
     pub use ::utils::puzzles_noop as puzzles;
@@ -33,6 +49,14 @@ pub use ::year2015;
 pub use ::year2016;
 #[cfg(feature = "year2017")]
 pub use ::year2017;
+#[cfg(feature = "year2020")]
+pub use ::year2020;
+#[cfg(feature = "year2021")]
+pub use ::year2021;
+#[cfg(feature = "year2022")]
+pub use ::year2022;
+#[cfg(feature = "year2023")]
+pub use ::year2023;
 #[cfg(feature = "year2024")]
 pub use ::year2024;
 
@@ -104,6 +128,10 @@ macro_rules! all_puzzles {
                 $crate::year2015::puzzles,
                 $crate::year2016::puzzles,
                 $crate::year2017::puzzles,
+                $crate::year2020::puzzles,
+                $crate::year2021::puzzles,
+                $crate::year2022::puzzles,
+                $crate::year2023::puzzles,
                 $crate::year2024::puzzles,
 
                 $callback
@@ -0,0 +1,42 @@
+//! Exports images of ASCII-art answers as RGBA pixel buffers, which can be drawn to a canvas.
+
+use aoc::utils::answer_image;
+
+const BUFFER_LENGTH: usize = 256 * 1024;
+const ON: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+const OFF: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+
+#[unsafe(no_mangle)]
+static mut IMAGE: [u8; BUFFER_LENGTH] = [0u8; BUFFER_LENGTH];
+#[unsafe(no_mangle)]
+static mut IMAGE_WIDTH: u32 = 0;
+#[unsafe(no_mangle)]
+static mut IMAGE_HEIGHT: u32 = 0;
+
+/// Enable recording images and clear any previous image.
+pub fn start() {
+    answer_image::set_enabled(true);
+    let _ = answer_image::take();
+}
+
+/// Write the recorded image, if any, to the exported buffer.
+///
+/// The width and height are set to zero if no image was recorded or it is too large.
+pub fn finish() {
+    let image = answer_image::take().filter(|i| i.pixels.len() * 4 <= BUFFER_LENGTH);
+
+    // SAFETY: No other Rust code accesses these variables or creates references - they're only read
+    // from JS.
+    unsafe {
+        let (width, height) = image.as_ref().map_or((0, 0), |i| (i.width, i.height));
+        IMAGE_WIDTH = u32::try_from(width).unwrap();
+        IMAGE_HEIGHT = u32::try_from(height).unwrap();
+
+        if let Some(image) = image {
+            let buf: *mut [u8; 4] = (&raw mut IMAGE).cast();
+            for (i, &pixel) in image.pixels.iter().enumerate() {
+                *buf.add(i) = if pixel { ON } else { OFF };
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-//! Generates custom sections containing the list of solutions and their examples.
+//! Generates custom sections containing the list of solutions, their descriptions and examples.
 
 use aoc::all_puzzles;
 
@@ -47,7 +47,7 @@ macro_rules! matcher {
     )*) => {$(
         mod $year {$(
             mod $day {
-                use aoc::utils::PuzzleExamples;
+                use aoc::utils::{Puzzle, PuzzleExamples};
 
                 #[unsafe(link_section = "aoc_puzzles")]
                 #[used]
@@ -60,6 +60,11 @@ macro_rules! matcher {
                     b'0' + $d % 10,
                 ];
 
+                #[unsafe(link_section = concat!("aoc_description_", stringify!($y), "_", stringify!($d)))]
+                #[used]
+                static PUZZLE_DESCRIPTION: [u8; aoc::$year::$day::DESCRIPTION.len()]
+                    = aoc::utils::str_array(aoc::$year::$day::DESCRIPTION);
+
                 #[unsafe(link_section = concat!("aoc_examples_", stringify!($y), "_", stringify!($d)))]
                 #[used]
                 static PUZZLE_EXAMPLES: [u8; super::super::examples_len(aoc::$year::$day::EXAMPLES)]
@@ -0,0 +1,99 @@
+//! Validates the combination of cargo and target features at compile time, and reports the
+//! enabled features to the host.
+//!
+//! Some combinations build successfully but produce a broken or suboptimal module, so they are
+//! rejected here instead of being discovered in the browser.
+
+#[cfg(all(
+    target_family = "wasm",
+    feature = "multithreading",
+    not(target_feature = "atomics")
+))]
+compile_error!(
+    "the multithreading feature requires the atomics, bulk-memory and mutable-globals target \
+     features, otherwise worker threads can't share memory"
+);
+
+#[cfg(all(
+    target_family = "wasm",
+    target_feature = "atomics",
+    not(feature = "multithreading")
+))]
+compile_error!(
+    "the atomics target feature requires the multithreading feature, otherwise the module imports \
+     shared memory without starting any worker threads"
+);
+
+#[cfg(all(
+    target_family = "wasm",
+    feature = "multithreading",
+    not(target_feature = "simd128")
+))]
+compile_error!(
+    "the multithreading feature requires the simd128 target feature, as every browser which \
+     supports wasm threads also supports SIMD"
+);
+
+/// Features which affect the compiled module, and whether each is enabled.
+///
+/// The order matches the bits returned by [`compiled_features`].
+pub const FEATURES: [(&str, bool); 3] = [
+    ("simd128", cfg!(target_feature = "simd128")),
+    ("multithreading", cfg!(feature = "multithreading")),
+    ("answer-images", cfg!(feature = "answer-images")),
+];
+
+/// Returns a bitmask of the enabled features, with bit `i` set if the `i`th entry in [`FEATURES`]
+/// is enabled.
+#[unsafe(no_mangle)]
+extern "C" fn compiled_features() -> u32 {
+    FEATURES
+        .iter()
+        .enumerate()
+        .filter(|&(_, &(_, enabled))| enabled)
+        .fold(0, |acc, (i, _)| acc | (1 << i))
+}
+
+const fn section_len() -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < FEATURES.len() {
+        if FEATURES[i].1 {
+            if len > 0 {
+                len += 1;
+            }
+            len += FEATURES[i].0.len();
+        }
+        i += 1;
+    }
+    len
+}
+
+const fn section<const N: usize>() -> [u8; N] {
+    let mut output = [0; N];
+    let mut o = 0;
+    let mut i = 0;
+    while i < FEATURES.len() {
+        if FEATURES[i].1 {
+            if o > 0 {
+                output[o] = b',';
+                o += 1;
+            }
+            let name = FEATURES[i].0.as_bytes();
+            let mut j = 0;
+            while j < name.len() {
+                output[o] = name[j];
+                j += 1;
+                o += 1;
+            }
+        }
+        i += 1;
+    }
+    output
+}
+
+/// Comma separated list of the enabled features, allowing the build to be checked without
+/// instantiating the module.
+#[unsafe(link_section = "aoc_features")]
+#[used]
+static FEATURES_SECTION: [u8; section_len()] = section();
@@ -1,22 +1,64 @@
 //! Simple WebAssembly interface without external libraries.
+#[cfg(feature = "answer-images")]
+mod answer_image;
 mod custom_sections;
+mod features;
 #[cfg(feature = "multithreading")]
 mod multithreading;
 
-use aoc::all_puzzles;
-use aoc::utils::input::InputType;
+use aoc::utils::progress::Progress;
+use aoc::{Parts, Timings};
 use std::error::Error;
-use std::ffi::CStr;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
 
-const BUFFER_LENGTH: usize = 1024 * 1024;
+/// Input buffer allocated by [`alloc_input`], which JS writes the UTF-8 input into before calling
+/// [`run_puzzle`].
+static INPUT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
 
+/// Nanoseconds taken to parse the input, solve part 1 and solve part 2 in the last call to
+/// [`run_puzzle`], or zero for stages which weren't run.
 #[unsafe(no_mangle)]
-static INPUT: [u8; BUFFER_LENGTH] = [0u8; BUFFER_LENGTH];
+static mut TIMINGS: [u64; 3] = [0; 3];
+
+#[cfg(target_family = "wasm")]
+#[link(wasm_import_module = "aoc")]
+extern "C" {
+    /// Current time in milliseconds, supplied by JS using `performance.now()`.
+    fn now() -> f64;
+}
+
+/// Answers from the last call to [`run_puzzle`], each stored as a little-endian [`u32`] length
+/// followed by the UTF-8 bytes, returned to JS by [`answer`].
+static ANSWERS: Mutex<[Vec<u8>; 2]> = Mutex::new([Vec::new(), Vec::new()]);
+
+/// Allocate a zeroed input buffer of the provided length in bytes, replacing any previous input,
+/// and return its address.
+///
+/// Allocating may grow the memory, so JS must create views of the memory after calling this.
 #[unsafe(no_mangle)]
-static mut PART1: [u8; BUFFER_LENGTH] = [0u8; BUFFER_LENGTH];
+extern "C" fn alloc_input(len: u32) -> *mut u8 {
+    let mut input = INPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    *input = vec![0; len as usize];
+    input.as_mut_ptr()
+}
+
+/// Free the input buffer returned by [`alloc_input`].
+///
+/// Does nothing if the address doesn't match the current input buffer.
 #[unsafe(no_mangle)]
-static mut PART2: [u8; BUFFER_LENGTH] = [0u8; BUFFER_LENGTH];
+extern "C" fn free_input(ptr: *mut u8) {
+    let mut input = INPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    if input.as_mut_ptr() == ptr {
+        *input = Vec::new();
+    }
+}
 
+/// Solve the puzzle using the input written to the buffer returned by [`alloc_input`], returning
+/// whether it succeeded.
+///
+/// Answers, or the error message on failure, are read using [`answer`].
 #[unsafe(no_mangle)]
 extern "C" fn run_puzzle(
     year: u16,
@@ -25,58 +67,100 @@ extern "C" fn run_puzzle(
     run_part1: bool,
     run_part2: bool,
 ) -> bool {
-    let (success, part1, part2) = match run(year, day, is_example, run_part1, run_part2) {
-        Ok((part1, part2)) => (true, part1, part2),
-        Err(err) => (false, err.to_string(), String::new()),
+    #[cfg(feature = "answer-images")]
+    answer_image::start();
+    Progress::start();
+    let result = run(year, day, is_example, run_part1, run_part2);
+    #[cfg(feature = "answer-images")]
+    answer_image::finish();
+
+    let (success, part1, part2, timings) = match result {
+        Ok((part1, part2, timings)) => (true, part1, part2, timings),
+        Err(err) => (false, err.to_string(), String::new(), Timings::default()),
     };
 
-    // SAFETY: No other Rust code accesses these variables or creates references - they're only read
-    // from JS.
+    // SAFETY: No other Rust code accesses this variable or creates references - it's only read from
+    // JS.
     unsafe {
-        write_string((&raw mut PART1).cast(), &part1);
-        write_string((&raw mut PART2).cast(), &part2);
+        TIMINGS = [timings.parse, timings.part1, timings.part2]
+            .map(|d| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX));
     }
+    *ANSWERS.lock().unwrap_or_else(PoisonError::into_inner) =
+        [length_prefixed(&part1), length_prefixed(&part2)];
 
     success
 }
 
+/// Returns the address of the progress counters reported by the running solution.
+///
+/// The counters store the units of work completed followed by the total, or zero if unknown, and
+/// can be polled from another thread while a puzzle runs when using shared memory.
+#[unsafe(no_mangle)]
+extern "C" fn progress_counters() -> *const AtomicU64 {
+    Progress::counters().as_ptr()
+}
+
+/// Returns the address of the length-prefixed answer for the provided part from the last call to
+/// [`run_puzzle`], or the error message for part 1 if it failed.
+///
+/// The answer is stored as a little-endian [`u32`] length followed by the UTF-8 bytes, and remains
+/// valid until the next call to [`run_puzzle`]. Returns null for invalid parts.
+#[unsafe(no_mangle)]
+extern "C" fn answer(part: u32) -> *const u8 {
+    let answers = ANSWERS.lock().unwrap_or_else(PoisonError::into_inner);
+    answers
+        .get(part.wrapping_sub(1) as usize)
+        .map_or(std::ptr::null(), Vec::as_ptr)
+}
+
 fn run(
     year: u16,
     day: u8,
     is_example: bool,
     run_part1: bool,
     run_part2: bool,
-) -> Result<(String, String), Box<dyn Error>> {
-    let input = CStr::from_bytes_until_nul(&INPUT)?.to_str()?;
-    let input_type = if is_example {
-        InputType::Example
+) -> Result<(String, String, Timings), Box<dyn Error>> {
+    let input = INPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    let input = std::str::from_utf8(&input)?;
+    let parts = match (run_part1, run_part2) {
+        (true, false) => Parts::Part1,
+        (false, true) => Parts::Part2,
+        (true, true) => Parts::Both,
+        (false, false) => return Ok((String::new(), String::new(), Timings::default())),
+    };
+
+    let (output, timings) = if is_example {
+        aoc::run_example_timed(year, day, input, parts, clock)?
     } else {
-        InputType::Real
+        aoc::run_timed(year, day, input, parts, clock)?
     };
+    Ok((
+        output.part1.map(|a| a.to_string()).unwrap_or_default(),
+        output.part2.map(|a| a.to_string()).unwrap_or_default(),
+        timings,
+    ))
+}
 
-    macro_rules! matcher {
-        ($(
-            $y:literal => $year:ident{$(
-                $d:literal => $day:ident,
-            )*}
-        )*) => {
-            match (year, day) {$($(
-                ($y, $d) => {
-                    let solution = aoc::$year::$day::new(input, input_type)?;
-                    let part1 = if run_part1 { solution.part1().to_string() } else { String::new() };
-                    let part2 = if run_part2 { solution.part2().to_string() } else { String::new() };
-                    Ok((part1, part2))
-                }
-            )*)*
-                _ => Err("unsupported puzzle".into()),
-            }
-        };
-    }
-    all_puzzles! {matcher}
+/// Returns the current time using the JS clock.
+#[cfg(target_family = "wasm")]
+fn clock() -> Duration {
+    // SAFETY: The imported function takes no arguments and only returns the time
+    Duration::from_secs_f64(unsafe { now() } / 1000.)
+}
+
+/// Returns the time since the first call, for running tests and checks on other targets.
+#[cfg(not(target_family = "wasm"))]
+fn clock() -> Duration {
+    static START: std::sync::LazyLock<std::time::Instant> =
+        std::sync::LazyLock::new(std::time::Instant::now);
+    START.elapsed()
 }
 
-unsafe fn write_string(buf: *mut u8, str: &str) {
-    let len = str.len().min(BUFFER_LENGTH - 1);
-    std::ptr::copy_nonoverlapping(str.as_ptr(), buf, len);
-    *buf.add(len) = 0;
+/// Encode the answer as a little-endian [`u32`] length followed by the bytes.
+fn length_prefixed(answer: &str) -> Vec<u8> {
+    let len = u32::try_from(answer.len()).unwrap_or(u32::MAX);
+    let mut buf = Vec::with_capacity(answer.len() + 4);
+    buf.extend_from_slice(&len.to_le_bytes());
+    buf.extend_from_slice(&answer.as_bytes()[..len as usize]);
+    buf
 }
@@ -1,4 +1,4 @@
-use aoc::utils::wasm::scoped_tasks::worker;
+use aoc::utils::wasm::scoped_tasks::{worker, worker_status};
 use std::alloc::{alloc_zeroed, Layout};
 
 /// Allocate stack for worker threads.
@@ -15,3 +15,21 @@ extern "C" fn allocate_stack(size: usize, align: usize) -> *mut u8 {
 extern "C" fn worker_thread() {
     worker();
 }
+
+/// Number of workers registered with the scoped task pool.
+#[unsafe(no_mangle)]
+extern "C" fn worker_count() -> usize {
+    worker_status().workers
+}
+
+/// Total number of scoped tasks run on workers.
+#[unsafe(no_mangle)]
+extern "C" fn worker_tasks_executed() -> usize {
+    worker_status().tasks_executed
+}
+
+/// Total number of scoped tasks run on the spawning thread as no workers were available.
+#[unsafe(no_mangle)]
+extern "C" fn worker_inline_fallbacks() -> usize {
+    worker_status().inline_fallbacks
+}
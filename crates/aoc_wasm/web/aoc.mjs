@@ -2,9 +2,15 @@
  * @typedef {Object} ModuleExports
  * @property {WebAssembly.Memory} memory
  * @property {(year: number, day: number, is_example: boolean, part1: boolean, part2: boolean) => number} run_puzzle
- * @property {WebAssembly.Global} INPUT
- * @property {WebAssembly.Global} PART1
- * @property {WebAssembly.Global} PART2
+ * @property {(length: number) => number} alloc_input
+ * @property {(ptr: number) => void} free_input
+ * @property {(part: number) => number} answer
+ * @property {() => number} progress_counters
+ * @property {() => number} compiled_features
+ * @property {WebAssembly.Global} TIMINGS
+ * @property {WebAssembly.Global} [IMAGE]                               If built with answer-images
+ * @property {WebAssembly.Global} [IMAGE_WIDTH]                         If built with answer-images
+ * @property {WebAssembly.Global} [IMAGE_HEIGHT]                        If built with answer-images
  * @property {number} PUZZLES
  * @property {WebAssembly.Global} [__tls_size]                          If multithreaded
  * @property {WebAssembly.Global} [__tls_align]                         If multithreaded
@@ -12,6 +18,17 @@
  * @property {WebAssembly.Global} [__stack_pointer]                     If multithreaded
  * @property {(size: number, align: number) => number} [allocate_stack] If multithreaded
  * @property {() => void} [worker_thread]                               If multithreaded
+ * @property {() => number} [worker_count]                              If multithreaded
+ * @property {() => number} [worker_tasks_executed]                     If multithreaded
+ * @property {() => number} [worker_inline_fallbacks]                   If multithreaded
+ */
+
+/**
+ * @typedef {Object} WorkerStatus
+ * @property {number} expected          Number of workers started by the host
+ * @property {number} live              Number of workers registered with the scoped task pool
+ * @property {number} tasksExecuted     Total number of scoped tasks run on workers
+ * @property {number} inlineFallbacks   Total number of scoped tasks run inline as no workers were available
  */
 
 /**
@@ -25,7 +42,25 @@
  * @typedef {Map<number, Map<number, ExampleInput[]>>} Puzzles
  */
 
-const BUFFER_SIZE = 1024 * 1024;
+/**
+ * @typedef {Object} Progress
+ * @property {number} completed     Units of work completed by the running solution
+ * @property {number | null} total  Total units of work, or null if unknown
+ */
+
+/**
+ * @typedef {Object} Timings
+ * @property {number} parse  Milliseconds taken to parse the input
+ * @property {number} part1  Milliseconds taken to solve part 1, or 0 if it wasn't run
+ * @property {number} part2  Milliseconds taken to solve part 2, or 0 if it wasn't run
+ */
+
+/**
+ * @typedef {Object} AnswerImage
+ * @property {number} width
+ * @property {number} height
+ * @property {Uint8ClampedArray} rgba  One white or transparent RGBA pixel per grid cell
+ */
 
 export class Aoc {
     /** @type {boolean} */
@@ -67,6 +102,34 @@ export class Aoc {
         return years;
     }
 
+    /**
+     * Returns the features the module was built with, such as "simd128" and "multithreading".
+     *
+     * @param {WebAssembly.Module} module
+     * @return {string[]}
+     * */
+    static features(module) {
+        const section = WebAssembly.Module.customSections(module, "aoc_features")[0];
+        if (section === undefined) return [];
+
+        const features = new TextDecoder().decode(section);
+        return features === "" ? [] : features.split(",");
+    }
+
+    /**
+     * @param {WebAssembly.Module} module
+     * @param {number} year
+     * @param {number} day
+     * @return {string}
+     * */
+    static description(module, year, day) {
+        const section_name = `aoc_description_${year}_${day}`;
+        const section = WebAssembly.Module.customSections(module, section_name)[0];
+        if (section === undefined) throw new Error(`Missing ${section_name} custom section`);
+
+        return new TextDecoder().decode(section);
+    }
+
     /**
      * @param {WebAssembly.Module} module
      * @param {number} year
@@ -95,24 +158,49 @@ export class Aoc {
         return result;
     }
 
+    /**
+     * Returns the imports required to instantiate the module, providing the shared memory for multithreaded modules.
+     *
+     * @param {WebAssembly.Memory} [memory]
+     * @return {WebAssembly.Imports}
+     */
+    static imports(memory) {
+        const imports = {aoc: {now: () => performance.now()}};
+        if (memory !== undefined) imports.env = {memory};
+        return imports;
+    }
+
+    /**
+     * Returns whether the module imports shared memory, throwing an error if it has any unsupported imports.
+     *
+     * @param {WebAssembly.Module} module
+     * @return {boolean}
+     */
+    static isMultithreaded(module) {
+        let multithreaded = false;
+        for (const {module: name, name: field, kind} of WebAssembly.Module.imports(module)) {
+            if (name === "env" && field === "memory" && kind === "memory") {
+                multithreaded = true;
+            } else if (!(name === "aoc" && field === "now" && kind === "function")) {
+                throw new Error("Unsupported module");
+            }
+        }
+        return multithreaded;
+    }
+
     /**
      * @param {WebAssembly.Module} module
      * @param {WebAssembly.Instance} [instance]
      */
     constructor(module, instance) {
-        const imports = WebAssembly.Module.imports(module);
-        if (imports.length === 0) {
-            this.#multithreaded = false;
-            this.#module = module;
-            this.#instance = instance ?? new WebAssembly.Instance(module);
-            this.#memory = this.#exports.memory;
-        } else if (imports.length === 1 && imports[0].module === "env" && imports[0].name === "memory" && imports[0].kind === "memory") {
-            this.#multithreaded = true;
-            this.#module = module;
+        this.#module = module;
+        this.#multithreaded = Aoc.isMultithreaded(module);
+        if (this.#multithreaded) {
             if (instance) throw new Error("Instance cannot be provided for multithreaded modules");
             this.newInstance();
         } else {
-            throw new Error("Unsupported module");
+            this.#instance = instance ?? new WebAssembly.Instance(module, Aoc.imports());
+            this.#memory = this.#exports.memory;
         }
     }
 
@@ -130,7 +218,7 @@ export class Aoc {
             numWorkers ??= navigator.hardwareConcurrency;
 
             this.#memory = new WebAssembly.Memory({initial: 96, maximum: 2048, shared: true});
-            this.#instance = new WebAssembly.Instance(this.#module, {env: {memory: this.#memory}});
+            this.#instance = new WebAssembly.Instance(this.#module, Aoc.imports(this.#memory));
 
             // Stack alignment must be at least 16 bytes.
             //
@@ -158,7 +246,7 @@ export class Aoc {
                 this.#workers.push(worker);
             }
         } else {
-            this.#instance = new WebAssembly.Instance(this.#module);
+            this.#instance = new WebAssembly.Instance(this.#module, Aoc.imports());
         }
     }
 
@@ -169,13 +257,19 @@ export class Aoc {
      * @param {boolean} [isExample]
      * @param {boolean} [part1]
      * @param {boolean} [part2]
-     * @return {{success: true, part1: string, part2: string} | {success: false, error: string}}
+     * @return {{success: true, part1: string, part2: string, image: AnswerImage | null, timings: Timings} | {success: false, error: string}}
      */
     run(year, day, input, isExample = false, part1 = true, part2 = true) {
+        const status = this.workerStatus();
+        if (status !== null && status.live < status.expected) {
+            console.warn(`Only ${status.live} of ${status.expected} workers are running, solutions may run slower`);
+        }
+
         let success;
         try {
-            this.#write(input);
+            const ptr = this.#write(input);
             success = this.#exports.run_puzzle(year, day, isExample, part1, part2);
+            this.#exports.free_input(ptr);
         } catch (e) {
             this.newInstance();
             return {
@@ -187,72 +281,125 @@ export class Aoc {
         if (success) {
             return {
                 success: true,
-                part1: this.#read("PART1"),
-                part2: this.#read("PART2"),
+                part1: this.#read(1),
+                part2: this.#read(2),
+                image: this.#readImage(),
+                timings: this.#readTimings(),
             }
         } else {
             return {
                 success: false,
-                error: this.#read("PART1"),
+                error: this.#read(1),
             }
         }
     }
 
+    /**
+     * Returns a view of the progress counters reported by the running solution, or null if the module isn't
+     * multithreaded.
+     *
+     * The view shares the module's memory, so it can be sent to another thread and read using {@link Aoc.progress}
+     * while a puzzle runs. Single-threaded modules block the thread running the puzzle, so progress can't be polled.
+     *
+     * @return {BigUint64Array | null}
+     */
+    get progressCounters() {
+        if (!this.#multithreaded) return null;
+        return new BigUint64Array(this.#memory.buffer, this.#exports.progress_counters(), 2);
+    }
+
+    /**
+     * Reads the progress from a view returned by {@link Aoc#progressCounters}.
+     *
+     * @param {BigUint64Array} counters
+     * @return {Progress}
+     */
+    static progress(counters) {
+        const total = Number(Atomics.load(counters, 1));
+        return {
+            completed: Number(Atomics.load(counters, 0)),
+            total: total === 0 ? null : total,
+        };
+    }
+
+    /**
+     * Returns the status of the worker pool, or null if the module isn't multithreaded.
+     *
+     * @return {WorkerStatus | null}
+     */
+    workerStatus() {
+        if (!this.#multithreaded) return null;
+        return {
+            expected: this.#workers.length,
+            live: this.#exports.worker_count(),
+            tasksExecuted: this.#exports.worker_tasks_executed(),
+            inlineFallbacks: this.#exports.worker_inline_fallbacks(),
+        };
+    }
+
     /** @return {ModuleExports} */
     get #exports() {
         return /** @type {any} */ (this.#instance.exports);
     }
 
     /**
-     * @param {"INPUT"|"PART1"|"PART2"} type
-     * @return {Uint8Array}
+     * Copies the input into a newly allocated input buffer, returning its address.
+     *
+     * @param {string} input
+     * @return {number}
      */
-    #buffer(type) {
-        const address = this.#exports[type].value;
-        return new Uint8Array(this.#memory.buffer)
-            .subarray(address, address + BUFFER_SIZE);
-    }
-
-    /** @param {string} input */
     #write(input) {
-        const buffer = this.#buffer("INPUT");
-        if (this.#multithreaded) {
-            // Can't encode directly into SharedArrayBuffer
-            const temp = new Uint8Array(BUFFER_SIZE);
-            const result = new TextEncoder().encodeInto(input, temp);
-            if (result.read < input.length || result.written === buffer.length) {
-                throw new Error("Input string is too long");
-            }
-            buffer.set(temp.subarray(0, result.written));
-            buffer[result.written] = 0;
-        } else {
-            const result = new TextEncoder().encodeInto(input, buffer);
-            if (result.read < input.length || result.written === buffer.length) {
-                throw new Error("Input string is too long");
-            }
-            buffer[result.written] = 0;
-        }
+        const bytes = new TextEncoder().encode(input);
+        const ptr = this.#exports.alloc_input(bytes.length);
+        // Allocating may grow the memory, so the view must be created afterwards
+        new Uint8Array(this.#memory.buffer, ptr, bytes.length).set(bytes);
+        return ptr;
     }
 
     /**
-     * @param {"PART1"|"PART2"} type
-     * @return string
+     * Returns the image of the ASCII-art answer, or null if the solution didn't record one or the
+     * module was built without answer-images.
+     *
+     * @return {AnswerImage | null}
      */
-    #read(type) {
-        let buffer = this.#buffer(type);
+    #readImage() {
+        const exports = this.#exports;
+        if (exports.IMAGE === undefined) return null;
 
-        const end = buffer.indexOf(0);
-        if (end !== -1) {
-            buffer = buffer.subarray(0, end);
-        }
+        const view = new DataView(this.#memory.buffer);
+        const width = view.getUint32(exports.IMAGE_WIDTH.value, true);
+        const height = view.getUint32(exports.IMAGE_HEIGHT.value, true);
+        if (width === 0 || height === 0) return null;
 
-        if (this.#multithreaded) {
-            // Can't decode directly from SharedArrayBuffer
-            const temp = new Uint8Array(buffer.length);
-            temp.set(buffer);
-            buffer = temp;
-        }
+        // Always copy, as the memory may be a SharedArrayBuffer and is reused by later runs
+        const address = exports.IMAGE.value;
+        const rgba = new Uint8ClampedArray(width * height * 4);
+        rgba.set(new Uint8Array(this.#memory.buffer, address, rgba.length));
+        return {width, height, rgba};
+    }
 
-        return (new TextDecoder()).decode(buffer);
+    /**
+     * Returns the time taken by each stage of the last solution.
+     *
+     * @return {Timings}
+     */
+    #readTimings() {
+        const nanos = new BigUint64Array(this.#memory.buffer, this.#exports.TIMINGS.value, 3);
+        const [parse, part1, part2] = Array.from(nanos, (n) => Number(n) / 1e6);
+        return {parse, part1, part2};
+    }
+
+    /**
+     * Reads the length-prefixed answer for the provided part.
+     *
+     * @param {1|2} part
+     * @return string
+     */
+    #read(part) {
+        const ptr = this.#exports.answer(part);
+        const length = new DataView(this.#memory.buffer).getUint32(ptr, true);
+        // Always copy, as the memory may be a SharedArrayBuffer which can't be decoded directly
+        const bytes = new Uint8Array(this.#memory.buffer, ptr + 4, length).slice();
+        return new TextDecoder().decode(bytes);
     }
 }
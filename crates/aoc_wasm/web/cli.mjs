@@ -0,0 +1,44 @@
+#!/usr/bin/env node
+// Command line shim for running the WebAssembly module outside the browser, used by
+// `cargo xtask test` to check WASM builds against stored answers.
+//
+// Usage: node cli.mjs $module $year $day $input
+//
+// Prints a single line of JSON matching the aoc binary's `--format json` output. Only
+// single-threaded modules are supported, as the multithreaded module requires browser workers.
+
+import {readFileSync} from "node:fs";
+import {Aoc} from "./aoc.mjs";
+
+const [modulePath, year, day, inputPath] = process.argv.slice(2);
+if (inputPath === undefined) {
+    console.error("Usage: node cli.mjs $module $year $day $input");
+    process.exit(2);
+}
+
+const module = new WebAssembly.Module(readFileSync(modulePath));
+if (Aoc.isMultithreaded(module)) {
+    console.error("Multithreaded modules are not supported");
+    process.exit(2);
+}
+
+const input = readFileSync(inputPath, "utf-8").trimEnd().replaceAll("\r\n", "\n");
+
+const aoc = new Aoc(module);
+const start = process.hrtime.bigint();
+const result = aoc.run(parseInt(year, 10), parseInt(day, 10), input);
+const time = process.hrtime.bigint() - start;
+
+if (result.success) {
+    console.log(JSON.stringify({
+        year: parseInt(year, 10),
+        day: parseInt(day, 10),
+        part1: result.part1,
+        part2: result.part2,
+        time_ns: Number(time),
+        cached: false,
+    }));
+} else {
+    console.log(JSON.stringify({year: parseInt(year, 10), day: parseInt(day, 10), error: result.error}));
+    process.exit(1);
+}
@@ -47,18 +47,26 @@ async function run(input, isExample, part) {
     element.textContent = "";
     element.classList.add("skeleton-block");
 
+    let progressInterval;
     const promise = new Promise((resolve, reject) => {
         worker.onmessage = (e) => {
-            resolve(e);
+            const [type, data] = e.data;
+            if (type === "progress") {
+                progressInterval = setInterval(() => showProgress(element, Aoc.progress(data), start), 100);
+            } else {
+                resolve(data);
+            }
         };
         worker.onerror = reject;
     });
 
     const start = performance.now();
     worker.postMessage(["run", YEAR, DAY, input, isExample, part !== "2", part !== "1"]);
-    const {data: result} = await promise;
+    const result = await promise;
     const end = performance.now();
 
+    clearInterval(progressInterval);
+    element.textContent = "";
     worker.onmessage = undefined;
     worker.onerror = undefined;
 
@@ -86,10 +94,19 @@ async function run(input, isExample, part) {
             body.classList.add("message-body", "has-text-success");
             body.innerText = output;
             article.appendChild(body);
+
+            if (name === "Part 2" && result.image) {
+                body.appendChild(imageCanvas(result.image));
+            }
         }
 
+        const stages = [["parse", result.timings.parse]];
+        if (part !== "2") stages.push(["part 1", result.timings.part1]);
+        if (part !== "1") stages.push(["part 2", result.timings.part2]);
+
         const timeTaken = document.createElement("i");
-        timeTaken.innerText = "Took " + Math.round(end - start) + " ms";
+        timeTaken.innerText = "Took " + Math.round(end - start) + " ms ("
+            + stages.map(([name, ms]) => name + " " + formatMillis(ms)).join(", ") + ")";
         element.appendChild(timeTaken);
     } else {
         const article = document.createElement("article");
@@ -106,6 +123,52 @@ async function run(input, isExample, part) {
     element.scrollIntoView({behavior: "smooth"});
 }
 
+/**
+ * @param {Element} element
+ * @param {import("./aoc.mjs").Progress} progress
+ * @param {number} start
+ */
+function showProgress(element, progress, start) {
+    // Avoid flickering for fast solutions
+    const elapsed = performance.now() - start;
+    if (progress.completed === 0 || elapsed < 500) return;
+
+    // Skeleton blocks hide their text
+    element.classList.remove("skeleton-block");
+
+    const seconds = (elapsed / 1000).toFixed(1) + "s";
+    if (progress.total === null) {
+        element.textContent = `${progress.completed.toLocaleString()} completed, ${seconds}`;
+    } else {
+        const percent = Math.floor(Math.min(progress.completed / progress.total, 1) * 100);
+        element.textContent = `${percent}% completed, ${seconds}`;
+    }
+}
+
+/**
+ * Format a duration like the native CLI, using microseconds or milliseconds.
+ *
+ * @param {number} ms
+ * @return {string}
+ */
+function formatMillis(ms) {
+    return ms < 1 ? (ms * 1000).toFixed(3) + " µs" : ms.toFixed(3) + " ms";
+}
+
+/** @param {import("./aoc.mjs").AnswerImage} image */
+function imageCanvas(image) {
+    const canvas = document.createElement("canvas");
+    canvas.width = image.width;
+    canvas.height = image.height;
+    canvas.classList.add("is-block", "mt-2");
+    // Scale up without smoothing to keep pixels sharp
+    canvas.style.width = (image.width * 6) + "px";
+    canvas.style.imageRendering = "pixelated";
+    canvas.style.backgroundColor = "black";
+    canvas.getContext("2d").putImageData(new ImageData(image.rgba, image.width, image.height), 0, 0);
+    return canvas;
+}
+
 function updateNavbar() {
     document.querySelectorAll(".aoc-current-year").forEach((elem) => {
         elem.innerText = YEAR.toString();
@@ -132,6 +195,7 @@ function updateNavbar() {
         elem.classList.add("navbar-item");
         elem.innerText = day.toString().padStart(2, "0");
         elem.href = `#${YEAR}${day.toString().padStart(2, "0")}`
+        elem.title = Aoc.description(module, YEAR, day);
         days.appendChild(elem);
     }
 }
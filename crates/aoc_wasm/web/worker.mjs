@@ -9,15 +9,18 @@ onmessage = (e) => {
             instance = new Aoc(...e.data);
             break;
         case "run":
+            const counters = instance.progressCounters;
+            if (counters !== null) postMessage(["progress", counters]);
+
             console.time("solution");
             const result = instance.run(...e.data);
             console.timeEnd("solution");
-            postMessage(result);
+            postMessage(["result", result]);
             console.log(result);
             break;
         case "thread":
             const [module, memory, ptr] = e.data;
-            instance = new WebAssembly.Instance(module, {env: {memory}});
+            instance = new WebAssembly.Instance(module, Aoc.imports(memory));
             instance.exports.__stack_pointer.value = ptr; // Stack uses storage below the provided pointer
             instance.exports.__wasm_init_tls(ptr); // TLS uses storage above the provided pointer
             instance.exports.worker_thread();
@@ -0,0 +1,40 @@
+//! Records the compiler version and git commit for [`env_info`](src/env_info.rs).
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=RUSTC");
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    if let Some(version) = command_output(Command::new(rustc).arg("--version")) {
+        println!("cargo::rustc-env=UTILS_RUSTC_VERSION={version}");
+    }
+
+    if let Some(hash) = command_output(Command::new("git").args(["rev-parse", "HEAD"])) {
+        println!("cargo::rustc-env=UTILS_GIT_HASH={hash}");
+
+        // Only watch files which exist, as missing files cause the build script to always rerun
+        if let Some(git_dir) = command_output(Command::new("git").args(["rev-parse", "--git-dir"]))
+        {
+            let git_dir = PathBuf::from(git_dir);
+            for path in ["HEAD", "refs/heads", "packed-refs"] {
+                let path = git_dir.join(path);
+                if path.exists() {
+                    println!("cargo::rerun-if-changed={}", path.display());
+                }
+            }
+        }
+    }
+}
+
+fn command_output(command: &mut Command) -> Option<String> {
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = String::from_utf8(output.stdout).ok()?;
+    Some(output.trim().to_string())
+}
@@ -0,0 +1,77 @@
+//! Pixel images of answers drawn as ASCII art.
+//!
+//! Solutions which decode letters drawn on a grid can call [`record`] with the grid alongside
+//! returning the decoded string, which [`ocr::Letters`](crate::ocr::Letters) does automatically.
+//! Images are only stored when enabled with [`set_enabled`], which `aoc_wasm` does so the web
+//! frontend can draw the grid to a canvas, so the calls can be left in solutions. When disabled,
+//! each call only costs a relaxed atomic load.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static IMAGE: Mutex<Option<Image>> = Mutex::new(None);
+
+/// Boolean pixel grid, stored in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<bool>,
+}
+
+/// Enable or disable storing images.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if images passed to [`record`] are being stored.
+#[inline]
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Store an image of the answer, replacing any previous image, if enabled using [`set_enabled`].
+///
+/// # Panics
+///
+/// Panics if the number of pixels doesn't match the width and height.
+///
+/// # Examples
+/// ```
+/// # use utils::answer_image::{self, Image};
+/// answer_image::record(3, 2, [true, false, true, false, true, false]);
+/// assert_eq!(answer_image::take(), None);
+///
+/// answer_image::set_enabled(true);
+/// answer_image::record(3, 2, [true, false, true, false, true, false]);
+/// assert_eq!(
+///     answer_image::take(),
+///     Some(Image {
+///         width: 3,
+///         height: 2,
+///         pixels: vec![true, false, true, false, true, false],
+///     }),
+/// );
+/// assert_eq!(answer_image::take(), None);
+/// ```
+pub fn record(width: usize, height: usize, pixels: impl IntoIterator<Item = bool>) {
+    if !is_enabled() {
+        return;
+    }
+
+    let pixels: Vec<bool> = pixels.into_iter().collect();
+    assert_eq!(pixels.len(), width * height, "incorrect number of pixels");
+    *IMAGE.lock().unwrap_or_else(PoisonError::into_inner) = Some(Image {
+        width,
+        height,
+        pixels,
+    });
+}
+
+/// Remove and return the stored image, if any.
+#[must_use]
+pub fn take() -> Option<Image> {
+    IMAGE.lock().unwrap_or_else(PoisonError::into_inner).take()
+}
@@ -0,0 +1,118 @@
+//! Expected answer files, shared by the corpus tests and `cargo xtask test`.
+//!
+//! Each line of an answers file is either `$part: $answer` or `$part check: $script`, where `$part`
+//! is `part1` or `part2`. Parts without any lines aren't checked.
+//!
+//! Repeating `$part: $answer` lines lists alternative accepted answers, for puzzles which accept
+//! any of several valid outputs. Check scripts are for puzzles with too many valid answers to
+//! list, and are run with the input path and actual answer as arguments, accepting the answer if
+//! the script exits successfully. Script paths are relative to the directory containing the
+//! answers file.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Expected answers for both parts of a puzzle.
+///
+/// # Examples
+/// ```
+/// # use std::path::Path;
+/// # use utils::answers::ExpectedAnswers;
+/// let expected = ExpectedAnswers::parse("part1: 12\npart2: a,b,c\npart2: c,b,a\n", Path::new("."))
+///     .unwrap();
+/// let input = Path::new("input.txt");
+/// assert_eq!(expected.part1.check("12", input), Ok(()));
+/// assert_eq!(
+///     expected.part1.check("13", input),
+///     Err("incorrect, expected \"12\", got \"13\"".to_string()),
+/// );
+/// assert_eq!(expected.part2.check("c,b,a", input), Ok(()));
+/// assert_eq!(
+///     expected.part2.check("b,a,c", input),
+///     Err("incorrect, expected \"a,b,c\" or \"c,b,a\", got \"b,a,c\"".to_string()),
+/// );
+///
+/// assert!(ExpectedAnswers::parse("part3: 1", Path::new(".")).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part1: Expected,
+    pub part2: Expected,
+}
+
+/// Accepted answers for one part of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub answers: Vec<String>,
+    pub scripts: Vec<PathBuf>,
+}
+
+impl ExpectedAnswers {
+    /// Parse the contents of an answers file stored in the provided directory.
+    pub fn parse(contents: &str, dir: &Path) -> Result<Self, String> {
+        let mut result = Self::default();
+        for line in contents.lines() {
+            let (key, value) = line
+                .split_once(": ")
+                .ok_or_else(|| format!("invalid answers line {line:?}"))?;
+            let (part, is_script) = match key.strip_suffix(" check") {
+                Some(part) => (part, true),
+                None => (key, false),
+            };
+            let expected = match part {
+                "part1" => &mut result.part1,
+                "part2" => &mut result.part2,
+                _ => return Err(format!("invalid answers line {line:?}")),
+            };
+            if is_script {
+                expected.scripts.push(dir.join(value));
+            } else {
+                expected.answers.push(value.to_string());
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Expected {
+    /// Returns true if no answers or scripts are provided, meaning the part isn't checked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty() && self.scripts.is_empty()
+    }
+
+    /// Check whether the actual answer for the provided input is accepted.
+    ///
+    /// Listed answers are compared first, before running any check scripts. Parts without any
+    /// answers or scripts accept every answer.
+    pub fn check(&self, actual: &str, input: &Path) -> Result<(), String> {
+        if self.is_empty() || self.answers.iter().any(|a| a == actual) {
+            return Ok(());
+        }
+
+        let mut errors = Vec::new();
+        if !self.answers.is_empty() {
+            let expected = self
+                .answers
+                .iter()
+                .map(|a| format!("{a:?}"))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            errors.push(format!("incorrect, expected {expected}, got {actual:?}"));
+        }
+
+        for script in &self.scripts {
+            match Command::new(script).arg(input).arg(actual).output() {
+                Ok(output) if output.status.success() => return Ok(()),
+                Ok(output) => errors.push(format!(
+                    "{actual:?} rejected by {}: {}",
+                    script.display(),
+                    String::from_utf8_lossy(&output.stderr).trim_end(),
+                )),
+                Err(err) => errors.push(format!("failed to run {}: {err}", script.display())),
+            }
+        }
+
+        Err(errors.join(", "))
+    }
+}
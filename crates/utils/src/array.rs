@@ -2,7 +2,7 @@
 
 use std::fmt;
 use std::hash::Hash;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
 
 /// A fixed-size array-backed vector.
 ///
@@ -212,3 +212,382 @@ impl<T: Default + Copy, const N: usize> Default for ArrayVec<T, N> {
         Self::new()
     }
 }
+
+/// A fixed-size, heap-allocated, `D` dimensional array with row-major storage.
+///
+/// Elements are indexed using `[usize; D]` arrays, with the last index varying fastest in memory.
+/// Each index is bounds checked against its dimension, so out-of-range coordinates can't alias a
+/// different element. When the `unsafe` feature is enabled the redundant check on the flattened
+/// index is skipped.
+///
+/// The outermost dimension splits the array into layers, which are rows for [`Array2`] and planes
+/// for [`Array3`]. Layers are contiguous, so can be accessed as slices, which is useful for dynamic
+/// programming where each layer is computed from the previous one.
+///
+/// # Examples
+/// ```
+/// # use utils::array::Array2;
+/// // Count lattice paths, where each cell is the sum of the cells above and to the left
+/// let mut paths = Array2::new([5, 5], 1u64);
+/// for r in 1..5 {
+///     for c in 1..5 {
+///         paths[[r, c]] = paths[[r - 1, c]] + paths[[r, c - 1]];
+///     }
+/// }
+/// assert_eq!(paths[[4, 4]], 70);
+/// assert_eq!(paths.layer(2), &[1, 3, 6, 10, 15]);
+/// assert_eq!(paths.get([5, 0]), None);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct NdArray<T, const D: usize> {
+    dims: [usize; D],
+    data: Vec<T>,
+}
+
+/// A two dimensional [`NdArray`], indexed using `[row, column]`.
+pub type Array2<T> = NdArray<T, 2>;
+
+/// A three dimensional [`NdArray`], indexed using `[plane, row, column]`.
+pub type Array3<T> = NdArray<T, 3>;
+
+impl<T, const D: usize> NdArray<T, D> {
+    /// Creates a new array with the provided dimensions, filled with `value`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `D` is zero or if the total number of elements overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array3;
+    /// let array = Array3::new([2, 3, 4], 0u8);
+    /// assert_eq!(array.dims(), [2, 3, 4]);
+    /// assert_eq!(array.len(), 24);
+    /// ```
+    #[must_use]
+    pub fn new(dims: [usize; D], value: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = Self::checked_len(dims).expect("array size overflowed");
+        Self {
+            dims,
+            data: vec![value; len],
+        }
+    }
+
+    /// Creates a new array with the provided dimensions from row-major data.
+    ///
+    /// Returns [`None`] if the length of the data doesn't match the dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array2;
+    /// let array = Array2::from_vec([2, 3], vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(array[[1, 0]], 4);
+    /// assert_eq!(Array2::from_vec([2, 3], vec![1, 2, 3]), None);
+    /// ```
+    #[must_use]
+    pub fn from_vec(dims: [usize; D], data: Vec<T>) -> Option<Self> {
+        if Self::checked_len(dims)? == data.len() {
+            Some(Self { dims, data })
+        } else {
+            None
+        }
+    }
+
+    fn checked_len(dims: [usize; D]) -> Option<usize> {
+        assert!(D > 0, "array must have at least one dimension");
+        dims.iter().try_fold(1usize, |acc, &d| acc.checked_mul(d))
+    }
+
+    /// Returns the size of each dimension.
+    #[inline]
+    #[must_use]
+    pub fn dims(&self) -> [usize; D] {
+        self.dims
+    }
+
+    /// Returns the total number of elements.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the array has no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the row-major index into the backing storage for the provided coordinates, or
+    /// [`None`] if any coordinate is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array3;
+    /// let array = Array3::new([2, 3, 4], 0u8);
+    /// assert_eq!(array.flat_index([1, 2, 3]), Some(23));
+    /// assert_eq!(array.flat_index([0, 3, 0]), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn flat_index(&self, index: [usize; D]) -> Option<usize> {
+        let mut flat = 0;
+        for (&i, &d) in index.iter().zip(&self.dims) {
+            if i >= d {
+                return None;
+            }
+            flat = flat * d + i;
+        }
+        Some(flat)
+    }
+
+    /// Returns the coordinates for the provided row-major index, or [`None`] if it is out of
+    /// bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array3;
+    /// let array = Array3::new([2, 3, 4], 0u8);
+    /// assert_eq!(array.coords(23), Some([1, 2, 3]));
+    /// assert_eq!(array.coords(24), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn coords(&self, mut flat: usize) -> Option<[usize; D]> {
+        if flat >= self.data.len() {
+            return None;
+        }
+        let mut index = [0; D];
+        for (i, &d) in index.iter_mut().zip(&self.dims).rev() {
+            *i = flat % d;
+            flat /= d;
+        }
+        Some(index)
+    }
+
+    /// Returns a reference to the element at the provided coordinates, or [`None`] if out of
+    /// bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: [usize; D]) -> Option<&T> {
+        let flat = self.flat_index(index)?;
+        Some(self.element(flat))
+    }
+
+    /// Returns a mutable reference to the element at the provided coordinates, or [`None`] if out
+    /// of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: [usize; D]) -> Option<&mut T> {
+        let flat = self.flat_index(index)?;
+        Some(self.element_mut(flat))
+    }
+
+    #[inline]
+    fn element(&self, flat: usize) -> &T {
+        #[cfg(feature = "unsafe")]
+        unsafe {
+            // SAFETY: flat indexes are only created by flat_index, which checks each coordinate
+            self.data.get_unchecked(flat)
+        }
+
+        #[cfg(not(feature = "unsafe"))]
+        &self.data[flat]
+    }
+
+    #[inline]
+    fn element_mut(&mut self, flat: usize) -> &mut T {
+        #[cfg(feature = "unsafe")]
+        unsafe {
+            // SAFETY: flat indexes are only created by flat_index, which checks each coordinate
+            self.data.get_unchecked_mut(flat)
+        }
+
+        #[cfg(not(feature = "unsafe"))]
+        &mut self.data[flat]
+    }
+
+    /// Sets every element to `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array2;
+    /// let mut array = Array2::new([2, 2], 0);
+    /// array.fill(5);
+    /// assert_eq!(array.as_slice(), &[5, 5, 5, 5]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Returns the backing storage as a row-major slice.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the backing storage as a mutable row-major slice.
+    #[inline]
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the backing storage.
+    #[inline]
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    #[inline]
+    fn layer_len(&self) -> usize {
+        self.dims[1..].iter().product()
+    }
+
+    /// Returns the contiguous slice for the provided index in the outermost dimension.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn layer(&self, index: usize) -> &[T] {
+        assert!(index < self.dims[0], "layer index out of bounds");
+        let len = self.layer_len();
+        &self.data[index * len..(index + 1) * len]
+    }
+
+    /// Returns the mutable contiguous slice for the provided index in the outermost dimension.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn layer_mut(&mut self, index: usize) -> &mut [T] {
+        assert!(index < self.dims[0], "layer index out of bounds");
+        let len = self.layer_len();
+        &mut self.data[index * len..(index + 1) * len]
+    }
+
+    /// Returns the previous layer and a mutable reference to the provided layer, for computing
+    /// each layer from the previous one.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the index is zero or out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array2;
+    /// // Pascal's triangle
+    /// let mut triangle = Array2::new([5, 5], 0u32);
+    /// triangle[[0, 0]] = 1;
+    /// for r in 1..5 {
+    ///     let (previous, row) = triangle.layer_with_previous_mut(r);
+    ///     row[0] = 1;
+    ///     for c in 1..5 {
+    ///         row[c] = previous[c - 1] + previous[c];
+    ///     }
+    /// }
+    /// assert_eq!(triangle.layer(4), &[1, 4, 6, 4, 1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn layer_with_previous_mut(&mut self, index: usize) -> (&[T], &mut [T]) {
+        assert!(
+            index > 0 && index < self.dims[0],
+            "layer index out of bounds"
+        );
+        let len = self.layer_len();
+        let (before, after) = self.data.split_at_mut(index * len);
+        (&before[(index - 1) * len..], &mut after[..len])
+    }
+
+    /// Returns an iterator over the layers of the outermost dimension.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array2;
+    /// let array = Array2::from_vec([3, 2], vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(array.layers().map(|r| r.iter().sum::<i32>()).collect::<Vec<_>>(), [3, 7, 11]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn layers(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        // Chunk size must be non-zero, and any non-zero value yields no chunks for empty data
+        self.data.chunks_exact(self.layer_len().max(1))
+    }
+
+    /// Returns an iterator over every window of `size` consecutive layers, each as a contiguous
+    /// slice.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::array::Array2;
+    /// let array = Array2::from_vec([3, 2], vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let mut windows = array.layer_windows(2);
+    /// assert_eq!(windows.next(), Some(&[1, 2, 3, 4][..]));
+    /// assert_eq!(windows.next(), Some(&[3, 4, 5, 6][..]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn layer_windows(&self, size: usize) -> impl ExactSizeIterator<Item = &[T]> {
+        assert!(size > 0, "window size must be non-zero");
+        let len = self.layer_len();
+        let count = (self.dims[0] + 1).saturating_sub(size);
+        (0..count).map(move |i| &self.data[i * len..(i + size) * len])
+    }
+}
+
+impl<T, const D: usize> Index<[usize; D]> for NdArray<T, D> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: [usize; D]) -> &Self::Output {
+        let Some(flat) = self.flat_index(index) else {
+            panic!(
+                "index {index:?} out of bounds for dimensions {:?}",
+                self.dims
+            );
+        };
+        self.element(flat)
+    }
+}
+
+impl<T, const D: usize> IndexMut<[usize; D]> for NdArray<T, D> {
+    #[inline]
+    fn index_mut(&mut self, index: [usize; D]) -> &mut Self::Output {
+        let Some(flat) = self.flat_index(index) else {
+            panic!(
+                "index {index:?} out of bounds for dimensions {:?}",
+                self.dims
+            );
+        };
+        self.element_mut(flat)
+    }
+}
+
+impl<T: fmt::Debug, const D: usize> fmt::Debug for NdArray<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NdArray")
+            .field("dims", &self.dims)
+            .field("data", &self.data)
+            .finish()
+    }
+}
@@ -1,7 +1,11 @@
 //! Bit manipulation helpers.
 
+use crate::collections::DeterministicHashSet;
+use crate::multiversion;
 use crate::number::UnsignedInteger;
 
+pub mod words;
+
 /// Iterator which yields all the set or unset bits in a provided number.
 pub struct BitIterator<T: UnsignedInteger> {
     n: T,
@@ -63,3 +67,260 @@ impl<T: UnsignedInteger> Iterator for BitIterator<T> {
         }
     }
 }
+
+multiversion! {
+    use {crate::bit::words::*};
+
+    #[dyn_dispatch = words::FASTEST]
+    /// Returns the number of set bits in the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::bit::count_ones_slice;
+    /// assert_eq!(count_ones_slice(&[0b1011, 0, u64::MAX]), 67);
+    /// ```
+    #[must_use]
+    pub fn count_ones_slice(words: &[u64]) -> usize {
+        count_ones(words)
+    }
+}
+
+multiversion! {
+    use {crate::bit::words::*};
+
+    #[dyn_dispatch = words::FASTEST]
+    /// Reverses the order of all the bits in the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::bit::reverse_bits_slice;
+    /// let mut words = [1, 0, 0b110];
+    /// reverse_bits_slice(&mut words);
+    /// assert_eq!(words, [0b011 << 61, 0, 1 << 63]);
+    /// ```
+    pub fn reverse_bits_slice(words: &mut [u64]) {
+        reverse_bits(words);
+    }
+}
+
+multiversion! {
+    use {crate::bit::words::*};
+
+    #[dyn_dispatch = words::FASTEST]
+    /// Returns the number of zero bits above the most significant set bit in the slice, or the
+    /// total number of bits if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::bit::leading_zeros_slice;
+    /// assert_eq!(leading_zeros_slice(&[1, 1 << 60, 0]), 67);
+    /// ```
+    #[must_use]
+    pub fn leading_zeros_slice(words: &[u64]) -> usize {
+        leading_zeros(words)
+    }
+}
+
+multiversion! {
+    use {crate::bit::words::*};
+
+    #[dyn_dispatch = words::FASTEST]
+    /// Returns the index of the least significant set bit in the slice, or the total number of
+    /// bits if no bits are set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::bit::trailing_zeros_slice;
+    /// assert_eq!(trailing_zeros_slice(&[0, 0b100, 1]), 66);
+    /// ```
+    #[must_use]
+    pub fn trailing_zeros_slice(words: &[u64]) -> usize {
+        trailing_zeros(words)
+    }
+}
+
+/// Set of visited states, encoded as integers less than a caller-declared bound.
+///
+/// The representation is picked from the bound:
+/// - Small state spaces use a single fixed bitset, allocated upfront.
+/// - Medium state spaces use a paged bitset, only allocating pages containing visited states.
+/// - Larger state spaces fall back to a [`DeterministicHashSet`].
+///
+/// This avoids having to hand-tune the visited set for each search, while still being close to
+/// the fastest option for the state space.
+///
+/// # Examples
+/// ```
+/// # use utils::bit::DenseVisited;
+/// for bound in [1 << 10, 1 << 32, u64::MAX] {
+///     let mut visited = DenseVisited::new(bound);
+///     assert!(visited.insert(5));
+///     assert!(visited.insert(bound - 1));
+///     assert!(!visited.insert(5));
+///     assert!(visited.contains(5));
+///     assert!(!visited.contains(6));
+///     assert_eq!(visited.len(), 2);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DenseVisited {
+    storage: Storage,
+    bound: u64,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Storage {
+    Fixed(Vec<u64>),
+    Paged(Vec<Option<Box<[u64]>>>),
+    Hashed(DeterministicHashSet<u64>),
+}
+
+const PAGE_BITS: u32 = 18;
+const PAGE_WORDS: usize = 1 << (PAGE_BITS - 6);
+/// Largest bound using a fixed bitset, 16 MiB.
+const FIXED_MAX: u64 = 1 << 27;
+/// Largest bound using a paged bitset, with a 2 MiB page table.
+const PAGED_MAX: u64 = 1 << 36;
+
+impl DenseVisited {
+    /// Create a new empty set for states in the range `0..bound`.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn new(bound: u64) -> Self {
+        let storage = if bound <= FIXED_MAX {
+            Storage::Fixed(vec![0; bound.div_ceil(64) as usize])
+        } else if bound <= PAGED_MAX {
+            Storage::Paged(vec![None; bound.div_ceil(1 << PAGE_BITS) as usize])
+        } else {
+            Storage::Hashed(DeterministicHashSet::default())
+        };
+        Self {
+            storage,
+            bound,
+            len: 0,
+        }
+    }
+
+    /// Add a state to the set, returning whether it was newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is not less than the bound.
+    #[inline]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn insert(&mut self, state: u64) -> bool {
+        assert!(state < self.bound, "state {state} out of bounds");
+
+        let inserted = match &mut self.storage {
+            Storage::Fixed(words) => Self::set_bit(&mut words[(state >> 6) as usize], state),
+            Storage::Paged(pages) => {
+                let page = pages[(state >> PAGE_BITS) as usize]
+                    .get_or_insert_with(|| vec![0; PAGE_WORDS].into_boxed_slice());
+                let word = (state as usize >> 6) & (PAGE_WORDS - 1);
+                Self::set_bit(&mut page[word], state)
+            }
+            Storage::Hashed(set) => set.insert(state),
+        };
+        self.len += usize::from(inserted);
+        inserted
+    }
+
+    /// Returns whether the set contains the provided state.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn contains(&self, state: u64) -> bool {
+        if state >= self.bound {
+            return false;
+        }
+
+        match &self.storage {
+            Storage::Fixed(words) => words[(state >> 6) as usize] & (1 << (state & 63)) != 0,
+            Storage::Paged(pages) => {
+                pages[(state >> PAGE_BITS) as usize]
+                    .as_ref()
+                    .is_some_and(|page| {
+                        let word = (state as usize >> 6) & (PAGE_WORDS - 1);
+                        page[word] & (1 << (state & 63)) != 0
+                    })
+            }
+            Storage::Hashed(set) => set.contains(&state),
+        }
+    }
+
+    /// Returns the number of states in the set.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set contains no states.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all states from the set, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        match &mut self.storage {
+            Storage::Fixed(words) => words.fill(0),
+            Storage::Paged(pages) => pages.iter_mut().flatten().for_each(|page| page.fill(0)),
+            Storage::Hashed(set) => set.clear(),
+        }
+        self.len = 0;
+    }
+
+    #[inline]
+    fn set_bit(word: &mut u64, state: u64) -> bool {
+        let bit = 1 << (state & 63);
+        let inserted = *word & bit == 0;
+        *word |= bit;
+        inserted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn dense_visited_matches_hash_set() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for bound in [
+            1000,
+            FIXED_MAX,
+            FIXED_MAX + 1,
+            PAGED_MAX,
+            PAGED_MAX + 1,
+            u64::MAX,
+        ] {
+            let mut visited = DenseVisited::new(bound);
+            let mut expected = HashSet::new();
+            let states: Vec<u64> = (0..2000).map(|_| random() % bound).collect();
+            for &s in &states {
+                assert_eq!(visited.insert(s), expected.insert(s));
+                assert_eq!(visited.len(), expected.len());
+            }
+            for &s in &states {
+                assert!(visited.contains(s));
+                assert_eq!(visited.contains(s ^ 1), expected.contains(&(s ^ 1)));
+            }
+            assert!(!visited.contains(bound));
+
+            visited.clear();
+            assert!(visited.is_empty());
+            assert!(states.iter().all(|&s| !visited.contains(s)));
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Bulk operations on bitsets stored as [`u64`] slices.
+//!
+//! Slices are treated as one large little-endian integer, so bit `i` is stored in bit `i % 64` of
+//! word `i / 64`, matching the layout used by [`DenseVisited`](super::DenseVisited) and bitmask
+//! based puzzle solutions.
+//!
+//! The vectorized versions process [`U32Vector::LANES`](crate::simd::scalar::U32Vector::LANES)
+//! words at once, which allows the compiler to use vector popcount and shuffle instructions.
+//! Prefer the dynamic dispatch wrappers in [`bit`](super), such as
+//! [`count_ones_slice`](super::count_ones_slice), which use the [`FASTEST`] version.
+use crate::multiversion;
+use crate::multiversion::Version;
+use std::sync::LazyLock;
+
+/// Fastest supported implementation, for dynamic dispatch.
+///
+/// Determined using a small microbenchmark at runtime.
+pub static FASTEST: LazyLock<Version> = multiversion! { fastest(microbenchmark()) };
+
+multiversion! {
+    use {crate::simd::*};
+
+    /// [`multiversion!`] bulk popcount implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use utils::bit::words::array128;
+    /// assert_eq!(array128::count_ones(&[0b1011, 0, u64::MAX, 1 << 63, 7]), 71);
+    /// ```
+    #[must_use]
+    pub fn count_ones(words: &[u64]) -> usize {
+        let mut counts = [0u32; U32Vector::LANES];
+        let chunks = words.chunks_exact(U32Vector::LANES);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            for (count, &word) in counts.iter_mut().zip(chunk) {
+                *count += word.count_ones();
+            }
+        }
+
+        counts.iter().map(|&c| c as usize).sum::<usize>()
+            + remainder.iter().map(|w| w.count_ones() as usize).sum::<usize>()
+    }
+
+    /// [`multiversion!`] bulk bit-reverse implementation.
+    ///
+    /// Reverses the order of every bit in the slice, so bit `i` moves to bit `64 * len - 1 - i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use utils::bit::words::array128;
+    /// let mut words = [0b1011, 1 << 62];
+    /// array128::reverse_bits(&mut words);
+    /// assert_eq!(words, [0b10, 0b1101 << 60]);
+    /// ```
+    pub fn reverse_bits(words: &mut [u64]) {
+        words.reverse();
+        for chunk in words.chunks_mut(U32Vector::LANES) {
+            for word in chunk {
+                *word = word.reverse_bits();
+            }
+        }
+    }
+
+    /// [`multiversion!`] bulk leading zeros implementation.
+    ///
+    /// Returns the number of zero bits above the most significant set bit, or the total number of
+    /// bits if no bits are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use utils::bit::words::array128;
+    /// assert_eq!(array128::leading_zeros(&[1, 0b100, 0, 0]), 189);
+    /// assert_eq!(array128::leading_zeros(&[0, 0]), 128);
+    /// ```
+    #[must_use]
+    pub fn leading_zeros(words: &[u64]) -> usize {
+        let chunks = words.rchunks_exact(U32Vector::LANES);
+        let mut skipped = 0;
+        let mut last = chunks.remainder();
+        for chunk in chunks {
+            if chunk.iter().fold(0, |acc, &w| acc | w) != 0 {
+                last = chunk;
+                break;
+            }
+            skipped += chunk.len();
+        }
+
+        64 * skipped + last
+            .iter()
+            .rev()
+            .position(|&w| w != 0)
+            .map_or(64 * last.len(), |i| 64 * i + last[last.len() - 1 - i].leading_zeros() as usize)
+    }
+
+    /// [`multiversion!`] bulk trailing zeros implementation.
+    ///
+    /// Returns the index of the least significant set bit, or the total number of bits if no bits
+    /// are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use utils::bit::words::array128;
+    /// assert_eq!(array128::trailing_zeros(&[0, 0, 0b1000, 1]), 131);
+    /// assert_eq!(array128::trailing_zeros(&[0, 0]), 128);
+    /// ```
+    #[must_use]
+    pub fn trailing_zeros(words: &[u64]) -> usize {
+        let chunks = words.chunks_exact(U32Vector::LANES);
+        let mut skipped = 0;
+        let mut first = chunks.remainder();
+        for chunk in chunks {
+            if chunk.iter().fold(0, |acc, &w| acc | w) != 0 {
+                first = chunk;
+                break;
+            }
+            skipped += chunk.len();
+        }
+
+        64 * skipped + first
+            .iter()
+            .position(|&w| w != 0)
+            .map_or(64 * first.len(), |i| 64 * i + first[i].trailing_zeros() as usize)
+    }
+
+    pub(super) fn microbenchmark() {
+        let mut words = BENCH_WORDS;
+        for _ in 0..16 {
+            std::hint::black_box(count_ones(std::hint::black_box(&words)));
+            std::hint::black_box(trailing_zeros(std::hint::black_box(&words[..1024])));
+            reverse_bits(std::hint::black_box(&mut words));
+        }
+    }
+}
+
+const BENCH_WORDS: [u64; 2048] = {
+    let mut out = [0u64; 2048];
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut i = 1024;
+    while i < out.len() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        out[i] = state;
+        i += 1;
+    }
+    out
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::multiversion_test;
+
+    multiversion_test! {
+        use {crate::bit::words::*};
+
+        #[test]
+        fn multiversion() {
+            let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+            let mut random = move || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+
+            for len in 0..300 {
+                let mut words: Vec<u64> = (0..len).map(|_| random() & random()).collect();
+                if len > 2 {
+                    // Test long runs of zeros at both ends
+                    words[..len / 3].fill(0);
+                    words[len - len / 3..].fill(0);
+                }
+
+                let bits: Vec<bool> = (0..64 * len).map(|i| words[i / 64] & (1 << (i % 64)) != 0).collect();
+                assert_eq!(count_ones(&words), bits.iter().filter(|&&b| b).count(), "length {len}");
+                assert_eq!(trailing_zeros(&words), bits.iter().position(|&b| b).unwrap_or(bits.len()), "length {len}");
+                assert_eq!(leading_zeros(&words), bits.iter().rev().position(|&b| b).unwrap_or(bits.len()), "length {len}");
+
+                reverse_bits(&mut words);
+                let reversed: Vec<bool> = (0..64 * len).map(|i| words[i / 64] & (1 << (i % 64)) != 0).collect();
+                assert!(reversed.iter().eq(bits.iter().rev()), "length {len}");
+            }
+        }
+    }
+}
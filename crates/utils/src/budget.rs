@@ -0,0 +1,72 @@
+//! Soft time budgets for anytime algorithms.
+//!
+//! Exploratory solutions, such as beam or local searches, can check [`Budget::is_exhausted`] or
+//! [`Budget::remaining`] in their main loops and return the best answer found so far once the
+//! budget runs out, instead of overrunning. The [`aoc`](../../aoc/) binary starts a budget before
+//! running each solution when run with `--budget`.
+//!
+//! Budgets are soft limits, only enforced by solutions which check them. When no budget is set,
+//! each check only costs a relaxed atomic load.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Global soft time budget, shared by all threads.
+///
+/// # Examples
+/// ```
+/// # use std::time::Duration;
+/// # use utils::budget::Budget;
+/// assert_eq!(Budget::remaining(), None);
+/// assert!(!Budget::is_exhausted());
+///
+/// Budget::start(Some(Duration::from_secs(60)));
+/// assert!(Budget::remaining().unwrap() > Duration::from_secs(59));
+/// assert!(!Budget::is_exhausted());
+///
+/// Budget::start(Some(Duration::ZERO));
+/// assert_eq!(Budget::remaining(), Some(Duration::ZERO));
+/// assert!(Budget::is_exhausted());
+///
+/// Budget::start(None);
+/// assert_eq!(Budget::remaining(), None);
+/// ```
+#[derive(Debug)]
+pub struct Budget;
+
+impl Budget {
+    /// Start a new budget of the provided duration from now, or remove the budget if [`None`].
+    pub fn start(limit: Option<Duration>) {
+        let mut deadline = DEADLINE.lock().unwrap_or_else(PoisonError::into_inner);
+        // Saturate to no deadline if the limit overflows
+        *deadline = limit.and_then(|limit| Instant::now().checked_add(limit));
+        ENABLED.store(deadline.is_some(), Ordering::Relaxed);
+    }
+
+    /// Returns the time left in the current budget, or [`None`] if there is no budget.
+    ///
+    /// Returns [`Duration::ZERO`] once the budget is exhausted.
+    #[inline]
+    #[must_use]
+    pub fn remaining() -> Option<Duration> {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let deadline = *DEADLINE.lock().unwrap_or_else(PoisonError::into_inner);
+        deadline.map(|d| d.saturating_duration_since(Instant::now()))
+    }
+
+    /// Returns true if the current budget has run out.
+    ///
+    /// Always returns false if there is no budget.
+    #[inline]
+    #[must_use]
+    pub fn is_exhausted() -> bool {
+        Self::remaining() == Some(Duration::ZERO)
+    }
+}
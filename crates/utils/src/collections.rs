@@ -0,0 +1,168 @@
+//! Hash map and set aliases with deterministic iteration order, and specialized collections.
+//!
+//! [`std::collections::HashMap`] uses a randomly seeded hasher by default, meaning iteration order
+//! changes between runs. The aliases in this module use [`FixedState`] instead, which always
+//! produces the same hashes for the same seed on every platform, so solutions which iterate maps or
+//! sets produce reproducible results.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+
+mod arena_list;
+mod trie;
+pub use arena_list::{rotate_ring, ArenaList};
+pub use trie::{AhoCorasick, Trie, TrieBuilder};
+
+/// [`HashMap`] using [`FixedState`], with deterministic iteration order.
+///
+/// # Examples
+/// ```
+/// # use utils::collections::DeterministicHashMap;
+/// let build = || {
+///     let mut map = DeterministicHashMap::default();
+///     for (i, name) in ["a", "b", "c", "d", "e", "f", "g", "h"].into_iter().enumerate() {
+///         map.insert(name, i);
+///     }
+///     map
+/// };
+/// assert_eq!(
+///     build().into_iter().collect::<Vec<_>>(),
+///     build().into_iter().collect::<Vec<_>>(),
+/// );
+/// ```
+pub type DeterministicHashMap<K, V> = HashMap<K, V, FixedState>;
+
+/// [`HashSet`] using [`FixedState`], with deterministic iteration order.
+pub type DeterministicHashSet<T> = HashSet<T, FixedState>;
+
+/// [`BuildHasher`] which creates [`FixedHasher`]s with a fixed seed.
+///
+/// The [`Default`] implementation uses a constant seed. Use [`FixedState::with_seed`] to check that
+/// a solution's output doesn't depend on iteration order.
+///
+/// # Examples
+/// ```
+/// # use std::hash::BuildHasher;
+/// # use utils::collections::FixedState;
+/// assert_eq!(FixedState::default().hash_one(123u64), FixedState::default().hash_one(123u64));
+/// assert_eq!(FixedState::default().hash_one(123usize), FixedState::default().hash_one(123u64));
+/// assert_ne!(FixedState::with_seed(1).hash_one(123u64), FixedState::with_seed(2).hash_one(123u64));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedState {
+    seed: u64,
+}
+
+impl FixedState {
+    const DEFAULT_SEED: u64 = 0x243f_6a88_85a3_08d3;
+
+    /// Create a new [`FixedState`] with the provided seed.
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl Default for FixedState {
+    #[inline]
+    fn default() -> Self {
+        Self::with_seed(Self::DEFAULT_SEED)
+    }
+}
+
+impl BuildHasher for FixedState {
+    type Hasher = FixedHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        FixedHasher { state: self.seed }
+    }
+}
+
+/// Fast, platform-independent, non-cryptographic [`Hasher`].
+///
+/// Values are combined using a multiply-rotate construction, with a final mixing step so both the
+/// high and low bits of the hash depend on every input bit. `usize` and `isize` values are hashed
+/// as 64-bit integers, so hashes match on 32-bit and 64-bit platforms.
+///
+/// This hasher is not resistant to collision attacks and should only be used with puzzle inputs.
+#[derive(Copy, Clone, Debug)]
+pub struct FixedHasher {
+    state: u64,
+}
+
+impl FixedHasher {
+    const MULTIPLIER: u64 = 0x517c_c1b7_2722_0a95;
+
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.state = (self.state.rotate_left(5) ^ word).wrapping_mul(Self::MULTIPLIER);
+    }
+}
+
+impl Hasher for FixedHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // Finalizer from MurmurHash3
+        let mut x = self.state;
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        x ^ (x >> 33)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut buf = [0u8; 8];
+            buf[..remainder.len()].copy_from_slice(remainder);
+            self.add(u64::from_le_bytes(buf));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(u64::from(i));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    #[inline]
+    #[expect(clippy::cast_possible_truncation)]
+    fn write_u128(&mut self, i: u128) {
+        self.add(i as u64);
+        self.add((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    #[expect(clippy::cast_sign_loss)]
+    fn write_isize(&mut self, i: isize) {
+        self.add(i as i64 as u64);
+    }
+}
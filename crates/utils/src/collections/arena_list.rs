@@ -0,0 +1,287 @@
+use std::collections::VecDeque;
+
+/// Circular doubly linked list, storing nodes in an arena and linking them using indexes.
+///
+/// Nodes are identified by the `u32` index returned when they are inserted, which stays valid
+/// until the node is removed. Removed nodes aren't reused, so indexes are never invalidated by
+/// other insertions or removals. Storing the links as indexes keeps nodes small and avoids the
+/// borrow checker issues of pointer based lists.
+///
+/// Useful for puzzles which repeatedly splice elements into or out of a circle, such as marble
+/// games and counting-out games, where [`Vec::insert`] and [`Vec::remove`] would be too slow.
+///
+/// # Examples
+/// ```
+/// # use utils::collections::ArenaList;
+/// let mut list = ArenaList::new();
+/// let a = list.push('a');
+/// let c = list.insert_after(a, 'c');
+/// let b = list.insert_before(c, 'b');
+/// assert_eq!(list.iter_from(a).copied().collect::<String>(), "abc");
+/// assert_eq!(list.next(c), a);
+/// assert_eq!(list.step(a, -1), c);
+///
+/// assert_eq!(list.remove(b), 'b');
+/// assert_eq!(list.iter_from(c).copied().collect::<String>(), "ca");
+/// assert_eq!(list.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ArenaList<T> {
+    nodes: Vec<Node<T>>,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    prev: u32,
+    next: u32,
+    removed: bool,
+}
+
+impl<T: Copy> ArenaList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Create an empty list with space for the provided number of insertions.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Add the first node to an empty list, which links to itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list isn't empty.
+    pub fn push(&mut self, value: T) -> u32 {
+        assert!(self.is_empty(), "list must be empty");
+        let index = self.next_index();
+        self.nodes.push(Node {
+            value,
+            prev: index,
+            next: index,
+            removed: false,
+        });
+        self.len = 1;
+        index
+    }
+
+    /// Insert a new node after the provided node, returning its index.
+    #[inline]
+    pub fn insert_after(&mut self, node: u32, value: T) -> u32 {
+        let next = self.node(node).next;
+        self.link(node, next, value)
+    }
+
+    /// Insert a new node before the provided node, returning its index.
+    #[inline]
+    pub fn insert_before(&mut self, node: u32, value: T) -> u32 {
+        let prev = self.node(node).prev;
+        self.link(prev, node, value)
+    }
+
+    /// Remove the provided node from the list, returning its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node has already been removed.
+    #[inline]
+    pub fn remove(&mut self, node: u32) -> T {
+        let Node {
+            value, prev, next, ..
+        } = *self.node(node);
+        self.nodes[next as usize].prev = prev;
+        self.nodes[prev as usize].next = next;
+        self.nodes[node as usize].removed = true;
+        self.len -= 1;
+        value
+    }
+
+    /// Returns the index of the node after the provided node.
+    #[inline]
+    #[must_use]
+    pub fn next(&self, node: u32) -> u32 {
+        self.node(node).next
+    }
+
+    /// Returns the index of the node before the provided node.
+    #[inline]
+    #[must_use]
+    pub fn prev(&self, node: u32) -> u32 {
+        self.node(node).prev
+    }
+
+    /// Returns the index of the node `offset` steps after the provided node, or before it if
+    /// `offset` is negative.
+    #[inline]
+    #[must_use]
+    pub fn step(&self, mut node: u32, offset: isize) -> u32 {
+        if offset >= 0 {
+            for _ in 0..offset {
+                node = self.next(node);
+            }
+        } else {
+            for _ in 0..offset.unsigned_abs() {
+                node = self.prev(node);
+            }
+        }
+        node
+    }
+
+    /// Returns the value stored in the provided node.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, node: u32) -> T {
+        self.node(node).value
+    }
+
+    /// Returns a mutable reference to the value stored in the provided node.
+    #[inline]
+    pub fn get_mut(&mut self, node: u32) -> &mut T {
+        let node = &mut self.nodes[node as usize];
+        assert!(!node.removed, "node has been removed");
+        &mut node.value
+    }
+
+    /// Returns an iterator over the values in the list, starting from the provided node.
+    pub fn iter_from(&self, start: u32) -> impl Iterator<Item = &T> + '_ {
+        let mut node = start;
+        (0..self.len).map(move |_| {
+            let current = &self.nodes[node as usize];
+            node = current.next;
+            &current.value
+        })
+    }
+
+    /// Returns the number of nodes in the list.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list contains no nodes.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn node(&self, node: u32) -> &Node<T> {
+        let node = &self.nodes[node as usize];
+        assert!(!node.removed, "node has been removed");
+        node
+    }
+
+    #[inline]
+    fn link(&mut self, prev: u32, next: u32, value: T) -> u32 {
+        let index = self.next_index();
+        self.nodes.push(Node {
+            value,
+            prev,
+            next,
+            removed: false,
+        });
+        self.nodes[prev as usize].next = index;
+        self.nodes[next as usize].prev = index;
+        self.len += 1;
+        index
+    }
+
+    fn next_index(&self) -> u32 {
+        u32::try_from(self.nodes.len()).expect("too many nodes")
+    }
+}
+
+/// Rotate a ring buffer so the element `offset` positions after the current front becomes the
+/// new front, or before it if `offset` is negative.
+///
+/// The offset wraps around, so it can exceed the length of the buffer. Rotating an empty buffer
+/// does nothing.
+///
+/// # Examples
+/// ```
+/// # use std::collections::VecDeque;
+/// # use utils::collections::rotate_ring;
+/// let mut ring = VecDeque::from([0, 1, 2, 3, 4]);
+/// rotate_ring(&mut ring, 2);
+/// assert_eq!(ring, [2, 3, 4, 0, 1]);
+/// rotate_ring(&mut ring, -3);
+/// assert_eq!(ring, [4, 0, 1, 2, 3]);
+/// rotate_ring(&mut ring, 11);
+/// assert_eq!(ring, [0, 1, 2, 3, 4]);
+/// ```
+#[inline]
+pub fn rotate_ring<T>(ring: &mut VecDeque<T>, offset: isize) {
+    if ring.is_empty() {
+        return;
+    }
+    #[expect(clippy::cast_possible_wrap)]
+    let offset = offset.rem_euclid(ring.len() as isize) as usize;
+    ring.rotate_left(offset);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2018 day 9 marble game, which requires splicing into a circle near the current marble
+    fn marble_game_list(players: usize, last_marble: u32) -> u64 {
+        let mut scores = vec![0u64; players];
+        let mut circle = ArenaList::with_capacity(last_marble as usize + 1);
+        let mut current = circle.push(0);
+        for marble in 1..=last_marble {
+            if marble % 23 == 0 {
+                let removed = circle.step(current, -7);
+                current = circle.next(removed);
+                scores[marble as usize % players] += u64::from(marble + circle.remove(removed));
+            } else {
+                current = circle.insert_after(circle.next(current), marble);
+            }
+        }
+        scores.into_iter().max().unwrap()
+    }
+
+    fn marble_game_ring(players: usize, last_marble: u32) -> u64 {
+        let mut scores = vec![0u64; players];
+        // The current marble is always kept at the back of the ring
+        let mut circle = VecDeque::from([0]);
+        for marble in 1..=last_marble {
+            if marble % 23 == 0 {
+                rotate_ring(&mut circle, -7);
+                let removed = circle.pop_back().unwrap();
+                rotate_ring(&mut circle, 1);
+                scores[marble as usize % players] += u64::from(marble + removed);
+            } else {
+                rotate_ring(&mut circle, 1);
+                circle.push_back(marble);
+            }
+        }
+        scores.into_iter().max().unwrap()
+    }
+
+    #[test]
+    fn marble_game() {
+        for (players, last_marble, expected) in [
+            (9, 25, 32),
+            (10, 1618, 8317),
+            (13, 7999, 146_373),
+            (17, 1104, 2764),
+            (21, 6111, 54718),
+            (30, 5807, 37305),
+        ] {
+            assert_eq!(marble_game_list(players, last_marble), expected);
+            assert_eq!(marble_game_ring(players, last_marble), expected);
+        }
+    }
+}
@@ -0,0 +1,427 @@
+use crate::scratch::with_scratch;
+
+const NO_SYMBOL: u16 = u16::MAX;
+const NO_PATTERN: u32 = u32::MAX;
+// Set on child entries when a pattern ends at the child, avoiding a separate lookup when matching
+const TERMINAL: u32 = 1 << 31;
+
+/// Byte trie for matching many patterns against text.
+///
+/// Nodes are identified by indexes, starting with [`Trie::ROOT`]. Patterns are numbered in the
+/// order they are added, with duplicate patterns keeping the first index.
+///
+/// Only bytes which appear in the patterns are given child slots, so tries over small alphabets,
+/// such as letters representing colors, stay compact and cache friendly.
+///
+/// # Examples
+/// ```
+/// # use utils::collections::Trie;
+/// let trie = Trie::new(&[b"r", b"wr", b"b", b"g", b"bwu", b"rb", b"gb", b"br"]);
+/// assert!(trie.contains(b"bwu"));
+/// assert!(!trie.contains(b"bw"));
+/// assert_eq!(trie.prefix_matches(b"brwrr").collect::<Vec<_>>(), vec![(1, 2), (2, 7)]);
+/// assert_eq!(trie.count_segmentations(b"gbbr"), 4);
+/// assert_eq!(trie.count_segmentations(b"ubwu"), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trie {
+    symbols: [u16; 256],
+    alphabet_len: usize,
+    children: Vec<u32>,
+    node_patterns: Vec<u32>,
+    pattern_lengths: Vec<usize>,
+}
+
+/// Builder for [`Trie`], which collects patterns before building the trie.
+///
+/// # Examples
+/// ```
+/// # use utils::collections::TrieBuilder;
+/// let mut builder = TrieBuilder::new();
+/// builder.insert(b"ab").insert(b"abc");
+/// let trie = builder.build();
+/// assert_eq!(trie.prefix_matches(b"abcd").collect::<Vec<_>>(), vec![(2, 0), (3, 1)]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TrieBuilder {
+    bytes: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl TrieBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pattern to the trie.
+    pub fn insert(&mut self, pattern: &[u8]) -> &mut Self {
+        self.bytes.extend_from_slice(pattern);
+        self.ends.push(self.bytes.len());
+        self
+    }
+
+    /// Build the trie from the inserted patterns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the trie has more than 2<sup>31</sup> nodes or [`u32::MAX`] patterns.
+    #[must_use]
+    pub fn build(&self) -> Trie {
+        let mut symbols = [NO_SYMBOL; 256];
+        let mut alphabet_len = 0;
+        for &b in &self.bytes {
+            if symbols[b as usize] == NO_SYMBOL {
+                symbols[b as usize] = u16::try_from(alphabet_len).unwrap();
+                alphabet_len += 1;
+            }
+        }
+
+        let mut trie = Trie {
+            symbols,
+            alphabet_len,
+            children: vec![0; alphabet_len],
+            node_patterns: vec![NO_PATTERN],
+            pattern_lengths: Vec::with_capacity(self.ends.len()),
+        };
+
+        let mut start = 0;
+        for &end in &self.ends {
+            let (mut node, mut slot) = (Trie::ROOT, None);
+            for &b in &self.bytes[start..end] {
+                let s = node * alphabet_len + symbols[b as usize] as usize;
+                node = if trie.children[s] == 0 {
+                    let child = trie.node_patterns.len();
+                    assert!(child < TERMINAL as usize, "too many trie nodes");
+                    trie.children[s] = u32::try_from(child).unwrap();
+                    trie.children.resize(trie.children.len() + alphabet_len, 0);
+                    trie.node_patterns.push(NO_PATTERN);
+                    child
+                } else {
+                    (trie.children[s] & !TERMINAL) as usize
+                };
+                slot = Some(s);
+            }
+
+            if let Some(slot) = slot {
+                trie.children[slot] |= TERMINAL;
+            }
+            if trie.node_patterns[node] == NO_PATTERN {
+                trie.node_patterns[node] = u32::try_from(trie.pattern_lengths.len()).unwrap();
+            }
+            trie.pattern_lengths.push(end - start);
+            start = end;
+        }
+
+        trie
+    }
+}
+
+impl Trie {
+    /// Index of the root node, which represents the empty prefix.
+    pub const ROOT: usize = 0;
+
+    /// Build a trie containing the provided patterns.
+    #[must_use]
+    pub fn new(patterns: &[&[u8]]) -> Self {
+        let mut builder = TrieBuilder::new();
+        for pattern in patterns {
+            builder.insert(pattern);
+        }
+        builder.build()
+    }
+
+    /// Returns the child of the provided node for the next byte, if any pattern continues with it.
+    #[inline]
+    #[must_use]
+    pub fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        self.child_entry(node, byte)
+            .map(|entry| (entry & !TERMINAL) as usize)
+    }
+
+    #[inline]
+    fn child_entry(&self, node: usize, byte: u8) -> Option<u32> {
+        let symbol = self.symbols[byte as usize];
+        if symbol == NO_SYMBOL {
+            return None;
+        }
+        match self.children[node * self.alphabet_len + symbol as usize] {
+            0 => None,
+            entry => Some(entry),
+        }
+    }
+
+    /// Returns the index of the pattern ending at the provided node, if any.
+    #[inline]
+    #[must_use]
+    pub fn pattern(&self, node: usize) -> Option<usize> {
+        match self.node_patterns[node] {
+            NO_PATTERN => None,
+            pattern => Some(pattern as usize),
+        }
+    }
+
+    /// Returns the length of the pattern with the provided index.
+    #[inline]
+    #[must_use]
+    pub fn pattern_len(&self, pattern: usize) -> usize {
+        self.pattern_lengths[pattern]
+    }
+
+    /// Returns the number of patterns added to the trie, including duplicates.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pattern_lengths.len()
+    }
+
+    /// Returns true if the trie contains no patterns.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pattern_lengths.is_empty()
+    }
+
+    /// Returns the number of nodes in the trie, including the root.
+    #[inline]
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.node_patterns.len()
+    }
+
+    /// Returns true if the provided pattern is in the trie.
+    #[must_use]
+    pub fn contains(&self, pattern: &[u8]) -> bool {
+        pattern
+            .iter()
+            .try_fold(Self::ROOT, |node, &b| self.child(node, b))
+            .is_some_and(|node| self.pattern(node).is_some())
+    }
+
+    /// Returns an iterator over the patterns which are prefixes of the provided text, yielding each
+    /// match's length and pattern index in increasing length order.
+    pub fn prefix_matches<'a>(
+        &'a self,
+        text: &'a [u8],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let mut node = Self::ROOT;
+        text.iter()
+            .map_while(move |&b| {
+                node = self.child(node, b)?;
+                Some(node)
+            })
+            .enumerate()
+            .filter_map(|(i, node)| Some((i + 1, self.pattern(node)?)))
+    }
+
+    /// Returns the number of ways the text can be split into a sequence of patterns.
+    ///
+    /// Counts are memoized for each suffix of the text, so this runs in `O(n * m)` time, where `m`
+    /// is the length of the longest pattern, even if there are exponentially many ways.
+    #[must_use]
+    pub fn count_segmentations(&self, text: &[u8]) -> u64 {
+        with_scratch(|ways: &mut Vec<u64>| {
+            // ways[i] is the number of ways to build the suffix of length i
+            ways.push(1);
+            for start in (0..text.len()).rev() {
+                let suffix_len = text.len() - start;
+                let mut node = Self::ROOT;
+                let mut count = 0;
+                for (i, &b) in text[start..].iter().enumerate() {
+                    let Some(entry) = self.child_entry(node, b) else {
+                        break;
+                    };
+                    node = (entry & !TERMINAL) as usize;
+                    count += u64::from(entry >> 31) * ways[suffix_len - 1 - i];
+                }
+                ways.push(count);
+            }
+            ways[text.len()]
+        })
+    }
+
+    /// Returns true if the text can be split into a sequence of patterns.
+    #[must_use]
+    pub fn can_segment(&self, text: &[u8]) -> bool {
+        with_scratch(|possible: &mut Vec<bool>| {
+            possible.push(true);
+            for start in (0..text.len()).rev() {
+                let suffix_len = text.len() - start;
+                let result = self
+                    .prefix_matches(&text[start..])
+                    .any(|(len, _)| possible[suffix_len - len]);
+                possible.push(result);
+            }
+            possible[text.len()]
+        })
+    }
+}
+
+/// Aho-Corasick automaton for finding every occurrence of many patterns in text in one pass.
+///
+/// # Examples
+/// ```
+/// # use utils::collections::AhoCorasick;
+/// let ac = AhoCorasick::new(&[b"he", b"she", b"his", b"hers"]);
+/// assert_eq!(
+///     ac.find_iter(b"ushers").collect::<Vec<_>>(),
+///     vec![(1..4, 1), (2..4, 0), (2..6, 3)],
+/// );
+/// assert_eq!(ac.count_matches(b"hishe"), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    trie: Trie,
+    transitions: Vec<u32>,
+    output_links: Vec<u32>,
+}
+
+impl AhoCorasick {
+    /// Build an automaton matching the provided patterns.
+    #[must_use]
+    pub fn new(patterns: &[&[u8]]) -> Self {
+        Self::from(Trie::new(patterns))
+    }
+
+    /// Returns the underlying trie.
+    #[must_use]
+    pub fn trie(&self) -> &Trie {
+        &self.trie
+    }
+
+    /// Returns an iterator over every match in the text, including overlapping matches, as the
+    /// byte range and pattern index.
+    ///
+    /// Matches are ordered by end position, and then from longest to shortest.
+    pub fn find_iter<'a>(
+        &'a self,
+        text: &'a [u8],
+    ) -> impl Iterator<Item = (std::ops::Range<usize>, usize)> + 'a {
+        let mut node = Trie::ROOT;
+        text.iter().enumerate().flat_map(move |(i, &b)| {
+            node = self.step(node, b);
+            let mut output = if self.trie.pattern(node).is_some() {
+                node
+            } else {
+                self.output_links[node] as usize
+            };
+            std::iter::from_fn(move || {
+                if output == Trie::ROOT {
+                    return None;
+                }
+                let pattern = self.trie.pattern(output).unwrap();
+                output = self.output_links[output] as usize;
+                Some((i + 1 - self.trie.pattern_len(pattern)..i + 1, pattern))
+            })
+        })
+    }
+
+    /// Returns the number of matches in the text, including overlapping matches.
+    #[must_use]
+    pub fn count_matches(&self, text: &[u8]) -> usize {
+        self.find_iter(text).count()
+    }
+
+    #[inline]
+    fn step(&self, node: usize, byte: u8) -> usize {
+        let symbol = self.trie.symbols[byte as usize];
+        if symbol == NO_SYMBOL {
+            Trie::ROOT
+        } else {
+            self.transitions[node * self.trie.alphabet_len + symbol as usize] as usize
+        }
+    }
+}
+
+impl From<Trie> for AhoCorasick {
+    fn from(trie: Trie) -> Self {
+        let alphabet_len = trie.alphabet_len;
+        let mut transitions: Vec<u32> = trie.children.iter().map(|&c| c & !TERMINAL).collect();
+        let mut failure_links = vec![0u32; trie.node_count()];
+        let mut output_links = vec![0u32; trie.node_count()];
+
+        // Breadth first, so failure links always point to already processed shallower nodes
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(Trie::ROOT);
+        while let Some(node) = queue.pop_front() {
+            let failure = failure_links[node] as usize;
+            for symbol in 0..alphabet_len {
+                let slot = node * alphabet_len + symbol;
+                let child = transitions[slot] as usize;
+                if child == 0 {
+                    transitions[slot] = if node == Trie::ROOT {
+                        0
+                    } else {
+                        transitions[failure * alphabet_len + symbol]
+                    };
+                    continue;
+                }
+
+                let child_failure = if node == Trie::ROOT {
+                    0
+                } else {
+                    transitions[failure * alphabet_len + symbol]
+                };
+                failure_links[child] = child_failure;
+                output_links[child] = if trie.pattern(child_failure as usize).is_some() {
+                    child_failure
+                } else {
+                    output_links[child_failure as usize]
+                };
+                queue.push_back(child);
+            }
+        }
+
+        Self {
+            trie,
+            transitions,
+            output_links,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[expect(clippy::cast_possible_truncation)]
+    fn aho_corasick_matches_naive_search() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move |n: u8| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % u64::from(n)) as u8
+        };
+
+        for _ in 0..100 {
+            let patterns: Vec<Vec<u8>> = (0..=random(10))
+                .map(|_| (0..=random(4)).map(|_| b'a' + random(3)).collect())
+                .collect();
+            let pattern_refs: Vec<&[u8]> = patterns.iter().map(Vec::as_slice).collect();
+            let text: Vec<u8> = (0..50).map(|_| b'a' + random(4)).collect();
+
+            let trie = Trie::new(&pattern_refs);
+            let first_index = |p: &[u8]| patterns.iter().position(|q| q.as_slice() == p).unwrap();
+            for (i, p) in patterns.iter().enumerate() {
+                assert!(trie.contains(p));
+                assert_eq!(trie.pattern_len(i), p.len());
+            }
+
+            let mut expected = Vec::new();
+            for end in 1..=text.len() {
+                let mut matches: Vec<_> = (0..end)
+                    .map(|start| start..end)
+                    .filter(|r| patterns.iter().any(|p| p.as_slice() == &text[r.clone()]))
+                    .map(|r| (r.clone(), first_index(&text[r])))
+                    .collect();
+                matches.sort_by_key(|(r, _)| r.start);
+                expected.extend(matches);
+            }
+
+            let ac = AhoCorasick::from(trie);
+            assert_eq!(ac.find_iter(&text).collect::<Vec<_>>(), expected);
+        }
+    }
+}
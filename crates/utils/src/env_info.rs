@@ -0,0 +1,69 @@
+//! Information about the machine and build, included in reports so results can be compared.
+
+use crate::multithreading::get_thread_count;
+use crate::multiversion::{selected_versions, Version};
+use std::num::NonZeroUsize;
+
+/// Snapshot of the environment solutions were run in.
+///
+/// Captured using [`EnvInfo::capture`], which should be called after running the solutions so the
+/// multiversion selections are included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvInfo {
+    /// CPU model name, if it could be determined.
+    pub cpu_model: Option<String>,
+    /// Number of logical cores available to the process.
+    pub cores: usize,
+    /// Number of worker threads used by multithreaded solutions.
+    pub threads: usize,
+    /// Operating system, such as `linux`.
+    pub os: &'static str,
+    /// CPU architecture, such as `x86_64`.
+    pub arch: &'static str,
+    /// Output of `rustc --version` for the compiler which built the binary, if available.
+    pub rustc_version: Option<&'static str>,
+    /// Git commit the binary was built from, if available.
+    pub git_hash: Option<&'static str>,
+    /// Module path and selected version for each dynamic dispatch site, in the order they were
+    /// evaluated.
+    pub multiversion: Vec<(&'static str, Version)>,
+}
+
+impl EnvInfo {
+    /// Capture the current environment.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::env_info::EnvInfo;
+    /// let info = EnvInfo::capture();
+    /// assert!(info.cores >= 1);
+    /// assert!(info.threads >= 1);
+    /// assert_eq!(info.arch, std::env::consts::ARCH);
+    /// ```
+    #[must_use]
+    pub fn capture() -> Self {
+        Self {
+            cpu_model: cpu_model(),
+            cores: std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            threads: get_thread_count().get(),
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            rustc_version: option_env!("UTILS_RUSTC_VERSION"),
+            git_hash: option_env!("UTILS_GIT_HASH"),
+            multiversion: selected_versions(),
+        }
+    }
+}
+
+fn cpu_model() -> Option<String> {
+    // Only Linux is supported, as other platforms require unsafe system calls
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        matches!(
+            key.trim(),
+            "model name" | "Processor" | "cpu model" | "machine"
+        )
+        .then(|| value.trim().to_string())
+    })
+}
@@ -0,0 +1,95 @@
+//! Lightweight tracing for walking through solutions step by step.
+//!
+//! Solutions can call [`explain!`](crate::explain!) to describe key intermediate values and
+//! decisions. Output is only printed when enabled with [`set_enabled`], which the
+//! [`aoc`](../../aoc/) binary does when run with `--explain`, so the calls can be left in
+//! solutions. When disabled, each call only costs a relaxed atomic load.
+//!
+//! Tracing is intended for small example inputs. Solutions should avoid explaining every step of
+//! loops which run many times on real inputs.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Enable or disable printing explanations.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if explanations are being printed.
+///
+/// Can be used to skip computing values which are only needed for explanations.
+#[inline]
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Print an explanation line at the current indentation level.
+///
+/// Prefer using [`explain!`](crate::explain!), which avoids formatting the arguments when disabled.
+pub fn print(args: std::fmt::Arguments) {
+    let depth = DEPTH.with(Cell::get);
+    println!("{:width$}{args}", "", width = depth * 2);
+}
+
+/// Print a heading and indent explanations until the returned guard is dropped.
+///
+/// # Examples
+/// ```
+/// # use utils::{explain, explain::section};
+/// utils::explain::set_enabled(true);
+/// {
+///     let _section = section(format_args!("Step {}", 1));
+///     explain!("indented");
+/// }
+/// explain!("not indented");
+/// ```
+#[must_use = "the section ends when the guard is dropped"]
+pub fn section(heading: std::fmt::Arguments) -> Section {
+    let enabled = is_enabled();
+    if enabled {
+        print(heading);
+        DEPTH.with(|d| d.set(d.get() + 1));
+    }
+    Section { enabled }
+}
+
+/// Guard returned by [`section`], which removes a level of indentation when dropped.
+#[derive(Debug)]
+pub struct Section {
+    enabled: bool,
+}
+
+impl Drop for Section {
+    fn drop(&mut self) {
+        if self.enabled {
+            DEPTH.with(|d| d.set(d.get().saturating_sub(1)));
+        }
+    }
+}
+
+/// Macro to print an explanation if enabled, using [`format!`] syntax.
+///
+/// See the [`explain`](crate::explain) module for more details.
+///
+/// # Examples
+/// ```
+/// # use utils::explain;
+/// let value = 5;
+/// explain!("value is {value}"); // Not printed unless enabled
+/// ```
+#[macro_export]
+macro_rules! explain {
+    ($($args:tt)+) => {
+        if $crate::explain::is_enabled() {
+            $crate::explain::print(format_args!($($args)+));
+        }
+    };
+}
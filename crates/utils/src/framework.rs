@@ -1,5 +1,7 @@
 use crate::date::{Day, Year};
+use crate::input::{InputError, InputType};
 use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
 
 /// Common trait implemented by puzzles to provide [`Year`] and [`Day`].
 ///
@@ -7,6 +9,10 @@ use std::fmt::{Debug, Display};
 pub trait Puzzle {
     const YEAR: Year;
     const DAY: Day;
+    /// One line summary of the solution, taken from the first line of the struct's doc comment.
+    const DESCRIPTION: &'static str;
+    /// Hash of the solution's source file, which changes whenever the solution is edited.
+    const SOURCE_HASH: u64;
 }
 
 /// Trait implemented by puzzles to provide example inputs and answers.
@@ -16,10 +22,280 @@ pub trait PuzzleExamples<P1: Debug + Display + 'static, P2: Debug + Display + 's
     const EXAMPLES: &'static [(&'static str, Option<P1>, Option<P2>)];
 }
 
+/// Trait implemented by puzzles which can generate their own inputs.
+///
+/// Generated inputs allow puzzles to be run without real inputs, for example in stress tests and
+/// benchmarks in CI. Implementations should return inputs in the same format as real inputs, and
+/// always return the same input for the same seed.
+///
+/// Implementations are detected automatically by the [`aoc`](../aoc/) crate's registry.
+pub trait PuzzleGenerator {
+    fn generate(seed: u64) -> String;
+}
+
+/// Helper used by the registry to find [`PuzzleGenerator`] implementations for concrete types.
+///
+/// The inherent `generator` method is only available when the type implements
+/// [`PuzzleGenerator`]. Otherwise, method resolution falls back to [`NoPuzzleGenerator`].
+#[doc(hidden)]
+pub struct GeneratorProbe<T>(PhantomData<T>);
+
+impl<T> GeneratorProbe<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for GeneratorProbe<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PuzzleGenerator> GeneratorProbe<T> {
+    #[must_use]
+    pub fn generator(&self) -> Option<fn(u64) -> String> {
+        Some(T::generate)
+    }
+}
+
+#[doc(hidden)]
+pub trait NoPuzzleGenerator {
+    #[must_use]
+    fn generator(&self) -> Option<fn(u64) -> String> {
+        None
+    }
+}
+
+impl<T> NoPuzzleGenerator for GeneratorProbe<T> {}
+
+/// Checks that a puzzle's `new`, `part1` and `part2` functions have the signatures expected by the
+/// [`aoc`](../aoc/) and `aoc_wasm` crates.
+///
+/// Called inside a `const` item by [`year!`](crate::year!) so that any signature drift is reported
+/// as a compile error in the year crate itself, instead of only when building the binaries.
+#[doc(hidden)]
+#[inline]
+pub const fn assert_puzzle<S: Puzzle + 'static, P1: Display, P2: Display>(
+    _new: fn(&'static str, InputType) -> Result<S, InputError>,
+    _part1: fn(&'static S) -> P1,
+    _part2: fn(&'static S) -> P2,
+) {
+}
+
+/// Checks that the days provided to [`year!`](crate::year!) are contiguous, start at 1, are within
+/// `1..=25` and match the module and struct names.
+///
+/// Called inside a `const` item by [`year!`](crate::year!) so mistakes when editing the macro
+/// invocation are reported as compile errors.
+///
+/// # Examples
+///
+/// ```
+/// const _: () = utils::assert_days(&[1, 2], &["day01", "day02"], &["Day01", "Day02"]);
+/// ```
+///
+/// ```compile_fail
+/// const _: () = utils::assert_days(&[1, 3], &["day01", "day03"], &["Day01", "Day03"]);
+/// ```
+///
+/// ```compile_fail
+/// const _: () = utils::assert_days(&[1, 2], &["day01", "day03"], &["Day01", "Day02"]);
+/// ```
+#[doc(hidden)]
+pub const fn assert_days(days: &[u8], modules: &[&str], structs: &[&str]) {
+    assert!(
+        days.len() == modules.len() && days.len() == structs.len(),
+        "expected the same number of days, modules and structs"
+    );
+
+    let mut i = 0;
+    while i < days.len() {
+        let day = days[i];
+        assert!(
+            day >= 1 && day <= 25,
+            "day numbers must be between 1 and 25"
+        );
+        assert!(
+            day as usize == i + 1,
+            "day numbers must be contiguous and start at 1"
+        );
+        assert!(
+            matches_day_name(modules[i].as_bytes(), *b"day", day),
+            "day module name doesn't match day number, expected `dayNN`"
+        );
+        assert!(
+            matches_day_name(structs[i].as_bytes(), *b"Day", day),
+            "day struct name doesn't match day number, expected `DayNN`"
+        );
+        i += 1;
+    }
+}
+
+/// Extracts the first line of the doc comment on `pub struct $name` in the provided source file.
+///
+/// Called by [`year!`](crate::year!) using the day's source file included with
+/// [`include_str!`], so doc comments are available to the binaries without parsing source files at
+/// runtime.
+///
+/// # Examples
+///
+/// ```
+/// const SOURCE: &str = "use std::fmt;\n\n/// Summary line.\n///\n/// Details.\n#[derive(Debug)]\npub struct Day01;";
+/// assert_eq!(utils::doc_summary(SOURCE, "Day01"), "Summary line.");
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = utils::doc_summary("#[derive(Debug)]\npub struct Day01;", "Day01");
+/// ```
+#[doc(hidden)]
+#[must_use]
+pub const fn doc_summary(source: &'static str, name: &str) -> &'static str {
+    let mut bytes = source.as_bytes();
+    let name = name.as_bytes();
+
+    // Find the most recent doc comment block before the struct
+    let mut summary: &[u8] = &[];
+    let mut in_doc_block = false;
+    while !bytes.is_empty() {
+        let mut end = 0;
+        while end < bytes.len() && bytes[end] != b'\n' {
+            end += 1;
+        }
+        let (line, rest) = bytes.split_at(end);
+        let line = strip_suffix(line, b"\r");
+        bytes = if rest.is_empty() {
+            rest
+        } else {
+            rest.split_at(1).1
+        };
+
+        if starts_with(line, b"///") {
+            if !in_doc_block {
+                summary = strip_prefix(strip_prefix(line, b"///"), b" ");
+                in_doc_block = true;
+            }
+        } else if starts_with(line, b"pub struct ") {
+            let rest = strip_prefix(line, b"pub struct ");
+            if starts_with(rest, name)
+                && (rest.len() == name.len() || !rest[name.len()].is_ascii_alphanumeric())
+            {
+                assert!(in_doc_block, "expected doc comment on day struct");
+                return match std::str::from_utf8(summary) {
+                    Ok(s) => s,
+                    Err(_) => panic!("invalid UTF-8 in doc comment"),
+                };
+            }
+            in_doc_block = false;
+        } else if !starts_with(line, b"#[") {
+            in_doc_block = false;
+        }
+    }
+
+    panic!("day struct not found in source file");
+}
+
+/// Hashes a day's source file using 64-bit FNV-1a.
+///
+/// Called by [`year!`](crate::year!) to implement [`Puzzle::SOURCE_HASH`] at compile time.
+///
+/// # Examples
+///
+/// ```
+/// const HASH: u64 = utils::source_hash("pub struct Day01;");
+/// assert_eq!(HASH, utils::source_hash("pub struct Day01;"));
+/// assert_ne!(HASH, utils::source_hash("pub struct Day02;"));
+/// assert_eq!(utils::source_hash(""), 0xcbf2_9ce4_8422_2325);
+/// ```
+#[doc(hidden)]
+#[must_use]
+pub const fn source_hash(source: &str) -> u64 {
+    let bytes = source.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Copies a string into a fixed-size array.
+///
+/// Used by [`year!`](crate::year!) so constants only contain the required part of a larger string.
+///
+/// # Examples
+///
+/// ```
+/// const SOURCE: &str = "Summary line.\nRest of the file";
+/// const BYTES: [u8; 13] = utils::str_array(SOURCE.split_at(13).0);
+/// assert_eq!(&BYTES, b"Summary line.");
+/// ```
+#[doc(hidden)]
+#[must_use]
+pub const fn str_array<const N: usize>(s: &str) -> [u8; N] {
+    assert!(s.len() == N, "string length doesn't match array length");
+    let bytes = s.as_bytes();
+    let mut output = [0; N];
+    let mut i = 0;
+    while i < N {
+        output[i] = bytes[i];
+        i += 1;
+    }
+    output
+}
+
+const fn starts_with(bytes: &[u8], prefix: &[u8]) -> bool {
+    if bytes.len() < prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn strip_prefix<'a>(bytes: &'a [u8], prefix: &[u8]) -> &'a [u8] {
+    if starts_with(bytes, prefix) {
+        bytes.split_at(prefix.len()).1
+    } else {
+        bytes
+    }
+}
+
+const fn strip_suffix<'a>(bytes: &'a [u8], suffix: &[u8]) -> &'a [u8] {
+    if bytes.len() >= suffix.len() {
+        let (start, end) = bytes.split_at(bytes.len() - suffix.len());
+        if starts_with(end, suffix) {
+            return start;
+        }
+    }
+    bytes
+}
+
+const fn matches_day_name(name: &[u8], prefix: [u8; 3], day: u8) -> bool {
+    name.len() == 5
+        && name[0] == prefix[0]
+        && name[1] == prefix[1]
+        && name[2] == prefix[2]
+        && name[3] == b'0' + day / 10
+        && name[4] == b'0' + day % 10
+}
+
 /// Macro to generate the crate root for each year crate, implementing common items.
 ///
 /// For each day, the module is declared, the struct re-exported and the [`Puzzle`] trait
-/// implemented.
+/// implemented, with [`Puzzle::DESCRIPTION`] extracted from the struct's doc comment and
+/// [`Puzzle::SOURCE_HASH`] calculated from the day's source file. A compile-time assertion is also
+/// generated for each day, checking that `new`, `part1` and `part2` have the expected signatures
+/// and that both parts return types implementing [`Display`]. The list of days is also checked at
+/// compile time to be contiguous from day 1, and to match the module and struct names.
 ///
 /// A `puzzle!` macro is defined and exported, which takes one or more callback macro paths and a
 /// list of arguments captured as `tt` fragments. The macro expands to calling the first callback
@@ -52,9 +328,44 @@ macro_rules! year {
                 const YEAR: $crate::date::Year = $crate::date::Year::new_const::<$year>();
                 #[doc = concat!("Day ", $day)]
                 const DAY: $crate::date::Day = $crate::date::Day::new_const::<$day>();
+                const DESCRIPTION: &'static str = {
+                    // Copy the summary into its own array, as the slice returned by doc_summary
+                    // would otherwise keep the entire source file in the binary
+                    const SUMMARY: &str = $crate::doc_summary(
+                        include_str!(concat!(
+                            env!("CARGO_MANIFEST_DIR"),
+                            "/src/",
+                            stringify!($day_mod),
+                            ".rs",
+                        )),
+                        stringify!($day_struct),
+                    );
+                    const BYTES: [u8; SUMMARY.len()] = $crate::str_array(SUMMARY);
+                    match ::std::str::from_utf8(&BYTES) {
+                        Ok(s) => s,
+                        Err(_) => panic!("invalid UTF-8 in doc comment"),
+                    }
+                };
+                const SOURCE_HASH: u64 = $crate::source_hash(include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/src/",
+                    stringify!($day_mod),
+                    ".rs",
+                )));
             }
+            const _: () = $crate::assert_puzzle(
+                $day_struct::new,
+                $day_struct::part1,
+                $day_struct::part2,
+            );
         )+
 
+        const _: () = $crate::assert_days(
+            &[$($day),+],
+            &[$(stringify!($day_mod)),+],
+            &[$(stringify!($day_struct)),+],
+        );
+
         /// Macro which supplies a list of implemented puzzle solutions in this crate.
         ///
         /// Automatically generated by [utils::year!]. Refer to its documentation for more details.
@@ -118,15 +429,77 @@ macro_rules! puzzles_noop {
 ///     {file: "day01_example.txt", part2: 483},
 /// ]);
 /// ```
+///
+/// Inputs which `new` should reject can be provided using `should_error` with a fragment of the
+/// expected error message. These are only used to generate a test, and aren't included in
+/// [`PuzzleExamples::EXAMPLES`]:
+///
+/// ```ignore
+/// examples!(Day01 -> (u32, u64) [
+///     {input: "ABCDEF", part1: 30, part2: 342},
+///     {input: "ABC?EF", should_error: "invalid character"},
+/// ]);
+/// ```
+///
+/// When `utils` is built with the `compact` feature, [`PuzzleExamples::EXAMPLES`] is always empty
+/// and example files aren't included in the binary.
 #[macro_export]
 macro_rules! examples {
-    ($day:ident$(<$lifetime:lifetime>)? -> ($p1:ty, $p2:ty) [$($($tail:tt,)+)?]) => {
+    ($day:ident$(<$lifetime:lifetime>)? -> ($p1:ty, $p2:ty) [$($tail:tt,)*]) => {
+        $crate::examples!(@split [$day$(<$lifetime>)?] ($p1, $p2) [] [] $($tail,)*);
+    };
+
+    // Separate error cases from examples, which aren't included in EXAMPLES
+    (@split $day:tt $types:tt [$($ex:tt)*] [$($err:tt)*]
+        {input: $str:literal, should_error: $msg:literal $(,)?}, $($rest:tt)*
+    ) => {
+        $crate::examples!(@split $day $types [$($ex)*] [$($err)* ($str, $msg)] $($rest)*);
+    };
+    (@split $day:tt $types:tt [$($ex:tt)*] [$($err:tt)*]
+        {file: $file:literal, should_error: $msg:literal $(,)?}, $($rest:tt)*
+    ) => {
+        $crate::examples!(@split $day $types [$($ex)*] [$($err)* (
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $file)).trim_ascii_end(),
+            $msg,
+        )] $($rest)*);
+    };
+    (@split $day:tt $types:tt [$($ex:tt)*] [$($err:tt)*] $item:tt, $($rest:tt)*) => {
+        $crate::examples!(@split $day $types [$($ex)* $item] [$($err)*] $($rest)*);
+    };
+    (@split [$day:ident$(<$lifetime:lifetime>)?] ($p1:ty, $p2:ty)
+        [$($($tail:tt)+)?] [$($($err:tt)+)?]
+    ) => {
         impl $crate::PuzzleExamples<$p1, $p2> for $day$(<$lifetime>)? {
-            const EXAMPLES: &'static [(&'static str, Option<$p1>, Option<$p2>)] = &[$($(
-                $crate::examples!(@item $tail)
-            ),+)?];
+            const EXAMPLES: &'static [(&'static str, Option<$p1>, Option<$p2>)] =
+                $crate::examples_slice!($($($crate::examples!(@item $tail)),+)?);
         }
 
+        $(
+        #[cfg(test)]
+        mod example_error_tests {
+            use $crate::input::InputType;
+            use super::$day;
+
+            #[test]
+            fn should_error() {
+                let cases: &[(&str, &str)] = &[$($err),+];
+                for (i, &(input, fragment)) in cases.iter().enumerate() {
+                    match $day::new(input, InputType::Example) {
+                        Ok(_) => panic!("new succeeded for error example {i}: {input:?}"),
+                        Err(err) => {
+                            let message = err.to_string();
+                            assert!(
+                                message.contains(fragment),
+                                "error for error example {i} doesn't contain {fragment:?}: \
+                                {input:?}\n{message}",
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        )?
+
         $(
         #[cfg(test)]
         mod example_tests {
@@ -211,3 +584,25 @@ macro_rules! examples {
     };
     (@ignore $($tail:tt)*) => {};
 }
+
+/// Expands to a slice of the provided examples, or an empty slice when built with the `compact`
+/// feature.
+///
+/// Defined in `utils` so the feature is checked here instead of in each year crate.
+#[cfg(not(feature = "compact"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! examples_slice {
+    ($($item:expr),*) => {
+        &[$($item),*]
+    };
+}
+
+#[cfg(feature = "compact")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! examples_slice {
+    ($($item:expr),*) => {
+        &[]
+    };
+}
@@ -0,0 +1,553 @@
+//! Breadth-first search over large integer state spaces.
+//!
+//! [`HashSet`](std::collections::HashSet) based visited sets use several times more memory than
+//! the states themselves, and become slow once they no longer fit in cache. For searches over
+//! billions of states encoded as [`u64`]s, this module instead stores each level as a sorted
+//! [`Frontier`], and the visited states as a [`VisitedSet`] of sorted runs, which can optionally be
+//! spilled to disk. Duplicates are removed in batches by sorting and merging, which is sequential
+//! and cache friendly.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Batch of states, deduplicated by sorting.
+///
+/// States are pushed in any order, then [`Frontier::sort_dedup`] sorts them and removes duplicates.
+/// Methods which search the frontier require it to be sorted.
+///
+/// # Examples
+/// ```
+/// # use utils::frontier::Frontier;
+/// let mut frontier = Frontier::new();
+/// frontier.extend([5, 3, 5, 1, 3]);
+/// frontier.sort_dedup();
+/// assert_eq!(frontier.as_slice(), [1, 3, 5]);
+/// assert!(frontier.contains(3));
+/// assert!(!frontier.contains(4));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frontier {
+    states: Vec<u64>,
+    sorted: bool,
+}
+
+impl Frontier {
+    /// Creates a new empty frontier.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            sorted: true,
+        }
+    }
+
+    /// Add a state to the frontier.
+    #[inline]
+    pub fn push(&mut self, state: u64) {
+        self.states.push(state);
+        self.sorted = false;
+    }
+
+    /// Sort the states and remove duplicates.
+    pub fn sort_dedup(&mut self) {
+        if !self.sorted {
+            self.states.sort_unstable();
+            self.states.dedup();
+            self.sorted = true;
+        }
+    }
+
+    /// Returns the number of states, including duplicates if not sorted.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns true if the frontier contains no states.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Returns the states, which are only sorted and unique after [`Frontier::sort_dedup`].
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[u64] {
+        &self.states
+    }
+
+    /// Returns true if the sorted frontier contains the provided state.
+    #[must_use]
+    pub fn contains(&self, state: u64) -> bool {
+        debug_assert!(self.sorted, "frontier must be sorted");
+        self.states.binary_search(&state).is_ok()
+    }
+
+    /// Returns true if both sorted frontiers contain any of the same states.
+    #[must_use]
+    pub fn intersects(&self, other: &Frontier) -> bool {
+        debug_assert!(self.sorted && other.sorted, "frontiers must be sorted");
+        let (mut a, mut b) = (
+            self.states.iter().peekable(),
+            other.states.iter().peekable(),
+        );
+        while let (Some(&x), Some(&y)) = (a.peek(), b.peek()) {
+            match x.cmp(y) {
+                std::cmp::Ordering::Less => a.next(),
+                std::cmp::Ordering::Greater => b.next(),
+                std::cmp::Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Remove all states.
+    pub fn clear(&mut self) {
+        self.states.clear();
+        self.sorted = true;
+    }
+}
+
+impl Extend<u64> for Frontier {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        self.states.extend(iter);
+        self.sorted = false;
+    }
+}
+
+/// Set of visited states, stored as sorted runs.
+///
+/// Each inserted [`Frontier`] is stored as a new run, and runs of similar sizes are merged so the
+/// number of runs stays logarithmic in the number of states.
+///
+/// When created using [`VisitedSet::with_spill`], runs are written to temporary files once the
+/// number of states in memory exceeds the limit, and streamed back when removing visited states
+/// from a frontier. Files are deleted when the set is dropped. Spilling isn't supported on
+/// WebAssembly, which has no filesystem.
+///
+/// # Panics
+///
+/// Methods panic if reading or writing spill files fails.
+///
+/// # Examples
+/// ```
+/// # use utils::frontier::{Frontier, VisitedSet};
+/// let dir = std::env::temp_dir();
+/// for mut visited in [VisitedSet::new(), VisitedSet::with_spill(&dir, 4)] {
+///     for batch in [[1, 5, 9], [2, 4, 6], [3, 7, 8]] {
+///         let mut frontier = Frontier::new();
+///         frontier.extend(batch);
+///         frontier.sort_dedup();
+///         visited.insert(&frontier);
+///     }
+///     assert_eq!(visited.len(), 9);
+///
+///     let mut frontier = Frontier::new();
+///     frontier.extend([0, 4, 10, 7, 11]);
+///     frontier.sort_dedup();
+///     visited.remove_visited(&mut frontier);
+///     assert_eq!(frontier.as_slice(), [0, 10, 11]);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct VisitedSet {
+    memory: Vec<Vec<u64>>,
+    memory_len: usize,
+    disk: Vec<DiskRun>,
+    len: usize,
+    spill: Option<(PathBuf, usize)>,
+}
+
+impl VisitedSet {
+    /// Creates a new empty set, stored entirely in memory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new empty set, which spills runs to files in `dir` once more than `memory_limit`
+    /// states are stored in memory.
+    #[must_use]
+    pub fn with_spill(dir: impl Into<PathBuf>, memory_limit: usize) -> Self {
+        Self {
+            spill: Some((dir.into(), memory_limit)),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of states.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set contains no states.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the set contains the provided state.
+    ///
+    /// Runs spilled to disk are scanned, so prefer [`VisitedSet::remove_visited`] when checking many
+    /// states.
+    #[must_use]
+    pub fn contains(&self, state: u64) -> bool {
+        self.memory
+            .iter()
+            .any(|run| run.binary_search(&state).is_ok())
+            || self
+                .disk
+                .iter()
+                .any(|run| run.iter().take_while(|&s| s <= state).any(|s| s == state))
+    }
+
+    /// Remove the states which are in the set from the sorted frontier.
+    pub fn remove_visited(&self, frontier: &mut Frontier) {
+        debug_assert!(frontier.sorted, "frontier must be sorted");
+        for run in &self.memory {
+            if frontier.states.is_empty() {
+                return;
+            }
+            if frontier.states.len() * 16 < run.len() {
+                frontier.states.retain(|s| run.binary_search(s).is_err());
+            } else {
+                retain_absent(&mut frontier.states, run.iter().copied());
+            }
+        }
+        for run in &self.disk {
+            if frontier.states.is_empty() {
+                return;
+            }
+            retain_absent(&mut frontier.states, run.iter());
+        }
+    }
+
+    /// Insert the states in the sorted frontier, which must not already be in the set.
+    pub fn insert(&mut self, frontier: &Frontier) {
+        debug_assert!(frontier.sorted, "frontier must be sorted");
+        if frontier.is_empty() {
+            return;
+        }
+
+        self.len += frontier.len();
+        self.memory_len += frontier.len();
+        self.memory.push(frontier.states.clone());
+        while let [.., a, b] = self.memory.as_slice() {
+            if a.len() > 2 * b.len() {
+                break;
+            }
+            let (b, a) = (self.memory.pop().unwrap(), self.memory.pop().unwrap());
+            let mut merged = Vec::with_capacity(a.len() + b.len());
+            merge(a.into_iter(), b.into_iter(), |s| merged.push(s));
+            self.memory.push(merged);
+        }
+
+        if let Some((dir, limit)) = &self.spill {
+            if self.memory_len > *limit {
+                self.spill_memory(&dir.clone());
+            }
+        }
+    }
+
+    fn spill_memory(&mut self, dir: &Path) {
+        let mut merged = self.memory.pop().unwrap_or_default();
+        while let Some(run) = self.memory.pop() {
+            let mut next = Vec::with_capacity(merged.len() + run.len());
+            merge(run.into_iter(), merged.into_iter(), |s| next.push(s));
+            merged = next;
+        }
+        self.memory_len = 0;
+        self.disk.push(DiskRun::write(dir, merged.len(), merged));
+
+        while let [.., a, b] = self.disk.as_slice() {
+            if a.len > 2 * b.len {
+                break;
+            }
+            let (b, a) = (self.disk.pop().unwrap(), self.disk.pop().unwrap());
+            // Stream the merge to avoid loading both runs into memory
+            let run = DiskRun::write_with(dir, a.len + b.len, |out| merge(a.iter(), b.iter(), out));
+            self.disk.push(run);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DiskRun {
+    path: PathBuf,
+    len: usize,
+}
+
+impl DiskRun {
+    fn write(dir: &Path, len: usize, states: Vec<u64>) -> Self {
+        Self::write_with(dir, len, |out| states.into_iter().for_each(out))
+    }
+
+    fn write_with(dir: &Path, len: usize, f: impl FnOnce(&mut dyn FnMut(u64))) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = dir.join(format!(
+            "aoc-frontier-{}-{}.bin",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        let file = File::create(&path).expect("failed to create spill file");
+        let mut writer = BufWriter::new(file);
+        let mut result = Ok(());
+        f(&mut |s| {
+            if result.is_ok() {
+                result = writer.write_all(&s.to_le_bytes());
+            }
+        });
+        result
+            .and_then(|()| writer.flush())
+            .expect("failed to write spill file");
+
+        Self { path, len }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = u64> {
+        let file = File::open(&self.path).expect("failed to open spill file");
+        let mut reader = BufReader::new(file);
+        std::iter::from_fn(move || {
+            let mut buf = [0u8; 8];
+            match reader.read_exact(&mut buf) {
+                Ok(()) => Some(u64::from_le_bytes(buf)),
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => None,
+                Err(err) => panic!("failed to read spill file: {err}"),
+            }
+        })
+    }
+}
+
+impl Drop for DiskRun {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn retain_absent(states: &mut Vec<u64>, mut run: impl Iterator<Item = u64>) {
+    let mut next = run.next();
+    states.retain(|&s| {
+        while next.is_some_and(|r| r < s) {
+            next = run.next();
+        }
+        next != Some(s)
+    });
+}
+
+fn merge(a: impl Iterator<Item = u64>, b: impl Iterator<Item = u64>, mut out: impl FnMut(u64)) {
+    let (mut a, mut b) = (a.peekable(), b.peekable());
+    loop {
+        let s = match (a.peek(), b.peek()) {
+            (Some(&x), Some(&y)) if x <= y => {
+                if x == y {
+                    b.next();
+                }
+                a.next()
+            }
+            (Some(_) | None, Some(_)) => b.next(),
+            (Some(_), None) => a.next(),
+            (None, None) => return,
+        };
+        out(s.unwrap());
+    }
+}
+
+/// Level-synchronous breadth-first search using a [`Frontier`] and [`VisitedSet`].
+///
+/// Each call to [`Bfs::step`] expands every state in the current frontier, then removes duplicates
+/// and visited states in one batch.
+///
+/// # Examples
+/// ```
+/// # use utils::frontier::Bfs;
+/// // Reachable numbers using x -> 2x and x -> x + 3, up to 20
+/// let mut bfs = Bfs::new([1]);
+/// let expand = |x, next: &mut utils::frontier::Frontier| {
+///     for y in [x * 2, x + 3] {
+///         if y <= 20 { next.push(y); }
+///     }
+/// };
+///
+/// assert!(bfs.step(expand));
+/// assert_eq!(bfs.frontier().as_slice(), [2, 4]);
+/// assert!(bfs.step(expand));
+/// assert_eq!(bfs.frontier().as_slice(), [5, 7, 8]);
+///
+/// while bfs.step(expand) {}
+/// assert_eq!(bfs.visited().len(), 14);
+/// ```
+#[derive(Debug)]
+pub struct Bfs {
+    frontier: Frontier,
+    next: Frontier,
+    visited: VisitedSet,
+    depth: usize,
+}
+
+impl Bfs {
+    /// Creates a new search from the provided states, with an in-memory [`VisitedSet`].
+    #[must_use]
+    pub fn new(starts: impl IntoIterator<Item = u64>) -> Self {
+        Self::with_visited(starts, VisitedSet::new())
+    }
+
+    /// Creates a new search from the provided states, using the provided empty [`VisitedSet`].
+    #[must_use]
+    pub fn with_visited(starts: impl IntoIterator<Item = u64>, mut visited: VisitedSet) -> Self {
+        let mut frontier = Frontier::new();
+        frontier.extend(starts);
+        frontier.sort_dedup();
+        visited.remove_visited(&mut frontier);
+        visited.insert(&frontier);
+        Self {
+            frontier,
+            next: Frontier::new(),
+            visited,
+            depth: 0,
+        }
+    }
+
+    /// Returns the distance of the states in the current frontier.
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the sorted current frontier.
+    #[inline]
+    #[must_use]
+    pub fn frontier(&self) -> &Frontier {
+        &self.frontier
+    }
+
+    /// Returns the set of states visited so far, including the current frontier.
+    #[inline]
+    #[must_use]
+    pub fn visited(&self) -> &VisitedSet {
+        &self.visited
+    }
+
+    /// Advance to the next level, calling `expand` for each state in the current frontier to push
+    /// its neighbors.
+    ///
+    /// Returns false if the new frontier is empty, meaning the search is complete.
+    pub fn step(&mut self, mut expand: impl FnMut(u64, &mut Frontier)) -> bool {
+        self.next.clear();
+        for &state in &self.frontier.states {
+            expand(state, &mut self.next);
+        }
+        self.next.sort_dedup();
+        self.visited.remove_visited(&mut self.next);
+        self.visited.insert(&self.next);
+
+        std::mem::swap(&mut self.frontier, &mut self.next);
+        self.depth += 1;
+        !self.frontier.is_empty()
+    }
+}
+
+/// Bidirectional breadth-first search, returning the shortest distance between two searches.
+///
+/// `forward` pushes the successors of a state for `from_start`, and `backward` pushes the
+/// predecessors of a state for `from_goal`, which are the same for undirected graphs. The search
+/// with the smaller frontier is advanced each step, which explores far fewer states than a single
+/// search when the number of states grows exponentially with distance.
+///
+/// Both searches should be newly created. Returns [`None`] if either search is exhausted without
+/// meeting the other.
+///
+/// # Examples
+/// ```
+/// # use utils::frontier::{bidirectional, Bfs};
+/// // Shortest sequence of x -> 2x, x -> x + 1 and their inverses from 1 to 1000
+/// let distance = bidirectional(
+///     Bfs::new([1]),
+///     Bfs::new([1000]),
+///     |x, next| next.extend([x * 2, x + 1]),
+///     |x, next| {
+///         if x % 2 == 0 { next.push(x / 2); }
+///         if x > 0 { next.push(x - 1); }
+///     },
+/// );
+/// assert_eq!(distance, Some(14));
+/// ```
+pub fn bidirectional(
+    mut from_start: Bfs,
+    mut from_goal: Bfs,
+    mut forward: impl FnMut(u64, &mut Frontier),
+    mut backward: impl FnMut(u64, &mut Frontier),
+) -> Option<usize> {
+    loop {
+        // States in both frontiers are exactly from_start.depth from the start and from_goal.depth
+        // from the goal, so the first intersection gives the shortest distance
+        if from_start.frontier.intersects(&from_goal.frontier) {
+            return Some(from_start.depth + from_goal.depth);
+        }
+
+        let advanced = if from_start.frontier.len() <= from_goal.frontier.len() {
+            from_start.step(&mut forward)
+        } else {
+            from_goal.step(&mut backward)
+        };
+        if !advanced {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn visited_set_matches_hash_set() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 5000
+        };
+
+        let dir = std::env::temp_dir();
+        let mut sets = [VisitedSet::new(), VisitedSet::with_spill(&dir, 300)];
+        let mut expected = HashSet::new();
+        for i in 0..100 {
+            let mut frontier = Frontier::new();
+            frontier.extend((0..i * 2).map(|_| random()));
+            frontier.sort_dedup();
+            let mut remaining: Vec<u64> = frontier
+                .as_slice()
+                .iter()
+                .copied()
+                .filter(|s| !expected.contains(s))
+                .collect();
+            remaining.sort_unstable();
+
+            for set in &mut sets {
+                let mut frontier = frontier.clone();
+                set.remove_visited(&mut frontier);
+                assert_eq!(frontier.as_slice(), remaining);
+                set.insert(&frontier);
+                assert_eq!(set.len(), expected.len() + remaining.len());
+            }
+            expected.extend(remaining);
+        }
+
+        assert!(!sets[1].disk.is_empty());
+        for s in 0..5000 {
+            assert_eq!(sets[0].contains(s), expected.contains(&s));
+            assert_eq!(sets[1].contains(s), expected.contains(&s));
+        }
+    }
+}
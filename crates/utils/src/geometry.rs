@@ -0,0 +1,355 @@
+//! 2D geometry helpers for line segments, rectangles and wrapping positions.
+
+use crate::number::{Integer, Number, SignedInteger};
+use crate::point::Point2D;
+use std::cmp::Ordering;
+
+pub mod surface;
+
+/// Checks if the closed line segments `a` and `b` intersect, including touching end points and
+/// collinear overlaps.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::segments_intersect;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// assert!(segments_intersect((p(0, 0), p(4, 4)), (p(0, 4), p(4, 0))));
+/// assert!(segments_intersect((p(0, 0), p(4, 0)), (p(4, 0), p(4, 4))));
+/// assert!(segments_intersect((p(0, 0), p(4, 0)), (p(2, 0), p(6, 0))));
+/// assert!(!segments_intersect((p(0, 0), p(4, 0)), (p(5, 0), p(6, 0))));
+/// assert!(!segments_intersect((p(0, 0), p(4, 4)), (p(1, 0), p(5, 4))));
+/// ```
+#[must_use]
+pub fn segments_intersect<T: SignedInteger>(
+    a: (Point2D<T>, Point2D<T>),
+    b: (Point2D<T>, Point2D<T>),
+) -> bool {
+    let d1 = orientation(b.0, b.1, a.0);
+    let d2 = orientation(b.0, b.1, a.1);
+    let d3 = orientation(a.0, a.1, b.0);
+    let d4 = orientation(a.0, a.1, b.1);
+
+    if d1 != d2
+        && d1 != Ordering::Equal
+        && d2 != Ordering::Equal
+        && d3 != d4
+        && d3 != Ordering::Equal
+        && d4 != Ordering::Equal
+    {
+        return true;
+    }
+
+    (d1 == Ordering::Equal && within_bounds(b, a.0))
+        || (d2 == Ordering::Equal && within_bounds(b, a.1))
+        || (d3 == Ordering::Equal && within_bounds(a, b.0))
+        || (d4 == Ordering::Equal && within_bounds(a, b.1))
+}
+
+/// Returns the sign of the cross product `(b - a) x (c - a)`.
+#[inline]
+fn orientation<T: SignedInteger>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> Ordering {
+    let ab = b - a;
+    let ac = c - a;
+    (ab.x * ac.y).partial_cmp(&(ab.y * ac.x)).unwrap()
+}
+
+/// Checks if `p` is inside the bounding box of the segment `s`.
+#[inline]
+fn within_bounds<T: Integer>(s: (Point2D<T>, Point2D<T>), p: Point2D<T>) -> bool {
+    min(s.0.x, s.1.x) <= p.x
+        && p.x <= max(s.0.x, s.1.x)
+        && min(s.0.y, s.1.y) <= p.y
+        && p.y <= max(s.0.y, s.1.y)
+}
+
+#[inline]
+fn min<T: Integer>(a: T, b: T) -> T {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+#[inline]
+fn max<T: Integer>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Intersection between two paths returned by [`manhattan_path_intersections`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PathIntersection<T: SignedInteger> {
+    pub position: Point2D<T>,
+    /// Number of steps along the first path to reach the intersection.
+    pub steps_a: T::Unsigned,
+    /// Number of steps along the second path to reach the intersection.
+    pub steps_b: T::Unsigned,
+}
+
+/// Find all the points where two paths made of horizontal and vertical segments intersect.
+///
+/// Each path is provided as a list of vertices. Intersections are returned in order along the first
+/// path, including every integer point of any collinear overlaps. If a path crosses the same point
+/// multiple times, each combination of visits is returned.
+///
+/// # Panics
+/// This function panics if any segment is not horizontal or vertical.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::{manhattan_path_intersections, PathIntersection};
+/// # use utils::point::Point2D;
+/// let p = Point2D::<i32>::new;
+/// let a = [p(0, 0), p(8, 0), p(8, 5), p(3, 5), p(3, 2)];
+/// let b = [p(0, 0), p(0, 7), p(6, 7), p(6, 3), p(2, 3)];
+///
+/// let intersections = manhattan_path_intersections(&a, &b);
+/// assert_eq!(intersections, vec![
+///     PathIntersection { position: p(0, 0), steps_a: 0, steps_b: 0 },
+///     PathIntersection { position: p(6, 5), steps_a: 15, steps_b: 15 },
+///     PathIntersection { position: p(3, 3), steps_a: 20, steps_b: 20 },
+/// ]);
+/// ```
+#[must_use]
+pub fn manhattan_path_intersections<T: SignedInteger>(
+    a: &[Point2D<T>],
+    b: &[Point2D<T>],
+) -> Vec<PathIntersection<T>> {
+    let segments_a = path_segments(a);
+    let segments_b = path_segments(b);
+
+    let mut intersections = Vec::new();
+    for &(a0, a1, a_steps, a_skip_start) in &segments_a {
+        let mut a_points = Vec::new();
+        for &(b0, b1, b_steps, b_skip_start) in &segments_b {
+            let x_min = max(min(a0.x, a1.x), min(b0.x, b1.x));
+            let x_max = min(max(a0.x, a1.x), max(b0.x, b1.x));
+            let y_min = max(min(a0.y, a1.y), min(b0.y, b1.y));
+            let y_max = min(max(a0.y, a1.y), max(b0.y, b1.y));
+
+            let mut x = x_min;
+            while x <= x_max {
+                let mut y = y_min;
+                while y <= y_max {
+                    let position = Point2D::new(x, y);
+                    if !((a_skip_start && position == a0) || (b_skip_start && position == b0)) {
+                        a_points.push(PathIntersection {
+                            position,
+                            steps_a: a_steps + a0.manhattan_distance_from(position),
+                            steps_b: b_steps + b0.manhattan_distance_from(position),
+                        });
+                    }
+                    y += T::ONE;
+                }
+                x += T::ONE;
+            }
+        }
+
+        a_points.sort_by(|a, b| a.steps_a.partial_cmp(&b.steps_a).unwrap());
+        intersections.extend(a_points);
+    }
+
+    intersections
+}
+
+/// Returns each segment in the path with the number of steps before the segment, and whether the
+/// start point should be skipped as it is the end point of the previous segment.
+#[expect(clippy::type_complexity)]
+fn path_segments<T: SignedInteger>(
+    path: &[Point2D<T>],
+) -> Vec<(Point2D<T>, Point2D<T>, T::Unsigned, bool)> {
+    let mut steps = T::Unsigned::ZERO;
+    let mut segments = Vec::with_capacity(path.len());
+
+    match path {
+        [] => {}
+        &[p] => segments.push((p, p, steps, false)),
+        _ => {
+            for (i, w) in path.windows(2).enumerate() {
+                assert!(
+                    w[0].x == w[1].x || w[0].y == w[1].y,
+                    "segments must be horizontal or vertical"
+                );
+                segments.push((w[0], w[1], steps, i > 0));
+                steps += w[0].manhattan_distance_from(w[1]);
+            }
+        }
+    }
+
+    segments
+}
+
+/// Calculates the total area of the union of the provided rectangles using a sweep line.
+///
+/// Each rectangle is provided as a pair of the minimum (inclusive) and maximum (exclusive) corners.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::rectangle_union_area;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// assert_eq!(rectangle_union_area(&[(p(1, 3), p(5, 7)), (p(3, 1), p(7, 5))]), 28u32);
+/// assert_eq!(rectangle_union_area(&[(p(0, 0), p(2, 2)), (p(5, 5), p(6, 6))]), 5u32);
+/// assert_eq!(rectangle_union_area::<i32>(&[]), 0);
+/// ```
+#[must_use]
+pub fn rectangle_union_area<T: Integer>(rectangles: &[(Point2D<T>, Point2D<T>)]) -> T::Unsigned {
+    rectangle_coverage_area(rectangles, 1)
+}
+
+/// Calculates the total area covered by at least `min_count` of the provided rectangles using a
+/// sweep line.
+///
+/// Each rectangle is provided as a pair of the minimum (inclusive) and maximum (exclusive) corners.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::rectangle_coverage_area;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// let claims = [(p(1, 3), p(5, 7)), (p(3, 1), p(7, 5)), (p(5, 5), p(7, 7))];
+/// assert_eq!(rectangle_coverage_area(&claims, 1), 32u32);
+/// assert_eq!(rectangle_coverage_area(&claims, 2), 4u32);
+/// assert_eq!(rectangle_coverage_area(&claims, 3), 0u32);
+/// ```
+#[must_use]
+pub fn rectangle_coverage_area<T: Integer>(
+    rectangles: &[(Point2D<T>, Point2D<T>)],
+    min_count: usize,
+) -> T::Unsigned {
+    let mut ys = Vec::with_capacity(rectangles.len() * 2);
+    let mut events = Vec::with_capacity(rectangles.len() * 2);
+    for &(min, max) in rectangles {
+        if min.x >= max.x || min.y >= max.y {
+            continue;
+        }
+        ys.push(min.y);
+        ys.push(max.y);
+        events.push((min.x, true, min.y, max.y));
+        events.push((max.x, false, min.y, max.y));
+    }
+    ys.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    ys.dedup();
+    events.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let index = |y: T| {
+        ys.binary_search_by(|v| v.partial_cmp(&y).unwrap())
+            .expect("y coordinate should be in list")
+    };
+
+    let mut counts = vec![0usize; ys.len().saturating_sub(1)];
+    let mut area = T::Unsigned::ZERO;
+    let mut prev_x = events.first().map_or(T::ZERO, |e| e.0);
+    for (x, start, y0, y1) in events {
+        if x != prev_x {
+            let mut covered = T::Unsigned::ZERO;
+            for (i, &c) in counts.iter().enumerate() {
+                if c >= min_count {
+                    covered += ys[i].abs_diff(ys[i + 1]);
+                }
+            }
+            area += covered * prev_x.abs_diff(x);
+            prev_x = x;
+        }
+
+        for c in &mut counts[index(y0)..index(y1)] {
+            if start {
+                *c += 1;
+            } else {
+                *c -= 1;
+            }
+        }
+    }
+
+    area
+}
+
+/// Wraps each component of `position` into the range `0..bounds`, for positions on a torus.
+///
+/// Unlike the `%` operator, negative components wrap to positive values.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::torus_wrap;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// assert_eq!(torus_wrap(p(12, -1), p(11, 7)), p(1, 6));
+/// assert_eq!(torus_wrap(p(-22, 14), p(11, 7)), p(0, 0));
+/// ```
+#[inline]
+#[must_use]
+pub fn torus_wrap<T: Number>(position: Point2D<T>, bounds: Point2D<T>) -> Point2D<T> {
+    Point2D::new(
+        position.x.rem_euclid(bounds.x),
+        position.y.rem_euclid(bounds.y),
+    )
+}
+
+/// Moves `position` by `velocity` multiplied by `steps`, wrapping around on a torus with the
+/// provided `bounds`.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::torus_step;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// assert_eq!(torus_step(p(2, 4), p(2, -3), 5, p(11, 7)), p(1, 3));
+/// ```
+#[inline]
+#[must_use]
+pub fn torus_step<T: Number>(
+    position: Point2D<T>,
+    velocity: Point2D<T>,
+    steps: T,
+    bounds: Point2D<T>,
+) -> Point2D<T> {
+    torus_wrap(position + velocity * steps, bounds)
+}
+
+/// Wraps `position` into the rectangle provided as a pair of the minimum (inclusive) and maximum
+/// (exclusive) corners.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::rectangle_wrap;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// let rectangle = (p(1, 1), p(5, 4));
+/// assert_eq!(rectangle_wrap(p(0, 1), rectangle), p(4, 1));
+/// assert_eq!(rectangle_wrap(p(5, 4), rectangle), p(1, 1));
+/// assert_eq!(rectangle_wrap(p(3, 2), rectangle), p(3, 2));
+/// ```
+#[inline]
+#[must_use]
+pub fn rectangle_wrap<T: Number>(
+    position: Point2D<T>,
+    (min, max): (Point2D<T>, Point2D<T>),
+) -> Point2D<T> {
+    min + torus_wrap(position - min, max - min)
+}
+
+/// Moves `position` by `velocity` multiplied by `steps`, wrapping around within the rectangle
+/// provided as a pair of the minimum (inclusive) and maximum (exclusive) corners.
+///
+/// # Examples
+/// ```
+/// # use utils::geometry::rectangle_step;
+/// # use utils::point::Point2D;
+/// let p = Point2D::new;
+/// assert_eq!(rectangle_step(p(1, 1), p(-1, 0), 3, (p(1, 1), p(5, 4))), p(2, 1));
+/// ```
+#[inline]
+#[must_use]
+pub fn rectangle_step<T: Number>(
+    position: Point2D<T>,
+    velocity: Point2D<T>,
+    steps: T,
+    rectangle: (Point2D<T>, Point2D<T>),
+) -> Point2D<T> {
+    rectangle_wrap(position + velocity * steps, rectangle)
+}
@@ -0,0 +1,536 @@
+//! Movement over the surface of a sparse grid, wrapping around its edges.
+//!
+//! A [`Surface`] is built from a grid where some cells are part of the surface and the rest are
+//! empty space. Stepping from a cell onto an adjacent surface cell moves normally, while stepping
+//! off the surface wraps to another edge, either:
+//!
+//! - [`Surface::flat`]: the opposite end of the same row or column.
+//! - [`Surface::cube`]: the adjacent face after folding the surface into a cube, detecting the
+//!   layout of the net automatically.
+//!
+//! Positions use [`Point2D`] with `x` as the column and `y` as the row, so `y` increases down the
+//! grid.
+
+use crate::collections::DeterministicHashMap;
+use crate::point::{Point2D, Point3D};
+
+/// Direction of movement over a [`Surface`], in clockwise order.
+///
+/// Casting to an integer returns 0 for right, 1 for down, 2 for left and 3 for up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Right, Self::Down, Self::Left, Self::Up];
+
+    /// Rotate 90 degrees clockwise.
+    #[inline]
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Rotate 90 degrees counterclockwise.
+    #[inline]
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Rotate 180 degrees.
+    #[inline]
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the change in column and row when moving one step in this direction.
+    #[inline]
+    #[must_use]
+    pub fn offset(self) -> Point2D<isize> {
+        match self {
+            Self::Right => Point2D::new(1, 0),
+            Self::Down => Point2D::new(0, 1),
+            Self::Left => Point2D::new(-1, 0),
+            Self::Up => Point2D::new(0, -1),
+        }
+    }
+}
+
+type Wraps = DeterministicHashMap<(Point2D<usize>, Direction), (Point2D<usize>, Direction)>;
+
+/// Surface of a sparse grid with precomputed wrapping transitions.
+///
+/// # Examples
+///
+/// Flat wrapping returns to the other end of the row or column:
+/// ```
+/// # use utils::geometry::surface::{Direction, Surface};
+/// # use utils::point::Point2D;
+/// //  .##
+/// //  ##.
+/// let cells = vec![false, true, true, true, true, false];
+/// let surface = Surface::flat(2, 3, cells);
+/// assert_eq!(
+///     surface.step(Point2D::new(2, 0), Direction::Right),
+///     (Point2D::new(1, 0), Direction::Right),
+/// );
+/// assert_eq!(
+///     surface.step(Point2D::new(0, 1), Direction::Up),
+///     (Point2D::new(0, 1), Direction::Up),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Surface {
+    rows: usize,
+    cols: usize,
+    cells: Vec<bool>,
+    wraps: Wraps,
+}
+
+impl Surface {
+    /// Create a surface where stepping off an edge wraps to the opposite end of the row or column.
+    ///
+    /// `cells` contains `rows * cols` values in row-major order, which are true for cells on the
+    /// surface.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cells` doesn't contain `rows * cols` values.
+    #[must_use]
+    pub fn flat(rows: usize, cols: usize, cells: Vec<bool>) -> Self {
+        let mut surface = Self::empty(rows, cols, cells);
+
+        for (pos, dir) in surface.edges() {
+            // Walk backwards to the other end of the row or column
+            let back = dir.reverse();
+            let mut target = pos;
+            while let Some(next) = surface.neighbor(target, back) {
+                target = next;
+            }
+            surface.wraps.insert((pos, dir), (target, dir));
+        }
+
+        surface
+    }
+
+    /// Create a surface where stepping off an edge moves onto the adjacent face of the cube formed
+    /// by folding the surface.
+    ///
+    /// `cells` contains `rows * cols` values in row-major order, which are true for cells on the
+    /// surface. The surface must be made up of six square faces aligned to a grid of the same size,
+    /// forming any of the cube's nets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cells` doesn't contain `rows * cols` values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::geometry::surface::{Direction, Surface};
+    /// # use utils::point::Point2D;
+    /// //  #
+    /// // ###
+    /// //  #
+    /// //  #
+    /// let cells = [".#.", "###", ".#.", ".#."]
+    ///     .iter()
+    ///     .flat_map(|row| row.bytes().map(|b| b == b'#'))
+    ///     .collect();
+    /// let surface = Surface::cube(4, 3, cells).unwrap();
+    ///
+    /// // Stepping up from the top face lands on the bottom face, still moving up
+    /// assert_eq!(
+    ///     surface.step(Point2D::new(1, 0), Direction::Up),
+    ///     (Point2D::new(1, 3), Direction::Up),
+    /// );
+    /// // Stepping up from the left face lands on the top face, now moving right
+    /// assert_eq!(
+    ///     surface.step(Point2D::new(0, 1), Direction::Up),
+    ///     (Point2D::new(1, 0), Direction::Right),
+    /// );
+    ///
+    /// let not_a_cube = vec![true; 6];
+    /// assert_eq!(Surface::cube(1, 6, not_a_cube).unwrap_err(), "surface is not a cube net");
+    /// ```
+    pub fn cube(rows: usize, cols: usize, cells: Vec<bool>) -> Result<Self, &'static str> {
+        let mut surface = Self::empty(rows, cols, cells);
+
+        let area = surface.cells.iter().filter(|&&c| c).count();
+        let size = (1..=area).find(|s| s * s * 6 >= area).unwrap_or(0);
+        if size == 0 || size * size * 6 != area || rows % size != 0 || cols % size != 0 {
+            return Err("surface area is not six square faces");
+        }
+
+        // Every face must be entirely on the surface or entirely empty
+        let (face_rows, face_cols) = (rows / size, cols / size);
+        let mut faces = vec![None; face_rows * face_cols];
+        for r in 0..rows {
+            for c in 0..cols {
+                let corner = (r / size * size) * cols + (c / size * size);
+                if surface.cells[r * cols + c] != surface.cells[corner] {
+                    return Err("surface faces are not aligned to the face size");
+                }
+            }
+        }
+
+        // Fold the net, tracking the outward normal of each face, and the directions of increasing
+        // columns and rows on that face
+        let start = (0..face_rows * face_cols)
+            .find(|&f| surface.cells[(f / face_cols * size) * cols + (f % face_cols * size)])
+            .unwrap();
+        faces[start] = Some(Face {
+            normal: Point3D::new(0, 0, 1),
+            right: Point3D::new(1, 0, 0),
+            down: Point3D::new(0, 1, 0),
+        });
+        let mut queue = vec![start];
+        while let Some(f) = queue.pop() {
+            let face = faces[f].unwrap();
+            let (fr, fc) = (f / face_cols, f % face_cols);
+            for dir in Direction::ALL {
+                let offset = dir.offset();
+                let (Some(nr), Some(nc)) = (
+                    fr.checked_add_signed(offset.y),
+                    fc.checked_add_signed(offset.x),
+                ) else {
+                    continue;
+                };
+                if nr >= face_rows || nc >= face_cols {
+                    continue;
+                }
+                let n = nr * face_cols + nc;
+                if faces[n].is_none() && surface.cells[(nr * size) * cols + (nc * size)] {
+                    faces[n] = Some(face.fold(dir));
+                    queue.push(n);
+                }
+            }
+        }
+
+        let faces: Vec<(usize, Face)> = faces
+            .iter()
+            .enumerate()
+            .filter_map(|(i, f)| f.map(|f| (i, f)))
+            .collect();
+        let mut normals: Vec<_> = faces.iter().map(|(_, f)| f.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        if faces.len() != 6 || normals.len() != 6 {
+            return Err("surface is not a cube net");
+        }
+
+        // Edges are mapped between faces using 3D coordinates of cell centers, doubled to keep them
+        // integers, so each face spans -size to size
+        let size_i = i32::try_from(size).expect("face size should fit in i32");
+        for (pos, dir) in surface.edges() {
+            let (_, face) = faces
+                .iter()
+                .find(|&&(f, _)| f == (pos.y / size) * face_cols + pos.x / size)
+                .unwrap();
+            let row = i32::try_from(pos.y % size).unwrap();
+            let col = i32::try_from(pos.x % size).unwrap();
+            let center = face.normal * size_i
+                + face.right * (2 * col + 1 - size_i)
+                + face.down * (2 * row + 1 - size_i);
+
+            let edge = face.direction(dir);
+            let target_center = center - face.normal + edge;
+            let &(target_index, target) = faces.iter().find(|(_, f)| f.normal == edge).unwrap();
+
+            let target_col = (dot(target_center, target.right) + size_i - 1) / 2;
+            let target_row = (dot(target_center, target.down) + size_i - 1) / 2;
+            let target_pos = Point2D::new(
+                target_index % face_cols * size + usize::try_from(target_col).unwrap(),
+                target_index / face_cols * size + usize::try_from(target_row).unwrap(),
+            );
+            let target_dir = Direction::ALL
+                .into_iter()
+                .find(|&d| target.direction(d) == Point3D::ORIGIN - face.normal)
+                .unwrap();
+            surface.wraps.insert((pos, dir), (target_pos, target_dir));
+        }
+
+        Ok(surface)
+    }
+
+    fn empty(rows: usize, cols: usize, cells: Vec<bool>) -> Self {
+        assert_eq!(cells.len(), rows * cols, "expected rows * cols cells");
+        Self {
+            rows,
+            cols,
+            cells,
+            wraps: Wraps::default(),
+        }
+    }
+
+    /// Returns the number of rows in the grid.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the grid.
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns true if the position is on the surface.
+    #[must_use]
+    pub fn contains(&self, pos: Point2D<usize>) -> bool {
+        pos.x < self.cols && pos.y < self.rows && self.cells[pos.y * self.cols + pos.x]
+    }
+
+    /// Step one cell from the provided position, returning the new position and direction.
+    ///
+    /// The direction only changes when wrapping around the edge of a cube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position isn't on the surface.
+    #[must_use]
+    pub fn step(&self, pos: Point2D<usize>, dir: Direction) -> (Point2D<usize>, Direction) {
+        assert!(self.contains(pos), "position should be on the surface");
+        match self.neighbor(pos, dir) {
+            Some(next) => (next, dir),
+            None => self.wraps[&(pos, dir)],
+        }
+    }
+
+    /// Returns the adjacent position if it is on the surface.
+    fn neighbor(&self, pos: Point2D<usize>, dir: Direction) -> Option<Point2D<usize>> {
+        let offset = dir.offset();
+        let next = Point2D::new(
+            pos.x.checked_add_signed(offset.x)?,
+            pos.y.checked_add_signed(offset.y)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// Returns every position and direction which steps off the surface.
+    fn edges(&self) -> Vec<(Point2D<usize>, Direction)> {
+        let mut edges = Vec::new();
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                let pos = Point2D::new(x, y);
+                if !self.contains(pos) {
+                    continue;
+                }
+                for dir in Direction::ALL {
+                    if self.neighbor(pos, dir).is_none() {
+                        edges.push((pos, dir));
+                    }
+                }
+            }
+        }
+        edges
+    }
+}
+
+/// Orientation of a face of the cube in 3D space.
+#[derive(Copy, Clone, Debug)]
+struct Face {
+    normal: Point3D<i32>,
+    right: Point3D<i32>,
+    down: Point3D<i32>,
+}
+
+impl Face {
+    /// Returns the 3D vector for a direction on this face.
+    fn direction(self, dir: Direction) -> Point3D<i32> {
+        match dir {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => Point3D::ORIGIN - self.right,
+            Direction::Up => Point3D::ORIGIN - self.down,
+        }
+    }
+
+    /// Returns the orientation of the adjacent face in the net after folding along the edge in the
+    /// provided direction.
+    fn fold(self, dir: Direction) -> Self {
+        let edge = self.direction(dir);
+        let inward = Point3D::ORIGIN - self.normal;
+        match dir {
+            Direction::Right | Direction::Left => Self {
+                normal: edge,
+                right: if dir == Direction::Right {
+                    inward
+                } else {
+                    self.normal
+                },
+                down: self.down,
+            },
+            Direction::Down | Direction::Up => Self {
+                normal: edge,
+                right: self.right,
+                down: if dir == Direction::Down {
+                    inward
+                } else {
+                    self.normal
+                },
+            },
+        }
+    }
+}
+
+fn dot(a: Point3D<i32>, b: Point3D<i32>) -> i32 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(net: &str) -> (usize, usize, Vec<bool>) {
+        let lines: Vec<&str> = net.lines().collect();
+        let cols = lines.iter().map(|l| l.len()).max().unwrap();
+        let cells = lines
+            .iter()
+            .flat_map(|l| (0..cols).map(|i| matches!(l.as_bytes().get(i), Some(b'.' | b'#'))))
+            .collect();
+        (lines.len(), cols, cells)
+    }
+
+    // All 11 nets of the cube
+    const NETS: [&str; 11] = [
+        ".#..\n####\n.#..",
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    fn scaled(net: &str, size: usize) -> String {
+        let mut result = String::new();
+        for line in net.lines() {
+            for _ in 0..size {
+                for c in line.chars() {
+                    for _ in 0..size {
+                        result.push(if c == '#' { '.' } else { ' ' });
+                    }
+                }
+                result.push('\n');
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn cube_nets() {
+        for net in NETS {
+            for size in [1, 2, 4] {
+                let (rows, cols, cells) = parse(&scaled(net, size));
+                let surface = Surface::cube(rows, cols, cells).unwrap();
+
+                for y in 0..rows {
+                    for x in 0..cols {
+                        let start = Point2D::new(x, y);
+                        if !surface.contains(start) {
+                            continue;
+                        }
+                        for dir in Direction::ALL {
+                            // Stepping and then stepping back in the opposite direction returns to
+                            // the start
+                            let (pos, d) = surface.step(start, dir);
+                            let (back, back_dir) = surface.step(pos, d.reverse());
+                            assert_eq!((back, back_dir), (start, dir.reverse()), "{net:?} {size}");
+
+                            // Walking straight around the cube returns to the start
+                            let (mut pos, mut d) = (start, dir);
+                            for _ in 0..4 * size {
+                                (pos, d) = surface.step(pos, d);
+                            }
+                            assert_eq!((pos, d), (start, dir), "{net:?} {size}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn not_cube_nets() {
+        for net in ["######", "####\n##..", "###\n###", ".#.\n###\n.#.\n##."] {
+            let (rows, cols, cells) = parse(net);
+            assert!(Surface::cube(rows, cols, cells).is_err(), "{net:?}");
+        }
+    }
+
+    const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.";
+
+    const EXAMPLE_PATH: &str = "10R5L5R10L4R5L5";
+
+    fn password(surface: &Surface, walls: &[bool]) -> usize {
+        let mut pos = Point2D::new(
+            (0..surface.cols())
+                .find(|&x| surface.contains(Point2D::new(x, 0)))
+                .unwrap(),
+            0,
+        );
+        let mut dir = Direction::Right;
+
+        let mut steps = 0;
+        for b in EXAMPLE_PATH.bytes().chain([b'E']) {
+            if b.is_ascii_digit() {
+                steps = steps * 10 + usize::from(b - b'0');
+                continue;
+            }
+
+            for _ in 0..steps {
+                let (next, next_dir) = surface.step(pos, dir);
+                if walls[next.y * surface.cols() + next.x] {
+                    break;
+                }
+                (pos, dir) = (next, next_dir);
+            }
+            steps = 0;
+
+            match b {
+                b'L' => dir = dir.turn_left(),
+                b'R' => dir = dir.turn_right(),
+                _ => {}
+            }
+        }
+
+        1000 * (pos.y + 1) + 4 * (pos.x + 1) + dir as usize
+    }
+
+    #[test]
+    fn example() {
+        let (rows, cols, cells) = parse(EXAMPLE);
+        let walls: Vec<bool> = EXAMPLE
+            .lines()
+            .flat_map(|l| (0..cols).map(move |i| l.as_bytes().get(i) == Some(&b'#')))
+            .collect();
+
+        let flat = Surface::flat(rows, cols, cells.clone());
+        assert_eq!(password(&flat, &walls), 6032);
+
+        let cube = Surface::cube(rows, cols, cells).unwrap();
+        assert_eq!(password(&cube, &walls), 5031);
+    }
+}
@@ -2,7 +2,17 @@
 
 use crate::bit::BitIterator;
 use crate::number::UnsignedInteger;
+use std::fmt::{Display, Write};
 use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+
+mod bellman_ford;
+mod csr;
+mod shortest_path;
+pub use bellman_ford::NegativeCycle;
+pub use csr::{CsrBuilder, CsrGraph};
+pub use shortest_path::{astar, astar_buckets, dijkstra, dijkstra_buckets};
 
 /// Explore all hamiltonian paths/cycles in a graph.
 ///
@@ -126,3 +136,100 @@ pub fn explore_hamiltonian_paths<E, P: Copy>(
         initial_path,
     );
 }
+
+/// Convert a directed graph to [Graphviz](https://graphviz.org/) DOT format.
+///
+/// `adjacency` contains the outgoing edges for each vertex. Vertices are labelled using `labels`,
+/// or their indices if `labels` is empty. Undirected graphs should include each edge in only one
+/// direction to avoid duplicate edges.
+///
+/// # Panics
+///
+/// This function panics if `labels` is not empty and has a different length to `adjacency`.
+///
+/// # Examples
+/// ```
+/// # use utils::graph::to_dot;
+/// let adjacency = [vec![1, 2], vec![2], vec![]];
+/// assert_eq!(
+///     to_dot(&adjacency, &["COM", "B", "C"]),
+///     "digraph {\n  0 [label=\"COM\"];\n  1 [label=\"B\"];\n  2 [label=\"C\"];\n  0 -> 1;\n  0 -> 2;\n  1 -> 2;\n}\n",
+/// );
+/// assert_eq!(
+///     to_dot(&adjacency, &[] as &[&str]),
+///     "digraph {\n  0;\n  1;\n  2;\n  0 -> 1;\n  0 -> 2;\n  1 -> 2;\n}\n",
+/// );
+/// ```
+#[must_use]
+pub fn to_dot<E: AsRef<[usize]>, L: Display>(adjacency: &[E], labels: &[L]) -> String {
+    assert!(
+        labels.is_empty() || labels.len() == adjacency.len(),
+        "labels must be empty or have one label per vertex"
+    );
+
+    let mut dot = String::from("digraph {\n");
+    for i in 0..adjacency.len() {
+        if let Some(label) = labels.get(i) {
+            let label = label.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(dot, "  {i} [label=\"{label}\"];").unwrap();
+        } else {
+            writeln!(dot, "  {i};").unwrap();
+        }
+    }
+    for (i, edges) in adjacency.iter().enumerate() {
+        for &j in edges.as_ref() {
+            writeln!(dot, "  {i} -> {j};").unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+static DUMP: Mutex<Option<(PathBuf, bool)>> = Mutex::new(None);
+
+/// Set the file graphs are written to by [`dump`], or [`None`] to disable dumping.
+///
+/// The [`aoc`](../../aoc/) binary sets this when run with `--dump-graph`.
+pub fn set_dump_path(path: Option<PathBuf>) {
+    *DUMP.lock().unwrap_or_else(PoisonError::into_inner) = path.map(|p| (p, false));
+}
+
+/// Returns true if graphs passed to [`dump`] are being written.
+///
+/// Can be used to skip building structures which are only needed for dumping.
+#[must_use]
+pub fn is_dumping() -> bool {
+    DUMP.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_some()
+}
+
+/// Write the DOT graph returned by `f` to the dump file, if enabled using [`set_dump_path`].
+///
+/// `f` is only called when enabled, so calls can be left in solutions. The first graph written
+/// replaces the file's contents, and later graphs are appended, which Graphviz renders as separate
+/// graphs.
+///
+/// # Panics
+///
+/// Panics if writing the file fails.
+pub fn dump(f: impl FnOnce() -> String) {
+    let mut guard = DUMP.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some((path, written)) = guard.as_mut() else {
+        return;
+    };
+
+    let dot = f();
+    let result = if *written {
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, dot.as_bytes()))
+    } else {
+        std::fs::write(&path, dot)
+    };
+    if let Err(err) = result {
+        panic!("failed to write graph to {}: {err}", path.display());
+    }
+    *written = true;
+}
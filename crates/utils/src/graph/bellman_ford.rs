@@ -0,0 +1,182 @@
+use super::CsrGraph;
+use crate::number::SignedInteger;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Error returned by [`CsrGraph::bellman_ford`] when a negative cycle is reachable from the
+/// source, making some distances unbounded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    /// Vertices in the cycle in edge order, starting from the lowest numbered vertex. The last
+    /// vertex has an edge back to the first.
+    pub cycle: Vec<usize>,
+}
+
+impl Display for NegativeCycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "negative cycle through vertices {:?}", self.cycle)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+impl<W: SignedInteger> CsrGraph<W> {
+    /// Find the shortest distance from the source to every vertex, allowing negative edge weights.
+    ///
+    /// Returns [`None`] for unreachable vertices, or a [`NegativeCycle`] error if a negative
+    /// cycle is reachable from the source. Runs in `O(VE)` time, stopping early once no distances
+    /// change, so prefer Dijkstra's algorithm when all weights are non-negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::graph::CsrGraph;
+    /// let graph = CsrGraph::from_weighted_edges(4, [(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2)]);
+    /// assert_eq!(graph.bellman_ford(0), Ok(vec![Some(0), Some(2), Some(5), Some(4)]));
+    /// assert_eq!(graph.bellman_ford(3), Ok(vec![None, None, None, Some(0)]));
+    ///
+    /// let cyclic = CsrGraph::from_weighted_edges(4, [(0, 1, 1), (1, 2, -2), (2, 3, -1), (3, 1, 1)]);
+    /// let err = cyclic.bellman_ford(0).unwrap_err();
+    /// assert_eq!(err.cycle, vec![1, 2, 3]);
+    /// assert_eq!(err.to_string(), "negative cycle through vertices [1, 2, 3]");
+    /// ```
+    pub fn bellman_ford(&self, source: usize) -> Result<Vec<Option<W>>, NegativeCycle> {
+        let vertices = self.vertex_count();
+        let mut distances = vec![None; vertices];
+        let mut predecessors = vec![usize::MAX; vertices];
+        distances[source] = Some(W::ZERO);
+
+        let mut changed = None;
+        for _ in 0..vertices {
+            changed = None;
+            for from in 0..vertices {
+                let Some(distance) = distances[from] else {
+                    continue;
+                };
+                for (to, weight) in self.edges(from) {
+                    let new = distance + weight;
+                    if distances[to].is_none_or(|d| new < d) {
+                        distances[to] = Some(new);
+                        predecessors[to] = from;
+                        changed = Some(to);
+                    }
+                }
+            }
+            if changed.is_none() {
+                return Ok(distances);
+            }
+        }
+
+        // Without negative cycles, all distances are final after `vertices - 1` passes. A vertex
+        // updated in the final pass must be reachable from a negative cycle, and following the
+        // predecessors `vertices` times from it is guaranteed to end inside the cycle.
+        let mut vertex = changed.unwrap();
+        for _ in 0..vertices {
+            vertex = predecessors[vertex];
+        }
+
+        let mut cycle = vec![vertex];
+        let mut previous = predecessors[vertex];
+        while previous != vertex {
+            cycle.push(previous);
+            previous = predecessors[previous];
+        }
+        cycle.reverse();
+
+        let min = cycle.iter().enumerate().min_by_key(|&(_, &v)| v).unwrap().0;
+        cycle.rotate_left(min);
+        Err(NegativeCycle { cycle })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    fn dijkstra(graph: &CsrGraph<i64>, source: usize) -> Vec<Option<i64>> {
+        let mut distances = vec![None; graph.vertex_count()];
+        let mut queue = BinaryHeap::from([Reverse((0, source))]);
+        while let Some(Reverse((distance, vertex))) = queue.pop() {
+            if distances[vertex].is_some() {
+                continue;
+            }
+            distances[vertex] = Some(distance);
+            for (to, weight) in graph.edges(vertex) {
+                if distances[to].is_none() {
+                    queue.push(Reverse((distance + weight, to)));
+                }
+            }
+        }
+        distances
+    }
+
+    fn random_graph(seed: u64, vertices: usize, edges: usize, min_weight: i64) -> CsrGraph<i64> {
+        let mut state = seed;
+        let mut random = move |n: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            usize::try_from(state % n as u64).unwrap()
+        };
+        let range = usize::try_from(100 - min_weight).unwrap();
+        CsrGraph::from_weighted_edges(
+            vertices,
+            (0..edges).map(|_| {
+                let weight = i64::try_from(random(range)).unwrap() + min_weight;
+                (random(vertices), random(vertices), weight)
+            }),
+        )
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        for seed in 1..50 {
+            let graph = random_graph(seed, 30, 80, 0);
+            for source in [0, 7, 29] {
+                assert_eq!(
+                    graph.bellman_ford(source),
+                    Ok(dijkstra(&graph, source)),
+                    "seed {seed}, source {source}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn negative_cycles() {
+        let mut cycles = 0;
+        for seed in 1..200 {
+            let graph = random_graph(seed, 20, 30, -20);
+            let Err(NegativeCycle { cycle }) = graph.bellman_ford(0) else {
+                continue;
+            };
+            cycles += 1;
+
+            let mut total = 0;
+            for (i, &from) in cycle.iter().enumerate() {
+                let to = cycle[(i + 1) % cycle.len()];
+                total += graph
+                    .edges(from)
+                    .filter(|&(t, _)| t == to)
+                    .map(|(_, w)| w)
+                    .min()
+                    .expect("cycle edge should exist");
+            }
+            assert!(total < 0, "seed {seed}: cycle {cycle:?} has weight {total}");
+        }
+        assert!(cycles > 10);
+    }
+
+    #[test]
+    fn negative_cycle_unreachable() {
+        // The cycle 2 -> 3 -> 2 is negative but can't be reached from 0
+        let graph =
+            CsrGraph::from_weighted_edges(4, [(0, 1, -5), (2, 3, 1), (3, 2, -2), (2, 1, 1)]);
+        assert_eq!(
+            graph.bellman_ford(0),
+            Ok(vec![Some(0), Some(-5), None, None])
+        );
+        assert_eq!(graph.bellman_ford(2).unwrap_err().cycle, vec![2, 3]);
+    }
+}
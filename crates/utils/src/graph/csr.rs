@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+/// Directed graph stored in compressed sparse row format.
+///
+/// The outgoing edges for every vertex are stored contiguously in one allocation, ordered by
+/// source vertex, and then by the order they were added. This avoids the per-vertex allocations
+/// and pointer chasing of `Vec<Vec<usize>>` adjacency lists.
+///
+/// Vertices are numbered from 0, and edge weights default to `()` for unweighted graphs.
+///
+/// # Examples
+/// ```
+/// # use utils::graph::CsrGraph;
+/// let graph = CsrGraph::from_edges(4, [(0, 1), (2, 3), (0, 2), (1, 2)]);
+/// assert_eq!(graph.vertex_count(), 4);
+/// assert_eq!(graph.edge_count(), 4);
+/// assert_eq!(graph.neighbors(0), &[1, 2]);
+/// assert_eq!(graph.neighbors(3), &[]);
+///
+/// let weighted = CsrGraph::from_weighted_edges(3, [(0, 1, 5), (1, 2, 7), (0, 2, 15)]);
+/// assert_eq!(weighted.edges(0).collect::<Vec<_>>(), vec![(1, 5), (2, 15)]);
+/// assert_eq!(weighted.weights(1), &[7]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrGraph<W = ()> {
+    offsets: Vec<u32>,
+    targets: Vec<u32>,
+    weights: Vec<W>,
+}
+
+/// Builder for [`CsrGraph`], collecting edges before sorting them by source vertex.
+///
+/// # Examples
+/// ```
+/// # use utils::graph::CsrBuilder;
+/// let mut builder = CsrBuilder::new(3);
+/// builder.add_undirected_edge(0, 1, 'a');
+/// builder.add_edge(2, 0, 'b');
+/// let graph = builder.build();
+/// assert_eq!(graph.edges(0).collect::<Vec<_>>(), vec![(1, 'a')]);
+/// assert_eq!(graph.edges(1).collect::<Vec<_>>(), vec![(0, 'a')]);
+/// assert_eq!(graph.edges(2).collect::<Vec<_>>(), vec![(0, 'b')]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsrBuilder<W = ()> {
+    vertices: usize,
+    edges: Vec<(u32, u32, W)>,
+}
+
+impl<W: Copy> CsrBuilder<W> {
+    /// Create a builder for a graph with the provided number of vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of vertices exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn new(vertices: usize) -> Self {
+        assert!(u32::try_from(vertices).is_ok(), "too many vertices");
+        Self {
+            vertices,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Add a directed edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either vertex is out of bounds.
+    #[inline]
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) -> &mut Self {
+        assert!(
+            from < self.vertices && to < self.vertices,
+            "edge {from} -> {to} out of bounds"
+        );
+        #[expect(clippy::cast_possible_truncation)]
+        self.edges.push((from as u32, to as u32, weight));
+        self
+    }
+
+    /// Add an edge in both directions.
+    #[inline]
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: W) -> &mut Self {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight)
+    }
+
+    /// Build the graph, preserving the order edges were added for each vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of edges exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn build(&self) -> CsrGraph<W> {
+        assert!(u32::try_from(self.edges.len()).is_ok(), "too many edges");
+
+        let mut edges = self.edges.clone();
+        edges.sort_by_key(|&(from, ..)| from);
+
+        let mut offsets = vec![0u32; self.vertices + 1];
+        for &(from, ..) in &edges {
+            offsets[from as usize + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        CsrGraph {
+            offsets,
+            targets: edges.iter().map(|&(_, to, _)| to).collect(),
+            weights: edges.iter().map(|&(.., weight)| weight).collect(),
+        }
+    }
+}
+
+impl CsrGraph<()> {
+    /// Build an unweighted graph from directed edges.
+    #[must_use]
+    pub fn from_edges(vertices: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut builder = CsrBuilder::new(vertices);
+        for (from, to) in edges {
+            builder.add_edge(from, to, ());
+        }
+        builder.build()
+    }
+}
+
+impl<W: Copy> CsrGraph<W> {
+    /// Build a weighted graph from directed edges.
+    #[must_use]
+    pub fn from_weighted_edges(
+        vertices: usize,
+        edges: impl IntoIterator<Item = (usize, usize, W)>,
+    ) -> Self {
+        let mut builder = CsrBuilder::new(vertices);
+        for (from, to, weight) in edges {
+            builder.add_edge(from, to, weight);
+        }
+        builder.build()
+    }
+
+    /// Returns the number of vertices.
+    #[inline]
+    #[must_use]
+    pub fn vertex_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the total number of edges.
+    #[inline]
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns the targets of the outgoing edges from the provided vertex.
+    #[inline]
+    #[must_use]
+    pub fn neighbors(&self, vertex: usize) -> &[u32] {
+        &self.targets[self.range(vertex)]
+    }
+
+    /// Returns the weights of the outgoing edges from the provided vertex, in the same order as
+    /// [`neighbors`](Self::neighbors).
+    #[inline]
+    #[must_use]
+    pub fn weights(&self, vertex: usize) -> &[W] {
+        &self.weights[self.range(vertex)]
+    }
+
+    /// Returns an iterator over the target and weight of each outgoing edge from the provided
+    /// vertex.
+    #[inline]
+    pub fn edges(&self, vertex: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        let range = self.range(vertex);
+        self.targets[range.clone()]
+            .iter()
+            .zip(&self.weights[range])
+            .map(|(&t, &w)| (t as usize, w))
+    }
+
+    /// Returns the number of outgoing edges from the provided vertex.
+    #[inline]
+    #[must_use]
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.range(vertex).len()
+    }
+
+    #[inline]
+    fn range(&self, vertex: usize) -> Range<usize> {
+        self.offsets[vertex] as usize..self.offsets[vertex + 1] as usize
+    }
+}
@@ -0,0 +1,440 @@
+use crate::collections::DeterministicHashMap;
+use crate::number::Integer;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Find the lowest cost path from `start` to a goal state using Dijkstra's algorithm.
+///
+/// `successors` returns the states reachable from a state, along with the non-negative cost of
+/// moving to each. The search stops at the first state for which `is_goal` returns true.
+///
+/// Returns the total cost and the path, including both the start and goal states, or [`None`] if
+/// no goal state is reachable. See [`astar`] to guide the search using a heuristic, and
+/// [`dijkstra_buckets`] for a faster variant when costs are small integers.
+///
+/// # Examples
+/// ```
+/// # use utils::graph::dijkstra;
+/// // Shortest path through a grid, where entering each cell costs the cell's value
+/// let grid = [
+///     [1, 1, 6],
+///     [1, 9, 1],
+///     [2, 1, 3],
+/// ];
+/// let (cost, path) = dijkstra(
+///     (0usize, 0usize),
+///     |&(r, c)| {
+///         [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)]
+///             .into_iter()
+///             .filter(|&(r, c)| r < 3 && c < 3)
+///             .map(|(r, c)| ((r, c), grid[r][c]))
+///     },
+///     |&pos| pos == (2, 2),
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+///
+/// // Odd numbers are unreachable when only adding 2
+/// let successors = |&x: &u32| [(x + 2, 1u32)].into_iter().filter(|&(x, _)| x <= 10);
+/// assert_eq!(dijkstra(0, successors, |&x| x == 5), None);
+/// ```
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(BinaryHeap::new(), start, successors, |_| C::ZERO, is_goal)
+}
+
+/// Find the lowest cost path from `start` to a goal state using A* search.
+///
+/// Behaves like [`dijkstra`], but states are explored in order of their cost plus `heuristic`,
+/// which must never overestimate the remaining cost to reach a goal for the result to be optimal.
+/// States are re-explored if a cheaper path to them is found later, so the heuristic doesn't need
+/// to be consistent, although consistent heuristics explore fewer states.
+///
+/// # Examples
+/// ```
+/// # use utils::graph::astar;
+/// // Shortest path around a wall, using the Manhattan distance as the heuristic
+/// let wall = |x: i32, y: i32| x == 2 && y < 4;
+/// let (cost, path) = astar(
+///     (0, 0),
+///     |&(x, y)| {
+///         [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+///             .into_iter()
+///             .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !wall(x, y))
+///             .map(|pos| (pos, 1u32))
+///     },
+///     |&(x, y)| (4 - x).unsigned_abs() + y.unsigned_abs(),
+///     |&pos| pos == (4, 0),
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 12);
+/// assert_eq!(path.len(), 13);
+/// assert!(path.contains(&(2, 4)));
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(BinaryHeap::new(), start, successors, heuristic, is_goal)
+}
+
+/// Variant of [`dijkstra`] using a bucket queue, optimized for small integer costs.
+///
+/// The queue stores one bucket per total cost, so pushing and popping states are constant time
+/// operations instead of logarithmic. This is usually faster than [`dijkstra`] when edge costs are
+/// small, but uses memory proportional to the cost of the path found.
+///
+/// # Panics
+///
+/// Panics if a total cost is negative or doesn't fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// # use utils::graph::{dijkstra, dijkstra_buckets};
+/// // Shortest sequence of +1 (cost 1) and *2 (cost 3) operations from 1 to 100
+/// let successors = |&x: &u32| [(x + 1, 1u32), (x * 2, 3)].into_iter().filter(|&(x, _)| x <= 100);
+/// let (cost, path) = dijkstra_buckets(1, successors, |&x| x == 100).unwrap();
+/// assert_eq!(cost, 18);
+/// assert_eq!(path[path.len() - 4..], [24, 25, 50, 100]);
+/// assert_eq!(dijkstra(1, successors, |&x| x == 100).unwrap().0, cost);
+/// ```
+pub fn dijkstra_buckets<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(
+        BucketQueue::default(),
+        start,
+        successors,
+        |_| C::ZERO,
+        is_goal,
+    )
+}
+
+/// Variant of [`astar`] using a bucket queue, optimized for small integer costs.
+///
+/// See [`dijkstra_buckets`] for the trade-offs compared to a binary heap.
+///
+/// # Panics
+///
+/// Panics if a total cost plus heuristic is negative or doesn't fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// # use utils::graph::astar_buckets;
+/// // Shortest sequence of -1 (cost 1) and +3 (cost 2) operations from 0 to 10
+/// let (cost, path) = astar_buckets(
+///     0i32,
+///     |&x| [(x - 1, 1u32), (x + 3, 2)],
+///     |&x| u32::try_from(10 - x).map_or(0, |d| d.div_ceil(3) * 2),
+///     |&x| x == 10,
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 10);
+/// assert_eq!(path.first(), Some(&0));
+/// assert_eq!(path.last(), Some(&10));
+/// ```
+pub fn astar_buckets<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(
+        BucketQueue::default(),
+        start,
+        successors,
+        heuristic,
+        is_goal,
+    )
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: usize,
+    cost: C,
+    heuristic: C,
+}
+
+fn search<S, C, I>(
+    mut queue: impl PriorityQueue<C>,
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut indices = DeterministicHashMap::default();
+    indices.insert(start.clone(), 0);
+    let mut nodes = vec![Node {
+        heuristic: heuristic(&start),
+        state: start,
+        parent: usize::MAX,
+        cost: C::ZERO,
+    }];
+    queue.push(nodes[0].heuristic, 0);
+
+    while let Some((priority, index)) = queue.pop() {
+        let Node {
+            ref state,
+            cost,
+            heuristic: h,
+            ..
+        } = nodes[index];
+        if cost + h != priority {
+            // Stale entry, a cheaper path to this state has since been found
+            continue;
+        }
+
+        if is_goal(state) {
+            let mut path = Vec::new();
+            let mut i = index;
+            while i != usize::MAX {
+                path.push(nodes[i].state.clone());
+                i = nodes[i].parent;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, edge_cost) in successors(state) {
+            debug_assert!(edge_cost >= C::ZERO, "edge costs must be non-negative");
+            let next_cost = cost + edge_cost;
+            match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if next_cost < node.cost {
+                        node.cost = next_cost;
+                        node.parent = index;
+                        queue.push(next_cost + node.heuristic, *entry.get());
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    let h = heuristic(entry.key());
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        parent: index,
+                        cost: next_cost,
+                        heuristic: h,
+                    });
+                    entry.insert(next_index);
+                    queue.push(next_cost + h, next_index);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+trait PriorityQueue<C> {
+    fn push(&mut self, priority: C, index: usize);
+    fn pop(&mut self) -> Option<(C, usize)>;
+}
+
+impl<C: Integer> PriorityQueue<C> for BinaryHeap<QueueEntry<C>> {
+    #[inline]
+    fn push(&mut self, priority: C, index: usize) {
+        BinaryHeap::push(self, QueueEntry { priority, index });
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<(C, usize)> {
+        BinaryHeap::pop(self).map(|e| (e.priority, e.index))
+    }
+}
+
+struct QueueEntry<C> {
+    priority: C,
+    index: usize,
+}
+
+// Reversed so the lowest priority is popped first, then the earliest discovered state
+impl<C: Integer> Ord for QueueEntry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .partial_cmp(&self.priority)
+            .unwrap()
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl<C: Integer> PartialOrd for QueueEntry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Integer> PartialEq for QueueEntry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<C: Integer> Eq for QueueEntry<C> {}
+
+struct BucketQueue<C> {
+    buckets: Vec<Vec<(C, usize)>>,
+    current: usize,
+}
+
+impl<C> Default for BucketQueue<C> {
+    fn default() -> Self {
+        Self {
+            buckets: Vec::new(),
+            current: 0,
+        }
+    }
+}
+
+impl<C: Integer> PriorityQueue<C> for BucketQueue<C> {
+    #[inline]
+    fn push(&mut self, priority: C, index: usize) {
+        let bucket = priority
+            .try_into()
+            .ok()
+            .and_then(|p| usize::try_from(p).ok())
+            .expect("bucket queue priorities must be non-negative and fit in usize");
+        if bucket >= self.buckets.len() {
+            self.buckets.resize_with(bucket + 1, Vec::new);
+        }
+        self.buckets[bucket].push((priority, index));
+
+        // Priorities only decrease with inconsistent heuristics
+        self.current = self.current.min(bucket);
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<(C, usize)> {
+        while let Some(bucket) = self.buckets.get_mut(self.current) {
+            if let Some(entry) = bucket.pop() {
+                return Some(entry);
+            }
+            self.current += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::CsrGraph;
+
+    fn random_graph(seed: u64, vertices: usize, edges: usize) -> CsrGraph<u32> {
+        let mut state = seed;
+        let mut random = move |n: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            usize::try_from(state % n as u64).unwrap()
+        };
+        CsrGraph::from_weighted_edges(
+            vertices,
+            (0..edges).map(|_| {
+                let weight = u32::try_from(random(10)).unwrap();
+                (random(vertices), random(vertices), weight)
+            }),
+        )
+    }
+
+    fn path_cost(graph: &CsrGraph<u32>, path: &[usize]) -> u32 {
+        path.windows(2)
+            .map(|w| {
+                graph
+                    .edges(w[0])
+                    .filter(|&(to, _)| to == w[1])
+                    .map(|(_, weight)| weight)
+                    .min()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn matches_bellman_ford() {
+        for seed in 1..50 {
+            let graph = random_graph(seed, 40, 100);
+            let successors = |&v: &usize| graph.edges(v).collect::<Vec<_>>();
+            let signed = CsrGraph::from_weighted_edges(
+                40,
+                (0..40).flat_map(|v| graph.edges(v).map(move |(to, w)| (v, to, i64::from(w)))),
+            );
+            let expected = signed.bellman_ford(0).unwrap();
+
+            for (goal, &expected) in expected.iter().enumerate() {
+                let results = [
+                    dijkstra(0, successors, |&v| v == goal),
+                    dijkstra_buckets(0, successors, |&v| v == goal),
+                    // Zero is a valid (if unhelpful) heuristic for every goal
+                    astar(0, successors, |_| 0, |&v| v == goal),
+                    astar_buckets(0, successors, |_| 0, |&v| v == goal),
+                ];
+                for result in results {
+                    assert_eq!(
+                        result.as_ref().map(|&(cost, _)| i64::from(cost)),
+                        expected,
+                        "seed {seed}, goal {goal}"
+                    );
+                    if let Some((cost, path)) = result {
+                        assert_eq!(path.first(), Some(&0));
+                        assert_eq!(path.last(), Some(&goal));
+                        assert_eq!(path_cost(&graph, &path), cost);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inconsistent_heuristic() {
+        // Admissible but inconsistent heuristic, which delays exploring 1 until after 2 has been
+        // explored through the more expensive direct edge, so 2 must be re-explored
+        let edges = [(0, 1, 1), (0, 2, 4), (1, 2, 1), (2, 3, 5)];
+        let graph = CsrGraph::from_weighted_edges(4, edges);
+        let successors = |&v: &usize| graph.edges(v).collect::<Vec<_>>();
+        let heuristic = |&v: &usize| [0, 6, 0, 0][v];
+
+        let expected = Some((7, vec![0, 1, 2, 3]));
+        assert_eq!(astar(0, successors, heuristic, |&v| v == 3), expected);
+        assert_eq!(
+            astar_buckets(0, successors, heuristic, |&v| v == 3),
+            expected
+        );
+    }
+}
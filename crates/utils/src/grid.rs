@@ -1,6 +1,8 @@
 //! Grid helpers.
 
 use crate::input::InputError;
+use crate::point::Point2D;
+use std::ops::{Index, IndexMut};
 
 /// Parse 2D grid.
 ///
@@ -143,6 +145,545 @@ pub fn from_str_padded<T: Clone>(
     Ok((rows, padded_columns, data))
 }
 
+/// 2D grid with padding around the edges, indexed using logical (unpadded) coordinates.
+///
+/// Coordinates are `(row, column)` relative to the first cell of the parsed input, so padding cells
+/// have negative coordinates or coordinates past [`rows`](Self::rows) and [`cols`](Self::cols).
+/// Translating coordinates to indices into the flat [`data`](Self::data) uses debug assertions to
+/// catch coordinates outside the padded grid, which would otherwise wrap onto another row.
+///
+/// Hot loops can still use raw indices with [`offset`](Self::offset), relying on the padding to
+/// avoid bounds checks when considering a cell's neighbors.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::grid::PaddedGrid;
+/// let grid = PaddedGrid::from_str("##.#\n#..#\n#.##", 1, false, |c| match c {
+///     b'#' => Some(true),
+///     b'.' => Some(false),
+///     _ => None,
+/// }).unwrap();
+/// assert_eq!((grid.rows(), grid.cols()), (3, 4));
+/// assert_eq!(grid[(0, 2)], false);
+/// assert_eq!(grid[(2, 3)], true);
+///
+/// // Neighbors of edge cells are padding
+/// assert_eq!(grid[(-1, 0)], false);
+/// assert_eq!(grid[(2, 4)], false);
+///
+/// // Raw indices can be moved using offsets and converted back to coordinates
+/// let index = grid.index(1, 3);
+/// assert_eq!(grid.position(index.wrapping_add_signed(grid.offset(1, -1))), (2, 2));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaddedGrid<T> {
+    rows: usize,
+    cols: usize,
+    padding: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> PaddedGrid<T> {
+    /// Parse 2D grid, adding padding around the edges.
+    ///
+    /// See [`from_str_padded`].
+    pub fn from_str(
+        input: &str,
+        padding: usize,
+        padding_value: T,
+        func: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, InputError> {
+        let (rows, cols, data) = from_str_padded(input, padding, padding_value, func)?;
+        Ok(Self {
+            rows: rows - 2 * padding,
+            cols: cols - 2 * padding,
+            padding,
+            data,
+        })
+    }
+}
+
+impl<T> PaddedGrid<T> {
+    /// Returns the number of rows, excluding padding.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns, excluding padding.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of padding rows and columns on each edge.
+    #[inline]
+    #[must_use]
+    pub fn padding(&self) -> usize {
+        self.padding
+    }
+
+    /// Returns the number of columns, including padding.
+    ///
+    /// This is the distance between vertically adjacent cells in [`data`](Self::data).
+    #[inline]
+    #[must_use]
+    pub fn padded_cols(&self) -> usize {
+        self.cols + 2 * self.padding
+    }
+
+    /// Returns the cells in row-major order, including padding.
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the cells in row-major order, including padding.
+    #[inline]
+    #[must_use]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the cells in row-major order, including padding.
+    #[inline]
+    #[must_use]
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns true if the coordinates are inside the grid, excluding padding.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        usize::try_from(row).is_ok_and(|r| r < self.rows)
+            && usize::try_from(col).is_ok_and(|c| c < self.cols)
+    }
+
+    /// Returns the index into [`data`](Self::data) for the provided coordinates.
+    ///
+    /// Coordinates inside the padding are allowed. Coordinates outside the padding are caught by a
+    /// debug assertion, and in release builds return an unspecified index.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn index(&self, row: isize, col: isize) -> usize {
+        let padding = self.padding as isize;
+        debug_assert!(
+            (-padding..(self.rows + self.padding) as isize).contains(&row)
+                && (-padding..(self.cols + self.padding) as isize).contains(&col),
+            "({row}, {col}) is outside the padded {}x{} grid",
+            self.rows,
+            self.cols,
+        );
+        ((row + padding) as usize) * self.padded_cols() + (col + padding) as usize
+    }
+
+    /// Returns the coordinates for the provided index into [`data`](Self::data).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn position(&self, index: usize) -> (isize, isize) {
+        assert!(index < self.data.len(), "index out of bounds");
+        let padded_cols = self.padded_cols();
+        (
+            (index / padded_cols) as isize - self.padding as isize,
+            (index % padded_cols) as isize - self.padding as isize,
+        )
+    }
+
+    /// Returns the difference between the indices of a cell and the cell `(rows, cols)` away.
+    ///
+    /// Adding the offset to an index using [`usize::wrapping_add_signed`] stays inside the grid as
+    /// long as the padding is at least as large as the distance moved from a non-padding cell.
+    #[inline]
+    #[must_use]
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn offset(&self, rows: isize, cols: isize) -> isize {
+        rows * self.padded_cols() as isize + cols
+    }
+
+    /// Returns an iterator over the indices of every cell, excluding padding, in row-major order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + use<'_, T> {
+        let padded_cols = self.padded_cols();
+        let start = self.padding * padded_cols + self.padding;
+        (0..self.rows).flat_map(move |r| {
+            let row_start = start + r * padded_cols;
+            row_start..row_start + self.cols
+        })
+    }
+}
+
+impl<T> Index<(isize, isize)> for PaddedGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): (isize, isize)) -> &Self::Output {
+        &self.data[self.index(row, col)]
+    }
+}
+
+impl<T> IndexMut<(isize, isize)> for PaddedGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (isize, isize)) -> &mut Self::Output {
+        let index = self.index(row, col);
+        &mut self.data[index]
+    }
+}
+
+/// Rectangular 2D grid stored in row-major order.
+///
+/// Cells are addressed using [`Point2D<usize>`], with `x` as the column and `y` as the row, so
+/// `y` increases downwards unlike [`Point2D::UP`].
+///
+/// # Examples
+///
+/// ```
+/// # use utils::grid::Grid;
+/// # use utils::point::Point2D;
+/// let mut grid = Grid::from_str("#S.\n.#.\n..E", |b| match b {
+///     b'#' | b'.' | b'S' | b'E' => Some(b),
+///     _ => None,
+/// }).unwrap();
+/// assert_eq!((grid.rows(), grid.cols()), (3, 3));
+///
+/// let start = grid.find(|&b| b == b'S').unwrap();
+/// assert_eq!(start, Point2D::new(1, 0));
+/// assert_eq!(grid[start], b'S');
+/// assert_eq!(grid.get(Point2D::new(3, 0)), None);
+///
+/// // Neighbors outside the grid are skipped
+/// assert_eq!(
+///     grid.neighbors4(start).collect::<Vec<_>>(),
+///     [Point2D::new(2, 0), Point2D::new(1, 1), Point2D::new(0, 0)],
+/// );
+/// assert_eq!(grid.neighbors8(Point2D::new(1, 1)).count(), 8);
+///
+/// grid[start] = b'.';
+/// assert_eq!(grid.row(0), b"#..");
+/// assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"..E");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from cells in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells doesn't match the dimensions.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            rows * cols,
+            data.len(),
+            "data length must equal rows * cols"
+        );
+        Self { rows, cols, data }
+    }
+
+    /// Parse 2D grid.
+    ///
+    /// See [`from_str`].
+    pub fn from_str(input: &str, func: impl FnMut(u8) -> Option<T>) -> Result<Self, InputError> {
+        let (rows, cols, data) = from_str(input, func)?;
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the cells in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the cells in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the cells in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns true if the point is inside the grid.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: Point2D<usize>) -> bool {
+        point.x < self.cols && point.y < self.rows
+    }
+
+    /// Returns the index into [`data`](Self::data) for the provided point, or [`None`] if it is
+    /// outside the grid.
+    #[inline]
+    #[must_use]
+    pub fn index_of(&self, point: Point2D<usize>) -> Option<usize> {
+        self.contains(point)
+            .then_some(point.y * self.cols + point.x)
+    }
+
+    /// Returns the point for the provided index into [`data`](Self::data).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn position(&self, index: usize) -> Point2D<usize> {
+        assert!(index < self.data.len(), "index out of bounds");
+        Point2D::new(index % self.cols, index / self.cols)
+    }
+
+    /// Returns a reference to the cell at the point, or [`None`] if it is outside the grid.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, point: Point2D<usize>) -> Option<&T> {
+        self.index_of(point).map(|i| &self.data[i])
+    }
+
+    /// Returns a mutable reference to the cell at the point, or [`None`] if it is outside the grid.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, point: Point2D<usize>) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.data[i])
+    }
+
+    /// Returns the cells in the provided row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row out of bounds");
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns an iterator over each row.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks_exact(self.cols.max(1))
+    }
+
+    /// Returns an iterator over the cells in the provided column, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column out of bounds");
+        self.data[col..].iter().step_by(self.cols)
+    }
+
+    /// Returns an iterator over the columns, each yielding cells from top to bottom.
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|c| self.column(c))
+    }
+
+    /// Returns the point of the first cell matching the predicate in row-major order.
+    #[must_use]
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point2D<usize>> {
+        self.data
+            .iter()
+            .position(predicate)
+            .map(|i| self.position(i))
+    }
+
+    /// Returns an iterator over the orthogonally adjacent points inside the grid, in clockwise
+    /// order starting from up.
+    pub fn neighbors4(&self, point: Point2D<usize>) -> impl Iterator<Item = Point2D<usize>> + '_ {
+        self.neighbors(point, 2)
+    }
+
+    /// Returns an iterator over the orthogonally and diagonally adjacent points inside the grid, in
+    /// clockwise order starting from up.
+    pub fn neighbors8(&self, point: Point2D<usize>) -> impl Iterator<Item = Point2D<usize>> + '_ {
+        self.neighbors(point, 1)
+    }
+
+    fn neighbors(
+        &self,
+        point: Point2D<usize>,
+        step: usize,
+    ) -> impl Iterator<Item = Point2D<usize>> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .step_by(step)
+            .filter_map(move |&(dr, dc)| {
+                let neighbor = Point2D::new(
+                    point.x.checked_add_signed(dc)?,
+                    point.y.checked_add_signed(dr)?,
+                );
+                self.contains(neighbor).then_some(neighbor)
+            })
+    }
+}
+
+impl<T> From<Grid<T>> for (usize, usize, Vec<T>) {
+    #[inline]
+    fn from(grid: Grid<T>) -> Self {
+        (grid.rows, grid.cols, grid.data)
+    }
+}
+
+impl<T> Index<Point2D<usize>> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point2D<usize>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Point2D<usize>> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point2D<usize>) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {rows}x{cols} grid"))
+    }
+}
+
+/// Parse 2D grid of bytes at compile time.
+///
+/// Const subset of [`from_str`] for inputs known at compile time, such as test fixtures, which
+/// avoids parsing them at runtime. Each byte must be contained in `valid`, and a single trailing
+/// newline is allowed. `N` must equal the number of cells, which can be calculated using
+/// [`const_len`]. The [`const_grid!`](crate::const_grid!) macro wraps both functions.
+///
+/// Returns (number of rows, number of columns, data).
+///
+/// # Panics
+///
+/// Panics if the grid is empty, rows have different lengths, an invalid byte is found or `N` is
+/// incorrect. When evaluated in a const context these are compile errors.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::grid::{const_len, from_str_const};
+/// const INPUT: &str = "##.#\n#..#\n#.##\n";
+/// const GRID: (usize, usize, [u8; const_len(INPUT)]) = from_str_const(INPUT, b"#.");
+/// assert_eq!(GRID, (3, 4, *b"##.##..##.##"));
+/// ```
+///
+/// ```compile_fail
+/// # use utils::grid::{const_len, from_str_const};
+/// const INPUT: &str = "##.#\n#..\n#.##";
+/// const GRID: (usize, usize, [u8; const_len(INPUT)]) = from_str_const(INPUT, b"#.");
+/// ```
+#[must_use]
+pub const fn from_str_const<const N: usize>(input: &str, valid: &[u8]) -> (usize, usize, [u8; N]) {
+    let bytes = input.as_bytes();
+    let mut data = [0u8; N];
+    let (mut len, mut rows, mut columns, mut line_start) = (0, 0, 0, 0);
+
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == b'\n' {
+            let line_len = i - line_start;
+            if line_len == 0 && i == bytes.len() && rows > 0 {
+                // Trailing newline
+                break;
+            }
+            assert!(line_len > 0, "expected grid");
+            if rows == 0 {
+                columns = line_len;
+            }
+            assert!(line_len == columns, "expected rows to have the same length");
+            rows += 1;
+            line_start = i + 1;
+        } else {
+            assert!(contains(valid, bytes[i]), "invalid character");
+            assert!(len < N, "grid length doesn't match N");
+            data[len] = bytes[i];
+            len += 1;
+        }
+        i += 1;
+    }
+
+    assert!(len == N, "grid length doesn't match N");
+    (rows, columns, data)
+}
+
+/// Returns the number of cells in a grid, for use as the length in [`from_str_const`].
+#[must_use]
+pub const fn const_len(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\n' {
+            len += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+const fn contains(haystack: &[u8], needle: u8) -> bool {
+    let mut i = 0;
+    while i < haystack.len() {
+        if haystack[i] == needle {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Macro to parse a 2D grid of bytes at compile time using [`from_str_const`].
+///
+/// Evaluates to `(rows, columns, &'static [u8])`, and can be used in const contexts.
+///
+/// # Examples
+///
+/// ```
+/// const EXAMPLE: (usize, usize, &[u8]) = utils::const_grid!("#.#\n...", b"#.");
+/// assert_eq!(EXAMPLE, (2, 3, b"#.#...".as_slice()));
+/// ```
+#[macro_export]
+macro_rules! const_grid {
+    ($input:expr, $valid:expr) => {{
+        const INPUT: &str = $input;
+        const GRID: (usize, usize, [u8; $crate::grid::const_len(INPUT)]) =
+            $crate::grid::from_str_const(INPUT, $valid);
+        (GRID.0, GRID.1, &GRID.2 as &[u8])
+    }};
+}
+
 /// Checks that the provided grid has walls on each edge.
 ///
 /// # Examples
@@ -174,3 +715,276 @@ pub fn is_enclosed<T>(rows: usize, cols: usize, grid: &[T], is_wall: impl Fn(&T)
         && grid[(rows - 1) * cols..].iter().all(&is_wall)
         && (1..rows).all(|r| is_wall(&grid[r * cols]) && is_wall(&grid[(r + 1) * cols - 1]))
 }
+
+/// Offsets for the 8 neighboring cells, in clockwise order starting from up, as (row, column).
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Iterator over the cells on a line between two points, using Bresenham's line algorithm.
+///
+/// Both endpoints are included. Created by [`line`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Line {
+    current: Point2D<i32>,
+    end: Point2D<i32>,
+    delta: Point2D<i32>,
+    step: Point2D<i32>,
+    error: i32,
+    done: bool,
+}
+
+/// Returns an iterator over the cells on the line between `from` and `to`.
+///
+/// # Examples
+/// ```
+/// # use utils::grid::line;
+/// # use utils::point::Point2D;
+/// assert_eq!(
+///     line(Point2D::new(0, 0), Point2D::new(4, 2)).collect::<Vec<_>>(),
+///     [
+///         Point2D::new(0, 0),
+///         Point2D::new(1, 1),
+///         Point2D::new(2, 1),
+///         Point2D::new(3, 2),
+///         Point2D::new(4, 2),
+///     ],
+/// );
+/// assert_eq!(
+///     line(Point2D::new(2, 2), Point2D::new(2, -1)).collect::<Vec<_>>(),
+///     [Point2D::new(2, 2), Point2D::new(2, 1), Point2D::new(2, 0), Point2D::new(2, -1)],
+/// );
+/// ```
+pub fn line(from: Point2D<i32>, to: Point2D<i32>) -> Line {
+    let delta = Point2D::new((to.x - from.x).abs(), -(to.y - from.y).abs());
+    Line {
+        current: from,
+        end: to,
+        delta,
+        step: Point2D::new((to.x - from.x).signum(), (to.y - from.y).signum()),
+        error: delta.x + delta.y,
+        done: false,
+    }
+}
+
+impl Iterator for Line {
+    type Item = Point2D<i32>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let point = self.current;
+        if point == self.end {
+            self.done = true;
+            return Some(point);
+        }
+
+        let e2 = 2 * self.error;
+        if e2 >= self.delta.y {
+            self.error += self.delta.y;
+            self.current.x += self.step.x;
+        }
+        if e2 <= self.delta.x {
+            self.error += self.delta.x;
+            self.current.y += self.step.y;
+        }
+        Some(point)
+    }
+}
+
+/// Returns the index of the first cell matching `is_blocking` when scanning from `index` in the
+/// provided (row, column) direction, excluding the starting cell.
+///
+/// Returns [`None`] if the edge of the grid is reached first.
+///
+/// # Examples
+/// ```
+/// # use utils::grid::first_in_direction;
+/// let grid = b"\
+///     #..L\
+///     ....\
+///     .L.#";
+/// let is_seat = |&b: &u8| b != b'.';
+/// assert_eq!(first_in_direction(3, 4, grid, 0, (0, 1), is_seat), Some(3));
+/// assert_eq!(first_in_direction(3, 4, grid, 0, (1, 1), is_seat), None);
+/// assert_eq!(first_in_direction(3, 4, grid, 10, (-1, -1), is_seat), Some(0));
+/// assert_eq!(first_in_direction(3, 4, grid, 11, (0, -1), is_seat), Some(9));
+/// ```
+pub fn first_in_direction<T>(
+    rows: usize,
+    cols: usize,
+    grid: &[T],
+    index: usize,
+    (dr, dc): (isize, isize),
+    is_blocking: impl Fn(&T) -> bool,
+) -> Option<usize> {
+    assert_eq!(grid.len(), rows * cols);
+    assert!(dr != 0 || dc != 0, "direction must be non-zero");
+
+    let (mut r, mut c) = (index / cols, index % cols);
+    loop {
+        r = r.checked_add_signed(dr).filter(|&r| r < rows)?;
+        c = c.checked_add_signed(dc).filter(|&c| c < cols)?;
+        let i = r * cols + c;
+        if is_blocking(&grid[i]) {
+            return Some(i);
+        }
+    }
+}
+
+/// Returns the first cell matching `is_blocking` in each of the [`DIRECTIONS_8`] from `index`.
+///
+/// Useful for puzzles where cells interact with the nearest visible cell, such as seating
+/// simulations.
+///
+/// # Examples
+/// ```
+/// # use utils::grid::visible_from;
+/// let grid = b"\
+///     L.L\
+///     ...\
+///     L.#";
+/// let visible = visible_from(3, 3, grid, 4, |&b| b != b'.');
+/// assert_eq!(visible, [None, Some(2), None, Some(8), None, Some(6), None, Some(0)]);
+/// ```
+pub fn visible_from<T>(
+    rows: usize,
+    cols: usize,
+    grid: &[T],
+    index: usize,
+    is_blocking: impl Fn(&T) -> bool,
+) -> [Option<usize>; 8] {
+    DIRECTIONS_8.map(|d| first_in_direction(rows, cols, grid, index, d, &is_blocking))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn line_matches_naive() {
+        let mut state = 0x1234_5678_9abc_def0;
+        for _ in 0..10_000 {
+            let mut coord = || (xorshift(&mut state) % 41) as i32 - 20;
+            let from = Point2D::new(coord(), coord());
+            let to = Point2D::new(coord(), coord());
+            let points: Vec<_> = line(from, to).collect();
+
+            let (dx, dy) = (to.x - from.x, to.y - from.y);
+            assert_eq!(points.first(), Some(&from));
+            assert_eq!(points.last(), Some(&to));
+            assert_eq!(
+                points.len(),
+                dx.unsigned_abs().max(dy.unsigned_abs()) as usize + 1
+            );
+
+            for (i, w) in points.windows(2).enumerate() {
+                // Each step moves to an adjacent cell, always along the major axis
+                let step = Point2D::new(w[1].x - w[0].x, w[1].y - w[0].y);
+                assert!(
+                    step.x.abs() <= 1 && step.y.abs() <= 1,
+                    "{from:?} {to:?} step {i}"
+                );
+                if dx.abs() >= dy.abs() {
+                    assert_eq!(step.x, dx.signum());
+                } else {
+                    assert_eq!(step.y, dy.signum());
+                }
+            }
+
+            for p in &points {
+                // Each cell is within half a cell of the ideal line along the minor axis
+                let cross = i64::from(dx) * i64::from(p.y - from.y)
+                    - i64::from(dy) * i64::from(p.x - from.x);
+                let major = i64::from(dx.abs().max(dy.abs()));
+                assert!(2 * cross.abs() <= major, "{from:?} {to:?} {p:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[expect(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn visible_from_matches_naive() {
+        let mut state = 0x0fed_cba9_8765_4321;
+        for _ in 0..1_000 {
+            let rows = (xorshift(&mut state) % 8) as usize + 1;
+            let cols = (xorshift(&mut state) % 8) as usize + 1;
+            let grid: Vec<bool> = (0..rows * cols)
+                .map(|_| xorshift(&mut state) % 3 == 0)
+                .collect();
+
+            for index in 0..grid.len() {
+                let visible = visible_from(rows, cols, &grid, index, |&b| b);
+                for (d, &(dr, dc)) in DIRECTIONS_8.iter().enumerate() {
+                    let (mut r, mut c) = ((index / cols) as isize, (index % cols) as isize);
+                    let mut expected = None;
+                    loop {
+                        (r, c) = (r + dr, c + dc);
+                        if r < 0 || c < 0 || r >= rows as isize || c >= cols as isize {
+                            break;
+                        }
+                        if grid[r as usize * cols + c as usize] {
+                            expected = Some(r as usize * cols + c as usize);
+                            break;
+                        }
+                    }
+                    assert_eq!(visible[d], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn padded_grid_coordinates() {
+        for padding in 0..3 {
+            let grid = PaddedGrid::from_str("abc\ndef", padding, b'#', Some).unwrap();
+            assert_eq!((grid.rows(), grid.cols()), (2, 3));
+
+            let cells: Vec<u8> = grid.indices().map(|i| grid.data()[i]).collect();
+            assert_eq!(cells, b"abcdef");
+
+            for i in grid.indices() {
+                let (r, c) = grid.position(i);
+                assert!(grid.contains(r, c));
+                assert_eq!(grid.index(r, c), i);
+            }
+
+            let padding_cells = grid.data().len() - grid.indices().count();
+            assert_eq!(
+                (0..grid.data().len())
+                    .filter(|&i| {
+                        let (r, c) = grid.position(i);
+                        !grid.contains(r, c) && grid.data()[i] == b'#'
+                    })
+                    .count(),
+                padding_cells,
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "(0, 5) is outside the padded 2x3 grid"]
+    fn padded_grid_out_of_bounds() {
+        let grid = PaddedGrid::from_str("abc\ndef", 1, b'#', Some).unwrap();
+        let _ = grid[(0, 5)];
+    }
+}
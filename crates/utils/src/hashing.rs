@@ -0,0 +1,101 @@
+//! Helpers shared by the [`md5`](crate::md5), [`sha1`](crate::sha1) and
+//! [`sha256`](crate::sha256) implementations.
+
+use crate::multiversion;
+use std::array;
+use std::num::NonZeroUsize;
+
+/// Input for the microbenchmarks used to pick the fastest implementation.
+pub(crate) const BENCH_STRING: [[u8; 32]; 128] = {
+    let mut out = [*b"abcdefghijklmnopqrstuvwxyz012345"; 128];
+    let mut i = 0;
+
+    #[expect(clippy::cast_possible_truncation)]
+    while i < out.len() {
+        out[i][i % 32] = i as u8;
+        i += 1;
+    }
+
+    out
+};
+
+/// Convert a [`u32`] to eight ASCII hex digits, most significant first.
+///
+/// Implemented using bitwise operations to spread each nibble into separate bytes, followed by
+/// adding either '0' or 'a' to each byte using a bitmask.
+#[inline]
+pub(crate) fn u32_to_hex(n: u32) -> [u8; 8] {
+    const SPLAT: u64 = 0x0101_0101_0101_0101;
+
+    let mut n = u64::from(n);
+    // n = 0x0000_0000_1234_ABCD
+
+    n = ((n & 0x0000_0000_FFFF_0000) << 16) | (n & 0x0000_0000_0000_FFFF);
+    // n = 0x0000_1234_0000_ABCD
+
+    n = ((n & 0x0000_FF00_0000_FF00) << 8) | (n & 0x0000_00FF_0000_00FF);
+    // n = 0x0012_0034_00AB_00CD
+
+    n = ((n & 0x00F0_00F0_00F0_00F0) << 4) | (n & 0x000F_000F_000F_000F);
+    // n = 0x0102_0304_0A0B_0C0D
+
+    let letter_positions = (n + ((128 - 10) * SPLAT)) & (128 * SPLAT);
+    // letter_positions = 0x0000_0000_8080_8080
+
+    let letter_mask = letter_positions - (letter_positions >> 7);
+    // letter_mask = 0x0000_0000_7F7F_7F7F
+
+    let hex = (n + u64::from(b'0') * SPLAT) + (letter_mask & (u64::from(b'a' - b'0' - 10) * SPLAT));
+    // hex = 0x3132_3334_6162_6364
+
+    hex.to_be_bytes()
+}
+
+/// Write the decimal digits of the value to the start of the buffer, returning the number of
+/// digits.
+pub(crate) fn u32_to_ascii(buf: &mut [u8], mut value: u32) -> usize {
+    assert!(buf.len() >= 10);
+
+    let length = 1 + value.checked_ilog10().unwrap_or(0) as usize;
+    assert!(length < 10);
+
+    for d in (0..length).rev() {
+        let new = (value % 10) as u8 + b'0';
+        buf[d] = new;
+        value /= 10;
+    }
+
+    length
+}
+
+multiversion! {
+    use {crate::simd::*};
+
+    /// Write the decimal digits of `base + i` interleaved for each lane `i`, returning the number
+    /// of digits, or [`None`] if the lanes have different numbers of digits.
+    #[inline]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn u32_to_ascii_multi(buf: &mut [u8], base: u32) -> Option<NonZeroUsize> {
+        assert!(buf.len() >= U32Vector::LANES * 10);
+
+        let length = 1 + base.checked_ilog10().unwrap_or(0) as usize;
+        assert!(length <= 10);
+
+        let mut values: [u32; U32Vector::LANES] = array::from_fn(|i| base + i as u32);
+        for d in (0..length).rev() {
+            let digits: &mut [u8; U32Vector::LANES] =
+                (&mut buf[d * U32Vector::LANES..(d + 1) * U32Vector::LANES]).try_into().unwrap();
+            for i in 0..U32Vector::LANES {
+                digits[i] = (values[i] % 10) as u8 + b'0';
+                values[i] /= 10;
+            }
+        }
+
+        if values.iter().any(|&x| x > 0) {
+            // At least one number has an extra digit, fallback to scalar code
+            return None;
+        }
+
+        Some(NonZeroUsize::new(length).unwrap())
+    }
+}
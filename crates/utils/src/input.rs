@@ -32,12 +32,27 @@ pub enum InputType {
 ///   |    ^
 /// ".trim_start());
 /// ```
+///
+/// When the error is on the last line of the input, any formatting problems found by [`validate`]
+/// which may have caused the error are included as notes:
+///
+/// ```
+/// # use utils::input::InputError;
+/// let input = "12 34\n56 78 \n\n";
+/// let error = InputError::new(input, 11, "expected newline");
+/// assert!(error.to_string().ends_with("
+///   |      ^
+///   = note: line 2 has trailing whitespace
+///   = note: input ends with 1 blank line
+/// "));
+/// ```
 #[derive(Debug)]
 pub struct InputError {
     line_number: usize,
     column_number: usize,
     line: String,
     source: Box<dyn Error>,
+    notes: Vec<InputWarning>,
 }
 
 impl InputError {
@@ -50,11 +65,24 @@ impl InputError {
         let (line_number, column_number, line) = Self::line_position(input, index);
         let line = line.replace('\t', " ");
 
+        // Only check for formatting problems when the error is on the last line of content, where
+        // trailing whitespace is the most likely cause. Missing final newlines are excluded as they
+        // are common in examples and rarely cause errors.
+        let notes = if input[index..].trim_end().contains('\n') {
+            Vec::new()
+        } else {
+            validate(input)
+                .into_iter()
+                .filter(|w| *w != InputWarning::MissingFinalNewline)
+                .collect()
+        };
+
         InputError {
             line_number,
             column_number,
             line,
             source: source.into(),
+            notes,
         }
     }
 
@@ -84,7 +112,13 @@ impl Display for InputError {
             self.line_number,
             self.line,
             " ".repeat(self.column_number),
-        )
+        )?;
+
+        for note in &self.notes {
+            writeln!(f, "{pad} = note: {note}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -94,6 +128,160 @@ impl Error for InputError {
     }
 }
 
+/// Formatting problem found in an input by [`validate`].
+///
+/// These are warnings rather than errors as most solutions handle them, but they are a common cause
+/// of confusing parse errors when inputs are copied or saved incorrectly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputWarning {
+    /// Input starts with a UTF-8 byte order mark.
+    ByteOrderMark,
+    /// Input uses `\r\n` line endings.
+    CarriageReturns,
+    /// One or more lines end with spaces or tabs.
+    TrailingWhitespace { first_line: usize, lines: usize },
+    /// Input ends with one or more blank lines.
+    TrailingBlankLines(usize),
+    /// Input doesn't end with a newline.
+    MissingFinalNewline,
+}
+
+impl Display for InputWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match *self {
+            InputWarning::ByteOrderMark => write!(f, "input starts with a byte order mark"),
+            InputWarning::CarriageReturns => write!(f, "input contains \\r\\n line endings"),
+            InputWarning::TrailingWhitespace {
+                first_line,
+                lines: 1,
+            } => write!(f, "line {first_line} has trailing whitespace"),
+            InputWarning::TrailingWhitespace { first_line, lines } => write!(
+                f,
+                "{lines} lines have trailing whitespace, starting with line {first_line}"
+            ),
+            InputWarning::TrailingBlankLines(1) => write!(f, "input ends with 1 blank line"),
+            InputWarning::TrailingBlankLines(n) => write!(f, "input ends with {n} blank lines"),
+            InputWarning::MissingFinalNewline => write!(f, "input does not end with a newline"),
+        }
+    }
+}
+
+/// Check the provided input for common formatting problems.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::input::{validate, InputWarning};
+/// assert_eq!(validate("1 2\n3 4\n"), vec![]);
+/// assert_eq!(
+///     validate("1 2 \r\n3 4\t\r\n5 6\r\n\r\n"),
+///     vec![
+///         InputWarning::CarriageReturns,
+///         InputWarning::TrailingWhitespace { first_line: 1, lines: 2 },
+///         InputWarning::TrailingBlankLines(1),
+///     ],
+/// );
+/// assert_eq!(
+///     validate("\u{feff}1 2\n3 4"),
+///     vec![InputWarning::ByteOrderMark, InputWarning::MissingFinalNewline],
+/// );
+/// ```
+#[must_use]
+pub fn validate(input: &str) -> Vec<InputWarning> {
+    let mut warnings = Vec::new();
+
+    if input.starts_with('\u{feff}') {
+        warnings.push(InputWarning::ByteOrderMark);
+    }
+    if input.contains('\r') {
+        warnings.push(InputWarning::CarriageReturns);
+    }
+
+    let mut first_line = 0;
+    let mut lines = 0;
+    let mut blank_lines = 0;
+    for (i, line) in input.split_terminator('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.ends_with([' ', '\t']) {
+            if lines == 0 {
+                first_line = i + 1;
+            }
+            lines += 1;
+        }
+
+        if line.is_empty() {
+            blank_lines += 1;
+        } else {
+            blank_lines = 0;
+        }
+    }
+
+    if lines > 0 {
+        warnings.push(InputWarning::TrailingWhitespace { first_line, lines });
+    }
+    if blank_lines > 0 {
+        warnings.push(InputWarning::TrailingBlankLines(blank_lines));
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        warnings.push(InputWarning::MissingFinalNewline);
+    }
+
+    warnings
+}
+
+/// Default maximum input size accepted by [`check_size`], 16 MiB.
+///
+/// Real puzzle inputs are at most tens of kilobytes, so anything larger is likely the wrong file
+/// or a generated input.
+pub const DEFAULT_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+/// Error returned by [`check_size`] when an input exceeds the size limit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InputSizeError {
+    pub size: usize,
+    pub limit: usize,
+}
+
+impl Display for InputSizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "input is {} bytes, exceeding the limit of {} bytes",
+            self.size, self.limit
+        )
+    }
+}
+
+impl Error for InputSizeError {}
+
+/// Check the provided input isn't larger than the limit in bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::input::{check_size, InputSizeError};
+/// assert_eq!(check_size("1 2\n3 4\n", 8), Ok(()));
+/// assert_eq!(
+///     check_size("1 2\n3 4\n5 6\n", 8),
+///     Err(InputSizeError { size: 12, limit: 8 }),
+/// );
+/// assert_eq!(
+///     check_size("1 2\n3 4\n5 6\n", 8).unwrap_err().to_string(),
+///     "input is 12 bytes, exceeding the limit of 8 bytes",
+/// );
+/// ```
+pub fn check_size(input: &str, limit: usize) -> Result<(), InputSizeError> {
+    if input.len() > limit {
+        Err(InputSizeError {
+            size: input.len(),
+            limit,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Helper trait to simplify error location tracking.
 ///
 /// Used in [`InputError::new`].
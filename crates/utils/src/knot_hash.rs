@@ -0,0 +1,276 @@
+//! Implementation of the knot hash function from [2017 day 10](../../year2017/struct.Day10.html).
+//!
+//! The vectorized versions hash multiple inputs of the same length at once. Instead of reversing
+//! segments of each list, they track the position of each value relative to the current position,
+//! which can be updated using only lane-wise arithmetic. This does far more work per hash than
+//! reversing the list directly, so depending on the CPU the scalar version, which does reverse the
+//! list, may still be the [`FASTEST`].
+use crate::hashing::{u32_to_hex, BENCH_STRING};
+use crate::multiversion;
+use crate::multiversion::Version;
+use std::array;
+use std::sync::LazyLock;
+
+/// Fastest supported implementation, for dynamic dispatch.
+///
+/// Determined using a small microbenchmark at runtime.
+pub static FASTEST: LazyLock<Version> = multiversion! { fastest(microbenchmark()) };
+
+/// Lengths appended to the input before hashing.
+const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+
+/// Number of rounds used by [`hash`].
+const ROUNDS: u32 = 64;
+
+/// Apply the provided number of knot rounds to the list `0..=255`, returning the sparse hash.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::knot_hash::rounds;
+/// let list = rounds(&[3, 4, 1, 5], 1);
+/// assert_eq!(list[..8], [2, 1, 0, 6, 5, 4, 3, 7]);
+/// ```
+#[inline]
+#[must_use]
+pub fn rounds(lengths: &[u8], rounds: u32) -> [u8; 256] {
+    sparse_hash(lengths, &[], rounds)
+}
+
+/// Returns the knot hash of the input bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::knot_hash::hash;
+/// assert_eq!(hash(b"").as_slice(), &[
+///     0xa2, 0x58, 0x2a, 0x3a, 0x0e, 0x66, 0xe6, 0xe8, 0x6e, 0x38, 0x12, 0xdc, 0xb6, 0x72, 0xa2, 0x72,
+/// ]);
+/// ```
+#[inline]
+#[must_use]
+pub fn hash(buf: &[u8]) -> [u8; 16] {
+    let sparse = sparse_hash(buf, &SUFFIX, ROUNDS);
+
+    array::from_fn(|i| {
+        sparse[16 * i..16 * (i + 1)]
+            .iter()
+            .fold(0, |acc, x| acc ^ x)
+    })
+}
+
+#[inline]
+#[expect(clippy::cast_possible_truncation)]
+fn sparse_hash(lengths: &[u8], suffix: &[u8], rounds: u32) -> [u8; 256] {
+    let mut list = array::from_fn(|i| i as u8);
+    let mut position = 0;
+    let mut skip = 0;
+
+    for _ in 0..rounds {
+        for &length in lengths.iter().chain(suffix) {
+            list[0..length as usize].reverse();
+            list.rotate_left((length as usize + skip) % 256);
+            position = (position + length as usize + skip) % 256;
+            skip += 1;
+        }
+    }
+
+    list.rotate_right(position);
+    list
+}
+
+/// Convert a knot hash to ASCII hex.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::knot_hash::{hash, to_hex};
+/// assert_eq!(to_hex(hash(b"AoC 2017")), *b"33efeb34ea91902bb2f59c9920caa6cd");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_hex(hash: [u8; 16]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    for (out, chunk) in result.chunks_exact_mut(8).zip(hash.chunks_exact(4)) {
+        out.copy_from_slice(&u32_to_hex(u32::from_be_bytes(chunk.try_into().unwrap())));
+    }
+    result
+}
+
+multiversion! {
+    use {crate::simd::*};
+
+    /// [`multiversion!`] knot hash implementation.
+    ///
+    /// The bytes for each lane must be interweaved, and each lane must be the same length.
+    ///
+    /// # Examples
+    ///
+    /// For [`array128`](crate::simd::array128) with four lanes:
+    /// ```
+    /// # use utils::knot_hash::{self, array128};
+    /// assert_eq!(
+    ///     array128::hash(b"1A,C,o,12C,2"),
+    ///     [
+    ///         knot_hash::hash(b"1,2"),
+    ///         knot_hash::hash(b"AoC"),
+    ///         knot_hash::hash(b",,,"),
+    ///         knot_hash::hash(b"C12"),
+    ///     ],
+    /// );
+    /// ```
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn hash(buf: &[u8]) -> [[u8; 16]; U32Vector::LANES] {
+        assert_eq!(buf.len() % U32Vector::LANES, 0);
+
+        if U32Vector::LANES == 1 {
+            // Tracking positions is much slower than reversing the list directly for a single lane
+            return array::from_fn(|_| crate::knot_hash::hash(buf));
+        }
+
+        let mut lengths: Vec<U32Vector> = buf
+            .chunks_exact(U32Vector::LANES)
+            .map(|chunk| array::from_fn(|i| u32::from(chunk[i])).into())
+            .collect();
+        lengths.extend(SUFFIX.map(|l| U32Vector::splat(u32::from(l))));
+
+        let mask = U32Vector::splat(255);
+        let one = U32Vector::splat(1);
+        let minus_one = U32Vector::splat(u32::MAX);
+
+        // Position of each value relative to the current position
+        let mut relative: Vec<U32Vector> = (0..256).map(U32Vector::splat).collect();
+        let mut current = U32Vector::splat(0);
+        let mut skip = 0u32;
+
+        for _ in 0..ROUNDS {
+            for &length in &lengths {
+                let neg_length = !length + one;
+                let neg_skip = U32Vector::splat(skip.wrapping_neg());
+
+                // Values inside the reversed segment move to length - 1 - relative, and then
+                // everything moves back by length + skip as the current position advances:
+                //   inside:  (length - 1 - relative) - (length + skip) = !relative - skip
+                //   outside: relative - (length + skip)
+                for r in &mut relative {
+                    // All bits set when relative >= length, otherwise zero
+                    let outside = ((*r + neg_length) >> 31) + minus_one;
+                    *r = ((*r ^ !outside) + neg_skip + neg_length.andnot(!outside)) & mask;
+                }
+
+                current = (current + length + U32Vector::splat(skip)) & mask;
+                skip += 1;
+            }
+        }
+
+        let current: [u32; U32Vector::LANES] = current.into();
+        let mut result = [[0u8; 16]; U32Vector::LANES];
+        for (value, &r) in relative.iter().enumerate() {
+            let r: [u32; U32Vector::LANES] = r.into();
+            for (lane, dense) in result.iter_mut().enumerate() {
+                let position = (r[lane] + current[lane]) & 255;
+                dense[position as usize / 16] ^= value as u8;
+            }
+        }
+        result
+    }
+
+    pub(super) fn microbenchmark() {
+        // Hash 128 inputs of 8 bytes each
+        let bench_string = &BENCH_STRING.as_flattened()[..8 * 128];
+        for chunk in bench_string.chunks(8 * U32Vector::LANES) {
+            std::hint::black_box(hash(chunk));
+        }
+    }
+}
+
+/// Returns the knot hash of each input, using the [`FASTEST`] implementation.
+///
+/// Consecutive inputs of the same length are hashed together using the vectorized implementation.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::knot_hash::{hash, hash_many};
+/// let inputs: Vec<String> = (0..20).map(|i| format!("flqrgnkx-{i}")).collect();
+/// let inputs: Vec<&[u8]> = inputs.iter().map(|s| s.as_bytes()).collect();
+/// assert_eq!(
+///     hash_many(&inputs),
+///     inputs.iter().map(|s| hash(s)).collect::<Vec<_>>(),
+/// );
+/// ```
+#[must_use]
+pub fn hash_many(inputs: &[&[u8]]) -> Vec<[u8; 16]> {
+    many::hash_many(inputs)
+}
+
+mod many {
+    use super::FASTEST;
+    use crate::multiversion;
+
+    multiversion! {
+        use {crate::simd::*, crate::knot_hash::*};
+
+        #[dyn_dispatch = FASTEST]
+        pub(super) fn hash_many(inputs: &[&[u8]]) -> Vec<[u8; 16]> {
+            let mut results = Vec::with_capacity(inputs.len());
+            let mut buf = Vec::new();
+
+            let mut remaining = inputs;
+            while let Some(first) = remaining.first() {
+                let same = remaining
+                    .iter()
+                    .take(U32Vector::LANES)
+                    .take_while(|x| x.len() == first.len())
+                    .count();
+                if same == 1 {
+                    results.push(crate::knot_hash::hash(first));
+                    remaining = &remaining[1..];
+                    continue;
+                }
+
+                // Interweave the bytes, repeating the first input in any unused lanes
+                buf.clear();
+                let lanes: [&[u8]; U32Vector::LANES] =
+                    std::array::from_fn(|lane| if lane < same { remaining[lane] } else { first });
+                for i in 0..first.len() {
+                    buf.extend(lanes.iter().map(|input| input[i]));
+                }
+                results.extend_from_slice(&hash(&buf)[..same]);
+                remaining = &remaining[same..];
+            }
+
+            results
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::multiversion_test;
+
+    multiversion_test! {
+        use {crate::simd::*, crate::knot_hash::*};
+
+        #[test]
+        fn multiversion() {
+            let strings = [&b"flqrgnkx-0"[..], b"AoC 2017--", b"1,2,3,4,5,", b"\x00\xff\x80abcdefg"];
+
+            for len in [0, 3, 10] {
+                let mut buf = Vec::new();
+                for i in 0..len {
+                    for s in strings.iter().cycle().take(U32Vector::LANES) {
+                        buf.push(s[i]);
+                    }
+                }
+
+                assert_eq!(
+                    hash(&buf),
+                    std::array::from_fn(|i| crate::knot_hash::hash(&strings[i % strings.len()][..len])),
+                    "length {len}",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Lazily computed values shared between puzzle parts.
+//!
+//! Work needed by both parts is usually done in `new`, which wastes time when only one part is
+//! run, as the WASM runner allows. Storing the result in a [`Lazy`] field instead computes it on
+//! first use by either part, and reuses it when both parts are run.
+//!
+//! The [`lazy!`](crate::lazy!) macro can be used to declare an accessor method which initializes
+//! the field with the same name.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::OnceLock;
+
+/// Value which is computed on first access.
+///
+/// Wraps [`OnceLock`] rather than [`OnceCell`](std::cell::OnceCell) so puzzle structs remain
+/// [`Sync`] and can still be shared with worker threads.
+#[derive(Clone, Default)]
+pub struct Lazy<T>(OnceLock<T>);
+
+impl<T> Lazy<T> {
+    /// Creates a new uninitialized value.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    /// Returns the value, computing it using the provided function if uninitialized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::lazy::Lazy;
+    /// let lazy = Lazy::new();
+    /// assert_eq!(lazy.get(), None);
+    /// assert_eq!(lazy.get_or_init(|| 12), &12);
+    /// assert_eq!(lazy.get_or_init(|| unreachable!()), &12);
+    /// assert_eq!(lazy.get(), Some(&12));
+    /// ```
+    #[inline]
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        self.0.get_or_init(f)
+    }
+
+    /// Returns the value if it has already been computed.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> Option<&T> {
+        self.0.get()
+    }
+}
+
+impl<T: Debug> Debug for Lazy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.get() {
+            Some(v) => f.debug_tuple("Lazy").field(v).finish(),
+            None => f.write_str("Lazy(<uninit>)"),
+        }
+    }
+}
+
+/// Macro to declare accessor methods for [`Lazy`] fields.
+///
+/// Each method must have the same name as the [`Lazy`] field it initializes, and returns a
+/// reference to the computed value. The body is only evaluated on the first call.
+///
+/// # Examples
+/// ```
+/// # use utils::lazy::Lazy;
+/// struct Day {
+///     numbers: Vec<u32>,
+///     sorted: Lazy<Vec<u32>>,
+/// }
+///
+/// impl Day {
+///     utils::lazy! {
+///         /// Numbers in ascending order, shared by both parts.
+///         fn sorted(&self) -> Vec<u32> {
+///             let mut sorted = self.numbers.clone();
+///             sorted.sort_unstable();
+///             sorted
+///         }
+///     }
+///
+///     fn part1(&self) -> u32 {
+///         self.sorted()[0]
+///     }
+///
+///     fn part2(&self) -> u32 {
+///         self.sorted()[self.sorted().len() - 1]
+///     }
+/// }
+///
+/// let day = Day { numbers: vec![5, 2, 8], sorted: Lazy::new() };
+/// assert_eq!(day.part2(), 8);
+/// assert_eq!(day.part1(), 2);
+/// ```
+#[macro_export]
+macro_rules! lazy {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident(&$self:ident) -> $ty:ty $body:block
+    )+) => {$(
+        $(#[$meta])*
+        #[inline]
+        $vis fn $name(&$self) -> &$ty {
+            $self.$name.get_or_init(|| $body)
+        }
+    )+};
+}
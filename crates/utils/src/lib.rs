@@ -1,31 +1,66 @@
 //! Common utilities used by the [`aoc`](../aoc/) and year crates.
 #![cfg_attr(not(feature = "unsafe"), forbid(unsafe_code))]
 
+pub mod answer_image;
+#[cfg(not(target_family = "wasm"))]
+pub mod answers;
 pub mod array;
 pub mod bit;
+pub mod budget;
+pub mod collections;
 pub mod date;
+pub mod env_info;
+pub mod explain;
 mod framework;
+pub mod frontier;
+pub mod geometry;
 pub mod graph;
 pub mod grid;
+mod hashing;
 pub mod input;
+pub mod knot_hash;
+pub mod lazy;
 pub mod md5;
 #[cfg(not(target_family = "wasm"))]
 pub mod multithreading;
 pub mod multiversion;
 pub mod number;
+pub mod ocr;
 pub mod parser;
 pub mod point;
+pub mod progress;
+mod reduce;
+pub mod replay;
+#[cfg(any(test, all(target_family = "wasm", feature = "wasm-multithreading")))]
+mod scheduling;
+mod scratch;
+pub mod search;
+pub mod sha1;
+pub mod sha256;
+pub mod short_key;
+pub mod sim;
 pub mod simd;
+mod solution;
+pub mod stack;
 #[cfg(target_family = "wasm")]
 pub mod wasm;
 
-pub use framework::{Puzzle, PuzzleExamples};
+#[doc(hidden)]
+pub use framework::{
+    assert_days, assert_puzzle, doc_summary, source_hash, str_array, GeneratorProbe,
+    NoPuzzleGenerator,
+};
+pub use framework::{Puzzle, PuzzleExamples, PuzzleGenerator};
+pub use solution::{catch_panic, SolutionError};
 #[cfg(target_family = "wasm")]
 pub use wasm::multithreading;
 
 /// Standard imports for puzzle solutions.
 pub mod prelude {
     pub use crate::examples;
+    pub use crate::explain;
     pub use crate::input::{InputError, InputType, MapWithInputExt as _};
+    pub use crate::lazy;
+    pub use crate::lazy::Lazy;
     pub use crate::parser::{self, Parser as _};
 }
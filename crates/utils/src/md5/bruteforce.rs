@@ -1,6 +1,7 @@
+use crate::hashing::u32_to_ascii;
+use crate::progress::Progress;
 use crate::{md5, multithreading, multiversion};
 use std::array;
-use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 /// Brute force hashes of a prefix followed by an increasing integer.
@@ -19,6 +20,8 @@ use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 /// ```ignore
 /// predicate(i, hash(to_hex(hash(to_hex(hash(prefix + i.to_string()))))))
 /// ```
+///
+/// Each completed hash, including additional hashes, is reported to [`Progress`].
 pub fn find_hash_with_appended_count(
     prefix: &str,
     additional_hashes: u32,
@@ -37,23 +40,8 @@ pub fn find_hash_with_appended_count(
     });
 }
 
-fn u32_to_ascii(buf: &mut [u8], mut value: u32) -> usize {
-    assert!(buf.len() >= 10);
-
-    let length = 1 + value.checked_ilog10().unwrap_or(0) as usize;
-    assert!(length < 10);
-
-    for d in (0..length).rev() {
-        let new = (value % 10) as u8 + b'0';
-        buf[d] = new;
-        value /= 10;
-    }
-
-    length
-}
-
 multiversion! {
-    use {crate::simd::*, crate::md5::*};
+    use {crate::simd::*, crate::md5::*, crate::hashing::*};
 
     #[dyn_dispatch = md5::FASTEST]
     #[expect(clippy::cast_possible_truncation)]
@@ -116,32 +104,7 @@ multiversion! {
                     }
                 }
             }
+            Progress::add(u64::from(batch_size) * (u64::from(additional_hashes) + 1));
         }
     }
-
-    #[inline]
-    #[expect(clippy::cast_possible_truncation)]
-    pub fn u32_to_ascii_multi(buf: &mut [u8], base: u32) -> Option<NonZeroUsize> {
-        assert!(buf.len() >= U32Vector::LANES * 10);
-
-        let length = 1 + base.checked_ilog10().unwrap_or(0) as usize;
-        assert!(length <= 10);
-
-        let mut values: [u32; U32Vector::LANES] = array::from_fn(|i| base + i as u32);
-        for d in (0..length).rev() {
-            let digits: &mut [u8; U32Vector::LANES] =
-                (&mut buf[d * U32Vector::LANES..(d + 1) * U32Vector::LANES]).try_into().unwrap();
-            for i in 0..U32Vector::LANES {
-                digits[i] = (values[i] % 10) as u8 + b'0';
-                values[i] /= 10;
-            }
-        }
-
-        if values.iter().any(|&x| x > 0) {
-            // At least one number has an extra digit, fallback to scalar code
-            return None;
-        }
-
-        Some(NonZeroUsize::new(length).unwrap())
-    }
 }
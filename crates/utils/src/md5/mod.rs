@@ -5,6 +5,7 @@
 //!
 //! The vectorized versions hash multiple inputs of the same length at once, which provides a
 //! significant performance increase for the brute force puzzle solutions.
+use crate::hashing::{u32_to_hex, BENCH_STRING};
 use crate::multiversion;
 use crate::multiversion::Version;
 use std::array;
@@ -14,7 +15,7 @@ mod bruteforce;
 pub use bruteforce::find_hash_with_appended_count;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 /// Fastest supported implementation, for dynamic dispatch.
 ///
@@ -270,19 +271,6 @@ multiversion! {
     }
 }
 
-const BENCH_STRING: [[u8; 32]; 128] = {
-    let mut out = [*b"abcdefghijklmnopqrstuvwxyz012345"; 128];
-    let mut i = 0;
-
-    #[expect(clippy::cast_possible_truncation)]
-    while i < out.len() {
-        out[i][i % 32] = i as u8;
-        i += 1;
-    }
-
-    out
-};
-
 /// Convert an MD5 hash to ASCII hex.
 ///
 /// Implemented using bitwise operations on each [`u32`] to spread each nibble into separate bytes,
@@ -311,31 +299,3 @@ pub fn to_hex([a, b, c, d]: [u32; 4]) -> [u8; 32] {
     result[24..32].copy_from_slice(&u32_to_hex(d));
     result
 }
-
-#[inline]
-fn u32_to_hex(n: u32) -> [u8; 8] {
-    const SPLAT: u64 = 0x0101_0101_0101_0101;
-
-    let mut n = u64::from(n);
-    // n = 0x0000_0000_1234_ABCD
-
-    n = ((n & 0x0000_0000_FFFF_0000) << 16) | (n & 0x0000_0000_0000_FFFF);
-    // n = 0x0000_1234_0000_ABCD
-
-    n = ((n & 0x0000_FF00_0000_FF00) << 8) | (n & 0x0000_00FF_0000_00FF);
-    // n = 0x0012_0034_00AB_00CD
-
-    n = ((n & 0x00F0_00F0_00F0_00F0) << 4) | (n & 0x000F_000F_000F_000F);
-    // n = 0x0102_0304_0A0B_0C0D
-
-    let letter_positions = (n + ((128 - 10) * SPLAT)) & (128 * SPLAT);
-    // letter_positions = 0x0000_0000_8080_8080
-
-    let letter_mask = letter_positions - (letter_positions >> 7);
-    // letter_mask = 0x0000_0000_7F7F_7F7F
-
-    let hex = (n + u64::from(b'0') * SPLAT) + (letter_mask & (u64::from(b'a' - b'0' - 10) * SPLAT));
-    // hex = 0x3132_3334_6162_6364
-
-    hex.to_be_bytes()
-}
@@ -40,7 +40,7 @@ pub const STRINGS: [[u8; 330]; 8] = [
     *b"\xc5\x48\x51\x10\xdb\x1e\x21\xc9\x46\xb1\xe6\x2e\x70\x94\x81\xf8\xcf\x23\x39\x46\xd4\x78\xe9\x32\xfb\xca\x8b\xa1\x19\xb6\xf8\xa2\xa4\xad\xf4\x18\x75\x61\xaa\x6c\x2d\x14\xc2\xba\x60\xa0\x5d\x0d\x8e\xa2\x1a\x90\x8f\x95\xa4\xbd\xc9\x9f\xe9\xfa\x73\x98\x3e\x0f\xce\x2a\xc1\xc1\x67\x2b\x37\xfe\xaa\xea\xa7\x61\x5e\x75\x39\xcd\xe1\x60\xe7\x54\xc9\xc4\xc2\x3d\x4a\x5e\xad\x71\x99\x68\x7e\x4d\xcb\x9e\x8d\x63\x6e\x11\x20\x50\xa7\x71\x89\x45\xa4\x9c\xc4\x9e\x52\x86\xa9\x8d\x86\xc2\x3a\x9f\x79\x4a\x15\x06\x8d\xd8\xff\xe3\xcc\x58\xce\x6f\x5b\x92\x49\x98\x94\x88\x7f\xc4\x74\x68\x65\xa9\x99\xd9\xf2\xe8\xde\x8d\xe7\xb0\xcb\x3e\x5a\xd0\x99\x2c\xeb\x87\xe8\xc4\x31\x29\x5f\x0e\xbd\x29\x1b\xec\xbd\xed\xe3\x9c\xdc\xc2\x63\x05\xa8\x13\x0f\x5d\x8a\x58\x87\x93\x2c\xa7\x45\x09\x6c\x03\x77\x08\x3a\x0f\x0d\x2d\xce\xa1\xff\x23\xb3\xc1\x0c\xf3\x2e\xe5\x69\x63\x9c\x27\x94\xfb\x3e\xa4\x38\xbd\xc5\x17\xf0\x67\x5b\xe2\x13\x56\x4c\xd3\x48\x2c\x70\x58\xea\x18\x8c\xf3\x9b\xbd\xcf\xa1\x60\x6c\x92\xc8\xfb\xf2\x6f\x7c\x3c\xb7\x9b\xf3\x61\x34\x27\x3c\x8d\xe4\x9e\x61\x15\xf1\x50\xf8\x17\xcf\x2e\x6e\x5b\x5c\xf7\x13\xb2\x1a\x79\xda\xc6\x84\xcd\x18\x9e\xb2\x42\x71\xa5\x64\x3a\x2f\x18\xa4\x67\x65\xbc\x30\x41\xf6\x02\x42\xd3\x49\xe9\x07\x57\xae\x24\x8c\xb8\xd7\x4c\x81\xc4\xc8\x6f\x3a\xbb\xe0\x3e\x8b\xe3\xda\xf0\xf2\x2e\xf7\x78\x5b\x75\x4c\x44\x49",
 ];
 
-pub const HASHES: [[&str; 331]; 8] = [
+pub static HASHES: [[&str; 331]; 8] = [
     [
         // string 0
         "d41d8cd98f00b204e9800998ecf8427e", // 0
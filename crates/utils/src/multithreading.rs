@@ -3,10 +3,17 @@
 //! The main purpose of this module is to allow the number of worker threads used by each puzzle
 //! solution to be controlled by a CLI argument.
 
+use std::fmt::Debug;
 use std::num::NonZeroUsize;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::Relaxed;
 
+#[cfg(feature = "unsafe")]
+mod pool;
+
+pub use crate::reduce::{par_map_reduce, tree_reduce};
+pub use crate::scratch::with_scratch;
+
 static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
 
 /// Get the number of worker threads to use.
@@ -27,25 +34,121 @@ pub fn get_thread_count() -> NonZeroUsize {
 
 /// Set the number of worker threads to use.
 ///
-/// This will affect any future call to [`get_thread_count`].
+/// This will affect any future call to [`get_thread_count`], and stops any idle threads in the
+/// [`worker_pool`] beyond the new count.
 pub fn set_thread_count(count: NonZeroUsize) {
     NUM_THREADS.store(count.get(), Relaxed);
+    #[cfg(feature = "unsafe")]
+    pool::set_idle_limit(count.get() - 1);
 }
 
 /// Run a worker function concurrently using a pool of worker threads.
 ///
-/// This is a wrapper around [`std::thread::scope`] for spawning a pool of identical worker threads.
+/// With the `unsafe` feature, the current thread runs one copy of the worker and the remaining
+/// copies run on persistent threads which are reused between calls, so running many solutions
+/// back-to-back doesn't pay the cost of spawning threads each time. Otherwise, this is a wrapper
+/// around [`std::thread::scope`].
+///
+/// Either way, this returns once all the workers have finished, and panics if any of them
+/// panicked.
 ///
 /// The number of workers is controlled by [`get_thread_count`].
 pub fn worker_pool(worker: impl Fn() + Copy + Send) {
     let threads = get_thread_count().get();
     if threads == 1 {
         worker();
-    } else {
-        std::thread::scope(|scope| {
-            for _ in 0..threads {
-                scope.spawn(worker);
-            }
+        return;
+    }
+
+    #[cfg(feature = "unsafe")]
+    pool::run(threads, worker);
+    #[cfg(not(feature = "unsafe"))]
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(worker);
+        }
+    });
+}
+
+/// Run the provided function using several different thread counts, asserting it returns the same
+/// result each time.
+///
+/// Intended for tests of code using parallel reductions, such as [`par_map_reduce`], to check the
+/// result doesn't depend on how work is split between threads. The previous thread count is
+/// restored afterwards.
+///
+/// # Panics
+///
+/// Panics if the results differ.
+///
+/// # Examples
+/// ```
+/// # use utils::multithreading::{assert_thread_count_independent, par_map_reduce};
+/// let items: Vec<f64> = (1..=10_000).map(|x| 1.0 / f64::from(x)).collect();
+/// let sum = assert_thread_count_independent(|| {
+///     par_map_reduce(&items, 100, |&x| x, |a, b| a + b).unwrap()
+/// });
+/// assert!((sum - 9.787_606).abs() < 1e-6);
+/// ```
+pub fn assert_thread_count_independent<T: PartialEq + Debug>(f: impl Fn() -> T) -> T {
+    let previous = get_thread_count();
+
+    set_thread_count(NonZeroUsize::new(1).unwrap());
+    let expected = f();
+    for threads in [2, 3, 8] {
+        set_thread_count(NonZeroUsize::new(threads).unwrap());
+        assert_eq!(f(), expected, "result differs using {threads} threads");
+    }
+
+    set_thread_count(previous);
+    expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_sum() {
+        let items: Vec<f32> = (1..=50_000u16)
+            .map(|x| f32::from(x).sqrt().sin() * 1e3)
+            .collect();
+        for chunk_size in [1, 7, 64, 1000] {
+            assert_thread_count_independent(|| {
+                par_map_reduce(&items, chunk_size, |&x| x, |a, b| a + b)
+            });
+        }
+    }
+
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn worker_pool_panic() {
+        let runs = AtomicUsize::new(0);
+        let result = catch_unwind(|| {
+            worker_pool(|| {
+                assert_ne!(runs.fetch_add(1, Relaxed), 0, "worker panicked");
+            });
+        });
+        assert!(result.is_err());
+
+        // All workers finish before the panic is propagated, and the pool is still usable after
+        let completed = runs.load(Relaxed);
+        worker_pool(|| {
+            runs.fetch_add(1, Relaxed);
         });
+        assert!(runs.load(Relaxed) > completed);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let items: Vec<i8> = (0..10_000)
+            .map(|x| i8::try_from((x * 37) % 255 - 127).unwrap())
+            .collect();
+        for chunk_size in [1, 3, 100] {
+            assert_thread_count_independent(|| {
+                par_map_reduce(&items, chunk_size, |&x| x, i8::saturating_add)
+            });
+        }
     }
 }
@@ -0,0 +1,115 @@
+//! Persistent worker threads for [`worker_pool`](super::worker_pool).
+//!
+//! Each idle thread waits for jobs on its own channel. Threads are taken from the idle list for
+//! the duration of a call and returned afterwards, and exit once their sender is dropped.
+
+use super::get_thread_count;
+use std::any::Any;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread;
+
+static IDLE: Mutex<Vec<Sender<Job>>> = Mutex::new(Vec::new());
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Stop idle threads beyond the provided limit.
+pub(super) fn set_idle_limit(limit: usize) {
+    IDLE.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .truncate(limit);
+}
+
+/// Run `threads` copies of the worker, one on the current thread and the rest on pool threads.
+///
+/// New threads are spawned when there aren't enough idle threads, for example when called from
+/// inside another worker.
+pub(super) fn run(threads: usize, worker: impl Fn() + Copy + Send) {
+    let mut senders = {
+        let mut idle = IDLE.lock().unwrap_or_else(PoisonError::into_inner);
+        let len = idle.len();
+        idle.split_off(len.saturating_sub(threads - 1))
+    };
+    while senders.len() < threads - 1 {
+        senders.push(spawn_pool_thread());
+    }
+
+    let latch = Arc::new(Latch {
+        mutex: Mutex::new((senders.len(), None)),
+        condvar: Condvar::new(),
+    });
+    for sender in &senders {
+        let job_latch = latch.clone();
+        let job: Box<dyn FnOnce() + Send + '_> = Box::new(move || {
+            // The worker is moved into catch_unwind and dropped before the latch is released
+            let result = catch_unwind(AssertUnwindSafe(worker));
+            job_latch.release(result.err());
+        });
+        // SAFETY: The latch is waited on below before returning, even if the worker on this thread
+        // panics, so the job can't outlive the data borrowed by the worker
+        let job = unsafe { std::mem::transmute::<Box<dyn FnOnce() + Send + '_>, Job>(job) };
+        if let Err(mpsc::SendError(job)) = sender.send(job) {
+            // The pool thread exited, so run its copy of the worker on this thread instead
+            job();
+        }
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(worker));
+    let panicked = latch.wait();
+
+    let mut idle = IDLE.lock().unwrap_or_else(PoisonError::into_inner);
+    let keep = (get_thread_count().get() - 1).saturating_sub(idle.len());
+    idle.extend(senders.into_iter().take(keep));
+    drop(idle);
+
+    if let Err(payload) = result {
+        resume_unwind(payload);
+    }
+    if let Some(payload) = panicked {
+        resume_unwind(payload);
+    }
+}
+
+fn spawn_pool_thread() -> Sender<Job> {
+    let (sender, receiver) = mpsc::channel::<Job>();
+    thread::Builder::new()
+        .name("worker".to_string())
+        .spawn(move || {
+            for job in receiver {
+                job();
+            }
+        })
+        .expect("failed to spawn worker thread");
+    sender
+}
+
+/// Counts the jobs still running for a [`run`] call, storing the first panic payload.
+struct Latch {
+    mutex: Mutex<(usize, Option<Box<dyn Any + Send>>)>,
+    condvar: Condvar,
+}
+
+impl Latch {
+    fn release(&self, panic: Option<Box<dyn Any + Send>>) {
+        let mut guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        guard.0 -= 1;
+        if guard.1.is_none() {
+            guard.1 = panic;
+        }
+        if guard.0 == 0 {
+            self.condvar.notify_all();
+        }
+    }
+
+    fn wait(&self) -> Option<Box<dyn Any + Send>> {
+        let mut guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        while guard.0 > 0 {
+            guard = self
+                .condvar
+                .wait(guard)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        guard.1.take()
+    }
+}
@@ -3,7 +3,7 @@
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
-use std::sync::{LazyLock, OnceLock};
+use std::sync::{LazyLock, Mutex, OnceLock, PoisonError};
 
 /// Macro to generate multiversioned functions.
 ///
@@ -148,38 +148,43 @@ macro_rules! multiversion {
 
     // Microbenchmark for dynamic dispatch
     (fastest($name:ident())) => {
-        ::std::sync::LazyLock::new(#[cfg_attr(target_family = "wasm", expect(unreachable_code))] || {
-            use $crate::multiversion::Version::*;
-
-            // Instant::now() isn't implemented in WebAssembly, so hardcode implementations
-            #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
-            return Array256;
-            #[cfg(all(target_family = "wasm"))]
-            return Array128;
+        ::std::sync::LazyLock::new(|| {
+            #[cfg_attr(target_family = "wasm", expect(unreachable_code))]
+            fn select() -> $crate::multiversion::Version {
+                use $crate::multiversion::Version::*;
+
+                // Instant::now() isn't implemented in WebAssembly, so hardcode implementations
+                #[cfg(all(target_family = "wasm", target_feature = "simd128"))]
+                return Array256;
+                #[cfg(all(target_family = "wasm"))]
+                return Array128;
+
+                if let Some(version) = $crate::multiversion::Version::get_override() {
+                    return version;
+                }
 
-            if let Some(version) = $crate::multiversion::Version::get_override() {
-                return version;
+                $crate::multiversion::VERSIONS
+                    .iter()
+                    .map(|&x| {
+                        let start = ::std::time::Instant::now();
+                        ::std::hint::black_box(match x {
+                            Scalar => scalar::$name(),
+                            Array128 => array128::$name(),
+                            Array256 => array256::$name(),
+                            #[cfg(not(target_family = "wasm"))]
+                            Array4096 => array4096::$name(),
+                            #[cfg(all(feature="unsafe", any(target_arch = "x86", target_arch = "x86_64")))]
+                            AVX2 => unsafe { avx2::$name() },
+                        });
+                        (start.elapsed(), x)
+                    })
+                    // .inspect(|x| { dbg!(x); })
+                    .min_by_key(|x| x.0)
+                    .unwrap()
+                    .1
             }
 
-            $crate::multiversion::VERSIONS
-                .iter()
-                .map(|&x| {
-                    let start = ::std::time::Instant::now();
-                    ::std::hint::black_box(match x {
-                        Scalar => scalar::$name(),
-                        Array128 => array128::$name(),
-                        Array256 => array256::$name(),
-                        #[cfg(not(target_family = "wasm"))]
-                        Array4096 => array4096::$name(),
-                        #[cfg(all(feature="unsafe", any(target_arch = "x86", target_arch = "x86_64")))]
-                        AVX2 => unsafe { avx2::$name() },
-                    });
-                    (start.elapsed(), x)
-                })
-                // .inspect(|x| { dbg!(x); })
-                .min_by_key(|x| x.0)
-                .unwrap()
-                .1
+            $crate::multiversion::record_selection(module_path!(), select())
         })
     };
 
@@ -393,6 +398,30 @@ impl Version {
     }
 }
 
+static SELECTED: Mutex<Vec<(&'static str, Version)>> = Mutex::new(Vec::new());
+
+/// Record the version selected by a dynamic dispatch microbenchmark, returning it unchanged.
+///
+/// Used by the `fastest` rule of [`multiversion!`](crate::multiversion!).
+#[doc(hidden)]
+pub fn record_selection(module: &'static str, version: Version) -> Version {
+    SELECTED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((module, version));
+    version
+}
+
+/// Returns the module path and selected version for each dynamic dispatch microbenchmark which has
+/// been evaluated, in the order they were evaluated.
+#[must_use]
+pub fn selected_versions() -> Vec<(&'static str, Version)> {
+    SELECTED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
 /// Error type returned when trying to convert an invalid string to a [`Version`].
 #[derive(Debug)]
 pub struct UnknownVersion(String);
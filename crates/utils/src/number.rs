@@ -7,6 +7,11 @@ use std::ops::{
     Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+mod ratio;
+mod stats;
+pub use ratio::Ratio;
+pub use stats::{mean, median, mode, std_dev, weighted_mean};
+
 /// Trait implemented by the primitive number types, combining common supertraits.
 pub trait Number:
     Copy
@@ -400,3 +405,340 @@ pub fn mod_pow<T: UnsignedInteger>(base: T, exponent: T, modulus: T) -> T {
 
     result
 }
+
+/// Precomputed [Barrett reduction](https://en.wikipedia.org/wiki/Barrett_reduction) for a fixed
+/// [`u64`] modulus.
+///
+/// Replaces the division in `x % modulus` with a multiplication and shift, which is significantly
+/// faster in hot loops where the modulus is only known at runtime. When the modulus is a constant,
+/// the compiler already performs a similar optimization for `%`, but creating the reducer in a
+/// `const` still allows code to be written once for both cases.
+///
+/// # Examples
+/// ```
+/// # use utils::number::Barrett;
+/// const REDUCER: Barrett = Barrett::new(2147483647);
+/// assert_eq!(REDUCER.reduce(u64::MAX), u64::MAX % 2147483647);
+/// assert_eq!(REDUCER.mul_mod(1092455, 16807), (1092455 * 16807) % 2147483647);
+///
+/// let runtime = Barrett::new(1_000_000_007);
+/// assert_eq!(runtime.reduce(123_456_789_012_345), 123_456_789_012_345 % 1_000_000_007);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Barrett {
+    modulus: u64,
+    factor: u128,
+}
+
+impl Barrett {
+    /// Precompute the reduction factor for the provided modulus.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    #[inline]
+    #[must_use]
+    pub const fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be non-zero");
+        Self {
+            modulus,
+            factor: (1u128 << 64) / modulus as u128,
+        }
+    }
+
+    /// Returns the modulus.
+    #[inline]
+    #[must_use]
+    pub const fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Calculates `x % modulus`.
+    #[inline]
+    #[must_use]
+    pub const fn reduce(self, x: u64) -> u64 {
+        // The quotient estimate is at most one less than the true quotient
+        let quotient = ((x as u128 * self.factor) >> 64) as u64;
+        let remainder = x - quotient * self.modulus;
+        if remainder >= self.modulus {
+            remainder - self.modulus
+        } else {
+            remainder
+        }
+    }
+
+    /// Calculates `(a * b) % modulus`.
+    ///
+    /// The product `a * b` must not overflow a [`u64`], which is always the case for reduced
+    /// inputs when the modulus is less than 2<sup>32</sup>.
+    #[inline]
+    #[must_use]
+    pub const fn mul_mod(self, a: u64, b: u64) -> u64 {
+        self.reduce(a * b)
+    }
+}
+
+/// Computes a smallest prime factor table for all numbers below `limit` using a linear sieve.
+///
+/// Entries for `0` and `1` are set to `0`. Every prime `p` has `table[p] == p`, which allows any
+/// number below the limit to be factorized by repeatedly dividing by its smallest prime factor.
+///
+/// # Examples
+/// ```
+/// # use utils::number::smallest_prime_factors;
+/// let spf = smallest_prime_factors(16);
+/// assert_eq!(spf, vec![0, 0, 2, 3, 2, 5, 2, 7, 2, 3, 2, 11, 2, 13, 2, 3]);
+/// ```
+#[must_use]
+#[expect(clippy::cast_possible_truncation)]
+pub fn smallest_prime_factors(limit: usize) -> Vec<u32> {
+    assert!(
+        u32::try_from(limit).is_ok(),
+        "limit must fit in a u32 to be stored in the table"
+    );
+
+    let mut table = vec![0u32; limit];
+    let mut primes = Vec::new();
+
+    for i in 2..limit {
+        if table[i] == 0 {
+            table[i] = i as u32;
+            primes.push(i as u32);
+        }
+
+        let spf = table[i];
+        for &p in &primes {
+            let multiple = i * p as usize;
+            if p > spf || multiple >= limit {
+                break;
+            }
+            table[multiple] = p;
+        }
+    }
+
+    table
+}
+
+/// Computes the number of divisors for all numbers below `limit`.
+///
+/// The entry for `0` is set to `0`.
+///
+/// # Examples
+/// ```
+/// # use utils::number::divisor_count_sieve;
+/// let counts = divisor_count_sieve(13);
+/// assert_eq!(counts, vec![0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+/// ```
+#[must_use]
+pub fn divisor_count_sieve(limit: usize) -> Vec<u32> {
+    let mut counts = vec![0u32; limit];
+    for d in 1..limit {
+        for multiple in (d..limit).step_by(d) {
+            counts[multiple] += 1;
+        }
+    }
+    counts
+}
+
+/// Computes the sum of divisors for all numbers below `limit`.
+///
+/// The entry for `0` is set to `0`.
+///
+/// # Examples
+/// ```
+/// # use utils::number::divisor_sum_sieve;
+/// let sums = divisor_sum_sieve(13);
+/// assert_eq!(sums, vec![0, 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+/// ```
+#[must_use]
+pub fn divisor_sum_sieve(limit: usize) -> Vec<u64> {
+    let mut sums = vec![0u64; limit];
+    for d in 1..limit {
+        for multiple in (d..limit).step_by(d) {
+            sums[multiple] += d as u64;
+        }
+    }
+    sums
+}
+
+/// Factorizes `n` into its prime factors.
+///
+/// Returns a list of `(prime, exponent)` pairs sorted by prime. Small factors are removed using
+/// trial division, with any remaining factors found using Pollard's rho algorithm and a
+/// deterministic Miller-Rabin primality test.
+///
+/// # Examples
+/// ```
+/// # use utils::number::factorize;
+/// assert_eq!(factorize(1), vec![]);
+/// assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(600851475143), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+/// assert_eq!(factorize(18446744073709551557), vec![(18446744073709551557, 1)]);
+/// assert_eq!(
+///     factorize(4294967291 * 4294967279),
+///     vec![(4294967279, 1), (4294967291, 1)],
+/// );
+/// assert_eq!(
+///     factorize(18446744073709551443),
+///     vec![(3183958073, 1), (5793651691, 1)],
+/// );
+/// ```
+#[must_use]
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut exponent = 0;
+        while n % p == 0 {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+    }
+
+    let mut stack = Vec::new();
+    if n > 1 {
+        stack.push(n);
+    }
+    let mut large = Vec::new();
+    while let Some(n) = stack.pop() {
+        if miller_rabin(n) {
+            large.push(n);
+        } else {
+            let d = pollard_rho(n);
+            stack.push(d);
+            stack.push(n / d);
+        }
+    }
+
+    large.sort_unstable();
+    for p in large {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+
+    factors
+}
+
+#[inline]
+#[expect(clippy::cast_possible_truncation)]
+fn mul_mod_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((u128::from(a) * u128::from(b)) % u128::from(modulus)) as u64
+}
+
+/// Computes `(x * x + c) % modulus`, adding in u128 as the sum overflows a u64 when the modulus is
+/// close to [`u64::MAX`].
+#[inline]
+#[expect(clippy::cast_possible_truncation)]
+fn square_add_mod_u64(x: u64, c: u64, modulus: u64) -> u64 {
+    ((u128::from(x) * u128::from(x) + u128::from(c)) % u128::from(modulus)) as u64
+}
+
+#[inline]
+fn pow_mod_u64(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod_u64(result, base, modulus);
+        }
+        base = mul_mod_u64(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller-Rabin primality test, valid for all 64-bit integers.
+fn miller_rabin(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'bases: for a in BASES {
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// Finds a non-trivial factor of the odd composite `n` using Brent's variant of Pollard's rho.
+#[expect(clippy::many_single_char_names)]
+fn pollard_rho(n: u64) -> u64 {
+    const BATCH: u32 = 128;
+
+    for c in 1u64.. {
+        let f = |x: u64| square_add_mod_u64(x, c, n);
+
+        let (mut x, mut y, mut ys) = (0, 2, 0);
+        let mut q = 1;
+        let mut g = 1;
+        let mut r = 1u32;
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod_u64(q, x.abs_diff(y), n);
+                }
+                g = gcd_u64(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        if g == n {
+            // Batched product hit zero, backtrack one step at a time
+            loop {
+                ys = f(ys);
+                g = gcd_u64(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+
+    unreachable!()
+}
+
+#[inline]
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
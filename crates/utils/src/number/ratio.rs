@@ -0,0 +1,298 @@
+use crate::number::SignedInteger;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Exact rational number, stored as a normalized numerator and denominator.
+///
+/// The denominator is always positive and the fraction is always fully reduced, so two equal
+/// ratios always have the same representation. All operations check for overflow: the `checked_*`
+/// methods return [`None`] on overflow, while the operator implementations panic.
+///
+/// # Examples
+/// ```
+/// # use utils::number::Ratio;
+/// let a = Ratio::new(6i128, -4).unwrap();
+/// assert_eq!((a.numer(), a.denom()), (-3, 2));
+///
+/// let b = Ratio::new(1i128, 3).unwrap();
+/// assert_eq!(a + b, Ratio::new(-7, 6).unwrap());
+/// assert_eq!(a - b, Ratio::new(-11, 6).unwrap());
+/// assert_eq!(a * b, Ratio::new(-1, 2).unwrap());
+/// assert_eq!(a / b, Ratio::new(-9, 2).unwrap());
+/// assert!(a < b);
+///
+/// assert_eq!(Ratio::new(1i128, 0), None);
+/// assert_eq!(Ratio::new(i128::MIN, -1), None);
+/// assert_eq!(Ratio::from_integer(i128::MAX).checked_add(Ratio::ONE), None);
+/// assert!(Ratio::new(i128::MIN, 3).unwrap() < Ratio::new(i128::MIN + 1, 3).unwrap());
+/// assert!(Ratio::new(i128::MAX, i128::MAX - 1) < Ratio::new(i128::MAX - 1, i128::MAX - 2));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<T: SignedInteger> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: SignedInteger> Ratio<T> {
+    pub const ZERO: Self = Self {
+        numer: T::ZERO,
+        denom: T::ONE,
+    };
+    pub const ONE: Self = Self {
+        numer: T::ONE,
+        denom: T::ONE,
+    };
+
+    /// Create a new normalized ratio.
+    ///
+    /// Returns [`None`] if the denominator is zero or if normalizing the sign overflows.
+    #[inline]
+    #[must_use]
+    pub fn new(numer: T, denom: T) -> Option<Self> {
+        if denom == T::ZERO {
+            return None;
+        }
+
+        let gcd = gcd(numer, denom);
+        let (mut numer, mut denom) = (numer / gcd, denom / gcd);
+        if denom < T::ZERO {
+            numer = T::ZERO.checked_sub(numer)?;
+            denom = T::ZERO.checked_sub(denom)?;
+        }
+
+        Some(Self { numer, denom })
+    }
+
+    /// Create a ratio representing the provided integer.
+    #[inline]
+    #[must_use]
+    pub fn from_integer(n: T) -> Self {
+        Self {
+            numer: n,
+            denom: T::ONE,
+        }
+    }
+
+    /// Returns the numerator.
+    #[inline]
+    #[must_use]
+    pub fn numer(self) -> T {
+        self.numer
+    }
+
+    /// Returns the denominator, which is always positive.
+    #[inline]
+    #[must_use]
+    pub fn denom(self) -> T {
+        self.denom
+    }
+
+    /// Returns true if this ratio represents an integer.
+    #[inline]
+    #[must_use]
+    pub fn is_integer(self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// Returns the integer this ratio represents, or [`None`] if it is not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::number::Ratio;
+    /// assert_eq!(Ratio::new(12i64, 4).unwrap().to_integer(), Some(3));
+    /// assert_eq!(Ratio::new(12i64, 5).unwrap().to_integer(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_integer(self) -> Option<T> {
+        if self.is_integer() {
+            Some(self.numer)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the largest integer less than or equal to this ratio.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::number::Ratio;
+    /// assert_eq!(Ratio::new(7i64, 2).unwrap().floor(), 3);
+    /// assert_eq!(Ratio::new(-7i64, 2).unwrap().floor(), -4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn floor(self) -> T {
+        div_floor(self.numer, self.denom)
+    }
+
+    /// Checked addition, returning [`None`] on overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let gcd = gcd(self.denom, rhs.denom);
+        let (lhs_scale, rhs_scale) = (rhs.denom / gcd, self.denom / gcd);
+        Self::new(
+            self.numer
+                .checked_mul(lhs_scale)?
+                .checked_add(rhs.numer.checked_mul(rhs_scale)?)?,
+            self.denom.checked_mul(lhs_scale)?,
+        )
+    }
+
+    /// Checked subtraction, returning [`None`] on overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// Checked multiplication, returning [`None`] on overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce first to keep intermediate values small
+        let gcd1 = gcd(self.numer, rhs.denom);
+        let gcd2 = gcd(rhs.numer, self.denom);
+        Self::new(
+            (self.numer / gcd1).checked_mul(rhs.numer / gcd2)?,
+            (self.denom / gcd2).checked_mul(rhs.denom / gcd1)?,
+        )
+    }
+
+    /// Checked division, returning [`None`] on overflow or division by zero.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    /// Checked negation, returning [`None`] on overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numer: T::ZERO.checked_sub(self.numer)?,
+            denom: self.denom,
+        })
+    }
+
+    /// Checked reciprocal, returning [`None`] on overflow or if this ratio is zero.
+    #[inline]
+    #[must_use]
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::new(self.denom, self.numer)
+    }
+}
+
+/// Returns the greatest common divisor, which may be negative.
+///
+/// Returns 1 instead of -1, as dividing the minimum value by -1 overflows.
+#[inline]
+fn gcd<T: SignedInteger>(mut a: T, mut b: T) -> T {
+    while a != T::ZERO {
+        if a == T::ONE || a == T::MINUS_ONE {
+            return T::ONE;
+        }
+        (a, b) = (b % a, a);
+    }
+    if b == T::ZERO {
+        T::ONE
+    } else {
+        b
+    }
+}
+
+/// Floor division for a positive divisor, without overflowing for the minimum value.
+#[inline]
+fn div_floor<T: SignedInteger>(a: T, b: T) -> T {
+    let quotient = a / b;
+    if a % b < T::ZERO {
+        quotient - T::ONE
+    } else {
+        quotient
+    }
+}
+
+impl<T: SignedInteger> From<T> for Ratio<T> {
+    #[inline]
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: SignedInteger + Eq> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare using continued fractions to avoid overflow from cross multiplication
+        let (mut a, mut b) = (self.numer, self.denom);
+        let (mut c, mut d) = (other.numer, other.denom);
+        let mut reversed = false;
+        loop {
+            let (a_floor, c_floor) = (div_floor(a, b), div_floor(c, d));
+            let (a_rem, c_rem) = (a.rem_euclid(b), c.rem_euclid(d));
+
+            let ordering = if a_floor != c_floor {
+                a_floor.partial_cmp(&c_floor).unwrap()
+            } else if a_rem == T::ZERO || c_rem == T::ZERO {
+                (a_rem != T::ZERO).cmp(&(c_rem != T::ZERO))
+            } else {
+                // Compare the reciprocals of the fractional parts, which reverses the order
+                (a, b, c, d) = (b, a_rem, d, c_rem);
+                reversed = !reversed;
+                continue;
+            };
+
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl<T: SignedInteger + Eq> PartialOrd for Ratio<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: SignedInteger + Display> Display for Ratio<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+macro_rules! ratio_ops {
+    ($($t:ident::$f:ident => $checked:ident, $msg:literal;)+) => {$(
+        impl<T: SignedInteger> $t for Ratio<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+    )+};
+}
+ratio_ops! {
+    Add::add => checked_add, "attempt to add with overflow";
+    Sub::sub => checked_sub, "attempt to subtract with overflow";
+    Mul::mul => checked_mul, "attempt to multiply with overflow";
+    Div::div => checked_div, "attempt to divide by zero or with overflow";
+}
+
+impl<T: SignedInteger> Neg for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
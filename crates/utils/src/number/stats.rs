@@ -0,0 +1,114 @@
+use crate::collections::DeterministicHashMap;
+use std::hash::Hash;
+
+/// Computes the arithmetic mean of the provided values.
+///
+/// Returns [`None`] if there are no values.
+///
+/// # Examples
+/// ```
+/// # use utils::number::mean;
+/// assert_eq!(mean([1.0, 2.0, 3.0, 6.0]), Some(3.0));
+/// assert_eq!(mean([]), None);
+/// ```
+#[must_use]
+pub fn mean(values: impl IntoIterator<Item = f64>) -> Option<f64> {
+    weighted_mean(values.into_iter().map(|v| (v, 1.0)))
+}
+
+/// Computes the weighted arithmetic mean of the provided `(value, weight)` pairs.
+///
+/// Returns [`None`] if the total weight is zero.
+///
+/// # Examples
+/// ```
+/// # use utils::number::weighted_mean;
+/// assert_eq!(weighted_mean([(1.0, 3.0), (5.0, 1.0)]), Some(2.0));
+/// assert_eq!(weighted_mean([(1.0, 0.0)]), None);
+/// ```
+#[must_use]
+pub fn weighted_mean(values: impl IntoIterator<Item = (f64, f64)>) -> Option<f64> {
+    let (sum, total_weight) = values
+        .into_iter()
+        .fold((0.0, 0.0), |(sum, total), (v, w)| (sum + v * w, total + w));
+    if total_weight == 0.0 {
+        None
+    } else {
+        Some(sum / total_weight)
+    }
+}
+
+/// Computes the population standard deviation of the provided values.
+///
+/// Uses Welford's algorithm, which avoids the loss of precision from subtracting large sums.
+/// Returns [`None`] if there are no values.
+///
+/// # Examples
+/// ```
+/// # use utils::number::std_dev;
+/// assert_eq!(std_dev([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(2.0));
+/// assert_eq!(std_dev([1e9 + 1.0, 1e9 + 3.0]), Some(1.0));
+/// assert_eq!(std_dev([]), None);
+/// ```
+#[must_use]
+pub fn std_dev(values: impl IntoIterator<Item = f64>) -> Option<f64> {
+    let (mut count, mut mean, mut m2) = (0.0, 0.0, 0.0);
+    for v in values {
+        count += 1.0;
+        let delta = v - mean;
+        mean += delta / count;
+        m2 += delta * (v - mean);
+    }
+    if count == 0.0 {
+        None
+    } else {
+        Some((m2 / count).sqrt())
+    }
+}
+
+/// Returns the median of the provided values, reordering the slice.
+///
+/// The median is found using [`slice::select_nth_unstable`] without fully sorting the slice. For an
+/// even number of values the lower of the two middle values is returned, which is still a value
+/// minimizing the sum of absolute differences. Returns [`None`] if the slice is empty.
+///
+/// # Examples
+/// ```
+/// # use utils::number::median;
+/// assert_eq!(median(&mut [16, 1, 2, 0, 4, 2, 7, 1, 2, 14]), Some(2));
+/// assert_eq!(median(&mut [5, 1, 3]), Some(3));
+/// assert_eq!(median(&mut [4, 1]), Some(1));
+/// assert_eq!(median::<u32>(&mut []), None);
+/// ```
+#[must_use]
+pub fn median<T: Ord + Copy>(values: &mut [T]) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    let index = (values.len() - 1) / 2;
+    let (_, &mut median, _) = values.select_nth_unstable(index);
+    Some(median)
+}
+
+/// Returns the most common value, preferring the smallest value when there is a tie.
+///
+/// Returns [`None`] if there are no values.
+///
+/// # Examples
+/// ```
+/// # use utils::number::mode;
+/// assert_eq!(mode([3, 1, 3, 2, 1, 3]), Some(3));
+/// assert_eq!(mode(["b", "a", "b", "a"]), Some("a"));
+/// assert_eq!(mode::<u8>([]), None);
+/// ```
+#[must_use]
+pub fn mode<T: Ord + Hash + Copy>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut counts = DeterministicHashMap::default();
+    for v in values {
+        *counts.entry(v).or_insert(0usize) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|&(v1, c1), &(v2, c2)| c1.cmp(&c2).then(v2.cmp(&v1)))
+        .map(|(v, _)| v)
+}
@@ -0,0 +1,212 @@
+//! Recognizing letters drawn on pixel grids.
+//!
+//! Some puzzles draw their answer as letters on a grid, using one of two fonts: a small font with
+//! letters 6 pixels high drawn every 5 columns, and a large font with letters 10 pixels high drawn
+//! every 8 columns. Solutions can return [`Letters`], which recognizes the text and falls back to
+//! drawing the grid when a letter isn't recognized.
+
+use crate::answer_image::{self, Image};
+use std::fmt::{self, Debug, Display, Formatter};
+
+struct Font {
+    letters: &'static str,
+    // Letters drawn side by side, each taking width columns
+    rows: &'static [&'static str],
+    width: usize,
+    stride: usize,
+}
+
+/// Letters 6 pixels high, drawn every 5 columns.
+const SMALL: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#...#####.",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#...#.",
+    "#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#...#..",
+    "####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#...#...",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#....",
+    "#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#..####.",
+];
+
+/// Letters 10 pixels high, drawn every 8 columns.
+const LARGE: [&str; 10] = [
+    "..##..#####..####.############.####.#....#...####....##.....#....######.#####.#....#######",
+    ".#..#.#....##....##.....#.....#....##....#....#.#...#.#.....##...##....##....##....#.....#",
+    "#....##....##.....#.....#.....#.....#....#....#.#..#..#.....##...##....##....#.#..#......#",
+    "#....##....##.....#.....#.....#.....#....#....#.#.#...#.....#.#..##....##....#.#..#.....#.",
+    "#....######.#.....#####.#####.#.....######....#.##....#.....#.#..######.#####...##.....#..",
+    "#######....##.....#.....#.....#..####....#....#.##....#.....#..#.##.....#..#....##....#...",
+    "#....##....##.....#.....#.....#....##....#....#.#.#...#.....#..#.##.....#...#..#..#..#....",
+    "#....##....##.....#.....#.....#....##....##...#.#..#..#.....#...###.....#...#..#..#.#.....",
+    "#....##....##....##.....#.....#...###....##...#.#...#.#.....#...###.....#....##....##.....",
+    "#....######..####.#######......###.##....#.###..#....########....##.....#....##....#######",
+];
+
+const FONTS: [Font; 2] = [
+    Font {
+        letters: "ABCEFGHIJKLOPRSUYZ",
+        rows: &SMALL,
+        width: 5,
+        stride: 5,
+    },
+    Font {
+        letters: "ABCEFGHJKLNPRXZ",
+        rows: &LARGE,
+        width: 6,
+        stride: 8,
+    },
+];
+
+/// Recognize the letters drawn on a grid, stored in row-major order.
+///
+/// The font is chosen using the height of the grid. Returns [`None`] if the height doesn't match
+/// either font, or any letter isn't recognized.
+///
+/// # Panics
+///
+/// Panics if the number of pixels doesn't match the width and height.
+///
+/// # Examples
+/// ```
+/// # use utils::ocr;
+/// let grid = [
+///     "#..#..###.",
+///     "#..#...#..",
+///     "####...#..",
+///     "#..#...#..",
+///     "#..#...#..",
+///     "#..#..###.",
+/// ];
+/// let pixels: Vec<bool> = grid.concat().bytes().map(|b| b == b'#').collect();
+/// assert_eq!(ocr::recognize(10, 6, &pixels), Some("HI".to_string()));
+/// assert_eq!(ocr::recognize(5, 12, &pixels), None);
+/// ```
+#[must_use]
+pub fn recognize(width: usize, height: usize, pixels: &[bool]) -> Option<String> {
+    assert_eq!(pixels.len(), width * height, "incorrect number of pixels");
+
+    let font = FONTS.iter().find(|f| f.rows.len() == height)?;
+    if width == 0 {
+        return None;
+    }
+
+    (0..width)
+        .step_by(font.stride)
+        .map(|x| {
+            // Pixels past the end of the grid are treated as off, so the spacing after the last
+            // letter can be omitted
+            let pixel = |dx: usize, y: usize| x + dx < width && pixels[y * width + x + dx];
+
+            font.letters.char_indices().find_map(|(i, c)| {
+                let matches = font.rows.iter().enumerate().all(|(y, row)| {
+                    let glyph = &row.as_bytes()[i * font.width..(i + 1) * font.width];
+                    (0..font.stride).all(|dx| pixel(dx, y) == (glyph.get(dx) == Some(&b'#')))
+                });
+                matches.then_some(c)
+            })
+        })
+        .collect()
+}
+
+/// Answer drawn as letters on a grid.
+///
+/// Displays as the recognized letters, or as the grid drawn using `#` and `.` if any letter isn't
+/// recognized, so the answer can still be read. Can be compared with either form as a string, which
+/// allows [`examples!`](crate::examples!) to use `&'static str` for the answer type.
+///
+/// # Examples
+/// ```
+/// # use utils::ocr::Letters;
+/// let grid = ["####.", "#....", "###..", "#....", "#....", "####."];
+/// let letters = Letters::new(5, 6, grid.concat().bytes().map(|b| b == b'#'));
+/// assert_eq!(letters.text(), Some("E"));
+/// assert_eq!(letters.to_string(), "E");
+/// assert_eq!(letters, "E");
+///
+/// let unknown = Letters::new(3, 2, [true, false, true, false, true, false]);
+/// assert_eq!(unknown.text(), None);
+/// assert_eq!(unknown.to_string(), "#.#\n.#.");
+/// assert_eq!(unknown, "#.#\n.#.");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Letters {
+    image: Image,
+    text: Option<String>,
+}
+
+impl Letters {
+    /// Recognize the letters drawn on a grid, stored in row-major order.
+    ///
+    /// The grid is also passed to [`answer_image::record`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of pixels doesn't match the width and height.
+    #[must_use]
+    pub fn new(width: usize, height: usize, pixels: impl IntoIterator<Item = bool>) -> Self {
+        let pixels: Vec<bool> = pixels.into_iter().collect();
+        let text = recognize(width, height, &pixels);
+        answer_image::record(width, height, pixels.iter().copied());
+        Self {
+            image: Image {
+                width,
+                height,
+                pixels,
+            },
+            text,
+        }
+    }
+
+    /// Returns the recognized letters, if every letter was recognized.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Returns the grid the letters were drawn on.
+    #[must_use]
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the grid drawn using `#` and `.`, with rows separated by newlines.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut output = String::with_capacity((self.image.width + 1) * self.image.height);
+        for (y, row) in self
+            .image
+            .pixels
+            .chunks(self.image.width.max(1))
+            .enumerate()
+        {
+            if y > 0 {
+                output.push('\n');
+            }
+            output.extend(row.iter().map(|&b| if b { '#' } else { '.' }));
+        }
+        output
+    }
+}
+
+impl Display for Letters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.text {
+            Some(text) => f.write_str(text),
+            None => f.write_str(&self.render()),
+        }
+    }
+}
+
+/// Draws the grid instead of listing the pixels, to keep failed assertions readable.
+impl Debug for Letters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Letters")
+            .field("text", &self.text)
+            .field("image", &format_args!("\n{}\n", self.render()))
+            .finish()
+    }
+}
+
+impl PartialEq<&str> for Letters {
+    fn eq(&self, other: &&str) -> bool {
+        self.text.as_deref() == Some(*other) || self.render() == *other
+    }
+}
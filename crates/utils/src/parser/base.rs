@@ -1,7 +1,7 @@
 use crate::input::{InputError, MapWithInputExt};
 use crate::parser::combinator::{
-    Map, MapResult, Optional, Or, RepeatArrayVec, RepeatN, RepeatVec, WithConsumed, WithPrefix,
-    WithSuffix,
+    Map, MapResult, Optional, Or, RepeatArrayVec, RepeatN, RepeatUntil, RepeatVec, WithConsumed,
+    WithPrefix, WithSuffix,
 };
 use crate::parser::error::{ParseError, WithErrorMsg};
 use crate::parser::iterator::{ParserIterator, ParserMatchesIterator};
@@ -234,6 +234,45 @@ pub trait Parser: Sized {
         }
     }
 
+    /// Repeat this parser until the terminator matches, returning a [`Vec`].
+    ///
+    /// The terminator is tried before each item. If `consume_terminator` is false, the terminator
+    /// is only peeked and the remaining input starts with it. If neither the terminator nor the
+    /// item parser matches, the error from the parser which processed further is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use utils::parser::{self, ParseError, Parser};
+    /// let parser = parser::u32()
+    ///     .with_suffix(parser::eol())
+    ///     .repeat_until(parser::eol(), true);
+    /// assert_eq!(
+    ///     parser.parse(b"12\n34\n\n56\n"),
+    ///     Ok((vec![12, 34], &b"56\n"[..]))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse(b"12\n34\n5a\n"),
+    ///     Err((ParseError::Expected("newline or end of input"), &b"a\n"[..]))
+    /// );
+    ///
+    /// let parser = parser::u32()
+    ///     .with_suffix(",")
+    ///     .repeat_until(";", false);
+    /// assert_eq!(parser.parse(b"1,2,;3"), Ok((vec![1, 2], &b";3"[..])));
+    /// assert_eq!(parser.parse(b";"), Ok((vec![], &b";"[..])));
+    /// ```
+    fn repeat_until<T: Parser>(
+        self,
+        terminator: T,
+        consume_terminator: bool,
+    ) -> RepeatUntil<Self, T> {
+        RepeatUntil {
+            parser: self,
+            terminator,
+            consume_terminator,
+        }
+    }
+
     /// Return the output of this parser as well as the bytes consumed.
     ///
     /// This can be used to map any errors that occur while processing the parsed input back to the
@@ -425,7 +464,7 @@ pub trait Parser: Sized {
     /// assert_eq!(filtered, vec![22, 44]);
     /// # Ok::<(), InputError>(())
     /// ```
-    fn parse_iterator(self, input: &str) -> ParserIterator<Self> {
+    fn parse_iterator(self, input: &str) -> ParserIterator<'_, Self> {
         ParserIterator {
             input,
             remaining: input.as_bytes(),
@@ -449,7 +488,7 @@ pub trait Parser: Sized {
     ///     vec![123, 456, 7, 8, 9]
     /// );
     /// ```
-    fn matches_iterator(self, input: &str) -> ParserMatchesIterator<Self> {
+    fn matches_iterator(self, input: &str) -> ParserMatchesIterator<'_, Self> {
         ParserMatchesIterator {
             remaining: input.as_bytes(),
             parser: self,
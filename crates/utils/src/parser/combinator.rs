@@ -207,6 +207,47 @@ impl<P: Parser, S: Parser> Parser for RepeatVec<P, S> {
     }
 }
 
+#[derive(Copy, Clone)]
+pub struct RepeatUntil<P, T> {
+    pub(super) parser: P,
+    pub(super) terminator: T,
+    pub(super) consume_terminator: bool,
+}
+impl<P: Parser, T: Parser> Parser for RepeatUntil<P, T> {
+    type Output<'i> = Vec<P::Output<'i>>;
+    type Then<U: Parser> = Then2<Self, U>;
+
+    #[inline]
+    fn parse<'i>(&self, mut input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        let mut output = Vec::new();
+        loop {
+            let (err1, remaining1) = match self.terminator.parse(input) {
+                Ok((_, remaining)) if self.consume_terminator => return Ok((output, remaining)),
+                Ok(_) => return Ok((output, input)),
+                Err(e) => e,
+            };
+
+            match self.parser.parse(input) {
+                Ok((v, remaining)) => {
+                    let consumed = input.len() - remaining.len();
+                    assert!(consumed > 0, "parsing item consumed no input");
+
+                    output.push(v);
+                    input = remaining;
+                }
+                Err((err2, remaining2)) => {
+                    // Return error from the parser which processed further, or the item's if equal
+                    return Err(if remaining1.len() < remaining2.len() {
+                        (err1, remaining1)
+                    } else {
+                        (err2, remaining2)
+                    });
+                }
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct Or<A, B> {
     pub(super) first: A,
@@ -294,3 +335,111 @@ impl<A: Parser, B: Parser> Parser for WithSuffix<A, B> {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct SeparatedPair<A, S, B> {
+    first: A,
+    separator: S,
+    second: B,
+}
+impl<A: Parser, S: Parser, B: Parser> Parser for SeparatedPair<A, S, B> {
+    type Output<'i> = (A::Output<'i>, B::Output<'i>);
+    type Then<T: Parser> = Then2<Self, T>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        match self.first.parse(input) {
+            Ok((a, remaining1)) => match self.separator.parse(remaining1) {
+                Ok((_, remaining2)) => match self.second.parse(remaining2) {
+                    Ok((b, remaining3)) => Ok(((a, b), remaining3)),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Parser that parses two values separated by a separator (normally a string literal).
+///
+/// The result of the separator parser is discarded. Equivalent to
+/// `first.then(second.with_prefix(separator))`.
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{self, Parser};
+/// assert_eq!(
+///     parser::separated_pair(parser::u32(), " -> ", parser::i32())
+///         .parse(b"123 -> -45,"),
+///     Ok(((123, -45), &b","[..]))
+/// );
+/// assert!(parser::separated_pair(parser::u32(), " -> ", parser::i32())
+///     .parse(b"123 => -45")
+///     .is_err());
+/// ```
+#[must_use]
+pub fn separated_pair<A: Parser, S: Parser, B: Parser>(
+    first: A,
+    separator: S,
+    second: B,
+) -> SeparatedPair<A, S, B> {
+    SeparatedPair {
+        first,
+        separator,
+        second,
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Delimited<O, P, C> {
+    open: O,
+    parser: P,
+    close: C,
+}
+impl<O: Parser, P: Parser, C: Parser> Parser for Delimited<O, P, C> {
+    type Output<'i> = P::Output<'i>;
+    type Then<T: Parser> = Then2<Self, T>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        match self.open.parse(input) {
+            Ok((_, remaining1)) => match self.parser.parse(remaining1) {
+                Ok((v, remaining2)) => match self.close.parse(remaining2) {
+                    Ok((_, remaining3)) => Ok((v, remaining3)),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Parser that parses a value between an opening and closing delimiter (normally string
+/// literals).
+///
+/// The results of the delimiter parsers are discarded. Equivalent to
+/// `parser.with_prefix(open).with_suffix(close)`.
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{self, Parser};
+/// assert_eq!(
+///     parser::delimited("<", parser::u32(), ">").parse(b"<123>456"),
+///     Ok((123, &b"456"[..]))
+/// );
+/// assert!(parser::delimited("<", parser::u32(), ">").parse(b"<123").is_err());
+/// ```
+#[must_use]
+pub fn delimited<O: Parser, P: Parser, C: Parser>(
+    open: O,
+    parser: P,
+    close: C,
+) -> Delimited<O, P, C> {
+    Delimited {
+        open,
+        parser,
+        close,
+    }
+}
@@ -27,6 +27,10 @@ pub enum ParseError {
     ///
     /// Used as a fallback if min/max bound doesn't fit in an [`i128`] (for example, [`u128::MAX`]).
     NumberOutOfRange(),
+    /// Expected number between $min and $max.
+    ///
+    /// Returned by [`bounded`](super::bounded), listing the allowed range.
+    NumberOutOfBounds(i128, i128),
     /// Custom error returned by [`Parser::map_res`] & [`Parser::error_msg`].
     Custom(&'static str),
 }
@@ -71,6 +75,9 @@ impl Display for ParseError {
             ParseError::NumberTooLarge(x) => write!(f, "expected number <= {x}"),
             ParseError::NumberTooSmall(x) => write!(f, "expected number >= {x}"),
             ParseError::NumberOutOfRange() => write!(f, "number out of range"),
+            ParseError::NumberOutOfBounds(min, max) => {
+                write!(f, "expected number between {min} and {max}")
+            }
             ParseError::Custom(x) => f.write_str(x),
         }
     }
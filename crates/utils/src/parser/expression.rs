@@ -0,0 +1,295 @@
+use crate::parser::then::Then2;
+use crate::parser::{ParseError, ParseResult, Parser};
+
+/// Associativity of an [`Operator`], deciding how chains of operators with the same precedence are
+/// grouped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a - b - c` is parsed as `(a - b) - c`.
+    Left,
+    /// `a ^ b ^ c` is parsed as `a ^ (b ^ c)`.
+    Right,
+}
+
+/// Binary infix operator used by [`expression`] and [`expression_tree`].
+///
+/// Operators with higher precedence bind more tightly.
+#[derive(Copy, Clone, Debug)]
+pub struct Operator<T> {
+    pub symbol: &'static str,
+    pub precedence: u8,
+    pub associativity: Associativity,
+    pub apply: fn(T, T) -> T,
+}
+
+impl<T> Operator<T> {
+    /// Create a left associative operator.
+    #[must_use]
+    pub const fn left(symbol: &'static str, precedence: u8, apply: fn(T, T) -> T) -> Self {
+        Self {
+            symbol,
+            precedence,
+            associativity: Associativity::Left,
+            apply,
+        }
+    }
+
+    /// Create a right associative operator.
+    #[must_use]
+    pub const fn right(symbol: &'static str, precedence: u8, apply: fn(T, T) -> T) -> Self {
+        Self {
+            symbol,
+            precedence,
+            associativity: Associativity::Right,
+            apply,
+        }
+    }
+}
+
+/// Expression tree returned by [`expression_tree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr<T> {
+    Value(T),
+    /// Operator symbol and its left and right operands.
+    Binary(&'static str, Box<Expr<T>>, Box<Expr<T>>),
+}
+
+impl<T: Clone> Expr<T> {
+    /// Evaluate the tree, applying each operator from the provided table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree contains an operator symbol not in the table.
+    #[must_use]
+    pub fn evaluate(&self, operators: &[Operator<T>]) -> T {
+        match self {
+            Expr::Value(v) => v.clone(),
+            Expr::Binary(symbol, left, right) => {
+                let operator = operators
+                    .iter()
+                    .find(|o| o.symbol == *symbol)
+                    .expect("operator should be in the table");
+                (operator.apply)(left.evaluate(operators), right.evaluate(operators))
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Expression<'a, P, T> {
+    atom: P,
+    operators: &'a [Operator<T>],
+}
+impl<P: for<'i> Parser<Output<'i> = T>, T> Parser for Expression<'_, P, T> {
+    type Output<'i> = T;
+    type Then<U: Parser> = Then2<Self, U>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        parse_expression(&self.atom, self.operators, input, 0, &|x| x, &|o, l, r| {
+            (o.apply)(l, r)
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ExpressionTree<'a, P, T> {
+    atom: P,
+    operators: &'a [Operator<T>],
+}
+impl<P: for<'i> Parser<Output<'i> = T>, T> Parser for ExpressionTree<'_, P, T> {
+    type Output<'i> = Expr<T>;
+    type Then<U: Parser> = Then2<Self, U>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        parse_expression(
+            &self.atom,
+            self.operators,
+            input,
+            0,
+            &Expr::Value,
+            &|o, l, r| Expr::Binary(o.symbol, Box::new(l), Box::new(r)),
+        )
+    }
+}
+
+/// Parser for infix expressions, evaluating them using the provided operator table.
+///
+/// Operands are parsed using `atom`, and can be grouped using parentheses. Spaces and tabs are
+/// skipped around operators and parentheses. When several operator symbols match, the longest is
+/// used. Parsing stops before the first byte which isn't an operator.
+///
+/// # Examples
+/// Standard precedence rules:
+/// ```
+/// # use utils::parser::{self, Operator, Parser};
+/// const STANDARD: &[Operator<i64>] = &[
+///     Operator::left("+", 1, |a, b| a + b),
+///     Operator::left("-", 1, |a, b| a - b),
+///     Operator::left("*", 2, |a, b| a * b),
+///     Operator::left("/", 2, |a, b| a / b),
+///     Operator::right("**", 3, |a, b| a.pow(b.try_into().unwrap())),
+/// ];
+/// let parser = parser::expression(parser::i64(), STANDARD);
+/// assert_eq!(parser.parse_complete("1 + 2 * 3 + 4 * 5 + 6").unwrap(), 33);
+/// assert_eq!(parser.parse_complete("2 * (3 + 4) - 10 / 2 - 1").unwrap(), 8);
+/// assert_eq!(parser.parse_complete("2 ** 3 ** 2").unwrap(), 512);
+/// assert_eq!(parser.parse_complete("((2))*-3").unwrap(), -6);
+/// ```
+///
+/// 2020 day 18 style flipped precedence, where addition binds more tightly than multiplication:
+/// ```
+/// # use utils::parser::{self, Operator, Parser};
+/// const FLIPPED: &[Operator<u64>] = &[
+///     Operator::left("+", 2, |a, b| a + b),
+///     Operator::left("*", 1, |a, b| a * b),
+/// ];
+/// let parser = parser::expression(parser::u64(), FLIPPED);
+/// assert_eq!(parser.parse_complete("1 + 2 * 3 + 4 * 5 + 6").unwrap(), 231);
+/// assert_eq!(parser.parse_complete("2 * 3 + (4 * 5)").unwrap(), 46);
+/// assert_eq!(
+///     parser.parse_complete("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap(),
+///     23340,
+/// );
+///
+/// // Parsing stops before the first unknown operator
+/// assert_eq!(parser.parse(b"1 + 2 - 3"), Ok((3, &b" - 3"[..])));
+/// ```
+///
+/// Errors point at the unexpected input:
+/// ```
+/// # use utils::parser::{self, Operator, ParseError, Parser};
+/// # const FLIPPED: &[Operator<u64>] = &[
+/// #     Operator::left("+", 2, |a, b| a + b),
+/// #     Operator::left("*", 1, |a, b| a * b),
+/// # ];
+/// let parser = parser::expression(parser::u64(), FLIPPED);
+/// assert_eq!(parser.parse(b"(1 + 2"), Err((ParseError::ExpectedByte(b')'), &b""[..])));
+/// assert_eq!(
+///     parser.parse(b"1 + * 2"),
+///     Err((ParseError::Expected("unsigned integer"), &b"* 2"[..])),
+/// );
+/// ```
+#[must_use]
+pub fn expression<P: for<'i> Parser<Output<'i> = T>, T>(
+    atom: P,
+    operators: &[Operator<T>],
+) -> Expression<'_, P, T> {
+    Expression { atom, operators }
+}
+
+/// Parser for infix expressions, returning an [`Expr`] tree.
+///
+/// Parses the same syntax as [`expression`], but builds a tree instead of evaluating it, which can
+/// later be evaluated using [`Expr::evaluate`].
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{self, Expr, Operator, Parser};
+/// const STANDARD: &[Operator<u32>] = &[
+///     Operator::left("+", 1, |a, b| a + b),
+///     Operator::left("*", 2, |a, b| a * b),
+/// ];
+/// let tree = parser::expression_tree(parser::u32(), STANDARD)
+///     .parse_complete("1 + 2 * (3 + 4)")
+///     .unwrap();
+/// assert_eq!(
+///     tree,
+///     Expr::Binary(
+///         "+",
+///         Box::new(Expr::Value(1)),
+///         Box::new(Expr::Binary(
+///             "*",
+///             Box::new(Expr::Value(2)),
+///             Box::new(Expr::Binary(
+///                 "+",
+///                 Box::new(Expr::Value(3)),
+///                 Box::new(Expr::Value(4)),
+///             )),
+///         )),
+///     ),
+/// );
+/// assert_eq!(tree.evaluate(STANDARD), 15);
+/// ```
+#[must_use]
+pub fn expression_tree<P: for<'i> Parser<Output<'i> = T>, T>(
+    atom: P,
+    operators: &[Operator<T>],
+) -> ExpressionTree<'_, P, T> {
+    ExpressionTree { atom, operators }
+}
+
+fn skip_spaces(input: &[u8]) -> &[u8] {
+    let len = input
+        .iter()
+        .position(|&b| b != b' ' && b != b'\t')
+        .unwrap_or(input.len());
+    &input[len..]
+}
+
+/// Precedence climbing, only continuing while operators bind at least as tightly as
+/// `min_precedence`.
+fn parse_expression<'i, P: for<'x> Parser<Output<'x> = T>, T, U>(
+    atom: &P,
+    operators: &[Operator<T>],
+    input: &'i [u8],
+    min_precedence: u16,
+    leaf: &impl Fn(T) -> U,
+    combine: &impl Fn(&Operator<T>, U, U) -> U,
+) -> ParseResult<'i, U> {
+    let (mut left, mut input) = parse_operand(atom, operators, input, leaf, combine)?;
+
+    loop {
+        let rest = skip_spaces(input);
+        let Some(operator) = operators
+            .iter()
+            .filter(|o| rest.starts_with(o.symbol.as_bytes()))
+            .max_by_key(|o| o.symbol.len())
+        else {
+            break;
+        };
+
+        let precedence = u16::from(operator.precedence);
+        if precedence < min_precedence {
+            break;
+        }
+        let next_min = match operator.associativity {
+            Associativity::Left => precedence + 1,
+            Associativity::Right => precedence,
+        };
+
+        let (right, rest) = parse_expression(
+            atom,
+            operators,
+            &rest[operator.symbol.len()..],
+            next_min,
+            leaf,
+            combine,
+        )?;
+        left = combine(operator, left, right);
+        input = rest;
+    }
+
+    Ok((left, input))
+}
+
+fn parse_operand<'i, P: for<'x> Parser<Output<'x> = T>, T, U>(
+    atom: &P,
+    operators: &[Operator<T>],
+    input: &'i [u8],
+    leaf: &impl Fn(T) -> U,
+    combine: &impl Fn(&Operator<T>, U, U) -> U,
+) -> ParseResult<'i, U> {
+    let input = skip_spaces(input);
+    if let [b'(', rest @ ..] = input {
+        let (value, rest) = parse_expression(atom, operators, rest, 0, leaf, combine)?;
+        return match skip_spaces(rest) {
+            [b')', rest @ ..] => Ok((value, rest)),
+            rest => Err((ParseError::ExpectedByte(b')'), rest)),
+        };
+    }
+
+    let (value, rest) = atom.parse(input)?;
+    Ok((leaf(value), rest))
+}
@@ -0,0 +1,215 @@
+use crate::parser::then::Then2;
+use crate::parser::{ParseError, ParseResult, Parser};
+
+/// Value returned by the [`json`] parser.
+///
+/// Strings borrow from the input and are returned as they appear in the document, without escape
+/// sequences being decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonValue<'i> {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(&'i str),
+    Array(Vec<JsonValue<'i>>),
+    /// Object members in the order they appear in the input.
+    Object(Vec<(&'i str, JsonValue<'i>)>),
+}
+
+#[derive(Copy, Clone)]
+pub struct Json();
+impl Parser for Json {
+    type Output<'i> = JsonValue<'i>;
+    type Then<T: Parser> = Then2<Self, T>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        parse_value(input)
+    }
+}
+
+/// Parser for JSON values.
+///
+/// Leading whitespace and whitespace between tokens is skipped. Only integer numbers which fit in an
+/// [`i64`] are supported.
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{self, JsonValue, Parser};
+/// assert_eq!(
+///     parser::json().parse_complete(r#"{"a": [1, -2, "red"], "b": {"c": null, "d": true}}"#).unwrap(),
+///     JsonValue::Object(vec![
+///         ("a", JsonValue::Array(vec![
+///             JsonValue::Number(1),
+///             JsonValue::Number(-2),
+///             JsonValue::String("red"),
+///         ])),
+///         ("b", JsonValue::Object(vec![
+///             ("c", JsonValue::Null),
+///             ("d", JsonValue::Bool(true)),
+///         ])),
+///     ])
+/// );
+/// assert_eq!(
+///     parser::json().parse(br#""escaped \"quote\"" remaining"#),
+///     Ok((JsonValue::String(r#"escaped \"quote\""#), &b" remaining"[..]))
+/// );
+/// ```
+///
+/// Invalid documents return an error at the first unexpected byte:
+/// ```
+/// # use utils::parser::{self, ParseError, Parser};
+/// assert_eq!(
+///     parser::json().parse(b"[1, 2,]"),
+///     Err((ParseError::Expected("JSON value"), &b"]"[..]))
+/// );
+/// assert_eq!(
+///     parser::json().parse(br#"{"a" 1}"#),
+///     Err((ParseError::ExpectedByte(b':'), &b"1}"[..]))
+/// );
+/// assert_eq!(
+///     parser::json().parse(br#"["abc"#),
+///     Err((ParseError::ExpectedByte(b'"'), &b""[..]))
+/// );
+/// assert_eq!(
+///     parser::json().parse(br#""\x""#),
+///     Err((ParseError::Expected("escape sequence"), &b"x\""[..]))
+/// );
+/// assert_eq!(
+///     parser::json().parse(b"1.5"),
+///     Err((ParseError::Custom("fractional numbers are not supported"), &b".5"[..]))
+/// );
+/// ```
+#[must_use]
+pub fn json() -> Json {
+    Json()
+}
+
+fn skip_whitespace(input: &[u8]) -> &[u8] {
+    let len = input
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+        .unwrap_or(input.len());
+    &input[len..]
+}
+
+fn parse_value(input: &[u8]) -> ParseResult<'_, JsonValue<'_>> {
+    let input = skip_whitespace(input);
+    match input {
+        [b'{', rest @ ..] => parse_object(rest),
+        [b'[', rest @ ..] => parse_array(rest),
+        [b'"', rest @ ..] => {
+            let (s, rest) = parse_string(rest)?;
+            Ok((JsonValue::String(s), rest))
+        }
+        [b'-' | b'0'..=b'9', ..] => parse_number(input),
+        [b'n', b'u', b'l', b'l', rest @ ..] => Ok((JsonValue::Null, rest)),
+        [b't', b'r', b'u', b'e', rest @ ..] => Ok((JsonValue::Bool(true), rest)),
+        [b'f', b'a', b'l', b's', b'e', rest @ ..] => Ok((JsonValue::Bool(false), rest)),
+        _ => Err((ParseError::Expected("JSON value"), input)),
+    }
+}
+
+fn parse_number(input: &[u8]) -> ParseResult<'_, JsonValue<'_>> {
+    let (n, rest) = crate::parser::i64().parse(input)?;
+    if let [b'.' | b'e' | b'E', ..] = rest {
+        return Err((
+            ParseError::Custom("fractional numbers are not supported"),
+            rest,
+        ));
+    }
+    Ok((JsonValue::Number(n), rest))
+}
+
+/// Parse a string after the opening quote, returning the raw contents.
+fn parse_string(input: &[u8]) -> ParseResult<'_, &str> {
+    let mut i = 0;
+    loop {
+        match input.get(i) {
+            Some(b'"') => break,
+            Some(b'\\') => match input.get(i + 1) {
+                Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => i += 2,
+                Some(b'u') => {
+                    for j in i + 2..i + 6 {
+                        if !input.get(j).is_some_and(u8::is_ascii_hexdigit) {
+                            return Err((
+                                ParseError::Expected("hex digit"),
+                                &input[j.min(input.len())..],
+                            ));
+                        }
+                    }
+                    i += 6;
+                }
+                _ => return Err((ParseError::Expected("escape sequence"), &input[i + 1..])),
+            },
+            Some(0..=0x1F) => {
+                return Err((
+                    ParseError::Custom("control character in string"),
+                    &input[i..],
+                ))
+            }
+            Some(_) => i += 1,
+            None => return Err((ParseError::ExpectedByte(b'"'), &input[i..])),
+        }
+    }
+
+    match std::str::from_utf8(&input[..i]) {
+        Ok(s) => Ok((s, &input[i + 1..])),
+        Err(e) => Err((
+            ParseError::Custom("invalid UTF-8 in string"),
+            &input[e.valid_up_to()..],
+        )),
+    }
+}
+
+/// Parse an array after the opening bracket.
+fn parse_array(input: &[u8]) -> ParseResult<'_, JsonValue<'_>> {
+    let mut values = Vec::new();
+
+    let mut input = skip_whitespace(input);
+    if let [b']', rest @ ..] = input {
+        return Ok((JsonValue::Array(values), rest));
+    }
+
+    loop {
+        let (value, rest) = parse_value(input)?;
+        values.push(value);
+
+        match skip_whitespace(rest) {
+            [b',', rest @ ..] => input = rest,
+            [b']', rest @ ..] => return Ok((JsonValue::Array(values), rest)),
+            rest => return Err((ParseError::Expected("',' or ']'"), rest)),
+        }
+    }
+}
+
+/// Parse an object after the opening brace.
+fn parse_object(input: &[u8]) -> ParseResult<'_, JsonValue<'_>> {
+    let mut members = Vec::new();
+
+    let mut input = skip_whitespace(input);
+    if let [b'}', rest @ ..] = input {
+        return Ok((JsonValue::Object(members), rest));
+    }
+
+    loop {
+        let (key, rest) = match input {
+            [b'"', rest @ ..] => parse_string(rest)?,
+            _ => return Err((ParseError::ExpectedByte(b'"'), input)),
+        };
+
+        let rest = match skip_whitespace(rest) {
+            [b':', rest @ ..] => rest,
+            rest => return Err((ParseError::ExpectedByte(b':'), rest)),
+        };
+
+        let (value, rest) = parse_value(rest)?;
+        members.push((key, value));
+
+        match skip_whitespace(rest) {
+            [b',', rest @ ..] => input = skip_whitespace(rest),
+            [b'}', rest @ ..] => return Ok((JsonValue::Object(members), rest)),
+            rest => return Err((ParseError::Expected("',' or '}'"), rest)),
+        }
+    }
+}
@@ -3,19 +3,26 @@
 mod base;
 mod combinator;
 mod error;
+mod expression;
 mod iterator;
+mod json;
 mod macros;
 mod number;
 mod one_of;
 mod simple;
 mod then;
+mod tokenizer;
 
 pub use base::*;
+pub use combinator::{delimited, separated_pair};
 pub use error::ParseError;
+pub use expression::{expression, expression_tree, Associativity, Expr, Operator};
 pub use iterator::{ParserIterator, ParserMatchesIterator};
-pub use number::{i128, i16, i32, i64, i8, number_range, u128, u16, u32, u64, u8};
+pub use json::{json, JsonValue};
+pub use number::{bounded, i128, i16, i32, i64, i8, number_range, u128, u16, u32, u64, u8};
 pub use one_of::one_of;
 pub use simple::{byte, byte_range, constant, eof, eol, noop, take_while, take_while1};
+pub use tokenizer::{tokenize, Token, TokenKind, Tokenizer};
 
 pub use crate::parser_literal_map as literal_map;
 pub use crate::parser_parse_tree as parse_tree;
@@ -147,3 +147,98 @@ pub fn number_range<I: Integer + Parseable>(range: RangeInclusive<I>) -> NumberR
     assert!(min <= max);
     NumberRange { min, max }
 }
+
+/// Trait implemented by the integer types supported by [`bounded()`].
+///
+/// Only types where every value fits in an [`i128`] are supported.
+pub trait BoundedInteger: Integer + Parseable {
+    #[doc(hidden)]
+    const MIN_I128: i128;
+    #[doc(hidden)]
+    const MAX_I128: i128;
+    #[doc(hidden)]
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! bounded_integer {
+    ($($n:ident),+) => {$(
+        #[allow(clippy::allow_attributes, clippy::cast_lossless, clippy::cast_possible_wrap)]
+        impl BoundedInteger for std::primitive::$n {
+            const MIN_I128: i128 = std::primitive::$n::MIN as i128;
+            const MAX_I128: i128 = std::primitive::$n::MAX as i128;
+
+            #[inline]
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        }
+    )+};
+}
+bounded_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, i128 }
+
+#[derive(Copy, Clone)]
+pub struct Bounded<I, const MIN: i128, const MAX: i128>(PhantomData<I>);
+
+impl<I: BoundedInteger, const MIN: i128, const MAX: i128> Bounded<I, MIN, MAX> {
+    const VALID: () = assert!(
+        MIN <= MAX && MIN >= I::MIN_I128 && MAX <= I::MAX_I128,
+        "bounds must be ordered and fit in the output type",
+    );
+}
+
+impl<I: BoundedInteger, const MIN: i128, const MAX: i128> Parser for Bounded<I, MIN, MAX> {
+    type Output<'i> = I;
+    type Then<T: Parser> = Then2<Self, T>;
+
+    #[inline]
+    fn parse<'i>(&self, input: &'i [u8]) -> ParseResult<'i, Self::Output<'i>> {
+        match I::PARSER.parse(input) {
+            Ok((v, remaining)) if (MIN..=MAX).contains(&v.to_i128()) => Ok((v, remaining)),
+            Ok(_) | Err((ParseError::NumberTooLarge(_) | ParseError::NumberTooSmall(_), _)) => {
+                Err((ParseError::NumberOutOfBounds(MIN, MAX), input))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Parser for numbers within compile-time bounds.
+///
+/// The output type is inferred from how the result is used, and the bounds are checked to fit in
+/// it at compile time, so a range which doesn't fit in the chosen type fails to build instead of
+/// overflowing on unexpected inputs. Stable Rust can't pick a type based on const generic values,
+/// so the smallest type which fits should be chosen explicitly when inference doesn't constrain
+/// it.
+///
+/// Values outside the bounds, including those which overflow the output type, return
+/// [`ParseError::NumberOutOfBounds`] listing the allowed range.
+///
+/// See also [`number_range()`], which takes runtime bounds.
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{self, ParseError, Parser};
+/// let (v, remaining): (u8, _) = parser::bounded::<_, 1, 200>().parse(b"123,").unwrap();
+/// assert_eq!((v, remaining), (123, &b","[..]));
+///
+/// assert_eq!(
+///     parser::bounded::<u8, 1, 200>().parse(b"300"),
+///     Err((ParseError::NumberOutOfBounds(1, 200), &b"300"[..]))
+/// );
+/// assert_eq!(
+///     parser::bounded::<i16, -5, 5>().parse(b"-99999"),
+///     Err((ParseError::NumberOutOfBounds(-5, 5), &b"-99999"[..]))
+/// );
+/// ```
+///
+/// Bounds which don't fit in the output type fail to compile:
+/// ```compile_fail
+/// # use utils::parser::{self, Parser};
+/// let _ = parser::bounded::<u8, 0, 256>().parse(b"0");
+/// ```
+#[inline]
+#[must_use]
+pub fn bounded<I: BoundedInteger, const MIN: i128, const MAX: i128>() -> Bounded<I, MIN, MAX> {
+    let () = Bounded::<I, MIN, MAX>::VALID;
+    Bounded(PhantomData)
+}
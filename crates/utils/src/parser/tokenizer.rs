@@ -0,0 +1,191 @@
+use crate::input::InputError;
+use std::str::FromStr;
+
+/// Kind of [`Token`] returned by [`Tokenizer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Decimal integer, with an optional leading `-` or `+` sign.
+    Integer,
+    /// ASCII letter followed by ASCII letters, digits and underscores.
+    Word,
+    /// Any other single non-whitespace character.
+    Symbol,
+}
+
+/// Token returned by [`Tokenizer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Token<'i> {
+    pub kind: TokenKind,
+    /// Token text, which borrows from the input so it can be passed to [`InputError::new`].
+    pub text: &'i str,
+    /// Byte offset of the token in the input.
+    pub offset: usize,
+}
+
+/// Iterator splitting loosely structured inputs into typed tokens.
+///
+/// Whitespace between tokens is skipped. Useful for inputs which are sentences, where a full
+/// parser would be verbose, but [`str::split_whitespace`] loses the positions needed for error
+/// messages and doesn't separate punctuation.
+///
+/// Created by [`tokenize`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Tokenizer<'i> {
+    input: &'i str,
+    offset: usize,
+}
+
+/// Split the input into [`Token`]s.
+///
+/// # Examples
+/// ```
+/// # use utils::parser::{tokenize, TokenKind};
+/// let tokens: Vec<_> = tokenize("Blueprint 1: costs -4 ore.")
+///     .map(|t| (t.kind, t.text))
+///     .collect();
+/// assert_eq!(tokens, [
+///     (TokenKind::Word, "Blueprint"),
+///     (TokenKind::Integer, "1"),
+///     (TokenKind::Symbol, ":"),
+///     (TokenKind::Word, "costs"),
+///     (TokenKind::Integer, "-4"),
+///     (TokenKind::Word, "ore"),
+///     (TokenKind::Symbol, "."),
+/// ]);
+/// ```
+///
+/// The `expect_` methods consume tokens of a specific kind, returning [`InputError`]s pointing at
+/// the unexpected token:
+/// ```
+/// # use utils::parser::tokenize;
+/// let mut tokens = tokenize("Blueprint 12: Each ore robot costs 4 ore.");
+/// tokens.expect_keyword("Blueprint").unwrap();
+/// assert_eq!(tokens.expect_integer::<u32>().unwrap(), 12);
+/// tokens.expect_symbol(':').unwrap();
+/// assert_eq!(tokens.expect_word().unwrap(), "Each");
+///
+/// let err = tokens.expect_integer::<u32>().unwrap_err();
+/// assert!(err.to_string().contains("column 20"));
+/// ```
+pub fn tokenize(input: &str) -> Tokenizer<'_> {
+    Tokenizer { input, offset: 0 }
+}
+
+impl<'i> Tokenizer<'i> {
+    /// Returns the next token without consuming it.
+    #[must_use]
+    pub fn peek(&self) -> Option<Token<'i>> {
+        self.clone().next()
+    }
+
+    /// Returns true if there are no tokens remaining.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.peek().is_none()
+    }
+
+    /// Consume an integer token, parsing it as `T`.
+    pub fn expect_integer<T: FromStr>(&mut self) -> Result<T, InputError> {
+        let token = self.expect(TokenKind::Integer, "expected integer")?;
+        token
+            .text
+            .parse()
+            .map_err(|_| InputError::new(self.input, token.text, "integer out of range"))
+    }
+
+    /// Consume a word token, returning its text.
+    pub fn expect_word(&mut self) -> Result<&'i str, InputError> {
+        Ok(self.expect(TokenKind::Word, "expected word")?.text)
+    }
+
+    /// Consume a word token matching the provided keyword.
+    pub fn expect_keyword(&mut self, keyword: &str) -> Result<(), InputError> {
+        match self.peek() {
+            Some(token) if token.kind == TokenKind::Word && token.text == keyword => {
+                self.next();
+                Ok(())
+            }
+            token => Err(self.error(token, format!("expected {keyword:?}"))),
+        }
+    }
+
+    /// Consume a symbol token matching the provided character.
+    pub fn expect_symbol(&mut self, symbol: char) -> Result<(), InputError> {
+        match self.peek() {
+            Some(token) if token.kind == TokenKind::Symbol && token.text.starts_with(symbol) => {
+                self.next();
+                Ok(())
+            }
+            token => Err(self.error(token, format!("expected {symbol:?}"))),
+        }
+    }
+
+    /// Returns an error if there are tokens remaining.
+    pub fn expect_end(&self) -> Result<(), InputError> {
+        match self.peek() {
+            None => Ok(()),
+            token => Err(self.error(token, "expected end of input")),
+        }
+    }
+
+    fn expect(&mut self, kind: TokenKind, message: &'static str) -> Result<Token<'i>, InputError> {
+        match self.peek() {
+            Some(token) if token.kind == kind => {
+                self.next();
+                Ok(token)
+            }
+            token => Err(self.error(token, message)),
+        }
+    }
+
+    fn error(&self, token: Option<Token<'i>>, message: impl Into<String>) -> InputError {
+        match token {
+            Some(token) => InputError::new(self.input, token.text, message.into()),
+            None => InputError::new(self.input, self.input.len(), message.into()),
+        }
+    }
+}
+
+impl<'i> Iterator for Tokenizer<'i> {
+    type Item = Token<'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+        let mut start = self.offset;
+        while start < bytes.len() && bytes[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        if start == bytes.len() {
+            self.offset = start;
+            return None;
+        }
+
+        let is_digit = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+        let (kind, end) = if is_digit(start)
+            || (matches!(bytes[start], b'-' | b'+') && is_digit(start + 1))
+        {
+            let mut end = start + 1;
+            while is_digit(end) {
+                end += 1;
+            }
+            (TokenKind::Integer, end)
+        } else if bytes[start].is_ascii_alphabetic() {
+            let mut end = start + 1;
+            while end < bytes.len() && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_') {
+                end += 1;
+            }
+            (TokenKind::Word, end)
+        } else {
+            let len = self.input[start..].chars().next().map_or(1, char::len_utf8);
+            (TokenKind::Symbol, start + len)
+        };
+
+        self.offset = end;
+        Some(Token {
+            kind,
+            text: &self.input[start..end],
+            offset: start,
+        })
+    }
+}
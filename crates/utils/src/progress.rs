@@ -0,0 +1,82 @@
+//! Progress reporting for long-running solutions.
+//!
+//! Slow solutions, such as MD5 brute-force searches, can call [`Progress::add`] as they complete
+//! work, and [`Progress::set_total`] if the total amount of work is known in advance. Runners call
+//! [`Progress::start`] before each solution and poll [`Progress::current`] from another thread to
+//! display progress. The [`aoc`](../../aoc/) binary shows a progress bar when stderr is a
+//! terminal.
+//!
+//! Reports are shared by all threads, so solutions using worker pools can report from every
+//! worker. Each report only costs a relaxed atomic add, but should still be made once per batch of
+//! work rather than in tight inner loops.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Amount of work completed and the total amount of work, or zero if unknown.
+static COUNTERS: [AtomicU64; 2] = [AtomicU64::new(0), AtomicU64::new(0)];
+
+/// Snapshot of the progress of the current solution.
+///
+/// # Examples
+/// ```
+/// # use utils::progress::Progress;
+/// Progress::start();
+/// assert_eq!(Progress::current(), Progress { completed: 0, total: None });
+///
+/// Progress::add(250);
+/// assert_eq!(Progress::current(), Progress { completed: 250, total: None });
+///
+/// Progress::set_total(1000);
+/// Progress::add(250);
+/// assert_eq!(Progress::current(), Progress { completed: 500, total: Some(1000) });
+///
+/// Progress::start();
+/// assert_eq!(Progress::current(), Progress { completed: 0, total: None });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Units of work completed so far.
+    pub completed: u64,
+    /// Total units of work, if known.
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    /// Reset the progress before running a new solution.
+    pub fn start() {
+        COUNTERS[0].store(0, Ordering::Relaxed);
+        COUNTERS[1].store(0, Ordering::Relaxed);
+    }
+
+    /// Set the total units of work the current solution will complete.
+    #[inline]
+    pub fn set_total(total: u64) {
+        COUNTERS[1].store(total, Ordering::Relaxed);
+    }
+
+    /// Record that the provided units of work have been completed.
+    #[inline]
+    pub fn add(completed: u64) {
+        COUNTERS[0].fetch_add(completed, Ordering::Relaxed);
+    }
+
+    /// Returns the progress of the current solution.
+    #[must_use]
+    pub fn current() -> Self {
+        let total = COUNTERS[1].load(Ordering::Relaxed);
+        Self {
+            completed: COUNTERS[0].load(Ordering::Relaxed),
+            total: (total > 0).then_some(total),
+        }
+    }
+
+    /// Returns the shared counters, storing the units of work completed followed by the total, or
+    /// zero if unknown.
+    ///
+    /// Used by hosts which poll the progress directly from shared memory, such as the WebAssembly
+    /// module's JavaScript wrapper.
+    #[must_use]
+    pub fn counters() -> &'static [AtomicU64; 2] {
+        &COUNTERS
+    }
+}
@@ -0,0 +1,99 @@
+//! Deterministic parallel reductions, re-exported by [`multithreading`](crate::multithreading).
+
+use crate::multithreading::worker_pool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// Map each item and reduce the results in parallel, returning [`None`] if there are no items.
+///
+/// Items are split into fixed size chunks which are each reduced in order, before the chunk
+/// results are combined using [`tree_reduce`]. As neither the chunk boundaries nor the shape of
+/// the tree depend on the number of threads or which thread processes each chunk, the result is
+/// identical for every thread count, even when the reduction isn't associative, such as floating
+/// point addition or saturating arithmetic.
+///
+/// Small chunks allow more parallelism when each item is expensive, while large chunks reduce
+/// synchronization overhead when each item is cheap.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+///
+/// # Examples
+/// ```
+/// # use utils::multithreading::par_map_reduce;
+/// let items: Vec<u32> = (1..=1000).collect();
+/// assert_eq!(par_map_reduce(&items, 64, |&x| u64::from(x * x), |a, b| a + b), Some(333_833_500));
+/// assert_eq!(par_map_reduce(&[] as &[u32], 64, |&x| x, |a, b| a + b), None);
+/// ```
+pub fn par_map_reduce<T: Sync, R: Send>(
+    items: &[T],
+    chunk_size: usize,
+    map: impl Fn(&T) -> R + Sync,
+    reduce: impl Fn(R, R) -> R + Sync,
+) -> Option<R> {
+    assert!(chunk_size > 0, "chunk size must be non-zero");
+
+    let chunks = items.len().div_ceil(chunk_size);
+    let results: Vec<Mutex<Option<R>>> = (0..chunks).map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+    worker_pool(|| loop {
+        let chunk = next.fetch_add(1, Ordering::Relaxed);
+        if chunk >= chunks {
+            break;
+        }
+
+        let start = chunk * chunk_size;
+        let end = (start + chunk_size).min(items.len());
+        let result = items[start..end].iter().map(&map).reduce(&reduce);
+        *results[chunk]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = result;
+    });
+
+    tree_reduce(
+        results
+            .into_iter()
+            .map(|r| {
+                r.into_inner()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .expect("every chunk should be reduced")
+            })
+            .collect(),
+        reduce,
+    )
+}
+
+/// Reduce the values by repeatedly combining adjacent pairs, returning [`None`] if there are no
+/// values.
+///
+/// The order of operations only depends on the number of values, so the result is deterministic
+/// even for non-associative reductions. The depth of the tree is logarithmic in the number of
+/// values, which also reduces the accumulated rounding error compared to a sequential fold when
+/// summing floating point numbers.
+///
+/// # Examples
+/// ```
+/// # use utils::multithreading::tree_reduce;
+/// assert_eq!(
+///     tree_reduce(vec!["a", "b", "c", "d", "e"].into_iter().map(String::from).collect(), |a, b| {
+///         format!("({a}{b})")
+///     }),
+///     Some("(((ab)(cd))e)".to_string()),
+/// );
+/// assert_eq!(tree_reduce(Vec::<u32>::new(), |a, b| a + b), None);
+/// ```
+pub fn tree_reduce<R>(mut values: Vec<R>, reduce: impl Fn(R, R) -> R) -> Option<R> {
+    while values.len() > 1 {
+        let mut iter = values.into_iter();
+        let mut next = Vec::with_capacity(iter.len().div_ceil(2));
+        while let Some(a) = iter.next() {
+            next.push(match iter.next() {
+                Some(b) => reduce(a, b),
+                None => a,
+            });
+        }
+        values = next;
+    }
+    values.pop()
+}
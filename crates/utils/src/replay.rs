@@ -0,0 +1,195 @@
+//! Recording and replaying decisions made by interactive or randomized solutions.
+//!
+//! Solutions route each decision which may differ between runs, such as random choices during a
+//! search, through [`decide`]. When recording, the decisions are collected into a [`Replay`],
+//! which can be saved and later replayed so the run is reproduced exactly. When neither recording
+//! nor replaying, [`decide`] just returns the provided value.
+//!
+//! The [`aoc`](../../aoc/) binary records and replays runs with the `--record` and `--replay`
+//! options, and `cargo xtask test` replays `inputs/$year/$day.replay` files when present.
+//!
+//! Recording state is global, so decisions should only be made from one thread at a time to keep
+//! their order deterministic.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
+
+/// Sequence of recorded decisions.
+///
+/// The text format is a `aoc-replay v1` header line, followed by one decision per line.
+///
+/// # Examples
+/// ```
+/// # use utils::replay::Replay;
+/// let replay: Replay = "aoc-replay v1\n4\n8\n15\n".parse().unwrap();
+/// assert_eq!(replay.decisions(), [4, 8, 15]);
+/// assert_eq!(replay.to_string(), "aoc-replay v1\n4\n8\n15\n");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Replay {
+    decisions: Vec<u64>,
+}
+
+impl Replay {
+    const HEADER: &'static str = "aoc-replay v1";
+
+    #[must_use]
+    pub fn decisions(&self) -> &[u64] {
+        &self.decisions
+    }
+}
+
+impl Display for Replay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for decision in &self.decisions {
+            writeln!(f, "{decision}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Replay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        if lines.next().map(str::trim_end) != Some(Self::HEADER) {
+            return Err(format!("expected {:?} header", Self::HEADER));
+        }
+
+        let decisions = lines
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                line.trim()
+                    .parse()
+                    .map_err(|err| format!("invalid decision on line {}: {err}", i + 2))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { decisions })
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    Off,
+    Recording(Vec<u64>),
+    Replaying(Vec<u64>, usize),
+}
+
+static STATE: Mutex<State> = Mutex::new(State::Off);
+
+fn state() -> std::sync::MutexGuard<'static, State> {
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Start recording decisions.
+pub fn start_recording() {
+    *state() = State::Recording(Vec::new());
+}
+
+/// Start replaying the provided decisions.
+pub fn start_replay(replay: Replay) {
+    *state() = State::Replaying(replay.decisions, 0);
+}
+
+/// Stop recording or replaying.
+///
+/// Returns the recorded decisions when recording. When replaying, returns an error if not every
+/// decision was used, as the run diverged from the recording.
+pub fn finish() -> Result<Option<Replay>, String> {
+    match std::mem::replace(&mut *state(), State::Off) {
+        State::Off => Ok(None),
+        State::Recording(decisions) => Ok(Some(Replay { decisions })),
+        State::Replaying(decisions, next) if next == decisions.len() => Ok(None),
+        State::Replaying(decisions, next) => Err(format!(
+            "replay diverged, only {next} of {} decisions were used",
+            decisions.len()
+        )),
+    }
+}
+
+/// Make a decision, which is recorded or replayed if enabled.
+///
+/// `f` is only called when not replaying.
+///
+/// # Panics
+///
+/// Panics if replaying and all the recorded decisions have been used.
+///
+/// # Examples
+/// ```
+/// # use utils::replay::{decide, finish, start_recording, start_replay};
+/// start_recording();
+/// assert_eq!(decide(|| 7), 7);
+/// assert_eq!(decide(|| 3), 3);
+/// let replay = finish().unwrap().unwrap();
+///
+/// start_replay(replay);
+/// assert_eq!(decide(|| unreachable!()), 7);
+/// assert_eq!(decide(|| unreachable!()), 3);
+/// assert_eq!(finish(), Ok(None));
+/// ```
+pub fn decide(f: impl FnOnce() -> u64) -> u64 {
+    if let State::Replaying(decisions, next) = &mut *state() {
+        let decision = *decisions
+            .get(*next)
+            .expect("replay diverged, all decisions have been used");
+        *next += 1;
+        return decision;
+    }
+
+    // Call f without holding the lock, in case it makes decisions itself
+    let decision = f();
+    if let State::Recording(decisions) = &mut *state() {
+        decisions.push(decision);
+    }
+    decision
+}
+
+/// Small random number generator which records and replays its outputs using [`decide`].
+///
+/// # Examples
+/// ```
+/// # use utils::replay::Rng;
+/// let mut rng = Rng::new(1);
+/// let a = rng.next_u64();
+/// assert_ne!(a, rng.next_u64());
+/// assert_eq!(Rng::new(1).next_u64(), a);
+/// assert!(rng.below(10) < 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random [`u64`], using the splitmix64 generator.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let state = self.state;
+        decide(|| {
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        })
+    }
+
+    /// Returns a random number less than `bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must be non-zero");
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+}
@@ -0,0 +1,188 @@
+//! Target-independent task scheduling used by the WebAssembly scoped tasks.
+//!
+//! The ordering of queued tasks and handing tasks to idle workers don't depend on WebAssembly, so
+//! they are kept here where they can be tested natively.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::sync::mpsc::{Receiver, SyncSender, TrySendError};
+use std::sync::Mutex;
+
+/// Order used to run queued tasks with the same priority.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SchedulingPolicy {
+    /// Run the oldest queued task first.
+    #[default]
+    Fifo,
+    /// Run the newest queued task first.
+    Lifo,
+}
+
+/// Queue of tasks ordered by priority, then by the [`SchedulingPolicy`] passed to
+/// [`pop`](Self::pop).
+pub(crate) struct TaskQueue<T> {
+    tasks: Vec<(i32, u64, T)>,
+    next_sequence: u64,
+}
+
+impl<T> TaskQueue<T> {
+    pub(crate) fn push(&mut self, priority: i32, task: T) {
+        self.tasks.push((priority, self.next_sequence, task));
+        self.next_sequence += 1;
+    }
+
+    /// Remove the next task to run according to priority and scheduling policy.
+    pub(crate) fn pop(&mut self, policy: SchedulingPolicy) -> Option<T> {
+        let iter = self.tasks.iter().enumerate();
+        let (index, _) = match policy {
+            SchedulingPolicy::Fifo => iter.max_by_key(|&(_, &(priority, sequence, _))| {
+                (priority, std::cmp::Reverse(sequence))
+            })?,
+            SchedulingPolicy::Lifo => {
+                iter.max_by_key(|&(_, &(priority, sequence, _))| (priority, sequence))?
+            }
+        };
+        Some(self.tasks.swap_remove(index).2)
+    }
+}
+
+impl<T> Default for TaskQueue<T> {
+    fn default() -> Self {
+        Self {
+            tasks: Vec::new(),
+            next_sequence: 0,
+        }
+    }
+}
+
+impl<T> Debug for TaskQueue<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskQueue")
+            .field("len", &self.tasks.len())
+            .field("next_sequence", &self.next_sequence)
+            .finish()
+    }
+}
+
+/// Pool of workers which each accept a task only while idle.
+pub(crate) struct Workers<T> {
+    senders: Mutex<VecDeque<SyncSender<T>>>,
+}
+
+impl<T> Workers<T> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            senders: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Register a new worker, returning the receiver the worker should run tasks from.
+    ///
+    /// The worker is only sent tasks while it is blocked receiving from the returned receiver.
+    /// Dropping the receiver removes the worker from the pool.
+    pub(crate) fn register(&self) -> Receiver<T> {
+        let (tx, rx) = std::sync::mpsc::sync_channel(0);
+        self.senders.lock().unwrap().push_front(tx);
+        rx
+    }
+
+    /// Send the task to an idle worker, returning it if no workers are idle.
+    pub(crate) fn try_send(&self, mut task: T) -> Result<(), T> {
+        let mut guard = self.senders.lock().unwrap();
+        let queue = &mut *guard;
+
+        for _ in 0..queue.len() {
+            let Some(sender) = queue.pop_front() else {
+                break;
+            };
+
+            match sender.try_send(task) {
+                Ok(()) => {
+                    queue.push_back(sender);
+                    return Ok(());
+                }
+                Err(TrySendError::Full(v)) => {
+                    task = v;
+                    queue.push_back(sender);
+                }
+                Err(TrySendError::Disconnected(v)) => {
+                    task = v;
+                    // Worker gone, discard sender
+                }
+            }
+        }
+
+        Err(task)
+    }
+
+    /// Returns the number of registered workers.
+    pub(crate) fn len(&self) -> usize {
+        self.senders.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pop_order(policy: SchedulingPolicy, priorities: &[i32]) -> Vec<usize> {
+        let mut queue = TaskQueue::default();
+        for (i, &priority) in priorities.iter().enumerate() {
+            queue.push(priority, i);
+        }
+        std::iter::from_fn(|| queue.pop(policy)).collect()
+    }
+
+    #[test]
+    fn priority_fifo() {
+        assert_eq!(
+            pop_order(SchedulingPolicy::Fifo, &[1, 3, 2, 3, 1, 2]),
+            vec![1, 3, 2, 5, 0, 4]
+        );
+    }
+
+    #[test]
+    fn priority_lifo() {
+        assert_eq!(
+            pop_order(SchedulingPolicy::Lifo, &[1, 3, 2, 3, 1, 2]),
+            vec![3, 1, 5, 2, 4, 0]
+        );
+    }
+
+    #[test]
+    fn interleaved_push_pop() {
+        let mut queue = TaskQueue::default();
+        queue.push(0, 'a');
+        queue.push(0, 'b');
+        assert_eq!(queue.pop(SchedulingPolicy::Fifo), Some('a'));
+        queue.push(1, 'c');
+        queue.push(0, 'd');
+        assert_eq!(queue.pop(SchedulingPolicy::Lifo), Some('c'));
+        assert_eq!(queue.pop(SchedulingPolicy::Lifo), Some('d'));
+        assert_eq!(queue.pop(SchedulingPolicy::Lifo), Some('b'));
+        assert_eq!(queue.pop(SchedulingPolicy::Lifo), None);
+    }
+
+    #[test]
+    fn try_send_requires_idle_worker() {
+        let workers = Workers::new();
+        assert_eq!(workers.try_send(1), Err(1));
+
+        // Registered, but not yet waiting to receive
+        let rx = workers.register();
+        assert_eq!(workers.len(), 1);
+        assert_eq!(workers.try_send(2), Err(2));
+
+        let handle = std::thread::spawn(move || rx.recv().unwrap());
+        let mut task = 3;
+        while let Err(rejected) = workers.try_send(task) {
+            task = rejected;
+            std::thread::yield_now();
+        }
+        assert_eq!(handle.join().unwrap(), 3);
+
+        // Receiver dropped when the thread finished, so the worker is removed
+        assert_eq!(workers.try_send(4), Err(4));
+        assert_eq!(workers.len(), 0);
+    }
+}
@@ -0,0 +1,79 @@
+//! Per-thread scratch buffers, re-exported by [`multithreading`](crate::multithreading).
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+
+thread_local! {
+    static BUFFERS: RefCell<Vec<(TypeId, Box<dyn Any>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run the provided function with an empty scratch [`Vec`] owned by the current thread.
+///
+/// The buffer is cleared after each use, but its allocation is kept and reused by the next call on
+/// the same thread with the same element type. This allows tasks running on worker threads to
+/// avoid repeatedly allocating temporary buffers, without sharing buffers between threads.
+///
+/// Nested calls with the same element type receive a separate buffer.
+///
+/// # Examples
+/// ```
+/// # use utils::multithreading::{with_scratch, worker_pool};
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// let next = AtomicU32::new(0);
+/// let total = AtomicU32::new(0);
+/// worker_pool(|| {
+///     while let n @ 0..100 = next.fetch_add(1, Ordering::Relaxed) {
+///         let sum = with_scratch(|digits: &mut Vec<u32>| {
+///             assert!(digits.is_empty());
+///             digits.extend([n / 10, n % 10]);
+///             digits.iter().sum::<u32>()
+///         });
+///         total.fetch_add(sum, Ordering::Relaxed);
+///     }
+/// });
+/// assert_eq!(total.into_inner(), 900);
+/// ```
+pub fn with_scratch<T: 'static, R>(f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+    let mut buffer = take::<T>();
+    let result = f(&mut buffer);
+    buffer.clear();
+    put(buffer);
+    result
+}
+
+fn take<T: 'static>() -> Vec<T> {
+    BUFFERS
+        .try_with(|buffers| {
+            let mut buffers = buffers.borrow_mut();
+            let index = buffers
+                .iter()
+                .position(|(t, _)| *t == TypeId::of::<Vec<T>>())?;
+            let (_, buffer) = buffers.swap_remove(index);
+            buffer.downcast::<Vec<T>>().ok().map(|b| *b)
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+fn put<T: 'static>(buffer: Vec<T>) {
+    if buffer.capacity() == 0 {
+        return;
+    }
+
+    let _ = BUFFERS.try_with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+        if let Some((_, existing)) = buffers
+            .iter_mut()
+            .find(|(t, _)| *t == TypeId::of::<Vec<T>>())
+        {
+            // Nested call returned first, keep whichever buffer is larger
+            let existing = existing.downcast_mut::<Vec<T>>().unwrap();
+            if existing.capacity() < buffer.capacity() {
+                *existing = buffer;
+            }
+        } else {
+            buffers.push((TypeId::of::<Vec<T>>(), Box::new(buffer)));
+        }
+    });
+}
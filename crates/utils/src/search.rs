@@ -0,0 +1,526 @@
+//! Generic search algorithms for optimization puzzles.
+
+use crate::collections::DeterministicHashMap;
+use crate::multithreading;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Beam search, keeping the highest scoring states at each step.
+///
+/// Starting from `initial`, each step expands every state in the beam using `successors`, then
+/// keeps the best `width(step)` candidates by `score` as the next beam. Search stops after `steps`
+/// steps or once no successors are generated. The highest scoring state seen at any step is
+/// returned along with its score.
+///
+/// States are deduplicated using the `key` function. States with the same key are assumed to be
+/// equivalent, so only the highest scoring state is kept. Returning [`None`] disables
+/// deduplication for that state.
+///
+/// Beam search is a heuristic: states pruned from the beam are never revisited, so the result is
+/// only guaranteed to be optimal if the beam is wide enough to never prune a state leading to the
+/// optimum. Ties are broken by the order states are generated, so results are deterministic.
+///
+/// # Examples
+///
+/// 0-1 knapsack problem, maximizing the total value of the items with a weight limit of 10:
+/// ```
+/// # use utils::search::beam_search;
+/// let items = [(5, 10), (4, 40), (6, 30), (3, 50)]; // (weight, value)
+///
+/// let (best, value) = beam_search(
+///     (0, 0u32, 0u32), // (next item, total weight, total value)
+///     items.len(),
+///     |_| 4,
+///     |&(i, weight, value)| {
+///         let (w, v) = items[i];
+///         let skip = Some((i + 1, weight, value));
+///         let take = (weight + w <= 10).then_some((i + 1, weight + w, value + v));
+///         skip.into_iter().chain(take)
+///     },
+///     |&(_, _, value)| value,
+///     |&(i, weight, _)| Some((i, weight)),
+/// );
+/// assert_eq!(value, 90);
+/// assert_eq!(best, (4, 7, 90));
+/// ```
+pub fn beam_search<S, I, Sc, K>(
+    initial: S,
+    steps: usize,
+    mut width: impl FnMut(usize) -> usize,
+    mut successors: impl FnMut(&S) -> I,
+    mut score: impl FnMut(&S) -> Sc,
+    mut key: impl FnMut(&S) -> Option<K>,
+) -> (S, Sc)
+where
+    S: Clone,
+    I: IntoIterator<Item = S>,
+    Sc: Ord + Copy,
+    K: Hash + Eq,
+{
+    let initial_score = score(&initial);
+    let mut best = (initial.clone(), initial_score);
+    let mut beam = vec![(initial_score, initial)];
+    let mut candidates = Vec::new();
+    let mut seen = DeterministicHashMap::default();
+
+    for step in 0..steps {
+        candidates.clear();
+        seen.clear();
+
+        for (_, state) in &beam {
+            for next in successors(state) {
+                let next_score = score(&next);
+                if let Some(k) = key(&next) {
+                    if let Some(&index) = seen.get(&k) {
+                        let (existing_score, _) = &candidates[index];
+                        if next_score > *existing_score {
+                            candidates[index] = (next_score, next);
+                        }
+                        continue;
+                    }
+                    seen.insert(k, candidates.len());
+                }
+                candidates.push((next_score, next));
+            }
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        let width = width(step).max(1);
+        if candidates.len() > width {
+            // Partition so the best `width` states come first, then restore generation order
+            // within the kept states for deterministic tie-breaking in later steps
+            let mut order: Vec<usize> = (0..candidates.len()).collect();
+            order.select_nth_unstable_by(width - 1, |&a, &b| {
+                candidates[b].0.cmp(&candidates[a].0).then(a.cmp(&b))
+            });
+            order.truncate(width);
+            order.sort_unstable();
+
+            let mut keep = vec![false; candidates.len()];
+            for i in order {
+                keep[i] = true;
+            }
+            let mut i = 0;
+            candidates.retain(|_| {
+                i += 1;
+                keep[i - 1]
+            });
+        }
+
+        for (candidate_score, state) in &candidates {
+            if *candidate_score > best.1 {
+                best = (state.clone(), *candidate_score);
+            }
+        }
+
+        std::mem::swap(&mut beam, &mut candidates);
+    }
+
+    best
+}
+
+/// Order in which [`branch_and_bound`] explores nodes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Explore the most recently generated node first.
+    ///
+    /// Uses little memory and quickly finds complete solutions to prune with, so is usually the
+    /// best choice when the bound is loose.
+    #[default]
+    DepthFirst,
+    /// Explore the node with the highest bound first.
+    ///
+    /// Explores the fewest nodes when the bound is tight, but the shared frontier may grow large.
+    BestFirst,
+}
+
+/// Branch and bound search, maximizing the value of a solution.
+///
+/// Starting from `root`, `branch` is called to generate the children of each node. `value` returns
+/// the value of a node if it is a complete solution, and `bound` must return an upper bound on the
+/// value of the node and all its descendants. Nodes whose bound is not greater than the best value
+/// found so far are pruned. Minimization problems can be solved by negating values and bounds.
+///
+/// Nodes are explored in parallel using [`multithreading::worker_pool`], with the best value
+/// found so far (the incumbent) stored in an atomic so all workers can prune using it. The best
+/// nodes are expanded first until there is a node for each worker. Each worker then keeps a local
+/// stack of nodes, sharing nodes when the shared frontier is empty. When using
+/// [`Strategy::BestFirst`] all nodes are kept in a shared priority queue instead.
+///
+/// Returns the best value and the node which achieved it, or [`None`] if no solutions were found.
+/// If multiple solutions share the best value, which one is returned depends on scheduling.
+///
+/// # Examples
+///
+/// 0-1 knapsack problem, maximizing the total value of the items with a weight limit of 10:
+/// ```
+/// # use utils::search::{branch_and_bound, Strategy};
+/// let items = [(5, 10), (4, 40), (6, 30), (3, 50)]; // (weight, value)
+///
+/// for strategy in [Strategy::DepthFirst, Strategy::BestFirst] {
+///     let (value, node) = branch_and_bound(
+///         (0, 0, 0), // (next item, total weight, total value)
+///         strategy,
+///         |&(_, _, value)| Some(value),
+///         |&(i, _, value)| value + items[i..].iter().map(|&(_, v)| v).sum::<i64>(),
+///         |&(i, weight, value), children| {
+///             if let Some(&(w, v)) = items.get(i) {
+///                 children.push((i + 1, weight, value));
+///                 if weight + w <= 10 {
+///                     children.push((i + 1, weight + w, value + v));
+///                 }
+///             }
+///         },
+///     )
+///     .unwrap();
+///     assert_eq!(value, 90);
+///     assert_eq!(node, (4, 7, 90));
+/// }
+/// ```
+pub fn branch_and_bound<N: Clone + Send>(
+    root: N,
+    strategy: Strategy,
+    value: impl Fn(&N) -> Option<i64> + Sync,
+    bound: impl Fn(&N) -> i64 + Sync,
+    branch: impl Fn(&N, &mut Vec<N>) + Sync,
+) -> Option<(i64, N)> {
+    let shared = BranchAndBound {
+        incumbent: AtomicI64::new(i64::MIN),
+        best: Mutex::new(None),
+        frontier: Mutex::new(BinaryHeap::from([BoundedNode {
+            bound: bound(&root),
+            sequence: 0,
+            node: root,
+        }])),
+        frontier_len: AtomicUsize::new(1),
+        sequence: AtomicUsize::new(1),
+        strategy,
+    };
+
+    // Workers exit once the shared frontier is empty, so expand the best nodes until there is at
+    // least one for each worker
+    let threads = multithreading::get_thread_count().get();
+    let (mut children, mut expanded) = (Vec::new(), Vec::new());
+    while shared.frontier_len.load(Ordering::Relaxed) < threads {
+        let Some(node) = shared.pop() else {
+            break;
+        };
+        shared.expand(node, &value, &bound, &branch, &mut children, &mut expanded);
+        for node in expanded.drain(..) {
+            shared.push(node);
+        }
+    }
+
+    multithreading::worker_pool(|| shared.worker(&value, &bound, &branch));
+
+    shared.best.into_inner().unwrap()
+}
+
+struct BranchAndBound<N> {
+    incumbent: AtomicI64,
+    best: Mutex<Option<(i64, N)>>,
+    frontier: Mutex<BinaryHeap<BoundedNode<N>>>,
+    frontier_len: AtomicUsize,
+    sequence: AtomicUsize,
+    strategy: Strategy,
+}
+
+impl<N: Clone> BranchAndBound<N> {
+    fn worker(
+        &self,
+        value: &impl Fn(&N) -> Option<i64>,
+        bound: &impl Fn(&N) -> i64,
+        branch: &impl Fn(&N, &mut Vec<N>),
+    ) {
+        let mut local = Vec::new();
+        let (mut children, mut expanded) = (Vec::new(), Vec::new());
+
+        // Workers never wait for each other, as on WebAssembly workers may run one after another on
+        // the same thread. Any node added to the shared frontier is eventually explored, as the
+        // worker which added it checks the shared frontier before exiting.
+        while let Some(node) = local.pop().or_else(|| self.pop()) {
+            self.expand(node, value, bound, branch, &mut children, &mut expanded);
+
+            match self.strategy {
+                Strategy::DepthFirst => {
+                    // Children are pushed in reverse so the first child is explored first
+                    local.extend(expanded.drain(..).rev());
+
+                    // Share the oldest local node, which is likely to have the largest subtree
+                    if local.len() > 1 && self.frontier_len.load(Ordering::Relaxed) == 0 {
+                        self.push(local.remove(0));
+                    }
+                }
+                Strategy::BestFirst => {
+                    let mut frontier = self.frontier.lock().unwrap();
+                    for mut child in expanded.drain(..) {
+                        child.sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+                        frontier.push(child);
+                    }
+                    self.frontier_len.store(frontier.len(), Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Explore a node, adding any children which may improve on the incumbent to `expanded`.
+    fn expand(
+        &self,
+        BoundedNode { bound: b, node, .. }: BoundedNode<N>,
+        value: &impl Fn(&N) -> Option<i64>,
+        bound: &impl Fn(&N) -> i64,
+        branch: &impl Fn(&N, &mut Vec<N>),
+        children: &mut Vec<N>,
+        expanded: &mut Vec<BoundedNode<N>>,
+    ) {
+        if b <= self.incumbent.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if let Some(v) = value(&node) {
+            self.update_incumbent(v, &node);
+        }
+
+        branch(&node, children);
+        let incumbent = self.incumbent.load(Ordering::Relaxed);
+        expanded.extend(children.drain(..).filter_map(|node| {
+            let b = bound(&node);
+            (b > incumbent).then_some(BoundedNode {
+                bound: b,
+                sequence: 0,
+                node,
+            })
+        }));
+    }
+
+    fn pop(&self) -> Option<BoundedNode<N>> {
+        let mut frontier = self.frontier.lock().unwrap();
+        let node = frontier.pop();
+        self.frontier_len.store(frontier.len(), Ordering::Relaxed);
+        node
+    }
+
+    fn push(&self, mut node: BoundedNode<N>) {
+        node.sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let mut frontier = self.frontier.lock().unwrap();
+        frontier.push(node);
+        self.frontier_len.store(frontier.len(), Ordering::Relaxed);
+    }
+
+    fn update_incumbent(&self, value: i64, node: &N) {
+        if self.incumbent.fetch_max(value, Ordering::Relaxed) >= value {
+            return;
+        }
+
+        let mut best = self.best.lock().unwrap();
+        if best.as_ref().is_none_or(|&(v, _)| v < value) {
+            *best = Some((value, node.clone()));
+        }
+    }
+}
+
+struct BoundedNode<N> {
+    bound: i64,
+    sequence: usize,
+    node: N,
+}
+
+// Order by bound, then by insertion order so equal bounds are explored first in first out
+impl<N> Ord for BoundedNode<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bound
+            .cmp(&other.bound)
+            .then(other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<N> PartialOrd for BoundedNode<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> PartialEq for BoundedNode<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<N> Eq for BoundedNode<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn knapsack_optimum(items: &[(u32, u32)], limit: u32) -> u32 {
+        let mut best = vec![0u32; limit as usize + 1];
+        for &(w, v) in items {
+            for capacity in (w..=limit).rev() {
+                best[capacity as usize] =
+                    best[capacity as usize].max(best[(capacity - w) as usize] + v);
+            }
+        }
+        best[limit as usize]
+    }
+
+    fn knapsack_beam(items: &[(u32, u32)], limit: u32, width: usize) -> u32 {
+        beam_search(
+            (0, 0u32, 0u32),
+            items.len(),
+            |_| width,
+            |&(i, weight, value)| {
+                let (w, v) = items[i];
+                let skip = Some((i + 1, weight, value));
+                let take = (weight + w <= limit).then_some((i + 1, weight + w, value + v));
+                skip.into_iter().chain(take)
+            },
+            |&(_, _, value)| value,
+            |&(i, weight, _)| Some((i, weight)),
+        )
+        .1
+    }
+
+    fn knapsack_branch_and_bound(items: &[(u32, u32)], limit: u32, strategy: Strategy) -> u32 {
+        // Fractional knapsack relaxation, with items sorted by value density
+        let mut items = items.to_vec();
+        items.sort_by(|&(w1, v1), &(w2, v2)| (v2 * w1).cmp(&(v1 * w2)));
+        let bound = |&(i, weight, value): &(usize, u32, u32)| {
+            let (mut weight, mut value) = (f64::from(weight), f64::from(value));
+            for &(w, v) in &items[i..] {
+                let take = (f64::from(limit) - weight).min(f64::from(w));
+                weight += take;
+                value += take / f64::from(w) * f64::from(v);
+            }
+            #[expect(clippy::cast_possible_truncation)]
+            let bound = value.floor() as i64;
+            bound
+        };
+
+        let (value, _) = branch_and_bound(
+            (0, 0, 0),
+            strategy,
+            |&(_, _, value)| Some(i64::from(value)),
+            bound,
+            |&(i, weight, value), children| {
+                if let Some(&(w, v)) = items.get(i) {
+                    if weight + w <= limit {
+                        children.push((i + 1, weight + w, value + v));
+                    }
+                    children.push((i + 1, weight, value));
+                }
+            },
+        )
+        .unwrap();
+        u32::try_from(value).unwrap()
+    }
+
+    fn random_items() -> Vec<(u32, u32)> {
+        // Deterministic pseudo-random items
+        let mut x = 12345u32;
+        (0..40)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 17;
+                x ^= x << 5;
+                (x % 50 + 1, (x >> 8) % 100 + 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn knapsack() {
+        let items = random_items();
+        let optimum = knapsack_optimum(&items, 200);
+
+        // With deduplication by (item, weight), a beam as wide as the number of possible weights
+        // is exact
+        assert_eq!(knapsack_beam(&items, 200, 201), optimum);
+
+        // Narrower beams may be suboptimal, but never exceed the optimum
+        assert!(knapsack_beam(&items, 200, 5) <= optimum);
+        assert!(knapsack_beam(&items, 200, 1) <= knapsack_beam(&items, 200, 201));
+
+        assert_eq!(
+            knapsack_branch_and_bound(&items, 200, Strategy::DepthFirst),
+            optimum
+        );
+        assert_eq!(
+            knapsack_branch_and_bound(&items, 200, Strategy::BestFirst),
+            optimum
+        );
+    }
+
+    #[test]
+    fn branch_and_bound_no_solution() {
+        let result = branch_and_bound(
+            0u32,
+            Strategy::DepthFirst,
+            |_| None,
+            |_| 0,
+            |&x, children| {
+                if x < 10 {
+                    children.push(x + 1);
+                }
+            },
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn branch_and_bound_parallel() {
+        use std::collections::HashSet;
+        use std::num::NonZeroUsize;
+        use std::thread::{self, ThreadId};
+        use std::time::Duration;
+
+        let previous = multithreading::get_thread_count();
+        multithreading::set_thread_count(NonZeroUsize::new(4).unwrap());
+
+        // Complete binary tree with no pruning, slowed down so all workers have time to start
+        let explored: Mutex<HashSet<ThreadId>> = Mutex::new(HashSet::new());
+        let result = branch_and_bound(
+            (0u32, 0u32),
+            Strategy::DepthFirst,
+            |&(depth, x)| (depth == 10).then_some(i64::from(x)),
+            |&(depth, x)| i64::from(((x + 1) << (10 - depth)) - 1),
+            |&(depth, x), children| {
+                explored.lock().unwrap().insert(thread::current().id());
+                thread::sleep(Duration::from_micros(50));
+                if depth < 10 {
+                    children.push((depth + 1, x * 2));
+                    children.push((depth + 1, x * 2 + 1));
+                }
+            },
+        );
+
+        multithreading::set_thread_count(previous);
+        assert_eq!(result, Some((1023, (10, 1023))));
+        assert!(
+            explored.into_inner().unwrap().len() > 1,
+            "expected more than one worker to explore nodes"
+        );
+    }
+
+    #[test]
+    fn width_schedule() {
+        let mut widths = Vec::new();
+        let (best, score) = beam_search(
+            0u32,
+            5,
+            |step| {
+                widths.push(step);
+                2 + step
+            },
+            |&x| [x * 2, x * 2 + 1],
+            |&x| x,
+            |_| None::<()>,
+        );
+        assert_eq!((best, score), (31, 31));
+        assert_eq!(widths, vec![0, 1, 2, 3, 4]);
+    }
+}
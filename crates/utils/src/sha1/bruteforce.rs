@@ -0,0 +1,107 @@
+use crate::hashing::u32_to_ascii;
+use crate::progress::Progress;
+use crate::{multithreading, multiversion, sha1};
+use std::array;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Brute force hashes of a prefix followed by an increasing integer.
+///
+/// This function calls the predicate repeatedly until it returns true from a pool of worker threads
+/// each using the [`FASTEST`](super::FASTEST) supported vectorized SHA-1 implementation to hash
+/// multiple inputs at once.
+///
+/// When `additional_hashes` is zero, the predicate will be called with:
+/// ```ignore
+/// predicate(i, hash(prefix + i.to_string()))
+/// ```
+///
+/// When `additional_hashes` is more than zero, key stretching is used. For example, passing 2 will
+/// cause the predicate to be called with:
+/// ```ignore
+/// predicate(i, hash(to_hex(hash(to_hex(hash(prefix + i.to_string()))))))
+/// ```
+///
+/// Each completed hash, including additional hashes, is reported to [`Progress`].
+pub fn find_hash_with_appended_count(
+    prefix: &str,
+    additional_hashes: u32,
+    predicate: impl Fn(u32, [u32; 5]) -> bool + Copy + Sync,
+) {
+    let counter = AtomicU32::new(0);
+    let done = AtomicBool::new(false);
+    multithreading::worker_pool(|| {
+        worker(
+            prefix.as_bytes(),
+            additional_hashes,
+            &predicate,
+            &counter,
+            &done,
+        );
+    });
+}
+
+multiversion! {
+    use {crate::simd::*, crate::sha1::*, crate::hashing::*};
+
+    #[dyn_dispatch = sha1::FASTEST]
+    #[expect(clippy::cast_possible_truncation)]
+    fn worker(
+        prefix: &[u8],
+        additional_hashes: u32,
+        predicate: impl Fn(u32, [u32; 5]) -> bool + Copy + Send,
+        counter: &AtomicU32,
+        done: &AtomicBool,
+    ) {
+        let lane_size = prefix.len() + 10; // u32::MAX is 10 digits long
+
+        let mut buf = vec![0u8; lane_size * U32Vector::LANES];
+        for i in 0..prefix.len() {
+            buf[i * U32Vector::LANES..(i + 1) * U32Vector::LANES].fill(prefix[i]);
+        }
+
+        let mut single = vec![0u8; lane_size];
+        single[..prefix.len()].copy_from_slice(prefix);
+
+        let batch_size = if additional_hashes > 0 {
+            U32Vector::LANES as u32
+        }  else {
+            1000u32.next_multiple_of(U32Vector::LANES as u32)
+        };
+
+        while !done.load(Ordering::Acquire) {
+            let batch_start = counter.fetch_add(batch_size, Ordering::AcqRel);
+            for base in (batch_start..batch_start + batch_size).step_by(U32Vector::LANES) {
+                let mut hashes = match u32_to_ascii_multi(&mut buf[U32Vector::LANES * prefix.len()..], base) {
+                    Some(length) => hash(&buf[..U32Vector::LANES * (prefix.len() + length.get())]),
+                    None => {
+                        // Lengths are different
+                        array::from_fn(|i| {
+                            let digits = u32_to_ascii(&mut single[prefix.len()..], base + i as u32);
+                            sha1::hash(&single[..prefix.len() + digits])
+                        })
+                    }
+                };
+
+                let mut hex_buf = [0u8; 40 * U32Vector::LANES];
+                for _ in 0..additional_hashes {
+                    for i in 0..U32Vector::LANES {
+                        let hex = sha1::to_hex(hashes[i]);
+                        for h in 0..40 {
+                            hex_buf[h * U32Vector::LANES + i] = hex[h];
+                        }
+                    }
+                    hashes = hash(&hex_buf);
+                }
+
+                for (i, &hash) in hashes.iter().enumerate() {
+                    if predicate(base + i as u32, hash) {
+                        // Don't return early, as the predicate may rely on seeing every match
+                        // in the batch. See the MD5 version for an example.
+                        done.store(true, Ordering::Release);
+                    }
+                }
+            }
+            Progress::add(u64::from(batch_size) * (u64::from(additional_hashes) + 1));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Implementation of the SHA-1 hash function.
+//!
+//! **WARNING: Don't use SHA-1 for anything remotely security-sensitive!**
+//! This implementation is meant to be used for Advent of Code puzzles only.
+//!
+//! The vectorized versions hash multiple inputs of the same length at once, which provides a
+//! significant performance increase for brute force puzzle solutions.
+use crate::hashing::{u32_to_hex, BENCH_STRING};
+use crate::multiversion;
+use crate::multiversion::Version;
+use std::array;
+use std::sync::LazyLock;
+
+mod bruteforce;
+pub use bruteforce::find_hash_with_appended_count;
+
+#[cfg(test)]
+mod tests;
+
+/// Fastest supported implementation, for dynamic dispatch.
+///
+/// Determined using a small microbenchmark at runtime.
+pub static FASTEST: LazyLock<Version> = multiversion! { fastest(microbenchmark()) };
+
+/// Returns the SHA-1 hash of the input slice.
+///
+/// Wrapper around the [`scalar`] implementation.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::sha1::{hash, to_hex};
+/// assert_eq!(
+///     to_hex(hash(b"")),
+///     *b"da39a3ee5e6b4b0d3255bfef95601890afd80709",
+/// );
+/// assert_eq!(
+///     to_hex(hash(b"Hello World")),
+///     *b"0a4d55a8d778e5022fab701977c5d840bbc486d0",
+/// );
+/// ```
+#[must_use]
+pub fn hash(buf: &[u8]) -> [u32; 5] {
+    scalar::hash(buf)[0]
+}
+
+const INITIAL_STATE: [u32; 5] = [
+    0x6745_2301,
+    0xefcd_ab89,
+    0x98ba_dcfe,
+    0x1032_5476,
+    0xc3d2_e1f0,
+];
+
+multiversion! {
+    use {crate::simd::*};
+
+    // The length of 1/2/3/4 bytes for each lane
+    const ONE_BYTE: usize = U32Vector::LANES;
+    const TWO_BYTES: usize = 2 * U32Vector::LANES;
+    const THREE_BYTES: usize = 3 * U32Vector::LANES;
+    const FOUR_BYTES: usize = 4 * U32Vector::LANES;
+
+    /// [`multiversion!`] SHA-1 hash implementation.
+    ///
+    /// The bytes for each lane must be interweaved, and each lane must be the same length.
+    ///
+    /// # Examples
+    ///
+    /// For [`array128`](crate::simd::array128) with four lanes:
+    /// ```
+    /// # use utils::sha1::{self, array128};
+    /// assert_eq!(
+    ///     array128::hash(b"hwafeobglrchlldiodej"),
+    ///     [
+    ///         sha1::hash(b"hello"),
+    ///         sha1::hash(b"world"),
+    ///         sha1::hash(b"abcde"),
+    ///         sha1::hash(b"fghij"),
+    ///     ],
+    /// );
+    #[must_use]
+    pub fn hash(mut buf: &[u8]) -> [[u32; 5]; U32Vector::LANES] {
+        assert_eq!(buf.len() % U32Vector::LANES, 0);
+        let bytes = buf.len() / U32Vector::LANES;
+
+        let mut state = INITIAL_STATE.map(U32Vector::splat);
+
+        let mut end_marker_written = false;
+        let mut bit_count_written = false;
+        while !bit_count_written {
+            let mut words = [U32Vector::splat(0); 16];
+
+            let remaining = (buf.len() / FOUR_BYTES).min(16);
+            for (w, chunk) in words.iter_mut().zip(buf.chunks_exact(FOUR_BYTES)) {
+                *w = gather(chunk.try_into().unwrap());
+            }
+            buf = &buf[remaining * FOUR_BYTES..];
+
+            if remaining < 16 {
+                if !end_marker_written {
+                    // 0x80 end marker after final byte
+                    words[remaining] = gather_remaining(buf);
+                    buf = &[];
+                    end_marker_written = true;
+                }
+
+                if !bit_count_written && remaining <= 13 {
+                    let bits = bytes as u64 * 8;
+                    words[14] = U32Vector::splat((bits >> 32) as u32);
+                    words[15] = U32Vector::splat((bits & 0xFFFF_FFFF) as u32);
+                    bit_count_written = true;
+                }
+            }
+
+            state = sha1_block(state, &words);
+        }
+
+        // `state.map(|x| x.into());` doesn't always get vectorised
+        let state: [[u32; U32Vector::LANES]; 5] = array::from_fn(|i| state[i].into());
+
+        array::from_fn(|i| array::from_fn(|j| state[j][i]))
+    }
+
+    #[inline]
+    fn gather(buf: &[u8; FOUR_BYTES]) -> U32Vector {
+        let mut values = [0u32; U32Vector::LANES];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = u32::from_be_bytes([
+                buf[i], buf[ONE_BYTE + i], buf[TWO_BYTES + i], buf[THREE_BYTES + i]
+            ]);
+        }
+        values.into()
+    }
+
+    #[inline]
+    fn gather_remaining(buf: &[u8]) -> U32Vector {
+        match buf.len() {
+            THREE_BYTES => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], buf[ONE_BYTE + i], buf[TWO_BYTES + i], 0x80]);
+                }
+                values.into()
+            }
+            TWO_BYTES => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], buf[ONE_BYTE + i], 0x80, 0]);
+                }
+                values.into()
+            }
+            ONE_BYTE => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], 0x80, 0, 0]);
+                }
+                values.into()
+            }
+            0 => U32Vector::splat(0x8000_0000),
+            _ => unreachable!("less than 4 bytes left"),
+        }
+    }
+
+    #[expect(clippy::many_single_char_names)]
+    fn sha1_block(state: [U32Vector; 5], m: &[U32Vector; 16]) -> [U32Vector; 5] {
+        // The message schedule is computed in place, only storing the previous 16 words
+        let mut w = *m;
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        macro_rules! round {
+            ($i:expr, $k:expr, $f:expr) => {
+                let i = $i;
+                if i >= 16 {
+                    w[i % 16] = (w[(i + 13) % 16] ^ w[(i + 8) % 16] ^ w[(i + 2) % 16] ^ w[i % 16])
+                        .rotate_left(1);
+                }
+
+                let temp = a.rotate_left(5) + $f + e + U32Vector::splat($k) + w[i % 16];
+                e = d;
+                d = c;
+                c = b.rotate_left(30);
+                b = a;
+                a = temp;
+            };
+        }
+
+        for i in 0..20 {
+            round!(i, 0x5a82_7999, (b & c) | d.andnot(b));
+        }
+        for i in 20..40 {
+            round!(i, 0x6ed9_eba1, b ^ c ^ d);
+        }
+        for i in 40..60 {
+            round!(i, 0x8f1b_bcdc, (b & c) | (b & d) | (c & d));
+        }
+        for i in 60..80 {
+            round!(i, 0xca62_c1d6, b ^ c ^ d);
+        }
+
+        [
+            state[0] + a,
+            state[1] + b,
+            state[2] + c,
+            state[3] + d,
+            state[4] + e,
+        ]
+    }
+
+    pub(super) fn microbenchmark() {
+        let bench_string = BENCH_STRING.as_flattened();
+        for chunk in bench_string.chunks(32 * U32Vector::LANES) {
+            for len in 1..=32 {
+                std::hint::black_box(hash(&chunk[..len * U32Vector::LANES]));
+            }
+        }
+    }
+}
+
+/// Convert a SHA-1 hash to ASCII hex.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::sha1::to_hex;
+/// assert_eq!(
+///     to_hex([0xa999_3e36, 0x4706_816a, 0xba3e_2571, 0x7850_c26c, 0x9cd0_d89d]),
+///     *b"a9993e364706816aba3e25717850c26c9cd0d89d",
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn to_hex(hash: [u32; 5]) -> [u8; 40] {
+    let mut result = [0u8; 40];
+    for (chunk, &word) in result.chunks_exact_mut(8).zip(hash.iter()) {
+        chunk.copy_from_slice(&u32_to_hex(word));
+    }
+    result
+}
@@ -0,0 +1,2706 @@
+use crate::md5::tests::STRINGS;
+use crate::multiversion_test;
+use crate::sha1::to_hex;
+use std::array;
+
+multiversion_test! {
+    use {crate::simd::*, crate::sha1::*};
+
+    #[test]
+    fn multiversion() {
+        let mut buf = Vec::new();
+        for len in 0..=STRINGS[0].len() {
+            if len > 0 {
+                for s in STRINGS.iter().cycle().take(U32Vector::LANES) {
+                    buf.push(s[len - 1]);
+                }
+            }
+            let results = hash(&buf);
+
+            assert_eq!(
+                results.map(to_hex_string),
+                array::from_fn(|i| HASHES[i % 8][len]),
+                "length {len}"
+            );
+        }
+    }
+}
+
+fn to_hex_string(hash: [u32; 5]) -> String {
+    String::from_utf8(to_hex(hash).to_vec()).unwrap()
+}
+
+pub static HASHES: [[&str; 331]; 8] = [
+    [
+        // string 0
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "86f7e437faa5a7fce15d1ddcb9eaeaea377667b8", // 1
+        "da23614e02469a0d7c7bd1bdab5c9c474b1904dc", // 2
+        "a9993e364706816aba3e25717850c26c9cd0d89d", // 3
+        "81fe8bfe87576c3ecb22426f8e57847382917acf", // 4
+        "03de6c570bfe24bfc328ccd7ca46b76eadaf4334", // 5
+        "1f8ac10f23c5b5bc1167bda84b833e5c057a77d2", // 6
+        "2fb5e13419fc89246865e7a324f476ec624e8740", // 7
+        "425af12a0743502b322e93a015bcf868e324d56a", // 8
+        "c63b19f1e4c8b5f76b25c49b8b87f57d8e4872a1", // 9
+        "d68c19a0a345b7eab78d5e11e991c026ec60db63", // 10
+        "5dfac39f71ad4d35a153ba4fc12d943a0e178e6a", // 11
+        "eb4608cebfcfd4df81410cbd06507ea6af978d9c", // 12
+        "4b9892b6527214afc655b8aa52f4d203c15e7c9c", // 13
+        "85d7c5ff403abe72df5b8a2708821ee33cd0bcce", // 14
+        "2938dcc2e3aa77987c7e5d4a0f26966706d06782", // 15
+        "14f3995288acd189e6e50a7af47ee7099aa682b9", // 16
+        "d83d621fcd2d4d2985547043a7a5fd4d0a090a54", // 17
+        "e381fe42c57e48a082178641effd1cb90771021a", // 18
+        "893e69ff0109f3459c4243013b3de8b12b41a30e", // 19
+        "14a23ad70f2a5dd725575de6c43e1cdd8b15e3e5", // 20
+        "eca986b95d587f34d71ca7752a4e0010bfb5c6f4", // 21
+        "c436f0485bf34625ff7764ead634bccd875b514d", // 22
+        "f41a73ec7a087859800f5c4cbf71e94487346b6c", // 23
+        "d717e22e1659305fad6ef08864923db64aba9c08", // 24
+        "97b2cc05c4620ebf4ce1330623b950a4a171f7e2", // 25
+        "32d10c7b8cf96570ca04ce37f2a19d84240d3a89", // 26
+        "ea480db814cfe4a11950f062f41d6ae4eaed75b1", // 27
+        "b4536b8619d7ae2b7e865e1dc8c789e867041e68", // 28
+        "0074786f4b18984d6c198ff2e18ea8c982952b48", // 29
+        "8f9e47cadeffafa933683fd256f00c9cf6cc2c51", // 30
+        "57b45e4705b897afce805f2999f4930b1eb4dbf4", // 31
+        "3b7ccbbc266830cc3d2f8297a705d659c513ee8a", // 32
+        "fb14fa73d2d8e3aa4d682793aaa1d828151311c9", // 33
+        "5992493cb081b3b287aaba4db77fd3953fc7e22b", // 34
+        "0bbb1cfb83a5a3b0903fae0b6eb7e498905eea22", // 35
+        "254ae957a4e924e2b48763bffb72115baf6de333", // 36
+        "aaa9bd78c7ac15cbef271f957b20e7529ece9637", // 37
+        "708f3c6c9908251c76343946165e29940c61bbc7", // 38
+        "52af0b6af03e26bc3af3eca6f961d1a3d3d6bf6e", // 39
+        "4bc31373dcfa04765713d4f499fc47f6227eef68", // 40
+        "cdfdf4d46536ba9fa296a6ed022ab6ec1df2ad5c", // 41
+        "4b847f70e754331b85d5df4b8a3237a46233d00d", // 42
+        "8182ab96845ec15506933731b2758d614e63cd6c", // 43
+        "81b4ca503f16fa02f74e375f22fb4f686eb723b0", // 44
+        "3f4da22571df5f79d63a9507fe2225824a92c8a2", // 45
+        "7d0adb03889b333a81e767e36dface767a5b4af6", // 46
+        "09d76359efd8afb9c1e61b629205d4b5a8fdfec1", // 47
+        "53b52f27bc93be0d2115c088f70a8682a8679bda", // 48
+        "81e7b04a7a14cd116e64c3ce53e10d1101863c67", // 49
+        "33ae166cde61ec8a1061045f18cc2513ab7ec105", // 50
+        "7a2b08c98f3e8ba71c7017103958d99084545c5a", // 51
+        "db16441c4b330570a9ac83b0e0b006fcd74cc32b", // 52
+        "887eb8958888024972fba71fd98fab0c9f6da279", // 53
+        "c2725b307649a44556117fc43c954cdb4799f9cc", // 54
+        "ed8b55273b7180b9a64b763ffd802939834d6d6c", // 55
+        "329c9c4de87d10dec65d56fdfb41da089cbf2a62", // 56
+        "b06471185f50f52e0e487dc13bde2c319c5b7874", // 57
+        "a6226fc4c04a2c7456b1b7d53edeb38d3f2c98d0", // 58
+        "668c39e8af5e4eeb0c5ba97ff30516bde2cccb88", // 59
+        "c5577bc06634f898f86686de57af82b7c5e40358", // 60
+        "fc1daf923163178197d564e8dcfd58aca00bc31f", // 61
+        "f43b04e4a98aebe3c874514f11a73dbd7d6c150b", // 62
+        "75fc7ee428da028f11752b109affa0839f4bdaa0", // 63
+        "ea33090eac3a084a89aebecbdbbcc5c6e0e56f23", // 64
+        "fc2bb37b0bf8aefb6e1386cc3902bbc2c6eb7258", // 65
+        "975339378931f227a89ea78568ca1535b09b9670", // 66
+        "cad3ca5cddb4b71b5a87b1eeb9244ae3c751634a", // 67
+        "34995cff377b9ccf26ea28d79e41b14dd2ff23f9", // 68
+        "f6049bff4a686b50f08172548d66638648eb3962", // 69
+        "b969d0976c6ee08f3c7b6d88fa3de8c950af4494", // 70
+        "d909c8f38abeee05fac98ea179f93b414ec222f1", // 71
+        "5be93f260c9293bad17fdf2a6f560b237d463b9f", // 72
+        "b0c731008d24d162f744aebd60507012e164c151", // 73
+        "f98d8ad5f6f4830a01c08f5f42597855d3bfd877", // 74
+        "d0123563a84286eca29dff5ddbc0027b40c0e6a2", // 75
+        "e06178206ea02d748fb8f0ff9eae225659ae9252", // 76
+        "7176e038e784245bfa6fe27743ebae2f4dccd7fb", // 77
+        "3c54c32f067d90ed480b3228786fce9a7ed2cb0c", // 78
+        "85a76d3d5dc6e1f3e7c6ab09f64521dee3179805", // 79
+        "3dbfa8e463b489e0136ad8a3979bf5ad1a82b3be", // 80
+        "18d6b1622dd3e71092c1b6ac7bec2e6de9a5e2b1", // 81
+        "a7a0161d4e3bb6ce3e8c603a42f08a1f6f570f16", // 82
+        "e8594dbe77837fbac8b933ff6c302fe48e0274bd", // 83
+        "352d57a55ec91ca18ce2607e416101162faa07e3", // 84
+        "fc5663cbdf40ada605ce977389c363aa90b35457", // 85
+        "c018aaa164607f7f98130cf69bf650235e0581f2", // 86
+        "1ad9f744f1d340f5ff1d4a39d19ad0cbd09ba4bc", // 87
+        "07f421fa2da0a7db024eb00248d608e1da3b0a0a", // 88
+        "37744b4cf780fa70853b87914ecf9821b74f250b", // 89
+        "1af9c3892f55400ee7c9ff7db8f72c3e97cb3a37", // 90
+        "f68750a71c23c2391be9d1a3e62ea6529d52bee6", // 91
+        "50c8ac9e11ae895fcd8dcf5f710433b155ac1ad4", // 92
+        "ba5733b9b2bd0e80cbb65b25c86d9c93cc8f71f0", // 93
+        "6d34de936852cdfa8f8049f03e8322363c348180", // 94
+        "4f6acbecae23d13b0767d1c95a42ce79d964a820", // 95
+        "2114ef8fc1b5dd68c853e55fae95e9d8ef887100", // 96
+        "0b5cb449b07a4716722767cf617e8c206d59cc49", // 97
+        "6eb3e2cbc5fe5be481b44c7eb11e946e527c0e95", // 98
+        "09a9cd24a0cfa944dc323c4822db957a6adde178", // 99
+        "2f5903f0a9fd1d416ad9babc13cd3b77e8377db8", // 100
+        "3686f994d9888a7cce6f1f98f98db65de251312a", // 101
+        "0a411f7346404b75937af84c5ddce1732cd94254", // 102
+        "29809fdb27c0bdd980760cb06b1e5af88b0248e5", // 103
+        "e643c5902cc3e2e5cbdb09483da0163b3b22078c", // 104
+        "befc225bab567089e20c9a0888f9bde5ebe00283", // 105
+        "89dd450760262fcfaece7b2d2ce67da28a130a15", // 106
+        "f8762333e026b84fb514333b299f1784492c2195", // 107
+        "2937521d5cba6a383f9f51961617e5603f8b00f4", // 108
+        "e9d3bbcdebdb3751988ff87259ccd9b6fd39118e", // 109
+        "ae8242a21aecce7633a644b3649d0e2f8f3f5ec8", // 110
+        "fb25cf94b858a5044d59a2540efb34209616fce6", // 111
+        "1c22872e5ccad984f5ce462899d68f125f53de87", // 112
+        "fff847a893877d5d4898d8c0ff47a4e4a889834e", // 113
+        "43e067892cbfab437a8b1c6796a142a9c35f86e5", // 114
+        "7ede14ec27f07971a9475e3245cd19f1b6d0f67d", // 115
+        "e5e19d93298b5bd6bc66dd9079bdd2af5d9e75b0", // 116
+        "e23a096428bef3e098cf7e286c18aaf77112e153", // 117
+        "9f9196fff5e01ab081a111e8f1ac4c753e49fd22", // 118
+        "08edb77d6e1145fe6546e492185f0ebc40fe2298", // 119
+        "c18c66fc738b51eb07dbf48e56dbf927a16706f5", // 120
+        "ce8562482f328785f5111ace4e81be830409508e", // 121
+        "d93dae9781ad65d2a0cb0eca97eb0828146e2df1", // 122
+        "19979f96b1ba0c7677e191c6e1a20bfb08cf9443", // 123
+        "1b967a1680f2b25cc8010ea48a719569586b2bff", // 124
+        "017f6de7aad17ffc134bcb1a00db5dffcb0c4847", // 125
+        "09aa12b89f47ab38c2e59efd593e1b58c18580fa", // 126
+        "8cbeda4df6102c2713eff636d83c24214697f6dc", // 127
+        "675ad66c009e17e032cbfc4159d0dc6396a299df", // 128
+        "e2f88adefbcde55e859b6f27cee7b5f0c57e60a0", // 129
+        "7d4d68a21214db99f2d12a6576b21b8c69e60359", // 130
+        "072a473c2fa53e4e9cb8e740ac8ebb7f73149882", // 131
+        "f3cd32bc770bcd2e865d2e1c3142971d191a3bcc", // 132
+        "595689157b4d5ad23bca0d50f007aa1da1951dfd", // 133
+        "bbbf5da4ece31e840d40b84be36265200c584c4e", // 134
+        "1c7941dde50a05da3d76824058f571afd29ea212", // 135
+        "077c81870cfd7e49b45451f57654c0f17c111eea", // 136
+        "2268779c27e3ae063c93b83b13b925577406d415", // 137
+        "df3f801ea547f8b0d10e5902cca304a6a02c5c04", // 138
+        "d8ab36dbe749510b34de8d0d3615c09f1e039e86", // 139
+        "2248d1cdc9d918e9142c2786647d945636c82f2d", // 140
+        "99e9f06b0f10bf50736081977dcce47a292c2d0e", // 141
+        "83847fc46ee70c55ebcb28ab54907b89aa2e7ddc", // 142
+        "cd4625c83f2c7b011f68647dca36e8f1b1182665", // 143
+        "7859d453c03337c6af7aba2405cc67d5bb300a21", // 144
+        "bb17c584051a29908a7b4e797ceb8bdc4346831a", // 145
+        "2aa658edcc24a00cdabe7a00335884ddcdc6ef22", // 146
+        "967a5b51f04cfb573ed414538ae7d7764bb8e4ca", // 147
+        "cd9961c99122c63c3da3444b4b2536ec7bc9a69e", // 148
+        "d73308b4c519a91c7c531a022ef6aaac400f2264", // 149
+        "2da02463716a44a14bbc9dfbd0a75428f41d3ec7", // 150
+        "4c44b15963b7ebe9ddbf3f4039291fc50c0f5d50", // 151
+        "90cfa736d7d0b7f27c2fa13cd6d08c4bc9253a76", // 152
+        "d382e7f0c191960d55809c209cf94d41e57389e6", // 153
+        "608d5f313c1a00b177c8af1c248ae9c3b85a230c", // 154
+        "55511dcec14f92f22e77a0d852e389d454c983e5", // 155
+        "8150967bd7a0a1ab48dcc72155e7c9934fd5f7cd", // 156
+        "2c7dae0a38d06602326698d1f10684225e81528d", // 157
+        "9ecd6343db2c05846c9143b875f1b366d339f681", // 158
+        "e6bf889e1f465cd3c793cd2d3f1260f71a5b173d", // 159
+        "99f7d3cdb69ecc6256e3c33549c7e6b5aa1c7619", // 160
+        "0aa544afba2b4df02631d6c3075d58a3b7146279", // 161
+        "b4ff334512944b9215a6ee6c2457b389b58532d1", // 162
+        "8858921b292eb99b14e67cb498e245d794bbf963", // 163
+        "0bffe08befd46d382d016d3ad96f7f021a4b1539", // 164
+        "ec1bd83fcc9bdfd941e9252564da9be12b3b44b0", // 165
+        "eb1e380f4e65a2747024f382bf7bc669bbcb3b1e", // 166
+        "e6f34cd05ef4c9bb11e405872128c0864964e5c2", // 167
+        "d8d59268238ad4d66aa4726d10d0dc74493ec297", // 168
+        "a692cbe1b0cbc837988221af1ae697059b08c1f1", // 169
+        "9a17e139b9ea57d68f166edac47d33f4b078334f", // 170
+        "167af1230a6b984c99ebda8bf53aecc3aa2a5783", // 171
+        "401a03ebce55b5fb25a1266f7488f7f0f187d814", // 172
+        "e0ce32051d40f322fb101a0b5e73d12d1c363da7", // 173
+        "88937c5f40ce5b690c5c3627830a6debc40e6c66", // 174
+        "977e75854b4433bb03dbf2161837f4b5652b23a5", // 175
+        "e5801046f0f8c3899e06e9cf4692a4cd56c2919d", // 176
+        "2be38aaf8b7eb400ea746544224e3ce1e981dd3d", // 177
+        "9d1e460651adea77680ab0a8888492464a2a59d7", // 178
+        "c2801800b68301ca9e0aead113be49b0f6a1f093", // 179
+        "4f2fa9d5dc9325ddc49a327e4e183f9382a2b0dd", // 180
+        "7827c90719dc4ebc849916d12ce44be8cf3cec95", // 181
+        "0fa2bc5e9480fb16da597c6a92f2b2a74420d132", // 182
+        "20ec31651bd634073815e841937e094fb255666d", // 183
+        "9963e4a0f51f80272b01c932a5fd8d57a49a2473", // 184
+        "c22bbcad557fc4d8c40d0fed29e9ce916e54f55e", // 185
+        "8964785bb1193ac1a1ee976f6b5d1b70de971141", // 186
+        "8bf64a601d75880149ab926d83cffdf4d9479545", // 187
+        "6126c3765c2a9a429ca62a09137db96e45fd8e98", // 188
+        "74ac2b997581105026021278b24bb1920653788f", // 189
+        "af44c3a0113bb576011a4e39a151ce669ca1f243", // 190
+        "3c66fa540e6b713c85b903eaf5589d8a9e112ab8", // 191
+        "bf5838b2823bec44480a696685b2862c8f2823d2", // 192
+        "f8c1d6afaef96c4b2cf8eb3b7f11750d9f6cdcbd", // 193
+        "14c8d089ff3667f40466525c7f24e085e0d3df9d", // 194
+        "797f7111a54e5479aba5f05cad262041aae69f5a", // 195
+        "ad5aa200196a540173e327354554eb5005309dc2", // 196
+        "0ad4c345fe79174004ff6d7ac9c665ab0ac70a3e", // 197
+        "a4ebe3f252d5d070fafb37ed2f6277c57f58ae8c", // 198
+        "82519ae4a423c23ef23d271773054fa4813e67cf", // 199
+        "48904a78fe160b23f5124f7df5b8fed456e5300c", // 200
+        "6660b72bbd51e9775139441118decbde75535e6a", // 201
+        "4c8139091532bf54c669c66b49eedd48fe7de53c", // 202
+        "6dcc0218ff7d41b05a8f3830b9279705889b51e2", // 203
+        "feb1eb7175bd82eb816bbd93918ac8771957984d", // 204
+        "324a540a95f7e2224456e53be4a019ce45e4cb4a", // 205
+        "98fa7b14e467916df153a408ded282ff4f7230d3", // 206
+        "3be7bac634e2be7b734f42885ddeba8dd7e34f49", // 207
+        "7effaea215b3694238cabe30f829b93cca73c865", // 208
+        "dcf278dd2e550a7ac9febbc4454fa744e5c1f6f6", // 209
+        "2b0a48f87efb01f238f41bfb0133974ca0bafea0", // 210
+        "539324e4c2d7fb4abc7441331760c7ef4b33193b", // 211
+        "d13dcdb8abe78085a87781e1758d30d331adada2", // 212
+        "bbc0451c46cdd0a9018b9c8dfa17d5fd9c50fd09", // 213
+        "0cc2667ca5189a260543beedd191e6ef74dbf31d", // 214
+        "6cc2ca47a9f45593cfef67ba752b86b532d22a96", // 215
+        "860134ef6a05fccfbffaf008473d02b12083844e", // 216
+        "0d155e60aa7b8f2d1af4fa90b9c92447bbd71230", // 217
+        "f09be52c7625a1aba2b6ff66ca1cec8f789170b1", // 218
+        "d30c81f670a6362c37bf518fd70734ab44249fb7", // 219
+        "081b183f4ddafabb5763ce4723142785a12e1390", // 220
+        "e3892261dff4481a0cb0b4486e3da3dd2d5771a9", // 221
+        "15bbd7bcefebe300f44d34366a251e1462db9814", // 222
+        "9d6b5ac7e9d3b4169d5b7e860eb2e847965eed5a", // 223
+        "9339374ee3e06e60052ebaed5a9437e7d888f11f", // 224
+        "3883c19cc53c8191e2afe94900d0734a51fe8f91", // 225
+        "d56911e73a23b19b34ccb2899c309cc1b05bdf22", // 226
+        "78df5284bddad6f3697fcfdaa944106612931d67", // 227
+        "9c51f79adf4ae6e7444c534ee87686e41333a4f0", // 228
+        "543f06241846dc5a11224944a6f6bacea2ad2af6", // 229
+        "b738ef416eb8d55a53a408ee7df9b19d5a6f1e4c", // 230
+        "a85941faa5554315b309d3bac3271247a06c08f6", // 231
+        "2ba44a438e3f61d7a40b1c530bb571dfc20d914c", // 232
+        "386fd31c82264928f0e6a4e0cd8d981dd8a60813", // 233
+        "667c7de1ba3f04faeca62b72747867ad8f6c143e", // 234
+        "1d3b632c8a24b1333cf8f2994ee7449a68818336", // 235
+        "c8b1f6e004a20411cab890717af143a71f8900bc", // 236
+        "c232632c8c75c3cd01667794e2cf1627d32804c0", // 237
+        "9ebad6e3a6039b590d9c26c40436305231aa59eb", // 238
+        "5442eebad9c8409c9fe686992fb0126f4684e1de", // 239
+        "9fcc53cf65658742843c0bfa600f66de2b02d93e", // 240
+        "6fe98b2465527be5042a6bf080d09fb7fcaa666c", // 241
+        "1ecbf1ce885378b0850d5a0f192495b03e30742f", // 242
+        "a2a6270f79230a1d054171fee709c3e652d3c0dd", // 243
+        "1d6017c7887041ccb3a55b3b3177149bd1ba45d9", // 244
+        "c9a76f9518e8ee79ad69b124582701ec97018aee", // 245
+        "dc7ee7fb571cd27c3848d46f66a9684884225ceb", // 246
+        "3e666c26c962bcab0c14e67880f13b9ca8094424", // 247
+        "e2cf834b2b91578bbb8908df9fc947bd1576e7fa", // 248
+        "cc94c6ee58e02cc7a13eaf10f8367626f3aba073", // 249
+        "8acc3dd649895e27dd0d14baa789ab827b5a77ac", // 250
+        "583d62ee0c0dbd8e99fc20746816ddd29536669f", // 251
+        "d17407ab78c862d725cb0d8f1b44c24ea660c6cb", // 252
+        "eb064df2605352e9952d3ac36ebdb405f91b91c8", // 253
+        "6df376fde969475c5801d9a2b01ee38b6976b33b", // 254
+        "e16ce03781535c54057d169147fce0bdd3072901", // 255
+        "7be3fd40397408ca1874c4a88ace01c8873ea656", // 256
+        "3e17110bd2e22c8d4c0c8bfdd1ccd402a46c8761", // 257
+        "a4ea20bdbf3a9bcb40d6d471452d25ddc156cb9b", // 258
+        "4bb189c5fc910788595b612ebe0b63de154d4664", // 259
+        "154c9f833d50c31cdce8de25db01bdb9cc8d2f39", // 260
+        "6f016774b2daa77b6657821296f2a930804dfcae", // 261
+        "0212ea3a02bc823f988f7aa26b9edeb59622e0a4", // 262
+        "e60afdb55b3864b686eed2405145297265705cfd", // 263
+        "e5767c8a2b4f92136f63163513bed990372221a1", // 264
+        "a47ebafc4bd203a7d82c932c9da18af8fb1099ef", // 265
+        "b60f9011c112f20329a5b5422943cbe1f9feb358", // 266
+        "b2414a9190d30fd3a2391e4234a0a8b2deee0d3e", // 267
+        "c4077a91f06f9c844369ac40b0445ad12c4015a5", // 268
+        "76801be9a2b28cb03d39e8a90159d6d91e77dba7", // 269
+        "86ff91ca5c0a78f6901c76f11870b468bfd53026", // 270
+        "b5cb0fd5fee6035a3c4bb689f386df3829e07b4f", // 271
+        "920a98cc6216b33bd28d47befcf8aec111764c34", // 272
+        "0d81bbc28b06a838240cb5602f533ecd7a210ec8", // 273
+        "1d5fd8ed9a0afcb4c64f21d15626a6a4da6fb4b3", // 274
+        "2c64414e87847ade34ad17a876ba2c02ff7744eb", // 275
+        "5ab6ab462b801b62228d9792b4e2ab23cd868a12", // 276
+        "634cfb60882405a1ef86ea40aacc0732035f4170", // 277
+        "ecdbee15469e3a6dd026c8691d97dca82a236ee9", // 278
+        "5f1e1082a90df6d05c2bd17bcb3aa8a9bcdb69b2", // 279
+        "fb668c171b1503c5aa04f7d4ae8a6c9c4037581d", // 280
+        "d1a78548e3ca6869c238fb48040e0f5b501e11b3", // 281
+        "ffd3cd82feb31da3f891993b3bbf2d48b2356e1b", // 282
+        "8db0a93d38cb6963e649d1d7784e05a9660758cf", // 283
+        "88f1eec056e09e83a553d9547674eb936b8c9232", // 284
+        "7d690356e24e31fdc82ff8aa52d4e9f26f13ff6b", // 285
+        "38dbe4b261c0126450e29717bae680baefa9e8b2", // 286
+        "7fb7db49c67a1febc6fc5a3f8a17409435ce9dbd", // 287
+        "552e414c51b3c1789d90fe3d19ef42844f24077a", // 288
+        "a7118d0daabc49462695d16555050d78adb5fa6a", // 289
+        "cd1db59dd2aab808e3d398e861347d5a24dc3c49", // 290
+        "e3a18f820e866c87495fdbdf6d9ccf3674b904ba", // 291
+        "d975e6b5ee32e277c0958c725377bd54adb22be2", // 292
+        "e94d7a0096d0f84d9d21260f5dc6b914337fa7a1", // 293
+        "c11b241ed20e471c1f205edc0a67da5d2549e123", // 294
+        "b81508c15fbff51d8760dbf0ba9ec8bdaa8bf2d7", // 295
+        "f92c22f8ac040044b10d69bde3c2a821444127e1", // 296
+        "1110f3261e002f7e243c788839587551aaef1654", // 297
+        "c6f73ca2e1a1570c48e80495ef1751eb4fa002dd", // 298
+        "e17b3161f09f604b33ba6ba96a0c190fa0760da3", // 299
+        "398ae84bd627abd7a598a00f958ea8af1c64b9d0", // 300
+        "0a798152086597b186543cbba0f9520db9110aa4", // 301
+        "af76d239157cc94a161ccc87b2435d30f0acc757", // 302
+        "c9f622622603623986c4a5f1fa431d4979681a4b", // 303
+        "2f9f2dd5f0588b29c7b9f9a1e2c386d8654094c6", // 304
+        "7312a4c1d85dc2036854fc137200ac631fc58083", // 305
+        "ed9e4890a74db32260a96dd9873a4713106b978d", // 306
+        "21038384a9e67a2e81c8c5edc428c1a8c6fa0aa7", // 307
+        "08ace378036aa4b58dac995523733b78c867a164", // 308
+        "8b9dcff8a7d5641ca7dc59efaa6cb73f5fd301e9", // 309
+        "856e50fa7d0a34f6d55083695cf7896e1fa54b00", // 310
+        "b2700b924ce54f1e5933447d0d67f099196ede3d", // 311
+        "3ea071f76e265cfb39b56da5fa95ce8b4439c380", // 312
+        "500c00280a5b803921da40db2eedda8e12d526fb", // 313
+        "f183b04ec5fe94e5f79118677a9b5bb4efde40a8", // 314
+        "811826ad7638bdbbfc812b094104208e6cef827d", // 315
+        "e813024594de8008b15c1c9b33338d080b2ff4ee", // 316
+        "0ef0d0f87b020a85e42570e1091cb287004b85c1", // 317
+        "734424c41280a525c22e32ad1f3f472c1a0234fd", // 318
+        "bf797cb6357a03ff6866cfcb383e7e7a821bd0f1", // 319
+        "82e4f6b56b22bcdf4d7e4a6a4f4174d3877b5287", // 320
+        "da2edf02e62b8a63b193ef16360c52aa777a71ea", // 321
+        "76fb83e10252e22f239591a72f5bd317239c4a4f", // 322
+        "23684aacff0c3d197487adf23560b235fb4d55e7", // 323
+        "fd815adf93559a3e6e43516337b48d1d8d76fea6", // 324
+        "854654875fdf6b08dbb9d5ff47ce81bb61e78047", // 325
+        "204aedaf9ce220478656694a90977337ed7d5818", // 326
+        "c20b037796b5ee39b71cb5bbac62a37b8923476e", // 327
+        "1f25c52697651d6b7998c4107db06795d7e2b38e", // 328
+        "1713571ecb63729ca2f9fa03adaa4cf4cf367780", // 329
+        "b16e09bf12ef592041339d0db8b6891f2a67db1b", // 330
+    ],
+    [
+        // string 1
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "ebcdcb7effcc3f06e0d503638ac621de877fc554", // 1
+        "b573f2e02812856a99f4ea423f9b5307aa0c3288", // 2
+        "a423c62ff4200a76992d9d81368251d468517070", // 3
+        "20228e7f0d1a944129b0fa6da6d707ef48dba7b5", // 4
+        "9f805f5686329aa6d0503eb70a032dc8abdc7e02", // 5
+        "84bf1c34c5f7e67f01811a20e79162787cfd2061", // 6
+        "e6af4493f529c67b92fdf568383daccb114ffca5", // 7
+        "cc9c765f7f5bc944974671413852591bfc4a3a82", // 8
+        "281ea0bffee0df71118634d9e9a01336eb288528", // 9
+        "974f2d4db970eaa13bbf41d6f7ad421657f3f81e", // 10
+        "160a087922da3e060697600da1f87441b7e450f9", // 11
+        "28dcfc64c0a5f6c05f1972c50aaf20bd914a3e0b", // 12
+        "5f59c5efd992a03600d769585c995b27f222d02f", // 13
+        "21e14238195ef667cbaef4d6ede19cb8a58b221a", // 14
+        "3ddc8fd0f270cdaad3080317104b198d44f8ec04", // 15
+        "63a759ce958706ea2d0eb28ef1a6e049b259a152", // 16
+        "b88b8081a212ee8caad95f5f9f0e8fbfa97c146c", // 17
+        "76bd6c5e107a5433ed91b10b605adc17c2c821cd", // 18
+        "ffffe24f149c4a0eb2bc258fcfb04f6cf96567c5", // 19
+        "10755836cbb30280d58f92a58615d3441bfe2912", // 20
+        "133c0c486cf407bfc5ef50f54871347cf3028321", // 21
+        "fd9b3b09ceff7d7542d6c701c38e0f166d1b72b3", // 22
+        "f790d7480e056147c39f5467540493db9b76dede", // 23
+        "f29ac84171857c27b841f0e6485fd7429e4c4999", // 24
+        "e262ac8601ec678144ba5289dd7d1902ac3f9046", // 25
+        "df37fbffd859b1b82688ae56f660a848be845f24", // 26
+        "abb0589643eda47dbd34fb4e63cbc8778c97dcf0", // 27
+        "55b1fa91645e081d49ce5f77970c4eccaa8e547f", // 28
+        "fe0cfdd83207e70c26c6938a54417c7081d5cee7", // 29
+        "433c5d810e78a0add300107ae251b22fdc97073e", // 30
+        "26e6eec19d49387631a31e2528730d0a890b8483", // 31
+        "35175f8c381ae0a3ed3e00efc8aca7c9291287bc", // 32
+        "7676f739163d02fcdd40493ed3810a948cc68ef3", // 33
+        "56bcd55129589e9473680e4d7efd0c99b0b2f24c", // 34
+        "96de9a085023a4994f05f05dd192de04db2fd4fb", // 35
+        "da9f0f4722ceaff25f93759deddfc4337e0fa262", // 36
+        "63aa2b00497df19d381538492b7ccaccf5a2f11d", // 37
+        "37184263f2ac8c091d3a40e97fbf310920258dac", // 38
+        "480b1ca3703f2b2e21eba7cfacec418c8b9945c6", // 39
+        "6598818d4fb4bd9bca5117161b332cec5822867c", // 40
+        "471475d66af0ac12ee66371f86f24e1438421675", // 41
+        "1b85e87bd0665ae425bc915b23cef95f10ef7194", // 42
+        "29c35346ff29be238e1fff88d1a13271fdfc72a3", // 43
+        "f2712c21cb7fc42e6c18cee49f3e6098e1efbc66", // 44
+        "6f65e92a5f28f8118a10f0345992eda0c5a7bb7b", // 45
+        "abde12fa8b4f65a8c92e3792d2e475e1d2a5937d", // 46
+        "59da776ff4b700c620bca8d67e7e3e2d6c75d58b", // 47
+        "cb10058adcc7504477000d4a730c4024ee12b370", // 48
+        "2eaeb6d6496cbdae0faad47cfc54b978fa6e3a18", // 49
+        "b39db37e18a7bcef21788e42da3e34f7cc93df54", // 50
+        "280c334678fc02d1b841649f7d919965d5ac4273", // 51
+        "7ad5aaacb1bd9ef5ad9095ef642d34ff2a5c6308", // 52
+        "46f55c1fa76742dcf1bb4c38904b2fb71a1077fa", // 53
+        "668879c61febf0e473e8ea7223c3c0fc03580faa", // 54
+        "29892d39fd5c01b2bf5120bfb80d320d5baecf6b", // 55
+        "2af4dc6415a35d25991a48124e171e4d1fb904ce", // 56
+        "1e1ddf31dbfdb28b652b4b54ba7ed55daead69c2", // 57
+        "f71030a32a23c54d9321a9aca54efa4f3c7f2c56", // 58
+        "8bd80b7d212c4f761cdbe90faff0e5d4a70cdde6", // 59
+        "ddb73107e4e841f78f5a35ccd128ffcff7992f78", // 60
+        "808dbe352c8b0f595284cb4854a92b15444f6968", // 61
+        "cf4f8efaef8b9994dce40aa7fd61400623b346d9", // 62
+        "992918ddb431b694e0d26ec265f0345b59dd824c", // 63
+        "29d455f92d7df5729facb46dbfca2bf472a137f9", // 64
+        "3fe4eabf5086adf3c254e634c57004366d849218", // 65
+        "226e7762f92560d23c66b91ad3aeed5c4557ce4f", // 66
+        "fcbb0f67efcf5d4b538edd728a69dbf5ec40a13a", // 67
+        "39af83082ebea755c4cff3803f6af9f6578d9510", // 68
+        "69bd25f667770de19baf0037f58563344b1f6d52", // 69
+        "2a4bb786562f7b032ef68cd9fe33444960611d4f", // 70
+        "33db49339345cb3b248bd1ef2bca14397df1e038", // 71
+        "e45fcf061952819bf77bb04cd0459090ac6cec8e", // 72
+        "de074a97d9db0e444745ca7c1503115db72097c2", // 73
+        "fe301d0569c31570750a65c27e83b0358579b7a2", // 74
+        "4900a59b099ab2410c08ec255da305b8046162af", // 75
+        "94f2eccf38c4c9fc9d751eab02b015aea31a04f6", // 76
+        "fcfe37851d74d53a2e8635295868b1af9a036a76", // 77
+        "869e8c8a1aed9619f02414e55fedc2c1290dfe51", // 78
+        "37f10c0fddd8316ece7542265c4c21e4845548b5", // 79
+        "e41cfc801e6b1be9e5ec2a905272310c48b92123", // 80
+        "32f7079c327f6c11ee95a7eac18fe0fe180094a7", // 81
+        "76f308ec6a6ab5e3652f994491bf5362e1e35104", // 82
+        "29f27695dcea087f80fe5409ba9e321bbddb0ec7", // 83
+        "10ca7f987fd8ec16369bbe25a1f7e5df38725cdf", // 84
+        "d441b9c31ffe2f9486989b5db051da2d45e18dd4", // 85
+        "0ea4b5cef796a07c22b6bc0a795b3b934fff49e8", // 86
+        "cab05a8d5deb29a049a7320ae588212edc667cb7", // 87
+        "3242f07c8757000502e68e9b31bb86a660549663", // 88
+        "85892272590925e76fb9509ac0db971b6be09a19", // 89
+        "86696270098243cfda1de26c83d93cd4ab539be4", // 90
+        "3f23480c7b254a45e464fff31d97e5898d2d14bb", // 91
+        "a025f43855fa782cf463844a0f8f838d4391a88e", // 92
+        "020d2e2f4ec0c0ae13c06e78fbb62b5f041e6fae", // 93
+        "bdd47af203e1f183908f19f9fcadb84dd72d1490", // 94
+        "4bc474385196e621071d20c8b0a725ce5e20a078", // 95
+        "3f93b1e8b2d25355f440b84794d6d470085a231f", // 96
+        "13ecd0298ebbaa245f668e04164baea4bc43724b", // 97
+        "ffa60c73545ee169d985a8b9d4bc3c6ed6c55e88", // 98
+        "09d1f70e5f22bd1bb637cbe49363027aea698cf8", // 99
+        "cd1f5503fcceada43d2492af0f189f58b3b88bb4", // 100
+        "1405a31696f5ce9fb3d7b81c212a29c070353c8c", // 101
+        "c8746963c5940b84fd41a6338e8553ef97c6c8f7", // 102
+        "e93176afd8114137264996a9d50a9649ba8e17da", // 103
+        "9bd24e3cc6167bbcddc95a85efbac830beee86da", // 104
+        "7d908f93dc43e57f3a0b57449fbe4a2bc1f8bc78", // 105
+        "40803b59b139f5294060772caea0a273671541e5", // 106
+        "0969c3b1ac1cfa5baac59261189bdc1cf46d4a58", // 107
+        "44f470cf3c1cc27a13c4f1c49fca989442da0608", // 108
+        "0a31df75aa30903a7f5684fd8e2b90406affaa54", // 109
+        "e49097b68fc7dca1c064c0f2cba5e724a266b501", // 110
+        "4a2f77ada207b1ca05cf260abe0c01cf8b9d68ce", // 111
+        "652cbc7a2e1a893a74dba5d3d4198bcfc8fa24ad", // 112
+        "67bcb237912ef2498f4144a73920d4321b3911d9", // 113
+        "acc3544fa647c9c16735af15be056a81678d00e3", // 114
+        "3edb1a1cac5f8b0e0244816a530ac6c8ed47c2df", // 115
+        "072421d45f6c25e3bca900d0bcd58d7e6eb3a894", // 116
+        "c4d5fd1715bb6ec7c740ad3b8c718607cd6572f5", // 117
+        "03c2085526026de9f66ba5d40dda4ba8ab4a6e1c", // 118
+        "fcf48860fffc640833731f342e533f7e5c222dbd", // 119
+        "03c3e760fd6916071d96d20b9eb279fa564ee168", // 120
+        "d1d7cee4f08e82acc6c387052898014dc76127b5", // 121
+        "32fec99ad6b715e1e2c9357b8cc55f709a9292fb", // 122
+        "b3789aa3b10be7f5479010ad125c9d90e1014e45", // 123
+        "8c8650e6a57c8ca05c2fe50a91cb035ceab6c747", // 124
+        "fd9f7b16b8c4e685e36d3b3a9950d8ee1a5aef73", // 125
+        "dc89fc5ad76f5d38f773c050da9d010c611ae2a1", // 126
+        "224ea7ce13d38cc2425340ba438756020b20f566", // 127
+        "38bb9f1bd673daee07d4f57ff549c1798c60f5d8", // 128
+        "e517a63b803e282225064f4be972ee856e625498", // 129
+        "73f95c9627dbbc6264a33852c250ce26071383cf", // 130
+        "0cf2bea08fcac6e5e6d4125a07762aed76f39e47", // 131
+        "459aba76816137eac3f956b201f08912a0a61e4b", // 132
+        "f674659ba7a8af40145c400fa66266a4114f4ff4", // 133
+        "7e0127fd3d5117474b19547e5a02f971ee8b31d9", // 134
+        "a08c97937cfe410562aacd24d8ee473fa5110b7b", // 135
+        "26f18b8cc9a40bb29576718f38bbf3fc4603bd87", // 136
+        "bdc243f1c36b4b52105975eae4a75c972fc711cf", // 137
+        "a65eadde0a2a2c9e56a2c0620c198916aee18d23", // 138
+        "4f6c5a5bd76aa4b14d681e91becabd30fe021b50", // 139
+        "2f2bf05a41909236bc02a69698b4d09004849ce0", // 140
+        "f2a1803c2c7016389414eb883a89c1460fea0c26", // 141
+        "88426c681c0ff1eaca64e03ba2b4e57c7646f81e", // 142
+        "cfc5a479b6bd7204d05145a869912902126a2f17", // 143
+        "64c2c461f78d7b029b55dce389d84558ebb3b484", // 144
+        "fe65189f3c205af386c01aaf68f26f4eecb189fb", // 145
+        "d154d47ab400622a459b835e577e501dce489755", // 146
+        "86ea000aef7ecd24e30eb6e2b3f654f0c02cc98c", // 147
+        "2b30e3a58fedcd958fe9f8ee23b89b2a052171e2", // 148
+        "26b1a1074cf9a701762d6fec32050bc39a6e59d2", // 149
+        "53bfc33752cfeafdfcc18e8424ac3e0b730a8e71", // 150
+        "adf7adca5bf2cf4b6bbf36cd0aee4e9a7e929833", // 151
+        "30504e3dfb24a891adf59ddcb071131c1543711e", // 152
+        "447a1b9cac0362bcb3aca11272e913797ca8c533", // 153
+        "2c2521e710c337fb9b41b5df759a7383ad581e41", // 154
+        "e7f479c7dce69a022f4d91c7a9280a30b70767d9", // 155
+        "bdf6336c4f1dcb67bc637e3e303fcc218eda00e0", // 156
+        "003fd61f30f9b12844fd0594072f66dd4fb83c46", // 157
+        "cccf0c5714edcf05912830f0abb1766784638395", // 158
+        "ed7fe68b5c8629d2f851ccae37bd3501ce33818a", // 159
+        "a6de8c09a7c9564cdf888493776ea0fadbf75370", // 160
+        "6685d0d992e95381d4a0aa450ede9fe4759b9c46", // 161
+        "49de7fffcea82cff00a9e4c3150967d49e898745", // 162
+        "9cc0210721af9e3bcc323ebddcd7d2040774e16a", // 163
+        "e29ec6453cef047089c9bb09f9b13ac1315c54f5", // 164
+        "fd02228f2fefb0664e6c6244f8358b8c87efad40", // 165
+        "8251c5f82e7994c9e3116d587f0109c0c7ff5d08", // 166
+        "d8e10baf5aa6c06ebb4917706909f37967202fe4", // 167
+        "3a4d229984f0fc3b245c025db3a6192313999904", // 168
+        "b19c33f63245c92da1e3337df59c1549c05737ce", // 169
+        "620d0c8a59c931530f3b95b90eeb20be56925be5", // 170
+        "c2f533b4a22a9e2b0a8ebb64af5bfc517cf7b40b", // 171
+        "166e1e5660275026dd900ac9820b60997522ee7d", // 172
+        "9dbfa2cc05867b14f79e4e29b0b4ec1efcd3f00c", // 173
+        "619aa659a6366c8b0f0355486f2d57f100fd0fc2", // 174
+        "ad6d3c40063eaf13bda98d7a21705d432673ef8c", // 175
+        "b5467ae057021605365ab9117e41b04d018eadc9", // 176
+        "458be3ebc9916eab9e74df1e5feb58b108e1bcc4", // 177
+        "fbf81d0a342444f11307fcdb8c27a9f0111340b5", // 178
+        "421010c86339c9bed817df9281097b4d17c8b192", // 179
+        "dc78d5d40a9f86a1cdee0b95e7eb80ae0cf457be", // 180
+        "0bce468f22445b08f1b8634d345f551b3069c7b3", // 181
+        "f37f4027453af1a48e4d13620c547957fd3c4d03", // 182
+        "58800a062ae43aa6492761d3553e810f23dbbd6c", // 183
+        "aab35cc0a6866aba8b32e37a385f8b444d659fd5", // 184
+        "3f79a82666d0ba08b7765c49ffdd8d72758bb9a8", // 185
+        "9d407b670e1b609a17166b33b54cec058bcdacac", // 186
+        "c816f5eae1b64280f3fb6818d533f4ce04c0b529", // 187
+        "6cac2daa7a42ae38c32ad9f5810784fb4d660ce0", // 188
+        "fc3abffa636b6d15c4fcc18c9941c00e9431790f", // 189
+        "ce80cee4855f0b9ed66b199984101889a96b7f0e", // 190
+        "77b930aa2998226ef019e221203fc14e933cb9e2", // 191
+        "001770a6822c8b263a6716fd933d725d6c4c518e", // 192
+        "ac716e23018c69f11a2b621ed67286a509b0da53", // 193
+        "c5ff104f3a9cba370f27abafc9336ec8a8b54179", // 194
+        "7a2849c04d7a0e1f84ec0c87b8cce83a182a631b", // 195
+        "28601a3973f44f19710a14373a78246945176c18", // 196
+        "25a06fb5e12c3f931ef88844853ab28f86226a1e", // 197
+        "90a5194255c5b66fc0b56d9f4c723d0360224580", // 198
+        "259bdcdf2243f158341b831ed468df9d3e6c4c30", // 199
+        "58868d65f7981230184e142ee8c6fe6c7e7f30f9", // 200
+        "944202db057ea407f877fe4551507c900e65f1b7", // 201
+        "3a1c3acafba9e59dab7730d973b58c5df1ac88e8", // 202
+        "3ba796306639beafa9dd2ea59d860b0f955f1701", // 203
+        "45acfe67565984bd3f8b5d2dc94edc7dcbe77de5", // 204
+        "de886f6fce616ef6103c491d4fda4341c049a426", // 205
+        "c45d07a2ef89c53a7b7fca065cf4d208aeb9eaaf", // 206
+        "3768dc43bef54cd7cd8eed83fa1a97bf7b0fe4b1", // 207
+        "03694db5a0f54e2da88c2ed22e3312f81e81924a", // 208
+        "61bbbd92aeae1ebe050d1c8993e4503f39e2faa3", // 209
+        "7f624ca317b3f816825ba7a689c51d0abcec3cf1", // 210
+        "66b9dd9dc7bfc00531d84278fed95c64a6262085", // 211
+        "35bc6be7f8f3b4e8c042140ceb37347aa506ade6", // 212
+        "3da01605c70169dcc06f3fc2e04c3e828adb9cb3", // 213
+        "7204a4205e75f29f586d43c2c4c0a5e1cb3cf808", // 214
+        "4d5449f577ac2df899d344d0075317103f2d21bc", // 215
+        "41cc1099b2a224ad5d596a32e2450c5002e25dc9", // 216
+        "ce22a7eb5654d29f39a86333f6215dd3f09b754f", // 217
+        "43b2f4c8efc086be10bb500add48f6f8f971870f", // 218
+        "5e838d7ee318ae60683c0ba2b09f9963d5727ff6", // 219
+        "140242efad2ea17e24d604f8074fb9865b2298c2", // 220
+        "2ec7e2b4a71b3a07f62a37bb014404ade8cb7eee", // 221
+        "871d9d0adbdf52f9ddaf5f24f20c3c34d91226cc", // 222
+        "0076f3b26689127de34078fd73e9e747d66d3ea8", // 223
+        "e4791ed62acab3984fdcc7c9173198f6c501bf1e", // 224
+        "c738ae258fc66d2019292d54f0b7c1c2c31b9e6c", // 225
+        "77432b37e6388ad951a224583ab6a1904b6285d5", // 226
+        "b8ddfc111bd6c52ffa15103935dbc9aefe3ee45c", // 227
+        "87365b5b8695c3652c80ae2ef4c1a343d635c58c", // 228
+        "1d3f834e85a09c67690af6efd7f0d07cc8a2cc5d", // 229
+        "be1f5a7c3c9da4f4cca29d5d97ecbd4585d8233a", // 230
+        "96a4979a0024ee549d7dd82baf34112463d52f04", // 231
+        "2396b128c8608ac55e77b6f2902f6360320c3261", // 232
+        "a8b47110809d21fe0ce7800fa8189d5b098d98cf", // 233
+        "02c5c5476499741948545b3bb059983d62459123", // 234
+        "742e83276dd2a95adf49a4206066fca382846387", // 235
+        "b12bc80b3bc84b56f30f7f4b06c2877c92ac1f02", // 236
+        "487dc1a37f4e68380cbd3536648e3aa98e5930eb", // 237
+        "6fb0f0f7f8a1e361472525b408d98558b9847110", // 238
+        "4d0bed3ebada0529c179956c23461ae410ce35b8", // 239
+        "dd0c38ec2227dd974d55e79087b98b153d03751b", // 240
+        "7cfe78a9ed4e21109b72258291c744636fa0f8af", // 241
+        "4facb65dd9ddb1642c5d0942f67d7ac52edf9597", // 242
+        "1b6ca18ee7bfe6584542436a0e8a858db2a4dab3", // 243
+        "5a9668300a94c7b0f35519dd14f5f51e31c0c073", // 244
+        "84ff8d949524625f1c6fdc9265bc822b4d82d51c", // 245
+        "7b3037984ea4be5470a1e9ae2f8f519c165f49a6", // 246
+        "09fb448b48c961f58fb76080370de5ba88c693dc", // 247
+        "08352af6b85bd5499d8f6a9ae62822a38bb27639", // 248
+        "6d0f18968598a34be075932175c39a3279902760", // 249
+        "020fc3d8b086f5d143eab96ea4dd6090a39c1626", // 250
+        "bcfa679d98100bb7426eb5784c4e04eb6fac8ca9", // 251
+        "ce6e20d6656466759b03b0379cded0a0016df918", // 252
+        "08632f47bfd39731a3891fd0d8a215beae4b2ca9", // 253
+        "6c8c89ae8f1fae35f4d106f72879aa8259c93820", // 254
+        "92f92777dd9b7ce3b6a231cee30d68abaa94e7e7", // 255
+        "3659cdd5eed91021264dae6422ebba4a3eecaf20", // 256
+        "aa8774bab7248aa5e86223acbdd919349d88d24b", // 257
+        "5cdef686779936ff015b953873e30c091e5abb0c", // 258
+        "412f5fbc70562d09d63e1a6a6e7ffad56e3c129f", // 259
+        "fad70bbd4bf55ca20dab642c39680a75f17370a0", // 260
+        "98e85a25140091cf658aaa300a99c62d58a6a2a5", // 261
+        "2af53148b25355360e1d35b9328008059952f9d8", // 262
+        "5a171b9c187bd4317d890a3c46f33cd0eeaccc35", // 263
+        "f79ddcc10a1d42be13bf9d0742d3eb83d7b1efe4", // 264
+        "f744b977fbf52fad88cfe4e6de14cf778429d57b", // 265
+        "7b69110ae68dff717e3187666d13ede2de649e4d", // 266
+        "d6f6a4a5da0889f82be9e9d134cb5a55148d275f", // 267
+        "9b268af1640d54582ddf12391dffa39058c4cd75", // 268
+        "7e721ec9b0eda42519d2aca8452fd0f8c4f74314", // 269
+        "28958279742306b152da1115bd5141f573ca616d", // 270
+        "ac3605a1a39143029d66cc5762a77afcd7bf0297", // 271
+        "aaf61829d4bc43e31103378b256ba475f0820151", // 272
+        "ea1643a1b995d262ac02b89c5bfed6a7065af1b6", // 273
+        "f02ecbb7ea3ecd37d6afb25cca6ac1166c80b027", // 274
+        "b896e7ae0fcfdf38b921a25ba3e312f167db1ea1", // 275
+        "799356e5aaf405ddacd5f37348b760af08c82219", // 276
+        "6d6930763fe03615a549aa47bb185d6f43150aff", // 277
+        "4eb5d8e7a956daeff79b4ed3d49aade203f43a52", // 278
+        "b5352bd19ddda109b8b8a3660be8442a9c871452", // 279
+        "19089390e23595ae6bd3ca9dda3ce5e264b136a1", // 280
+        "a805fa019e50231f837a67f795b4d19eccb695b2", // 281
+        "a55b5e97fe38e053ed762911de2df25bb1d56cff", // 282
+        "7290271820aeaf0a311cbe28088210461b3cfff6", // 283
+        "63c357e53000a027c4a153d55d7b57881b384a76", // 284
+        "e70d118139afb236c22c4340edf467664a9260d4", // 285
+        "3ee6d9c877bc4281c1275166eb60a574a0f1e954", // 286
+        "ba5f850069136fe3ebf0fd75fe2ca7afd4c5b161", // 287
+        "c84fdad7c071586afa5cc2f3d5de8915b7c25d00", // 288
+        "c4b1cf8ab18c85b4e80ca659f6fe6390d0dbe6bb", // 289
+        "7c332ce9e185d6956b08acb57eeab20de3ab9f0e", // 290
+        "bf5b932c759fdf21b8c360b177e7a5a068569189", // 291
+        "f2c2134ce4a8241cef156098be881bf7be74256f", // 292
+        "78d6abb04ac306db87af578c6b77b477dbfaeedf", // 293
+        "4a22bc7bdf2333e2bff1d46ed5d71bc5f89bd4ff", // 294
+        "48989fade8e8344f5dbb6270bd0fcf4b28cdd216", // 295
+        "7a87b1626f82ba91803c5b86a903ddddfd7547a5", // 296
+        "d118447a3d120309bef638f35a33ca650a2a647f", // 297
+        "436359aab9b4cb30d07d3c3ca95940ce2dcdd282", // 298
+        "b0c054cbdd4f79a414fc3b620b599aadcbccf0ae", // 299
+        "2044bae4a00bb7d127d12bf70c4e9d1d85db8221", // 300
+        "4ded6073428eb9732f1af18b42b33dc7e8f86df6", // 301
+        "00d4abc2e64ef4f5eaf37e0a549ba3afd623caf1", // 302
+        "14212c87b0d0e2c95d487d6c0b9a140839bf44fb", // 303
+        "afc8e36836e063022f0b268ceb1dcc0a21fe3df7", // 304
+        "b9d3e3fe51b9b89b50e82bef7f36e610e4890ede", // 305
+        "2a9a808ddee27c84a4f9741ceff3fdf05ffaa66f", // 306
+        "d07dfaa853f0d4437251984e71169a4195855f76", // 307
+        "aa7ce57775da61b38468c027fca46f07d17fc509", // 308
+        "18885ba8f44c47bcb4424a680ebf0a40f1160f13", // 309
+        "503b4f8a2e1ef0cb11eae21c3e0b642c1514adca", // 310
+        "04ece38419590e8fddee0ef224168d03df981ef2", // 311
+        "129da5825c3945debf2c86f74e83010b858726b7", // 312
+        "c62e930f7809e4f12596279f29b70fba54632c35", // 313
+        "1ffb3455f74d2348d711e754ed88cc9d8b38a128", // 314
+        "cbb4d5c200a3d4f3fcf71f64acf870b5c31b6391", // 315
+        "7e6537292e42d71a49294e740d742590eca738f2", // 316
+        "6c6686557c3dab048bfb60f7fb396bc7588a2668", // 317
+        "cc7932ea680717cdb0cd76b9e945bc27dfc68abb", // 318
+        "bdf71c214632002c21941e66e759e5cdeeb12777", // 319
+        "81f0fcf6378fe8fad2ea7e8325c195a5e0de897f", // 320
+        "8df36a79e0bdad5d22698dc6140db9ce75108516", // 321
+        "442cdb0059479ae1ee62e0877c8e4892dc24649c", // 322
+        "177be75d2354765fe543238a78303d197372352a", // 323
+        "db774cbca21049f976e1796715461cbee63e975f", // 324
+        "d82899b8cdc176017c7ba81e9a147b92aa82b7f9", // 325
+        "73608ec377803e54bccf0835af79d35bfc98da5e", // 326
+        "ded6ec820c436298abb0a896d5466dda99c6265e", // 327
+        "75f1b84153e39b303e24e9710cc9843327d1a358", // 328
+        "eac933a94ff052ef86ce674b47dfe2c609a2727e", // 329
+        "7e8ef68c270fddf835a5ad21c53c8be65653da82", // 330
+    ],
+    [
+        // string 2
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "60ba4b2daa4ed4d070fec06687e249e0e6f9ee45", // 1
+        "6066ad3ecd68f2c93a9d50bb987f85bcc6670cc8", // 2
+        "1377262473ac74c8faade468ad7ddf6fe311e687", // 3
+        "78f0808ae64fd1e0b3a19f1d742d0746168bbbe4", // 4
+        "62c2a0216ebc70bd56a64db72b8ec8ddad16709e", // 5
+        "5833e7bfed50115ec5e819e618238f9e3d224f53", // 6
+        "df948b9f06ef34e0ba018b41431b907d5de2663f", // 7
+        "2c0230e4368445df2775964d2abf9af72dab6e3e", // 8
+        "fdc491480c4929d4bc031739edd359e75cc47ea9", // 9
+        "ed70db9093d9f4684c87b4de65d23ed8f1fc5017", // 10
+        "05e64e8a6c412123c8e19d9a6ad816dc27b9d054", // 11
+        "5016fce9ff4839f804e88b76329cac6f21130c0c", // 12
+        "ad0cfaf63a3021562ed1e1590fc864c75a01a453", // 13
+        "7035b320696b9f16328d28c5bccbec73ceb0e4c3", // 14
+        "d2bd13fc0d73c2405fdf17bbc9ce88346c35e98e", // 15
+        "65df0a6e51c18801f0b80295e677b2b17831021c", // 16
+        "dad99da79fcac3248606ed1e367f0fda4e894b08", // 17
+        "689bab87eb75b58c987ff1e558305567c7a3fa5e", // 18
+        "070afb96fb67b33e74b286f6cf2621cd68cbf65d", // 19
+        "793a5debe4f21d09c1a0aeaa306687c6d4093983", // 20
+        "af60c6c2a2b92e8106ae02d92d10a37f307e6529", // 21
+        "5a226df5ac79e300f4d49f838def1f59d0ff61fc", // 22
+        "b044b9576696c317da99412c310b7dc082c7c6bc", // 23
+        "95a10ab8146ee4b068fab132bb06fc82da58e926", // 24
+        "c17ccf079b813ce3300fe13d9e1284c9c726a121", // 25
+        "c26439f7b0909a2b80a61131b3882ce4a54fb320", // 26
+        "3f7ee1091bb4106772fd92648872e2cca1c52736", // 27
+        "6f8c4a15e704072e6797eb2a1d82d68b523e0af1", // 28
+        "c20c05d900ba554b8be4f856bf4f20d1a22409f2", // 29
+        "48dd74dfa9d4308cb0029af1e6c474c629aed61c", // 30
+        "f6971a9e59e8f26e82e24b08e66ddeb019407469", // 31
+        "b4ca1cdc90b56fbdd4bf2e889f6b6eda41958208", // 32
+        "6a4161ad72ec58a0a481901cd68c634c7e46b49f", // 33
+        "d42adc0f1738e100479857f9d8dda8614eb0342c", // 34
+        "88d73093c75a8013c3c9e126b224ac1708a3e6b0", // 35
+        "15ebc1a22efcab35a03f43eba8b742ec9bcd97d0", // 36
+        "031267512bacfdb86c4d4ad9c1aba94258227592", // 37
+        "d8b93645e53e7b2cbb412b2196b87874511b7563", // 38
+        "b37419694f9fb74658d9f7a55b17633c1800158b", // 39
+        "8add9c9a7d0573d6f7de5dec2159383751575a84", // 40
+        "b62060c8a4685321e4818446982fc54c8af32137", // 41
+        "2b54e4741ea32ceadabd3f97e9a8093321f46f0b", // 42
+        "a959728a18fb2d4acd07979d28945ccbbd49d7c0", // 43
+        "aafb0bf6bb0906101f3cc128276dd65c01b07a23", // 44
+        "c95652637f5cf24c6d2652adab412487cdf2b9e3", // 45
+        "3666d11a90a32e0d0958958ba0a2d057c303a100", // 46
+        "e3bfe64ccb364622081378bbc4d21ae4e3217a61", // 47
+        "84d2736759a0bb409cdff86bf92148e80928c86b", // 48
+        "2c6745994105f28f62a624a503f5c5723a3fa1f6", // 49
+        "eaca05238825517c11217ffd9b9877e362cfe4d7", // 50
+        "0d7dac96f11686154d0657633a2d624197074c7a", // 51
+        "87451ae870fad6e46b943a1c105da50ee4375aa9", // 52
+        "54ab95f57327fd03e56975465ba3f39f53f6a522", // 53
+        "47f9fa6d6ace2c4fac6be45c926c745cad3bdd76", // 54
+        "366aae839f4e6786947f3db6abe1904a9e0dc0b3", // 55
+        "b3970ea20321ae4854064baae085483560f8a39e", // 56
+        "89f1fd8e360efd1f8e1b1cd3e2f3c234d6c05641", // 57
+        "bd5f3c75b41c23e7b2cc7f6bfacb1153afee03cd", // 58
+        "990afa417d38f6e635dc216b27aa05eee7198971", // 59
+        "384bf10d547580f651976bb687fd1a332ebc1316", // 60
+        "42f9f8cd00f33d6a4ae9ede3baa256acfbb085af", // 61
+        "1cfd960e4c728fd76e70882d070a3d0f440b9a62", // 62
+        "3f2110ea3c1061d92cf59e0278dae37dd2348923", // 63
+        "2c58d1e7dd0ecacb56ae489e87440e7b893c508b", // 64
+        "68f9a3f46816350f7a6be2285fc950549a87e86b", // 65
+        "8d6aed9b97c79ae401c0e6aa7501ca6acf921339", // 66
+        "a4acdc6ab38c346ee2af8c297048d3b60aa18d51", // 67
+        "3f5083a91ac0dae11f9b32c0939aff8488538cda", // 68
+        "33591d8cab784e9776c33c68d697bf6e5670b0bc", // 69
+        "3ce362c22ed0e97c2f3b8d299832441b953a621b", // 70
+        "543533d6d9b55133b9562ec3141c6892a84caf77", // 71
+        "850556d2ac89c9512046204b0999f221f95bd933", // 72
+        "61f801c38388bf3e3481b2fc1824342c88180c91", // 73
+        "fdd1c9decc5d3bb21a1cd037d783e96da59f26d2", // 74
+        "f376213826783b900b837d4906ea7f62bf3dc0c4", // 75
+        "cbbc23b790fcf71ce7718dcbf8e389ba4d5a11c3", // 76
+        "2f2b9286ad74d59422c920855717a9038b3c5b88", // 77
+        "9e17b3b2c1506171d3092199e49068ec2de5a5d6", // 78
+        "da7de417e194d03de13140b25985b2449d730190", // 79
+        "0980ed307dc96b456f6cd06d7c7a941a191bd702", // 80
+        "c02a28b877086443edc8120371fc595ba4e4a166", // 81
+        "4f542796b9aba8905a65a204e78f14919f436a05", // 82
+        "4749904436b2cba2cf6bc8daefc172538a253887", // 83
+        "dbce80f2d570fdeb2fa84b765d127e2dfe98c0b5", // 84
+        "2311387f26e14ac26d34be2755b290f4d75e028e", // 85
+        "76b31bec81ba6975c26121a05fb87b92cb7824d7", // 86
+        "52a2bcce9dabe8080910c0ae41ecdf6f966bd014", // 87
+        "a99d43a487c7ef242f2a4a5c9099a41c4d1bf09a", // 88
+        "eb12c69c89c5576abe74dab27d046d410533cbe0", // 89
+        "58828c54d10b0bcc7d6122502ee5f300d6f72ede", // 90
+        "7ba98745e2a6febaba7986774f3c219ba420f753", // 91
+        "94fcb74177af4182aa324fa37c9046f041f518cb", // 92
+        "e02c73dfb37fa31414883c9f0d40b5cbb0e7520a", // 93
+        "93abdf2a4a78bbd55024648e256858ec043d92d2", // 94
+        "06ea060f3d61c0575d490828abf4b0ea17a0f8c8", // 95
+        "13689f0e6812a0b6855a770f9c8eefb8e5886692", // 96
+        "bc17629374f17b9aaa7b66d149b66649decad0e1", // 97
+        "40e67283d30e0e3a57b2fb1c668c39a7b566fb56", // 98
+        "a25fce16ebffcb09143ccd655fb7aeb91f31e5c5", // 99
+        "93cc735875ed3644047edbc9b3a2603e9d4f1d18", // 100
+        "8063bc058098fbb4e73bf9e4ff3b9614726569c9", // 101
+        "ee2f938a94cac222429ae70c060f1897732e985a", // 102
+        "d9f92db2b5d4829c6b1bf75dea23e8f90b40e23a", // 103
+        "97bcde4c5caf1dc4acdc87540dbb2bbc4e8cc593", // 104
+        "446f72fa1b6c3e09484db103e01ac4f6d670431e", // 105
+        "b3524fcfde697347164aea6a71eb5aeab14bd9ed", // 106
+        "851782a7e8565ba4d0fbca3a31ecec12f396982a", // 107
+        "488ff3033c1820b50b2eedd0c4dc21bdfb21eeb6", // 108
+        "33d0fecff014e6d7aaba0b91ea1d0881971a1b1f", // 109
+        "6afee205c385ab522c609118876b35ec56ccce8b", // 110
+        "7ed9bb144454388f1a5c3c5c13fcacdf54030300", // 111
+        "c402bf9b2607f5a973182eff5daa82afbb15c4d2", // 112
+        "6e5a0bb5f93672a580be80009f5be9ea6f5fd1e9", // 113
+        "d036cdf2a5ffdc00bd1fd899da2384e06316a19c", // 114
+        "6b1ca3397d6024626562fa11f3b19925a2b750d8", // 115
+        "25118757fe6808c5a025e54c93ffff3fd9f3b51a", // 116
+        "90644dabce6ca09b3b0cc076aa99f7fce64a9e28", // 117
+        "7b1ef2549742e3605b45738daec7ed633cccaae4", // 118
+        "74b3c7f8c9dfe2a468d6f99cab196e6bc7398b33", // 119
+        "79bd495f5ad22cc99a52dbb87a93e1642b6ccee9", // 120
+        "20161a6dc7e3af13dfcbccb192b0fd4b61fd4aa3", // 121
+        "25efc50b201137f8bf6932f6465cb43732e1cda8", // 122
+        "00917de9ff0f00669ee4304743ab47d1f2975712", // 123
+        "53d306244737a55e0c77ded911773230ecda225a", // 124
+        "80a6f9fef6a446c972987b9e5f79c036616a37f6", // 125
+        "cfdca4b6f75e3e2db7af44d6c04d7bed0cf0d955", // 126
+        "9e92c51e8ad52cef94f7df2b58d0c9b6fba3d575", // 127
+        "a91565c59bec9ec22fab45fb00d965993056ce7e", // 128
+        "309f472c1e8fcb6b2715755fc0ee65e98c6a76c8", // 129
+        "fc526f2823aa32e8e5bce840916ef83a054d5672", // 130
+        "7d6fa216fdeb1c344947659686e87632c82e6585", // 131
+        "c19e94d1d8d2e4fb59543eadaa4947ec5c62c13e", // 132
+        "f525ef2966376dc9147792dbf12fdd84019b6903", // 133
+        "a5e77f7204dcaa6d86c7d4c1723410319d211083", // 134
+        "38ee6db04934f478350ad468394615b96ac8cdd3", // 135
+        "009eb9da43727b4d2b85b4dfb9932567703cc6be", // 136
+        "6878f0003aa6bb96b8ec8378768abb3c1f09a8e4", // 137
+        "8f0cd3608661e3e61c6563a21b669e5c4ab425a4", // 138
+        "e869509be6324f7b03100976ddd06e57c3286f67", // 139
+        "90f05476474ee06cd7355fdaf1aabc806b2a846e", // 140
+        "52c6e120a04daefe50d1e69b4f3f039106f47c62", // 141
+        "35cabe52e8ede38b60cd4267ecdf79d0b7bccec6", // 142
+        "bd2b001bcddb8ad29be3ef70750d9ed6407b59d4", // 143
+        "caefc702885a73aca5d6b3a4a7562083661ffac0", // 144
+        "1a148c5b8224aa0101b9142aa107334928610e83", // 145
+        "97cad2f5b441936e1d91e71b356e3be8d6c23433", // 146
+        "750160b04b24f1e8df6996f69c7ca88964722062", // 147
+        "2baee9d7b95f7107efa7312c994305a7989025bf", // 148
+        "140f61cf60984325cf30ae235193d3253007349c", // 149
+        "8ad1f051ee306dcf01d242b9e06a13da011e5963", // 150
+        "335d219f8835987b16feed30405e8a2c7ec8fba0", // 151
+        "06fe3d3d05bfb257259bdb366683c7f443b458c0", // 152
+        "f06da6a82b8135fd7b85105618e0c9aa56c98bb9", // 153
+        "2d4361e5c83dfebfab2c8bce6e00784c47d4205a", // 154
+        "ce58035ed120e74d1a2d83809f25603b7353511a", // 155
+        "09b1d1146b1d2cbf235c167626f60f2bf361600d", // 156
+        "30b33012cdee3145df5359b8eba1294c9a5057bf", // 157
+        "831976ee498612ea545cf1d970ccc8d1ceaa2e44", // 158
+        "f43b1db0e876003cb436403fe95a750fb767073c", // 159
+        "ec78693d86acb6cc6de753e849b6a49dcb534f6a", // 160
+        "af9d7eab02c873c3d75c484bb3c035d7526b7788", // 161
+        "61f093eb5a3417a41493fdba1985c168951dd2ed", // 162
+        "011a6a6c8ba16f60ba9c2c5548c3128bce83aa76", // 163
+        "ecaa7eac82a7989c8fa1082ed355bd7a5d217731", // 164
+        "c6152b5bca72efa9f8b2285a0b15f838dacac744", // 165
+        "699751c91f9ab2b987561ebe52eeb3ed5f964d51", // 166
+        "7e65619e83a116874cb933e5724801d25dbd8672", // 167
+        "fe809754790aad72077e95769083186b4322d0a1", // 168
+        "6ed4fb67377783c57659d3bb1d2845a98f552bb2", // 169
+        "0f165589df62ae9a2791d885d617c4fdc7b97103", // 170
+        "2a5857be6019a44b2e3e7c93b7c4b04c4ba4dbef", // 171
+        "9b2edc9b938cfea8aee84590f5b4f912fe76068a", // 172
+        "321f864d21e960fc04199345b5ac324c794f8355", // 173
+        "f9d89304363ab5d9eb60bd3be9f18f41668bedb4", // 174
+        "06abde923d182d0b8d0aa05d6980ca88347c4138", // 175
+        "a9b5f6a0d9a18e4fa2c3af391f29a8225d8ba838", // 176
+        "769b3381cdac7d8baf7286a2c7f6ada4140e71de", // 177
+        "43b2426c7360e8965aca3fda95ac41b1540e8e2a", // 178
+        "ffbbbd5f6bccb6d1572ea43225b9c78cbdaffc6d", // 179
+        "af3a62feeaf7ea96c1eec5634ac0ff1f4c9fd109", // 180
+        "10ba583af175116757c4a0780915ae2b61e39f48", // 181
+        "cea2751ec3bc3519ade1bb591341b14c21f885e0", // 182
+        "e7c8abe2ded1d6b1dddb212a4612eb6ef42d51bb", // 183
+        "701ddb1bc63eef19b863a2936e1de52000db540f", // 184
+        "cee15ee08366dcdb629a0e6b54e53d23682ccdce", // 185
+        "68d6822fee9c02b8afe27a637533cc51ae8d188d", // 186
+        "56bcbb6d05db6b74a9f7eed6abb45601d9f69b4e", // 187
+        "ba42493a478867313c995ca008fa8027c10318d5", // 188
+        "d975cd1a1fe7272a467cc921125ae2aa2dfcac22", // 189
+        "6c6a13c7ca29ffce6c14a2725a8c55bc4a025fb0", // 190
+        "fbfb9f8e12f555ec1fd9d0eebecd43a3e8a3d74e", // 191
+        "d4408a948a839082dd19244183f518352e75670d", // 192
+        "f20de1e92dada69d051b2b4da2f6a97f6309e614", // 193
+        "ab0d462ec36d0187654eb2b05f55042d6d84cfd1", // 194
+        "e6146f11a8f90db9bc078890d90c0373f34cf61a", // 195
+        "d42c58a98326372bd7fee3c14b2284dd1dc6441d", // 196
+        "4f6f341cddfe31ccd9c98d0c007195bfb377b50a", // 197
+        "2216db6fcdd4db416057e9b97de998f5299a06c0", // 198
+        "9fb738e863caa092d949e449ec8dd79f1d0c07c8", // 199
+        "acf7cc623273c8de2ee8ce7b253c93a39e1a2acc", // 200
+        "fced535243b656fb4f4b26472952276639c8cacc", // 201
+        "55ae9543e3c7cf63ddfaa3a54b3a9d68bb73ff36", // 202
+        "884cd1210a656f5e6b1c82ff77f8da13bb0823bd", // 203
+        "c4eb6bed0969814f501d343ef69e0f9ea2149521", // 204
+        "ce9776588421afda5155f0c15685b039d15c4c54", // 205
+        "37f7169e53398f901abaebe2e5684057b942a3d5", // 206
+        "e5aaacd21e4a93472d17890f1b9953ffbf882275", // 207
+        "a4a72f5082af3cbb0dfca5497b5793c92633efdb", // 208
+        "05f0892cf5681a27648453ce4a66b985909e90b5", // 209
+        "e8ddfb46f9e56288f761ce8d9c6bdf3cc93b1c3f", // 210
+        "8f2706850921a3b13beacab88340f68bd697a052", // 211
+        "429e75353fa3ea0d9dc4d86a94234e6473469e50", // 212
+        "8f50cfe84f4ca693c7a030962d24f40bf18b7c3f", // 213
+        "1a11a279c897c3c8e4dd052f96c736d77776e536", // 214
+        "236af4d2c413baa96f630ea22e5dcf6853b00dd0", // 215
+        "bdd91f607a762a4abd3dc610689aaa01af688989", // 216
+        "80105fd8e8c2f8404310e8e4ffbd52804b4b77e0", // 217
+        "8c51aa01f1aedb1e1558de30f566b0cb039157f8", // 218
+        "8101926f6b259fb4db3d57a7880eb32a2c91a833", // 219
+        "51ffe5c2e3a1a7c172e052be1e82a26e04989275", // 220
+        "3a1a5abc58ecedae720371dd1d088f84379731aa", // 221
+        "0eeaf81de1547cc8b0355813309e445e0d5f5199", // 222
+        "3b3782675f548e4e3052bef2fc3a51e4acb1d452", // 223
+        "4909ed770ccb3a46d25a4f6c6267d467471456b0", // 224
+        "fdb95b434e0c5b7ba34cbc4b5d498de5e286ec55", // 225
+        "e25691a717ad199b05893eaeb1d069645dc006ae", // 226
+        "b8112d409a93fad5796028e53a1e921b72488431", // 227
+        "b29113babb45bde8fcf9e8933b09c96d4757ab7a", // 228
+        "be5fd81439e5d8545b3aa7466a51073d436bd452", // 229
+        "34b33dc194e999bbf5b0c84156a647c45f707643", // 230
+        "14d6ed483f677b222d2ec4165740c1c40968d95c", // 231
+        "b2038922a82b75440f9167e3458586bc4b3854c6", // 232
+        "97ddd58a9e16d603f1d335640930c5bd69c6221c", // 233
+        "09c62e6773ca041892c17d02bb9fd7854eb64e35", // 234
+        "fcc908b78113915a3fb920a50b4594daebad4000", // 235
+        "5b1d3ee00a18501eda745180385f9d4dd11b41b4", // 236
+        "fc5194279a6aadbb2a656bb3bc265488820662d1", // 237
+        "d912388033a797183d634d39c2c3e8a5f7ba8a96", // 238
+        "2483195c440cecc131e5fe3c3c0b9f2ff8396fce", // 239
+        "680a4102dc6246cc24f354cde1b9952bcac7aa2f", // 240
+        "b89f45ac9567da6c37fd59afdc338898e022b6c1", // 241
+        "5c589c399d8c1432557d74d5e4995abd13c17842", // 242
+        "f77d08d301e48054fc51d07422be22f1d753b23d", // 243
+        "7a167ff44c89dcaee67095841962c308be5a1579", // 244
+        "47e1e37069f002c62beacbe3163d7cfd259f997c", // 245
+        "ccb7df3f38cf85ee73a68087670dcf0140b93753", // 246
+        "a7223de9b1756b7ffdbcdf2e6f05032ede480a4c", // 247
+        "a00f2f76f32ed6fa0a955f7be631eb20da30561d", // 248
+        "7fc6a6d1eb9c19063292fc0f2d7ba338456c1cb0", // 249
+        "c097fcf94c9db402cd46cec9b01521f44e713de2", // 250
+        "34acc391be4b1392a39d23116383ad1afe82cbd4", // 251
+        "026df186b67293be524ad5f76bc46cdfade429a9", // 252
+        "a21c6c8b5be2365ee27894eb5d735d2ee7cad15e", // 253
+        "0bddca2ffd27496bbcab24c5b921bfc568985680", // 254
+        "32070f6d7db272712cb670fb4a7df6b6d6080547", // 255
+        "bbb3d80c0da2863506256d376f8bae52920cc39f", // 256
+        "522d2e59622b1154e0d791cf3a683f77b86663b8", // 257
+        "0a0717fb05412bd3d1f64c51a6ba9810e7f4ba7e", // 258
+        "de009bde14a98612a0ef4d276a85b22656b33f37", // 259
+        "8a7fd3d818d6ef3c525a18d8a3eea535ad310a6b", // 260
+        "fa265d9960f511d3e8ff0456cd54cd5e54444239", // 261
+        "bc361b1a338a5c3696c9342bc9c2520c55878d4e", // 262
+        "f89098ff949e16ad52e3eefdfd3e2d813376ac11", // 263
+        "35d734429dc8ae17337a38bef2d6f4160b2e51e0", // 264
+        "d3b99b07eb82bb5cc56e6e2c4194519d3bdd2039", // 265
+        "d8d4e17ca66635695b459f5a1b2d05d8f2fa2bfc", // 266
+        "da5ba8e37f2559d3bb5967bb76fd7a720f7cb65c", // 267
+        "3ab59ad5164aa7e509cbb46f0bb179d8f1c78f7b", // 268
+        "7bdb019c0837fb157193d4114e15406b4a59340d", // 269
+        "16a4a61d2939c7ad4a1d9b93627332897c5cb7b7", // 270
+        "c186363b5ab073a81f0e24665bce4e3f9ca151bd", // 271
+        "7abafa8a5235feaf981ee3561f0b9c364094a5eb", // 272
+        "d2bd5f0d8bac37bc8f92addf084c7cf091497b66", // 273
+        "fee96c0bda1cf588f85fa85e888e464311371c03", // 274
+        "28e5f0d5d42d350f4c05a69d492f55e5b45d1af9", // 275
+        "4526bfa89c9fc9a505071d14eb7f589c5d49f0af", // 276
+        "bf1ed6247d930e54edb99f1cc80443775927da02", // 277
+        "75e7982bfccc83048bb36d45dcf4befad3ebf97a", // 278
+        "9acbe27391e8eac789fc4c786edd028174a005a8", // 279
+        "b86e32841ec1b3c7cd87036c987c6675a8fadeb5", // 280
+        "69cbd1773e4f85d8f52e94200efe5fbb360b485a", // 281
+        "adc9abf801db75936d5fb8a591e69c7c42a6cc37", // 282
+        "47d513eb7e2dfa9ef96fb68a9c3ced4a2d78098e", // 283
+        "dccac1c0bb20da87a3e832082820f08522c31c0a", // 284
+        "cf69f8e873af2bdff1f9a15c74cfe5450425fa7b", // 285
+        "f648dcf7479ad4122e783e654220d32b7ecdc2df", // 286
+        "21b56b2e8d413aa8e55e6bf71e00b4a76953f672", // 287
+        "ebb7b6570c39228f0b2b6122579f3cac238c7e96", // 288
+        "5ce73a7ac4121b30f0d93596307ed2909b18772d", // 289
+        "442bad2d0e3eddec69080a2d19fe37821415b18e", // 290
+        "590e1b6bebe1189409c4a6dbedf2e9eac6ceba11", // 291
+        "679a3c81ace50d9f6b0ec424bb5f4e94b264cc78", // 292
+        "5db6f4298889fd451c74642e1e18b5ce174197b7", // 293
+        "84d7bc3a1ad296e19b52091a8ec680560166905b", // 294
+        "68fd51aabb0d063279a1b0564962154af6dfd52b", // 295
+        "ce4ef49537b40c927672f626784cd66bbf745596", // 296
+        "0a995d8f593220a384c86877e254e9d9d1717067", // 297
+        "4ae56bb6fc0564ee277d6b108266ee7f5f8464de", // 298
+        "1d1dec1e6d027275925934dfb6324732e9768297", // 299
+        "7d57dbfd62b24f09bcbf9dff5ba2da2837458247", // 300
+        "1ae705de6d614242a027bd550ec5e0780c8b0e9d", // 301
+        "16afac32bd4262a2ac937987aa0a81a972505431", // 302
+        "73d7fa18949ea520234cf727ed6fcb7ec2bd4b22", // 303
+        "9e75cae7669563e18311bbd34cbaa36c2c2f1445", // 304
+        "4c514464dec0bcd9e9132ac583e0e11c4a145373", // 305
+        "3766e0391df35bff1889c073d80497017ca92dfa", // 306
+        "a841f37e1fc73e7d67bab42c12991636760b7876", // 307
+        "057985a6c6d9a392d44939ed7d32cb313ea747e7", // 308
+        "3dba5ea48026067a67e934c6eb0d5af5d2994569", // 309
+        "4da0dfe74c1baecdc0844305fc9d62703d7c3865", // 310
+        "761e65fb26a128b39a77eea5d7bf69d746be2cee", // 311
+        "7dacdfdd93b398742eb387bd5d8afe655921becd", // 312
+        "09ce457595706b7118a3e9f30b5f2af573a8efba", // 313
+        "f1eec59e456ba8b9cf042199c203667a35d9f529", // 314
+        "495015fce97f565459e0a72843e70d401796cd2a", // 315
+        "e6417086ed86d8d98bf39c52f61bd7ab57dae985", // 316
+        "315a1efa33c271b795e815dec75b32056e2e5501", // 317
+        "d8b748ca45ced74c8652b69283dded8ce66a4ab3", // 318
+        "fdb08bf4fa4f0ea605a17979a4b0101ae61801f2", // 319
+        "3f684066d848d2fee28f1cd28c5234c73f08184f", // 320
+        "bb4ff04baf152cd9ea747450d37737b7df6f954e", // 321
+        "df8d9032fbc0789ee81d4e0bb18b12bb3659a058", // 322
+        "f3397cc0704aed7d79de2f1b9f99b76ef58d2912", // 323
+        "79bfc2bc2f5e81d0eb354aa2c553f9cce5c89b6e", // 324
+        "c827704e1a21bfe5e6354276ba8952923362d68e", // 325
+        "c186ed7e45aed27b3caae6e65862bc62486e6ede", // 326
+        "e8b40ce0fde915e1c8e47cb99a7b6f5909e1be0f", // 327
+        "28588f4e6dad7ade8bb11f6014ea2d6423fe4743", // 328
+        "6759a7cacacc70a14d2b0ff4d58dd9811b0d4433", // 329
+        "2ece665495d177c65bc8b05ea446103aa63671c2", // 330
+    ],
+    [
+        // string 3
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "b34db2b72d63f33dbef80fb30e094cc0a91d6322", // 1
+        "69c79031bf127de205107bb9f7b30aad16c7b935", // 2
+        "c3188239e78950d5363b1c0dc5ddbf79d9d8e128", // 3
+        "f2ad3d0b85f6c3de3572e76103c31cc174857f89", // 4
+        "c3e7dd2413b96fab24e66ccb7ee47d43c23967b7", // 5
+        "b213c94f9fee0f35fc9607b592070dc95b949545", // 6
+        "48e2c10212bf2712a2ab5752ceeb3216b94dd47a", // 7
+        "8edc4aef71fe669f696e7656004ccf27ffd3dc49", // 8
+        "3ea1e20d0e52621edd8e7ae5d428db7c7869e1f5", // 9
+        "bee031fc9a8da649582cea2f6fc8d34769e7690a", // 10
+        "e0f85eea03d29ad5eb527be00a05dc84f6b7503f", // 11
+        "676ff60a2cc8b14d69c92fa796ae215ee893c058", // 12
+        "4c6a83ed0c4aeb6b0b6dd3203093615f3e6c7dc4", // 13
+        "31c8c5fa32dfe291d2862d6131bf74fbb1a58c41", // 14
+        "3d196e6d07d0fdfd7bd7e11378c40cc8bfe4ee41", // 15
+        "eefaff4ea9f2accf73522ee35e888e0f18ce0612", // 16
+        "bbf35bbda11eda4e5d5c275dac04e59a3e0c8546", // 17
+        "aec45684c99a31a1d8aa3fddbdb8d5a912dbadc3", // 18
+        "a8657d6a259e73c1f9604a2520777ed10749f2d9", // 19
+        "524b03ad756f414bf6824abd4dfe523ef857261a", // 20
+        "0499b41ed3e2237b844f5ec672fc6a9296d84318", // 21
+        "988f55e257264b9b522a5fd990091e89c7690ebf", // 22
+        "302459166b300191a867ca43dc78344f758558eb", // 23
+        "df036da00035605a1e85f7c74dd340e314755c28", // 24
+        "a9cc2352294e949044738103e9f8bb0860381008", // 25
+        "6f28654b7b8f560d862c2d031a042c40d14849be", // 26
+        "d4d20d9985209646009bd21e43f12220f5d6f342", // 27
+        "04511f5dba72c95f3d912c191a3654bafc9882a1", // 28
+        "f641e19047467632932b90ce6b7d47db42c50e7d", // 29
+        "028f8eb3d829fbf948542e76d31c91f0ef89c185", // 30
+        "87eca01ec8568cbe0d0be6a85e214c47475571df", // 31
+        "79727c5063b1c4ffab0f7471e07ed793a2302939", // 32
+        "dcff527e62eba42a66a9992c5282bd11c97be2fa", // 33
+        "d3467001955eb5fda707505d55c740cbf27a07dd", // 34
+        "0771451db172aab754f4a135d0d07bc3f7f0f485", // 35
+        "d5aebd210d20f57f8e39e7b8db92cbca6ec4b5d9", // 36
+        "d9600e90ce3366143ae5d55858bc8e0d828e9fdf", // 37
+        "1b726a40a5385617e6b3878847a8ec9e08508c6e", // 38
+        "b29557b45e45fea58469a39a9334f5585fb79577", // 39
+        "6ced5c17c5ea5e7b2725cbda1a194a0d0cb2f8c9", // 40
+        "21edeece079df0ed487c2390b7e9d131e5a3cd6a", // 41
+        "6d8becaa6158689298969e7128dc3bdae22b8ace", // 42
+        "b629e4d030e3a89ba8d23aaada8f69cd7bf4ec13", // 43
+        "8ab1888bfc37378bc289f3e4a7a7defdf97a1fb9", // 44
+        "13ddf9a8fe771b2eaf5241b1157ecffa121f8758", // 45
+        "d646ad4059167da4ff6f4539c45c3ead98282701", // 46
+        "cbddb7404c04b5bdcad26e4574e412ff78e1b3ad", // 47
+        "f67416a56ca60db0986200e413e31455e3bae10a", // 48
+        "2f53f97f8ea2d0cf5b0ae834ffc81a37fc9718dc", // 49
+        "75d342c2cd603ac24f0faed9a536b79af4033651", // 50
+        "4aaa221e08d1a98221a5c8b68b1a5199ba320a5e", // 51
+        "f9b1fdb77d3721016cb0e5eedf58d723d0a4b6db", // 52
+        "d227c1901b83e2202e0a1c9bd361371d1f1af600", // 53
+        "e629bd88782be39466066db35c0e101831ee00e4", // 54
+        "2ba54ce744458aba2b5aa0f3d99d1d7468c7b0f5", // 55
+        "f251bc14eebcd81687b29807a2babb8fcf35d0b5", // 56
+        "999252148e9ad0972d00286bf9774fe25224ec14", // 57
+        "497f3b97683963d48ee497279f5e259e9c7e083d", // 58
+        "f130d7fffc0367a46f62a3887ec95ab512b30622", // 59
+        "5befef92e92788a1925cffb5555d25ed458d56df", // 60
+        "5f9dccdb382f3de537ffe76b26ae297b40e0edc3", // 61
+        "bddf6f5aa16809da7c9c29fc91a1d1b6f6236744", // 62
+        "2ecc87d3501dea76f3a784bde29aeb652fc69424", // 63
+        "a58583a55ccd260f9f88e6eea6e317f49f34eae4", // 64
+        "faaa08ca52a1891769c9e1a41e5e503801ed7e7d", // 65
+        "f1947f0ebf5f24fb5c7b2993a4e9eef1dcb30332", // 66
+        "e55bb1ab244b9e370b0936a804cc9ef66001a416", // 67
+        "05561a7a3521f8e59377b8e9a2ad6a8dae3cb4ba", // 68
+        "d73c1321eac606985c5c10a99a08233555dcffe5", // 69
+        "ca87000da979fe719b46b77f90586bf22cf5e221", // 70
+        "cd22d5361321406a0048ee2e6654b3c7edc9bf70", // 71
+        "8e83af688334b42d09628b158afa77f33ef72107", // 72
+        "ebea3e27201a1fa322bf5cc5701d09978939bdde", // 73
+        "13447333fae99c46a5f759ad7e0ae0cae1cb3202", // 74
+        "0bccac0010e432e3d68488cb6e5a1f4ce5801793", // 75
+        "eebcfa917ff730fa0ffeb0c6ccd81d577a8c7a0c", // 76
+        "999b32c744f9d68eeea57f051b08c1ab33c75d2b", // 77
+        "2d3b6d88d7fb6beaf0f71a448bbbe9a977d5aca7", // 78
+        "6a2252e67a7039f7ae7e7170a0c6ac29be7d8ca9", // 79
+        "3a784b48b9a535f337624d31a702792b7c2315ba", // 80
+        "11d5535c7f03ac92312072569c0c12697deb139f", // 81
+        "b576e2b97fe69a6e57e569ce7a83cef1293fa52f", // 82
+        "978301a3ac733e5e36d68b0ee100eaa712f6004d", // 83
+        "59d5fe0d8b2f9c1213f58b79e254c516f8764569", // 84
+        "d733511f0e84c37d224324e2f9461abdf7900616", // 85
+        "87868e5281a8500e116d6bd3a30075f3395d4b60", // 86
+        "acf1cd599cf2fda3ce7b1c84af5a6ecd70aafd7d", // 87
+        "9a4a8dc541e4fc68d14f09f9bd06da5bbe3801db", // 88
+        "5a75dac393a3a9095dcd357c1029c8138c2b9709", // 89
+        "3c27a0756afa72e0ea697c745caf166c5b9c7c34", // 90
+        "009cd6e7a15eaaf58dfb71e3be10ccc5500b11f7", // 91
+        "b39131c944964cd14d5b4403f79dd2232a608b46", // 92
+        "88e6f5e7a65edd561cb32b5f47e905ddd6b58615", // 93
+        "b1d9523ed981b3574d43e3d955c43d0613e82947", // 94
+        "81e8a44462b8cb3470bc2fa2512ce53416eca139", // 95
+        "cf62d33db17c406377bee2dbd327e6198f1a17b3", // 96
+        "f917ef3ef115b9ae0642f11206b186f115d3e72c", // 97
+        "2806d4264e35c55bbcd880c82bef535c418231a6", // 98
+        "40cac28140fd4dfbcdddcb78e86508efd2769727", // 99
+        "47bf8bbcb68020d039fb4a574580ea50c5153799", // 100
+        "adf9f7ddff6c41ea2cc84bf3d47dcd83fb1d8834", // 101
+        "de2a416538828eb282d6f296dceea47c8bb9b613", // 102
+        "f642db95e643747ef5d89be77f554847a0c78650", // 103
+        "7ed036fc0ba03b928d1ea3c6cb67c050eff5a9ec", // 104
+        "3305e90920c2a7b06dd274d3eea55326de1c5539", // 105
+        "c77be96a734995efbbd77acaa31d0f9dc0403ee7", // 106
+        "3031e67886a25b700c150d630515c35b52391930", // 107
+        "0f9e77e96ed2fa22abeac61854e7f1d8902e5006", // 108
+        "8a8e47b2f8f38c71402563c03e472de0459fc091", // 109
+        "8fcbf95bdd242411662d04a2c0186943acb918b9", // 110
+        "21e300617e9122cea3a269592d54b79a179ee825", // 111
+        "a769db5fe9e492da7cdd93f512597fb02b81ef83", // 112
+        "946dcb2af5ddf6bda0b0d3bb1688606653590289", // 113
+        "2411e809ceec59f5e5654a31fb713e02998fd8b0", // 114
+        "c17f16845fb7f90bfcb3d91591d8aed843e25c30", // 115
+        "df3b86c4ceea696487833cbfcffdc70bb06fed73", // 116
+        "db0f3bdd829b38595d999fd9c7e302ed30d7a682", // 117
+        "1567eaa89df6cc54109baf9ea49edfed160547b5", // 118
+        "0da4ae6499379e81aa7efa23c782bdb8867074bd", // 119
+        "59dc4cd55f2fa96fb49c4347955f2cc55cb7584c", // 120
+        "ac0bad2b73c36587feecdefda454157ebb23b7d6", // 121
+        "32af5facc820e65c5b9f0240c58bd54fecc81b1c", // 122
+        "11e800805d9730bd0088f7736c117ee6e5633a3d", // 123
+        "ba78acb84b3a6550855cd0db5f077fb1a5312b60", // 124
+        "8525dd4c79021f85dac4470b6d0cfbfeb15eb5e1", // 125
+        "d8437c164acb37b6b19f904798eb72dd0700fb8e", // 126
+        "8b642d9df8ac28ed376164fadc99587047ff9338", // 127
+        "f976b95214182520bb2fd561cb2d849397dc089f", // 128
+        "1341bdcf6ca82c7db4f0ef35b1623ddd094ba276", // 129
+        "262e46eff30d79c5409a15c3974f9d23e31a4c3e", // 130
+        "81250996d2215719f8e2f9046a435f84335eb733", // 131
+        "7e4cdfe4f0a3eaebdbdfba72ec598a71c04dcab0", // 132
+        "42a85547ab614ac5f6aa61155083560ecdd6b265", // 133
+        "b11431249ee6f876b8a9673ab81d3e96bd58f5af", // 134
+        "25bf5b511bba7b0ec24482d9f7456e9cc254144e", // 135
+        "57020eca827dc607b09a712fa0bb51e44cfae800", // 136
+        "9a724787e9e7b3d5eb713e15f32f89de4d0f97c1", // 137
+        "e991a14618532da115c647bfec57ff8550984e20", // 138
+        "42518139a3fbbfb99816fec2e25586ddf1964c02", // 139
+        "74c1bb22e102b443ee0e0ad7eb75b2542557cd55", // 140
+        "d04317e0b733fadab562a5060735fdb468dc0321", // 141
+        "97f02020a94daf0b35aa70f520a412c3c1cbdc9a", // 142
+        "cd0875e92dab28833ec91d4c4a01548351ab6073", // 143
+        "36bb4d0aa39afaac52cab33e5d626f7a934adba6", // 144
+        "29109bc50151ea5a9a190fae64b8c0a5c2343cac", // 145
+        "870eab107327757a408927a9380c8e9a3dd2eed7", // 146
+        "919bc7c4afe2d2d826788f4cc3d3124092102c20", // 147
+        "ecba8ded9d76b27d4c6e69ac9aa0b91fae223b24", // 148
+        "6c676b7d7dc9190571aa29c791f0316bd1988ceb", // 149
+        "79c0403bdbf94148a99130bc6968be7bb967ec89", // 150
+        "b25c875c97987d8d8126c09e11d94625addf170a", // 151
+        "8a8c835ab084c17c432731c61a177ae7a8d161cc", // 152
+        "f7895b4a4f9da167d1dbbeecca9307f272ec5268", // 153
+        "c009524289ca58625c7a3988b7811f609b2bf94a", // 154
+        "766a3f622a74a8e5fb3daf3b86071a77f5d7a5be", // 155
+        "218304b77d8c7300f51adb116b34a880a0fea3f3", // 156
+        "68761d8c2e2663951308215fc85971ecd4ae23a0", // 157
+        "ace6909a4effb52b6725dfc3fc34b6dee4ec89e8", // 158
+        "3140129856a0a077de1be1efea8e4be490cfdadb", // 159
+        "2c576cd2fb56a4efa608bc3796fe14473bb48623", // 160
+        "9f20a2a8a894addd6cb062fbd12185a92d371855", // 161
+        "470645028eff3693a68edd585dcc316e775c21ca", // 162
+        "d1d4745a71cee0a2ce865305b5df075bd60f9bd0", // 163
+        "5094efb3608cf82bfacae6b34dec91e1cabcf09b", // 164
+        "5173740eeb3195051ed9c94ea04fb0ef29d14e8b", // 165
+        "79aa1e046658cf1267351e6b5fe53de44ea99f50", // 166
+        "5947aa27dc3bacf39de6c0347ae023f17c289d24", // 167
+        "5f121f4b3e2f6c133f28d83994134d0f6baf7c50", // 168
+        "2040e6f51e0e75d155762c9dcadc0082cdcde159", // 169
+        "e2128a35d9c525794a1d1da5196244f9e70380cf", // 170
+        "8851b7a2fd14c3f8ed027299a20a82733d2c6e36", // 171
+        "b4ecd262d17b2f7bebc187b8a11527469cf6071c", // 172
+        "136b35820d8684cbba90ae28b5605416402158d8", // 173
+        "b57124f9599e56590c7f4f9f050d66910cbfcfb4", // 174
+        "f6ca3742f51043f2b69b2363e3c7cff7f1ce4fbb", // 175
+        "1374408f0fe8b32084ac0a87a23eeda26e2faacd", // 176
+        "9e3991b58a048ab1a9cbf3c19bd58271d54d3c5a", // 177
+        "c00c8d33e55b88c39d33b0e8afec1c9360b18fe9", // 178
+        "f121c6b165e86be8963b11fc29c8a9c3679a2340", // 179
+        "fc0022b794767d1df4751912cf7fb46f242d2d16", // 180
+        "8a9a77571df564448c9253633aa8750580d324a2", // 181
+        "2c5861a1ec1ffb67b9649a5f495d493661465934", // 182
+        "6723ff36fb81011437676c084c624b14ff2fcf3f", // 183
+        "d7625d0ba48ed9dce9740aafb5cb547ecc198fd2", // 184
+        "27559d1c383df9174fbad94396af8b56b16c2100", // 185
+        "661fa1d4a8d6b6939d47ce69cb855bc9c433eb05", // 186
+        "ef2eb741755b7328d3bc2e5259732851534f94f8", // 187
+        "b6dcd152fe67865e422c001630ba666e87b2d01a", // 188
+        "b561caa5265978bb89b9a710304e1de973cfff0a", // 189
+        "0705432c4038ee4a5aa5a720fa3e5bbae7ae3632", // 190
+        "19e1bde4f5938a075692fba51d067dcc1fe32a11", // 191
+        "26db6b3e25620a0730bae6997b537a489c27760e", // 192
+        "b24bfff4088b6682c2a4894f53b262f8d335aa04", // 193
+        "ff2a76ad101ed349518e407988679208888841f4", // 194
+        "14d8d037a8e8a303bfd5a893dfa6370158049785", // 195
+        "450bb8d98363143bfe42c79d378ff5cb245951b4", // 196
+        "c008fcc024b5ba1961919e7d6073e9153493d2da", // 197
+        "ab63f3f188315763eb87d12964f3dfc4fc703735", // 198
+        "9609a5a93db83137cb65f3033029f8144de1a9fa", // 199
+        "5d6afbcf55d5dab13ac47930210ac5560e1a17cc", // 200
+        "af75ed2033043421c5bb81a885b19f8af498a6c8", // 201
+        "9872cb3c1473ffc07687cd44fb5a3e0901813ca5", // 202
+        "e948eeaa59a5b1b66eaec37ce83cee413f455819", // 203
+        "aafa495480d572f936b1e1234186a186cb19e92e", // 204
+        "b4c2022cae8f23c2c530dfc77ba9cdaea3bdd2ab", // 205
+        "6ec9b78b38386fc377a88dd1d8c72ac3f9702575", // 206
+        "4a473d74caec0e602e6f7c7e13f9a35f550ec9cb", // 207
+        "44cc45eecb27e41e549db7c99f67104bfefdbd35", // 208
+        "21a772f46f06a91258097547dc2f9bc3ed21ff83", // 209
+        "9cc3b6ee3a426eed6a74e7a7ff6fab5c70049490", // 210
+        "d9c2491afee34c20040155981d0603c283f381c5", // 211
+        "382b530a9b907748d012d5c64f842d16fa9e0946", // 212
+        "d2a48fefdab71bfe441d2762e4c8ee610a66522d", // 213
+        "14f991ab3e3b385d122d8c696dbc2f6f72950eeb", // 214
+        "a9b162c820e3e27a0b620a3ee27c22f6f2321d68", // 215
+        "9f05f814c228965a5c534020ea89021a43aca504", // 216
+        "0b4a431d40f928a9dd391b933f50e1e97007fd39", // 217
+        "7b7aa6fc5f570589dce396449e512025cc0712bf", // 218
+        "9907698a5737409e05b36ea165ebde78665a0ec0", // 219
+        "af8ad68df18a4d5db1144439990f890f21f5a9cb", // 220
+        "3b10b705232f59a0bf85b209b85b8cee2322d63b", // 221
+        "d6631fb5b28475d57171585946d9981a64da3426", // 222
+        "3668b56a692e93e1fefe7b87b8c71d27fd01898c", // 223
+        "1da3befe79d1423b4244864fb52f5dd408392b38", // 224
+        "aaa4500a2488c97cf5cc5cd1e901ad37f751dacd", // 225
+        "70a8d6e5265c5cfa89f66fadf140c02b01781da6", // 226
+        "01f5a8e6cb58cc841f97fb5a541c29b91b04dcf1", // 227
+        "8bf94837186fc211d940e870f732573a7de8e9c3", // 228
+        "65934129f5cd3f81c989cded40916d37d9fed9e7", // 229
+        "213cfc60a52b1bb12e5cefcbf278986a555b5b22", // 230
+        "1f16dd7b9c5642ccbc3b017b855e604fdc4005f5", // 231
+        "e4325e5b71ebaba0dc363f0fe5828aae0be2e694", // 232
+        "b331b62734862cf7d37dd778f947d61a760fefee", // 233
+        "a438a159e2f0fce95e50558a29db1d608b9756c5", // 234
+        "00125b1d83c662f4d218907980b0b1796882eb1c", // 235
+        "3f4821b0f7e0ccc9a3da1c016cc9d0e6f4d10b47", // 236
+        "5b78cd8a313a1efb5588bb5e2689614775cb6228", // 237
+        "86369da275d2f7af7fb856181e28580a57660a8b", // 238
+        "fb1ae03440f3d0c8c9f42e329885e9eb34e40419", // 239
+        "4bc19567750aa5756bff81887163bf740de6aa12", // 240
+        "19259d057c153054d6a49ec934d55362df49a6bb", // 241
+        "b78e436e32ff6d9e3e850095f3f4fb6663b272c4", // 242
+        "d02ec3554101c5d3225e6bc2172ac4596263bf32", // 243
+        "78f545235fca8ca95bd085d40812db5a8c7308a9", // 244
+        "1b11243b842a96ace310ef1f2fc41f5a9db1455e", // 245
+        "a237c1c531143af83484600172aa31e2d1871702", // 246
+        "bbe4418b370dd5038b993c9afa9a018b42f0724a", // 247
+        "da88516262725e509653b10f7583788434f15ee4", // 248
+        "cdd04910661f4914cdc6160fc66442d744c6d729", // 249
+        "c2b8ac3709df0043f082443a179d10fc20d6b327", // 250
+        "1723b93ea4b9a4e7cf2e341db7adf62f8dceb546", // 251
+        "28d566ae51823b1843a811d22f66e1ded215af96", // 252
+        "a4898a59eb87c16051415ccbbede5e0eea60a2ed", // 253
+        "669aa982abb3f28f6beb64baed9179f597988b2c", // 254
+        "793674e77055364685c7138bb7fef32eb64c60c2", // 255
+        "cdb6dcf9aba69367ebb329aaa376e0b02f121eb9", // 256
+        "6d4f671ddbc56449cffd8f5262af11e4beaf334c", // 257
+        "f29b7020cb42ab547634b758550ac08f868b857f", // 258
+        "33296038e248fb792e7fae7067410833f4b94547", // 259
+        "ed87088345e00f0476f12875067eaccde1eb68bf", // 260
+        "968eb997e08030e98eb23ac1dce127e290271b35", // 261
+        "838a6fa20923369cfa829e347c6897fead4b3768", // 262
+        "7736fd57944a6f910e5d58b3abcdd75f9de52bf0", // 263
+        "5728ab7fc3e70ea1f4f98c56016aed017f69e780", // 264
+        "410793cb7238ed2f772219486200dbf7c119d3d8", // 265
+        "bec373d72a2a3fb3de1ac6a7bd74f761b37bf5a1", // 266
+        "b113c1ac535a90eb26ff511dd1c5800d3df1cd4d", // 267
+        "7e223c672436423d5f9d5008c1eb76c71a70d90f", // 268
+        "f6ac8e22ed11257373ba904b5de461397e5958eb", // 269
+        "9bc73c0f2278253e3699add65021a7ad40faa6c0", // 270
+        "8e2dedd890a5e40b186e97754ac674a594d57605", // 271
+        "6eb73cc13d8471da7a4deb29da1f266916cb0fb1", // 272
+        "350d63f7e54e1d248c12fc3a089e41adeaaacc1a", // 273
+        "8116e1a2f866cc55d6a238fafe2ab0098c6c29f7", // 274
+        "ccebbde836ee372b20eb496efd31fcdc0bb896b0", // 275
+        "e8183a74b6dbad07d3a9bcd9ff7d3fe4e04ffac8", // 276
+        "5437a6b7b2c280ccd8d1fddf99060a72707a606d", // 277
+        "e52e3b3784efec7b37a0a927bb57b63e19d676a3", // 278
+        "d0ea3532c4b76ace707e118d354c034b12a6c249", // 279
+        "a248c07bc133e157aa59e1b1e7ad337c101b6dda", // 280
+        "47f753d73853a95cbc9cc48f1cfb0bdedc15709b", // 281
+        "b209fb6c22dbfdcb341fe29f50cb8106da2db7b8", // 282
+        "2348a60ddbbf1461deac871c5be11c0fb36140eb", // 283
+        "b05e7ed673c73df17ef4d1df59f9896a37cbaf1d", // 284
+        "e6d9cd8cd276447e0d2226f386115ad70a08e0f4", // 285
+        "bb8716cd75460bca4e289bef481e85e4b1da134b", // 286
+        "27016b0b363764fce7b41ff6126443e1413a6d43", // 287
+        "bfa3ab1183f17e894303c780fe78a7e78115ef29", // 288
+        "b979866de4be92b5bef131142cc34bd5da0130dc", // 289
+        "d3559354ddcb95946c00544cf6f051ed2c1945c3", // 290
+        "1aa5d363cdae2ece08ffc92f938ecd436f27da49", // 291
+        "deea30a939137786a1202c28bb12bc7a6765452f", // 292
+        "c3de3fde75dac1ffe88706d6143856aaddb6b5ef", // 293
+        "ea786b3c41a91bdfa6fa4c23afd1bf346f89039d", // 294
+        "132d581e447cf266bc3a85ff55b45e5ebe92222d", // 295
+        "c434c7c93bc4fda762c94a4027e1525904a824a0", // 296
+        "08a6205ba980d5a0a54806f1b746751d31831ee5", // 297
+        "48eadf955e0722c9f5bb8f82ddd81e323ed5ad32", // 298
+        "9396faca7f99ae57b984e6b244c1f6eeebda05dd", // 299
+        "70dc71454f2fb032d91d887d978d17270371a83b", // 300
+        "379f6b18c27dad442be58ca70f1c59b7db720ef1", // 301
+        "2eab84539ad662ab1f8a2b43fd5a071b4aedea7f", // 302
+        "97aafdee408e2ec28d9d2ae207c848d9eb07415f", // 303
+        "d2cc9797521691de87eed8cd36984ae7edfa44e1", // 304
+        "af73e51980eadbaccc791d7cec1918961be8cf6c", // 305
+        "92c5b84860fa8f4cd4a555106fa24d6b9994bb16", // 306
+        "7a813de918ba1ef2b3343d6a3474d8905aa12495", // 307
+        "c1449991320e41a476d189bb8c494976b06972c1", // 308
+        "cd29749d35de692d8ef0b96c892ba0697014e573", // 309
+        "f88cc919cf9c3c80eacbbc5a65dd699d88a86782", // 310
+        "85d4365dc414c10578c043e2db78d2916cb3de71", // 311
+        "42f805fcfb1ea0ff69f4987ebbd6601f54a999e5", // 312
+        "f56f0d4f12f04c2d0a9463608de15e3516386d2b", // 313
+        "38f2101229c6e8d31826429aa0bc58dd742d6ea2", // 314
+        "a436db2faa64d05ba81710b60cb1111340f16709", // 315
+        "ecf8796b8b5f5397f384bce5e19d4d284f01e177", // 316
+        "c53b3e225532ffd5205df0f24518048d75c2bf98", // 317
+        "03f74bea07b48b0e7bd84d3f031ae5dd92dbc9ee", // 318
+        "6d8ae5df871970a469fa54166974a2555131e42d", // 319
+        "fde5d2ea4389f15d36162e7045a605f708539381", // 320
+        "2be5a38d7c7abdd4d916e2921ad0a1c247c1f11c", // 321
+        "745e33fed2499b4bf17a32f37ca9d7e69c3c0dc9", // 322
+        "7eea892edc691b2ec10e55aa50a532c06089f9f9", // 323
+        "ef78381329e143fcb0069bdb527ce8105279d228", // 324
+        "892e0d76366e8e0bc1a65c7bf9bcf041ebd9efa9", // 325
+        "8d339720bb397ddc6098434484f10df09bc24520", // 326
+        "12e60464404b7954adf9bbf144ec9f8d238fef8b", // 327
+        "1853017d03b46277539edc666baf9a5e2e1643d4", // 328
+        "4c49093f436402d1497c2d88531874008894c1fb", // 329
+        "630c8fc98216481c3de202fa28a1be975076f215", // 330
+    ],
+    [
+        // string 4
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "3cdf2936da2fc556bfa533ab1eb59ce710ac80e5", // 1
+        "c515295c5d4168c8d7858bfbac3947e6e793e748", // 2
+        "c16cf616da9630031b5fa19072600087e0665edb", // 3
+        "eef3bd997c1219930acba6f270e3083e294b09f8", // 4
+        "134b101685a23722239172a0893f9812b7eff9ce", // 5
+        "d14edd1d9e740a9dd16fedc962066ab71dc63ef7", // 6
+        "89341cf42881d533abbba211edd7de78c437cdfa", // 7
+        "12fbe4fc1b878dd111d34b9b60d37b745c5ba92b", // 8
+        "af80a7149cda5bace27af127b7094d5b1094b387", // 9
+        "3a651e62e387e3eada65743b892af7d13e4b337a", // 10
+        "34287fffd5f6df2a2142e6a3f1e935a6d76628fe", // 11
+        "fba58c42422d76071433b2777f40e1745da93df0", // 12
+        "7f71eb6058bc90b42a5d4aa3a4b8e7dd1c33beb1", // 13
+        "2129112ee79441a1fbff7c836f1eb69660812453", // 14
+        "a49a1e2754cfb426b8cedc68c63c54a9dbdfef8c", // 15
+        "9cdf745c7a92c93e530870a41edd70f3d0e2251d", // 16
+        "f0525d9817da0439befa0e58d3612163f112eb73", // 17
+        "0e71e540ace205d30e3f34afee6dd25f546bcf8f", // 18
+        "6d6eec0aa3e8b77d8a508c6413a02edb5f98f1f3", // 19
+        "e48aa513591e01a1911925c3d085cdd2e7533ca6", // 20
+        "25958921e124a357bf96bcf9945956b88e42b92f", // 21
+        "1142aa6c8b1182739eca5aba176296bacc6d7754", // 22
+        "c1d759ea4f2e7e2c79428dfef4c0df8f344a435f", // 23
+        "22bfe007e538055f674445ed66388ea42992fede", // 24
+        "0575d0b751434a19854c715cc282b12ecb912618", // 25
+        "bde89cb4291d0061f96d6b0e552036f12822be09", // 26
+        "cc4894d8a64402e9e33364e299f1c028954e97e9", // 27
+        "7bfbe80780cd9739e118ecf826982c9105fabc66", // 28
+        "15e2a34fe3c45e93246a53d69d54f46aa19af77e", // 29
+        "2c98a593bb541cbddbb3e0e5b062033fe88d348e", // 30
+        "6aed6ddd31d0dc433d300a400bea397c41d9e543", // 31
+        "b0af57883bcd0a3d51eb647c4f83b14f029d0dc8", // 32
+        "4f78c60e12a2fa07fee5af80d08f250a29c2a34a", // 33
+        "5055e32786a9c32fc308a47cd3c6dda778301ecb", // 34
+        "e6a732582c5ad52f88bf44d354443c08130f93ca", // 35
+        "0051144cd9e44d4ce07326098be8774922266543", // 36
+        "9eed42d2e7b90d14b95c0afbd4e21b3b217f0569", // 37
+        "e46dba436722da1a8a8309761a5d46fe6434580d", // 38
+        "3707f76eb3f6ad686476f2175277fef5a4047e37", // 39
+        "6b125552dea94676a7286e8aae134768c3785cee", // 40
+        "ff159f96a5dc8a90513bbd8fc749e561e5eda5a4", // 41
+        "e708ffd50d8879976862cccf429450429850d0fe", // 42
+        "400d12b3eee9fb47ca10c929986f5df1025a623a", // 43
+        "dde29690a00e81b8d3c6f50f2fc63508868f4196", // 44
+        "eefb299ff794c2c3815e50f13b3ddbbad7d8c098", // 45
+        "cef04feed264c794ad82729ca15191db147cc840", // 46
+        "ea294c535e5cf65bd0d5103e9843b4a1fbd9daa4", // 47
+        "92c44f5e17c57f00b04caa8d3cb78d73f433be1d", // 48
+        "004a2821fa4a9493d2d5604de6afe4f1e60923aa", // 49
+        "065ac83c81aa460713a323c1b52b51afef84771a", // 50
+        "0ad0b3b4e8443b1083496282aa5b71d443167ce2", // 51
+        "0094ab65d1d715661ef2b5118a12386e26133775", // 52
+        "95bd738207607151dff8a6678efad8faee7352a3", // 53
+        "ea884880002e3d71465fd1fffe2cad3bb540f740", // 54
+        "70b7a0d6107ca325d1e239be22f2bcf0a995542b", // 55
+        "b2989d48b46a9ae5923298ee39dd1963cdd53d39", // 56
+        "48c1f55ccaf24313cbed7e4660c20f384049f35a", // 57
+        "9d036f1ccf9dc80081c5a37388cb6c5573b85413", // 58
+        "a260df62dca0cebee8796b5108433113bd35931a", // 59
+        "3d1b3218da0fc6b3477ddb14053ad88b021ab5d9", // 60
+        "d47b1918f89dc93a54e71965b21b1237b6389ebd", // 61
+        "71e07be30a4a731b76357df225e8d7b4f1e80a31", // 62
+        "af7d477a69f46251037ebeb79631938a0c27b6f7", // 63
+        "87cd848622ea5866c464fa3710c2b51d04a5bb1b", // 64
+        "2215f816a49805096d3c5efc6622abb466b9a15b", // 65
+        "466ad96c23fb3c29dda8f995cb672b5b6cebfe79", // 66
+        "852ebf8209ef6471d4977af0a8c553efda7fab49", // 67
+        "e4d6e76b821c36471f5dc7f089efd95805e7858b", // 68
+        "6fcf519353b2b00f0fc85dfb5a4f26de4a8a06a3", // 69
+        "73c4cd2f713a30e8f28bda0577a58689ec934290", // 70
+        "79219cc4d8c3012f75770b62f30435c2c2b35013", // 71
+        "4e89561ca53cbdec05292c5c6cc26e53983ae7e9", // 72
+        "c42e7abc85e810a6f62145530e08063973b2819a", // 73
+        "32f806572ff658e11c390b32d33a85987f32882e", // 74
+        "3cdad1569d6820b261f590517caa7047b6d4a37f", // 75
+        "e5b2013da5128da63edc56444555edbebf11fda6", // 76
+        "54f4378bffcd26a42ac9ce8a4ff7c351bef1e8c3", // 77
+        "d55980a0bda37131c81ed3cb46239ca6b7651679", // 78
+        "c4332aebd15abdc3ff1ab39c61774ec7270d3c11", // 79
+        "ea21ed2fc1c90e7966d48a83826f9acdfd548483", // 80
+        "253309e94b0808e12ef8899b3f824c7c60e06148", // 81
+        "7a21fbf9535c5979a796da19c2e70db45683b0f2", // 82
+        "41d0f6edf9b505037875184d62531077bc0eef09", // 83
+        "9c867b2c1627af0dcc55899dca07913b665328ac", // 84
+        "2ea29d393adf1d4a17bbb53ac679314976fae6d8", // 85
+        "1317c4976cee34cfffe71d20db587f89d073af4f", // 86
+        "508854e3868ecf1e83b865d5b9fba29d5c25af8c", // 87
+        "ce14d10a8a7e7bb1c5dca8e49e80d16a5e58c460", // 88
+        "0e9292ca6a562f2b6a3b909799ac638023f23c98", // 89
+        "ee378f14584410db85808204004a0cc45b494fce", // 90
+        "d6be4aebe278f0668860f3187d1e1af82d801fae", // 91
+        "75226e41cac1587b7599169b313aba655e6e3a75", // 92
+        "c7cc1a90ae816216c756f7f07872246ca772c328", // 93
+        "520e76bdd685f9108219c223aee853c54a714df6", // 94
+        "c1f58f2286c1ccc78ecb8eb140d1c84596250ade", // 95
+        "df53e1cd5f0a880d363102a05ae9eaf46f76ac11", // 96
+        "35d564e9d6bc0486ce5ab6bfdf59272ab34c8895", // 97
+        "52a69d9296d801a8533ea74efcd3b599c13fe354", // 98
+        "5491e07e4f3a2e4fdfc7cb85575ec07e58ce4f35", // 99
+        "bac230069955c595876c43510948174931e9f8f0", // 100
+        "c1065d93e3df6f35115b0c359d686a9c8a78b306", // 101
+        "0bee2e91a266de0d094b64aeb42770c689c8a36f", // 102
+        "8ba1b4dce76a9d7ae653337d49871d181dec365b", // 103
+        "dcb92a21093cc63f07cdadc76e5bba6429921f63", // 104
+        "233b930d42da6de216c3bd2d7703d01e1eba2696", // 105
+        "ff9fb27d115a8da1fbbbe1ce4888fb689b505f84", // 106
+        "e2233b3e7a6204c7b885f0a6711fc1d526a1f98c", // 107
+        "3709d23b8c610d81d8fa443e2749538c34fa9d1d", // 108
+        "3fe20a6d6244deb8cea99d7bb9d7e5ca75dc48f4", // 109
+        "9657cb48948ab50b0f76c6b05432de4afab6f5e7", // 110
+        "c725e8b4d36b1f1e5d4edad428a2f711deb076cf", // 111
+        "04b68c18ca1273a1b23064e31a2ca4f9802ee0d8", // 112
+        "58a3061187e25ee261b127e882846b7d2f5127a8", // 113
+        "ca80221877a81bb492fa65ce64748ed1e40503eb", // 114
+        "46312363acec4c3d49a28c7d96a5ea3786d915c6", // 115
+        "3b6fbf733c1d6b2718048f75aa9277946d7e30d5", // 116
+        "a86fb457322da964c316d6304eec608048a2704a", // 117
+        "6463e6be7cb05c18ae8922ed992a89441c166f16", // 118
+        "93aba73b21cd3d39ae46ed12d5d9ac5a0615367b", // 119
+        "6218c8a2316b7437ca9cb058efceb0c9ec9406f6", // 120
+        "80754f5a391c3d26772341d6ebf4f4cda6edc4e8", // 121
+        "500f54e121bbe524d35b872179499a35926a7f04", // 122
+        "058b805487dfbe03c7433dfba231a25967b6d061", // 123
+        "1bd9da7dc06f346b0182a91e5cf9d0aa606ac23a", // 124
+        "5796eadd897e9a9b4816eae6dc6189bd45c6c54e", // 125
+        "9ddde116d19760410490877516b2112ddd03dd36", // 126
+        "083737444a9c325d3705ac4e4d5b89ba9b75457d", // 127
+        "53a4a030974c113bf50b3ceeb919b5935933c8af", // 128
+        "7de45abc90d68dff573095508270d77d3ae88457", // 129
+        "34dd4ee946b35ba02361d8a87a381a097922388b", // 130
+        "55f6bddb2cd7db1e4af276378fda45f56c2b983a", // 131
+        "5af5f6a5397d2d6f9c3c81b5e98ddb594bcc3331", // 132
+        "a5b9c0cb57aa6bd53613c4f29dc8afabdb2954b1", // 133
+        "2fc46c0390fe4b7a3fcf39cb896fe79e811f2703", // 134
+        "6d6a54a8ae27a1d4ff9135240dfc8ccb57dddec8", // 135
+        "6fce2ad3680851cd1abd7768b9fbbd3605f35ea9", // 136
+        "d2f086b706769388200f897ce7bfb71b7aa3cc61", // 137
+        "af784666e209025aaed0548b88bacd0838f64cbc", // 138
+        "877953f82f23131e40df43b370ee6c11a3f08525", // 139
+        "055426d68cc4cdbce2ae8f925be4e28f8df226c0", // 140
+        "17fab4d3ad626f4419c2cebb4f0653e69be13f0e", // 141
+        "95fc2b13c155993110edc1004f50c5515757258b", // 142
+        "7a004bc78b9b9f49550c16ac51ad87acdc4bbe6c", // 143
+        "feda154becd50d1d2ee3dbca076446e6e8bae2f9", // 144
+        "67cf5ba130bf0aa8a2200cb63e869353ac7b3ab5", // 145
+        "794adf7849fdce1842e2ccb7dfaa9dee71592232", // 146
+        "4d7b9306774ce518a2234a531e3e153463b2a8d9", // 147
+        "a6287887ca0abb59d1c5f6718ec1e71919ecff09", // 148
+        "d89d64d5793b95db9dc688b0bea76047b5913dbb", // 149
+        "7e5f77d141c58c6eab9bf34812d8fed3a5fc3c71", // 150
+        "766c2be42804ef7a5316070ef06e3c931322c409", // 151
+        "3d06e862e9a1f0a7eb29467e6ec2cc74ccd2e0e9", // 152
+        "bb477a85553ba0130ae4b4d358f277f00a0aea34", // 153
+        "9cd4ac6f5bc6b8f0f5f797d248f4da4ff90c8d47", // 154
+        "4ec70381397ac208c3949cd05da77266226942e9", // 155
+        "ec53a75584df879a95f7c2d50d74db175953c354", // 156
+        "b9e5954f43d21acc8a40218f49facfb76561f657", // 157
+        "61593108f8cecf873911eac66939b14fbd512adf", // 158
+        "92cc14172051f524b481f5862369bdc15dad682d", // 159
+        "48c48fc19a49478aeaa3c7b40f9d3c64cbaad3a5", // 160
+        "22a8fe2053a8eec506e58dc446082b409aa1bdd5", // 161
+        "dccc3affb58d34b1b52bb05a30d855a45cef77d4", // 162
+        "03656bf6ee3f703d3e3c14045a6ff595785db5bf", // 163
+        "ce537ce1c7ca2a220360222a2dd18e04ebb33f60", // 164
+        "74415ddcc4fb87c079e9da6801578869f51f31c8", // 165
+        "409f028ae57f43a0da159db96fa2e1d02276fbd5", // 166
+        "673d80aa0bbe5f36c451612791b1d6effd5c6db0", // 167
+        "75004d424b2c9ca3e564f5880d92f590580c9da0", // 168
+        "f9a2db25af8df0a15cab19f77103920889716ee6", // 169
+        "cb7c98e8bf86bd9056025fc2e5edb9a60a4c5f5b", // 170
+        "2d5d5c8d49ae85bb3db6222a92ef01505db65d54", // 171
+        "596c02f045e34874b43245b6ac30de109558b20e", // 172
+        "a766503fcd6b23f7473d8e5d4135a43768461f65", // 173
+        "507b585dfbed54b84e915d5588ee60d7cf592cff", // 174
+        "a945365d3ba06f20c99f1a2b9a9b6b4b5da6da04", // 175
+        "e10b6655f9fe46b4f2b5e72261bd31a40cbdeed5", // 176
+        "43fefc275c28b57d366aa64695d7293de3788891", // 177
+        "c1c552e000046bc82b184ba517f12634bb15bcd4", // 178
+        "9ec990bde52e3983d2377b2e247bd595d26f4b0a", // 179
+        "49d075db9aa95b6a07d63716422d3a6220f3996e", // 180
+        "a8b582b5af56dfd402eced3fbc6fad6ed8c7b06f", // 181
+        "75817e4073722ea1e4e997b2738a0338be789ccf", // 182
+        "213297b8f0daa7e750b026ac94be29dd59c4627b", // 183
+        "4bf3ebd979046f3d0a69c6269ea37ad9a703b0a0", // 184
+        "0c841cd2113445a81c105d8a6cc38b468639297c", // 185
+        "0c17056aaf804efb8d9b2683ce1642d2b23c4a11", // 186
+        "a895414a3ed0c09d8495293bb13cea1bd46ea257", // 187
+        "f1d65049c677c816ff0475a73e2ca6c66819e889", // 188
+        "e2eaba88d5b38c48e7548de96291e0ef253896e7", // 189
+        "a3793868759e03b48554c92d63dbd036c52a7c8b", // 190
+        "566605f580a5d3d35e8d4083fa77822659038651", // 191
+        "295313b675ae78e080aafcdc02b7738f7ea9391b", // 192
+        "b8dda6ad261ce5c09ff6cb286d96d6006db4e146", // 193
+        "75d5b7732b98fb3a66584c48d93a0399f88bd26c", // 194
+        "a35e05f77459aa6308a85e4a407f0b42fdee7aef", // 195
+        "d28abfc75eff716dac0ab298584edc071127149d", // 196
+        "5f09bf696c36f13a51e6dab7c09c411be368174e", // 197
+        "c50baa9824b1adff0bcbac941f6bc11f85274d3c", // 198
+        "1e1fb1f0b0bad6020c7d2e0b4c981f6c742e0397", // 199
+        "0470c93a85761f51d21314f8f4f2c18dace7b2a0", // 200
+        "d0938d8bd4b71a92d2bac4e67aed68e06ca3d2cc", // 201
+        "134c6d3e528dad1c929cab275f74cef2f624fb4a", // 202
+        "db57c80e34b7bf3092268818904a3be1aa888275", // 203
+        "38944b97226942b282a782a75dd55c99da339cc2", // 204
+        "da82edb8bed4763efe181c4131b647833cddc4bc", // 205
+        "300e6cd6b30d8304b651cf4634dfe6aaad0512bb", // 206
+        "e7832659f28083809b1614db74d2047ee9bb40d3", // 207
+        "3c77bd7fb0409b06eda82509077bc86c25e701be", // 208
+        "42765f0b8bb0a411c70936dabc4f9375201bcd49", // 209
+        "f5534c8fda3ad49295f9c6ad9a793ee8f4ee9f4e", // 210
+        "838abb5efd3aaf475b01cf60a4d286f444ed2951", // 211
+        "d99869e2684d3bb4af43f0fa4ac68fcce312cf4d", // 212
+        "496eeb69eefaa83304c368429335c8c055c0c209", // 213
+        "28f86af3518e70ba60c6999d4efb50ded92b383a", // 214
+        "cf201081a8f861dbd826696f41faffd4ec70ee81", // 215
+        "94abe9d601df4365e364e8ee0f543e4c6239f990", // 216
+        "15d80d79f31e1cb487b6a82d2c5d5f319fbd4a72", // 217
+        "6b71993c395cd56a1d39bb7f6ac0d0b9959a9ce2", // 218
+        "dcd68f8c1e7d8e93423fb86396527e6e93802fd5", // 219
+        "6dd13a5fcaea592733fbf60523431e8156514761", // 220
+        "eec3007047c06ab8b4cfa46c807eb7b7d29444d9", // 221
+        "40105396182407a60f4995b3fcb2901b8f96cddb", // 222
+        "d1a285f9119e55ee7bccf998502c464466ec1dc7", // 223
+        "f3de41f45839d43c3fcb0a6c1c81dca0dc80d15c", // 224
+        "936823c1f6bc936355db6659c858ac6c3421d03e", // 225
+        "54c9ea744173fadba353e5557394ee0f6d5507f3", // 226
+        "ad63648054a9cd0a1880b69d8a4757d694df08cf", // 227
+        "10dbe8f1ca6f37deee2c3e0840f72faefd5468fd", // 228
+        "c3ffc0dd1dfbd16686200db142ed1cb8905cd97f", // 229
+        "717cf714134d13ee8e20a2a6f0c8a732578f1ded", // 230
+        "2acc489a00d03c7c5700d81afa67d0bc73cc254b", // 231
+        "8753b091d75da99826d225f66ee7a31c9098fcd0", // 232
+        "0c15bbb13e8341f71b5b93ade74e4d2bc247cc19", // 233
+        "34dd903488a631a274e18f00bbb088f4e6284ebd", // 234
+        "9674927e4d4507aed2818e6737c356528fd63e25", // 235
+        "56526efa3cefef70f5f7866af5321218688f2b8c", // 236
+        "24cf8c0e1a505d1e206a0b08e992904baf2a6969", // 237
+        "f1a37f33a343ef750813560874d7b4ef39da727c", // 238
+        "abab2ba1f8567cad3169d0718a3fff85731bc718", // 239
+        "5a827b63a70c42e310e49e182898e928a222db7e", // 240
+        "eebbbe782fa0839a8bc0a7e379c41b9c94402c10", // 241
+        "2ae359a143ceaa3b4143c0c6fb1b00fc571ef172", // 242
+        "d689907f4474e6b5e4093902a485dd87e4a4e3b0", // 243
+        "97e6bb62b1fb5a78a9c35e0f68f790f53a3bb961", // 244
+        "c2301fc1ec05ca673dc5ccae1ed9186482bae22b", // 245
+        "76b1f075f8008796dd0f3b9def55af550b3e85f9", // 246
+        "72f3d698fdb9a91b0669ef7f493eb36909f78e92", // 247
+        "dd597002f64d34e0efe336c8cdb271da60ae3731", // 248
+        "33b6583397c9220a13a156307c8096a06616206b", // 249
+        "9a8be725e4f7611118a3511b7ba0cfdb7b8955d3", // 250
+        "1d75709e8a335b0695f8016fb5720898089b5ec9", // 251
+        "8a7bbe5f1480adea4b46c27802d0e5153f73649b", // 252
+        "0f0bab98178cf65b8c0c5529c1cb3d8edd9d3f54", // 253
+        "1f7df33d085e7d05dda941bc91304f85591f4655", // 254
+        "8d1e65b0f3fd92c87fe5f0e9403868c70e71896c", // 255
+        "7d8d1c9dea0d76fdce224a335ca9fc95f441d248", // 256
+        "28c3b680da3329668c52ecfda97d3a2ea93ed490", // 257
+        "cb548e64e81e2debf445370b438859d3a09b19a5", // 258
+        "5dc60403832a30a7fc54e8d4a039a6f548014934", // 259
+        "2101115fecab2a50cc7d5df347eaf0f3d9d31768", // 260
+        "b4bdc71c7846633005047fc2acd77ba6919de31b", // 261
+        "d8509ed3ed09984f9d0e1538bbe97f9526af7fc9", // 262
+        "f54fd932ea3f44bb5ef8348ccf92e7abc938dd66", // 263
+        "06ba1de9bc6de809039053b89246bcd43a45d027", // 264
+        "34df202eabaed94942d3810eb4c8db2d2c23c2ad", // 265
+        "dac0bfbae7bc69266d4eadc0f2f975b41653a57d", // 266
+        "e0443d7de530105427119808b177bb3da01716e1", // 267
+        "4538e65937d7094c23874b16a92a90e1ae70f31b", // 268
+        "056c4884715522f8016278090933ffec5b6e2088", // 269
+        "01bb8e4eeb6e95c71b585d36c6de4b6624fe6f28", // 270
+        "e87ab4c3b243d2b7b233970d11f142b986b57747", // 271
+        "853455f0f20cf048fc2e5982cf10bcf338e0737d", // 272
+        "52f8a76a2e55fbda87ffa3c94d65d77e51c4bd59", // 273
+        "4805ac7cc2cc1ed8450caf62ace08b4da0a1f87a", // 274
+        "67fdc32c00ca8a3f4996d3a5d76ed41ce4d5be60", // 275
+        "2649f3f0f4eb542dc13841d11a3cf0c841bb7e70", // 276
+        "978a85e25710edc4272854a698f710f34983ded2", // 277
+        "6d313a3d54beb5a97affaab693fa26dfaa00e7f1", // 278
+        "1c66966a35eb27fe596093c503de3b7f34b4f897", // 279
+        "08fa468bb2573af2221ae7f434dc4d799c93e38f", // 280
+        "d4e1a16cb44fb716844ee50db8ba5edfccb4712d", // 281
+        "454362f3c47ec532528ae2eb508aeea8b11a967a", // 282
+        "f9cd7935a5a73bc291153badc29ac36b7e734d18", // 283
+        "4f8fdc381160902c9cdbf5a362ee6a6973448455", // 284
+        "ed1967fda9c20bd77a21e3504c346516c0aa8a63", // 285
+        "55510214bc8e2157d341521074211fb568e0e8e9", // 286
+        "687d60c00d967e0d176f94983120624777227caa", // 287
+        "f32b441fa851752eda2395be5a6625cb333825bd", // 288
+        "8679ace4d090da4c803429f07cf6f461ad71776d", // 289
+        "56be966b07759d5b47a457673e40355ce255e57c", // 290
+        "51a57f2cd6658546c13d926b607a6c6dbf9c238f", // 291
+        "7a7727b9cbdc1ef09d3034db5352820a0b15b7c5", // 292
+        "7098ba526f6236f43781f01d8a7aea7339eb54c9", // 293
+        "6ec558c05ebe1acaf4cfb054497d04ba214e2b5f", // 294
+        "5e9c5ae5e8cda732f3e4331ccac485deb92d04f3", // 295
+        "a9801d7fe5e04f6bdcf7ddc95211cabd865980a2", // 296
+        "15eb6efcd7aeb08899a3cdfaa9ce78d611cbf11c", // 297
+        "c5675457f97f4b6afc8d2722b9f352d21234feb9", // 298
+        "becd14e9b46b215e688202de4143e281f1a9010c", // 299
+        "9452f7f637868c56189b77c0f4c00c7f05d10d18", // 300
+        "ba7afb14808374853500ba5728a2c77f487da302", // 301
+        "a363813ae74d7b24acfd3a1c8b4a7437e456d916", // 302
+        "c1c6fb0a6f01e9ceaac2f6bf01f1e52ad8175c81", // 303
+        "d89a241fd8940ffe39c5970a188347f2e5915166", // 304
+        "f7732389262bc634611e515a851d4593d41a5dc3", // 305
+        "82d30a5001c66983a24cb9850f49bffa26082abb", // 306
+        "f0a3343b585e52c357915710a08129d736996770", // 307
+        "c05f1a52266d9ece399ad30ec24a7663748f7aed", // 308
+        "92acb4c15fc07705c63e2904a3feb5646ce13206", // 309
+        "46129009efb55484649e3dc13a0121da4aa14d0a", // 310
+        "347c8c4560831982131990116eaba2fddd2c35bc", // 311
+        "76e484ca8c25f093faf6ae41b8a78f9af2e22bb7", // 312
+        "8cba9b85e82f5b8a53e8c7cca9ffe27e91aba21f", // 313
+        "0b3757e1cc32741cb2aef78b9079adf87df1332e", // 314
+        "3ef1d119bc332ca06278fc2dca374840583cf6df", // 315
+        "6c07323b43efade61910965ab590cbb45c264136", // 316
+        "d2488c8f195f8cb3456b6734435f621106d65775", // 317
+        "adcc285a3b8e5bc955e9f625a7f62f6cd5d862fc", // 318
+        "7474ca558dd2dd1fbf2e37da539136d8a8bd0675", // 319
+        "982b51cba191bb92731ecb1f89d9604a97739597", // 320
+        "23b2c7bdfe94fefbfce51f2704c159e528149c69", // 321
+        "679682efca26015c200c035ccb820a974f44db14", // 322
+        "930ef2aeed396fab92e00ae9ffe50e8566ceedd1", // 323
+        "b4b2a7574207c25235d1878632ab5740cb6d214d", // 324
+        "09c461bb4872409cf982d978d1a92e3a225ac80c", // 325
+        "8e22df76d28b6ffbccfe3d8e01be51fcc4df53f2", // 326
+        "1a5c556a1eea9706edc1a62196ed3d8d300852b4", // 327
+        "d205b0f430dbe6a7e050f725f0b2f68b0192eba3", // 328
+        "4ec7ff3e92079a7f772cdf82e73bd7b1321f1348", // 329
+        "3cb512d319e83a474f9bb4f06ca46ab01ef9d115", // 330
+    ],
+    [
+        // string 5
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "e9d71f5ee7c92d6dc9e92ffdad17b8bd49418f98", // 1
+        "5b49d2e26e09577a3f86029b10e4fc6a7bf2c85d", // 2
+        "01f6f9bfc3ed342be0674bae9a801de04402dc42", // 3
+        "30d6bf4d7be6b68322cb162d4e00200183ddc95a", // 4
+        "5a7158ff3102268c499343c6e877d5ec29603312", // 5
+        "be299366fe0d3628eb7691a8004f9e73dc566783", // 6
+        "95973fcbf5cb3136b5da023663ec74f5d69d2c22", // 7
+        "12218f7644bc8ca2d49c65e7ee368ffd64333c1b", // 8
+        "ed4d380f2beb5d389cded3e5273d1726cca496a4", // 9
+        "22ba8c34a223dea7b05a9977f1c5ad9274cef7e0", // 10
+        "2fbe31d50cfa04c6ee95b59dbd5bf886ef486dc2", // 11
+        "2a7497a216aaeedb2746ada7403341f011ad6968", // 12
+        "89d2d89c568448ac84fb9edb165913b4a12b5a3b", // 13
+        "8fa48796e2b913a90a018c1e9afb60773bece66e", // 14
+        "092a2b0e402f8b7a85186dd553d0b8ba2f856e11", // 15
+        "e12dbad1e822709e951bc3e581f36334dc286544", // 16
+        "dca40f6c0df8440b0ebfd5719c0a7030823921aa", // 17
+        "936dd9c593ebded45f11300be21ac7bd1fc7c5bf", // 18
+        "4c568cbde6f815ac76e6646c2f8a0fa1377b0dfd", // 19
+        "270eaa308bc1b0d5d6ed56332ec78b340880c9a5", // 20
+        "f22c77b712f5878383c0ded668470f321cea1375", // 21
+        "802f99ba54b36c48855b28e21f4d395f8bb67ea6", // 22
+        "4f2211cad9916a15472f7fa516fd7ad7fb69ff7a", // 23
+        "f4681ae60925edb9333cf164231e7f219daa1c9a", // 24
+        "7e0abec0e967cc3f1f8a3c82fa95e810fc3f3251", // 25
+        "1224d94aeb8b5d68bafe617d0a17c13874c9175a", // 26
+        "cffab8522bc585e0b2d4e18f81c561a448498755", // 27
+        "8b4c0577b7fb5a9eb056c9468422852cde6f7715", // 28
+        "fac5256d8b3bd5eff55be56e33ea55787368257c", // 29
+        "b1450b61c236b704ec0a1079213d092da581af97", // 30
+        "38c31a2f5930db43e2af5a8ae19ff9df80b22323", // 31
+        "4bdb9c2c2d00bc3ae2c8837a78bd2428dcf4aacc", // 32
+        "904804647ee3fd88b0bb5c71a4af6e0470fed8b2", // 33
+        "528b6e9d1f130547a272f0b483f8b73e47f3875b", // 34
+        "e2d683e3130d5bebc10d9fd69854664bfd5f3b72", // 35
+        "9eb3d7e1350ead0fc08ddbd84fb942193fab2af5", // 36
+        "23859c730ed9dec7310d0688cf4e42632248794e", // 37
+        "b6f76c935c2cfe3e41476597ec2b6a697e05c4aa", // 38
+        "a3a05ed522d7f6a0c9914c7149def2c34482ead3", // 39
+        "edeb8f2559cff1020a2f43166f50ba2179d8d1c8", // 40
+        "b359b259b336bf643ca554996d6bbeccb5d8d434", // 41
+        "50d478d837ba668f4cbdca72a384be138dbd55f4", // 42
+        "5c5c7aa8e05341cea1d5b6270cc2236c5f08ea54", // 43
+        "d57a79c36a0d5b8f2757080f74a2973e12fd37c6", // 44
+        "4b90b768f7257527321fffc9494e494b5db2fac4", // 45
+        "3c0d9b43bbf8e19259c73d9700bbced7158e4d04", // 46
+        "9c3679185198b0db1ce6e4a2daa89ccedb6a7660", // 47
+        "f703f773c0ebaa2422f97ab36f23ab14b2b0e1d8", // 48
+        "6decb2383dc302151741f3c7dcd05ece2e1931ac", // 49
+        "a770386646ffb41b9d48bd0228caf57c8a4a9d30", // 50
+        "84afe31ee7770f2ff8b65a0ddb9e8ec504b1be3a", // 51
+        "b19176e9abae3dc5c9ee9a1d0fa8848ba7aa6f9c", // 52
+        "743a90734b366d327bf0bfe2df8beaf1cb9a5cca", // 53
+        "437f13facb4307acc8bef0910c5850158d382a1e", // 54
+        "c49aa1c61db15a73888ef3986cb8e3977d51f604", // 55
+        "266c99108b9fee6a3e9f8ebceb6fbff9a790e871", // 56
+        "330f569e10a6bd68d5e73e59db29d2030bb2484f", // 57
+        "960859107ecb1d896e72cedacd3f33b8c876b265", // 58
+        "ae50bc923314142a4dc4c7a82e4595df0d9e07b5", // 59
+        "07d0ef8d22a685841601524e8d86a9e6c7067083", // 60
+        "2d7422cc371bbd83700cbff716be041df35cec1e", // 61
+        "91f32b6e943c1546c931f3391961ca8be1e69ea2", // 62
+        "6f0badf0e38c67a426c8ea87c15351e62c5c1034", // 63
+        "04c979c4cd3c90010e9503464d019176e67ffb21", // 64
+        "77ee373d69dcc83050f6913a1edf4ee54d2d0584", // 65
+        "e839861dda6707bac08691e6fa6a18cf7930efff", // 66
+        "e73a2d20b3e940a72f1ee54f73648decfe3f1edf", // 67
+        "f4c282f132e51972b34fa8fd16d6cae89b18171c", // 68
+        "20d63f9db72d08028fe109a5e4f138eae5f7149b", // 69
+        "d503f70d1a1fceb6918522f822f06b1e44bf602e", // 70
+        "ae83d015c270ad9db487c8d664764c931bac8c42", // 71
+        "2b1f1dcf02b52900ad52b085095e88ffcbeb39cb", // 72
+        "30ac20b4164bf38e5f126fc5d373ecaeae13c011", // 73
+        "321e1ec43cf68d76133b02f18c41cc806db18499", // 74
+        "7846105343358dbe77ee54fa16504351df324221", // 75
+        "7fe36158823fcd483b8b970b99eb0cc1ef10f10c", // 76
+        "3d6d447bdd788fcdd16e2969d22ee8a721d36956", // 77
+        "ada44dd3351fb36924a3f2ff1e41e3bfca187e55", // 78
+        "9ef531078eb5f886d070aadfb402bc73915a3fab", // 79
+        "e928e12c5d2b790fedec93c472cea64d8c5a5b34", // 80
+        "6bd03575c4f1d6bfa86258fc3edc25fab4c8940a", // 81
+        "acecf121eac71a84458b2d589e1aff0cf64e4642", // 82
+        "214dc10d9821bc0d22eab19a4753824bdf91a54e", // 83
+        "d819c4c9f1397b847c2dbcffa30a7182a24c2868", // 84
+        "0e13700175f204d8401a1bf1a361e2b981ea576f", // 85
+        "202dee99abd75aa00253343051f76358ad063e04", // 86
+        "03a6725ac82b6184eb35b87a35e5b424bcc44564", // 87
+        "a26e2a65f6c38508728ecdbe11208622ef1a30e3", // 88
+        "1da626a77b0c95ab3bc7c45d0857e248c61cbfe1", // 89
+        "c027626b2157be1ae96587498a8bcae028f8ff61", // 90
+        "0873e85566340b3c2a9d080c4398c8b3c72234c2", // 91
+        "b7190dd95aa587301d56b9b2b8112f519a0f8f2e", // 92
+        "f3578d7b54e4ca6a2b9f062882efb86293b1e79f", // 93
+        "02cde9a39b04f738dd36ab7fff5d71187ddaa05f", // 94
+        "0045aefda23bea2d4ca91157cfdc96665b55c7b3", // 95
+        "8e7bcf90fe9cf3dfbb0425323e047afb5efc3c6c", // 96
+        "f40313ef49a99b5af358ab5caddb7840e43186c0", // 97
+        "b4b4abafd44cf032eadf375e2246a5c9ab7bda7c", // 98
+        "9ca383026b46dbe0b956201d600aa4eeef444edc", // 99
+        "c60ade669528e32f497abedc1bc90336ea544780", // 100
+        "98bad556befedb014b4562ef78eb990763753e77", // 101
+        "8d6ecece4136da9e6b1d912dd9f4b814cf918c80", // 102
+        "7ace67491ba2f294a1c1ac6440d94d57fe0afea4", // 103
+        "a2515a475839b55a9436bbd88d3f6a34929d0043", // 104
+        "0db15a331db16035b8a1099b13bd39fcec4b2886", // 105
+        "cd7839133109f8a55121f871d2d6af134255efe7", // 106
+        "351f6c68b1192be5d16ee2f0412ed412f88a8763", // 107
+        "f1e2f02432a58188418fb0cd64f800920990a7e2", // 108
+        "6091a3ac0a1f6275d16a068128c513dee389e6f9", // 109
+        "51ee3a928ca7c948caf9e3d357db8c750a8c0aff", // 110
+        "d60a4ba00712ab8a7d6a8613c4c1b8052b9d68fe", // 111
+        "d1f2b39d2d70b341c8ac008d5da306abe1bdc05c", // 112
+        "19feb8602f632498d15dd52030ded62ff5449f8c", // 113
+        "f072994ae0005d1f4cef52538d71e6414cf02454", // 114
+        "9cca526a8ff3195d9e9bc914e9933337ba2954bc", // 115
+        "3f88a65b6205afaeb6c1307daef5008acc26aa52", // 116
+        "3c936df0b58fd9d054a6daa26fc8655109b1e4fa", // 117
+        "5402dcf816c113298385385908e48b9a8e4fd0ff", // 118
+        "e98c4d9db35ad15addf4f937a7eecf01f2cee163", // 119
+        "d180dc1d50326149021040aab78425a8e0de65a6", // 120
+        "21784d51aebf3047f1ff01ef0369f332b2660bf0", // 121
+        "f406fbc1a47b3a570a4355929e275a41d230bf78", // 122
+        "d01e4d3c4f53dcc9179d1f938e956a754526287e", // 123
+        "c011dcb905c85fdb8dc9826d8d32abce1008ba62", // 124
+        "752062fbba8f3c9512b729b8dcad71ab57fba5e8", // 125
+        "eb33cc46088d09e62ac45ff861ec84cfe9ffa614", // 126
+        "53132f406035004bd41a3e8cec5a42881facb01d", // 127
+        "5a94a5933747d1825cb2f3ac0c129765bc1abc47", // 128
+        "a94271517cc11dfcf534df388f51c4fa82b2e90e", // 129
+        "4d3022a6f23928480f27a08f03e1d808ad9f4503", // 130
+        "3d536a7ff7742a3bd33bbc92a00857be9798c935", // 131
+        "1a027e5600ec741c14d432fbe45f91025518aff4", // 132
+        "48f58ce6778a786f88cc98720174982a2d8ec342", // 133
+        "073f954bdf4e60d0eaa6729fe931ff902157a05e", // 134
+        "1c99e6a5b3ce381a2fab6e05fe5810bff0b1f307", // 135
+        "235eb896fc5478fbf58fc2a9e6e5754b84798550", // 136
+        "1d8a52e970c539f53902545e1822ece13aa8c7ca", // 137
+        "fa4788d68cf94715f20c2c00d17cf7086758c163", // 138
+        "7b18c0605cb122ddd0414176ec5311ba1904fbd5", // 139
+        "49e2653df179bf3deff1968353ee39b43f6bc2dc", // 140
+        "ac240fc4d715c8e94b4ca890aaca5a9013ebe319", // 141
+        "eeeecac88b0723c538947db7c5b27a85da10a304", // 142
+        "04fc0c1a042cc9c54ca9d59d501c9b04de98b116", // 143
+        "240f80e7c2e72ee31bd57ba821d3aad647b82649", // 144
+        "8db362d02866ab618a863d0af4d7cc9fbe39cf52", // 145
+        "0d04459cfa16fef7312126e8d924b7682c43b2fa", // 146
+        "e506d897935c0936785c2b99824aef05c3ed6127", // 147
+        "d8883b84cbe56656159f76b66a9e182fc5dbdb1c", // 148
+        "fb502983267608dd6fc25cede60e8e6753d2e4fb", // 149
+        "51ad31c5233c6197acbbe139f28d445f89fa0d1f", // 150
+        "e1a919508a4a76853370a4046871e42de32a994a", // 151
+        "74ad2be61f4d71cae8cd62e9a23816494660aa8f", // 152
+        "6def7989c560056557a38c41e1eae2dd08ab8d29", // 153
+        "ada86a61676ed5de7b9f1675634280e112da8371", // 154
+        "c3a1c2dde0852dd990f609f953aec12c6e33c808", // 155
+        "1b602c7496c19dab1f65eb2955df4b8538cb4249", // 156
+        "a20860bf1741a992b942e427ba3b9df846293643", // 157
+        "51e6cf1df0814c934d7f7333526b00dfdfe1e552", // 158
+        "9293adb46fbacf8fc40dcc5c4be269f2894dc232", // 159
+        "79a1740d6e4c50e1d236022156d920e0b04b16b7", // 160
+        "39f6449092c6060691904e661ef281fb4318e0f4", // 161
+        "85a1f0e14b298828565c4d1655e189122220cdde", // 162
+        "44e986dfe0bae7ce13c233e66cfb863415eb5f6c", // 163
+        "c9f88a352e4378660baed530ac9196ded1376abe", // 164
+        "f3155bc38f01ece06d29d3b299e49733a8da2c02", // 165
+        "b6aac9886c847eb2145135682a794a7ab09a3fa6", // 166
+        "bb6584288ff6d8c6a0de53e013ff3464b6db1ae7", // 167
+        "8b03f857d3f82e52ed0607e222f3dc2233a79985", // 168
+        "911b354807c0ffaf3f13478dbe55b4f6c4645ece", // 169
+        "eecc84ebdb5824309f7b2118dbd1c7f0cd8dc6c7", // 170
+        "59867e0e5a5a3ce3d4dd67d46ed6b88fe9cd63ad", // 171
+        "b2e5444833cf7c3773bba0d73222cd7fa5926198", // 172
+        "1c2c80c4aef486a3d9af5cc0ed6a1ee4f990c536", // 173
+        "8b92c1b47789c87984e2d140a9c811c2c3128a07", // 174
+        "524b4e2a0b170dcb5420540964ae29bca9b812e5", // 175
+        "3fa849d9104d2ca7b8a7ea998c0f042d3fbde81f", // 176
+        "f81e80a7be18e4fe9b28d4e7aa3686bbc3f2a536", // 177
+        "fb78987df7cea05027dc9ca8825a5d31d5b54dca", // 178
+        "d8ce87642371710f1a6813e829c74ef9ba1151c4", // 179
+        "2db03564a1c5812cabef758c2a5aec43d93f0564", // 180
+        "2b35fbfdd3cb65731ba002ce1102ec801c5a9d6b", // 181
+        "2d590d318ddfb9e36ec7675abd8c4992a0933cb1", // 182
+        "49017db2006521dd42ea40ec8c8d71a93a779267", // 183
+        "b49e2d072c0ea8bdcd71733f7d4dcbe92a446e93", // 184
+        "25a68e0b8766dc80effb0d35f2ec662a83687bfd", // 185
+        "3984cf03debd660e2e5e2bbeb494f5a9f5162017", // 186
+        "22d920ea027d0524fba7ea49289879c571b8d58d", // 187
+        "1478d6c0d8d240158794091e8d5477915c3c654e", // 188
+        "27c44ab05b8654fca15556e96011c72b844c6351", // 189
+        "84566259f68f2d24d2ea2e9bb1f1248637593ff5", // 190
+        "ff059625937bdd4ab268a640afb38cf5842ff779", // 191
+        "e41e74bab93964097738c0bc62b39dee99b11510", // 192
+        "79cd67a2ac4bc54ffd636d43d95ea0b4a324d690", // 193
+        "9e7ccc0e893c742c9fa13ea0927d9c41ef51ffac", // 194
+        "48b9e01286f7723e3aaa2158f6101fca16f5a376", // 195
+        "38bc842fd733a40da8850facaf6f53f83b513e8c", // 196
+        "3c6604f41484549460f9ae8e9903fa7d7305f06a", // 197
+        "5b9a0173f8b783760d138ca19602d2f58e169835", // 198
+        "9c3d4f7d85cec12c1063ee3c40414d77916b328e", // 199
+        "41e99404192fdb51f23d552be150c488b1f7bca4", // 200
+        "372aaa7aa47b4f531da1838e0c2a1b8ddad3b12e", // 201
+        "1bb2cc29a92f0d468305186856eb85ee37f81f34", // 202
+        "12fb9c5f2f3a7f131ffd70c745213a475dc7e10d", // 203
+        "2bf8d00ec93aaf6c8fc7c06938c4926cf87e3809", // 204
+        "7056217f3a6f89371578997e8b53e7bc59116d1e", // 205
+        "398123487de4b90dd039d6585bd42c7d22447078", // 206
+        "d5596af765f64793d0204141e25adf25fa6891d8", // 207
+        "68a067673ee2756f2559b9f0a26f86d86891a46f", // 208
+        "4294b632e1cbf7721a74de2d1d9b52b97e3752ba", // 209
+        "07a8bb5986f3050c75e7777c121a85034fd76142", // 210
+        "a4318d6059879fbe1faffe3a0c3bbf77947eced5", // 211
+        "83e966aa916df0475f1e114b4d19b2d48fa9b846", // 212
+        "71cd9f44ae8df5b2ed50a1fd29ca25c16fad330d", // 213
+        "f0d0b2f94af72d6803f999418cd2fc5ae4e82f5f", // 214
+        "1dc12a4ab7fe01cdb6cab2425786f3391d45ed1e", // 215
+        "c0e3c9b13e813e6409a00563727c0959c59ea36e", // 216
+        "8cfd916a4b0f16d3faf6702a09e20ccbdc680e2a", // 217
+        "ecd52b9053be855453c8b25fc240a3f22b84774b", // 218
+        "f3f3e39516e8451ee80e95c67a15dcdd88be1ce7", // 219
+        "3068c0b5dd50ad2bef39f0b92a399ee8364aa144", // 220
+        "a5f2b638bb0682e623f24fdab7a6e97d7a2246f0", // 221
+        "0d8895b66cf2c6bcc1dc26aa590ea120c658a8d9", // 222
+        "20b99cc23bd0db3819174b86bb22cb335b28fa56", // 223
+        "906bae190660427a26bcd77e7d58e66c204583b5", // 224
+        "93628c6f21ec31f663c296109c2ca372a22dc865", // 225
+        "9f538de896ba84adf3c9bf9bcc0398694d81e3b0", // 226
+        "af14ec53444f2842fef984b949832118ebde9910", // 227
+        "fe6dbf3fff3deb384b330ddfece3e7313c9f80ab", // 228
+        "8852b72af156253712bfa13661a47da311d3b9eb", // 229
+        "1b2dc063fa9ed06270c941d9d8ffba10819ff2bf", // 230
+        "cbec81d7ae04446885d7d9c30e1d1839e2461801", // 231
+        "4511269cb145a32726a9a4c7de944dc9699e5c65", // 232
+        "53ecb2850edf2e50a6ed22426eaa6e8fde08d277", // 233
+        "4adf92bf967d837593ecf01ef961c5b72abda7c9", // 234
+        "96057fc82dc06dbf490f8d77aaa089351bb1c9b0", // 235
+        "496a5adc2b18b20d507b6ca9077580536f1ec44c", // 236
+        "064553cfae129b8052c0d9c35102cf6078247a3f", // 237
+        "144b77bae88b53862fea588cd1a7a01e28a8d3c0", // 238
+        "fd8e342c1b7388dadec32b7daaf4ebd381bbfc14", // 239
+        "ee019c191c3a6df7a22f30b74c68abaa7415ec6a", // 240
+        "3c541a09117e41cb897b10a1a166445712a74c03", // 241
+        "edd91065f3908fbb574a03aefdda9a2861793a32", // 242
+        "408dfa6e787164db6130098b0c1f86f90a80e0b7", // 243
+        "9b5e7f981b3b4b7c0aeafbd135041d3092493181", // 244
+        "8365672c85c132e54b3337e3212e999490499f58", // 245
+        "dd5feca098669c90c694c1b2aa9b629e8d575026", // 246
+        "137946817ca1dc747f3853b9f3b637175fbd9a1a", // 247
+        "db6ea36754f0e3f36cb666a2bf94e0c3dbff516a", // 248
+        "599ae0581a82816d4ae8d255ffce5fd32d0bcb3d", // 249
+        "4096ea71d300741ae9da1ccb017f3b86fd5932fc", // 250
+        "1c4e16490e224d6bff5b6c06c44e386978af0c3e", // 251
+        "f30c3f352a5028f9918b90027db0cf8cf331cfab", // 252
+        "d16201008859edf80f1a212edb733addb3b9450f", // 253
+        "b9badff2cc59f7618818c6ca0100b9a97cb8b3d2", // 254
+        "4dfd9c045598b5210ff395dcddde645ae032a211", // 255
+        "cd84ef68ad60afbd9de3460dfa303096cd2fe9a6", // 256
+        "104996b8700e0ac276032e6164f3e5d812a7037e", // 257
+        "5307b563102237121a6082a09e82e30a62fecd4d", // 258
+        "9854537c6ffda354422ef9defe09c3d320a1735c", // 259
+        "4d2608ee682f66c4db2e8e04979d3af49a758226", // 260
+        "6189a3fba34e39ee8e9d14ffc43495c5fcc80bc9", // 261
+        "e150e674e1aed5eae89866108f11639be0c9e728", // 262
+        "8ca32024989cc6f4a0d5dacde22a0baad5d76eb3", // 263
+        "3cebf3d8a43f1e3d82dbf1ac80b07ac637fe9a88", // 264
+        "5934a5ae11678c7057dea8b59d7d48a3df76929b", // 265
+        "8421514529f91dd88e4de4dff9ee0a391fccb584", // 266
+        "cb3670b9643a9bcfc254cd906a9af64a5a3d895b", // 267
+        "07ec45a7797d3e706c0a619a151f1a56a92e515b", // 268
+        "4f689ae348669ca3cd5ef72df61ecbbdb94eacef", // 269
+        "3620db33d97a7b86beff87d93a883a67e2a11321", // 270
+        "67019ee1924cb326fa097eae1e73285cc84de958", // 271
+        "f21b7085708e20efead2067af37392854b61443b", // 272
+        "85eb0b850469b2c3ef3b627d369a3eb70dfff789", // 273
+        "d93a099c72731a739fb65c2d6550def31d6ed4e4", // 274
+        "09e55e0bf58d029b83111ce71bec75371713a276", // 275
+        "01103cd623d01c9cf7330106654afcab2e1e145c", // 276
+        "a9340b4ee5586ca54ab54e085abe2f8235af3ece", // 277
+        "fa304130970d45cfbdd0071a18c907e458e59584", // 278
+        "2e1c530d276ae0f1d657ea59a5cec7e64c90fedb", // 279
+        "6b4e5001c86aba4e1007ed55e10da5c6c65af251", // 280
+        "73b1a1106d78cdd91bbc29bbd84194ce57f71f92", // 281
+        "7635757e58328e50f54d71d720a1af89598266a9", // 282
+        "16094422897303058120596185a017712813cce7", // 283
+        "ff4b83e4d46fec8087dc6d08479852de503b01cf", // 284
+        "6fe1d8d52a375c41afe0bdd7f1c20dd0f0b85021", // 285
+        "58191dc0038e8535a5d6185f619ee7a3a401da22", // 286
+        "a51a96aed3c94410ae7ac046b59ad5abcb8113fc", // 287
+        "0a4436b9ff0da05e3ed87429589a1d52610f184f", // 288
+        "a7f3f630ef01c9faecb4f2e8970c58caad379fe7", // 289
+        "72c3543b4c455cee64e6b654081e208aababfd20", // 290
+        "7b6273949a4c417aa9b8def55582d6d2598f58dc", // 291
+        "5ce6bf7554213aa7d7fefbd43f4d2734676f9a34", // 292
+        "1e45628e9c0164d50a71845525ff20c1a50e2801", // 293
+        "499d8782b7d966270e867524b821add412afae06", // 294
+        "12c7e91aa2b2d802f8a8ca8afd8b41dacdd49983", // 295
+        "64c0a4857634f784a0308f0c8b0f724854f11f86", // 296
+        "69966be432f74a262c6723d03e5628670dd3a1fb", // 297
+        "f78a53b994cc21478d454d1bc7716fc6eb626df4", // 298
+        "8906ea7d2040bfb3c7e8750875e6085eba0efac7", // 299
+        "bc8b0076a15899cf5a5257261fa483aefdfafb06", // 300
+        "74c054bfc7fc4adcb4a10992d986fd16d72e7725", // 301
+        "0b9b3d4553eaa739f05e368650277353a8e845be", // 302
+        "919f43d25a004c4a549ed39c8932ccdf29dffdb3", // 303
+        "815d2100fc1d54cd5da7a10ed450a80521e0a8fa", // 304
+        "d42f00b5edbc18c0d7aecd6a2097036c9092c21a", // 305
+        "487c58ce4f24cdf66955ee1400f1e12cb1e293f0", // 306
+        "d5bd910812d402bb808eb318ce014037d77565c5", // 307
+        "234f74f6cca3bbbc1630836c2378aee0731237c8", // 308
+        "69b371494f92db2ac800db580787bfce5569bc61", // 309
+        "32cbeb102ffa12fc6339745bdaf34f1e9321c52a", // 310
+        "39a13e75c2230b61b284ddbff353d2a8cc540527", // 311
+        "54ee62713de81f407d0657c899ecb46aca3740cf", // 312
+        "e4fe9d01ce6e24bb467f6b153257176fc9ce63a9", // 313
+        "0a0a99ce3b7778c9a70e3472c8be34ab383d8023", // 314
+        "44f14adbf721bd4b4d016b66cb646e7dbfbe7313", // 315
+        "a966a99cb52e7a58e6e3e4ac0f47d24bfec42759", // 316
+        "7c5e80f3c604ca242383a3a9ec302f3f3c2d23e6", // 317
+        "e66bb43081c085afd0eb3d4d709991b15f1dffbf", // 318
+        "400d9087e5027ccb9e1e911a61620fb915ded137", // 319
+        "29c1621f4224ade598afb5233ffc6afe5151ca80", // 320
+        "f2b71a85db335f706e4da2012a167f948d392b28", // 321
+        "55f6bde1ba9d8356185c6a10b552ceb00665995f", // 322
+        "5174529045459b263aa3b4635a23c37f2ec74acc", // 323
+        "b72da0d97f8d986d04e98a7dfc5160e5443500ec", // 324
+        "15cfe88dfa6ec026a2cf7f40761b022481f1104c", // 325
+        "f3849eb3c7b0f2e9d9819cc29db48899c9528796", // 326
+        "cfb61463f61549df720c8c9881d1e0623efe8123", // 327
+        "2735e8998c63e7f6e3def93ec1c3d597e3671ecf", // 328
+        "3d5c2f74d725659a12122180531a8e20a917fc18", // 329
+        "09da63dc4d352fc79d4f0682e82a090020021d79", // 330
+    ],
+    [
+        // string 6
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "dcf5bf6c63ba8e32483f75660b3a6a0f5d764483", // 1
+        "8628cbbcc3fbf2c3e020853c2f325855c231ad12", // 2
+        "065ea868348a7632b3cd555edc34992eab63c6c2", // 3
+        "390faf9ffd477ff6bea38db8a5c29190179d41aa", // 4
+        "49b6c8c3b4a0dec79b309680a6283de8ce472d37", // 5
+        "99a2b46bfdb56375017f15bf803a8adcb4a5bb59", // 6
+        "79d77b1daf4155052897f9f019ef91daea859034", // 7
+        "50fd898b51b9d770ad10ab459484f52f9d0acced", // 8
+        "05c2e35897bfe5bf26eef927c70a7d4de0763597", // 9
+        "c8d82a14572b1cb883661565b30d4f978d7c5da2", // 10
+        "ce9308cac9c5e63c69973e8f28b0960f6e1df507", // 11
+        "25f6b9c1e815d6598ab5772ad943acd38400b6cc", // 12
+        "365a629e60e3647690db4829c0be7f9b7aa399cf", // 13
+        "35408902f0af04316d451a9f1abf9ef713c18f8c", // 14
+        "7e66c9a07cb891a131d69d8d544c3353e58db169", // 15
+        "c09e56dc55fc5135d4e92b2c9c4161dd757852c5", // 16
+        "48692a6e198922d9307734c81d432e4f8c0387d1", // 17
+        "3a218f457d930167fcec1d86a51f9e61b88eeefe", // 18
+        "b0be99e3625c47ed3296b682fd39255f1446391f", // 19
+        "1edca9045b8bb049428a7e1051e1680a9a9c6416", // 20
+        "e7973e338ff2a51f6750f375eca523a27a12325c", // 21
+        "6dda7a7ca6702435503f8c68f53f0dbd112afd2e", // 22
+        "ebf111c5897ed119ee9d0bccdf6157d3bd2dc414", // 23
+        "3d4fc9f35283a28cc7c2a4add6b3bbbd8bccae1c", // 24
+        "a17343f6cab1cb220bba1a53842e4fd0c0934b28", // 25
+        "7f71e774a6e74b70202185beaeedaafab2bda796", // 26
+        "799781bba9110e8757571cef46e9d4c9aa64486f", // 27
+        "9f8f738649d23789e0fcc0fbdb222e281a1ea74a", // 28
+        "eeaa6a3d7893d70758b728e40ef49a0b8e2a79f2", // 29
+        "1d090c7d529111330bc312d02d57f2eef8ba7644", // 30
+        "35d7b9f3d3727afbe28b29b8ebb9556b8a4de7da", // 31
+        "5127074d8b7ecb84cafd87bd92a93c2d17779388", // 32
+        "0052125ee19b003e962c72b44ca3f6542e4d20d0", // 33
+        "cfd2b6ff83e60405cd6ae7ea8eba06c1e94b943d", // 34
+        "df4926a08423b7d1306d2d595eb39452a69a5610", // 35
+        "719fb3b33bf7394f3f632df8da19e2f2b4fe3f6b", // 36
+        "1f8e908759df41aebf94bed521391f2d09ce09eb", // 37
+        "bfca79b2cb155ed58ce9196505364b757dc19575", // 38
+        "e5c4a55c2c7ade2ba14f43981911bcafdcf7c1cc", // 39
+        "e4f0bbf0796ae53b3293176a41598f0a1e3759d0", // 40
+        "0fe49f39e03493a837a0fd027296509eed35fc08", // 41
+        "25aa895053b4da75c5745f33e4166e334051dd9c", // 42
+        "e79799dc10f7fb8c752f43e6f04da17f0b4d12c2", // 43
+        "c8e7e6fdcb2e2ce8c6e2797c588ee1e96a2acb3a", // 44
+        "09d7f816da5c707c7c4fbc7385b5914312483573", // 45
+        "bb41d3a1d949a4088d93dbb9964aad9ab420416b", // 46
+        "591c220a97de7c20734cbb2a1b30ff1391da860d", // 47
+        "2664578ccef73f51de9f053ae04c1a84d2bd7f38", // 48
+        "83b5d30808270bf34195fea9a890fa94ea5d8546", // 49
+        "d8aa1c0ab8880a3ec39a6c2b06ce2cafb2a76351", // 50
+        "42adfea23b71403427587004ae9b7a3215cc98f0", // 51
+        "d2491f37f05f9df47c786e07600bfc258dd2122c", // 52
+        "6ed03efc99699cb868958b41b61109f1b0055ede", // 53
+        "3a348246661756aef6f9dd9edab27f8a386a3131", // 54
+        "113ef652b555733a7cf35d493ae9ff9a13a37720", // 55
+        "43364c76f93a17dcc3f17d12cccb4c927327ec85", // 56
+        "41f01270b0c1ddeb0858f7dfbb247b1b7d43f720", // 57
+        "355ff04b7481b28bf6333ea3768ee0473cdddcdf", // 58
+        "d44282be954c09fdb1fc24a2741fb62d8dcda410", // 59
+        "7dcea5be12eda8bf48c745324a65a740f37c5540", // 60
+        "40f1cbbc2ce6eb2702fc460bf801951ec20e1425", // 61
+        "89ffbdb1adcb791ca2ea9eb630e61081bd0b367f", // 62
+        "fd1753a2338033d6cde1678a5bb4b337aa8ca4c6", // 63
+        "bc65086d501433e9c102424b09bac7ebb3f6d656", // 64
+        "72275092701cac80e4d7aea82913b62212bd3674", // 65
+        "bc3f630d83e26995db8da099df2d959ae45231ed", // 66
+        "e5ea30c8c9dc813884e368f553c8479706c0f3b6", // 67
+        "bce5852858f952672feec26fb62e260b204bdb6f", // 68
+        "9e8dd119b82b0b4ad1cc722af282de7af169f23e", // 69
+        "6c77faa5d9bed34662d2d4fe194e4b65debc0715", // 70
+        "d5dcb95781bfa8e53f3865559a80ad999eec23fb", // 71
+        "3d51821c3c34d6ac2f33b79fc221ff98d2dff2d3", // 72
+        "c28a4b5c3c1e0e091e31fe9fa57131e819795adc", // 73
+        "3495bd5f3438d0cb8fc696a714cc0db255634597", // 74
+        "ec6534044b95d9dca665e6e146a826e333f2e774", // 75
+        "bf1bba29a18c68dd2ac6b71b3340e432d1a71499", // 76
+        "254d981f595a2e138cf2f42a23ba748519529d78", // 77
+        "d2cef86df9f11a522f6366f2011ad5fe2477ac99", // 78
+        "f7aa8184049abe045405f5c21a1baac35117b89e", // 79
+        "2a57bba7197ad6168e25e110a64c0d6fca5a1702", // 80
+        "75010076bbe73790c1ddd452ed6e94b088cbe6f6", // 81
+        "84799ada680ce1e94519a38430cfb2370da4582c", // 82
+        "a8cc7a28dfb7c92a7ce118505c3037bf6861a75d", // 83
+        "c2f6d6585e267150db4c49fb2df31bc71cded0ca", // 84
+        "9a0d1c8fd9345fd110f791f39b58d640b4a2ba05", // 85
+        "69e23b1c240f13e646f614c180d2fcce3fd1bb5e", // 86
+        "2aa7b3f814f9a52142e155a953d92bc9e81c9521", // 87
+        "9fde7b4ed8c9b4d42027fba3565558bd163d4f6f", // 88
+        "f85ba54c40c0457ded1f40a8e070fb98aefd28c1", // 89
+        "a344d5347256f34824df6bec73a85d7c321b5384", // 90
+        "92d0c648264d1dafa457c97631042482b8139b7b", // 91
+        "98c17802132a8341a3163a27a90b0267e682a3e2", // 92
+        "348770c810a8811347478bca87231cad9f447555", // 93
+        "ed614f301bbcb955bae5310bb2679478d51596fd", // 94
+        "ff9dad09317cc989d73f51354580b3f57181ae75", // 95
+        "5027dd5725ab1ba543786a7aad010ca8be9fcae4", // 96
+        "d71b49dcd95a38b621f18109d318d884b3b42bdc", // 97
+        "024b2b464bdaf9cf9be4c6cbde29a5d036365e19", // 98
+        "8130a56fa4227557d7565b023a3562b006e9e546", // 99
+        "c43db769c118de34dea9a22c35c7cf70e9120dd2", // 100
+        "687085dc47b1c40473b64122ea4bea37c585df9f", // 101
+        "15018835b465a8d3145919c4d5ef7af9e72ff958", // 102
+        "3d2644e1d072075ce4551b5ceaa27a6c15d9ee62", // 103
+        "a15f8d97fa94a1f248a4bd744f6aeb6d84acb93d", // 104
+        "4c20f11fb077314f6ac1c47da89a407a40522689", // 105
+        "d666b05abbc2112979f62a419c5326782fdd44f6", // 106
+        "97f56ea3e6cd92812fa000385abf6e288611946d", // 107
+        "414c69bfe415b6060ad792e4e32729704bed2154", // 108
+        "5aec65140f0ab4251cbfed6a36314247eb5ba545", // 109
+        "8314815b6cd5ad10e2308dbeb61047818b417de0", // 110
+        "912696a658bfd795aa76b6520d70bb15e7f53cab", // 111
+        "2029cb86d1490ae85990f98e708ec28a95937daa", // 112
+        "1e07fbb27faba73554830fe13e68df745da71722", // 113
+        "1dd7cbceac5797f85825e6e233807490ea0c1a1b", // 114
+        "7209bb93abb3beadebe5766a70d392d3a0ae9a80", // 115
+        "5160267e37b0b051bbe8784fcf236b4c4cb6fed5", // 116
+        "e8236b1144633ae544eba9d0104cdc37b1fcbd47", // 117
+        "ea0297da384404c03b2c7aaa06c481dfff364472", // 118
+        "72871c5c150449d02625e15f8dfee30e4337f8b5", // 119
+        "78eb11e003e3cdbd1dd1de66453e929bba6dac9d", // 120
+        "ca4b0e2f333277dba46e91dd47487665d26edd43", // 121
+        "91f5b4ba51e3775630cca667efb06003128d6661", // 122
+        "61c6a2f49231f06821e14934f0df699a1c487dec", // 123
+        "c512c169b1a7fff414f8c009dba01993cd04b5f9", // 124
+        "231cded1d5af5fb773c8066357930ecd6f11199b", // 125
+        "0428c9f8660448326bc67408f20b271d7289e6c9", // 126
+        "51c5a8e4859bf10d0cad0aa77f1b25dcde59ffdd", // 127
+        "7ea5109f22af586046320a0ddebf4dc85965fe96", // 128
+        "c095f487280dbf638dfa929e3cde562a0c8aa082", // 129
+        "194796f5c7b5dbc358407ce6e6757a13ee25743c", // 130
+        "405f045750b07734c9ec21b14314e87569e74473", // 131
+        "86ebe5a0e5b1c446a49bf5588d4042c4ec0a1435", // 132
+        "18116b15b65af14318496bd14c229f3a07926ede", // 133
+        "b7597e2e637151985fdfa9d7ee65aa53583167a9", // 134
+        "aab1c68082ca25406dd417e20255411995f97e22", // 135
+        "0ee30547f53d59bfb1988b5a834f85f6276203fa", // 136
+        "780c833a13d40c8da2e3fd7fe48eaf23116c920d", // 137
+        "6adc9c27f7808b3b99283ee6f34730e6ec4c439a", // 138
+        "49ec085c8c53a86dd348f20f25f34aa8f08be3ba", // 139
+        "18bf47e0052b16c95099226044dd0d841f404cdc", // 140
+        "e9eb32d4ebbe80df741c1d518e7a5306359ceea9", // 141
+        "321325ba532fb1798e98810311b91830d79a6a5c", // 142
+        "c14c9560ce342364f087486be2c5419081907a0e", // 143
+        "8b03dc6d5459a471a597c5e74e8e5fa22a0bcced", // 144
+        "9a05d08bea4544401813f7b36c39341da2cd96a8", // 145
+        "e7c3a7be2e516fa08ddb5b65fa1170f69e8145e1", // 146
+        "38a8d4b86b10c2762e3ff06d09ccf8159ad8794e", // 147
+        "0bc15e92ae9cc1527a9bd2db3971095da3b2d28b", // 148
+        "7183804e7113467d89137c4a56bd4ea1459e37e7", // 149
+        "520ce9b2ff949098c1eb15febe5fc3d3b9a7c049", // 150
+        "7d966dabb383a1c4c21fd10f18150ab57109c46b", // 151
+        "c177b42f16436b8128570ede58411f7bbcc822cb", // 152
+        "c34c6a8a1d1265eed3f2eeb522d2b52e5d8801d8", // 153
+        "b79eb639a5b45dedfc5c1832792473d2f3bca5c3", // 154
+        "e5d00dc3ae926191b9145d104342ef8810db0635", // 155
+        "f35ad350465a3bb81323453f7a9551608674503d", // 156
+        "7022b07a1f6ee3c939fa623da5e86b429b447018", // 157
+        "6eaaa36225bc9191bf79d78d1c45fc35c9f7f9b3", // 158
+        "4c1b59724694f4a8279bda1ec44d1828f44fc2e9", // 159
+        "6cc7485553600503a49fa190a4149b1c9b09825a", // 160
+        "7332aec32c489dd8716803be7443a0d353c07b48", // 161
+        "17949c3fd6c8becdcfdfd1006afe8434a75c3672", // 162
+        "ed8eb228e1e8826ce14127dbac4b2a9bc83169aa", // 163
+        "4ca53d29f954112764d5045a0344dbb41674d4e9", // 164
+        "3e76064ce227eade5d97ff22d610bb5bebab597e", // 165
+        "917fad7608212168d6076c11c00db4fea5086cce", // 166
+        "2171cae4120ecf9dcfdd83fc5a701c47af7f9c68", // 167
+        "9e3a66e4ae68333801a2727c5e1f0e026f566426", // 168
+        "7ce17565bfc29a0c3c5fe02b0d58ae83626c8471", // 169
+        "949606b50ba232468b8c633ee7f393ca2c2ba4bd", // 170
+        "295feb8d32be0e739453de6a49b19069773b4ed9", // 171
+        "a644ae0dca89e74a808366fd28d1e9455202109e", // 172
+        "23721f37d4ceb9a2b78622ce488942e8905e77bb", // 173
+        "76efea9bd6234f613d2d1abcc1f76b305f9ba46d", // 174
+        "5e263cb60f20223964967225f7a7853dea385b08", // 175
+        "a592051b292d68a50e040e60f72341b53743fd39", // 176
+        "7497f7f46039b343536ed635e5811d90d9fe5920", // 177
+        "5f6f960ee5d4c514d25b5b38822c7e87c4abb414", // 178
+        "7150bee5ae2a937909525bcf5411120d89196be5", // 179
+        "2392c0c5a17f5755735f0e0c6e2be5291e1a633d", // 180
+        "048d0512e1f3b6395ab5b3ba21cae4bbbf02d838", // 181
+        "5c59aeeb544c021824e16b192e390ac90521ea2f", // 182
+        "7c9cbecf4abb7280cebedd558cb0d863268e2baa", // 183
+        "1ee117829e2e068d49cd7ba3e5e9d506dc55a506", // 184
+        "897248f97b94936cd7579326e4e73bd7bbe61643", // 185
+        "98038b267a9c02221bd221630f483371f8efd51e", // 186
+        "ab986be9a1e656267696246d7fc944ec93677962", // 187
+        "6182b662e5973b4341a44a201677c153c332a927", // 188
+        "951d650ae1c8c333dfe493d5a452cc44edc9673b", // 189
+        "05d66d8ac51b15eadee54e77473379f28448eba7", // 190
+        "83da5981e01c9cd7078b54c915b944370e449265", // 191
+        "ee00896c364acb27d947b5026b3663ecb54cbcfb", // 192
+        "9353c680c69420e7aaa9b03de385cc1b4e051208", // 193
+        "27740fef7bb01c393766fa5c635c90b0bc9e4891", // 194
+        "e550f0cf353c4669f2c8bb7726396028ee8e78a4", // 195
+        "352675c4fd5c63252395888a0db96f239aedb08f", // 196
+        "bfbe31195df8180f34d532ba5b48758b25f34b53", // 197
+        "ba3b692a0e6c20374e46ba691d9ef1141b632ee8", // 198
+        "827172b9fca75eb3f8b0eb062e3881ef42cb1673", // 199
+        "98839e202520a369db3a202da6973a1023f9e621", // 200
+        "e5363d6d6470260cf226f914f495b4db87a731b7", // 201
+        "d9de277d47f593eecf7803547a876e3b1aff583c", // 202
+        "5145cd38874a2e4a4d53513e53b6c24f04455b94", // 203
+        "2975d5b5c8b51a404e42886c3985620e530424a9", // 204
+        "41c5e36747cf5816f47a4d7e2d359ee1827a4e5f", // 205
+        "f90f0ffbd9dc48953578826cae54aa7238282d59", // 206
+        "9d6a8127c0b75642a63f797e46561a295f031361", // 207
+        "e7528a52eb6d1e4d9990785484f46607b453a6ac", // 208
+        "37cc4f40757a882ebb46cb384464d98eb4f32270", // 209
+        "9b17523245c5edda9db7f1ae1e310f2df3a69685", // 210
+        "604f7bd7336ba383a1ea0cd7b836e4c20d45d85e", // 211
+        "9ccc9bce76446f928f6a71f1def0c75c707b2f90", // 212
+        "4307c1e41cefa0b9b9f6fca526203e32925aa451", // 213
+        "2ddef58a0e6a99cd8940ef422087b95279c61dc3", // 214
+        "86521f62ef7bae3b093b4ec7b4eeae260a181a93", // 215
+        "1a2043465c13870dcc39920f9f30cd7f088b9595", // 216
+        "53f174c38c8c08945bf1779763257e44c53a2ee1", // 217
+        "02ec04d38d41a7edeea876edb47cc16fe38b78f6", // 218
+        "a4719d14f070d5c6ac5aacc8d51097751ce5d348", // 219
+        "f93f82ca2910056fef8fdad2658b6c6621212abe", // 220
+        "587cebabeec05555219691668ff5962f56b02c65", // 221
+        "95a6f84324a9f3c012f9d66f4800afef76953f53", // 222
+        "8396666867287b79ea8896e9d3a3d83d309fb60e", // 223
+        "4961b08c6a9a6c2051448f87b1b54b93db3fdba0", // 224
+        "114e491842e3b54aa2751b8bbc199c28c720f961", // 225
+        "bb4143122ef83d95840cf7ce55f592284a811752", // 226
+        "45f71b2264a3cab2cbee9ea98cf2116200aeb5d8", // 227
+        "36dfcd5b1f6aa37c716dbbdfa69a7425f2ff8d83", // 228
+        "fd8e2e631bbccbbddf5fe6764f40f193b53b8310", // 229
+        "83c8ac90ea5c7d855132c7e952f1d0cf765ce599", // 230
+        "1336de678531c1ab2d68fd68070169c777d96876", // 231
+        "1c0799e8c8dbf7ed91455ef958898447d46b3aea", // 232
+        "578a5acf6cd0fb8eae51eb26b5a7c59098ac875f", // 233
+        "d2a2b8ac0f0180e849c37bd417fdc3c7eafae171", // 234
+        "fddec89f7fb8b53844c4250cbf692c003ab18d4a", // 235
+        "fd421b50ca5ffcd8bee5c2df85bbcf217c7b50dd", // 236
+        "671ac5cd84c5ab096f0d4d3e5441c0e60c522e29", // 237
+        "a80ce5cdb2e1fcea5295c0e46fdffdacd7a4d57f", // 238
+        "5170e6d9ccb8bb71650e0fc670275fdc559f8423", // 239
+        "3416f4d9ff68defc9ffceaf8ce2979cba5d8e851", // 240
+        "4bfb95cc304faeeb542a48b1c69485278ba3dd5c", // 241
+        "215080854e4bd8a2d97c1f84c03a1564976bdd2f", // 242
+        "5be524bcff56632d3a3d6b304bc8b4968a9a09cd", // 243
+        "68804df801fb881afe37712b1a6d5535c45ce197", // 244
+        "f708f15cfa23a55eb844c350e5b4914a6ad6da61", // 245
+        "5fbb01f8141449c0e7a73f501cef38e97da8446a", // 246
+        "cdbbdb9cb02a6e3f43db60fd9861e324ef3d4223", // 247
+        "fbdd6f29b83991224fe89c706b9372c705ca3fd6", // 248
+        "6af845a6e02496f629ac52747da30665bfd4c53e", // 249
+        "0ee762ef8939b3ba84e029183d6860c89df4845c", // 250
+        "f6615c959a3713aa232443f4142a514f2fbbe8c6", // 251
+        "8f2d01b956f03b8fdd02250f9295512c2f15474d", // 252
+        "34e228ee201634438ce6aaa84f85340c84b548f8", // 253
+        "dc6fd15d375d8448ca6ba20c3fb053284b5d8907", // 254
+        "9faa7394c00351faefa7a682cb07110564229b97", // 255
+        "5c980a027c45e4d77f9fb7e536d2c225391c9637", // 256
+        "0035e245d47af272dcfb59f8c0acd67d83f08489", // 257
+        "827a7525017d772213b8b4ae70037477a59d07a2", // 258
+        "cbf5ec9e4f0acdedf1774e076714a4608ae507a4", // 259
+        "294c67a8c7aee78cc116bedc180cafa82b943213", // 260
+        "4d01447651114b5d6c1e514f45825f7308d2c4b9", // 261
+        "030a1e5567280ba341cc83a53c6ed5c5837f40d4", // 262
+        "bf2cc2800505cee936f2eb40803c85b17628b335", // 263
+        "89f8f7da455aa1de089ece11e86591755c5e2f2c", // 264
+        "6b0d7dcd35a05190ed6c4abada9e37f336301e32", // 265
+        "9140418d9f4a79cc4e81059c75e893a7f8e7ee2a", // 266
+        "e1b8c8181c93db0053dd42657de773df48331dd1", // 267
+        "7c86a197292855df22d0a426532543e7b5915c7f", // 268
+        "54d913b870abe8c4fee0e33b67ecd995abab9e45", // 269
+        "31d67c98803afb3783dc97b5d6351bd5351e6757", // 270
+        "71e5ecfe70bb0e3c19b9a640378f343463227b71", // 271
+        "47aeda94af5299d9ad9186560d64c0090bd3020e", // 272
+        "1764927ad2046538af83d16b4deb7d1bc37186c0", // 273
+        "bec196434496a0918198a5487634779c88061c40", // 274
+        "f6bfdbfa95f28599ea102fd87ac8bdfd96f72998", // 275
+        "f0e42e1a9f493597048c8424c35684e0541879e5", // 276
+        "2d02ba87d8eba5d1d0f0e68cdf0d55c06b80fd13", // 277
+        "7a5ceb2dc78701b129e117fdc6e43ee2b3fa534a", // 278
+        "2326159acf11a98c76ea4b8f14a785145c27f422", // 279
+        "888fea84349ceac0137f8629baa2877451b29f0a", // 280
+        "ffcff0c7e0552da6cc8f3930a6633c08a612ddaa", // 281
+        "4b0d54d8e889f40d2dc150f59cf891d1d3587d4a", // 282
+        "6eceaedb7cd6d382d663a0b775c33d6ce21c69bb", // 283
+        "0b8b9252999a6a10c570f26b6165bba614b94b0c", // 284
+        "a27c0c415d0ed201859afa1f2d76ab4b18618511", // 285
+        "e3dfa434302de8b0eb4ef24e01970cac14219682", // 286
+        "3bce275095747de5d5b377a08b5842c5bf00b657", // 287
+        "2e06cd3c60c23015776a1502275e3b00d2168126", // 288
+        "04f7558fa560f7b3229bd7ef64b7c95a87745bd2", // 289
+        "512e2d7bb417b8a1717897f1934a11fdf60e81d3", // 290
+        "15e45ce34a4deee4d009a06a9d81332ce99712aa", // 291
+        "3179d17ade418626edb78c95999da0268ca12e68", // 292
+        "d00d76d3033323017ec9cf1abfb4a24adbf807c3", // 293
+        "05b7bcf08e4658c05ecc12718c32fafafe5b3707", // 294
+        "a34929e8e8e1abbc62b689e6424b8585021f2cea", // 295
+        "e057c83c1d10d41a9303c138723e4ceef65e1d6c", // 296
+        "add850a26a3ee1575dcf0b3ebc19a3c46648c6bc", // 297
+        "b36361dea805c4a40b0d199df68af3d8f0fc8f31", // 298
+        "99b92ddadff6dbdfca1175f820dd9e2e77ed556f", // 299
+        "45869ff3ddfef28f1ea3c750f035ffb66b3c5966", // 300
+        "419c0a838d430058aac35f0ede856bff0cb9bf6b", // 301
+        "d574faf360c8bacb31fdbc4150191332e3209d11", // 302
+        "55c69296d728ec4a92e5de142496ec538fc8ec63", // 303
+        "7fe6b6265b0290aec53a0d04c69868c0c801fa26", // 304
+        "70c3efe99ad376d4933e86d9ac3d3b255ee6d765", // 305
+        "c66c307688a797e890b48f013b3474c50b07d532", // 306
+        "fecac0224b4c33b38a1451a3c3a8b9350d1d328d", // 307
+        "087b450cb82855f8091ace6578db5583c2f761e6", // 308
+        "ed61b4dc9c5abfa533186cd3831c2a235d062d26", // 309
+        "8df289adfc9b2fc0f0b478a2ab9cd1361707b1ef", // 310
+        "af1325722d948540257ce894b6bef31596856bbb", // 311
+        "f562951f65512c7a3181f1b06cf2637592651fed", // 312
+        "e0eb60a4b94e327fe788435ccd439a4ceef8d8f4", // 313
+        "9b3d0ffc2845156cff42b582ef0a6e70d86cbd7b", // 314
+        "2a08bd2e8d769abf56f02d71f3acfd078b3b7c00", // 315
+        "714d5b7327a0fc859afa49b02e0c22ff62b9f68f", // 316
+        "980f6ce8072a68cb3b217bbe03b976f7c0781181", // 317
+        "e1e728cd02da46e9cf792f874b23650a6d3b56d6", // 318
+        "6c5013d305086deb5913d064678f892b94494f4a", // 319
+        "ab8c56b2b992059635fc56fe3918ca1da99acf84", // 320
+        "2398cdd8d8311fec8ee4aba981311d5df519ffdd", // 321
+        "6bbfabf0d9b25f985f3791d74c83653914a8412e", // 322
+        "5c6abbd25dcf2a36a3aeb1fc4d63483974f09128", // 323
+        "0868caa4d5bcb767d9dba8a4657d039f04ede9d0", // 324
+        "9690fcd7b0e4150d7a011ca52803a9854b9a2b47", // 325
+        "15af14b9316c9330a520290e0aae34534f76f916", // 326
+        "ebb4ddf94360ca19e61c4bfad9b28bc11d8b18b4", // 327
+        "938a4d95599ff1ed10f2b624e19a10569fb1c801", // 328
+        "63ab39d397ac0b23f436ba102b980f19736143b8", // 329
+        "4f755cb87dc0c1b77c60879109c677f725557034", // 330
+    ],
+    [
+        // string 7
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709", // 0
+        "4105db4a5b81ca1f574b2f0d9576aaafd9ae9c76", // 1
+        "fd649c627492c91c2ee9d9d420c8ba0895393b25", // 2
+        "3ecc6a257c94871715e48b3d2c9db9ec365bc019", // 3
+        "a0a6eb1877730c8cfa563c817b3b9e1530bc9c24", // 4
+        "f9e54f6c8be4686081832ef076fcec1ddec5dfe7", // 5
+        "76ca889c4a1bad0cef2ce6304e6cc2168619e3f0", // 6
+        "0c8364adcc20329e8c12032c5ab3fff8844b03e6", // 7
+        "54921a1ccc9212720cd0dbd3f3b423bb641cd9c5", // 8
+        "e1fb2f6eb7d6a9d1fdeffb4f0f3a72832d30a673", // 9
+        "aaec52743fb27af08eb2f67191bc7475416e4c38", // 10
+        "57f1cf5daa85e39907414c5b94b187144b0107c1", // 11
+        "0e95f61dc2f21c14c390549aa8141357c967f9d0", // 12
+        "7099758280457534c2e4b7948d6cf0ca37e44a83", // 13
+        "ffcaafe980e21f342257f5c5cb549a5bb161b873", // 14
+        "9f427f02520802fe0cddd2196a0008fcdfd61172", // 15
+        "30277e7e9a313d5edbe8c008f4a6f171caa8a7d4", // 16
+        "7281fbb53acfe7b7918e848196bcfc9785af9bc1", // 17
+        "e50a1b45ce4d7e33ab54c104048c4ed309d33342", // 18
+        "59e7dec20d32b868ff079bbdecd79ffc43039bbd", // 19
+        "4ac8f338a437a53517058b9ec381c9e5f0ad78a4", // 20
+        "1b92054073b522a9547721da4ad3b6ea634efffb", // 21
+        "7d8dc1289f155e62efe752ffefbf7a6448d129ae", // 22
+        "5ca3ff8b659c989d684dabe880d099c04906fe91", // 23
+        "41ea640ee524ca5c42264384bca3c74a7759949a", // 24
+        "d75863857a7ce9d2c8cc97c73ddea698d8d58cac", // 25
+        "08bdb234db14b101ba153f5ed858b97d8b67d25b", // 26
+        "4f58e07d31b70167eac3958aee60047d9973c3bd", // 27
+        "d67cfaf27c834d60b75c6c25b0d566c1bb2d3f6c", // 28
+        "6c700321c4c721a3846b02f3c72272f5a9078477", // 29
+        "19f08a4b5cb6735a9f1f3c039b68b8e3574f7807", // 30
+        "fc2de8e8fe4aa19f2504d949eb0fb19b5c219c0c", // 31
+        "5e036a9a8854b1dc6324f3bef5fa1ce3cd0a8579", // 32
+        "6df667291b5529d4c9ad3178c329276d983f5bf7", // 33
+        "9ea015523999188a8b64b7151dd015ff9c06a322", // 34
+        "433f54eb0698a8acc3c0f26d81ce64c02e02789e", // 35
+        "f894b927e71ab6ebcd72d6cc496693cbd88f4f98", // 36
+        "50de57c001baea0e6a758dbcfc47ae310a7797d6", // 37
+        "a3086fcdff430636faa0c17ae085f1cfd28f01cb", // 38
+        "f8b8bfbfe6d7a5b42aa4074422cb98e9501cea21", // 39
+        "df17c9e4087daa9be0f180453cc55ec40232372f", // 40
+        "26687912e6bfaade8e045063862aa855ec9086df", // 41
+        "133a5594ff6d85802aa4405016f74ef05b7e20e0", // 42
+        "eed800349898861398406207d6e42cb36e2fc15c", // 43
+        "578ab0e2fba68ed5ca1a2f1a64f23395176667b1", // 44
+        "8c5fd1c23b8a9106b5efbe069b55fcbe0874ca30", // 45
+        "59222fd09af85d6a9fa8a8d344e41f6a7979e3a9", // 46
+        "cb4cf63f8efa68583490cee6a83ee18adfadaf29", // 47
+        "7ed9f83e283230045a00b5a6b645d1e31d8d1987", // 48
+        "645bf1bc27c84bd345c8e8b0ed2e13c7a0bac15a", // 49
+        "b64e64c8d8a8843645dee9a25d399e859479457e", // 50
+        "ea4a3649b0d54abea249d246b743c3f5dee46a61", // 51
+        "afaeee8654c1651118a26947cf4a92a919b472a6", // 52
+        "2c190416b710ffc5af5c707bb7760f769cf3c8af", // 53
+        "f8e70c5dcbfb3d48f6490f6ad73df627b905bb37", // 54
+        "e761161bf8dc8c684bb60b31271085ae8f5136e4", // 55
+        "f2fb6ed269cbec4a5dcc7c1f478fc6a3aa90f679", // 56
+        "4ecf998dcf21bb6cff79350229d2882af9f596a2", // 57
+        "6ddc5b2d6e0707f48586ae40031555417352d010", // 58
+        "934b2521d438843066e5e7c591967e32f0e5b586", // 59
+        "8b85990d60780da54c846b6ffaf1c9319d3fc9f1", // 60
+        "54db581d5023a84d71246e7c28316011988d2266", // 61
+        "47a0d0164a9e0ec70fe1346a15a5083c79374cdf", // 62
+        "035fe63e3f3afd54c99d659898fa3a2e7ee85572", // 63
+        "a893a6020a5a3ca82c3328169a2b04b9e77b419c", // 64
+        "4a3f63ccdb350e0c7b2cf253d56c4b71b7b6fdfd", // 65
+        "2c56dbaf33e91816396626a08baec5a50f911bdb", // 66
+        "3eef21deb221b0c91d30ee2eac6b51a274ee4266", // 67
+        "a8cac1a27565db24deaf0b42a6b6bac447a91983", // 68
+        "7db4f98397a604c94adbca7dd550b319e5874416", // 69
+        "c4177de97b02e77dbc473ad7dbc87dee20296bae", // 70
+        "b247ac3f68fc8a561b83d970ddb36c89034c80c6", // 71
+        "17dc3b985370f6628d932a60eca7f17c76667f81", // 72
+        "0d9e17e40fb6c2a20ef30731f669b6ba6dcc28a6", // 73
+        "f8d0b631447b0edbb93f5ddea3181d110f0b5691", // 74
+        "9371611fbbf54ac9784f9f62a9f9a23b6a9f31e1", // 75
+        "6033a55a139472413909d866db3b2cef9c9291bf", // 76
+        "cf7eac8de3b3bc6ebdaaa49733a77078716eca7d", // 77
+        "cc27f535a167646050a46b93f3c9eed1145a38f0", // 78
+        "64135b69b2520e9b6ee5b84ed6148eb31f188c03", // 79
+        "ef70b7f8d6fc8bee08193f2b2aee4ca24160fd6c", // 80
+        "7367a827231b7d6bb2e633cd6a10c1e61120921d", // 81
+        "c17c628cd2536c9871238615ee43acd628c5c84c", // 82
+        "d83308fd6a2bc5c697f8740461bd40ef5d1b6f08", // 83
+        "0e57a96f7592cccc93b8af456646dd4da4ec8769", // 84
+        "050ee41a2f998292b5755c1ece30c765f994b339", // 85
+        "3d5b8ddd1107eaa758040d0eb224f4e4ac936326", // 86
+        "540d9fe828ee015040fc398774ba7fe005a82457", // 87
+        "155b93804dab51d0ddbc6a327390af51700a6aea", // 88
+        "c6e4fb8e55a10765e2df0f5c4a6127dab3937692", // 89
+        "1e4418863410b87cecbcd25f65d378c07334e2a8", // 90
+        "4019cbf8d12f9b03e380788e8cf5c1b62e95e316", // 91
+        "1b2e148769e53e5e9166e5883221ed889c1155bd", // 92
+        "8b7465f8a6895b0b8c6a71c0224fe2ad6fe5ecb4", // 93
+        "a01f0941e08766df26c1c089faa84fcf4a15cac3", // 94
+        "430461be59154567e6ebf9a5c61fe1e2d41494bd", // 95
+        "14a3a8bfea3eaa471832069c829bb8ddd92ebc32", // 96
+        "1984b8dd3d3573924d8a0e16bd86c54bbc1b978c", // 97
+        "88c071c4207533338a96b9f99f6ed1120ab2da2b", // 98
+        "ffafb9af63a1ffd97c07f1d27d2c91e367858e39", // 99
+        "f71dfb5ceaec359389f7a1201e983ebc5be32c8e", // 100
+        "01108064efa16809f014eada9f41e953479fa995", // 101
+        "8f52560af4dfc780e1a5d29c8df7c1910ef79a42", // 102
+        "6d17f6896defcfa1ecedc83e7b87575737f916e1", // 103
+        "e7ad2bb40efb16734f0a5305dda9d8b7e8780d8f", // 104
+        "585afd40bc44959ac6107c71467bbde29fa65cc5", // 105
+        "49f7ee1c0bac616183a3e43e23fc4f3101d49962", // 106
+        "18aee7fda39ebc614ea7b3f0fd502f34546dd083", // 107
+        "a15dc6cb5dda7537a7586891078d017abf8b09d2", // 108
+        "22bab8850d2dea3c443443a69df640a9894cc67b", // 109
+        "9d7465aa34e3c836aa83d5aa1f9750e9f41e83a2", // 110
+        "dcf688d2a1bb2788f4b48efdb3a89836417e6f5a", // 111
+        "1c2bbea5386951678c28901b64c3f64f4e7ef6f7", // 112
+        "5ecca71ccbc6ded4c16516f2e23e99e00871a72e", // 113
+        "4e0f713cfb1093bb3adb4972cb2a43ba77228e7b", // 114
+        "110e5da7810fc90768a94b2989213782e30233a8", // 115
+        "05ef5c47637c5acbf1f964e2f176c82839e5de8c", // 116
+        "615bdccc6a810f11b69de4a6bac7c53b002e8e1f", // 117
+        "52e48c58c35ea12e4be7c8ab4e799256949cb012", // 118
+        "a383b780558ad48ae137b33b6a59019d5f5fc5ff", // 119
+        "d5f752833a07adeeaf65bf61a1c81581116486e8", // 120
+        "72273d0004755e1313b8127ebc9874b024b4a0dc", // 121
+        "ea141aca38eeb80b7e35fbba7ada92fc31905e8d", // 122
+        "d61e1394301788bd3cd21630fe373ef833985a8d", // 123
+        "6b72f2a3a2ff7262dc81ad0d789c989c43a71be1", // 124
+        "30eedd3fd900456ebff310e4d9dd57c9c3dc6cdb", // 125
+        "f6709f6c440ba8c00501aa1f73915f9b8a770b39", // 126
+        "f179c6ee9585bf0d59148674197b2e7ebd2fdc1e", // 127
+        "ae2d655f2eade9117d5b0a51c6fdf6531487026f", // 128
+        "e1a7613f8201c377d211ab042c7cf4bee761d558", // 129
+        "cfa5a1163a1b79737bde35eda3df8bff5977142f", // 130
+        "998ece4d52a32b32952671fdab5ca2c002b9631b", // 131
+        "32c9434ecd69a9f0674a51e3a5d8194f5908017d", // 132
+        "8387fd3a2acb621bf09100d9516b710a6b42d37b", // 133
+        "339af9121acf7623518eaa47fea4bea9f3b131bc", // 134
+        "b98a3610b7cd7f229a3537de6d303451be18edc3", // 135
+        "323da78934b70857f4765ac077f398c4e261c4a4", // 136
+        "7161d12c93db76eaa5376493ad92458ea7a9b791", // 137
+        "a3ee3404af09f9c8033c28ce3712fce38124ef15", // 138
+        "5b7713b44e66cf34ab156eee9b9cb58788678fc5", // 139
+        "84c63dfe41880b6cc4e69c061143f176ae1d301d", // 140
+        "80b19dcb1c8767019b10efe9a8f14e054c36f080", // 141
+        "e9fc4cab52438eae2db7127e98dc7e1fb3c18cbb", // 142
+        "dc11533e4dea0305b94152d23415f8bb9080b538", // 143
+        "901b09788e0617536e9d604cce62d081df202dc6", // 144
+        "e3b67b74ea728328157805bbaaa518c210a5a334", // 145
+        "01992cc00e27abfe4ce28a5a7a196d76bd846973", // 146
+        "07661569569157c375c4c2e1b2fd873f2829c5ad", // 147
+        "4e9c4b240c06179264987689815886b91d607fa0", // 148
+        "4abc2991693f8f872ef6f95d1bc7a2f79888d4b9", // 149
+        "ec9712d9e69ec4e18130f7b1fc3a3ce1a16f1c06", // 150
+        "85896e4ebec91a4c2894325e62888a8da912fc47", // 151
+        "1da8d21cdf2075bbb6f08be14520df6df710ed3c", // 152
+        "e12ec43a2ac4dfdb3fe10a67822f687bc6f685d5", // 153
+        "04783a3415d9d173721075d0c4402b00b80b4a66", // 154
+        "aa7c0a792c932cd594f23bc0a29481f8fa2abe2e", // 155
+        "db7973e22ce37f1bb321abbea2340a6a459875b0", // 156
+        "e49c4705afe2991d15b006bdcb18f1b340745cca", // 157
+        "7215579bc9fcc1320f0f189736eb128ad695b573", // 158
+        "dc9fc6b5726f34cf1ec6d7ae559a6502f6f33385", // 159
+        "bfcf64d52ff78206aecfbb2edac53378f9c7bf9c", // 160
+        "b1dde392ef44dd5a5e652e93d190b1598266bc41", // 161
+        "7b10fb46f8530913c5349cdcedc3a5d7485d818a", // 162
+        "427079ffcc763b1cc65f2ddf7ffd7afcd1db7c32", // 163
+        "85bc067d39d003ed95d6677b5e1e76683a916172", // 164
+        "337cbabb3a16cf84545d12e9b796e10511c19d7a", // 165
+        "61d06cc82727e5893b35f0e986e7d9f1bb80069b", // 166
+        "52bac6acc5fc47496ba4f7ba24adc058289bcbb1", // 167
+        "8e4b1e162ac329fc158798ab9b6658c47b2aab94", // 168
+        "d087125612967148624393abed3dec292003e619", // 169
+        "310d940875bbe829b1df7f2255f237a07d68656c", // 170
+        "d5e8c58dee6493c0f79a2fc69cfcef027c7b2ea3", // 171
+        "def2a43eae410e84a4808c6a66e50b3e68af7ff8", // 172
+        "ededfd8258bd591cff6bad0db19885390fff7907", // 173
+        "507553d07422a6a1dd3a679c0324c6b8e8d9fb5b", // 174
+        "10ee5158c47deb577d0e13e443407c01bd467e41", // 175
+        "76df80f90f065b83f767ee62e123a2ffa15f5e5a", // 176
+        "f5401093a495c6280f89358a7e0b3c364886ff0c", // 177
+        "bc28205340a4d425efa6d8d9aa2c870f596a9503", // 178
+        "d5609d7fbef42ee1b2a83a03db6404eb3e2297de", // 179
+        "6faab6e0b64c7f40155d312c652b3ba64febab6f", // 180
+        "e39c91fd62fa0366c8231425fea4ef79dc2736d0", // 181
+        "1e5577393e20c4191caab1c946c1ec2d85d3b0e5", // 182
+        "5688b6564b69cfd443c327834ca7c257bc6c0cc3", // 183
+        "7b9977b4df553e5f1fd6af1eab7ec4e87281ebb3", // 184
+        "2e91af1346b77a4cde051374c2aef8a4e9353d84", // 185
+        "101a18dc1ff9705b7cdcbd5e1161f9a94c3e494e", // 186
+        "708e72ce3504b269e409a85832572dd6af565433", // 187
+        "b527be00a92a5062d4ffeeb75fc727e9f31cf520", // 188
+        "908e082a96d83ee7606aa19061098dc483f7f270", // 189
+        "c56bfcd5cad3b684d3177590a2c4e998ec3aebad", // 190
+        "63a0620bc986b24f501ddee4886fba7073231449", // 191
+        "ef6afebef9edb2da1c2a1b2bf4e912c12a95ced5", // 192
+        "682ff2f418dd92010f0c683056741b3c2381692a", // 193
+        "d7a0d73e6704a63432dcbb61bca050dad56b88d0", // 194
+        "58bb54d4875f28e1bd0756f3cd33784206fa0728", // 195
+        "05d6149d87fa8f9433896f382d906c23f2b457b2", // 196
+        "54c297848a7383fbc3c43a377b11efd6bfb02888", // 197
+        "7a67917cdfb464018665409524b444d97324792d", // 198
+        "214f105266d75fcdba0cdfc652373c543f89e59a", // 199
+        "15ed9aca9f39a6a715c81ccd8a278e13c1516b9c", // 200
+        "993dd745338ba65c14f3c6be7601e00537a1adbf", // 201
+        "64d011cd2b6fa08ebfe1bdda88834bf6357a1233", // 202
+        "9ecaae668f3a4d81715ef2bce82a710183e4f9da", // 203
+        "635597f74eaa4e047d3592c144cb421aaee2eb09", // 204
+        "ab8f7ed6d683214297f15b6e613795dc3b96048f", // 205
+        "84f8cec0d7a83fb588473a8bfdd64de256dc5e54", // 206
+        "c88766ede61405d11b6015b79235eec96be58c8f", // 207
+        "8a9cee756d7e8d89f90366826cc7b3c5144e0622", // 208
+        "308b5550c2a5da70ddebe73e7239d3e6308bb23b", // 209
+        "9cb7aff709576d38e0c7585fe431e0c02f794fdd", // 210
+        "20468ecd92ab22b070e6295109624e8791fb4045", // 211
+        "5655c302ed4934612399483517c63cd62d582a7b", // 212
+        "76596f5a86468aa94c466775acde89f318414c90", // 213
+        "06fee46399520b74492747c40ac39c74ad5b1992", // 214
+        "9b2780e43ccf915b8959c97014286d3ece049b02", // 215
+        "08069b100f4adf8fcaab46a40ca3957d37cf9b49", // 216
+        "1e42e5fbf6537c5b0467d5dffa291449a96d4ba8", // 217
+        "c749bffb44caa00339408b27ed2b0a16ee1dbc7b", // 218
+        "fd44830f30169ac1cc7732bb08846a848d447488", // 219
+        "995f76efff01d86cb62b0023242780050b287a95", // 220
+        "cdb2ad8548fdf826f0b79bb7f5ac99708bfdfd73", // 221
+        "c8342139316ef187ca2718977847490f9d3aab8a", // 222
+        "bb5aeca05ccab24e4917b070c879e120c984cea1", // 223
+        "d5764434d6625894f39db2ecce4d80c44ca4eeb0", // 224
+        "cc253176823806a6ca2f2f6bc29e2024857ec6a4", // 225
+        "17e8a2161e81285101ad16a7cceb6d6845e314f6", // 226
+        "e8947c85a9d81b306cc222c1af30ba2008d1f24b", // 227
+        "d0405bd0f9ff50961180ca630fd8e24710a3e639", // 228
+        "58c2735e42cf53f873b4314a91b293046089e732", // 229
+        "02d017722365b59cf82ee3a43fd92497d675c658", // 230
+        "731e7c19640f038c1c921f3194e0dd07e4d235ca", // 231
+        "2ea32c840fdc59ab4c1e65a0a24c31de14528ed5", // 232
+        "1d1eeeef19fbf153d5615a25fc7e280edcb1b23c", // 233
+        "18dacddd36f7bd0cbd3016153daa67038470192a", // 234
+        "8fa417078f12431d52aac1745d4e972028990cae", // 235
+        "b56133304949a913e05b39454dbdea762eace851", // 236
+        "83cee1aa186e36c4951f38d9fe0183278bb94c66", // 237
+        "18b69c72e2fc8c3faf489ece0ddc12525889d36a", // 238
+        "9a544d2f0c359aa7f16666a0aca3fc55946336b6", // 239
+        "7cf92b605e88390f21d45c0fb2d0767cd51f4d96", // 240
+        "48ffe8e9acf572c403be97ad970a6753193ecdd2", // 241
+        "82b8aab0544d508c1dd98c3513300c54a05a9e1d", // 242
+        "54f553dfa357a15e470e08cda50ea4e7889cd8cb", // 243
+        "3836c19747c97bb90045172429ae2bbde1fccfd2", // 244
+        "b61be79dd87658ee6d956ab598534f3988837cf3", // 245
+        "da23469bbd74bde64fd4749dcdcae756ea97a612", // 246
+        "6daa08173ef8b23ae7d755ff766c02f4c0ced42b", // 247
+        "1e0a74b1bb2722e078a54b550ee91fd31d22e5b5", // 248
+        "7539ba6497f475877a087916365154d57d61131a", // 249
+        "404b022049baef771cd888d8a051694df88b7551", // 250
+        "5466cfa919ae6904b37eb5648e0265d9e44cc742", // 251
+        "4c4c41fc9669542844458ff0cc879ee3df0822b8", // 252
+        "515afa0801e28eb64ae3c25763b7c22f24d59bbf", // 253
+        "27955c1856383394f3b6d67f34d1d70108600434", // 254
+        "44880269ef739062b6ba099f7687ede38416d4a0", // 255
+        "0de3bd3d7d450ea3368c622316d66f417d8d245b", // 256
+        "72fac6796b25c67385485deadd952830a0d305a0", // 257
+        "930df5951cb5d2b26636b895cafa08b523a34cfd", // 258
+        "27dbfb1465030038f7cb891698c056a5cee0a046", // 259
+        "a9befae18d3be92e20067e4e1f2c4e10b7d32ca9", // 260
+        "4f1e9a110d3234f006939e36857766de698f2bb1", // 261
+        "c17689d578b6bb3ecd1f22b7cba05e8e4a44ced7", // 262
+        "c464671e4a555818c101910332eb200e11b8bbb9", // 263
+        "5e69c03c915e8e4179485f51028ff3ad6c4188d5", // 264
+        "a3da65bdfece8f2577bcdd41b9df3a9cf5b845ff", // 265
+        "9676d5705eb4717e557ca4b8617bd21cd8b3712f", // 266
+        "db4e8886c46a6b5df16ff16685d5c2eb92057fe7", // 267
+        "d986e36f8e79ccabbb578794e479ecb2727969c8", // 268
+        "30a1be26caba216f56f23409e318228552f39553", // 269
+        "9021070f4f04d649cadf3fba7e2c56a8a3c0d177", // 270
+        "66357b0bc014a60ff73396fce545b946e3bfd21e", // 271
+        "03f25b4dacb927caeceb92f3ae81f352344f1432", // 272
+        "8d6691f33b47799823566feead82078bba2aa3c5", // 273
+        "5c6ecd61160a16ee3c17ba219db36d1f6e75fde4", // 274
+        "fe8d1cdc15d92a991793fc8c0c0c5b2154abed16", // 275
+        "41f384f05f928ce9c749ae65af898df05d399c2f", // 276
+        "fa7e7c23c3d232d6e05f66e34e7a9cb24446c64b", // 277
+        "1bd29d22f96b34c42d566641da76d082458a6001", // 278
+        "fe1bb8a7e9ec1e73c2841ce7f620f73f8e307a14", // 279
+        "a424e2734b51e3f3b44eb5e837f8830d45fda123", // 280
+        "7fcc621bd162c16c0ca1a792c0e1b91bf03fafa3", // 281
+        "538de9e2ef9b570a72c689e2a6a8adfcb6f34dfc", // 282
+        "3127440d13268bb15832b09a1db1ddf09287e81a", // 283
+        "662b8de09589135f5859951f73cd34e1296aa154", // 284
+        "86bd376bf9ba42547ab2bea3eaa6790872e04023", // 285
+        "58ed6373e9609371e155603f2d2acb061c66dd7b", // 286
+        "efde50cf318f5d9e025fa84922dcf616497516e6", // 287
+        "b06ad5207141fb1e1a7316a79dcfa5f3d0272e3d", // 288
+        "854c9169a62c2ec2a7dc38fa22e198f911177248", // 289
+        "b68b36796d761280c6aaaf9cb4f77252510b3a34", // 290
+        "bf7150d68b1de6904e67bb7ed27216751688344b", // 291
+        "ae217cccaf89f686dc35a73d7cbf62a9bad141d8", // 292
+        "dcd420585bb3767f2bfc8fe04dce4780dbe063c1", // 293
+        "35ce3622b47d1c0c07e92d7ef3ffed3ca52f4feb", // 294
+        "e8fd1bc9875520a6ab155c444add377698db626a", // 295
+        "496cc652a84723de0cd2dc1729ba25cbf2fe5f4a", // 296
+        "e6f9eb1e620fa9c22accdb480bd25937358c1edc", // 297
+        "2bbd1b53442fec52ce71eabaf80d9e06d8fc4626", // 298
+        "68eeb52d3cbed49b3658d783d11be6b74b49b363", // 299
+        "62e8dd2685f80c6f77eb8b5b95599a50c48dfef1", // 300
+        "68bbbc378ef7e051feef80e0392c69cff7f68c03", // 301
+        "3e0f1072c11dc524983e10ff047aba50f64a0d43", // 302
+        "bb781c041f93bea57c43afe5cf98d19e2129c367", // 303
+        "bec85f29da83b7d507eac1847e7efa3eeac046c8", // 304
+        "ee2f87e306d49e382dbe91813069f24e1e43b499", // 305
+        "3330700116b76df1919179487a019d04a2110b46", // 306
+        "f647df6658ba1f856c923a94b6e3d7a853154ed0", // 307
+        "4e320291c0cb6464b8534d3f090fde98d3babd6e", // 308
+        "6c26ace759d3c099d23291cd7599c94c1429ee68", // 309
+        "5ce98a52d546051e1c07a5f5c7566cacbde2ac87", // 310
+        "4247adc6e46f42064a851496a2d76a595993476a", // 311
+        "6a60e2fcb1dcbeb8ccd1cc2d7d4ed5a093227fc8", // 312
+        "af2bf54af9c45348797132c762f88464f17365ac", // 313
+        "08a621b3c8f7081f0de69034bb1a38571834b567", // 314
+        "ce01716f409845dcefd040fc8a9a8802ddc534a6", // 315
+        "a357967961804d0ef2637052e8515f75d2542dbe", // 316
+        "89036b99ed85f9d6f5524aa194c1998beec2b885", // 317
+        "b328a988932be90104d6b8292c1f5dff95c30a70", // 318
+        "6931dacb82cb9e92c56765891487a76a18bf9c6d", // 319
+        "ba9d907fcffac90c3ff2121bc3b204a6cfb62999", // 320
+        "63d7d4868874165c659254ed765da6ce0c7af5b9", // 321
+        "edf026edfff0f9c1d8322c3b4e7a61f391a7a572", // 322
+        "ec3573cbe5145b9c0142a682e637ad639da4f99d", // 323
+        "0d5596011d79e7ae5a55957c3aee70881105d067", // 324
+        "f39d593b1223f0f1401fd7a3044080cd143997de", // 325
+        "fce23eb09947303939d1b69d7bae45ddfdd3cd78", // 326
+        "249831b69d84d071f2cf249e825aa95c1f7b2021", // 327
+        "04c7889200dbe66e2103b156bff41c3352381178", // 328
+        "2e7b3284ae2794b27c5b270c4b9e7611a514e7b9", // 329
+        "a534611b796953db3962441bf86f79e90306ee05", // 330
+    ],
+];
@@ -0,0 +1,311 @@
+//! Implementation of the SHA-256 hash function.
+//!
+//! **WARNING: This implementation isn't constant time and hasn't been audited.**
+//! This implementation is meant to be used for Advent of Code puzzles only.
+//!
+//! The vectorized versions hash multiple inputs of the same length at once, which provides a
+//! significant performance increase for brute force puzzle solutions.
+use crate::hashing::{u32_to_hex, BENCH_STRING};
+use crate::multiversion;
+use crate::multiversion::Version;
+use std::array;
+use std::sync::LazyLock;
+
+mod bruteforce;
+pub use bruteforce::find_hash_with_appended_count;
+
+#[cfg(test)]
+mod tests;
+
+/// Fastest supported implementation, for dynamic dispatch.
+///
+/// Determined using a small microbenchmark at runtime.
+pub static FASTEST: LazyLock<Version> = multiversion! { fastest(microbenchmark()) };
+
+/// Returns the SHA-256 hash of the input slice.
+///
+/// Wrapper around the [`scalar`] implementation.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::sha256::{hash, to_hex};
+/// assert_eq!(
+///     to_hex(hash(b"")),
+///     *b"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+/// );
+/// assert_eq!(
+///     to_hex(hash(b"Hello World")),
+///     *b"a591a6d40bf420404a011733cfb7b190d62c65bf0bcda32b57b277d9ad9f146e",
+/// );
+/// ```
+#[must_use]
+pub fn hash(buf: &[u8]) -> [u32; 8] {
+    scalar::hash(buf)[0]
+}
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+multiversion! {
+    use {crate::simd::*};
+
+    // The length of 1/2/3/4 bytes for each lane
+    const ONE_BYTE: usize = U32Vector::LANES;
+    const TWO_BYTES: usize = 2 * U32Vector::LANES;
+    const THREE_BYTES: usize = 3 * U32Vector::LANES;
+    const FOUR_BYTES: usize = 4 * U32Vector::LANES;
+
+    /// [`multiversion!`] SHA-256 hash implementation.
+    ///
+    /// The bytes for each lane must be interweaved, and each lane must be the same length.
+    ///
+    /// # Examples
+    ///
+    /// For [`array128`](crate::simd::array128) with four lanes:
+    /// ```
+    /// # use utils::sha256::{self, array128};
+    /// assert_eq!(
+    ///     array128::hash(b"hwafeobglrchlldiodej"),
+    ///     [
+    ///         sha256::hash(b"hello"),
+    ///         sha256::hash(b"world"),
+    ///         sha256::hash(b"abcde"),
+    ///         sha256::hash(b"fghij"),
+    ///     ],
+    /// );
+    #[must_use]
+    pub fn hash(mut buf: &[u8]) -> [[u32; 8]; U32Vector::LANES] {
+        assert_eq!(buf.len() % U32Vector::LANES, 0);
+        let bytes = buf.len() / U32Vector::LANES;
+
+        let mut state = INITIAL_STATE.map(U32Vector::splat);
+
+        let mut end_marker_written = false;
+        let mut bit_count_written = false;
+        while !bit_count_written {
+            let mut words = [U32Vector::splat(0); 16];
+
+            let remaining = (buf.len() / FOUR_BYTES).min(16);
+            for (w, chunk) in words.iter_mut().zip(buf.chunks_exact(FOUR_BYTES)) {
+                *w = gather(chunk.try_into().unwrap());
+            }
+            buf = &buf[remaining * FOUR_BYTES..];
+
+            if remaining < 16 {
+                if !end_marker_written {
+                    // 0x80 end marker after final byte
+                    words[remaining] = gather_remaining(buf);
+                    buf = &[];
+                    end_marker_written = true;
+                }
+
+                if !bit_count_written && remaining <= 13 {
+                    let bits = bytes as u64 * 8;
+                    words[14] = U32Vector::splat((bits >> 32) as u32);
+                    words[15] = U32Vector::splat((bits & 0xFFFF_FFFF) as u32);
+                    bit_count_written = true;
+                }
+            }
+
+            state = sha256_block(state, &words);
+        }
+
+        // `state.map(|x| x.into());` doesn't always get vectorised
+        let state: [[u32; U32Vector::LANES]; 8] = array::from_fn(|i| state[i].into());
+
+        array::from_fn(|i| array::from_fn(|j| state[j][i]))
+    }
+
+    #[inline]
+    fn gather(buf: &[u8; FOUR_BYTES]) -> U32Vector {
+        let mut values = [0u32; U32Vector::LANES];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = u32::from_be_bytes([
+                buf[i], buf[ONE_BYTE + i], buf[TWO_BYTES + i], buf[THREE_BYTES + i]
+            ]);
+        }
+        values.into()
+    }
+
+    #[inline]
+    fn gather_remaining(buf: &[u8]) -> U32Vector {
+        match buf.len() {
+            THREE_BYTES => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], buf[ONE_BYTE + i], buf[TWO_BYTES + i], 0x80]);
+                }
+                values.into()
+            }
+            TWO_BYTES => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], buf[ONE_BYTE + i], 0x80, 0]);
+                }
+                values.into()
+            }
+            ONE_BYTE => {
+                let mut values = [0u32; U32Vector::LANES];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = u32::from_be_bytes([buf[i], 0x80, 0, 0]);
+                }
+                values.into()
+            }
+            0 => U32Vector::splat(0x8000_0000),
+            _ => unreachable!("less than 4 bytes left"),
+        }
+    }
+
+    #[expect(clippy::many_single_char_names)]
+    fn sha256_block(state: [U32Vector; 8], m: &[U32Vector; 16]) -> [U32Vector; 8] {
+        // The message schedule is computed in place, only storing the previous 16 words
+        let mut w = *m;
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for (i, &k) in ROUND_CONSTANTS.iter().enumerate() {
+            if i >= 16 {
+                // Right rotations are implemented as left rotations by 32 - n
+                let w15 = w[(i + 1) % 16];
+                let w2 = w[(i + 14) % 16];
+                let s0 = w15.rotate_left(25) ^ w15.rotate_left(14) ^ (w15 >> 3);
+                let s1 = w2.rotate_left(15) ^ w2.rotate_left(13) ^ (w2 >> 10);
+                w[i % 16] = w[i % 16] + s0 + w[(i + 9) % 16] + s1;
+            }
+
+            let s1 = e.rotate_left(26) ^ e.rotate_left(21) ^ e.rotate_left(7);
+            let ch = (e & f) ^ g.andnot(e);
+            let t1 = h + s1 + ch + U32Vector::splat(k) + w[i % 16];
+            let s0 = a.rotate_left(30) ^ a.rotate_left(19) ^ a.rotate_left(10);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0 + maj;
+
+            h = g;
+            g = f;
+            f = e;
+            e = d + t1;
+            d = c;
+            c = b;
+            b = a;
+            a = t1 + t2;
+        }
+
+        [
+            state[0] + a,
+            state[1] + b,
+            state[2] + c,
+            state[3] + d,
+            state[4] + e,
+            state[5] + f,
+            state[6] + g,
+            state[7] + h,
+        ]
+    }
+
+    pub(super) fn microbenchmark() {
+        let bench_string = BENCH_STRING.as_flattened();
+        for chunk in bench_string.chunks(32 * U32Vector::LANES) {
+            for len in 1..=32 {
+                std::hint::black_box(hash(&chunk[..len * U32Vector::LANES]));
+            }
+        }
+    }
+}
+
+/// Convert a SHA-256 hash to ASCII hex.
+///
+/// # Examples
+///
+/// ```
+/// # use utils::sha256::to_hex;
+/// assert_eq!(
+///     to_hex([
+///         0xba78_16bf, 0x8f01_cfea, 0x4141_40de, 0x5dae_2223,
+///         0xb003_61a3, 0x9617_7a9c, 0xb410_ff61, 0xf200_15ad,
+///     ]),
+///     *b"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn to_hex(hash: [u32; 8]) -> [u8; 64] {
+    let mut result = [0u8; 64];
+    for (chunk, &word) in result.chunks_exact_mut(8).zip(hash.iter()) {
+        chunk.copy_from_slice(&u32_to_hex(word));
+    }
+    result
+}
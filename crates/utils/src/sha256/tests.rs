@@ -0,0 +1,2706 @@
+use crate::md5::tests::STRINGS;
+use crate::multiversion_test;
+use crate::sha256::to_hex;
+use std::array;
+
+multiversion_test! {
+    use {crate::simd::*, crate::sha256::*};
+
+    #[test]
+    fn multiversion() {
+        let mut buf = Vec::new();
+        for len in 0..=STRINGS[0].len() {
+            if len > 0 {
+                for s in STRINGS.iter().cycle().take(U32Vector::LANES) {
+                    buf.push(s[len - 1]);
+                }
+            }
+            let results = hash(&buf);
+
+            assert_eq!(
+                results.map(to_hex_string),
+                array::from_fn(|i| HASHES[i % 8][len]),
+                "length {len}"
+            );
+        }
+    }
+}
+
+fn to_hex_string(hash: [u32; 8]) -> String {
+    String::from_utf8(to_hex(hash).to_vec()).unwrap()
+}
+
+pub static HASHES: [[&str; 331]; 8] = [
+    [
+        // string 0
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "ca978112ca1bbdcafac231b39a23dc4da786eff8147c4e72b9807785afee48bb", // 1
+        "fb8e20fc2e4c3f248c60c39bd652f3c1347298bb977b8b4d5903b85055620603", // 2
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad", // 3
+        "88d4266fd4e6338d13b845fcf289579d209c897823b9217da3e161936f031589", // 4
+        "36bbe50ed96841d10443bcb670d6554f0a34b761be67ec9c4a8ad2c0c44ca42c", // 5
+        "bef57ec7f53a6d40beb640a780a639c83bc29ac8a9816f1fc6c5c6dcd93c4721", // 6
+        "7d1a54127b222502f5b79b5fb0803061152a44f92b37e23c6527baf665d4da9a", // 7
+        "9c56cc51b374c3ba189210d5b6d4bf57790d351c96c47c02190ecf1e430635ab", // 8
+        "19cc02f26df43cc571bc9ed7b0c4d29224a3ec229529221725ef76d021c8326f", // 9
+        "72399361da6a7754fec986dca5b7cbaf1c810a28ded4abaf56b2106d06cb78b0", // 10
+        "ca2f2069ea0c6e4658222e06f8dd639659cbb5e67cbbba6734bc334a3799bc68", // 11
+        "d682ed4ca4d989c134ec94f1551e1ec580dd6d5a6ecde9f3d35e6e4a717fbde4", // 12
+        "ff10304f1af23606ede1e2d8abcdc94c229047a61458d809d8bbd53ede1f6598", // 13
+        "0653c7e992d7aad40cb2635738b870e4c154afb346340d02c797d490dd52d5f9", // 14
+        "41c7760c50efde99bf574ed8fffc7a6dd3405d546d3da929b214c8945acf8a97", // 15
+        "f39dac6cbaba535e2c207cd0cd8f154974223c848f727f98b3564cea569b41cf", // 16
+        "918a954ac4dfb54ac39f068d9868227f69ab39bc362e2c9b0083bf6a109d6ad7", // 17
+        "2d1222692afaf56e95a8ab00879ed023a00db3e26fa14236e542748579285efa", // 18
+        "e250f886728b77ba63722c7e65fc73e203101a84281b32332fd67cc6a1ae3e22", // 19
+        "dd65eea0329dcb94b17187af9dff28c31a1d78026737a16af75979a1fa4618e5", // 20
+        "25f62a5a3d414ec6e20907df7f367f2b72625aade552db64c07933f6044fc49a", // 21
+        "f69f9b70d1c9a5442258ca76f8b0a7a45fcb4e31c36141b6357ec591328b0624", // 22
+        "7f07818e14d08944ce145629ca54332f5cfad148c590efbcb5c377f4d336e5f4", // 23
+        "93b0cabf8668e0c534c52a568957499e12a284f59d97dc9b2725ef836804875b", // 24
+        "69b980549d5045969285133df773ae91ddd5d0e5c73dc8ee959b2eb223bc5fbb", // 25
+        "71c480df93d6ae2f1efad1447c66c9525e316218cf51fc8d9ed832f2daf18b73", // 26
+        "3b6bd6f4ac0e5f0adcc69f8db8b060b3287d5d1248cfc83284d1e118d2ef04a4", // 27
+        "0564ec396d699896c7ff433995219110a0808636711497cc4116acabb38aaec6", // 28
+        "0535567cac40131191d11d06d45e2c71dfc56afcea1f6b6b31c8df05807143c3", // 29
+        "22ce81c762374e2766fd65bd61b141f6c875f9f15e9f912db95ff46f97a3bea9", // 30
+        "1d738dcec318e4ba9fb44d763834a357ce10fc862791fee99489b8731de9d7cb", // 31
+        "cfd2f1fad75a1978da0a444883db7251414b139f31f5a04704c291fdb0e175e6", // 32
+        "6a5068cb370322a7ac8028655dea6e73c4bb1d0d87ce63edb033b305ea37354f", // 33
+        "63f7bbffd7e9cc1f14f6569f004f69c0c617e129d41385370224339f21c8b913", // 34
+        "0e52673e4e1283ba5c620e3ec5438abe5771e98eb9f03cd346e71bb321b79c04", // 35
+        "fbe5c1c91fefaf94fac96ff624246acf9ab474ddeea685c070cf778687612b1f", // 36
+        "33e43a44f1527d040a7e962da99b7046c76b5208b7e5a78f5629f4a78b767a9c", // 37
+        "e041037867ad65172719f3d1627eb298babc3a7ee6226e12257dc3757dbd0170", // 38
+        "56ee6c80d1b48e4731fc8e69815298667d87f331ccc1453a5afec5d5fd072fc3", // 39
+        "08b14f5392d7acf18d7613f91b41ba279d5a3e0758877059b5da1da18b939817", // 40
+        "ac7e83c04f7561919dae20e8dbad34394107a0aa4d23b6351cc96751bcdd2c4c", // 41
+        "1005ee30797de0b279d16a6a541a348eb56dfeeac764c0a1fca48a5f9329ef10", // 42
+        "46a2199782c8827f0ac56f503be9d39efee97f40a736b92cc7d7c5f825cfd851", // 43
+        "dcd70351e9d104fe1abb29c39803a4e2cc6ff24da89847ad065c82279743b1a9", // 44
+        "fe4835b016d6fc7dff1579845cad0418df7886e8d048e75c7f59822881b46a42", // 45
+        "4fe67edd89579953d2da8bfc2182e9ee859cce243e8f01898f992c35fcf88888", // 46
+        "2ee4894204420788e7a545e1c12e9f1c2f40719ef19f288caea020eddebec1ba", // 47
+        "f6beefcee3822f0f5c29ef73eeca34132b6a9243c8a5dfa2d1c6b806e4365bc6", // 48
+        "52ddd33efd4d514c6e453d15f11169ff304044c2726dbbeb64263e8f5aacca7a", // 49
+        "8b645d0bd6201363a6e7eb54b8d86c391b19fb099a2484e34cf21dfa795f0554", // 50
+        "d155bcb2a03a47540715b055cd3a5558622e5563a545006cdabbff710158f04f", // 51
+        "3964294b664613798d1a477eb8ad02118b48d0c5738c427613202f2ed123b5f1", // 52
+        "eb88de1921c84b5353092fae10dc470cd5986d9819bab6e91c7bcc6ef88b67a2", // 53
+        "b76d92c062a5de1e755f42b24c4f9e9d8e5f0ee9fae08e36eac4c5921d448653", // 54
+        "d74ba075e4259c6c807c4101e66d281096cf9ff14ba01260dee741b1bdaef326", // 55
+        "8fb605eab2efae3d1fcc881fa5c5dd6219a17ca3663e46642ff566847c24c272", // 56
+        "f657700bee98bf60880401a6ea1e6e32fecc61cf4e22dab560f58ad30e001482", // 57
+        "bf1b8af8130a8549a0b26332678e532f46f989d29c61cbd398c3fd9b62e6448e", // 58
+        "60d0ba2d3510c243f1b619dac382d6a7dee50eb02f871e59c1066f728c7bd802", // 59
+        "a5c1354c0ccb753a33ba6978bf250fd8d253056481efe74e9661980ae1766751", // 60
+        "a1175908618a33d8783da0186c7088878ba8edb95aee2ff6a3165d99e80e16a5", // 61
+        "540363d1071a002997290cd8f4a2bdf3acd0355ffad3b3f25f52aad6ebad936a", // 62
+        "a662353085a6a20e4fc9bc74efaedde70fdd54566cad0be35cb318bc4b4425af", // 63
+        "fa443f246563d77f1f47c322251159e32a357005bf6bfcc618c3c905e849cbcd", // 64
+        "8cadd3d35d1b064d507bb344c355e65d4c720bbd5b038a983aec1744c6687d45", // 65
+        "09d05b338c2489b24767385affcf523d207c03f49d5edf41c16a41fb9821f27a", // 66
+        "183919a6f3b8cabca244a3f785b8390805cc5aeda99b8666fc55fa98cde58184", // 67
+        "d446c8bc6b0ccd313626c39e25647ad87fed3642f2d69d21f231d65259d977bd", // 68
+        "eac1d122066642b0ead70aceff1a089c7921dcff209ee276100371fbc32a1ab6", // 69
+        "7cf82b439c1e96b969c6d064729137bd0b45d0b4c70256b0688f57981fc3a0ec", // 70
+        "8d625b50ff1e9118aac239083e26530f19284798b274bfb9d4a07be92967886e", // 71
+        "676c8397f9033da10028c7bbdff2127f4fa4472dffcb16fa6bd1f3afcdd9cd2b", // 72
+        "4fa320a7142eba137620ab95b290f15d5ce085905ce08462193e9719b6f56cc6", // 73
+        "989e2b62b7ed0f17df69c145b81c4618c2f05582e6d3c55389dbe41b42f10d12", // 74
+        "3cf15e995414e352abe21662e8711a2c05c71df0b9905c27a6d0c7ec1270d329", // 75
+        "8a029c7fd3b59b611cf8757d10013dd3d3c89ae2f333f928c624e0ed9436a0c9", // 76
+        "da9eca781bd7041716532bdd77b80a783f081bcc7096713be807edd33295f146", // 77
+        "60a20d6b37562a0e99df133558ad3dc5834f42256e1f3424482874015175e64f", // 78
+        "b49238dda18625955138d85fe46f317372ca8a8f37a834cbd62043733897436e", // 79
+        "14ec7470f11d6350b554a33f2b3777d3e647a308d71df2471b3f8838722e1737", // 80
+        "4c1b7544c9a92df1fadccdbe787b8d47b9a14970de83f54a7b804c1ac3434f0f", // 81
+        "060fa3341cfc593eabfbd8470bb5eccc0acf818d8a9494c2138a6f1fc00f6cb9", // 82
+        "ac45ddec61d0354a742e65e4f16f662715eb3b15b53a93d22af8d7227505996a", // 83
+        "4a15ae722740a30fba678d78a19d0343a43ff04f025e1b92a8e78bb12b271ac8", // 84
+        "7d857c98a3ab88a91de89b0e05c832d45c4c81031cdf9d9698b80e4eb68e284c", // 85
+        "8f5269ff82f10c41d9a0b846ef18b5fdd7546da1d4d26b21d29204f85b3fab1b", // 86
+        "228e2bf44d9fe3a648be0438ff8c72febe6503913c2edbcbbf6fcdaec74796c2", // 87
+        "629656334718eda8168bce209649aca5bb584eac718b2764b1bd696a0c4d15dd", // 88
+        "598837ca3aa61c322ca3ee8aa2e218d4ec5aff50dacc931c22396a2ff05cd09b", // 89
+        "a790ea94808ab764feee55bfdc2e27dad29ac4367fab14ac5d100249299a1b3d", // 90
+        "2e190d561818092a11aa9bf852d08c3951bb0f4ac43df8fd14c7247ca226e01b", // 91
+        "eeb16c37c7eb1cf06bae705413bdee114b3836215e7a1c5a35de6f9d70a9824d", // 92
+        "0805d90e06cc583dbc4acc70239de9eef97393603811423ba3d42877a2b8c7ec", // 93
+        "55873de5ea82add79281253666a4e679d21d805bf2789fce198784d7ad40aab3", // 94
+        "87cdcb4f55ce1bcf747a3c39c6117b3019c4ae54d58bfc9e503ac0502bd6f6c8", // 95
+        "39a46e3763764d91a7e605f29910a87f1ac5ee0f6a8fd1f0ab7b74091f7c913a", // 96
+        "3c5a57f14b24d6478c29ad2429a02e87cd7f3f6d2ee97fce8fed70a322c518f9", // 97
+        "fc81553443abe581d175d4070f287b7226907636c63ff3f0c05c592a875d5e93", // 98
+        "2e59f99bd4a4e21d76d4bb6a13735e3f99589cace128b45d1f7c258a1d6d239f", // 99
+        "72ab0f393a4b635f2dccc18a40f42f5d9c4256c2f33a7f20c596e1dd6e6fd17d", // 100
+        "72d755aa06f09e50ef2e28cf473c3b116f77e3ea32d5ba31eaecb7400576cc56", // 101
+        "edac307a4853a32d855c99babc92cb53db76cc403721e9509e0e46868f8673d9", // 102
+        "bf2760e55abf55ee0d54bba1828718ab7fb03b4d4335de1e1422c1e08cea2b2c", // 103
+        "b6cbef24a2092449bf2ee197dad3df3cd2e9567af8a75a3ae5c061f753de1ad0", // 104
+        "31964db30ab8f29927666c235bc44c425e0a1aad20f0b3126928f84791386a5b", // 105
+        "09b3498a512e19203726e10d7eac95b37836a7de6fb3faa17c7a3df5edefdd26", // 106
+        "90ba8fdf66ebf8df0b2f14f852e0267249d8f23d5dfa9075ec82a043f9507523", // 107
+        "66365825c3611fa87520a42cf497579bedb5f1859ddc4fab11c4d5c7421b4d95", // 108
+        "fd1ff957ddb04371c56719fac5d19b803b665bc6757a0039f4ca6043ee627ca9", // 109
+        "735b54958296487c9e4519e817f042988170702901b49802200cef0e56aa0aa2", // 110
+        "86fcdf1b1d2dfea0ac1fc9f24993b13c1ce7fe116a713bb7b40663515828959b", // 111
+        "cc9def5b749d2ae0320b8f719a377f1e917747f2d9edf17eaa645f9dda286dea", // 112
+        "ddfcfaef19fe49929cf0d1afba23f9a649410b69d20d2e0c0d5232f2ad72f130", // 113
+        "45720f3ca77e3657b065ece528dbe1a54655f80d2813407e06bc4a4f6aab8b17", // 114
+        "b57a6e628faaa75870dbbb3add7dd44e4fbabe3b7490ef3c2ebd5c271f8a04d1", // 115
+        "d9ffde11171c961a49e71ff923f223e9521471a86b375d1729c4ec00912b5123", // 116
+        "6dfd7a1f972ab36cae3b034bacb61e71eca17ccb27a0e568bd6a6a4b4fca325f", // 117
+        "ee4b84c3e421dbf68e086f4a67c9a5729e6586f0e07d48aac9f9c44c22b8000a", // 118
+        "ec9f8fe47057035a9ad8135150a519210138144b8f9a639d553c487ecd300059", // 119
+        "15afa711c8fb60912dc0991ccb5932b38e198a2764102b8b2687047a32fe4861", // 120
+        "f1187c9e9943637f07024ad98c022bf68157c18bb8ebb88879c7edfee9671bdf", // 121
+        "5b49b66b5e559e3a0bd4977bcec4a586646e665f590601897539571e5da7de99", // 122
+        "5ae6fcef7dfcc453bd185fc6630366213c8beede107ccc08b9b9b6287e297429", // 123
+        "7050918ad9dd7b964492fe5d4220d07a230f83aee4b4f97ab8278f6c0c26e915", // 124
+        "6a75d22666a487f4a3255fb241df5637873831a050457a0cc8c1180e40eca67c", // 125
+        "a47621d20f3f35e6d425e1046920e9980ae1df18fca53838aedfe176f74221fe", // 126
+        "a4d557a75bce420e71138bb3586b173b30c8ea606121771ceefa5674c0a75b8f", // 127
+        "b43f5f36846c7f6ec5fbb587e29bfad6e0630aef833ead33bb07664a714d3aad", // 128
+        "7675c50b10dced8f0765b248c6715604a958a65c855bae921fbcaace14876c81", // 129
+        "116a7bd106e997c249255301d8d452d1dfa381673a1dac99ed8a6f30697670ee", // 130
+        "6b6b543340d009a0a6d66486fb5a54fc8d4417ec74c2b340e306204a81bb6c64", // 131
+        "ae571d7faa220de1b2cfcd8fd1c3b84c6048309d0e7fbe5dc44b1375f28201ba", // 132
+        "fba7ae4e60b68be3913ef2d8671bb88d8384cf9d8649a7317133d535c9ad0676", // 133
+        "0f47f30353eff301dacfc7b5cb863b9c8d333653f6a7199fdf4db87b390e0cb0", // 134
+        "e4786ebcacbc36a7340a4c97998a220dc29d39960b299a9204f6627b71ce91c1", // 135
+        "a701109e9ebe210edbf62ef165cf85df35e4dbbb84b468c6dd0229362d82e872", // 136
+        "831776c467637344a7e272357dbcbc22ccbd0d38ca086f680f26ae74af3d1543", // 137
+        "6ec7c38f20662cb8a4547c1ea46695c33b9df5cd8a76e0a86b2e04a3c7d24300", // 138
+        "0bb69b8d27367356faab53ad00e38cc051c06b99f1984830d7d958b52f2be89d", // 139
+        "00867166349e64ebadeb84d9a857ae8dbb33e894e3e68f78817f42ff8ac0294a", // 140
+        "38bf2395a218cec4de6575c14a3548781ec0377a5e0756065633b60884b0eeef", // 141
+        "9043f9c6c783eb8326358cf14c803d8e4271a4b109ce1e8f1bbc328cda9d9bfa", // 142
+        "a0e608a14dbc1cfa4ecc8458767ec96fd6dd97f4249179d7c19fc4fc03d47095", // 143
+        "4018b48e59fd014bcb805f77e1ace2dba465306029bf06fda9ebc37124f25855", // 144
+        "792be024282f48489e65456d1a858f65bb7fbe050a33053b950b761a38b3212d", // 145
+        "960b66c642b4225b901365becf11234da0dd8839e8a86412893a7431f6f9cf1f", // 146
+        "a1fe46241e6dac4ca5034673c2ae3a65476ec09bb153924ef32f2cc0bbcb0255", // 147
+        "b063cf54f87a93a96381e8a3a195979d23a003ca68f4c49aae706a21d79e8eba", // 148
+        "24d4b4756f310bf36bd9e942f964fd4fb3ee08868fa37840ee369baa53e3882d", // 149
+        "74f0beb1d1059b05497490d9a01b8ee2041c8c1bb9c892b9dd756342070805a5", // 150
+        "2956dad3a86750a41bde37aa8a249bbf151f33969baf0b364fc268319c0857eb", // 151
+        "b59ea69af48e5288a7a119879e8e5900920987cc736fa5effec999ec2062e093", // 152
+        "e621f5bd3336706eeafb4668aca7ba0055923ea6ab03a09ab2bb67a34f3dfb30", // 153
+        "4dd0e9c99bb8e6943a067bd446f546296dd0d5c92eaec25ecc3820b5f5ea34ff", // 154
+        "5074aa4bd65b3b8b810dc553a196fbcf4643c48145445aa75332fca129e3416d", // 155
+        "49ce3b5d2a1eb59641d3eced8835425c3262d33271d893a9334970ee375b860b", // 156
+        "f5a7d4349c2f138bf549d7bf0ba8c5fab0beef820c047b4d8af84fd1b003fe52", // 157
+        "93472ddf176ff2991ae50497a195efe2b9d029b61c2072faa386a1934984ca5e", // 158
+        "9b9f6bfeb1abe16add4e5a43b5a7f69a65a34a346cd7cc26a0dd37fc012fd61d", // 159
+        "6d838cf977158c796bc862741768355021e014cd0bfed5bb81d377bd3b1d05ad", // 160
+        "1ebe2e864ae149f85e9129254184553921c26a1d9f270f3094bf704148bd2523", // 161
+        "65f838127ab6f00b8c5a05a5a09ef3444ade239a29a7111ee239c939b8f0295f", // 162
+        "116b54f7715daaebccaafaf1c10ffa9644f95b511f644b310c554540d4ce1d67", // 163
+        "5691fb8740fdf9b5ebe2a5137b876ef2bdb16ba9fb65caf5c74e617b614de635", // 164
+        "fbc92d86b6757c8d583e1ae6863c990ed912095c55c3f1f0d0b3fc65fd3e6561", // 165
+        "ecdee245e6cbee864e90309c7d2d5ebba6be5ef76ae4974855ed4456c33b221e", // 166
+        "57d0682fd19528f9951c0b16395797282449639585cee8b4c874d3cd3e317e3d", // 167
+        "709ca7d0fd6b65a6df24576a4e2c1c0ffcaeefb5b967e7d39eeba7622403ee9d", // 168
+        "87f42d3589274b01b49464bd86d22e749f413ce2cbfa865be3b2bcaee45e163c", // 169
+        "3257442571215b279e88cb6b9453f7959595b0c65afc73a1e89dc38c26b6f512", // 170
+        "7e93a6f45b6a887cd43416613c15d2850d54ff10c8569703d74c6cd0ec7bb3a8", // 171
+        "2ea43f42062d95d650ca5d4b0b9063a3fff8fcdd8acb3bb7243afb46873d180d", // 172
+        "0791ed13f2de9ec6732eec0282631cd2f136af36ef7f0e99d2c7d8a51d3e02b5", // 173
+        "9944df8724e03dd758e449c1061f0d87fc972edcf15514298fa0386e78a6318d", // 174
+        "e9fa33117200767c85619f92877d2eebed87e4223b7a65c1ec41fca3794e22aa", // 175
+        "db4d1afa75a532916313ac54df425940f9b059c0a8ee7e1691a100a6ca64583e", // 176
+        "ef2bb1a6fe60009cba641071d6be34fc8d294c403d84cc0504dfc57b603e310f", // 177
+        "04b8b0ea35f26a857f3bfa9048dd4fd53e4cd648a4b78a51037da6e6255d1094", // 178
+        "8ebdcf6864af1c17d3f78ba0509daf8658d272f3a2b78fb1ab89aecb3c0812a6", // 179
+        "3a06af6d49e6dacff05f7a3484cc386ffd35be7e533f9763ab96e1c0acceee44", // 180
+        "b4b4dfb389d8bffcbd9c47635a05e98df489f44f1327b5c1c519a2ad277cdcb9", // 181
+        "8273dc534a2255d511755b4f5ec6ea30267a786e37b02b594107cd6595cb1523", // 182
+        "3c712f22813780dec151dc6df1d4a96513b03278c867c15a24a3c7d9d7d9288f", // 183
+        "1822cd7eccb285ac9c29e1843ce1ff1090ebbd67c67a4f7f3bb2564a870a628b", // 184
+        "f954409ffdbfb3d0a04b66d87008a1978805135f89fe5d07a6207421d31c212f", // 185
+        "ebda3dead98666cf7ffffb3de96279e57810a1d9ef276a739c3c83edd7029d5f", // 186
+        "edc45c34a7c1d2a1dd5f93eddcb5627a501e21888ab33df8e3af9dc688515745", // 187
+        "05b8209a555fef95e3a35360457a8e9974c23083b4babf983882f2889ecce976", // 188
+        "e5078adc6815ab785e938d7d713a89444b787ec27a22566b8f6d14de781a1d62", // 189
+        "b41038356c0f036ce60eca107aa8575c69afff51a7170ae76a2caa4b862279e3", // 190
+        "7efbff00a92eadc0733e1d0e7479e758f2745f55a827a3ab57f92ed1d4c76051", // 191
+        "d6023853dbfaca7c85e411516113ab1c66b7c3713677a3d76fca7ec8061029cc", // 192
+        "4a5e819502f8e2b35987ef9592299829fd77d8edb78b917e3123c3b0377ea68e", // 193
+        "fac00dc43b29d271e0a67b3721172f7b24ca5bf3e01e78cac6d232ca6405f3b6", // 194
+        "c3b761fdbb08ecdc3034466f096ea3c48d2da9fdc981487e0e15d8f85abd0b61", // 195
+        "050c96a136e6a1421822e0dad086c3f400652900a276b1a4575937f503c42953", // 196
+        "ec0d5ea833d88601cd493a2f3fc9ce9b756a683899cde4f888424538d596d013", // 197
+        "ada5e5d155ba02c027cf393d51e12e0489f84c4d1cd793afded82148f4767bc6", // 198
+        "c0fcf8519cab737ae889a8dc2cc9743409dde1f5945cf2a81f806e1c44f42b01", // 199
+        "0cd1f95d3a022d1e749b1116a229276d507d7c59e01349cb88de565463534be8", // 200
+        "3d77cd7e1543b7f41806cf3f2facf37ecd74d2cdee9a2454cc100ef9f1770d8c", // 201
+        "0cce86d01ba426542b55542b3e1dbb4d776cc26316397f295c01ca0f5ac92cfe", // 202
+        "b849f2f84fb243b70333643f41eb5a4f57fe21760197b92654e19153071e2216", // 203
+        "86db39251e05807bd85e357e369c03188fc8d1c5721e9e5ece1ec2a0def97702", // 204
+        "78ca00aecbb106a8e218cb08aa47969666b57d0454981f0f1ef88dbdfd02f316", // 205
+        "7efc3d2335196649fcca88eaf643fbb84909e6322a3188a9a3f11b10d5b89ce4", // 206
+        "fc24fc11a27fd0f37f37b6d56f5226fe31dfaf6d9f17d4cfae707abe0cf6875c", // 207
+        "104a1eb77d696400b58809fb970ebba3016567c6d2365312826245d7de6a3e7d", // 208
+        "040f86709364aad21dbc561c4b52a44b9eee98dbd654a9d8417631875afa1007", // 209
+        "06bea539bd8fc3c918a10a152c969b31045af1fc6bbd11a71803148e1915f72b", // 210
+        "4ded6570f8dfd902f7fb83625e4847584cec226c07ee1f570c9bdc1f765b6bfd", // 211
+        "d70f4f0dea0e055ddc3f3b9b195676cbd728b5c33ee326d019762cc54f7d40a2", // 212
+        "4c62460aecd804613c2618dea1ff202ab11d1b7e20ad9be19a94b6030f75d7bf", // 213
+        "596d07ceae4c61945f7ebec9efa664191aa7af497601b1e29ad14f5186b1fd25", // 214
+        "39b8601fad807069f657a6f40e16fdc6bf172cfea5104bb1b0fe806ec33c4a5e", // 215
+        "9e5ff4baf07bcd5d597deba535c5264caa5761889a881934788cfe2465501bd7", // 216
+        "c67bec169d860e484201fe1894879a617dce86287d5a01499870903f03462349", // 217
+        "818259801c60d339137378e5526721a66fce568632557aef11a887a9caa64bcd", // 218
+        "31a0b9da5ea3d88b903754fe511f9575f46c2dcf819d0b81f5e758c39138219b", // 219
+        "a9cfe76bfcc952ed3f066bad900be849428a962cd400e7ee8356b44fa7aced1e", // 220
+        "1662baf2621b80acd05cbeba8f889ef69ef0217a741f9abdc8a35d144d8e43aa", // 221
+        "b494db72ddd4a0e6cde9342b267e79ae5649b81214b2b47e20a1f71c34c6bd8b", // 222
+        "0d8f40457a29b2b091f1999f6d1d672098d4e3248d2d3213a427075891010fde", // 223
+        "604c95a4ad7bc7a9116298d3b183a0b29183899aeca0c88797f8307886a024fd", // 224
+        "d7ee7bd617322359435d1de051a83f0a561c0f4123276ba95c37d909483f482c", // 225
+        "dc7c04b86a0bdb262c830f54bab1ed9a4e77a81f04422c2217994939b3669c69", // 226
+        "869c11d25f9dc1c8991be4f1dd7abc175765405f8e90bf504c744d03ebc49255", // 227
+        "4b9545ef74369fd38961fd36e266ea10216e52ba0595289c453d3c03fee43001", // 228
+        "5f98174bee79089885d20825ab4490ab1dcaa60259471b1121e53584e9ace234", // 229
+        "1694498c9dab7f2b0223e67c4e721288c3e9cf9239dfbc2040ab54faa20bfeed", // 230
+        "1d27e445ba3579113eacc375d848e3f4162cfe7c57e0282c70be2fc290efc7c3", // 231
+        "67d3625bd35c2073a0f5ef9ca4514b4432d1b40868e8731ba6777e5672d30705", // 232
+        "2e3ae772ef396632a16050a772e6981341c9b2914809d7b3184cc48950a9254c", // 233
+        "7fa0d2c9cf29c8f8d36a524ba4bf75c31193cf4b7c64a0bcc55dc93e98722f83", // 234
+        "340bd29ba951fbc5be11be66f0e28a1b1da6bcaed8d15ea842c39e88a0d294b3", // 235
+        "ab42e4ca1bbaf9349c1d64f2759c6e6a0b5b551ea6260949970498e9ea2780bf", // 236
+        "06e30164844d8a2aa75afcba3435d67384a61c224b08fbd005ca7534e51e53f9", // 237
+        "8bec261ee9e67d5f0e9cde5ba55222ca9dd8240554ce24c1422cf2061577aea5", // 238
+        "4e6ca7af08bbbff0159411e0847670758aaf2a9324bc60ad7cd08fd4f0e6134c", // 239
+        "11504c77122fbb7dbbf3ccce4e19c07718f773bb5eb5db4f0a89d9d0a24092a9", // 240
+        "7f07935202a1d084b04afc83f5f14a3f43578e3b99b12f6e2d792a6e114d37f9", // 241
+        "5c6428a1a4a41df840363e439dbbcad22bf32c671ef466b65437ae5462e8ce48", // 242
+        "7846e25fc44e0aa9e186f2acb21819530eb3e7ff0a817229d3e8df00babd8aad", // 243
+        "01822acaa38e646f5ad683310b40dc526108f60a0f9f8e6af3137543e289ea00", // 244
+        "f07509cd0569a4143b84e316f885b8b5e1d96e7cd242a3c860a4a329b01518da", // 245
+        "6a17301e8057d968bb79a999e71242aa376c8b694dfa5e7ad137e6e941ca71c2", // 246
+        "11c7c6b1e7c232679096b80eca117442f0587809c7f148da4286bac496725efd", // 247
+        "b5b87e9846a5061e0fb92df6c7a883aa9633332fa0e959d607348feb35301d71", // 248
+        "75b395138d4e6c8ac79011a8f775b7e32374183aca892ea11f9a459b88e39c75", // 249
+        "deddef55134f8b3eb5f60afd116ad59f3bb3caddb0065f9e207be75eae433421", // 250
+        "70a488c11e2b44117f1773cc665fc53b9a8f8bbceea9dcdc5f22b02e76b4b58b", // 251
+        "b00082ab884a92f505a538da75055cee33df85ee223fcb388262b1a228d454c1", // 252
+        "36e6fc67932779bae59a2b61cbed6dee0c601b11234dae405b1fbaa755d00923", // 253
+        "bae70c222d578f78890943f62d98dc64c824b35fea8ce0506833c1ae38824b05", // 254
+        "ae71ecd0bf2debdd8676ef17015bd7319be47ad5b791e5c0fa55bd0f68a11360", // 255
+        "bde85ae9a610148b7ef71f29506636c5567c12266baea0dc2c5c0cc442d00e47", // 256
+        "bd1bd545caf3e55df2be0aeee9863372789b70fcd74ed5645d17b9f11a8eba6e", // 257
+        "17b30512395a6f3de823da9031151c596d99fab75a410dea43b084df64ae9c8e", // 258
+        "cb82a5c4ce22db8a35b9e29b84c76ffe6e11f3d73f6f1f4f7195007b2e4b83fa", // 259
+        "2723e0dc7a48fff5f13d8beafaae6613dd153e5ce78a1c2b1a2547cf44bd1895", // 260
+        "722b8235c448aa6ea2166d3b02ca70f91b58dbc2815bd683bb4ee88446ccc7ab", // 261
+        "75288f6061bf64ce8b4b0431a7d5cb3d4c4f8812bcc0812ab905df9d466b227f", // 262
+        "3ace38fa1a98d0f745ecc3d607c1d0cb4a6b3f0c6320e0ba2c143b58b08138f4", // 263
+        "a5ef321216920e348ff83a452e30f0b768e50fc1ced15ae1c5e72bada783d63d", // 264
+        "54ce04949b9e74491569821ad34bd5484e869592d7c21dc82b68196448211b53", // 265
+        "d511bbce33051677dbdf0ea7eec03f1280bbd88720fe258f949fbd78daf26298", // 266
+        "8ae011937af2639921c0bc9a36f77d4756ad50456959bb5713968c3771da808a", // 267
+        "df394b4c515db1dd3812cd10ca9e896d35011799e1121c714382c030854daed7", // 268
+        "d6f7f4264497301f5ccd82729ff03f0d3d82e98eab7e7d3a8b5a3e82cba35d71", // 269
+        "0eaf3e80fbe37902aac3907dc350d91234105f3344244fb683f90e769e251391", // 270
+        "53eaa0421ae5579831d88a8148f542140316940fac7cf2ac851c147cfc6cfcda", // 271
+        "de7334f8e672afd271537cfa797315b375762447531c3c656868e27f541abca3", // 272
+        "e48aca1771a2c4492fec52da57cf60e08f47d908a86991c09f41d3d800ee0da9", // 273
+        "1659421210eacb1709f99ba03c6d12b7ec39a6a3ef9b23c86e8bdf0b849050fe", // 274
+        "03f982e8a347f0b3deca57fe2251e5f7295339c1f394d58aeafc1ff5a6256cd4", // 275
+        "b044a0b8880b92897edb001059e16aa3d43103e341034ed1b20f59cfd972dc5b", // 276
+        "2ccf479dd66e6d63e0ecba32b5827f4cc07162391e80de33ae7c0bb7cd7f76a4", // 277
+        "2ee2ce5a8164c8598521810459441af0397192f835e35ac4240826832406cdd5", // 278
+        "574847ffb922c0fe253308a0f740e814e7d64a21490d3e8720facc3a65913609", // 279
+        "364fbf05ccfff596334dd0d005f6df2f6dbf09bcc04fd471a575af52980c7148", // 280
+        "5159cfb5674933b0179d84bf390b38c823cd656d237811f96e95fa6f7ac4ae74", // 281
+        "b654af115f8b349f9321439b553957a1ad370bebba985bdc188c27bf051855c2", // 282
+        "506a207323d217192e9c0e52db08f70d29f923e47dff47933fdcddbd7ee9e491", // 283
+        "5d05b7250813d3d714718c37a6cfa76879be975fcd5128ecba610eb86a3f4ad9", // 284
+        "6381a2b392bf7b90c8a777baad54ef13f9d82e99a97472f692fad6c905338a29", // 285
+        "dff4ed7ffe8da7b83f5928750b419df2985d82826806ba26ee5f88c6923dc24a", // 286
+        "ca9ee26822cf389eba268b8756d33aaf376a7f1b1c22143d941926ad2c24a321", // 287
+        "43e56ee874703b2bd770f617054ed0c7347faf5a0759159af3218606ed4cf182", // 288
+        "de2c687913cf2438ea45cc469c30b25f6fa984ce2a987097352f4125b178d505", // 289
+        "9da8ebc747011836c1a4b50647d57ea8cb839f4ef7e799f060f5234c448b2edd", // 290
+        "1e7f3976ab691605a08206a435fa10dc5f380cf5a8aa9bb4c36affc6943d9fb4", // 291
+        "ac663aeec3de08608ea051ac6a2ec145f7c40d88829ae81cf9fc78b4ec3dfd23", // 292
+        "a6dbc895c16ef2b12deb92ecde68272d7221c5383ae6ec0202fb5994a7338151", // 293
+        "668477299aa7fb37992d547933dd097b58f3124551f03f57ea875ca47865da42", // 294
+        "82ceed2da20f14e344b5c46103889c5f91baf7f6c57a9db015a718ce6908adcd", // 295
+        "9d1149651e66a0b2d92f58b732c5a26aa649a61db79afb9073866e9ecbfc4f27", // 296
+        "193feaa39815c4af31ee93603c60eb0fdf4301493e7e124b251e6f9adab3bebf", // 297
+        "ea8d02e6cd9ea9c8f2bd9390af347a77103263137be09f09e1fbac3c26b899de", // 298
+        "1d7454883ec61fa9a720affce63daac15f3b36e29478f9e1714871db9f8fd1c2", // 299
+        "6efa6496b57e92347dd62bd10f54541d3c6b7d60b91a0e42943219229fbdfbcb", // 300
+        "e777b876cab22355792f9954e14595c3ac7b734ffbc91d81906397b9714d2f49", // 301
+        "fc23f4f09d7e026413a896304d36d670dec20a279402c380ce1d5328c3fb40bf", // 302
+        "7d1d4680732ff079b85d31a2c3eb3200e93654ee0ba691fa107032b4d7992e8c", // 303
+        "19bdacf51b761df709c058c22f36c3a91c0e51028f5eca45f496df2d1363e3fb", // 304
+        "5324d6cc2edc574327e7df197684e5dc829061229dc686e828429ca96dda4327", // 305
+        "95ab1cfe14b5ed8e024201c81c11775a0d2ee90ecb19a67aad7ed506239a1f54", // 306
+        "60a9c399a8c5c6f1f947a72b3d7f73eaf731ddbea5142ca263db6dbba59f5fe8", // 307
+        "9013c0e8d288446948450b809c5fb356e0033126a14fadb316981cc824845e7c", // 308
+        "7e924f27998345506098ce9337456aefae15edb26befe4965f2aa662522cb2e0", // 309
+        "3980ff59f8dc1926e0aa9d0777dadb9293f8dfffea5d12122e78fe96c5b22d8c", // 310
+        "9fce2e78388f865894785e947e1d84896b9d36b2f79f66b179b9f776ad53a268", // 311
+        "2c9ea69ce1826094f67cb9e85a42d794a659ee84195f6945beb60b49a9be8dbd", // 312
+        "f9a93798e2f40b7c2531a8e1e2f8b1c3da5d2eb30048542cb3f1fac62e362877", // 313
+        "7321dea53d3e0cbddcaf0f4ea7dae5e099a01fdfb7ec1a11eaf721ab872b707f", // 314
+        "484c5278e7dde96a44b7ea1be4e56dbb2f202ecc40fd3f77e92c580a350b78a7", // 315
+        "e3df8f4ea36f939dd393066c22235459e1b4b175f37e991a9080b80c186997a3", // 316
+        "c68604496f2ceb950991fd08435ebd6bc21ece57a8ffd97f1af38b915158d12c", // 317
+        "e4c29cf3abd158b2e423fd676d330a65992a8e7cf860ed9fe4b2481d43d66d10", // 318
+        "643a5dce052bad753adb584d09d085fc35b2db63ede3dbe86fc92825e7bae3b2", // 319
+        "90081095f78fe3dda42a433f8c032912f7754681b3d1b3fd82111bab6391d067", // 320
+        "1e7a4c3ccf61e00a25b52df0e7cf3fbed452d4aa1e9bef19627f4a107c71b271", // 321
+        "ee4febf934c5f64209ee4bd16465f0149378206edfd4489238596ad38f53563d", // 322
+        "135f1fdc56e33fcfb06f69e46f87509fece3bc11fdb58cb6d005391c63af18b8", // 323
+        "91d9fdd3c016012a02ebe330151da11be6ae5552d996be960bc9977aa879ad3c", // 324
+        "210e7808eb823f2ce6f66aad86af386d6f78a3dbd6fff0f4d5ed6c3f134823ea", // 325
+        "591b572b450f351a603b088362f2bb179ab80a2ba4a8763b23a65d3d48cd27d3", // 326
+        "111af1ce74b2014cd1450c8b1b907e66b513279c997022be387ec57f05b8a674", // 327
+        "c0deff7a74d138253158fdca3342dff2459382e25f9aaa560248d0a5cbe6c439", // 328
+        "1d7208779c5a7ff857ecfb4138f4b07b937b8549c8c9fe743f75413670f85928", // 329
+        "f2dd3ff700414ff244b601bee6eaff2196abcb136bc1cbfc631a8bca80f5481f", // 330
+    ],
+    [
+        // string 1
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "d1211001882d2ce16a8553e449b6c8b7f71e61836efc2e416143808f20e721e7", // 1
+        "2f76a6156ba70977b85f6e964b22c55569ad083337bf6e3a89a8ec191859d34c", // 2
+        "2456e2a5a69fd573b62549730854d4ee8e7a99ef2d5b2beab1fc0c894ead7414", // 3
+        "c1bf7a57f37052a425b7fb082d45de1b52ccab0dee0c8e4e4756ec65656994ff", // 4
+        "6046d70487ed659356f975abd7758d71e724a43f3b7aa52f7adcc19c7af03a66", // 5
+        "0eb209d24b61463df7d966e2cb7571f28dc07e9f173e7c9ef67f631a2235f121", // 6
+        "03598479182a1d3a6c7dc39b8eb9f2c27ba8877d9650f68673a3da3c4c61f7ce", // 7
+        "fa255fe284df5040b3e9cf6509df4cf2a23e2f24b2bac0885db79113fa1c02fb", // 8
+        "db4af3f93db66755e8170e935c04a0ed6a9f0b5d0e68167760680117fe5e5087", // 9
+        "6fe3d067789f8d4b8baa25c777ed27c02b91b6d633f8cfa50786d1d8af8b57d2", // 10
+        "6323b9134bc2f4dee7b36cb305f52af4863aadd5f2e2ab19287ef78a67c8f9e4", // 11
+        "83ae12e502fab5804036e09f18b258bc420bff308ff1867fe5d85a00429e9ffb", // 12
+        "6a9b0f770f49ed87a9f6515e1c0e155c5f437ad5708a23b35f3e5951caf2860c", // 13
+        "9ca2961b0c3c4ed18783ceb257b94d988cb8fe6acc1568c050b87a09c43afe64", // 14
+        "7b36620b38c7131fd248432bcb4f1eee258da39e60763cd33fbc466d16f0824b", // 15
+        "7c9c8638b288d631b11a64f5e50fb2fef4665b4a9a51510c53938eea8a0baa3f", // 16
+        "244b8fbb80c219370adee6acbc2295090d7f15c21388b66f9e1b02b0215fb689", // 17
+        "e8cf1d769cad432b11887bbdff5697f7121b3204d762d7c049c84bb94f2a099e", // 18
+        "24fedfea1a0afae24a0cc016129830690cd7b12462a2ec39c1b6b2ca0d576f1f", // 19
+        "aa8c478c8ac5fb27cb6825bee739adceec48d5690c43dc5f659310558cc37bc6", // 20
+        "0ca9de4797c4e13c1e19189cff489f9a6c6be115d63e2d2f19471950cab8d42e", // 21
+        "3d456d70af88ae3aa9d548477cff1f13d1fd9151ef9cf346f885761db379221c", // 22
+        "9c44486f102a50c8bbeeaeff01822c14d5a0e1601f1ce39841883d9028b111f7", // 23
+        "7598cff17e35694e1420e0699de218cb279f800d4e1eb813610a7fb16f216b8a", // 24
+        "82bf73fd36e446fb311cee325d7f8aab30e6de53146307372ed3ebc97dc81879", // 25
+        "6d67a90e419ed006b203772d963dcd951438d707a7a3bbffa11ff5af2a6c5a00", // 26
+        "81faf380f539bbd34bff569ebeb64d012cdbae10c41e0e244755d57cba12fd87", // 27
+        "a74521ef72c68ed35e956593a7abcbaa6c4fc3011301cf4f0e61c36903c794a0", // 28
+        "e231d004655e4ab521a60cff2b52c5a69449df9143ce612b9509ab2ff55e0a5e", // 29
+        "c6b7075c9c21023693cc9990638b93fab82625f8fb622a20001434c03645eccd", // 30
+        "7cfb2a1172ed8be15f291177d73dd9c7e5a93ee38d3f67528fe17ebce61716b8", // 31
+        "fef92dee5a28e6785fd79f5d116385a43364b289048c42da2b50769b7cb3395b", // 32
+        "0f6880ede1f3679c2f77d7e55a8391f57be803c2f7579f170ffb4c270ac06d28", // 33
+        "411844b81633cc378f426e43a3b4afcaedf0abf73279dbce4e0b0db11f770a65", // 34
+        "26b6541feb0719e7b3903340048bc661555d92bbf127e66b1ad7dfbf84e421e6", // 35
+        "ede1135bf7e39df911b65159afd6fb3a3dcc6676ac505d2234142729e9dad018", // 36
+        "01351bb19ef1c9dc51556a5ef64b0f91fade1ba6c0565028b0961ab8e741bdfd", // 37
+        "2f2a4d062d322c4e508f6a7e846c84f28f4f64dd65731c052ae2ee66ea00122a", // 38
+        "73d9c41b3731a5adb67532352b5b9b89a91e7771f7bad4d4060e14b0d1e1b9ec", // 39
+        "46239746bdcbce6ea2185710ad256ed042e3943ef6894b48d10df3d8a7aab2e1", // 40
+        "5a4ae011ed6a6d6142065e2d80618f209ce90053b2e296d33597f0acc718be53", // 41
+        "57853ac4c57d73cc33885e7606db0cf451a966134ac424d58dac97dd183c0911", // 42
+        "4f430823ef3a1390016f3b730cc1076d710e83e6f4070b0d8aa6e4cfaa4db985", // 43
+        "5f386eb63b739017075b70d03efb9dbcecb863121c23aafb2c42f34674f80095", // 44
+        "a15e1c20ee951c0e1c363508ee196006d1ce4ca563a77a4b29044ccbf2099e70", // 45
+        "4876a7e6b50ca9a1c6c7d82f4739a50cd44f56cce9015d54718ed45959e3181f", // 46
+        "be1ced4e0211cb501d1188de528fad63b04167d93a8b367c2844c3f63bbd93e9", // 47
+        "f1672194e56fa86360f42d0114c5fb27ea4752a41b6ce505031b69bfe5b81df6", // 48
+        "d0f9b16a17c8caa64a15bff712714fa8832f40ea6e3edb21fbe23da5190270d0", // 49
+        "3a5642e567c22f85b850b5f350bf97ebb29acc0b9b70fa546fe043081dd041ae", // 50
+        "8846fd74b750d8e929ddad6bf02204c65452631fc96e129e5851a06ae2290c9c", // 51
+        "f87a1121625107c59cae9d532f9b9935848840aabf38f2252b6a0df442298c4b", // 52
+        "957e33d01725d55953e31c6ed8b397d0d5436d296dba07b00628fa8e70e12f03", // 53
+        "680e4474e41067ff98387f41530468c5975d49ba3fc8232b4fd9244a9d696893", // 54
+        "98bb07531b52ddea9ffb9ca838d3d27673b9691d4e5080af3db4a57498c0734a", // 55
+        "0f10c7dbc14e8c6d5775c3a21071012eecd0c6df97cdec8074db6aec82fa1ab2", // 56
+        "b27fc6e7820ecc056342b6020049943995799975915e244bfd934767816de16f", // 57
+        "63c92f9545a237ab0f6a549d3eae938941a837981e6a36bf2bb13bed9ee95d70", // 58
+        "fc28c42a6c78a7e4dd889f90161ae51a8de4d1af9f83f15bf9ce25aedb705074", // 59
+        "939582ef36641bae2a3205dce3b96700cd407c8e0eedc0a716d948b3e6990171", // 60
+        "85656841c64352249459d626e0b7dfd7f2c81a223b10273da0f079034d46bd6b", // 61
+        "fdc5ea00235c689cf685616fbf6c2c5e4ab8e15e556ddd07ba9ea2bb33ca7e41", // 62
+        "ec24b32b970368ac70454d8721f2569ee49c0c263dd6eaae5dfabf92c31364b1", // 63
+        "86a615052b8b3d95db3c956b8b7c86a8ef009128072411bf995151215dd8455e", // 64
+        "24cff2234ee181ecf7c920c9aa17679eb76812eb102b1513a88427247ebbcb54", // 65
+        "0da15d4428351aa97d9dfe6cf48082e8808571b424580e9758a035c72a1e329d", // 66
+        "71d190c10662213e2374eea01e0328fea1f0c1f76c3aec3f46cf5076849d0255", // 67
+        "fd302fae3c792211d53e8a0214b808632b1e07b279ed9f7cc81d15b7d7d4928c", // 68
+        "bf515662ca27d37e05f4c0dcdfc6c169c4e5b396909ae9b9e0e31c57daea9bab", // 69
+        "bb669e89e0c02a8af82ab6bd01ca13a596b713e3f151cd3ab92a5acdb627a6ca", // 70
+        "8edb11bacc210dba333609b40ce0262a64dbca17a0469fc3670f6033321571fc", // 71
+        "4153cbd1ff679849a1d9672b09ccdd2c11f66e21cb6dd7175991bf41f3cdd4bf", // 72
+        "91e1c673d2a00a0849d94250a8e6b8811319f7f3694331e29e7143c3b058b54f", // 73
+        "88da68512eedc31e35c63f0f00df76f4fa6943b65cfa5b8add30071d9e8289ef", // 74
+        "adbc94c7c07f89af77cbc1561efcc5263615b8f2a767c658391821e85dab45da", // 75
+        "dea33b38fd15336d12e0103672226bf117e813800627c2efba6d80978b0325b9", // 76
+        "3f57e955e7580e899eebdb310597db98c1a42b9d2c563921c5ef8e92dfbb2059", // 77
+        "f21153480870e1112b35f0d5ec0bce30de92533d788dd5ebda3283146a5e2b86", // 78
+        "edaa5252a2af7beb7ad0e855e7dc823b90f199d25825c41b2c5962ee68e03089", // 79
+        "101f66b4372b353363a4e39edee2f17ba35f605bc19edcdad8c96eb9548dd5b3", // 80
+        "13011fb9c80416103eb88feee2d5bcfabc3b91a674f0e5f87b2eff32e93069b3", // 81
+        "7d76e222f6aa7f8328b66f97e0339eaf301618e53ac5ce83e2ff859a4c39b435", // 82
+        "3ef00e9afbd14f7c848df7593ba42a2c05e7fc5062fbd68632c5679490256cef", // 83
+        "226bbf49abe874a3509de3029f77ae072cb8217e68aa067b7df13304c78e863b", // 84
+        "57339aca050446fb1276f9ed12ddf46d7a26826868ae3d38417fbe833aaa3dc1", // 85
+        "136b229cdb95dbd3bfe24841b2b2cd60fbea5c0e2a86733522f4979f01fe7812", // 86
+        "d9eefd8504d040a46e02db2ef59329a4ef132015028292fcbe0d850f2be6a5c1", // 87
+        "09f86e069f81e8d2e8af66876018e3cc9a44db51b55db99713baaf2e98e0da7e", // 88
+        "640f53ed16e379d96cdc9b284b150fc9038dabf1bd2f0de375f717d2c0d72334", // 89
+        "7207c5156deb43486937d9d4a506f2f6ab8d04eb37e6f81084e9538bff9d8dfd", // 90
+        "3dbb77abe70ab4bdb1519ae178971e715951d9a5e782192d13130eafd6e9e9db", // 91
+        "38c4515d5a4d192cf66126d5cb8ac1f4e7159262ae491cbe7e1b57226b67ee53", // 92
+        "c1750cc656ba15f390db8bb00857b831a1ebab496daa676fd47f4c2a2017c401", // 93
+        "70649a62dd709a739ca1d22123a8a737fe8122bc434bbc0e960d1589cb3ac6d3", // 94
+        "b0edfa61ffcc2825f3f0eacd22948bf794008382db00334ccb800a4b574beaa7", // 95
+        "a76b75584b499d5aa7c23f2cc4af152e4e374930c009a2a620520fa9f6541acb", // 96
+        "9184dcbc3dbfcd84a8a379a0db5ea9779df860054cb90b1dcbeb183cdb1b63f1", // 97
+        "7d34c7b4a83a4f5b361ee964dfcf0bb60091aef1b79663417d0ad9b05a44fe3a", // 98
+        "117e18d677e6a222f7511d53f69529ec7d4fbdaf9da30700808196a7a9cce239", // 99
+        "e181300ad9d4ac67d8d2dcf511c9a09218d48971e34d8447de531e3edd244f3d", // 100
+        "4d3ec93557ccbb6e9767da08ff0872f93162c57f7d522e7151b0e401c5945719", // 101
+        "83ac35365515e432492e7a8ec6eb664662d1fa4a89cd37f1d64504a65efba24f", // 102
+        "2a45ef5ae5452b00de940dfa19ab625406d962c1e9a38643d19097b06d15bb32", // 103
+        "7d923724f8ee4a21774fb3bbaf7bce59afc5ad18a97f1b9917bac5f2862620e4", // 104
+        "354c28a65e608f977e2ff879dd6afe4c09feae67f98394b9ea13ffa42d0a0dd1", // 105
+        "6322b5a11089ad3c1e23a111d9002d283ea52e2c3ecc92be64f8fcf15e9358b4", // 106
+        "7d946cfccef0f875d1405f0bb87dd4faabadcf707642fd656d44ad48bbe7b61b", // 107
+        "3f6b2636d75bf7149b5dd7f80e695a358c6090d59284c3edb5853bc26a4d5d58", // 108
+        "b5ae59cb0ebe936d6b031ea3fe9681c64d8bbfaaa8c61355d8d8a10b158df3a5", // 109
+        "97e2720c16b6de92d58198a9c22625ae2c3bf5cbf428972c51b84a9f73001cb6", // 110
+        "bb4863da49b76859763e95c5e81f38434e3e0a9c64858c654424d6b0a76be381", // 111
+        "830a34997438534108e1db4f49cf8911b2cee438b710c4493c8904515c91eee6", // 112
+        "dd7e5522f548819a044a5155a923274dda4e7ecbc031b73a1150f308da33cd03", // 113
+        "9a79a1b89cfde9aae38d8869056543a826526117b99d0d99d79fd89b55afc9d3", // 114
+        "7b90f82f472a8fe2fab8c96f6f5cf696c7e33c3f043aea95e83b8622985a4a13", // 115
+        "1d6bb9d4f346f6d41cedc4bd0e10e044dbf10b278159c27f1e6bb96bf746156a", // 116
+        "862bb78bcd945b52b85b510b794c74d4c54202b73cae356112845c92e588883f", // 117
+        "8ba1981804f0e097e18e896b322fb218d09331b76014c1ad40e0f99c1eaf6695", // 118
+        "7ee6d2f0dc39cd6fe37ae274fbcf36d05887cbc96978e449cfc90ac7bfaa694a", // 119
+        "b69d035b7871a73f10fd3ba598c8d7ed956a007a60bc864e109b1a72c80eae50", // 120
+        "67ad4c72beea74f45f848faec8416e07413388996840e9ef5abc48b2fa695d0f", // 121
+        "aa69c472b2da5fb381fbaf5b8ff2ef974b02400b4289a1bb2e9d7d7b8bc07e9f", // 122
+        "34b59dbd3bcdf75618ab2511e9daf89927c642ce55a500bac3ef6138597322a4", // 123
+        "9ba02e0c7867c3d9fc2ff17324de5c6b8a4a1abdd866b4ac59a9c46130089921", // 124
+        "06671dcd01bac9f78a3c4e4d68ecfc2932fc48f448d7f29579b5c75735e74fec", // 125
+        "a60a3898a11831ab62c97ef1b3261e550124379fe5f1cdaa3331a34f70a00111", // 126
+        "708ac36db177fa648fae01c5105046ca94a01789d7fc877aaafc7cfac96fb82a", // 127
+        "88940a2bb93a45fa80fdd34a772d4d7b38dda263161016e819fb82138049bc72", // 128
+        "a7691c3d11364b8451a73d7d91fc6fc348efa3b8ae81c8c3e253fcb8efc976c1", // 129
+        "c26648651a5ce4643ddd71226766b754d0a25d1c855c0754b274e0338510230f", // 130
+        "f3075ee279c3ab6140c5afa10a91f83863b623823362899f4247e756c0cb6066", // 131
+        "348b00893754fe1c6e9904f348d1f5f32daf17b139bba486c3b94839fe29cdef", // 132
+        "5285a89db2bbe0b92faee1c9f261ae9b72d8fec4e34c064285118a90ecfefd87", // 133
+        "421968ad68f1b27726010ce18879ee51ebbb2e574b0aa1152c47eb533595c828", // 134
+        "0df6c8bef2ecddbe7d9d411ca65892c596c8e6e4b813babe740cf314372fb3fd", // 135
+        "f24d0a466f41292a3d92055f33b0b053d3f7078e7c008a936500329830d08ed6", // 136
+        "d2f87886bddb6789aaa8e6ba26cf0bd0ebe3ee0998597f7637534bd1a0f18360", // 137
+        "10616c9b1cbd9dfa7e4592156f7025ae15a4672f1c301f5be469f122bd38b9af", // 138
+        "cbf6ef67511bd518ec6fb4a9f47138597a940513b86621c009bb4c5922d7485c", // 139
+        "fa7b4f33edcd27e9e0c46da3e120498385556581a197c7c5d2d1fdf97dd378cd", // 140
+        "12a4dae626b846e7b19b0091bc4442ef155d4a3edc3566193462c5489593eb54", // 141
+        "f0405296a2dc63475bf3497fcaf7880f3f938aa91e4aa991d9ca3fb1e7caa41b", // 142
+        "a1f22631501fef31979628c42a812006b97574eaff00bd97bec024819e0efd49", // 143
+        "f69d2e3418a68a981fd789c7fe405a92dd05757bc2c0f89f2a4f57d59a8f1426", // 144
+        "21d3bf60f2f60b40b54ef41badf0039a86d87d2825342dac6f339019c95f8cfe", // 145
+        "a383acb46f9cc6d42c4b2730a20273676125369285c434717c831ac5395cb6c8", // 146
+        "00e5ba02c29369c1cbbc36fad9597c2f1bd4def27d04daef5b35fa865c8597d2", // 147
+        "1b972fe7a92d431cbf6b9b44326a3a8d2f313a1f80900915e989b2b66e893c6f", // 148
+        "e4afa01dd6be8ce976e1293b70bedb0a2f01f4fa07d63a6cc36b7e253f839e06", // 149
+        "dfaf430a050d64dde814c7de98dd45cc3904584e53ebea47b394c08fa4891c65", // 150
+        "c2f654c8983c15d1b3ebe44db25069b2b86626c32e3c13df07a0bd5d56ee14bf", // 151
+        "db0b9d4dd795acca52d5416d151382610864d9ca014f48607d3dd225bfff2381", // 152
+        "f64a451d5219fd73b343b78b8ff910f802cea51911de98ba2fd680cd5e0cf0fd", // 153
+        "4d3c7e18c62f5899bdca6146455a15f3abd051f5edf170e99b770a94027a1ef7", // 154
+        "2bb244125be04106769143d1b37ca8fc4ddab952e5432a577540b8f24001b115", // 155
+        "9ef5dd2a2eb9306574bb7843725de9bc6b1341bb3b4fbad13c6332f497b34a8d", // 156
+        "97bd63db9eb4f0bfc422a8fac8dc5883d795954a20b0f9ac50b892eececb4c41", // 157
+        "791eccc59e22d7a4514d8e583bbb517da8e9bf86e387b63a486457e698eccdd7", // 158
+        "4d063bdff5b4eecb45d758445c8a6e952ee6b5d71bea6836712e89717f3646ca", // 159
+        "b370bb4a7f0aaefc98f2bb518034976fe49f7d5671d2803f2d8167c31e98d549", // 160
+        "5df2ccb4d41bc927e0f1c9d8f2d16b54b85b6f1fba88db8461e6282053a53833", // 161
+        "874654a04e81d92d3ca25339346df36013e3c5fc76d75a6c12988847d35d067d", // 162
+        "16ac1b91266d04d615468088baf7ceed728e37833e633f4a5722ca6321d7f949", // 163
+        "70a3f65b9bbe0f1947a564d79048ca7df127d320cb1f616829a5f86c16714049", // 164
+        "226a94fed8385d8fcf112933f5c74e6ad79e6fcd2fd7cebd02488ecbd4e21d22", // 165
+        "f2cd4ed057b17072e8b2c430038fd0747257c491fb35ecfeb44c2c3ba31e3ca5", // 166
+        "c1a26a28483552e41f5b76a51b9beb657c6bc59d7c09206c4039edb7f8181101", // 167
+        "54e856d1d4837dff0478158e8f0593d8d29c94356227a6deec7ea70089f6563e", // 168
+        "c148bb2d2be127fad5c3852e0807bf1e88bfab09107a564221f59b5263bd792c", // 169
+        "536ec326424f59de6c76e92100ffc6ecefc571907a72dadfd28444a7d7dc1702", // 170
+        "9c17ddb254e12b40e413364a9925365152ef9811e2bdcc0bad8d82e8e6f50506", // 171
+        "f33413cd0a1de8b515fef50c9dfbbc9c1b049c99687b4970163d58897be7dde3", // 172
+        "d1a223912196f4eb1a918cfcbca0c100787a95714e583f9c19a734b664550151", // 173
+        "74538f82c71bb3f087a7075a134af3b145867fe7e1331acf39d080e39d288e5d", // 174
+        "8879a94f9f797b0df21574be209f129f6eafe3f526f5177627875a18f245a7ae", // 175
+        "9625a73ed485e5bac1d80bb5d5257b57d7973a88c3316d1d3b69e98efa3df942", // 176
+        "af8c109eead4c089704b358d5867e89badadb242272fe9e42abeb0bd8d23e95f", // 177
+        "45b2688579e414779abc3243b61407c3cfc136e0b97e587685360667b6334140", // 178
+        "1c1b3cbf2e3a5951ca03e78677edf2840394de216384399d0495ba81e879ed15", // 179
+        "4e8a79e862a3ffd01ce9d3325c39ff5b3120ab5dba2225af79bec7231de20c7a", // 180
+        "f67b5ad6af5f613ed961c33b319832b425ed34145919011fe9c1da96619bd71e", // 181
+        "605486be225bfd82c1c4648598bb9069983cd3eccffc66d921aedc1af011cc9c", // 182
+        "3c16c887f1e21fd43b0f09acae280e5ab7fce0cdce19f4294e9ba1ff8267db2a", // 183
+        "405630ae1363a5dabf2a64c58f1af0c7f74d0ca03b7ad798241f52086d628105", // 184
+        "13a3b986740b32d33008bd631601642ef5bcc0862f4f0902bab258fdb14cfa9e", // 185
+        "c9aacc79d24801f25aec268bc9e639eec4212c976b808c1f30b7caf907f4bad0", // 186
+        "d5748210fed1dda6a13d3df7bc8fb3b54a890108f376f8a697d1b49881de96a4", // 187
+        "3bdf7d5ae3c57d73261bcd0841185545294313fc9619c6bdc28fa4e21ad31f39", // 188
+        "5d13653183286359b629a43c8312902920f3a338a31def4d10ad0f70ffdf243f", // 189
+        "39965c2570a35c068f8958bd34553f4a3e86b859bc939eb1be61c4780ced3569", // 190
+        "ebcb2c3d77f17fc44fd004dc98a86d10c82886f300a047cd13012e1f9216c2b7", // 191
+        "c29a1a245c199266aa15a3d1100c328437f1b49bd568627e9f42e7b99e4237e4", // 192
+        "996bddbd86b3ae84cdfa70ccbf14fc8e8055a6ac38e097abea6ba0377b51d12c", // 193
+        "1d05a535f6869602c51190b7720d69160559baea5b284933119320102663673e", // 194
+        "333828f984ad17de477dcef517d4723faa7e1430ebbb4c6792dc477db549a32d", // 195
+        "a9d5362b67f4e18796e07af09d01bc7b5c5b01415417e32b038d8bf6dbaa5fc8", // 196
+        "d04e3a7b1872827acb37462610a4f6d7469e03eee965ce89a812638ec19cec3f", // 197
+        "4d6dc79961ea84f31d8ed4dcfe67097167acb450ae979132fc73b80a8318a353", // 198
+        "56632106d43927d775d30d46104ad9f08bc64e8e35842cce25c02416d675d7b0", // 199
+        "de3a16b18881950b9e3a90ba2e2e40cfe9622ea301474e92a58c3da79e59a407", // 200
+        "f3ff0cd16a3548b1eb214f5577ed67628b212fd244a3ee6d081dc47decb3c205", // 201
+        "6e03866f1f1103ff61ed1685f1498f051f466b7ee65128b4c0fb0012cc61b547", // 202
+        "c91bbb1ad6628ff98ed86119e14e27d3be16a96a9e6374b5f4fe988623898809", // 203
+        "251ff3bb299ecf2031f86067fc886e9ba82407dea885617b06ed1298a7976267", // 204
+        "eafeef2af1077143a1ab97b475a251da1303e61c1f7e21321133645e7167fd77", // 205
+        "de9aca1445211b48024451495d8775bb9f0ae1b379034e368863305d10e45710", // 206
+        "b0835f47d36c813482c1fc7d21c949b2a87cae824631c12b99d238b81c04331e", // 207
+        "4be3e3c2089cb69f00dbb511ed6e7079702ff3cef606d27206afa5d8e66f9faf", // 208
+        "ffc7fbfa5604c3c8a737f207612de55fdc4615bebaf7d66765173c8a9e8f34bd", // 209
+        "820cd3811d0e50df6b7936bc56231dd0c089db84d8845a483eca0585fa3834f0", // 210
+        "bbd24555a6a38ed467fd50507bedf45b0dd007fb8a37095a51fe502b2c2853c8", // 211
+        "a96791c0c78d7345f40ec175c811fdbad9777a840ee68a7602fe5bb25a2758be", // 212
+        "d89c2ac252500deb14ae03ca0c991929bccf0f7aae9cc67ce905e85319c198ed", // 213
+        "67f35fcac01b1df710fe081dbe21f9d1c6975e695727edafe935b008035aed0c", // 214
+        "e4d6055b3d2f9db3e8029420d0a025f2727e6abc8b16e4163be51dfc8576634e", // 215
+        "edfb2594e146755ab61d467288e7c25e503bb64dab52f2e1ece2175393318aa6", // 216
+        "2d50ffa7bfb3b48521206d6cc6f571c7c98074f4f299f058b63e703cf982e332", // 217
+        "27b5c8a12e7bfa548fe367f24d8a6da54c151c606024e0000c6557cbc09b8aa1", // 218
+        "22ab6605627698963af3ee44d00d69d5aa4120b3a03e8e6bc4d774602c68672c", // 219
+        "dbcafa5281d86fe2c19a549143a26c2e1c78d2a94c4f13cf9f8023b5529ba739", // 220
+        "9b2eb1cfd6c5bcb67b0fa9a1ec845b165f42d3f187ed50c5142152ac03cee2f5", // 221
+        "5b67f15e2494dda3080e85c877599e7f3c69eb889d9427115e96b10c86488d7b", // 222
+        "bd927a7112fc414728744d7f770c5e36c906238348f23c9321ca15209649ab6a", // 223
+        "1ea842bee974e0c7e8a1277b839c35e935909510c8dc19347bbdd123fcc4d60f", // 224
+        "7a701df03872e6fc02cb2fc6c9ef358d5edce16ccff6dd2552154aa2c5ea72ba", // 225
+        "979382040ae54dae4ea1f6af8b2a2643b828e626b5a8b16a89ad6f29fd9f6cb3", // 226
+        "34759b35c74ebd604734174f220c057591a4bdafcd59d71976fba50a4c37c1f7", // 227
+        "9091656b605ff82617cbc1d9280c4078fa9f89399fdf4991e6c54f596bdfe2b5", // 228
+        "6b1127d5bd66330d6b87c6dec87e6a6f1e07a3b1487d1c286af2a83686a72f51", // 229
+        "d218446dc044a4a8d176753c6ab7f39bf23364c9e719ad0d67971bc706edaabc", // 230
+        "876357c784073d2ab241e79a0f5851b1d9e38cd1a5fcc360e2cf559f94539d50", // 231
+        "1ed97c61a2c8c34354187e165092cb1f5748dbcc3a8e90ef7c4e19bbe210893d", // 232
+        "887e4224ddcc8d7b54aede79f4ff1eca90c98ff9a868d4074b7335a809539fc7", // 233
+        "3932ffdcbac7baab89bb527c27e9ce0bdff01e6adae8d977d1c33d1e17896469", // 234
+        "d40335e92293604efe40d4ec788135838bff62397c6eb537f58a8c9745702785", // 235
+        "13edca8512eb0f1cc5073bb856f105979b933f0bfbb48a54b8341a246187dd99", // 236
+        "116d54bc966a349719641257834a67f21b4e2360aee9bb532be31467c0c8b878", // 237
+        "3731abe60f4496ffdab51f1254cabf295826074773eecb602ad6a4d2943012cf", // 238
+        "670a0df230bc79b22c084876cb9099273bf180798abdca712373e9b76219e62e", // 239
+        "6bbef0192b6d83b21fb5e0309806f59cc09a8939456fb344ac22c20855aa9692", // 240
+        "189c0fda959d5c2ae489a43abe6526fd488b66fe5beff0b351a0b221dfa95863", // 241
+        "977f1f43eaa545d3e2a95e3b491a7f83db980353559299020adead446d52e40e", // 242
+        "a09b4393d59484827a187daa34d0f83e2edec961dcb9071ecbd103bbea1e27ae", // 243
+        "85fea5732530ea335b58b09ab1b19398b503ac1e828711a201eaebaf18f16fc5", // 244
+        "e5514e2ce841bf4a8ca196bcbe31cb821ffec725e885713e1fbd61097fd6ac6f", // 245
+        "3d55b7dad27e565b3f5b9fa6da7c5617388cff2520f3a34dc7722b31e0358c9c", // 246
+        "54cea288d94e2661a9065a829965bfe0d3e7afdc9873893239ff87b954bd74e8", // 247
+        "34e612b9663ccec359b447f5558c7ac2387dc9a45cefa8a87adc327b08bf5596", // 248
+        "fda60275051298f2b9fcbc4b763a57211768c4b7363c17871cfece48a51a9e1f", // 249
+        "318d347a8dcb30a51e209faea86784f003a3a726ccfdc6c594aaf1421b65a566", // 250
+        "d5e0fe1f5733fb2a6528a673e5891d1436abf207810541af350879cf2ee69b25", // 251
+        "d63b55d3281231694566963fcdf95231c4c33cf607c58bb7894c187befb52de1", // 252
+        "b16bd15900eb72e2eff711ef5b35011214a6941a5142ed696c05c71d6f4d333a", // 253
+        "2fa93157ec0c3a2a24eba6cd97cba4093d360d88c9ae9b58c1e8450c92ac9168", // 254
+        "da0b8b43c8cb77436ea3517ac59dfe3eb08fb5017d902654f210e43f942d7e4e", // 255
+        "917c89b67a53853c1689488f2114922aa088f2b74f29ffe92d53aaf993b9863a", // 256
+        "003b7d582328bf42fb45f858d01592b90a804ac43d627bb800931ba0ee6820aa", // 257
+        "66bbb07f54079126c6373e042c1ca68a8167acc2a401a0435e5b7fbed0f3eaee", // 258
+        "b231fa620b677b72de9517faccae6546be90781aff2540d7f7d204a77c238396", // 259
+        "95c8243039b2b23e09de6c02a0aa732b13243cb96c1d0061f9389aa0dfac3877", // 260
+        "9bf9bcbf1b2be224f217b3f1f2d0b1114d9bd4b720262ee6662bce0a2b1b24e7", // 261
+        "af61337be94a47fe95d0474a6b5b961a4672b6ef2248f9b86979ed3ad63026c9", // 262
+        "1b18b47604129af5b01b897bf8e78fb2e482092240cad9b9e920f900f1130c58", // 263
+        "48c2b159348b13335181f5464991de7643078228529a468bb92404ee32d6bcb3", // 264
+        "e3da76f8cde78db31e82c778c9f34050a54bcf0a23732d7febbef631499f1eec", // 265
+        "28c5a7cef531c87308389a2858a8063d3911aa55a765c7bcbedfc30234101036", // 266
+        "3a3f27c7c8a7bad6ee902680bdbc46d54960a7eda59c2945c9dcd0df66b52eab", // 267
+        "9d26e9b801f1b788017b829da7d5eaefca1593fc4807791422a5e096b5e16cfc", // 268
+        "b0a9213cdfc8452469bc802c8800399480f9c2000e3a3be3a361086d36bb77bc", // 269
+        "9a1f3d7cb9f7bf0995ee0c8adac2943dba8c483f489d4ea496b253f244264b1c", // 270
+        "5f59dd030d63d220515db7cc410fb8c08ff2a6bc68521ef6ffbf53cda1c71b6b", // 271
+        "276b53df51722cd61b7d789d8a1d088c2918ab0ee7deee6fb5c2138882367728", // 272
+        "3f42d2f5adc8b3765aa01befe87805ee29c1335b9ced2aa6d5a9ec93a823acff", // 273
+        "0f009f23b58677936555089bcb5c8f2e01c584657563360f46542a93d690c926", // 274
+        "08bd2729a59a54803c5212ecc8925cb4ed94e5513401b1876a54d53b155580c1", // 275
+        "f98d492e1b8578b5727c349236eead51b306568f4fc36d7ed30c61aeece30ffa", // 276
+        "0b367b641ac3b0f03dac76d56a1427eeb2d0c93f88a6649b5332f25fdd499d86", // 277
+        "923f6cec02d1ef9b02a45492a3374539cb4de0af035b4e8ba823ecdb06ada17e", // 278
+        "6fc62dd4447a4c0e2a9e938d6ed672e7864b4e00a168f0702ca81339b88bc1a9", // 279
+        "21c79c3e4bf0b84d2dab31ffce533bcd6a06930ec46ee814ef86911268ebf7d7", // 280
+        "75a4f0cd5da23409f07b71752484753ced336e0e5c93ce6920e87246ce4076b3", // 281
+        "dc363f91cb1c01317a8d671eca3eef9aadb46a282299ac3b2d5b8bcac665c7dc", // 282
+        "daf7e8398a9dbe2d45517457b750cee3ec73cd9094d556eb4e790267202fec41", // 283
+        "95476dab5c95ece98f7f73a6b77a46d00ec5e8219de95eabbea6dd452612a81a", // 284
+        "6c5b0b60480fd142425fd00eecc915aabe38a48ec0cb7de7a8d8cb208513df9c", // 285
+        "975b7befd3e6493f1ba68e23094bc860d24b17fc4ab246804dc2bdd0b501ab92", // 286
+        "2976ecffd0ef4f5fb6fbacac0f3d91f5249f3f262b96e0105d55ab2037693840", // 287
+        "73cfb970b4e611b1f48e22a27e2628dff6e4c7c7f85cf943720e4c4a321a835d", // 288
+        "9a4a49afa4301f386086bc54df00784fa283669a5e79908b57ec55c41eaf1db1", // 289
+        "975882f97c3f561da51395436f2e14ff5cd530da36aa37f3618cf32d27ccf6c7", // 290
+        "06b77bc891815c4e49ea98543cc2f18fe9ed8c5a81c2f5d68841f9201e57740f", // 291
+        "54cb1a54bdf63a67614b26ad0e86273a6f5e2941c76bd734536ecccae85fa680", // 292
+        "811f8a3b01f5d56a29bba54970e02d64958482554626f992014993d0b3bb2b11", // 293
+        "7b54109e10a913eba6bde2bfd7cd8afd193b74939324b3039e2c3c5f1a3ee485", // 294
+        "1a39b09840f60a0567fbe6084f0938d599c589251688053c055a2d87709b50e2", // 295
+        "0fa93e83e5800bbcc531205efbf84ed97d6e68c11c9e1c6f6ff7f7b18d399c76", // 296
+        "31af625408de10d135b7bcfb8ed4019bc98eeb2922d48d726918345fd1ef4be6", // 297
+        "c33d2fe0756e41392353a753991a96a20fe7899aa2fcaaa16b3ee18aaf9e4a46", // 298
+        "26ad189f4cdcb2415322b3bc1f02018c71a3191a2c46dedad5789a87ec1601ea", // 299
+        "f5aaef62780a3ae4e1519800a55f9ac36028999d0bfd0b1ef0e82c473e49d74d", // 300
+        "7810e0404f3dc7e9ea0b6946dd0d7916156a2e7e575698cad33c9b9c9221bd87", // 301
+        "f46cdbd3eac7740ce9f4c015d9824534be4cecd571763b46778309bce4816256", // 302
+        "65faaba1d26ad1ab44bd89ab6edf073d02cdcb5d7a4c354e412f4a40d126b7e6", // 303
+        "99d608530248fc1b5c2c9bce1e10979fe83169e71b5bdc8762a046e7776a761c", // 304
+        "a7005d9684623bf9ebb26560598ec4fe506b3b75a6a08b73de6042ba9a3e50b0", // 305
+        "7f0203b12ffa98a1fe84005ad8970ddecebd026b9f2cf78286f5d81cc062f8c8", // 306
+        "cda8ad1e5259cd61bef033520fb3eba4cbaed738428a100020941779714c401f", // 307
+        "9721cce0185a911df3fb07a6b4d6c29814e58df31cf4d757ac920bbc5caf3138", // 308
+        "26712cd04217a97c8ec9a32afec6dbf903aaaf5ce156bb555633fa00292a271e", // 309
+        "9b211fe46e2ff0320fde4e92d6d1c78849bc7b2e9ada0e5a627b9bd0a01d01be", // 310
+        "cc7797d802a052c3adbe9d22ece6461a9d1e83f573829149c284d950f5feb9d9", // 311
+        "bac451db6a497b74c9ab581bf403aea074f1304b108b4f7bcecb26f32a9dbc11", // 312
+        "a4c0462754c36d01e8f948c05e7340db7da17df721500674defe1527270e8d6a", // 313
+        "1f5bab01e019d178974a2e68dab60717cfa8e21a1e9cb46813509ceed2cfc9aa", // 314
+        "45cb5f2ef76d5671992b03e163ba6f60ce552cf93f7e3f8c8f7523c60d1d2d7c", // 315
+        "c3be664ef38c4858d4de2be2a0b941cbf350354a2b1bc1876886dd1acff14c95", // 316
+        "54b3e886518e56c3222052ddc75d1917e62e1398a6193b4d29a791e4cb76e111", // 317
+        "468f2db297384e714d854b166f3a79549e4b5c62299cc4a8230aa1472525bdc2", // 318
+        "4e5490e48a050f76dfcd179b7db1307946369bfc5b96e755516c14cab6b5bb02", // 319
+        "a7384f1051ba3811cb6702ba80a3c3a859c04a034bcece8a19203576f92b90be", // 320
+        "f9dd07523744a33af560876cfc6a8a272b1fae1d324379a4c9b1b6a44af46410", // 321
+        "d0c93332ea63e7dc44a8bd763b6f622123e6b9e6cfe934ceefbcc99932598def", // 322
+        "667b008acf4197b7edafbaaaca8b6db272e4c44ce92b613d71055153f3bc84b2", // 323
+        "9a8f34c2f7bbef7f0f69442b9d79291366855a0b5ee216750b3a3ca3c715af69", // 324
+        "3da86813f06a43b702910ab52757d084e485ff731f59394acd7a611f7fea4dc3", // 325
+        "044bf000727e41039599a3ff9165e83c9f73b84a0c97f733b2f72c7c6ede6a97", // 326
+        "85838d30cca259a5a7416ce29638c397381003a5e80562f89e8b61f0174336cc", // 327
+        "68e22344c181517e3dd24161ee08cd1e21b9f6ec3d543688558e1ce61f03556a", // 328
+        "313ea8ccffec7391c2a479f63cef957da37ec224f003161f38ff876940cd3752", // 329
+        "f7955d7bed437284818b05abbf686137443507bbcc6633f5caa0df21edb16706", // 330
+    ],
+    [
+        // string 2
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "021fb596db81e6d02bf3d2586ee3981fe519f275c0ac9ca76bbcf2ebb4097d96", // 1
+        "abf662f8b8ab57a95e70e2b69c3fdc91c8198a2355d9cb2c2304572107fc9630", // 2
+        "d5eff17161335893355fab128cb39aad4e97b64228251bced1da58b8aabcb6bd", // 3
+        "becab550b45a92ef87e5981f8b5e1890c693ab112e3a658e3588a855b5cfb961", // 4
+        "38a66072e8ea0461dcf804eb8de8ed8a967e081d3a3162aeafaf168a2e354272", // 5
+        "d70541081dd817d1d97006c9fde8637a21ab649c138e0fa01a9de81e07c70138", // 6
+        "f3c95433e1c06f762179f5e66ba0b426b84d7e7a39fc585d075f204328e6d397", // 7
+        "e72599015f9dc1d6b2f7ee271efdf73c0d7e42484b555defb4d84b264f31f276", // 8
+        "687f113e2cca002e210301be64c363869388cdf6f6aebab633adf29f1ccc2645", // 9
+        "a2b714766fad6466be203ce556d9db26c939f37d7087989e8949815875b04ca4", // 10
+        "fc04971371090d6095804f29de961f4466c66e630c6c53185e5f7b47f20725d3", // 11
+        "8d29e5996da64da0e7078a1e77796e6140af748bad6dd9da08656446fb4c8602", // 12
+        "c246320947b595d1ab80fc472ee0ea386e8a0bccbb899b6de81a9d72fa7904de", // 13
+        "6353ea29b576b583c3148f865e863af7e1280134ff184ffe2b42b413c1e314b9", // 14
+        "f19a8015ec92e066599160fdbb3267c1b682f6cc10a25ab172cf77ee0997e75e", // 15
+        "a5f298bc06d9aa118dad1aac9039cb93e86f37200af5e3d30337eca7aacadfc0", // 16
+        "6d8d3734aa19ded0e093db6db8d7138f3519cae0f76da00dd0077e5c89fde5ca", // 17
+        "9370b0d2759213743b4644e1fa25d7278233e75c0ec0c25ec45b444ca7deed4c", // 18
+        "ed45337b424383c6ca8287a6800a7383e093d7ad360a04f5523ac3617b61b4b2", // 19
+        "1868c51784a944fe70339bddbb35ae0c07ea0b15bb7729f922282db6c4d60638", // 20
+        "5fefac12e4460a57373e4d0c8ae48d6051d435343147f4565c008b4a9aee1db8", // 21
+        "592ff75ed9ffe29b3db99912c83dba851056634a598ca7f14a2b3a5fda947eed", // 22
+        "6cdc7d0967212f0ce55d92846c9b934ae85423c0ba529c0be3a88a95e97b93b6", // 23
+        "481cc43581d78d381f334c9d7511d49a4b9daf91a65da4553607cfa75c95abc2", // 24
+        "85fccc4d45f7d17ef25b22bb6571ca7431b32c5676e865340e42fd95448ab99a", // 25
+        "ce1acefe6d5a51b8103e803d0d2fd21c09f94483edd497bd8085093f7fb588dc", // 26
+        "20fb5004054401693e8c2d05e278f30aec2f95d5b0fdaecb9d202b479fcf8585", // 27
+        "ca0adb6120bf1988b66f96fe7d2130cc4cb64c0d3814de4065abaf5df0cce1d8", // 28
+        "0557f332949766cf10450e364fcd29baaa56c9667c13c202d82e9f31c760cd8a", // 29
+        "953befbd2f18190648de842471e3e452ed630123ea865e13655eb0e8469007f3", // 30
+        "73fb21e5c462d8fecdd913ce9a37f8f630b57236c20cd313f9dec8ad1005a37d", // 31
+        "5f990ccbef55b7d80f19a99a986856c4126251f5aaa506bff23bbdb43dd77ffa", // 32
+        "e7f0cc78c90a72ce19a29d3ba0fc002277730c78e44f12e45dfae9264e8df993", // 33
+        "030a772f8fbb7e78d8df366b9135263254fc9171ed22aff47892ae1e2fd5783e", // 34
+        "80594bac59a40012bf5691dbf018f22aa2fb5ebc7cab950de4d83357a995bb43", // 35
+        "a1504a58d2b9c007d294f4dfd75bcf07a6fdccbee5ad518a81bd0bea318fd71a", // 36
+        "6d661305de707445cc795e0d418d77f7f167483c60dcff7efc5148178bf6fade", // 37
+        "3fa4ee66aec13a9b9e87324db52b505d7c306f2f3ff77721797924e24181627f", // 38
+        "28728c6e4da9554cf39a8ec1b560f3fdecd69bca0b9f798dc6a4ec54cdbe4db6", // 39
+        "a265c607e0c7a9f322c66db4890970a221d57bfcccf5c3fe7d58c4a7ec85ed2e", // 40
+        "47941aa6baecb4849dc34021da097523df3f79932f148195d53e081c4aef2f53", // 41
+        "0d726b51c6b9a19233e26947cec3c589b0beca9fc1052f1138e577b9a2786733", // 42
+        "54d501d93c6f51cb56fd6237d862f2a93cc199a75a65759e4fd37a911e17e2c7", // 43
+        "e7de13af38fa51c21707ec1d1f2954923d1d1915438ecfbe6ffd941d71c26696", // 44
+        "0bb533e35990ba78880a08faf63e170b4fffa6c316442daacc417baa2a89ba1e", // 45
+        "7717317a6af5520f3cbf439abbd41a514e4fb45b8a3f581aae58c051258ebff0", // 46
+        "b4571e9cd1158a242b17fb5d0f360de38e05eebe0f753087de03563a7fd9c19e", // 47
+        "ef6038c6ea9dd1d2b1a42bef2b79fbeda393758f0a0c308c0da5271f1f9b596f", // 48
+        "73e1fa00f7cbf3ea746c5606c96836fc61575cb2034d10a271ddec5adc21a61f", // 49
+        "ba9498c78c4a6fff81d12247868cfc4cc4f92bc5da795564c49cebbc9d574b00", // 50
+        "eb39d00241732a9da281ef6fb4572b96db2da86f9318fafff983662223790273", // 51
+        "75cf06fbe8756c859158304f417851784b097a2c8a5498335e3281d5f737d8b1", // 52
+        "def7c5d5cab1f123addcc4dda12fdffbc9538d98a0347efac36d4bf7a5416bbc", // 53
+        "e061489d9b186d1be00aea828ada9a16c595c2bb4741e69c806609a3fe60c82e", // 54
+        "730ca3eda863c61f76f845d0c5781d597391ca3c1b5edc04e50034cddfa63bd5", // 55
+        "7196b5f3d49cbffed76bcac8672d4683456ac4dd0600a3fdf337e5539425cc3c", // 56
+        "44e506e074df5cc2dc3c4e3ffc8395ff2e888c00dddb7ffeda2633637ea7e7a8", // 57
+        "e863b8405df97db54cb5d53234c076d5e2e1641bb97c950be90d330f9aecdeb8", // 58
+        "421c7dfcecc8b118265912f71eb7b6f525e9eaee403631c974db5c33af7c4006", // 59
+        "7bf517690184d75d3f13cd5647874760086b29bd05c21c86568cd8e26214af6b", // 60
+        "a17aa000037805876ba31e7ba04afa91cbe61ad7c3cd0d8e3e4d31f8a165beb0", // 61
+        "64e773572c532bc56e93fca8152c75d1a7c457084d37b9530951f3ee6ad58c4e", // 62
+        "f3bdfc3c124246148fe03f02399a2f013ebf731e12ee7e9184e8d31585b446f7", // 63
+        "17d1ea24ed78b2e0a2a68afdd8b1116a65acb9e0d1703fda387462078ad60a6e", // 64
+        "82a328d27dd001035ef6e80f7a4db820378b5266972bb3d3392bbfb1728b4e9c", // 65
+        "2435934b95ffd50ede3014fc75963b67b68c9b263e23043b87a20589f27f201d", // 66
+        "1e610dab96d00727b8c87cea28209fba4456d64f9d910cd1271d2e2146264395", // 67
+        "7bf31bebb7413633ea0dc58fa477274d0e2604629115629919d63bf4978fbedb", // 68
+        "bdf5f8bad762e89bba60bf7284f38b525c13b127688baf0d1ca01ecc6512fcd6", // 69
+        "86dd9a32c1d376ffacdaad9d10d6f009e633fcfb6388a615347ab424268e5102", // 70
+        "581a23b9a460b4562c65e9ab0de933a8be043da71138f46ec4527e31bd2c6168", // 71
+        "010fcf947dfe6983c29d728d769945c1f5de945580cacd435415d95fab69d441", // 72
+        "9db1c19e243b24be5d8abf02793337e942c9e5b907ba882ee10c084d2bc12056", // 73
+        "85e6fe3b02252f114efd15fd0b64fc999873f113f80124f83e5342f1c231e9c3", // 74
+        "05036878be2e4a5f53a811b7637307181846b133f69ba7f54a05eadcfa502e7a", // 75
+        "2bcaea6f5fd6cb547871776f42b18e47fb220c54ffd73aa17b648ea5c45f6115", // 76
+        "d49ec9db04ed17952be12fb328580e9d9a91f629a965529fcc35e57f48176b91", // 77
+        "31a4eed9aef27bbf8102c3ee8b31e63eef09cc8abc3fbb9cb571ec2c2fd1ce2d", // 78
+        "2353e6dbbeec0d802774487b31d5656a8983a67248e83504d81db803df346828", // 79
+        "74592800a68472a6ca3d85ec9de5ed0b88a66b966a2c043271ed8f0368e963a0", // 80
+        "e1814262daea7a2a2d01f71521236cab3a54215a711d9caa28c7a4d11e5c809c", // 81
+        "8da563e0be861b0df1ef356168f3ce5aff099f198bf1725073c290f679fd7f1c", // 82
+        "86c461a67a6f41ea3a55f41bafa2c74b4f0a06a71d2b5546eb98830b73022ba5", // 83
+        "48ce23d99b062cf291d97f1df8528cee16cd31c3deb5ce5dd697bc2318d21df6", // 84
+        "83eabf5f9ea98e8f90c2e7c460908cd85b63762a5fc564d519df6a10f332a774", // 85
+        "316487f73ba0d9fd82497da3ff48796134f73e219a5d7bda62854718c1136e27", // 86
+        "d2c062519129fc86d1efc8bfbabf20151cb575a37a733ecdd2f56a53a9daa572", // 87
+        "53eaea397df61e924991b0a0441838562bae2879728c7f6deccafc88fb44aa32", // 88
+        "6aaadc3263cc8046fe4f4ded4cd6af50dfa93f97736f127bcca9a4ebc96f976e", // 89
+        "5406d1fbb81c471c21f53ef94aac00b1090bda37acf5ca4604fa9e2b793a843d", // 90
+        "1217ad0a17ab482b5831210fb6cfa106c5154ba535a9fa04e73a64aa3697a618", // 91
+        "840ebcf6a656106a47fb7bfcc347ab75fc24a46217098b6db2fd2a2f6e1cdfdf", // 92
+        "b3b528aab3bff6203f481badc873d72f532acad30fc9a512d14c49cda73338ea", // 93
+        "5e294bcb7201abe7818bccfad54bd6291606a2c396b24ce87132128ee8ba6669", // 94
+        "baf72f1737ae08616acfbb523f630cdf37f4be8e1cc0332d4aacb59e0b3e2b39", // 95
+        "bcf3925d4814a755fae4e7fab69763c46e6b562b6cffb1c6b8d00309b6cbb45b", // 96
+        "db843c7e816d9e31573e7f1b90d5767f02e207b25a7ed555999f1abea2f66db9", // 97
+        "a39b5186ca0ccdc4b2b01542a25c61f52acd8dc69a180d2f2931715f56251b9c", // 98
+        "a12b742dc1c9eeedc40a5246352107624d47629b95b4be619843cbb2af1a4671", // 99
+        "01cda4af60624d687163945e9b3a8f3e11c2cf29df490e51c498199d858c1e5e", // 100
+        "a10183b9a70f13e29f92b2825ffa39d63d37d46a75c18939d92fad264b19e1d6", // 101
+        "98fec39013437f907a9d2bc4c432e568bfd63ad04a5e0ca663dba968d4313d13", // 102
+        "242aa67f860da488f76509ffd4d4cbd72fa9af9630338c5e6f1a85dab42670ea", // 103
+        "5a1021b9ffc968e68c07d9277a84a78c3910f13d51112a42a53d2476c410135c", // 104
+        "34227b8de498df05e05566ee5b81aaede74df97ea85445926efca35bce65ea30", // 105
+        "fc4eacf21f2eb365b09f7b54256c6617b058175d96a39d27651095b5e5d8741c", // 106
+        "85d2dadf98d36b8d71b8e2daf074fd4bc244b5e1e206fcef3d2b3ad038b537dc", // 107
+        "8ae87b5736d78524dd277bf3dca64140ed41e9ef79cb7eafd671146bad991236", // 108
+        "3eb3d02afd6c59adac857c7a5019e280117132c01efdc18ac8735ebab445a3c7", // 109
+        "210d4010d975ec182cced213bc190e6df9b54fab0542f366f27f1bd425193d0f", // 110
+        "f2df56a3b222da6098e57b2d52a87647439ddaf718709b2957c465c14862d56c", // 111
+        "b1d4350c000aa1fa05cec09b0bc9e95c590b5c650a6c042551d2b8df18a3db17", // 112
+        "981c559e5d96db7bcd4ea65d8663b09425d0ec623fcc43cd56f0557a0ec3d3cc", // 113
+        "3100a475b47317d672b026e82bd8c7c6bf9faa2a538b228859a65b97ea0c3aef", // 114
+        "5f593a9a6ed3f544e04916f3537c2a739d8f0d932308e0312a49f4630b3f48a0", // 115
+        "018ed7db7b1caffb195ba51fcebaf9bfff9c4ec3a64c16c2b4f2f6ae7e694d52", // 116
+        "8a80db887ce8bf7a4288c7992795cd15aba7350eb4edb4b1faa6ccf4715a5e26", // 117
+        "a5b97416ed3d4bc94b7f3cbad6f6fa9c6d5855b553e35780ef7dc25383be2419", // 118
+        "3ef5f68bb01c67db34b3449db3e0c5326f6e8bd20702ca8c5378e695365481e0", // 119
+        "f7d2f54b3d23117f2206b20a0bae892e9cf73816272c729074a498b3f0ce6c42", // 120
+        "e647efe1dc634bf455e3f2e12fabc07f47550f52ae30a85b6d05dbbed1890104", // 121
+        "8ea105e9843d28b12c787b46243c191393b3a05c1a4ffefd9b445667639b179b", // 122
+        "a6525d83bb81ec8f6a09938540c12a2ddd43f456b037e79869f512163c77711c", // 123
+        "5439dcef5c505e7c11d1fbc282007d8735fced8a293631381a54d665d9c3fcd4", // 124
+        "d503f0b37ec87815d0c0ca6f46c13d02e4a65ad37cdb6190bdeef4fe48e3e898", // 125
+        "bd82b647f25f073c47a1e9301f063d3d52fffd16b55fefb0f059a3ae5b3bf3f9", // 126
+        "88c10c4326803ff3b7b0027d8081a85ebf38eb894804486cdeff25bb64f16a4c", // 127
+        "7dc4c67655c2a7e472c7366d1e0fbeabf4f06d4ea3d0f271f4bb15d1284e0f85", // 128
+        "bf0d603c13b93ec69dce269f367dfbf4387daaf95207bef622688ddffb2c50b3", // 129
+        "70bfcd65783719d08aff9130881242c6f2dc3e3bd1df440686ad35b826f7ea35", // 130
+        "cca684ee779a0b29dfdecf2cc316e7f5c6ceff98651b156d29efb5bcc222bbf7", // 131
+        "562e4aa3079ef851cb597cd17de08dea44aafc929441d88afaf5774fe9bd965d", // 132
+        "baea60b1bb02ae439f40aad187f5e4e5626b1e2a907ba7e6f402c2c916aa146a", // 133
+        "6ff591377ba992f1a0c9f2736cb175be329973a454694c303bb538c21c8a1105", // 134
+        "058fc35ada6b65b75b58cc5d6d40eb4edaa049fda37ec32644a5e48a704242f6", // 135
+        "501001bb39120c88f6de0859c882bf4d45c3fba4814c9e8abd50a9fc936f8bca", // 136
+        "cc2d2f02856c8f5bd46fcad41a752467f40fafe13c3bbe882e803d263b4a75cd", // 137
+        "0fee9316f05e6cb5f8beac7edac0f8b7c440b1c44e46194bb9cab057d0486a4f", // 138
+        "78291af24bc2bb857ae595ed340a72fd9d902227be8c3bde2238318df0b1b4cc", // 139
+        "d7b98fd68e4c259e2165ad372f28b687e89c397458c9f905584da0a8f9c6aad8", // 140
+        "d40912e76a0b8d21d6c87cb067ec1f9d96437cc09f48fd48fc51d202c0376529", // 141
+        "b19f06073e20f99363ac286ceef4c7714e40fc684a59ec511e4b247044ed34ff", // 142
+        "c17207904177a6a514b5b746ed4a3bb73f3478cd4ea2c18a366e0997cb098528", // 143
+        "03c6199dc610f5bb6192d05fa4e03919bfb32919b1995c9157d961379ec945b8", // 144
+        "1c1174200a57636115d86c7f02156ea5cb143f4245c00abb940c0f0dc95536c5", // 145
+        "10fea2a738ad8c9f2a89cfe2f7e54d72dfbf853ab09d91565ac0a8a22aaa34f7", // 146
+        "9ba2d862d340d6cb1e330c24081bef4e05cf2a02fc10160bfa69b70135301d69", // 147
+        "d17f7334bc901bb0e7e8c9c377b16108a52f008e9da3fe6828e16008b85cd3de", // 148
+        "78b77cdbdfe4ead58d74f8c91342b6057b5b2a5099ba796c4e72cd2b090b6430", // 149
+        "44278d563ada2b3b78911902c223a036671bfe9709d9ecb17003c923d2608189", // 150
+        "2615e0a836b36c394c0cf64507375d3fe6ec31a57c64d42e3220d6399d47c2e2", // 151
+        "128703bd8511ddd1af5d9f5556d6c60941f5349092e1d22bc30dcb929337e312", // 152
+        "b603acdd7aaca5896c284b466b2c0a162c11135c82a33804b67648b582eda5ec", // 153
+        "f3e82be8e15485a9ddb642025c0abc9a483274ce983ac1687e0d27e7cb3e4989", // 154
+        "e058d543b3ab7c5eef909d56eec45a381791969673446ebeaec9a45679b18389", // 155
+        "6552e8af0b2e0f050080e37309c53f57e46be808ff092b8ba1bc5aaa0eb2ce55", // 156
+        "a135b0bb40fbcea29c5b62a937b010397e86994160d25e7f0021d7622c2b3386", // 157
+        "deadd6deeb565ae407ad25552adcf00b73c15c08aace9285221fcd0958ec290a", // 158
+        "e0c7f885392db363344cafa7b3c8f411e4b161824482fb1a441bff55e2bef006", // 159
+        "31974fd6973b681664df86ab672a3ca7f370360cc45dc5b806df149b50b34dda", // 160
+        "24e2abf71027f4e2ca17228a4dfc2393f224edcfaa1cfa5bef55f34af2506e73", // 161
+        "f7b51af48045f847560b2bf03cdd749dc40224bfa80b6aea4225c5e88a91e3b2", // 162
+        "fabcb67b617b7ec3fa12721ca360a7399a5747488f90dbaa496499b915977e75", // 163
+        "46eb338f696d031c00e3c456f48b6bd9def49a0f2e257dc0bd7b6464a57b742f", // 164
+        "5c575cef3e96d347a8e222cebffd09c453a653fe8679fa8b31e88c5461f96c6d", // 165
+        "42fceedd11d1e9d0f11919a1023b2cf86babf2ce5551532fc60f284d125b110c", // 166
+        "0e38b358c2f3e310f22460a273ae0c6eafccd2f7aaa8dedfc0b702a5f70415ed", // 167
+        "c5b79acdcd4f0c85669bea992c52a3f4ccad905d8fc53b6190a39f5d5a9688a4", // 168
+        "7208b089ac94466e37681084a9b711d81fa39d15b5e16a04f5a2b6267411b693", // 169
+        "8e4c1efbf04c026f43df9d50a39f1504b782cc592150b08b166637608932eaac", // 170
+        "4872e56307cd6dee4248db08534e8c1d4738de2d2b8b7ae2db8e9d7e07627e15", // 171
+        "0226ed2cf17eb4faf7dcdb02deecb78667cab98ac1c4f37c1a14a1205ad0d2b7", // 172
+        "16861547ca60cb6edd6b42f1192cc6c552dcc4121b7f021e052c1cbc8c5d6a74", // 173
+        "16a74858f2f5ea2ac333f5456c385c0f0339409747c3795dca5fcceecc5c1bf9", // 174
+        "cba35bea5e1ceb542b71958b877c84ef603753f13dc79ec8aeea36270cd1496a", // 175
+        "3a11c58b98970b05b70dc2e2d156d1d9404cebedaba2f5c8cec852e113006ea7", // 176
+        "2b128adb7ae52f24040912d2b80c4616a27fc5f678afe78cc7370ee5f188ac83", // 177
+        "7cd3be86d0ea0e10dce72e19ba9e10a97879e61f2e5b133a40fb8a9847812463", // 178
+        "400470300cff28f3b477673ebe0f3f196c2936871f1d99f063d7dd0185df7ad4", // 179
+        "de388503730463895195405b130c56ddc73276c83702ac4d0b0452d962403a84", // 180
+        "e20665df4a3287b5551e1c43ead372bb4469e3d46b5b7c77e95cd2da34a0af68", // 181
+        "ab1e2e11071489f48b7b0c73416d9c34bb935e0229ffa69ecf216aae7ddb1f85", // 182
+        "ec559d1284faeb82c589472628903c88f38ff7314286cd1df57cc00f10c19677", // 183
+        "5e478f1d2c01d45ace1bf9ef081aff22de7ed48b1b553279ef63d44116159779", // 184
+        "c6b4a7af0dac274da815a62dde8a4bc7967ae473388f0dac2b28873a5f9ae132", // 185
+        "ac88b377ca63165821addde7673a4fd4d5b0d9519890b6fecabd8c4f8349ec5e", // 186
+        "a55233cd4185c0401bc3745547eb4b871911ede70a344cc6906ec330b700677b", // 187
+        "fc88c7b8588400592349127274ca11c13026277a9d9b0184b08cbba42a019c66", // 188
+        "0373e9bfe1289af24b53e461cedcc1142bbe70073a288e66d9b3c07cf8e07cef", // 189
+        "1ed332cd53d373bcb3db8aa29254dcb822ba8d9afe921a34e6f2ffcf20231055", // 190
+        "fc9e393c3d266805ab762acf8806aabb19d672a43eca7cfdf9274991c4b3e16f", // 191
+        "dff4e8ef0f410bff8995ad43b3d464618ab51eeb9e3d863a3646f368f1b8e7ee", // 192
+        "b3ee4eab652835801eb48ae198ece8efee9fc990f40288010e669bf4ed1337a2", // 193
+        "c67319cff6a71780469086d3aef647adc50aa0bb41bb4f981148cfb4b7e080ba", // 194
+        "173a9194985c7dce5cc33f0e9e14871ac79a2aa8efd23c5a9e65b9d7c15905b6", // 195
+        "056eecdc5377620e65617b4db4adf220f69d82f27480fde8c7b3aebc8b2594f4", // 196
+        "d3ee7aa7555f494cb0e6380b3ad4c79ac6f71c11014eab167e1ed9d9f36d3c65", // 197
+        "ff2bab663c955fee9aaa72277710cbf0130c408b2fea1f5cd2a91c75e0d5a69f", // 198
+        "bd28eed89f488144a9825617edbb52a203b994b9147d8bc68f405cf7a21c8b70", // 199
+        "2f7089cecace8e8f25b9ef7fba82142a78a6b332670ef50787506bc29d186dda", // 200
+        "cb979795d1fa61c20805cb837407a9c11b14637d5eacc61037c8c4ea15483acd", // 201
+        "0e44b88a11231ea94d8f2a2a3c8dd038d061a027446ed37b12658e69c87e2a57", // 202
+        "c18ba1d07105acd52feb3fbcb70fc434153c6f13b3eaa3cc2c0ea3d1e14de7e0", // 203
+        "644d37f3ea0b44371e1094ee72fdf22fbd4399acb8796f447c2f337a79f0e2ec", // 204
+        "f7c47d15ceb29f5abcf716695a901537a79b7dd7ed5d6916a8ae92b394f93da5", // 205
+        "69be24a8d3132d73fe0438e205f7eb86179fd4983084e3a56fa38dd5f08dad6d", // 206
+        "c87c99a30a9fd5463e625047de003671b720b66a13e5a6aa0f6b5a5402fb287b", // 207
+        "876690ca2752d5cd4637449e6d2747d9880bc29c37773dad809ee3d451c7ee71", // 208
+        "1f7ed705ee86285c3f2525973c91180e626215e2a36e3719c0d65005b244507c", // 209
+        "898ca89889c2855dd16a3b9433c55a644ab9f0c6e546606ccc9258418b782354", // 210
+        "9c0c37b33e6573e3cff2e1a72ba65252e85ad50679a2b64b18e41af58327b004", // 211
+        "5cf94757a30c8a22440742dca3a5a1d9e28aeab5f0337b3ce325719b86f6c1c0", // 212
+        "99e8465d937cbef84ac3416e3e7ace652927e1cab5b7e387b04a83a7da5680cd", // 213
+        "24cebac8ced2272c63ba4594552b6362cad523b5ca1d428baf96246cdd93a4a0", // 214
+        "568d35931160dee23b0df800ea436521a4af0a66723420e36317978da9a30b97", // 215
+        "6db731cf16b82025550e8c549009f592b171beb07ebb1810176d24536159c16d", // 216
+        "130e0617f96492cc368f36763f5f12c24c16e7a7da3edcb80c274835be1599b6", // 217
+        "d68329c81a5759b64a9d00135a381ac2e493812fcc419c88ef08c0482e3a91c2", // 218
+        "bb72251a5c615db2e468323c7db6110480ed3390fe0d7dec515e4e53aed3526a", // 219
+        "1411c111245f850b35321799df1b569765fde24a7de907c33949c1dee69aab98", // 220
+        "368518da8efabf6a9ce87d52d47c4da939ed1d0e030012f137f8a4d815f0238f", // 221
+        "aebbd7c43cdc1ba4628b61235d4ac78f71c43d947dd614b01944e2c1fb8b6bc5", // 222
+        "6d1c45a84dbd7ae96e3f66f3db951ca3577db4350f389068499a132dba35938a", // 223
+        "2e7a9ffea0fcfdf4e4b050bac55d199a60a77031488f74a58c22baf7d580bc2b", // 224
+        "c91de1551eab41c41fc34f8776716d3c53594b696f0f7176d3961d5e9456c03f", // 225
+        "f829a5eec5064d00f07627d141bb81ca15450d0374a92af505d2160fcdd97679", // 226
+        "d474ca71dec7e61d78235918406967f09b0cc97c61842e63eec12bee66b69857", // 227
+        "37cbd302905bd57d0a456fe572c7fff5f5d25418e25c92bca0cc3f80d23d02e4", // 228
+        "22e9c1b2feb2f2c2095e9cd7ff860ee294e1c146a96211aa4b653ca294d179d5", // 229
+        "9872a725b01bf523d03a91d7f7a7da75f41db0b6ddf9149f47d375a14b401e28", // 230
+        "d9b2c793349ce82f47d6c3e0fa6df4c1ff4dc939f500a0d7d606c6c2c53b1f12", // 231
+        "a601b506d89a4457580c1f171cd63f66db5a88d9d9c703fa5a15d011f5f5ec86", // 232
+        "95ee6df58d9b713c606feb058187ae5f0248c158bd6c2ac1beeb73f4f2ac5941", // 233
+        "48be344dbbbd653b84cd50fe49cd7c2bec4939b8b5da037f09fe1629a62fd67a", // 234
+        "46f8154b9dbd3e3278edc0de0b84071e72d45ba71d0e85b76056a61017b12f5b", // 235
+        "6461fdac9d1ac96e5812b06a0de57233ee36f32adc007b9b7a5eb427707dcb57", // 236
+        "c8aa0c273d39c17ced5e4b37083dfa5f27950af48cea9bb417267d0c2ec3f322", // 237
+        "4dff568d3a34ded178b26603333e04a65050f080fb6a093edb39d4229ad1bdb7", // 238
+        "b2e0f6116c49701e94bcf8e5e570bcfdd6d83840443296e01b57a72d20f079f8", // 239
+        "e9cf5f32489daa05e7a4133f76b7b0f85356e1eb48e407eb918baa3faa02eb81", // 240
+        "691cc27a8f77576cb1cbd2f388d4c171d4d0e1fff9c5339ef52b6db05fd91226", // 241
+        "fb643675edf15502d17fff8547722086e89b6a9be27c0103e652eda7505be73d", // 242
+        "fd43221e1e73fd5d764c33e785faf55940f9f33969bebcb42325d81b7e2a756f", // 243
+        "b7733b0ff0c60d3d7329320a12e0cdbf6a29faca1ddfa913ce83fc285d392721", // 244
+        "252ecff29b7cc36c498047b82dd1a28ad1212decc487cc5680ceaeae7115acf4", // 245
+        "6d141f8ae579a7c51232da55025160b1aed6598d16b8158366d1fb33a23f67a4", // 246
+        "7e7dd905cac398c0b3f7e5dc23defd67494ca2264a697beb49550d2097136d59", // 247
+        "30279bc49c1c284b1fc4401314ca21819f488b32b1f18ebe05719e779a00765d", // 248
+        "e553dfe2ca3f277d72f51e6265fc8211400c3ac7e487aae831db2b2bded0a2cb", // 249
+        "447aa80a839a35182cb6214c675dc0eb1e6e7064729d5a06fa657cb8f01c7446", // 250
+        "d4e99a00eee2b7ce534e628404f53b53bcae04b2327855b4f5df74b5bfe8002d", // 251
+        "dff3de0063213a4b812d89347f03246703ce33e515994cd96ee3f7138b0f36d2", // 252
+        "cc342400b2b7b164225388dc4d1d149c3f67dc366493732df8029375cffd89af", // 253
+        "9b3d9653a1db863fb96e0b6a3b37022f5e7bff02618322b8c032a0581d7d83ea", // 254
+        "7e6f6e26bea38436c3ce5dfe4ba856dfb32b8d194bbbbd2d00b5c604743f780f", // 255
+        "b74d4535d8cfb552c9006f48cb3cd42c5dea08c135ae42d220f4196cfc3d9168", // 256
+        "aabef54907a9090b9429cb5bb03524fcc533c961c4e376d11f3a7146dbe4ac4d", // 257
+        "e605b3d69b050be26a6984ed935fc6a9f9568e7e908f32f63838fee42077ceeb", // 258
+        "035e270c938e7f149100dadf9bff0e72f97e94bbeb420445271f33b208704472", // 259
+        "7f845ddc4daf21015d5e8dd63e44e08a7c699a3834449d0a18f94c29fc680b2a", // 260
+        "b8da3d970940d4d1e64d186582554f3d5f383874576d8b499deec72ed5ceb094", // 261
+        "3d4ca891ff57f72d1c3981ff3e63282210f55cda6587f9a1ae0fab251d99b930", // 262
+        "ff3774e8f697f437f44a7a752951a291c032fbcdbe57228596af908293caeab8", // 263
+        "9708a0f4796bfe6161a6386696bc09f4535c579bad4da596429df74dce3fc24d", // 264
+        "8365379274fe7f361b47e51d998ce85a284a02d5e0b231435a30c0ab80c62e6b", // 265
+        "79e847e5eef483de3f8e085dca9384fe97057d1108ba882064df074f684ed280", // 266
+        "826f07cd7f2d6a928339a246074d83d5784ac82cc0c10d3226b7aacd992fc5da", // 267
+        "f13c66ebabac42a0c8e219b7309d78b6817bffb32eb213d8ab7e330c3a50aaf2", // 268
+        "97a7e6dfd7175f63151cb51734fac45019398b9aeeb7571671aec91a910d672a", // 269
+        "fbe5841485121c4fe511f55ab25a9dc47b0cc7d87a5ae021955a9955f60fbf02", // 270
+        "be8b6d213b795dba77a88bc34fecd5ecd33323efd0ea2be30705f5d1ca41421f", // 271
+        "a868df477e091b32ad22f773f732452ce5a19c3160ee597daf7f839fab617914", // 272
+        "a98187f9c639cbbc829a1a7c4c4950673d998d28b42afd705f96eae4eb80dcff", // 273
+        "ce0ee901788bb61aaf867d2a7cbe10ebdf2b2dfa8408c8c43e70167c2daf1e93", // 274
+        "087f2255f7e513ed89953cbe349d0b2117d79e93e50fb69192aeb0b5c219dbc2", // 275
+        "263a2502e08ffbd1faf85c396bcae4364d1f0d2ea5ba676a1e8cb6f775c0fbc6", // 276
+        "47da964608f837dfcb7f9053e79fe6fc4784f225feaac3387c3508e70ef65d82", // 277
+        "74106d9696737790fdbc210ceed5222f0082a7faffb5e58b573c0025c185b474", // 278
+        "157fc83db6e3a8b8c71109db372c78bd92c90d064f9cba8cd57d981e5cb465eb", // 279
+        "b6ad1c8237ddd71c9c67682893cfe897aee89ffff4885da8726ee61458594a45", // 280
+        "911c5087037fdc5d05746f9e66d9174a503afa84ea7b6687a7b05f5d806e986f", // 281
+        "2ea043cb75fc3ae42c16406ec7b936ddffc630ed1973f99b780d59b356a103d0", // 282
+        "94f8128c5dee56949d2ea2e57a40cb02d98352f635a5937142bd2f315aa8bfb5", // 283
+        "fda0f90a9a096ddc5d8fd3ec8756197de4c8cf3f97dc37963d26aea1a761e926", // 284
+        "a406ce47f2f964e30ed3f3a9c791fa191526ea340808b2b16d2dfdb2bcc92b4d", // 285
+        "5c3f8f7f355e24a037ca66ffb42ffcf54ed8589159d0be3dae4c59f12a67915c", // 286
+        "257956a5967b573413717a811d04e90cfbe436a19a923cf085b6d4d661c55058", // 287
+        "6acb790acf3dd87b7306e8bd4b76cf793e77bc355175d7efb608406c59a3d58a", // 288
+        "bc53725fd83c34720590c87989ba08dca504243bea9157756bb7f03b465c9084", // 289
+        "36d78a579cba14e2196c9198c8ba8282003217abfceb66fb3ffea30a0a1d9580", // 290
+        "3c2114287bfc8dc9ac56eba728f3c66d61f3536afd202ac14c5cf14a4ac1b5fa", // 291
+        "8278fc4766c8773aebcb9a44f1dcc56179d9d70865baf8c465f7b869bf8bd15d", // 292
+        "33dab0af25fe6e480c69819f26843a2f6f817d3c90bb2f192f22e5f4512dfa6b", // 293
+        "ac9a3acb61e6befa576321ec16310fb6fe77d5c062ff8996ed0ff6334f285f45", // 294
+        "ade0f9efa04ce800b80ea38d9493aea2c0bd437068c989027711e99a568e9ec2", // 295
+        "7abb99d27f323c7ebf03fb283f516d53b9878b975064b0a7ba6c7fbbb2e2ee39", // 296
+        "059647da56994de50e8bf5bd08059289edcc6c78f13801450fab340a438d09af", // 297
+        "06765a7adaa534d4252eaec39606b7e3d76c8efc98532b7c913965576619a0c2", // 298
+        "0e6de26d1ac16dbfe5246a553d86ed9143c1133f06db958a47d4756a007cb483", // 299
+        "5ef3038f719b37ce3681a57a7f907aaef7195d435421ce34a4f945f737c8b1e4", // 300
+        "2924479b5ceda18ead747ab6c6f5dc56a6517060f4e391f2cb008ede56d4400e", // 301
+        "036d1a6f39649f8c6f99c30d8b0831687e69782bb2b025cc3e1c9d67fd1bce7b", // 302
+        "7cf30edddd01351c842ef2d7cf39c5c4a093b197d9214d18215822af1cbd1a10", // 303
+        "602c52466eacb1b68d8e1f24437bf6220a7d684a1f7dc3664f7c8801229e3252", // 304
+        "c26e7eca9fa39d11fca39da7eacc32eb471f74c2acb22edfa2c42213b73a26da", // 305
+        "5f5bd0e1eaeaca227739ae94796feb0eb3138313472cdfd07b3035d1bf33a6dc", // 306
+        "da5ecff1ea70691ae8c6365c1aab573be92be42240497f2d113ca628ab25b9ba", // 307
+        "811ac518bc50edccd3bac0dd30a141a83cdc015654460f3cbb2aee42e8759959", // 308
+        "1f2d95fd010e0d9d23c9b9d2a6b1d7d89c37a107db491cff89c94f10cd965fef", // 309
+        "76fa3362c0d4e384a8df239c22421d9c613a1a3670bea5c119bcc90e7b51d285", // 310
+        "86024733839e4a3d44fa1091c2efd831314a910e496783e43797d8b37376dc3f", // 311
+        "598b399c564318541ca71ed778187871b5dfadea138b059567cb3ed76c98a591", // 312
+        "0c71587e1a14a6c1e9bf8f0c17b76ae57c68b2a1b130bcbfbdfbc921240d3dc7", // 313
+        "be64b36f3348dd66efb860e91183a56b94184fe4a3733547e844afdcf35ed892", // 314
+        "e521352191280ceac26751076adbf6c2aa868d70d9c4ff27e49093a82379bc96", // 315
+        "4686235e85de3d46b2486f47e4896387553ec894b9cbc32ecdd696bbd79788cd", // 316
+        "518b161ee6e0d399e98c8f9d2d5374458e2af5315bb637e05482dc3698321209", // 317
+        "f9c7e21551a021986ae09d5e45fc30c361bafaf5937333389fbbf445146977df", // 318
+        "88885097db82fbf48b41942356434d34d7de00efce4e93bf5ecf8ce1264c33be", // 319
+        "3f41eadfe149e6d1967e873837d19e18d27468672dd3316eede007a00626d298", // 320
+        "53f0e35aa1fed980810a5bee1accd569ac6ef98587170b1d641bb5407796910b", // 321
+        "3c4ad8b9170b711d2676d173e7e4cb277120425e7dda7a5e0a7f9d1d436cb87b", // 322
+        "76cf5e6717b8abb955754c4174cdb6a35532438eb21062c65eaa1704996c05c5", // 323
+        "5def760e863515e64f22e4efeaa9913d14e7e4b8442ae6f4b2c5ea1464106552", // 324
+        "eb12bf5d78da05594ee95add164ba6b8d5dfb64f0db4836f1aa07b700b8c2dea", // 325
+        "55edc7147a918f5365289d3915d23f5c94b06442964d00e6190da599e13ca146", // 326
+        "d7341470035da29b637022ef7d5e9b360e4d23e1add3bb76c835ea9938b4fab4", // 327
+        "8f8cb89b8ddfbf2ce3827e71b1cc133dc6de9953b4d931dc63be57c4de621a6e", // 328
+        "26a528bbbcf628c38eb253eecc3aab14011b5a89413c2a83b5cdc21b6bab5417", // 329
+        "180dbf2dbd4a115465920a61731119f210138a0449f2cf905d9983644637774d", // 330
+    ],
+    [
+        // string 3
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "28969cdfa74a12c82f3bad960b0b000aca2ac329deea5c2328ebc6f2ba9802c1", // 1
+        "6787148219b9b556566fbcf1a7638b356b15448f1e6a1aa0a6e63ffc036f8488", // 2
+        "2e19de444ea3b5d5432c46c971ffc424093da2ece6052bc80a5ade489bcd9d84", // 3
+        "a5f6cd4ec47c191288afd68b4b7c23b42a043524d5e93274ae7d00e740946262", // 4
+        "60d57621f315f6d8cb167a75690b54d8a0151761c14f6a09c89dabd372e5832d", // 5
+        "58e99eb2b8ae49aac0fd1df14adb3bc50cdf9dcb9a21ea626d4ad654ce7784a9", // 6
+        "235168d888298d46ce8eb2ab998ef13645b17ba59086e85d9765981014104397", // 7
+        "450573cc1dde4e402e8042d4bdecd9ad43e1fde15883c51aeb45f267cd0a01a9", // 8
+        "42a2f624635dfab8ac8701dfd33148e901a2104ff54e2bd557bdf6c756b5c8fe", // 9
+        "b6e9176c0b20010f94d1a39ef7f1a4dade47bf9adf5a2d6356f8a5f09a10acc8", // 10
+        "7b9c21ddf8a71f5806a02e4d6e9597741aea076668caa2356bc56118eb094c01", // 11
+        "369e3c28354e14b4b1ddd15b1608ab28d1b47a001d9006410ce894cd354143a1", // 12
+        "25491d3561c445f3b361b0353cbe06a1b717f8d54a45be936a2a7d3a18e5f9a1", // 13
+        "502dc200d32ae761b1f4ffabae11079ac38db520b1be6987797da44bc9315633", // 14
+        "7e71ef9d347f129270fde9549abe6ab0a14aa6124a601ec19a6f47bed74e7695", // 15
+        "b13c94d85e9f928f414eaf4dca561341a5a11f108f3eca038eba4ee49ebb4888", // 16
+        "19e178ea777f125bbf2deb3dc0ce715b5a1940b8a431f5339c5a92f199739504", // 17
+        "01a3ffc9eeca5584f2a1c2f1e918491bed24fd898463cd7c9b5a88b8c07f78f9", // 18
+        "97bf28d2f63f0c2d55d7228a0b5bbb278aed7722ea6f871f49f4a218754f3187", // 19
+        "42d335745b07e7483e88c7e252e86d4bc4c8cab1b781865acb61477b0be2ed6f", // 20
+        "419fe6dd0c78401b8a807f74e5c80d54dcbd8c13775d2f815ca77b21802a742a", // 21
+        "56c1038e8a12838da5a9870792f9f0568833e45c7376dd23eed168d353f61a88", // 22
+        "92771a7d4104aa57212553f1dc28ad0169843dcd00f1b59f81fff42523eccfe6", // 23
+        "f114442fe8bc7f64cffb3bb16abce22c1eff27b208a25430705fc981435ae7aa", // 24
+        "bd3702206c966fbad652a09ff4ab46238a787c5ce9cf4e812c48c83adb3a75e3", // 25
+        "e706e4479f589e31139787be6383d0f85ccfc55e40184ce703a7b9f37e165b10", // 26
+        "e973d428d6e68253e3778c97021971d0aceca8e3e0666e96619a93a00815fe8e", // 27
+        "255d609f3788fd7c0c0010247e4f53c40f4b59707de5c9451d61dd2d186c7e78", // 28
+        "61966c4de7576d7a96741f05f0091e62c5b7fdc4fcb4cd1a58f6fbcd937629ff", // 29
+        "eaabf6f5f59b596fa805a9d12dddbbd7d099d3d5e26352d5c8fb80748aa5753f", // 30
+        "f2addfcbcebdce7b2c9c603837e05385309c1951c6e650ac8a2114d1c1d964e2", // 31
+        "a7206a5c6e9e715baf5f80ad4fd8f0a8312d2eb9e6c02ce555780358b0c14001", // 32
+        "609445bd2b9d775b28d98824c580527f1c56a00779022c1236fc6e04c9c04117", // 33
+        "e313902a813609e6c6fe45898b0a12aa28e527e0a739697ccff11bf59827e7b7", // 34
+        "893e13ad08dc33b3c86fe24073b22cde0e2e35618c9994c64c06f4e3ae16ee54", // 35
+        "e44d1a58cc1235a77d3d3b0e754f21b55f25e570f7b9061c3ade1240c2f8fb3e", // 36
+        "97e88780c01f05e4a7f1f0cf3770b63ad18607d47d607f3878ba9021f475d9e6", // 37
+        "6a0b2a4cbc82f6d0a2d5de40fe8b19a386d0e4041d5bbab410cc93db2c0af4f8", // 38
+        "b0e31a86d734ebbfc200de19f1093bb2b3ba5e53db7df482009281b401c6f78c", // 39
+        "30e7353f8123bd69047541e32d3d1c6b31b2d06d9b48b1008a9937468cbd83ea", // 40
+        "e54703660154e51a1aa9e0b12d21a9b53bfbcb1f07e8abc99d520662217e7f3e", // 41
+        "d7df4e52b58dc4da32855405f2d6e3df47bf4faf3290adda4b13f52f2ea668bf", // 42
+        "3dde7d9c947f76cffba61328bdd526d46b8648a9013717aa67f6fe229e9ed4df", // 43
+        "60451b66024bc5404e8fadf7f5bd21c1445f81bee8e8b9078bcbefca5190116a", // 44
+        "44216b395fdcb9510e22b78b45d77e11467c5483506242c18dd78589e74fffa9", // 45
+        "69d8918f4eb71a62c5f1e2eaff00d844ec1f4e0d879ca6373bd72f4c6afd4d7f", // 46
+        "742a231e5dde0d7c583f4b13a84b32980185480fcab0225df80145c7aa59169a", // 47
+        "b33fbe361ae661510b451a80286fae533a2330dd632e26a09159cb046949a84e", // 48
+        "29be021366a68749a96637ec77794d2476171e2c00138a5698f04b896e7c0947", // 49
+        "254547081a40dea6eeb4a87a561886db3051af72acdbf49e6704bb2310d990b8", // 50
+        "738403e867a27de44c6faf5128e957703dbaa2e041c94fdba32e3bd8b6af2dcc", // 51
+        "40a2303a9c96eb3a4a2be14f9600ec6c3b8a53d2faa81dd58fe1ae996f6e9908", // 52
+        "146002e885e124d3e9fd581b6cfc46f31299a20a8a94ae44f97d12dfaea6763a", // 53
+        "ce5770a6e42a0daee9e60ec06a1f93aae20d6d51eee9582793167c429c829a00", // 54
+        "47a408fda85944d198ebd5f5d24740932d2ec7bc9fed6e70b1efe3e30f741531", // 55
+        "0baeedf6abfd66a4aedc7b62102327a1781642c8d8bfddac957f0947b1ce2a29", // 56
+        "c12ccb034ecefc2768f6127e967fcb880d7d3ce61130d61ea3a4da8dd7674c03", // 57
+        "8671d3b2b653984924f078ea1b71bd483d7c9ac617eb652e9e93c568b44297da", // 58
+        "85b56a1c35d19c3f12968dc3ff9092932a9e253d1149d647c6b6434cf86a58ce", // 59
+        "cc093c1e43b79bd24c06831d67be1c5b029ece45a990dce9bf25e5f3d1f5269d", // 60
+        "b5a53d81d4f37ca4a506ad995ba623300355f6c68b730385db66fbf548c33bad", // 61
+        "cb1edcaba7457135ed3ffee68d0c89ed8cdbf092698476e6907e2b329ca147c8", // 62
+        "3fda7f9be6c674831e148750b0f899600bd6c5c94d151a7c3b81c644e414a367", // 63
+        "39278c42519ab3be42afd91a6f8dd7028b3e8b74635704f6c07ba184046688bd", // 64
+        "9da6060c48d3c850968d5662f80fcba89ed115eece795ae255330dda412b19c7", // 65
+        "929f6134c8545e8a7f6fddb9b9f6cd67f1eebc34414aa1757e28b713006f5663", // 66
+        "d6dc8f76061ae6ec57baf8bf076d3b406692cfd981d8d3ad130795d889bc381e", // 67
+        "b5898c520fa903ef40fe896c56095c87d48e0e89569640922fe00cc60f7f63e2", // 68
+        "c05f4e56c55be6668540965b49de5474dbb39c692f7f445bc0de40247efd650f", // 69
+        "627c5c6df77729329d99f69ecdeb63f2b22c2b6f1ac4e04407aa5f35cc055435", // 70
+        "b58aa5a729d97f620a88f454ef0094e175e108ac068022e37f6f0fb1008a2784", // 71
+        "e577530dbcf5a7fd30039f0e8fea874dc8bf36e2a820547ed245484bd672803f", // 72
+        "4377c6523a4d4e9d4cac47f0f4367531dc5f01eb0844db040b84010769be8728", // 73
+        "53e2f58feb7c35c441eef6dae174912f98cc8e47a1b67cf076cb4754daeeed56", // 74
+        "c32e6f572a8102a283dd596f64bf0808439c58f3985bf95ddc7e3381f6e6469a", // 75
+        "333b3b108f366b99103bd672377c39ad0651602cb9f7a21dfef5cb052c09bdd8", // 76
+        "9e7b701f792ce23030de16cf3805e93f38f01e50981ca487d5be73351d2b267a", // 77
+        "b6fc1b3c803e1c26643e366c1573c36430fccfab4f7b2acd4219cca6141c5a82", // 78
+        "fec079e9e87fb26faf7d88681d65f78916662dcc344ceb595edc17090f53103a", // 79
+        "3aef0773c96ee51dffe1efbc19eb9a3a216a3c3bc6d4bbab95f01080c5094a30", // 80
+        "d851aa790479544e0b61006d9274726d07fda359cf56dfc90a8011ed0eafbae1", // 81
+        "e9d12504ccb1e56e5460a6abf08f4a57d7430cf50261fa0e10c6f9b648e67303", // 82
+        "80629ffc47a6195e1618f7a4f9097bf8995b8bd68bbb0f8f36e67412d405024f", // 83
+        "69d61929b43508346228a5c650ef41362eb4e0a411db959423e3a729b6291f59", // 84
+        "5b67a3b636ff0e17f81f4eded5667a6f3bc0b83e01b7b8285cf75275bf09c8b0", // 85
+        "9bb55836c4b19ea41cc255bc3f45ae8e81c1912060f50089700d2ca4295dbf2f", // 86
+        "cf8c71e1c2e4871e935d76b3c02d80db776fd1ddea1ccc4f64b483bbc3d4bdf3", // 87
+        "18dae80b4046cf42401232a7f15836bf4d4574b3dfa88822eb3075cbfe8ef54f", // 88
+        "431770ac8875160a24a92d98fb597fccc872c4f2355d7d3cb8814b146b86e505", // 89
+        "18f59b9b59f22f080c42b3b03ae982a4a5eb384d28b83e4e8bae7b54e13b86ac", // 90
+        "6346f814c8fce5c2078d6a0b067bbf263da9dee8c22cd080e5a629129fe58819", // 91
+        "a50de7b130c4ba11014d3f74004a4f590cefe12788745af0195442c238d0bf5a", // 92
+        "a39a8119aee0e0650257e42f51d1498eb505acab8cf102ce2d6c7d06d274b77e", // 93
+        "e27ff9ddf9a3afc835845cb8e6e79f4d5e9050cf0d01e369e489c9a2009ddb90", // 94
+        "8924bcdcef46a7f167634bb572723272e483fd41629765f09889978090e2f7d2", // 95
+        "a6cfb9c6b2411b871b22b6ac2be12a361a79c01ffb6eb7b2cd2709b3e4e8b4b8", // 96
+        "9131207fe126c26b2a5eb4a7aa25e7f727b6efb9e8136120e6aacb7cf814fa9a", // 97
+        "02656448d24c35c10dff045f5dd7ae4e7b2a3808b818980a52bd17c71d5d6f70", // 98
+        "86bd581a0f0144359f4e02462349856872a67a0df597338359d474ae7f4ff39d", // 99
+        "34ef57642e3a6544c9630cdbd3a6a5acbef7deb6a57cfba996bd818372286bd5", // 100
+        "d25a89cf94d6f505d5b07cf8cf0f4ebf847faf87ae92ea7b960eedeb05eba1fb", // 101
+        "8bc0c81a1e7e98b5d265e3463d5f629a7a4f5000b0705f89594f757b5a7e8cd1", // 102
+        "25d3be269b51f34275c22040987b9a1d53fa837d5454f107dde05e69ae5a60bf", // 103
+        "b618e44238b8ad76ef7dd70c58b02621fbec7676f5ab74dc979e779b079d9135", // 104
+        "8be35d86ce0d592ad48d845a0a01861b2fc585349703e911a82990262358091d", // 105
+        "e87047c7c20bff0afab0d9964f2507571349045a0272be24a863245d400867c9", // 106
+        "a484f1ce0ac633b9a96eb5a4ca883a388389f2bfb5246adf859f452db387bf57", // 107
+        "a8f7fda99086234d5581c87cc9badf5b3605ecf0439c29cbb324f96fa23abe40", // 108
+        "2b6af9f5fa8ade83612b0f366c924d1c4cea056918a54a2cdd824f63f4cc187d", // 109
+        "fe2e6f4dd187f9183c14313ca767e3655e57d39b75e6d2e8ff88d93477061b20", // 110
+        "8834b77eb7b28871e2d68e096198408b6b9c277a764fb4f083338214830699e3", // 111
+        "116ef75c2591084df686776320306585e691654ce0cd983fa7491d59cd17196d", // 112
+        "aaf0e65563afeaea7e21960fd8f5d29bab0f2cd7451dd97ab312c92853ea981d", // 113
+        "587c28d047c1f6f0857612f81c40b87fc574f0595dc9ecbad34cf45ce12a56eb", // 114
+        "8fd866dd7b175d7c11b82fb294d8b764a4ef4ddcb44f5cc35b121b623e72ad0f", // 115
+        "75ec62516fe1d82128a14464d03798bbe0f3ca26ed93827c2600ab4b239bdad6", // 116
+        "5d6e70752524943febd2adbca38fe8a64b25f97cac11b835e43104c0f2f58de6", // 117
+        "d9ddc28ba04acbcac2f03ad16dd3e673cc0367fc8223a3f775e131ada6b3a877", // 118
+        "03cb16133f1a5c5736145cf4f4553624b53f30ebd0f0a0da726741921c92ea0a", // 119
+        "96126dfbe8905525c85b0de7ccc0573094f0cc51608c36d7eb5898046d633603", // 120
+        "8ca8797074d31bf899f0f44d4591940f2e166689a3cc04de64f78c0fc5d685f0", // 121
+        "ed61f1311ce9a1dbd7f8c0b2e9328c42dc5870e0670680a2e0e959c1dd1ea388", // 122
+        "5b4a5969d2207e62ccf3863450fe67ae1581e5ce21d21ea973edfecc0f0a3dd4", // 123
+        "612177a96d842bc73ccf061939184d4c636ed6ada1dbb043b4f27c98604de66d", // 124
+        "05972025744b8bb62146e977a8efaaa7a489685c1bd4d99b0e8838ee7d05cecd", // 125
+        "b137a32fef65e7b735aa705aec83f8ad38755fec4592d4766518ead67aca856c", // 126
+        "df7cffe8c4dc127366cefefbff27ebb50b637a80ff6746e117b20289a6bdc8a8", // 127
+        "90438bb876d10a8385d95988dfd758f95ff1c97eec2573b1f3d25254171425b4", // 128
+        "9823da09696c1ec77b09ce0968fa871d8e93163deb37afb0476816fc2811fdfa", // 129
+        "d99025ba17a385d885ad4c2c25691f957414f9216b7f883303f211920216c581", // 130
+        "d24d7b90d6fa6c0b57822f6e27c74fcb03fd3e2415894d6546e98b0cd1d2997a", // 131
+        "338a9b86dd01121f13490d119fd2b2fa5f6ff1b780f159e4570570824178005a", // 132
+        "26ee88a336dbfdc28c35069e96c60fa22bf0a89080f676ff08d7fbcf0eb9c570", // 133
+        "3c1b95f0410d73e3a481ce4412c782fe41b776ae43e30fdcf7092fc6585087b5", // 134
+        "1069482c77ca03fa07931920625988a9ec44bb48c57d0d798e884bac5dda8f01", // 135
+        "e967ff4848dc1feea230b10947ab06b0f93f3530f99e59dcbf789b8f62afe616", // 136
+        "1801016722cad3812762db10b6e47731ea59d6effcc79a2a8fee7b8b5b75ebb5", // 137
+        "f44b1bfdfc23fbc415a2eae8ea9f7f4a81d1125598a71782561be8159afa4324", // 138
+        "9d79485462ad34c558343441b1df60c71628a087ae887539f0c22f7b8668604c", // 139
+        "87b718f4fbf486f046423a64f641a9f271afef94534d61d57ab0d1227ccbe2dd", // 140
+        "eb623182d984815d4ad4cc73192a9b1d691ec935dbfcc1a462d05a83bf768c08", // 141
+        "718f152c2cd30e59914e70b925bb48f71f5aed61d0fb99cd2addd31e8175c32d", // 142
+        "b032021eb9488645c32a03c7a34da6e66f57cd225833960229ed71f4d472a55d", // 143
+        "d5aa3077ca6c24f432a43acd383ac665afb75ce307130b3058195889c296c948", // 144
+        "b6894fa63a854a1e8f38b0eb0c2d7a9a5fe4812062a39dd4728b88bd83049103", // 145
+        "315be0fc989d6675b89f73c82273518afb150f9b2f248d2598117d73f1143a13", // 146
+        "f012bda9dbf5bfd314915dfab7f4c1e5a7b57b71920621abc2e5e107158e5a0c", // 147
+        "3b3bbdffbc4ca1beda4591d8f5197937bf205563adc58e8ee2247331510839d7", // 148
+        "9f29a52a26221d9c30c08c00b51a150f18bfaa8bac6c625309954145fde8e6a5", // 149
+        "f264f3cae7532316b073073302d74be984e63f2e7bea6a30fbae211c383d9088", // 150
+        "8f3e0b10ce01da3796534fc25089ecedea96e89d3b4e57cfb6ada2e81bd51bdc", // 151
+        "4fc04700a0e3d5e9849bd5dc9f9db5f40182a66926715f35528bceefeb64494d", // 152
+        "32d02b4fdbb6cf76f776ef716ffa4db513a60d8914787b7b2444a6ec65469694", // 153
+        "fab602df10102eb0f815fc99b14168d545c4ad9570cf14b6ebaade6fced7ffef", // 154
+        "70f3ffcc519af325808902a9c146d0bd9ca7705f186a1d18c2557a8fb1ec2cea", // 155
+        "ef732cac98850429df534979ac0bcaf9efcb5cf912f2061a073cdcb9846a0a91", // 156
+        "e9a914ab7aedb6ab1796a7dc326852eebca9ea4ded9416e61940ff6b16b534b7", // 157
+        "5c598f05b381ba2cde9f842d9cfcc1cb3ccf47ecc19c2af9cc40e040e613b34c", // 158
+        "19220a74d6c8aa45bef2053d4939d79fcc5d32c3fd3d8d83056f31b080446c31", // 159
+        "dee97218e4d67af5e86d1b2f761c3c5bea706095689914793e2f5ea04dd1a3b7", // 160
+        "fccac2e47c5bb34d1ab2772ce11ca9680c301e9043ce935f987bd87554265366", // 161
+        "e3d28bbe3991be0f3af8c4fd6116885aa749018638f005868c7d03a3e4fc734a", // 162
+        "2ffdf923b5bccc3cfffa8cdd0a93cff97079aeb9da4201a70ceeaa172fe0143d", // 163
+        "58cb282f9b19461c8e3dded0febbdaf44e214dec9e734b7327439316c710ab5c", // 164
+        "3fe244ddfad4f6c02225f294a62dc49763824d0acce29529d4c9d028e23e83ed", // 165
+        "4da148a9e294dc1350b7aa20c61bf48518b7d4dac74af8ee247490617ec15b98", // 166
+        "f94bff165516529cac25cd021ee89e29acd2dc5d6e34a8a5edb451c7f52fd5dc", // 167
+        "11938753be87c437d4b9429b71bff77154bdf5267230e50a6bbde6a781d547d4", // 168
+        "c98c093c4c5067105ac621b93216af0c61bf5484a6c00610f5b8df8b050b28c0", // 169
+        "8bd785ad2099d1ee61d2b96250cc2d02df91d94ebae1cbc3c7e7cf305d615435", // 170
+        "36fc1b4a39e31cfc7783ae608ae71db4bd451a09ce2437ddbe0fa57aba2584b6", // 171
+        "c0bf8f73215dfe6f0b12ec363cef9b286c5b03271c4a72bb27bba38ad517fda2", // 172
+        "b1f5aac9d62a4aa036fe14159244e7fb37b1cbbde93bf39394c21b4a2c7deda9", // 173
+        "27f6812420ea9809a4c9d95068443b083f2b0d5519d2554a37ce1482955e19ed", // 174
+        "2a03e15c300a304a52327c493baf8dd450155e5618369b3c7fdfd24ce0254a22", // 175
+        "b7d3732029a36b37ec35177bdee838d48b36c2a9d0b850070ee45322328bcef5", // 176
+        "570a4a4d8ca8baa859d706c1c402f2865e4dd4325bffbdb920e7892f58d15f70", // 177
+        "c91fde0b803d1c4fc072c76e1153e5bae696e41ba127b9818c7aaa36908e1927", // 178
+        "d0253ee30f1ee65729d8a373e9267bf62da8e3acfa812f0d949646ca33504735", // 179
+        "25b6fad9c654b5c73eb6f7e98480ecda1c98dbdfc0fd469e45ace5855696808e", // 180
+        "9faec9609ef8a6cdbafafc285553853815a9b336eee6fc48581a92dd23bd5cf9", // 181
+        "6237c4d8ef1b0fe03c45aa7de38fc3f57aba9b307bbd161ed7a8f9f735a869bd", // 182
+        "e616d8c4160657bb9463dce009a96ccff4cceb200b4879b785a2c79db83f0325", // 183
+        "e2c58bb889a16628ecff72b43f8fb617471eb110013c1a711e062b6388abab2f", // 184
+        "b63b28f6836fec0c38fab854053bd82762b9b19e7921bc545630130a2f0a21ea", // 185
+        "2139762134a52af03dd7f27664d71c9ebb2401c94c769f178c17326c9729eb5d", // 186
+        "d8b514305a65e47f604d89b6c4fa8216992ac8c46302d08bdbee03d927b28d9c", // 187
+        "e09d30a14a0001621ac539df7b0df31316527c9fc81a788e128e2166e5f78cca", // 188
+        "df559d27936333f780c39ee87c6158a9fe79caf85df74d59bf00fb1c862c211f", // 189
+        "dcaa1ba6bd6205dbfd8c59a4f728f9f981f34c8f99239b17e2e53c9dac76308c", // 190
+        "ff06aaefd36f030a22988278ff509ad6e563bb3da28c491faf173c21fc0a67d5", // 191
+        "0959f9f68c58d0f5cecfd0ceb51cba7871a3a2de11785861787f46bfe6a2a7bc", // 192
+        "85aff1ab67e9d074b7356e2b3b4782e9e6340fb6e2d4b93e5829118101ee8409", // 193
+        "e3fea741935e200862baea22d9e22f860073015bbe50cf241e1fa45ab4b33dc2", // 194
+        "7463ba1b8df68de304ce22977ecfa0c3abbb63868fe5fe28a75f91a9da5219a7", // 195
+        "3ece4c5fd6cc3ded61db5b1a09fb491b62b9efbfa31ab132d06b31ada81ae74a", // 196
+        "c6b601c0c694e0b99787db820b72e3ffb13036de83f6aa66cc060913929d6b6b", // 197
+        "e5327897b505bb2bee49392d0268303f32a857688453fd26354fd74688858754", // 198
+        "b1b361936d3501bc0182ed8f69f26b7eb682d6bf4e5a817fcbe586e0930a3725", // 199
+        "1ba25377ed9d16506bc94381ea4a004dee89a7a431ff7adbdadefc0c6a10f20b", // 200
+        "e55e6ccec68ac08aa423675f46ce640907f7f93cc64fee861b2f06adfc47f67c", // 201
+        "f5eba2ae97a37987736d3fcfb4894766518c93e30c5796477ba89b82387f7296", // 202
+        "f2dc74ef6125209b93a60ffad9f21b546c0cd6efee1f396b1b5ba7dd5ccc3764", // 203
+        "e59d5cd9f32753d3565e22ef7d5ec0ff5ba10286594a616081da2e38bfde362d", // 204
+        "58686cb16d1160694b0ec46473c64296dbde1e5c059485c3986ddbfe870ea6d0", // 205
+        "d9e0644c7b4c313f4128b07eb0bf7776c87d5d19a111c07e7a2de150c0440890", // 206
+        "123bf8a94bb6954f3e0dc85f04002b0e6cb922f08f40a3aee05a30876ffc6504", // 207
+        "487a37a5e39830d716430b3c4334d7e17e6f47f3b5493607f6fd728d24ea929e", // 208
+        "889e04e9929e3a05d401614dfe45a82dc38f6bf4ed84c421475a2a14acf3fc83", // 209
+        "8e4a2ae72582e55fecd0f7e52a4a5e0ee83922afba8a11e56b230066d5c605f8", // 210
+        "95804f5e653e063f90a09e566e67c017dc804c352afc6662d094a0199afbc5c4", // 211
+        "ac002ef997ca705860bc9f509615daa8aca8a813d1bb68e3507155abad86f337", // 212
+        "32eb1e44d9f80d401bba6bf4e94322bc6f4d5fb1c1c554dbc91d9ba2be064f50", // 213
+        "fcb47d6b9a8a0ad51bed4d5c2a20a39222847ef1d3147df42865a1619649f668", // 214
+        "a4997b5beb94e4999dbb7052a97413341236c3d385f0ba9c5f0d8323d0b9d4a7", // 215
+        "dc529e8ba414d38256500fa0cc4627fe577ee00889a12079ac6b506da795dfc4", // 216
+        "94058737a4fc2177ba8a403219a2b45e0a396f21f23e653ef34eb784745e20a8", // 217
+        "4b7a8350873f9cf458c2e1fe1945c1a2a559e4006d4d2c5f95805f604ea200b5", // 218
+        "b41c84f20e5c3ece87a73739d19255531552118f3bc3f79498fdd968881a72ed", // 219
+        "5cb6a81df7e7a5be19b2db72aee69de7789062b2ac6d277df72d43f1258853cc", // 220
+        "b2cbde6e6b6f56cde3415e3ecd6eeea096c75f0a2bfe9dccfcb41498e3b2a6f1", // 221
+        "03c8cd63c1b975c4bdf9ef29d5ce2192ea670c264d897174050ffd766fd347ee", // 222
+        "8ac23b4ae0ebbbcb261e3af28cdefe6d15d1652302b8a7a0b0d61e24d936d10e", // 223
+        "9863027313f3f558eee5725029adfb385b305f7ec0cdd3af31970483028209a9", // 224
+        "5caf37664e5c5237c3c475d1b26ec0a2161e5bbf08470ae26410ffae7bdff049", // 225
+        "56d631c5e8deecb6f2441e7230668b8b5d736c2c10331126066726db3d1397fd", // 226
+        "f4502e99106d604c2546b16c01a2fd49b29be1664c6e4266fe6d6b93bce6d1b8", // 227
+        "2e64e3b739f77417458d82265dd112ba5d63d7ae3af2cf8de7f2a6ed6052bbd2", // 228
+        "150279ed59db55451b09b3b54746dabce1729ffddbd33abd70a6407ece7bfdc7", // 229
+        "8c41101e56f8277103b9feaffb8d9da323456208fc4d8aff0f59bcaa8d0a4507", // 230
+        "dfa1ae34a0d104ab61bd0243ec249e49fc012005f9ede4202ab833975f1f1cf2", // 231
+        "08493e9792f41bd1c8e81849e8304f664e1387495e595f0ef75a7a7c6ee9cf27", // 232
+        "b0cb74f0b7efb43f23ca18326ce80ec7f94bd681ce1792edc9f91c7fe226c144", // 233
+        "0f114f26a76b330da59cd82e86423268435c78e4925167f1b92394cf50478c4c", // 234
+        "ddb1262483b14b117be5cfb65777fa265949a6df9294f2854c928deb20eea4cd", // 235
+        "cf49b94c7496eebe6b57ceabc75c55e6d8de6d007da2711bde4ce43ebb8b2b4e", // 236
+        "d332b844dacf593a246e20e48e6919590852a1692e34971a179082300643f7e3", // 237
+        "15a2d224c2c419bad15fef823dbfe4fba901fda7d238d953fb0d261191b21f12", // 238
+        "7d4bc357d1ab325dbc1d143f3197e99223c8b5798516c3ca8198c2bc4dcd90a6", // 239
+        "e61eadceb2304c1254ca894fd81fbf11575eac94dd96780547607d51a7d0efc3", // 240
+        "9c7888506ed0b545eeac71e840950ef779489669bcabf45417a3b4b676c8d268", // 241
+        "1627003a111acf300581dc0d25cc0667e5999533bd19f7097d74b0e542e6289b", // 242
+        "e9334be7fb89da67dcb5f4db5e0c1f68d0037b8233adb78a8f383326a0c089d6", // 243
+        "2785a95b24807cfc51e0b79c89c4e3345d52e0ae643722352b581f221c08c03f", // 244
+        "21a60cd6a6b323784b9803ed94ac070f22950cc66daaec5942826ea3f70986e9", // 245
+        "94328f7888cbabfdf14eb932c2b586e172eaf837c7f865823a5f5e7876eeb89a", // 246
+        "7a0fccfbe73d1e88a256c1768f4797d84c810334d67bafdef40621d86e57fecb", // 247
+        "91fe88de1dd4192f80e9d15c1d257d1918ac3e4d7ffb16a2d6389b02b849e6cf", // 248
+        "795d56e45c7aed423a2971e2eaa3702a8c14298a9209cb28ce4a3aff16d26180", // 249
+        "489717c50007a7477abb3d2c308660d037a7cadaa8a5351d0ae034a5b58f7156", // 250
+        "31b225dd9625a85cb3531700732f206d7ccab6cc12fab39e83b924be53ea1256", // 251
+        "b46f7bef87170d367aa97732bd3f89147cd04ea7d38973f3243b33eae4d75545", // 252
+        "1a643810f07280de131055119de24ac254174314485d62a8b735974162c08f81", // 253
+        "91ab5c80a94870cd5e88611c70b05e85b70f35a5c771491d2f6d9766e22e54b9", // 254
+        "d7586bb51a29eaca5afe4f75349c56dac9246dcb39ea05cb9baa6eb1d8161719", // 255
+        "ffde535cff5842831caefd07298b294dfa56fa41698a7ac705bde2e164323e95", // 256
+        "e2df3b32b678f18a528aace24d1de2ea3380d37fe6ecfaa67139661dd1053a38", // 257
+        "95592919e90a9b3347af1269ddd2e7ab225f7a58b5e4bf5db122fccc31bc7dd3", // 258
+        "f62f0a032a26f34599e62d86f228eb6dbb9be59b9c9af7cda7f8f43e793cddc8", // 259
+        "a013b7584de41d4dd43eadd4fe96d9bf9daa8d3e7a5fd6e48a033f32453033e6", // 260
+        "dacadc6687c48ae46aa7c75f58d139d6935530ebd27d3bc6dbc033f9ff9e1ad2", // 261
+        "3a6620449bfea2f688fe46ddf91f87c98586b88d29fc4d5f4d3e4acf21705dc5", // 262
+        "37f0697c4b00657e6eec049e80bb169abbb1cda69d4f8f63885a3016be614f91", // 263
+        "79cf495f9b32ac9997c9977fe78fffd57694d59905ca224f155f8def56dd6ebf", // 264
+        "bb2441ab318b750f63e4bbe66903890cfcb718fa3729d749362306a22499825f", // 265
+        "cc629715685928db0f1ffa11e7c992eb5c660640dc7fbd526cdbaa076d8a1cba", // 266
+        "ec5aabb532b86d363938e751e5234e1e9087a12f0348bc93dc485b718419b392", // 267
+        "e2c1ce3d366f3fb17c32484b8c5edf30225d4975690bded6771b9e10d7adc78d", // 268
+        "d70b0d4780b783a6d377e6a45e88077001a2cd0ba3a1c8bd9482ba73f2748fdc", // 269
+        "91aaa834f3829218669af47678ebc3806386da7c00adf45f7a17a3b7d20cfed1", // 270
+        "9c60c2b1e92878f1b8124dc98e13a0dbbd2922b348fc9e7866d8797ba0297f00", // 271
+        "91ecb91ce42b0611461bf15304cf41fb1654549f1de0519b4b183299084bf2ba", // 272
+        "73442b876480c15579addf95867805739a0237c2daf8fd39635d444064c1bdab", // 273
+        "f90ebb93ad5ffe18354edd0a4b39f481a4931acee9c53966043611826c45ecf2", // 274
+        "453e6a00230bf7522f0499b94bb9f3cca752866e09ae57906c7373c537d4166f", // 275
+        "802751844884003d284d7adab35bf186243ac3942160a10804597a180a144843", // 276
+        "db631f14f1404d96bf95db121441ac94afd96c180e9b70ca45cc807c8754e4e2", // 277
+        "39b4294ea0e182ac3865fbc5625bfe4f6cf3595999c67cfadb480661f03cee72", // 278
+        "a5d0710d4e612713a1241933f3d0bdbe19d8853898dc5a981472026ee2de86db", // 279
+        "834cd3b6996b31a3d1a1a41e04d0f400f932f1e4587f52a8762c300b624389b9", // 280
+        "e637e73f445adc375fbcbea04d7e7611dd23843d42bc2dce3693ef79ae6d1259", // 281
+        "555de485e0886039085bab461eaa5528c0cb6a3ea6651dfc01b3e317b7849d65", // 282
+        "60ba9aca93ff92f6668ab2b7c95bbad075ff870e908d89d945b1025963010a04", // 283
+        "ee1edd7d40acf9f66e4f4d497961503fc472f6753fdb013ca1f4c8bd304155a4", // 284
+        "659e87107e37250f9e549401aa34b4f16425d5a38355166ee3fe4b34df286df0", // 285
+        "268b80313ce665e4556b8f3f01cd1721fdcfa77040c8b4f4771b54f9f75d350d", // 286
+        "4716a6c5c9666bacc272c2b6028d65afa1499487179549f54a176feeef7ea578", // 287
+        "ad68bb2b67ad3107052adaa556aa1eff8759d37efee45c3ec9a7dc772e732e4f", // 288
+        "4819f9caa7589486e7cf254652b8147e7b765bab142123034283c032c6030844", // 289
+        "3a931d81dfe2493afb10c885ef3e8c4da3a4edd02c4834e5f15f58e71decce96", // 290
+        "880e9d8e1db282b5e98afb0bb931f2f080c2e4d64f4325ab00fd79af881811fb", // 291
+        "e4f7c87c0491d66aaad5c3cda3dc8c0fbbc6c1a16f26404899af5e53adfd1391", // 292
+        "2a7298d6cc4ef6b5f8a4ca70682faa73f1fa3f8093b15dc782d1b5d0da7e6948", // 293
+        "9b5bb8309197ede5239fbdcc976ca014a09116672f2833d827aa804faad25509", // 294
+        "e1937e3c21ba16bcac998e37d92aa2f5b714bb903c34513bd0a29b076ef39372", // 295
+        "e445314288d90319330a6c667ecbc8d7377c1919ad922a1990455489af83fe3a", // 296
+        "15e9886b18a8c1c8d207851542edc1ce764e13645d812392548097109d9ea3ea", // 297
+        "873ed29795d506cef92881761aa4231a0583cbab94b4bd531b37d69a94764f77", // 298
+        "80b0543e4a534dd98b1d174834bf9c03c31a684088834bb7957ca7bcbdfc4389", // 299
+        "dca49163ced5831064eb2531fb3a5d79d47ceaeb1a8dd57d51d3d00395944d8b", // 300
+        "f3e36dcfcdbf3534fb97296bdb02bde1c8497156eec7bc6f11c17a74a39aa67f", // 301
+        "dd637af0d4b4005c2134560ea3c6246204a92ecf1bced96b8afc6bbb3be5d3e3", // 302
+        "df6a3c6df01f137e32217d32cd35c8144458edad9c69ade693aabd2e7531151d", // 303
+        "b6a7212a14c2def709060d108da940c4041754fde8438b3903c90216ea4b4015", // 304
+        "f63b3c5025e20a2340bf97c169f6622330e89c48a49b037ab65b7f4094ce48d1", // 305
+        "3bfe381952bebde8cb334069668df9511191ab8d4de6c8d954ad770757084cc7", // 306
+        "c0f494063d5a6b70147272d0ac46c4af3775fd877d71645292e0a0ff0d96b401", // 307
+        "570136c728cbb9b5546ec12c01ef1e7943873524a13b82e83c9e826cb03ad476", // 308
+        "63b89d4d937334d5084c62e3884257c10e6c4dc27c9c7c95cb797e782b767fb2", // 309
+        "36e962e4d6bd4b36743095bbb9e6e05c3331ed0136d41bba701e0271a8d44192", // 310
+        "2d120098a0d9bc8fa938c401e1c1b000bf2e3d16a1db850cf72df4652e0204c7", // 311
+        "b368074f176f88d57520ef5bd6b6919caf2c7a227b1d8245167df29bf299801d", // 312
+        "dfd08dd70be5124877ecd77f8269152cc04d2056090dd0a528dcf6c2e44edeb0", // 313
+        "aefa6cb517ded4bc1eed8e3fd4c9fe239bbea7d95df190dfc2926295a32fde73", // 314
+        "711694684231c431d4309b8921add9d668bff31a0a108641b7a28750302779b8", // 315
+        "42d87f1e172cb9cdaa7e86fd096522fd5c6f1322bd3abbdf156c46957167685f", // 316
+        "a98d77bbfd5c97ec0d42ec893df9d308fa3664cce32748ec591735e5248d6279", // 317
+        "62c0f850234dce430ab010b481a7e92283171c3bb0248cf7f06e77d86a9c37f0", // 318
+        "a5e42035bfa9868034aab49abecbe6773627c33b2a899cc85d5bc3b7b3460b15", // 319
+        "76d09e3280ffe4e8704f989eec772a6afde6d1e78cda0d164420ae9615da2b63", // 320
+        "0abc02e1ef01d075c63cab37a736030db43cd76204ba28dcb260d67f8084e098", // 321
+        "c3e48fec12bf24d5aee8e66b7980be3de3c32b16819b799488b680ededf37ba5", // 322
+        "2afe5b88f1e56571805470ac8be51d4d66ac15339231aef112cb3b62ce4c8f90", // 323
+        "db83c64868cb9f90eeed4624c2ae20a6e1773f2706981b892fa3620ec50432a1", // 324
+        "da207c48f63d4fb0dcf639a42513799e8156a01f87440d2c974c90739d68886a", // 325
+        "986f401d6246568a8ee42d6450d9941d18964ac0d2085961ee78de57e1e6085b", // 326
+        "0487abdf9916c5c76c4830dd27887c1e45aed023df05795ce583f74475be7835", // 327
+        "661c4652abd358bf57ef871c7902f3f4d6810ad8f31225ed2f5443992e392ebf", // 328
+        "c22bcd9a83e58edc3d601c473928ee6689a7066121a8dee6736378195e1d94eb", // 329
+        "4cc3c2fc17f747e5f4581c2ba4400cc39a682180e5ffc06e431c38310f152ef9", // 330
+    ],
+    [
+        // string 4
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "09fc96082d34c2dfc1295d92073b5ea1dc8ef8da95f14dfded011ffb96d3e54b", // 1
+        "707cfa320f0b2e284385156b037f337344fe287aafa64202eef2bccbac199d93", // 2
+        "fd3bb902c215a339d10831d8f825d1a8112049d3eecbc81ab12e4715c487a90d", // 3
+        "d5e1c7db864e77e86b561e98e000b28b81ce5b8e63965fa6c7916d2ea00c5337", // 4
+        "0df58afbcd1689bedeedf5a55e405af0d39d8e296c919f0e95887ae0a6e419c2", // 5
+        "f039e3c5914166d6965eeb5f80ec1f8cbe173d44a45b9cb3c4854c6aab96de4c", // 6
+        "1780dbb260acc2650357cd8641718d47bc656e05429f07ef96f8f83b1688d362", // 7
+        "13624d107521c542e8a2b9571aac66fbb8617c72c375acfca3d8bd3b4e1db97b", // 8
+        "a8b371a4710d72507a012e8a6dd05bd15280ef188cf32ebd902b19917b9cd979", // 9
+        "2cc3171ca6bd752d19793801dd5f006c4c41f20d82a39f3dee44ab7598df7234", // 10
+        "aaf0527b7a9fb5190ef722378ea21d377c9dc328f97f559ac57f84ea123640b4", // 11
+        "552c3f6fa394aedd61353b1c03a255a13297be2d34f91bd848bff958b564c49a", // 12
+        "a1508de6552e2f311c7d4293e43b8c539b56b3dac7a085d5c5c02f5f08d29529", // 13
+        "6fbb2972bbbe55f3e2ab5e672aab8db41d731e5924d31737d1d4fa6be4e20947", // 14
+        "accae13ac6a8bf7c61896ccf82ba8ab2c22ac8ffc0ced812f074b771e28678d7", // 15
+        "45de9737ec3e2317ca5e2eed45cc314907c2d1a4a8415a345251d7029eb0f424", // 16
+        "6b4bdec33bbefb42d42e30f41582c7e710f089eb660c875bf1d7012c21b6aaa9", // 17
+        "235262d28ed9a799c8589f0f9fe3551c13263090f5055409e2861d7e018c252e", // 18
+        "7e426ee017eea988c264ea65413b5d5c383c542dacebb410469b25084f08000d", // 19
+        "4709b4244e167074e2fead30db606843f37cf939a3266c0520ae667b2a850c91", // 20
+        "f442bf71d73184a506ca24db13d9272edc82c6c2eac9ff51b1d488815b5cff04", // 21
+        "aa0c6f611e8687a2afb393dc20dba18f4f2ba55af9e5a9ead62cf4e86c96f953", // 22
+        "6c33090de3a83c6499c78edaec59fab44f90ab333d0d0cce61536fa1ed25f3c1", // 23
+        "f690e2dd554e37c65b0203d04b9546955f00d2295be2edb9883c57083963fef0", // 24
+        "f6fb37292ff6f3d937d3757972b1494239c9f8dc98338bf4dcea456005318dcf", // 25
+        "3c629dd7cf5c6148a8d8539e2f5c97ca516fdaa7a59e51fd6d5be3f1548b8d73", // 26
+        "8d4c3dc0bc4b05b7e1387b80105471339fec2f7ede7e9ebbb0b7d6aa14787690", // 27
+        "252f7b0a75d18de0de6ee60372bfd5fc52315ca16c4c193a4a8479846786db39", // 28
+        "433734dc5dc341207a5452d8fd39242fe9c45abc92c678cdcea5e07189695688", // 29
+        "c0886037f329a311bcff12209c746eb1f92a93da13cbeab0440c5a4d5450da5a", // 30
+        "d35c5d8e62374c97f24a64d52d1cb1f6c370829f8d4b549d1e70f24595723d54", // 31
+        "34d484965707f712517a7e7f7939a8715edb131da9d8ec0886ce7bca01c1673a", // 32
+        "33224b3cd7e68af57dc9edf38de77dd06afac4ed39aa06fdcd583836ec1d6951", // 33
+        "5df4a5b59fbbc5c2cd0267995862f581662e50de52fdb9e797dff54e720ab7bf", // 34
+        "b0b9768038e16278aaf46d07d38164248d78e4a6b66e57aac248c46abc08a792", // 35
+        "1fbee8ff9b3ab15c9eb4f6c3bed4d16d61ae45c1d13a03910a5965f704c06f9d", // 36
+        "389071d89532a9e32c2b0e6b38872807a102955b0b372be1441a84c1f9a91c83", // 37
+        "6e573531d644191e5067a9f02505ece84f64926f60b2b2301d8aee6a937a7db3", // 38
+        "93c0127c3d666fdccefe0e03a3cbad17950b1d4cd805e9422537ff0d6d5a0204", // 39
+        "67c532508beaab01deb0726ce7699354ff8ff28c142ddc3fd6bae02b0450ed91", // 40
+        "ff94cbd3eb60a3108a3a8204392a8ff29db08b5133c193da17a4d4b89e618845", // 41
+        "2c16aa986dd3194337728c616aa10a65a07072ac8976c3df8fb4953cf74eadef", // 42
+        "4f19a409e54d3fc8c95e3fa75f36094c58becccd1cb0bbd027fca84aefc0a2db", // 43
+        "b4dff1b2541f88ce32e623b74420e2f15796c1fbd1f9f441c4c7195700b6eaf5", // 44
+        "a4655a423b6b747e752552db9cc18e4a931c5ff34d39425bd24eb6d16f98c0a4", // 45
+        "1fd365b5eb214e35b871a72f89a9eae4ef1b895d968270e8f8671c4f204075f0", // 46
+        "8ec8dbf4153d61130ea084c504245e0792f257a15e8776cbf3df83710d731e48", // 47
+        "003b1b4a5cda7ce9076a677a6d4d3a827b22a09d5ab008f3efb3f545a05de705", // 48
+        "178a5cd36fb148d30935150597cffa82c6e2b791fb94089293e0e54ee8b87f41", // 49
+        "501d606faa2f66fcfc34ea43df7eee87d7c0ca62f48cf3c169955ec62347f62c", // 50
+        "17d7eb302da0f623a7c16b5ad7e4912612574c238d59710269693a51e8588207", // 51
+        "938bc0e36658db702edd8ceeb8462c25afcd4a244bd762e266f5482616f04ed4", // 52
+        "4a989ccce58c0e213adf60faf300f8c4eeb5c70e0a4888a10509489416cfb485", // 53
+        "95cc98c95a2180fb967033cd6c8d44ec23bb3b4fea4a8c438d9b777ab058cd02", // 54
+        "d9f3acace707d8d683d286428f13aef4dc268e16aee9cca93fc52d4f70754b37", // 55
+        "6b2bf7def78985faad1eb199e9880714af2d3fad481f9b2fad1d479c473f47c6", // 56
+        "cdfa6661f9ade05432d47d29c868071c067708324a1d53bc34c80715b2091fa9", // 57
+        "7638b07eb61d19d62e265bdd97e4634852823274374f86ab566fde550d17010d", // 58
+        "58229b7a77165f8e99a9cdb8edb412e3196dc02e71f514d4e0b31949567ad6ea", // 59
+        "592487a108a8304aa761669b8d7d64cffa1470573fec9a99fd744fa62665a850", // 60
+        "63d1e2be27138123c7246081f0edcbb3e848c4c5919b38571e5f0d80631af82d", // 61
+        "145b191d3ca0bddc2fbf7fc1b37950aaafacfcfc42bbb8d5179bc628eb9c8449", // 62
+        "274dbbf668513dd47bef7a68409c39c1eac9630de181043a7d0df2bdb6ebcd9b", // 63
+        "7b3067e22371758f479b8015a2cf626c2c34ce61b00bbd2fe6351267b6858fd5", // 64
+        "8b463b4e15148a319c68c2c3e1d5564193875b3b9290e4bce216787a91838b6a", // 65
+        "af75d2f6e103a00a5780032d6a9e9cde0c96673a242f395dc678930d7af3bed3", // 66
+        "f46ce3b3b28447128a9306494e292bfecf85d1bb2586fe652b241e899172cffc", // 67
+        "935ae1c1f48139a8cce47ef0f6c8694d705d1a6c7d75a3239bd7b3f641d8b9bb", // 68
+        "bfa2e224d69b0b21f399f24da175a1cd63bb6de353212b6eae0c405f8b8a4b13", // 69
+        "56c9160ba4fd57a677f5a37c7586c55e19ed48253ecee65e3ec4ad4ee7485d89", // 70
+        "da1e24f31c5833d0cdb817cff9d52b5a82c72a55594ba27f5a22a31fa0336209", // 71
+        "682d84b032f495d041b71c36f22fed08d6aaa050d98685681c08c836f6d2cf28", // 72
+        "bf3c8a852e63e5d01ba04ee60f8b3e726101a9e20f16218bab710a481d1ac07b", // 73
+        "9712f7e6f0f2991f3ac8ea6504643bcd284208243bd2e10b8ffb242a7cdde0bf", // 74
+        "5db9a4b0afd5e0aa9bc55baca7e666c7fe9c9a09629ad12fb8ae489cf0977ca9", // 75
+        "1a2690d62f8f2fa2423d26a75061f8b880e7109cbcbfa21d4ef76db3cc335aac", // 76
+        "cea7000c7c0d8f26f5f456383521bb46fb1155c1d0c67215db09b6cd2e9ae9c2", // 77
+        "78ce002ad2f5971afe16f0f8f001e6bbbf579de178b490d0b0bd51c81ed3bcb5", // 78
+        "fcee7a050e396b44d37b99cc6b3835d40b44a38fa99fc7833315123032451095", // 79
+        "a65a09d1ec880a486551d09f9f217005a903044feea4f6b293ee125b547bc55c", // 80
+        "925b21aee56abf3f15e5bdd797cab286cfefb1079c976d78c06fde93b5e650f2", // 81
+        "93c5d8426d48dcd005e8a30eb16099478140a59c19cc9be25f0587e01932ed7e", // 82
+        "314e81332a8a77b4516563610390ee7dafab8a13ac57bced1cc609cec6ea8125", // 83
+        "4e00511aeea6f49e7bcb8b9986654298c921a9ad2589d67cf6ce6aafc1bb2208", // 84
+        "ae91a5a046a13aee8b7442e467224cc549f8bff35464842c6c0e948a95807939", // 85
+        "e92463fd8321cd3544e2d51313d86a0e21aac0fa3fe1ae139b1077275cedacb5", // 86
+        "30ac501e485fd324ccf403502a3a55c0d048c3c94ebdf60ed7d98fd97c0fa02b", // 87
+        "6265a6cddb035054382e44854048dcb2dac328452e3e9526062fbeac23c5643b", // 88
+        "301c94aedaffe8c1ca9ebd47e2f8af9121f6c9637b83856b6d50dfcd8a6e3f1b", // 89
+        "335ef244baf85052130bd25210641d1eb5f9c959d1e6ff010097623a3cc8ca71", // 90
+        "4e1a01ca00724312fea781345a3d6ae9d437f307a9fbd5ddd644b7d80bfdac90", // 91
+        "1e4b8a6142504e716f8215c0b78d25a405f9849d845a2868c07d5d6d1a57d202", // 92
+        "faaaed698429598120c008e8e05c9b4f4ad8f2b2c1901b78bbac954a60e75e56", // 93
+        "80b5ca6366725d8377f5d5e3624937c211945bfb776a65606f4ea0133fa22774", // 94
+        "7b6c7568545763ebf00ac568896cc63ea846e52e15895d073c48c5fe5ec7e212", // 95
+        "a0d07f44b57dc160d805a28aa7052e331e41a7aef2daf19ec914c064b18b4e50", // 96
+        "2a9351a269cb2eed1474706ba6f2f44fd930b94b59dfc54c7cb71577eb523cff", // 97
+        "b1876236fb178d74e9597ba0cb895f439f9438f0e1ae6a437a203af488f0d3d3", // 98
+        "6bbadc37e89b8c9b511c32368be0016594b96310fcbf39b60756fcb5cefeb921", // 99
+        "a1c4724c374a9d80dbd5d5d9856b41c54d9361bec1f95843eb27ba3a97cab657", // 100
+        "beda8a79469e0d7e24e57512f1cc5327bb63438b15ecbb792a10d2735fe67ec5", // 101
+        "1028daecdbad4b11299e4e1969b49623d441717c252181a0b70c3f458a548084", // 102
+        "4ff46eabc00601e1845338e970de5d00e91de80e1011051ea30050d767bfcb91", // 103
+        "881321e5e9bc62e85a79328618ad9c4a9c568d429bb9f14516c792be12ba72f7", // 104
+        "006128784a88863ef80a4c54e5ed3d5b241d57a9a0a513a9b6a9918d6c3be464", // 105
+        "b95fe270e3ad2fc602997b088ac55041bb9eb537f9bef100e3aef5ec5f7fb7f1", // 106
+        "996bd7323c9c46ba07079675de5dc622a0088dd1626b1ee60138c01f851cabb1", // 107
+        "b8b64917f5b9a81dd693b77195a376b1b54c93554c8816b8e8959970909dfe5e", // 108
+        "f3318b3f9a570b7bd4f8975b285a34ed1b92e46fed03e1fa620f5ccabd1f13a8", // 109
+        "ed406278d06292a80148ff24922faf54259daaf9ebeda6167c8834d8e5d4253e", // 110
+        "153b2b68f71d19736669d3147f9e689bbe7b81ddca253e44fe0b99a5a1a37dff", // 111
+        "78292c4225596015e703cedd08b20519e77e5524dd0b66b4d440784aa3ca1489", // 112
+        "7d7e1ac6b06e1e97f14a5d557d9976692e3bbc1b33b66e6cdf2ca0fe01526865", // 113
+        "3c7889b6924ec0cad89ecbf02b04381c7bbb5cfaf4a9196237364d073b6f0558", // 114
+        "4a0eeba75a0acb6b30aadec7ef67ed514655f7e4caba322ac0604990b31dded8", // 115
+        "571b9990bedbd2540a7d5d1fd5c914ced152844e4217658a33c98093ea02f5a9", // 116
+        "8fa57dfbe774b27fbdc7c455e102175c5bcf05f2558a8dd75d864b7e301c62cc", // 117
+        "ee1fc71466b05c3e74ec809776c4e3cb3f918b7017173e759b7046486d5bd0c3", // 118
+        "b8c21a6d0f031d861f56cf5fbf45e0387eb2cd4b9a50feaaddef9c476caa22ee", // 119
+        "e57f8b6fd7841ee4a8f9340f9af8325d47e00ad5280b4919b4f60e58db55abf1", // 120
+        "4ae910afa1978fbb1f69dc4744024fe40de3094a89f59d2aa04b022704695f09", // 121
+        "ea7cd64311e97395b2fd335385a7c8b44b7c7f3695ff17b361b1dfc1bb289a3d", // 122
+        "5b77d37dc00bb030d23f26f4217787188779ebf46340a6529f1167933274ade6", // 123
+        "6822751b3e2e9adbb4f7f9d90c7294d29d2b9c4b2f6350b457b7ef5973302aa3", // 124
+        "ba311fbb239c8b8ee6856a95a7c8f75d78aa539d111528b4aea554fbc376ca21", // 125
+        "a94ddb521983c93a0c13b81a88cef23576531fabed14649b5289723c1009ccec", // 126
+        "acc8a3ffb5cfcdc79f2453e187bd3349eb45cb59cd342f5738e9a49ce1a5acb6", // 127
+        "749e0618e8d476487f23949f91e3f3593d575db01d7a06be0f97d25c5b1bc5b0", // 128
+        "d984d7f22adfe5129a79b7a94c530887783602f37d2cb5cf969e7a41b129a366", // 129
+        "f4119637092eb4d58b922175d7cf7731145e022abaf1d9fd852e453d1733ae9d", // 130
+        "7f18c4872c7bf4c55a5f301516bc32273e94718f08980d2f1d4e5fcbae7eb04e", // 131
+        "b3971f0871bd275d272dcc8a52d03cae41d85b8596b787aabcc1c5f8dc06f354", // 132
+        "54b1b6dbd58f501442cf9655001d05325006a3ebdce8f05c3337a4742c2569ca", // 133
+        "7eb02efac969980f4c8314bb9a8c7983c756a58d4f578e2daf1bec759ec824a0", // 134
+        "981286cc30c6271f49f74170badc1d75d92b0d10468a91bf11244f4bf805e95d", // 135
+        "31f6e2f3c50699e84778605bd73c10b0a986bf499d6573a21da19bfe54cebac8", // 136
+        "e3909b4c61b45a2694e5105de6e7174a87f112d90f509b5f7c07f296d067eff1", // 137
+        "5cc99ad00c8c186057a48025bee27fb8b2089e49bb61e6601c1e6085dea71eb5", // 138
+        "dfcd0ae349925511035aed6ee609617817bc8133602366de2c3e2d21beb81736", // 139
+        "4c1d132db5fcbea439657715590527ca68cb9cfaf33cc216b41642ba7189c294", // 140
+        "012566b4dd725f91005527aa3e50fb7f4e4083305328a0d36aefd6cd91167448", // 141
+        "7a69413ed2d75ff186657d502054f1ddbbf8ceb2f161e33d16d91c51b9d34f76", // 142
+        "5d1eae46cde498c25fbc3cd1bf402b464fc949882370ce973b58592e0ce1befc", // 143
+        "bad327f104a952d642242a016abbf838b879fbf1a7c327a7fd099a5c87829d87", // 144
+        "71bcaafe3c160139d57d08415b5c3992bc5216c81938a998ccffb16303499145", // 145
+        "a1c52cb248b77be2f6316ab947f40618bfbba21ec1ce67fd9994582a7821473c", // 146
+        "cd3f914f2059fa54469075012c730c5cb6613473d643c4705949225ea41635d3", // 147
+        "97175ea63248b973bdaa329c622f8ec2b48908981813e1de7daa4e468a5cf459", // 148
+        "f7f745815698b3e0b84c6a2861fb0a6482c48c00130a906d17a771532ecb6a0f", // 149
+        "f2dcdf439ecef4467e8e2a1d9781a05c88d596ebfe013127da454a44b32d4ae0", // 150
+        "841a216526589a7275a31ac8cc4c16dee3e3aecc65749dac900110c2e4e48337", // 151
+        "9fbd43eee1ae86a21e5fed1d7962f76373ef5bc124008109d9c725c7f3bd4065", // 152
+        "957e3bb70b7137c092aff603126641ee0d6bf2f89425cf3e9a8241529630a1d9", // 153
+        "ee73b7f1cdbad0d021ed16bf7e4e3cbddeaea89bd319cef7df468e2530f1681f", // 154
+        "b337f719c1e960fdf30f2d93dfc3d16d1d50c85efe7d9cd5b4882f9f3d36209c", // 155
+        "b18f0e11815179e6dbac70b8e9627e63658f8b955ecc1bf1add83bc1efd7daae", // 156
+        "67098eef8126f14723f1cc9e1f230a5f579ff1f123fe3f04750ba2087ce621cf", // 157
+        "9b013ca35580cd7e189071a5557b58b5a2cc14b4462a003c379a68078a22aa0e", // 158
+        "6022931997b590597ea3a20a4c070c2c75d38a48235b6f79b1e0c8019b428eeb", // 159
+        "a6c322136ffce27290089f28d8ca9c52b0b803e0996f8127ec0ed631b6fc89ca", // 160
+        "d6645c88859f0e87b74cd2012d7c5dbb07ed0b8f5bbf97da95fb118572d8e26a", // 161
+        "b654cdf27c478f417ed31ebe30cc3f07f02ad70b962f82c6ceb739cb763b4cae", // 162
+        "4f35b6f501ca46d487eb9f0981632e5553da77e8d508393c505abb53bef36f9d", // 163
+        "fed2501b9ddacf00fc73b6511e1fdc550e6c2c2998ed2b2d7e1a7e4576cce396", // 164
+        "4def5fc2f71bc24a34cf8dbed2d6986a64ceebeb99e9fcee72d6c0a92992152f", // 165
+        "6c4752a36fd4813da55443c97d1186e3ec8c2370ff0347002686b97c9cd5c16e", // 166
+        "292a4f57fda798b8e6d89e5a5cd306ee5b0f67e83a7e947fda8e16a2be503b0f", // 167
+        "dd5ecd9e8e3aee05f8789d279996b15522294326e383d7719226d35d095a998f", // 168
+        "9b940c35bc846ec7823b5fcbbbd7f6ac9588bbd6fc3c3f20da0b2b4ba2c7bd6b", // 169
+        "a90d9859ff5f40fcf9c159ea67dd2ce3c53556e77a7e0e30c84227165f99394f", // 170
+        "fe1bb15e9dbb53a25750166c0a4fcac4353335060c27058286c7571a84ab2472", // 171
+        "a668747824d441378d700b981db218f9aaf265a32e5cc812553d26524855bc5c", // 172
+        "5fde154dad381c3df4b0297dcbce8ce7eae3062032d487e2147652fe87916af7", // 173
+        "f9841d39a5ea871a7c06b4d040bb9b31808cce370b54721392417ba4eb9d4abd", // 174
+        "78fef42f85dd54606efa2d4e8a995dd1106f81b72fbd26f196eddde67531c058", // 175
+        "22ca5922eb1d4de8a932846333baf22e658d2e42bcf79cda88d8c7c387b9e5a8", // 176
+        "c374a0ce671cc8895c45c98dd84ff98e69e49db009dc56469da0e0b5a81261ae", // 177
+        "de07a5f4d61c0dd0a4a34e92ba86e929061a8ef08f30a316e15fa3dae282f7d4", // 178
+        "9c7e22aaf821e2f8bef8ad5c9f8ac5f57ecccf48a01e61be89633c5eaa9b6821", // 179
+        "b486db229164e736184eccd41691419a5f0003af7d86fb401a3c0f8155c63789", // 180
+        "6ad9612c9be609000d645cbd873b9165884061a8c88b872858969dc70e1d15e1", // 181
+        "03430aaa5d1a1fc8cd1aa4c633cc1e3f96428e2f78abafe8f347a3e4fd4243f8", // 182
+        "f5d386e874a50d10ae81e51eda2068c1134093d82face89a4048de11aada3b76", // 183
+        "fb0fb975eeaa2fc3024d6bf56dea37c28f20f4077436590e37a5f27ec50b657e", // 184
+        "40ff21c4ae8dfb906d798fc2b0c5deb87f65676a8d81209bd2ff4b37de3dccb0", // 185
+        "76b186d11f49d7c975334bbc2bf27c970c12521d35c0d0503ab96e8561d19246", // 186
+        "b34012e998ef2dc97bff4bf891ebcb751e6eaac8f17b82f3ce8b2eeee1387eb4", // 187
+        "a260cecfc54c8d3da65e79e025c34b0eb54c5e77e14d59fe64507adcd379c7de", // 188
+        "58e56523e130a6dcd1867702b74f460c70c18e813c37537bbd2cfeca7c98414a", // 189
+        "cd7e1d9aefaa3663d4402caf86ea7a40821e57a3bcd3c8665fc9fc61e2d9d026", // 190
+        "4723f92b69fde5044aac8228d7394ea648e6ec7f7f8aa1f9b92db01eb3e732cd", // 191
+        "1b72548ae3d6073d9c2d4ae7711399c0bcfd73735a91bf2ba1ca4155ffdce407", // 192
+        "3b587777f2db525bd0716b28d3872eb4d9323db1bd93fa407f4b54ba37bb04b2", // 193
+        "58243f2e68a1bf06b8481ae078a6e7581b46e4525f7bbcc9bbc4fd1c1f4d4354", // 194
+        "cb0cc841cb62b81b712521a3962853ff5f8dde14fc60e722f7dbc30b7f77eb83", // 195
+        "b15cf665a5f29b7614a4d4c27f1dbde551f9632f987226c5ce0eb04ee46ea40b", // 196
+        "ef04fd85fc6343a31b40e4876afef8f6cb9463e6006c78fe0142469a0412170b", // 197
+        "6a059f307eabfcee9e0faa1ee144ba67f5bbec28186c5decca745497d8f3953d", // 198
+        "1e7e2ef211792074f3194dff4aee730ef2cf5f2607a9067d1c7c4cd607006ad4", // 199
+        "c21a653a864e97e78880405c795082aeb36579c35a1026745b89d83e7fbb278d", // 200
+        "3a18f0fd4a4fde2b97c346f77eb948379e7edd7a98c860d053e9b6ad62e9a1ab", // 201
+        "0af621d9504bbda4c48194c96f871eea198a546b7437c0da06f9c08c7bd25c36", // 202
+        "0ba1dde0829adbbce8607fd55faa21a0d53b71505bcd98660cd80a065228f792", // 203
+        "8450dc4d037fcb0c450d2fc02a675c8df4aa635e4f078935c2e6814f15c0109c", // 204
+        "1e6cbb2621c735405f1640289036223c3ba696e064fb1d5a88ad3bead776de5f", // 205
+        "c94ff147216aab75e7dbca3684058c5e8437eae2eb7a2da2f896913004064cc8", // 206
+        "c6849484ff8fbd279736a443097617f7f5048d285aacef81cbdc3a3296634723", // 207
+        "8d62f1781e14544e4c845e60839c6b9af0217ff9c8b4d955ee0d0f9c939e0ba6", // 208
+        "6e140467f18ca9485a19a4491cba557f35e6c74c390fecfc01e73bc677abdc3e", // 209
+        "563e572cf05bf6153bf736f5a07149bc9c8b59b3255cf7be793a7eabf7607559", // 210
+        "47c1a205f3c2ac3520eecd21d94174c36950110f2d1e87065abb61dbeb5cf785", // 211
+        "feae54fd432362839c11e55cd41bbd1cafc906989410f5a2c630d7c5f9bd1b9e", // 212
+        "f7effbba0e1144bfbe482927fb01254936206f3af4d2f64a2440050aba0d64ea", // 213
+        "bf854074338327a7cb491d24f7d2a6262bfb8a4773ae04ba351bc0567d57a633", // 214
+        "2c208fc3fe79542790c1ea1c45aeef50a6b8a1527b8279105d286cf119b1c7ac", // 215
+        "9572aa9bd52f7e8f64ca72f0ae169bc1cd7406b6045f7f2d2f280322b7c1ad9a", // 216
+        "14d485b291e449d00f1837d5c008bf039e66fbc5a7e571597847014216917e01", // 217
+        "40332fec6347053f01c74cf2842e9e5f1439d3a92c4d47e6dc79c701e6f68264", // 218
+        "c9e462cadda65547160b4dd707cd0477d045f5d9425bf2a5b81ccd660b775ad0", // 219
+        "e3862624afa56b84cc17ab9723e167a8968e91eefd6bda3fae9187e4f385da98", // 220
+        "ce62fbd2e6fcde89a992414cf88bf4ccf0c0d8a43f115f2636724beb01abee48", // 221
+        "92a9f3293c925df2ff22b6b4aa6528a4ea27a8244ee6899858fc6714da8e5351", // 222
+        "272996ccd119e9312642b901647bc3270e2be2252de566d083db71ea0e92df5d", // 223
+        "1ece6c6d40e7c473757ae10bbffa73616d00130f951f0acb519e98cd38684be9", // 224
+        "1a0ec0c71608dd8fa28fff1e6ebaf6a09b5948dbf89ac7c506d2923cfddedaef", // 225
+        "d1623cbdf0c9c1fb266bc0745e47bd0e0f1a78101376a91c01e463063b062e24", // 226
+        "18db73ab5c4f17d4a43b2a735b3fd236877e016168b5635125be4489e671580f", // 227
+        "4ac57ab79b8fa08fd50aaaaadc992e718c2361ba16aec1acb9cbb4774bd6b0bd", // 228
+        "07558e8956e4d665a58073733f9056670c1cfcd96c3d123c226326ebd0645832", // 229
+        "0c2096b554c82be6ee17b7d0d94c325605fae45309b3638f3d2f5e6ec6e47ab1", // 230
+        "ca6f740b624dc5fb720ed289f5a7dc386bb4a44cb4295fbbb5fa0017605eae2b", // 231
+        "e0efa830e61c4f8b88a07700504f91257c496b82c59d6622a092582720df84d4", // 232
+        "cfb63bd2ecab16445e5172b6e19607c4010799dc41c40478f0d916b747338351", // 233
+        "661b3b0877b138680a3b14876ff92e51fbad8add87f1e1eec7ab5ef97d1f5e64", // 234
+        "89a58e6475083777e73858ec6c5d9b2974096710bfbfb50449ecce1b8c8da911", // 235
+        "a4b7a83a289446c159ac12d1f6225549247930d5cb52dd4ce7409de9b880be94", // 236
+        "87a6dc81efb192fce54a57b7a8c26432fcdefee1ce2a5b42df59ceff94e3422e", // 237
+        "7c5396f23e6f23ba25eeeeec3ba0b304a0e302608da9a85b51525af256c665cd", // 238
+        "f3df3f640b8d0d13c0791ca997f7f38666518f98cd51dee86c6baeac5feedf00", // 239
+        "c4e4ea7667495de56eeecacac59ff2bac947feeeb7a36a622bebf200491656b5", // 240
+        "a98e64174efd832ec8744c9dac4d6e0b9e99f55e69ea47203afad3e640adf2c5", // 241
+        "9c07e6445d440aac037e80bfe3b30f952c919ed6d8cab245925d4e7f74982bd1", // 242
+        "31e8955bdb7d4f8991e7d154350f3c8d67460cbfe47736b1979ece822f58a94b", // 243
+        "759b072970c1b6e17ea540a171564aba9522a6816346212a883731984354cdaa", // 244
+        "3fd4866091c1bd99bc509831f4e62d697724c454efa0c5394b08a71b794ae440", // 245
+        "0fcc96a489983a1a2a2b3760239a50d5e92c4683d3a6d91e692953828c89de37", // 246
+        "7c69893252d19f71f41da4fb5208be408d19418c647ea9dc8bb08e75cbae05d4", // 247
+        "f8b879a0c390d198dafbb6aa2dd4cd3ecc259d7d041806f05ac411ae201f2a92", // 248
+        "49e6ea70d614c99a5b71bae902f580096fb0fcc6aafba8426a5ea1cd4bf1ad46", // 249
+        "0632fa927aa404e7587a97eb8c0deecf84ffc8be89186960540d0c21121b35c5", // 250
+        "9f5fb3af1c374f312ad12046cbad1cda74fbac99d72ceaac8609ded86d88b74e", // 251
+        "5b914518f41fd13cf3ef3edc825c3955fab0f4d53f937cfa799f0f31c8c38461", // 252
+        "011a0abdbd118331c48d324358a94971b39ee25e5300db4c642eb6188450dcea", // 253
+        "4015b880d2f037ac2bce32335f786d872f5d1273a7fe1796531c19d3eb6a1a26", // 254
+        "9cd4f0726bd71940dbd5f7115c716e11acc96fb8a561764fd1c929f490313a61", // 255
+        "51873bd7a956bcf496d9bbf2b66eb62f00ca10cf1ddf580c802d858b9b10a3d5", // 256
+        "c0a8d8b04838ef2a2736410230196c31d2a63828e376713ce4b7f481fb5bfa07", // 257
+        "9b5550dfa3ad5ed5a17150e1a6aea09f6e7aa3039ee294c54088dfd43a6aa1d2", // 258
+        "5718f38aaaef9267c537199eeaafc2161aa9a2f4422e627225aedffbe30246e2", // 259
+        "9754ced4ac3693b86b36e10da0d42f7c410f9a5d89516863260cc9e8d86879c8", // 260
+        "0fccfd3330f6685c9b0313558d2b01a0ec0a5d7ec2653aaaca5c39372b8ebc4f", // 261
+        "c3218df54ae33d03d500228ac09b190c661fc908b76f2aab5bbca3d71ecf26c9", // 262
+        "e3cc3bb6b050c0860a863198fb7e624dd3f0bd39552eda19c05f4b4165432cfa", // 263
+        "be4965a7b753a51e4ad648a67c91f4f0ecef7a5ffa8a91323dd94557e090b068", // 264
+        "bb38a8dcb44164caa790d098e738e8780f80d1f9c45114ad239662b61a398dff", // 265
+        "401960f8adf2ba76176a1b421ebfa7d3d14e63f0bdcd043a84d267ee7d2cdcd9", // 266
+        "bccc9e1a129bdd35ab2601100fb25711b46015e1e445e051e0814e7f5a658071", // 267
+        "192ef53077332270b72e6cae6148d98ec558f4782c59eb806efc58a7c02a3624", // 268
+        "a2bd12573511508a9c6eac819eed4b6f80d7481ad1ad944a80f0e251abcd7708", // 269
+        "ef2caf26763b277d2040deca9c24c2b0b62c10e8a57f635f571b2e0c41172395", // 270
+        "ee3ac6c60d4c9199ebfd20ccc8bb1d50991091774b03352bb9cfdb136fd946f9", // 271
+        "6b268d0580ce49bfed9416e2b5abed28a0803879aa8a7bb51c8d0b76e2d3c641", // 272
+        "e656b0030237a0c32887c1261175d5cc105ff75e8019d3862098ba6a8c4c536a", // 273
+        "de564dec88b64f693dd2f31e0c769507b6d7e1eb42c36b74e16a953942426ac8", // 274
+        "16f8e44eadfd915b60e654f585df40aae9e45cd860baf50366f0ed66b925a16c", // 275
+        "6035c87ca79231da172afc0a7e2635e846b33616676d4e54f8e395d09dbedc64", // 276
+        "75711c24bfa900b0b24661cb55de74a291d890e045c95020c6239fff2afd3e3e", // 277
+        "93f1e10978b00494e4f93a309f437747b34cf6b14f59c1e3d4f42841b85b17c2", // 278
+        "c264c8d3369cf86733ac3ff8251410dacc36234e07e447f680710e8695bae006", // 279
+        "24343682a11bf0b2bb42d648dda936962fcc54c01f95359b04cdd010b37b242e", // 280
+        "43440f8333e8d052cb75e5477f351bae2acf132988e04b3ee07eebb20b8b2ec7", // 281
+        "04f42d166a40938e619f2ec0f7b6d3947d76b8200a300fee4af44eb25ef24c5b", // 282
+        "159e495dae1733477da3d0e731bd05fdfe2eb0d9e7aeeb06b535eb4e76481e3d", // 283
+        "ff0cbc794d5d82a5d96807f366c8817ffff3f4bc26d2d921cf72175ac286ca7b", // 284
+        "aecc3f092e6d53a626f6557b37afaf7f1431ff4b26f805eef49d93c8ffa2d991", // 285
+        "3936e94911e3674cc2e5eec204e459d823712315ccfdfafca29822df85977d21", // 286
+        "8754acf2d6c5394c3e349d0479ed00879b23e035e38326d8b6b5ac8a8d4d8709", // 287
+        "204f66a79f89a54d426bf5e15054996f79a62dbf820ba12ceae43d3fbae8961f", // 288
+        "4e99653036c650f72d6d8579ba69c1707be3a717bbfcffc677fe3de9c272f343", // 289
+        "b212845e3733119e0de76ecaa12ad5065f551a568a93d7f278ffc0519ee7046d", // 290
+        "db82ddd6afb4b2b5252f2ff7f6e39a7f01f8e18ecb45f4dafd3bd8894cf9a50b", // 291
+        "c89bdd86e8ffa633c1f4fb4d8ad817d0413479807e0cc7047bb11539eb60716f", // 292
+        "4e15f8ae0caf4f00fd1dcf97e095c32cee9704dc874ac6d7528d4317a872c307", // 293
+        "12fe1d4654b7f6c09ae303c72eeec566255b8599c925be377ce1964f56b35609", // 294
+        "934d2eec5e4eb709ed1fe66af608210fffc602031c0d1f9561a22330be9353f6", // 295
+        "492cbf7e3cfbc02eba9d132166115b730ea54e73ec822c59013721ec625734a9", // 296
+        "cc088cb96fe92fd06e3fde510f69b6afb14624c13126e381f25ae1c0361108ff", // 297
+        "ceecf266ab6cc412641a2d8513f598b63f1c55b75b62df83584283f1c8f51ddf", // 298
+        "c23014298f1bab7cd5d763b8a69ef30a00e1729f59d3456dc9f347f980fd74e8", // 299
+        "f9f5ec4976b3dd0e48446ada2c3ea79126b7b612e50f29e22b43864965db446b", // 300
+        "346a9665b6ed2ca1f59db4d06654fa641edd744fac009b514c283373a9ad0032", // 301
+        "1640da322fa28e58b8fb075467a4d38032eca1b7c7c5e89fb41ab237eadf82ae", // 302
+        "fffca9a66082df08a945ee9cd463fd4d14fb45cc40b30eb0d02475e4e36a90a8", // 303
+        "a1abd60c00fcc3fc5d812d2844c33eaede43966fd9797268ab6b1420389cd55e", // 304
+        "992e4ac9f90758413d4dd72103d0b299126c605bbd5492c61e7798ead2b97349", // 305
+        "e016bdb0d008082b91476cfbf668f5855bdf800cc0e9ced1e968e20828429875", // 306
+        "2dd68f42251ea8cc93ac3351c30fe32c8c60c1f5bb46fe99a14bac19e2446845", // 307
+        "a5866e6f6be006562292f3271f32f3ce6b680b617b3baf12787a36b0d1297263", // 308
+        "3524b438448a78f2a5f898a0f1096725d6e4b267377f2e54afd99bffc05f78f3", // 309
+        "2d361bafa39fe9dc99fde468e36be617de623c74a205307f881c08830c74d360", // 310
+        "bf700115485924f467f8c87f8c9916d1f1371c35379321b43bf668c10f11eda6", // 311
+        "f3f9c3227961c7188815d2ad235b8e98c0bdfa2bb202011c3b84910b6b0ae010", // 312
+        "8076557f9b71cbb29670a30f7bb5b28bb7e4d1ec9a302014f5ba93db8915cc39", // 313
+        "8698d17e7b86148a24463ba7e7a29667a3a769103589f43a8edda3a52423776e", // 314
+        "810d8c3019408a30864e70685fa5569ab213febe8089d1e25475c830ff7ebadc", // 315
+        "ebce9ec23131437770f3b3bc035e489ead64e280a4e45a2e159b652cb0d96322", // 316
+        "090121508bdd5f852b990600089a6b16946242d731b5880ccb9d729f3062124f", // 317
+        "9093adf6987cd67811b85fea0889b8854b56fe997f802f539ae195a5e50d6a2e", // 318
+        "3a47a113fb738ca747358e871035c5b77e8811e9cb246be96f322aa1b129c983", // 319
+        "4b14ed2e6ce8a2efb091a16826e00b86d09df2c4e25d4f565cc3b70c93829f20", // 320
+        "7652503bc5d6cb11f10e0c6916364cea7a277b0f17c1b144e4c609f2eedeb300", // 321
+        "368b714b672033c4e5b9d21cf062a0ff052ca1c843fbcc8b47f1e64377a49658", // 322
+        "2fc738879819b31eb6a1cc29277000b4e7b133ea44c72364232c1cb322b6d145", // 323
+        "679ef0aea64dc5cbf633303bc3df1cf0c1581a8bda804b25a3bfdfdbf8674507", // 324
+        "fcb04f9b617f802073a8354ffaa8c2ae2b227e4444b2cdee2c6f7d45dfe59daf", // 325
+        "050f17ac52517b914f8f90fc5e74431ef52cdb2e2ff43ffd0ba9e25b777f5f54", // 326
+        "f6bfeaba6b47d23b90259343a2bfa0ecbfe6da51a9c00a5b253ba9b035d5e80a", // 327
+        "38005a877c7f62efc679eefc821e96396ed606d748a75ae69ca8f104826ae538", // 328
+        "f0ef95585adc8313e6441561821d4dcf85314414603281dd923257cadad7d9b2", // 329
+        "98e199045795985c0cca68eb13d8811fb762ceeae3457ee07fd92ab015a2d027", // 330
+    ],
+    [
+        // string 5
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "3e23e8160039594a33894f6564e1b1348bbd7a0088d42c4acb73eeaed59c009d", // 1
+        "5b9cecdfc3bad7b05cec21cc94f2785699d5e8d5986c60316b125c3c4241fb77", // 2
+        "6ea4bdbbfae9859d5f692c62148792fc0af797b413dc76c8feb0a1500860ee96", // 3
+        "552a7e7887c8500a2539b8bc6be69883afaf67462101525f23f95385a8b9e5e2", // 4
+        "e6a79a916395c9c778422923543f0c1e55f6112b887dae91a753f4ed0fa2682f", // 5
+        "1647613089305c1ff6bb5b314f107842654f1e68d50dbe1544fd7ae3efbca27e", // 6
+        "d9ef6547908e348e51aad1d823547cfe82c8384abedb963783439cd6347be204", // 7
+        "49e4d46d7bff617ee6b3caceca60c3772faf14a630dc939369f140afff134a7b", // 8
+        "2c8e8f4890111a9b011718142d91981056bc21c1b69d67833cb2929eba236ddf", // 9
+        "3589965356d2624d0380bdcd48b404a52e1db716501fc83a223d999b64defd8b", // 10
+        "57d96f6ea387955db76b90233b4c6fcbfedfe32ed84fae8f88270331587da479", // 11
+        "4dba0774f0dbc03eea8917d3d648d3c80c37a9c2147b2f396cec3ae5e8ec0f7c", // 12
+        "8a7b5c56005611a98a4ea6b23b22a31257bde027556fe46838e8a46ef7230e32", // 13
+        "965fe865f879a7cabf1e6a8d4644441d3294af4ce4e779d66d9a2e58a24e7105", // 14
+        "98d1b4265cbdb05dae83742069177b3717f5309987dc411ecced5e85ed4d19eb", // 15
+        "710d2fce068157857917bb213569918ceca0df2e056c40fc6118b373327b5b22", // 16
+        "a515f1a6031cac4b3804731401b7726583299c09bf9330a18a230e68b21a8f02", // 17
+        "9904192859af5af8852ec0df7a454251530b885962127f5d2543629eba38294e", // 18
+        "ab0bc71b958a0e97f3bc1fe59f1468c15a31745fda869bed096eddb1b539882b", // 19
+        "81c1ebb49166b379945f1f0ea9f6fc7ba65f648228b9f03b1a09ab66bfc0cf0c", // 20
+        "f29aede8c351f45733e81271bc41ba22a682e83e267e362f93a5766ccc382c25", // 21
+        "8dffd2d777ba472f13fff30eb987786b0b06b398c53c3f25450e239675d3cc7b", // 22
+        "e8a2f9a6529583f5a8a9c616b1e00b1a53b73b76fc924adb30603e183cbf25c8", // 23
+        "d05f565a6db7eab2e3528d2e27e630f9ed02210036795d9fc8e4e49ca86c1293", // 24
+        "78e96e86f8d4ad35ef826c0f63be17cf4140a1e2e9714c75947c6ca90246e736", // 25
+        "c44c17d5fb9b6284aa765417b358b7693967819983afda183e3bd06b4437906d", // 26
+        "5fbaebc0118f8188da0b8bd49358a4ba53190abed34aff2d14df8418f46e5121", // 27
+        "a901dfa08d51dd4cd91684e70afc97efcbbebdeab7e106da0d4dec09f9f90cdf", // 28
+        "b4f2ce2bcee80f76c6617469b305204fada63a5046772f977da62263051e4cd9", // 29
+        "90c0f584b6e236fa31c9174088f8423ac7d799e8c77062b4d2928ef149ca60fc", // 30
+        "01a026bf0c184178520383dccd6d9845c62c7fab15f66f1208e8a9f182879e2e", // 31
+        "b70d9d426b04c15e23b1fb84bb8751129b8cfb4898382a884aefc3facde14b16", // 32
+        "89ab7ced0721f1b0fddea1538974ad4b376d9df15aad2181938915597a519ab4", // 33
+        "f1664d9b9b113b7986fd3e33887e9fe7fb956629c53e8555cb65e605e80a7094", // 34
+        "93d7c187952d65105b015e06a0cd436935d95a3f7b0ba86c225cbf0631fea235", // 35
+        "a50cd63e272e5ea12b7ee5f56c9acc6c8cbe4d8e726fe54508b38687be850174", // 36
+        "6a1c7fd963dbb40c234e4d7412c67cf0050d5099aaa9a8839133b016af2ed21b", // 37
+        "b5fe444604522eec092f4c09692c1550f7a4e9e91be6d7c948c89aee170fbe81", // 38
+        "9eef0dd4c30da5896edcbb863b8438cfd71072e02335dd827a5963521b20a174", // 39
+        "916cd44455f51121950ae43eaef415333622b38510003fd7ead168fb4472e618", // 40
+        "4657fdd81199ec2101fd25b034a5b24bb4e202cb4cbf96da1aca401f3bfe1d1b", // 41
+        "f756f28d52424edbc10c3d75018c74806f0db7209068feb4ad21c1b2a7a47d9d", // 42
+        "d808ca8c02291b6b28515d2e97684af595d4444d18b52d47e9523d60defcfbdd", // 43
+        "0eb7387d5ca6c560b6137fc8882ee095a19114bc0285e02ce56117403532e3da", // 44
+        "ee7a28a54eeee3edf815bc566b6f6f98fbfe2d71bfd8872c78a6b163545fd4ba", // 45
+        "694ea96cde5daf5873f9896311d2417ee9bf6bcf6bea5cbdca067e3d8639fe3c", // 46
+        "5d5aa60c4b10d69291a9db7e3edd0b43deb0ff187e625cdff5be88f0e7937d2f", // 47
+        "af1772a35e79b7dec5d43bf3ccbaaff904dc99198a4766e399a01993f581e35f", // 48
+        "bda56ac2959df5b963b3110be7a45a49b114a10bce3720d00b612e9c3f257e1e", // 49
+        "4c016f24fb7fee4fc5b56708eb47675a75d74d184f078f0809636a8b09a79946", // 50
+        "88ade16f92c0e4acb5d8672d6140df48a0768a507260b8ad4e4676661238f88b", // 51
+        "c42c81baaff69e8a05f70717f7b87529e5bffae38931d2394efe3cd0e4c29839", // 52
+        "d37c69678a74f9de426e31c3a867ac3c254edf6e6615a276e780b8598a8a4dc3", // 53
+        "061a6a6a139d410f3bb5d8e26abee985c66637ed93f756fa0357fc93ab7db702", // 54
+        "1a30bcb01ddd5ed0d7a25d97b65958475e995410fbd66a96f8302e1644986138", // 55
+        "7ffa63e197cb321cf161f34b44c2c2f988ce48e9127b0ee18d356b99cab9c054", // 56
+        "b761ffa4342a6ca5ddbaeb270cbf2c30105166c7d06f074cb5e68e0b67218500", // 57
+        "f1bb00a8f530a5561f519c9af2d1d0288964e9de0eb09abb5ce6563c755fb7f7", // 58
+        "f302ff3488797ac34403bf34692f06a3700d379ffa65311fe922c04fc9c13b37", // 59
+        "43b2e9d7781d2faafc1ff882b482cc822ef9891cde77194a581bc8b5a59a5f0e", // 60
+        "04c52ff64ef28bd97f554f38a159440e6385f76a5313e7982600b1896b47ed07", // 61
+        "cf8e9c4124c2e6decf4ad1fae9c7bc57bc90de4f3000043327862d663ed4f9b9", // 62
+        "a3e7a28d00c7e50118ceb1eb5f9e26c17d388c30d521af9f0f515d952464186f", // 63
+        "156938eba12e402a4cbc492b2d50bc0d28613f04016e55dbcd4b7401e889ee81", // 64
+        "b0c450c6363d5185dcdb72827bea53d63a1779c71b045bcace6d2312582deb86", // 65
+        "0fb2769d03b5ccbc04db6ddeeca3f046848c579a9b93840c59c12f835453a183", // 66
+        "bbe7a269f2c268c1315a640774c5529b3422d1c5271cfe1f528d4c7460bb181c", // 67
+        "fb23cde2ad19eedf416467c72d1d524d3037d36c90371e551de78da309a24afb", // 68
+        "cd38473ad5c9bd79f66aa6112e7913b30a91152c832f1d2006a7306b1654fb9d", // 69
+        "944125daa84f6f2fb4210a71f133753a2209dcc62b1220cf2d2305234016bf82", // 70
+        "76dcf54280dad3d741b8203ddf0c4f5138bcd9b56d578fe6fbceee58836a8185", // 71
+        "4353804d54909f7cd50673b0a7d0e065af6478c8b385b1d4a27f44e7d121306e", // 72
+        "62bb6197748c9329b0ef40cd22075a14b52a16fa59ff23cba0b4704964a5c97e", // 73
+        "a4e5ec10039fab94b517cc9d96f35028112e8257a6123bc002116ac4d3037223", // 74
+        "bf67cfa71eac1041521d77a8a92bede656e80da145e43c2a5043cb2b0b5f13c7", // 75
+        "31c65fcbce73d3625081698ff7f8b6698d13bbf7eb7ded5a89cc328a86e88553", // 76
+        "ee43dc93ccb7a8c859bbeb173273c8af73376e887623802fb7ad6da8cb75e2b1", // 77
+        "489408cf6841005d3c0a3b1486cc02c4b00af0de7f46ca1607bd4ac2bf308fd9", // 78
+        "cb025b3cc50ef264450b8783ad82f2248ba60080e38fc3415f63ac9403fc5408", // 79
+        "7000c110c74fd56d217bbb7f91a1b524177174ea6bd36f4dad69b0b5bca82046", // 80
+        "060162fd0ffd4879e96eed5e2a87829699b1464219e4324addc1f0c369683c95", // 81
+        "6a2cf6a1cf2c15e976dd8a834441dfb2a64243e9b40f2c6987067f5340f971e9", // 82
+        "f31c66a2a16076e5650e427f64124230fc74ad19deb85b3e14825b3b60d675e3", // 83
+        "dc28a8e6aba0c8b56528e1100a9504099f853a0e52975ac7bf49736fff82548b", // 84
+        "11a4c60ce666c0e56acb1d0bd0b133a8fbed880038f52d69425b3be28e09bb60", // 85
+        "07b745d7c971ed354a6b5e6bfab966b2a71c067777451d67ab338d070d7e5188", // 86
+        "1aef952b07d64d422bc5bf0f40cca8df7364f8da28a6af4d15eb71b465631e0e", // 87
+        "4ada3dbe0290c998d34f5821ce2ca8b2bb62e98b69b4645e11901ada96696b5e", // 88
+        "15cee6b20a9a3c5cc3c8c7d13c4cb8ca8cf7f94936df18605a90b59845bd7c6c", // 89
+        "3c15a9d798d04eb3804ff35b9e7c2ffba5ef88433068203d2fe3787419941fc5", // 90
+        "57a7c18adf026bfb0ace92e3aa4d9f792bbde736859c21ce10fb43769a25a9b8", // 91
+        "7672876bc5ca8dd6423517e87d52cc7adceefdc5c7d5013aaf8165a8aa076396", // 92
+        "714779816d4006062c2ead56628a830af22891a3049eee5693ae15b92420ec98", // 93
+        "5345d4e969da7726705a5e6e4dc626919b51b2d74c8f3e2ee15b88ebf247afe7", // 94
+        "b5b27cab08ab1e4934bd44dffdb3cd0fe091688a7242592c011125531dfd41a4", // 95
+        "96d0f9507620d0f74ae61b10a57ffd6ca9d3d48890685ced4698a4c527144322", // 96
+        "dd1bf018bc422ffdd52acccb4a7b08ac97bb3c45dc2e4988041457ee76b584d6", // 97
+        "f11bb40483a6956498fcff83cf1ff228acf8b33405229bf9e78b9bd98af6c6c8", // 98
+        "bee4a0a5ad1d52575dbd9adacd0ae494ad921197ea4b701a3d47972858935270", // 99
+        "0a62da3331dd672d6d68210c54a78edcb19b5896b5eaa9f400e4b2a13a94ff96", // 100
+        "2f20dfacd100f93841fde061b7ddb608653ad5e33e6018fbf7fde877e8cc7053", // 101
+        "3c0756d7e2d40cc84fb5ee821e2270ac0e599a8f4f8e486680a2924e1f292cd2", // 102
+        "fcf712e76e992b56b7f29871df7e6ebb45a1e50defe37dcebcddf61fca1dd68e", // 103
+        "e67419552d54f64cab44715ad49fe90d599fc4c65d9871cda7e82f26f181a00f", // 104
+        "9812c31ba3dcc997e7666728ff44565a82cc15e05181789f3e7c580715591d1f", // 105
+        "c53c7b50ee348efd27f789e3e39af1ccbf05c1b93278ce3a6396554f4939b45f", // 106
+        "41535aef0b4da97bd0461b4aa17cc383fec0a63586c1ec0d5c37fa14b4504083", // 107
+        "6f6ef41d47a6097bb7f1f1bba5ed8d34aafb30db09311f03e516322aa05ed317", // 108
+        "5c2cce17ef23e6335ed5c6ed6cb1a3ac3eaa423bd701fb14ae84db9bde0f81be", // 109
+        "e6a385fd037888a4b986c772d16a4bdd4a0a53abf98cce8fabedf9fb7de202cc", // 110
+        "91d092f3aaf7d91674baa1fc25e5fe540b7c491e6f3fc52690c3bf18bfdb9aae", // 111
+        "faaba16b08ea473ae4dafc272dbba53240b9f23586f7fc09efc9a47af5804539", // 112
+        "4eb2d6ec0d8eadcfa9b2a0c49c9a166d43d85b31cce78b6ce499b512c5e3f287", // 113
+        "831cc13aeac421f44810af51736de77176f2c572287dc65a49af938e74e5d95b", // 114
+        "0e638fd7d883bea18f9dd84e7ef18c6feba41e156ef059702e37d79562c0b391", // 115
+        "0d87be00a471a8aa4e3ee83d7390f3a21c07901aaad1974cc74e6e844e797dd4", // 116
+        "a88e04d6a14a3f9bf7308443e0761edf4fca2cf0d5d06e45859b81fe20c9c705", // 117
+        "8e9975b1cf049f6335e26132c4f5009b4dca23e1c3b3969e4fac9dff8ea70848", // 118
+        "79524464ac102d6322fca23a23421ffeaee5f21d3531cd85f649ccf1a400212f", // 119
+        "3ddf9680a45e3b841a86342b5d5a38c4d820623a8e2b727a279272cc73d62868", // 120
+        "4619820f013b248c141471fcb0f8eeafc97ebada403d50b4129bca67b201d55f", // 121
+        "b877c93dfa63e9b368f99a0d545d82d54e1a609f41d9a2e2eda05f77061f2657", // 122
+        "c65377a9622ad79319b59a2fb262bb63e4a05bc2a0432dd6ee3812a4a6d52aa8", // 123
+        "851e55adcef1b0615ebff92bf06c4433c005c1756470250ff276f14428875a9e", // 124
+        "88a2fd574380911716d2819a5e5fd628889c3843578dfd03e67a1c5207fe6ec9", // 125
+        "2f6d746281f5f10efe6d3f30886ad63fe477a9825211d3fd1d7e1fd6ca9db1e0", // 126
+        "dd4af768e6948618fd12afc89e82b2f7525a739f4aef16a4bc18e711767d54e9", // 127
+        "1e63b2398b84c213e3de6736f0c6514bd6b97b07d504efe7949f27dbbe739a1b", // 128
+        "5b0251a0cc9d45f69b924a88a074cd1875df9f710702623d6587758171cc640d", // 129
+        "b9560f479fd0e609e82cc74e06dbaf048cdca5c2c9ca59d8d8cdd49062f1a20d", // 130
+        "b1f93de3a3e75dbb19aaec9bfdb74505db35e993a7c0d6028d79402037641ff0", // 131
+        "0307015c107e2bd2317ec99e48b30cccc178a1ca9a3f8115aac6da036915ff66", // 132
+        "8abb6f1a45b7e69c964e450238d14f8dbcec2cd1116d9dc5b6c3a9b2fcd8e47b", // 133
+        "2548ba2e81173cdb58583ac32480a65d79aa20d939545db35dc960083f10d8e3", // 134
+        "dcecee3022d5b3e26e4feff332ea25057c665a6c682e2ac0255db67d6db4231c", // 135
+        "1ce46f2b67d5e9f1e1844acd504215ca2a669b429216a8fca6e2b0fe16739be4", // 136
+        "4049d5af3ff37acefdba95f6602878804df5076fb17081ff166fb794875e1f35", // 137
+        "8f8abb937da7d14217f7b11bf68ff76614352b532e302fab52e76a99f1016239", // 138
+        "c1652038b5beb1050050136c7377d0d112b785522fef00b6696876184c101100", // 139
+        "3394b486009fe28fe0a8c1b3ce1934f848cdf7f47d7c426e5a94428b58533c74", // 140
+        "4e4c23ce48c6ed3ce6a5f2055a1818e33abbb192bc2fa35293653b489041d040", // 141
+        "bf8957d8626d79b70b0c67db50a2510400e26537a3ee5b2f87020e25eee1d521", // 142
+        "ba9c6453e3e15717f6f31005913ee19ecb8ab6c57953b0da538869a900681554", // 143
+        "6739573034e0278b5c7392fb8c31e498b4ea072432a49bd93e97c9eb3ef4cfaf", // 144
+        "ef566fe8694ccc318c8dcbdb68a859a0ab10f45e7a67f6d5ebcddb15a68baf82", // 145
+        "796e6aa9d51d9bc7a91caa25014e4edaff18f05b2fa00cca6f6e322c9a863669", // 146
+        "ff1b1736bbc504c8211cc5a70504b255100c7137aa4991d4d41b80a7d9b97c58", // 147
+        "144e0e72d2d3cb3e4eb9929f121090bd18d30842e0f497602fcc35b1658822ca", // 148
+        "10ea5466ca5f96b91031564c6d6731b37ba6e6f3f3ed3966cb191d5ca58ca767", // 149
+        "6487a7e42f8246333b4d59accc414bbb6b3e6fcbdd5b5eb5d5b08180c0b42f1a", // 150
+        "1259ce064881d710068c9dbc963f764d7fb2ccd824989165d8787d117f10519c", // 151
+        "52f9bf423853e0855ec9e4c36d2a941a9d25ffdb8a5cc33bdc6a03792d95333d", // 152
+        "5e791ec36600fef2674358f0ccf9ae84d6b1ace5da9794ea7793e036df533f6a", // 153
+        "795f4d58e60513457e7f6d8fdaa98e4a659c27b3c73dbc6367e5e3b3236986d7", // 154
+        "8b376b33604c77aee8fb60b9ba8c2b8c82cf9a4ffc30b08d2fc09ef8fd023aea", // 155
+        "2b2725577d20a874de6e8c5f30e4a725f2fba57ef98bbfb5c1b81055aa945e87", // 156
+        "07ab54927f0245343d1a480a460f997d93b13d585cf36a2cc1d83f063784b34d", // 157
+        "0ad3d15785bd19becc82e4bff2ad5639e999f15f48fc1889a45ecce976bafb96", // 158
+        "5710fdf37df2cc99f6fc6538e7ad8e8339abcb59939fc857e4f891da3748e93f", // 159
+        "7374453e4545c1ffc864a6ccef05e32086472f47d79925b55775d017ddadd29a", // 160
+        "6fd4661e38484f8110b4959105371159bdad123168d72841bedeef9d403a8e12", // 161
+        "74d24f68349a526f8eba686a8800a46e9bc9c4f27f9cea001f6c01ab547d4a3d", // 162
+        "5be4257ea0282c145a594bd64e7abcaee1ebefe3e7145a0755e4ba7b4f26e497", // 163
+        "03313ac292de1949d1fe613c23e3b590aed989868b9fab8570dc81b212d225ee", // 164
+        "0f6c9cdc075a152a040b2a48ea600fe27f0837cd11063ca154282c2a57dc9af4", // 165
+        "91fc39776a3694881f1fcca542d09fd7faa35c64002961f533dbb2ba6077c329", // 166
+        "f8a3f6e9c888815be36cfe6e22dae9e7b316e10c1b12b6ee4f2356a634bb1e4a", // 167
+        "6bf9e3a54bb9bf00087391e34dc13f7f791f43419ad34caa94eeb3d0cd1fe62e", // 168
+        "b0d8ca5eb03a3e3efa307f5cea72b2510fc330ac59e5d841dd4af8399a2a02b0", // 169
+        "5f6ee8f5251780c95219ed6e01cce8c6426e89b2c5137a79640ffeb2ee69002c", // 170
+        "4f265b5ea4adc3dbd238d4be8c82627dded87438688b9357ee4efdbf6d3c9911", // 171
+        "c16f6a8c9093499a51546b673f824f7c0fadb141b246606b6d706b381fe2682b", // 172
+        "d3bf088ead706fc87a6014a098a68c0927f7ceb5ee7c2f885e1eb2f24c5ddae5", // 173
+        "ae05668798fd497e73ffb91a33ca2a35238e536ad84fa2709e414e45b7da34bb", // 174
+        "9c2b89bd851bf868867e394729cc872925ddc6021ec023c72c713de763c8dd3a", // 175
+        "d7c9681884474b9d2764638c91423d762a9720a47cfb0eee0c1ba5df905381d9", // 176
+        "7b00027177ed3744ba7cb1d12cc43caa215d5acbc9378b052644391ad5bb5a98", // 177
+        "19702636514ee2c2a0a9b9dffe563940415ee4c34199177a79adb375aabe8278", // 178
+        "676578e5a71995abc1339fe3fbbea558ec9c8ae7220826a7387d7b881c07235c", // 179
+        "b65391a0b24abd9883d5ad44d12e009c7538c376ff5f4c4d81b299d96617a048", // 180
+        "402a01a82446e1c973044d004819bd3e418e46c8fc820a160fe193cbf0b24b19", // 181
+        "5dc8ffd9466650065a89eae7cbef30dcbdcebec3d39e702a614f92c5e4a52463", // 182
+        "daf3dafc19eda905361655386e0370e004e74ad19a1e3413dfc70fec11649383", // 183
+        "eb9d64797814a150ee83fe64f82b46a5e2b3b1fe1afba0dbc38931f3af6cd856", // 184
+        "e3cdeb29a73ebe5044e605d204842c4a427af3e0cb62c728fe4128b1999de500", // 185
+        "a6d6372e2332563d7f0ae3026475b1e8ed0161af7df3df9c528d7480fea70e81", // 186
+        "8319bfa2b858ef4cf485bf3c4f73c7d5c5343f9c28de184cb3a2bbb0f08618ff", // 187
+        "546e3bd539d99cd8168612d8c8b734f941092cb189dd59a46559c61e899f40a0", // 188
+        "f89c47c7a2afe054a714d2f6a481933af75dfcb250e51659e6e972eff7dc3b2b", // 189
+        "41c3711c9dc8d370c3e765c8f0eba52e330dbc40810bc0720c1abd01ce525bcb", // 190
+        "1932767277e3bfb1d987a4c89b53b3a3c99cc4192d5ecb39d722ff202a11ad27", // 191
+        "1dcec04eaf05f6541846b3336e84a927450c8a59b1187e49997fafaf8416facc", // 192
+        "c2992f6769bafe27eaa6df8c774e223b015ff2a453c4da6227fa51dfc4672b3f", // 193
+        "dcdee7a938d6c48bcb3f4c10c8e5dd1921fd0fffca78c070ee7a3ddf54c43296", // 194
+        "6185845411c7edcb80be9da463814931b9053e83d46bc5cf206c461bb80e6925", // 195
+        "1a4d91584d7fe180d96cd4a2522fd6add358c02598fb74fe9decbce4e3dd445e", // 196
+        "754b4453c9941b20daa85007555b750cb065256e683ce17713ae0556ce260af3", // 197
+        "260b160127a0881b75b970f710e1ec690f463f70fd35b89f9fc829a018c24f37", // 198
+        "f66ccd5ad4e086127c002674c00789fd880915a6e06e6bc90392fef147d01dd2", // 199
+        "cde5d4a4dba3c3892b35a11cbbe3a6ad17cf89a5453f99ea09f5af6ea4e40b62", // 200
+        "b92c13499b2cbd85bd3324406002e28a8eaa2209b0ff583c92a016d9ffdd1ebb", // 201
+        "761bcdc4803d7c55f1bdf658ab04c8b75b7023b243816d3f990a0b6aa1ff9d7e", // 202
+        "b1001675696cd7adf78bce4c4e0d1bfd5178d513db7bef6bc7f2183871b8667b", // 203
+        "f053391ea30573b4347268415b86a5e287dd63f0a3e07424c27755e2ef3e1864", // 204
+        "b51f379a9e35a964cec4557a98996d2cbe861a5b7febc5227ef7d01190b2f03a", // 205
+        "5b8026cfeba1d9666b804bc269a4cf7e6ecf38719f2b2328e0771bd797ee57f8", // 206
+        "17f166c345ca01bcb4eb6f4641628b79ff52ff81e4aeb0e6aebe73eda191d334", // 207
+        "e7c6ca03481dab9261edcc1998daaa686f327cef83bbeb644c42ebf39e31c396", // 208
+        "f9dd898fb4d6d7d461cf2a9d0d782f8f1214dae85c28218352d566496cb8c5ae", // 209
+        "be102ea44047b224414503b7bc97222eedc1f7c5cb402ada15dba8ae7ace146a", // 210
+        "8b10d83fbe556c97908d092cdf2fadb2774fb43732483d09363e8fe629603a41", // 211
+        "8de1c929257aa8c7a8eca56635d0bd51269975f5de83c973ecffb79157fe109f", // 212
+        "a69d787147e0bcae8b73d96c86fb9e3fddc7033bd48d632d80ba72b5ad596bd4", // 213
+        "974a373efa1ae8a2bdd7da4f76fe7b6e0affc4b4ea84508769ae1cf7cc6193f4", // 214
+        "4e63cdb8690f0e4b3560a26fcc5a5412fa40ec0bc3328efcc1f9b801b9044659", // 215
+        "6f328f308fdab05739e86fd26b4e9fec9b4024c00f4905dc550ef6acd91ff7e8", // 216
+        "de6a4256746f37fa5bd84b429d542a2cbd05c745016e8f75cd844700255966da", // 217
+        "6ac4fb5d594b9fccaf0d98025a674779bad4a5dbeb61d08241877c44f54dbfdd", // 218
+        "71688413f8f22f6fe04922e9315c14c0cc44939acc7a68f4ec6979dbadb0a66b", // 219
+        "cbbf3c6bed9b1e2f214200f3e5382982b1bb1c1563e93f3c55fc985ff77db358", // 220
+        "322b7435f8862710513e344da6b5b08e290a5513ee93c3b026dcd111690cdf44", // 221
+        "7b757cde47a0d15b617e7f20ba7ebbdd7be56c7eb9263e631d619d637805be88", // 222
+        "eaf8091f3143619fd29e1872ef20bbc712070b67c2792e81473361420d4e3e89", // 223
+        "751d9782de0171bcd0d481bab3250ea0838f50a2ac19d15b72038f5d62e90795", // 224
+        "11f7850d90e1bcb28f0f5cdfe5a0dd6ef7d0d50112103ac7241db67f14e35bde", // 225
+        "28f624fc896f56d3be937ee4ddf02c92185ecfa12e6cc16a617c8a393821c6bb", // 226
+        "54e53d9dc37af18b391f6c6cd9d9f738218bfc3692d8c2f4eda35443ec0b907a", // 227
+        "ab270a58a78ee93cfba4909bdb183f6493831aa5c3d4955cd73fce497d3519a7", // 228
+        "00683ba247c663e5710aa93ff8b30583e314f210d1003e28043bd193c4f2e940", // 229
+        "51729095a3bc68bb7482a95692f1d31f542dabd2b60f52c64c810c237b3c92ce", // 230
+        "2a67afeddb55e038c7380a7ceeb93f522be8cb902312373119a10c314f76fafc", // 231
+        "707e32cb78e2082d8a261bc44a2bf25d0b91229fe1d10483c6120d5a08f5685d", // 232
+        "14c5593abc34a91e118cb3954d4747633202db7211493e62ef6d02e05f5e35f2", // 233
+        "c965a10efd383d1f347158f15b1f6253defde1c4d8057962eb3f33b7590c372f", // 234
+        "cef1b99cbc7ef086dd8d870d2fc254297fbb3a11a1ba08a1ff5cbf44a78e47c1", // 235
+        "0836f8954401ccf399b109c3f059d79516bf37e6cbeeeb9701a0df1841a760cf", // 236
+        "3cfccfa35aeee833abd0c17921cc297e442dd9929eda66b4edf15283aa3c4538", // 237
+        "a1030eaccb339c489ae1f3602647681b07e2ba5975cf70c86bd7af72e1f39820", // 238
+        "d469e5697058330d902d4f2ade8f0cfb6631280f0b94d03f1337b510cfce669b", // 239
+        "b7529cb19037f42f90410efe54b8f4e6b394fc0aff619cebdff61c41a7fe57e5", // 240
+        "d84b1dfd8a9765d75fdbb4b95a96956ffc10aa2c39fedca374d0305c2ec8ee48", // 241
+        "c5d2d6b01ee3194ae3b8a2d3a450d1415824b0974c1940f90b86c82533c88ece", // 242
+        "9d58d01ba1fa71056e8bff5bc458858de6e0771d414bf80e8c5228d8045436ea", // 243
+        "3b4b39e7d5edc4e919326d66a4cd4da467dc92ac8b1379486797c42188c28f93", // 244
+        "9bf4aa19531dd929c38a3eb894e3b47322dbc106117f34c70c25e99b348a589b", // 245
+        "cb3b0198124cfe55cfcd274f06297858037584018b63e95af274e7d57a011405", // 246
+        "a9d288a818f363d8303cd99fd8c702e4b20e5eab06e9e4db6ccc2e77b4316e37", // 247
+        "a5dbe2dcd57647dec79985b4fdd2fe51e66f471feb377083906f4af1e139c926", // 248
+        "6393e470771c54a8673a2b323c0cf0fc917e574a68d5af1975ab655a7dae5df8", // 249
+        "b515039a5882ea5c4de76efe74961c8f1360ec985d7bf826929156cfb9a349fd", // 250
+        "1b32a1a4a47fc0ba77180e8bf8b97405134d777017cd841c38e8f9f06be7db93", // 251
+        "1a7c27edcf10e0d91855661a1956e7a138935ec990c6b4d028aa840b4b60a175", // 252
+        "25d9e3c95582a108b12929fd135700fa18a9eb62cd264d4d2cd8b02777132094", // 253
+        "4d42cc17b1ef7a69b612b4361a2d10b9cbe365d2bc5de1b5aca7bddb98bd848e", // 254
+        "007a2fb22baa14d266a8bcd1f486725cdb83af65ed2b19602aa8dba478279044", // 255
+        "4d4462deeaeac984cd4a236b1be00ee3f93a94007c266a16e5965b90e5aeac00", // 256
+        "0bd087c6d1f4f2e091fd57f2b558ff3eec32813139dcaa070bf8ea17fc3e5637", // 257
+        "d6cbbdfd4306bd191fd3f4c878e11d3cf1f216ab273f83fea1b6014053980db7", // 258
+        "6601b8cdc098659c07852e11b8e6ea0f29eb0b20d7cca3e304d2501765f9186d", // 259
+        "5af010e249b4c1e1a4260486db4b5d079e2d65ad382a00d38641c0592c4e125f", // 260
+        "c9a1eb620ba67d5b156268e307788d0d978a3f53498f7ee29f192bd8cb72e647", // 261
+        "593fc7764e3a9b609a703e974055bc839dc16de70213457dd1abe50f8dd9916f", // 262
+        "f7041698a3a2cfe787b5a3b6e61e418cd596832ccfb224db2777ae53e7d7785b", // 263
+        "355a303d649847e0bca72547cf7fcba1df5526ad3d8710e9df7937f4c9738f6e", // 264
+        "6ca6075efa835aae1ec2bd88dfae9607f52b922d1689ed7b8155703c83e3c80d", // 265
+        "a4fec52357c30a60624f5748682744c9e6322e69ffca81d7645d631aff8a4662", // 266
+        "85068f57ab4c4e220aa177aad5fd0dbde464de9f92a8ade757b866e1635c970f", // 267
+        "e0aff3baa5e75ca8c56afaf60abcc7111b95c24318062a6083174b86b613776f", // 268
+        "e6e142924461cd7970e9bde23539f2a92ff3e25c5d5650bb3c4b0fe8b8c984ed", // 269
+        "a02cd3ba66ddfce67dcf9c785146c192ebae92fc757b2890ae55c028dfde9cc9", // 270
+        "b42cd7acf0df6c6270846e6cf41f81d7c2bd52eb53da3fbffff6377de547b34f", // 271
+        "045a1118f634e47d793b5060bb94f8ef772e23a25798576b56b9309cc949efb7", // 272
+        "c6e910998245037c23a9333cdc32979ffd3d0dc1d7aa0c9d3872dba661c45d02", // 273
+        "9176ef88b2a92aa68d0984b848cb948386b8060a837d2f545456d6125eaecfbe", // 274
+        "f63a6b5019a9c1d0b9674de2ec16f3d1dbda2066fc8d3be14b394f821819acb9", // 275
+        "ddae4b65b4eb0a12b4ac6f1dae4d7c79657ffe6079677d2c92e8b97f0da781df", // 276
+        "0bcaf52d87cb9c4b64378ac99cc7d872fa59fd5493bd4eeb9e344fd4b82b8181", // 277
+        "32ab0e0e409f9fa31ba70fec37b91af263e3e23e1e56864284c8a60f4d8aa9e5", // 278
+        "baba49609450aa6cdbb6ee9877e42973bc1d4b8d74963c2cb1fa456b28521238", // 279
+        "a57e3579f9c40221262ebd30a9f92b8b4db5520f7b1d152b8e993da5d28b7db8", // 280
+        "3e2674243979ac28e7bbb015e17fcbe793ba869706687e1a3d301b2265df433d", // 281
+        "d444bdcb1d60fd0c9260c44e869bc4c0adea33ab222aa2d47093a2a7125190b0", // 282
+        "c126f8d056394521406202f9e1c5668fda8639aafe59e272fd310163ac34a343", // 283
+        "f402cff56a570d01e3e979758c1eb7d5fc857141989d0ae1920648a4769c1ef3", // 284
+        "65f5e817ce7865407f196eac9345fea822fc5e2db2d7c685e81cc260b50a70a4", // 285
+        "f27b26ef702734177004af5f1ece67a3fe5ffb506156900a1f9e5be4568fd174", // 286
+        "44945ee20d86a285e3c0a64844188b0056ecd38e35c75bc2f92f55fa6b9c75da", // 287
+        "8a5edc6723b3d9d9a9561f7289c3988d51bd994f36b3061beef5a5637e50d2ba", // 288
+        "68f5bd761a5dee47b173c3c5a792d60602c65ace07500f0a9f1f6a89b9529010", // 289
+        "8c57a64bb1ad9a6e4b8c918c29c46eb172354469235a371e184becf15afb01bd", // 290
+        "b6c85a38196528ab0266ed3dd6fe96632d906b01932a47f1b4b16d46697da82f", // 291
+        "d570c589aede82eeca2d98cbfd7227b853ac11fd0e2808b7048c40b7acbafc6b", // 292
+        "7eb7408a0c34acd3269cb7c2dbac90fe1ca08223a5f9bff68925beebcd9b84b5", // 293
+        "528162a2dc2ee4bcdddafe732a2812333833d270c61e26bec2991877abe2170a", // 294
+        "5a704ef04a47bb999278aa69d5bfb362c2405246116fcfd6c561075a984aa660", // 295
+        "557415febbcb404f263a2a478c9dfbda8a2ccc2188159d3bd270f280310093d8", // 296
+        "15d6dd65e5cc541ebaf3962ccc8c31a48196514cf314cbe5029b8427f38f6f19", // 297
+        "ee91057d0f1e1aacad781f2cc98fbb5974963e1b9b64bbff2c018c0ca9071679", // 298
+        "ef5267b62cb60cb15f6ca3f92680ef3066d7fce47cc36e557b406ff3b6cb9dfe", // 299
+        "a623a866a143d138d793375beb8d956e2893bf67632074333aa507c72af6fc22", // 300
+        "be3f4c01a87a34bb3b70e62e3e9b14c42a0b6b17fa22f1bd96bd55b7de57ed9c", // 301
+        "00e4a15aca20d0978cd382ec3cb7ec7a361acfd671181d3c6d5125f64f6cc6ac", // 302
+        "37500ae9c81c35eb003cc4267c212f2944b1149409dfa8869c7465b0fa17f2ba", // 303
+        "fdf8cbbd47f24647b6c6d24b67288417196fd9b99d1da42bef4a96238d090370", // 304
+        "b0703c2219ad16aae93d14589234fe211a2e296cb8314ee385e1cce087c48655", // 305
+        "036c84273077c28bf231592566323d664519849e37d2ddcfd252c50eae835334", // 306
+        "aeb3efb1cd24f0b5f158d6adfd57810bdbd26f10a6fba9e0fd0c5e978abf19a9", // 307
+        "b41e8a27d48dfe281acb034d05d0dfd1164afc65958b73ba4dd8510665275561", // 308
+        "3057f50c00042ac23b9739215c3315badbbcdf2f9b777025f250e0c9a1824e17", // 309
+        "7e623465ee44eb090950300df5f1d2f88c9a59cbf0c6b6ed112b753e48710434", // 310
+        "f3ec4f1e832a12d2c31103330548a86a394aa67b99d37be9ef291061d9d238ff", // 311
+        "e84d5f7715fa440fad1384940a9c33fbcaf0fe6b7243d0fdabf713bb9f1cf450", // 312
+        "7c616ed6289de8c011079cc87b19188994250efd79d2b1fe5d0e8402df174f75", // 313
+        "19ef750f0669d82b6d76553f0a36c6c4111bc713a8024899bc1f396430ff51be", // 314
+        "6b7ec81a852ef7b3b8b099419f4ee919e2acac3ae415c9e19e97c59f207ffa4c", // 315
+        "c88811830eb0968f3aa34f14c648f2991081874059c1df2ca0de1f833fa415f8", // 316
+        "e231da19e189d47b19d70bf5b0577345c2854801b9233f33f89be086c4f71811", // 317
+        "bfdf2d0a3e8e14b810de7f627ff8f08bc62dae779815c03d3def384d2a1c45f0", // 318
+        "5a016cbe66d8828598f84ed95c09c9f9b4a39d0eacf8dd0abcfcf9fcca1e1d30", // 319
+        "5bcacc349a93371921eec32b7b7853b29e5e9ed066d96a688f6911cbe1e72ee0", // 320
+        "aba57cb10d2241a04ce7c35c7321494b1b69c2ff44063ba44aaabedac7265f84", // 321
+        "3b6a9e3361f83f6aaa0cb195ebdc4b791df0b7834f5d14dab2994749a0a95071", // 322
+        "94909b92bb5a6a575e69e48ce7a0a0b5e6a79960729b186aae61400edbab0e8b", // 323
+        "d7822ac5d98eab2c61148a05f6ced6e7fe3330f7c0be8a53a786efa002a01831", // 324
+        "71c570f6d0e65b1bce2b2b9f0859fdf249f346e685bb4c43fc0d0ec903f8962f", // 325
+        "faa3e8329a68e85c147a51ec8377c3252b27a06ec153350bf6bda183b72c6bf7", // 326
+        "6d4fa5d0046c0b30d769bfeb4800eb2c0fe430e8de68e8feb08a928db84d17d1", // 327
+        "fbc277313538eb57112d0e8daa16da8a85fb56a6af0042231586b0d520941ed4", // 328
+        "e383fe3894e06a5a62ef603bb93b4862344eaad9706eee6ec9653a765d51e854", // 329
+        "16158db2a5113d88652afb43d811a650d7f79874dd0470d60922fa41ae362ec0", // 330
+    ],
+    [
+        // string 6
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "2dbf9365a0b09d85bbd6176d8b2332aa5ae97bef652712473bc69165e74b22ed", // 1
+        "7f092c8522eaeb4066197d94d450c952cd6c26ce300c83917fc980baa19b47c6", // 2
+        "fad9987ee6f2dd02a236351df6ab8c89f41088b351b176afe166a9362d572538", // 3
+        "667ea89d97f6115d177c1e838587192804c34b1597f7929711ab664cd1fcd80f", // 4
+        "3eb1ae3d4f0be888adb4d8207596a9049c9010afcddac9c7a438b13383f6a878", // 5
+        "888bd719e00d12e00bd0afc2f1b7aede4eedfd018f178692f9e56af4014188eb", // 6
+        "02898424b7ae4f6286c5816ac2bd107f3ec8b0aee16fd2604bcdace3a2ccf624", // 7
+        "a841752719afd684c89b213144dc9f9931805f0d98fabbd98c8ee108c51755d0", // 8
+        "79692898103375cc7e416d5f6cb0667487b08c80097d71dae6e017b5e0d59c0b", // 9
+        "7d05374c4a12fcbcdda65cf46b31e0de67759fc4cb5fad4e85e815d36291b38a", // 10
+        "d3c9484046fad031289d103460ec7ab900c1409ef9227c03e87d2ce8da435eca", // 11
+        "912aba82ea21d510e50d89cb9247045ddbf890d23598ad1cde49e9760cbc3f52", // 12
+        "09f2f142ca25047f47529ba2b12fb09e77ee401b607fbf2351b74e5f16ca13cd", // 13
+        "d96eb89c6f222d2ac3206bc60ee759a69191d8104810a08f1d374e831599337c", // 14
+        "05ff5b742733d95e9b8075c44b7352f3845b7586baa2587b3ab80e25ff80c36f", // 15
+        "f227875c5e7a2988c60c26389876d49002e41d57b51e4457d3cd9d4bce385475", // 16
+        "668190ece2b75f77f6c8a78e40d2d73195745419c12f972d65bdc59f56cc7970", // 17
+        "4de8606240f2a18ad1e10f90ec0559a3d264cc2f211d16d9f63e4a84dccaf929", // 18
+        "81b136f425a42356e2c1efab799c7cfbf38cd060d77c1339ef8b562499402fa7", // 19
+        "2e56c9b2222dca7bf95e325294f0d6ce0540da4f1e87760a5ec8810b1fc07ffe", // 20
+        "8188370740edd75d454c03494ccb00ba415cdaf0ce68cf730cb93eee7b8cddd1", // 21
+        "dd5e85eae68414130765c79f59317a06ce8f29066198171bf6b0750cf4cd4127", // 22
+        "469e65b834cf66a92388d290213230c5fa04cc92d1d6c3e32c0461608e5fd2f0", // 23
+        "a55f94f82040730c87c804fee578cb742108a82f18f6624d0ce9b5874c79efc1", // 24
+        "ac777e6b240348100aa8159ea746f8a98084bb7117a7d5f9d9d6e7eadd9af9fe", // 25
+        "0aa3578ecc60eebc5d6ff05bad8bb9a87386d0fa1fa225e8f353d740e44053a5", // 26
+        "d83688b99a4fad555cf5e45165066310250021942f1e6c55d318a841c3891cfc", // 27
+        "96399658913bc9660817b5aa4d931c04b534421a2ed1b9599ae31c26ade8ff01", // 28
+        "8dc0da4496168d8325774d17d1316623d4fc10667fe5692a89e15884c596fa58", // 29
+        "1771f9c8ad0a8067dc77ac4699dfa9b20a03869767450181e124778f86bf8455", // 30
+        "2d8879640eaa03b0fe74ec35276c4a7a2d526485242ffd7e6c4702f9e9121614", // 31
+        "b75f21b917faaea49dc9e4c8c892884a81074181c9ce23c4a5336636ff403cbd", // 32
+        "8ad03dacdd5a9fcae8c473895094c3a5fc8fb1df17e87aac0b09c16734c821bc", // 33
+        "114d6e74b4ac060477dcd4696e2001db1ef99883199f60eb96a83f889497428c", // 34
+        "36dd9bb509dab3ce236c5fb801dbbebadd2c3cd8d691aa848d23f37c0cd8e46b", // 35
+        "b206d0e5d4600a4c63b7dbd8956a8b240ca24df5ccf64a20300256352f2f7cc6", // 36
+        "e7d4450cf8e5999d8354d5c6a87c2f94bfede255f96f560e627b7c7215459f45", // 37
+        "6cb102bea5cc9af2b649eb81096f360b2f7c444bd4585457e6c74f924253de6e", // 38
+        "1c6518aa41bd8c8cf57d460f1c702c55185fe3c8dcceb89b6d6d59ed19c26e45", // 39
+        "e0fd5695215d70f1ee37b64bf9c9db3646fe881d67f3400cdfdb9888e9771dff", // 40
+        "78bf08c1f2161fde966f4a2c62f1da38e5a49ccb135c059f7b247c1f8affb97b", // 41
+        "c6e8606dc9d2b083ba8a03cc77d026054832744cc7d89b74d33ef97968535403", // 42
+        "cc09d6390d9377f5021eec4b08546fbed466fcb86245061b8b5791101494e3ad", // 43
+        "b4d89ae2d0de328c8cde46d85856af5dfc3d0c855333c1fb98b9ffb7574cb7cc", // 44
+        "a44e6abbe40b641d1f13869d98ad711b873939a089fb739cb6843ffc4f1ad988", // 45
+        "e8cdace243e4a07629a76aef109e57255ae0042bcf9cb4b82e370911abf94875", // 46
+        "3e555d3c5c14cb2c6706b72cd7fb15bb8d8717567ccd16abece11cf5cc7b76ba", // 47
+        "32348ec1c0e6f6489ac71a1a3fc13bc47bf45694b332517522b267bfd4b70549", // 48
+        "b40f441914126079ff43fae1244afd1595fdc8319e4c45447fb2497982f79d44", // 49
+        "70a01706e035b97eadc29af8e5412856a28ee778bebbb14606273f4d73a42857", // 50
+        "cd11b9baa60d769e4fea8fdfcc383a4ff87323c55db26aff18ce26be2e16d7b5", // 51
+        "a103ef1e579d7e1361391469fbef3b6bffd648275b3b584a5f22e69c6e824cdf", // 52
+        "3b67f9d4d85cdc09e30673a0412d7ce98af6968e8fcb86d8cef461b7a025e5e3", // 53
+        "fdea810d1e6c173bacdf71b76593a297a2d738a9ecf0bbcb160e20dbe47e7b8e", // 54
+        "e6aba812fb4460fb4ae62c5b8a84a0014516f185f41b458d6d5937857686e9f2", // 55
+        "69dc533cf585ffb57ee57e350609eb7f163c6867e2cd16438dc919d58e10f9a4", // 56
+        "d77def312138554ea6e3a8a50d0abc3d3b329f675624e13c2d2006a845c16a57", // 57
+        "93bd66a7da14283d5da2b5c8ac3aed0fa9f43f576445031550de4da036edf7f7", // 58
+        "2be94617b6ef77f8ad182f04db089a4fc5e9cf28bcf181f94daeddb990ddffd6", // 59
+        "3268db2d7438ddcc5810f0684a978b9d1df91f7b1b6e7e83d0295adedc1afdab", // 60
+        "9457c4fe87ee43703696ecc87e987a121257768d79261d0f1a5afcff65caef94", // 61
+        "04be62536818d7c22d553e036ffd4cca2b2aa3fb3d298c4e91698c07dcb92625", // 62
+        "98efb182797f8d807bae3a8a9210432a622cfb236ec3859d366e56e25d89bf3d", // 63
+        "a0ce74376069653ef6949da0329a4f47ff415d27d8c1e26fe237b6daf892fa63", // 64
+        "8fdfb6577a2bc96cb63e40220e330340ed96ea98a15c39f4b3e511d3c7eebd7d", // 65
+        "cac3ae76fd5b3c222e18acada9fcbad5ddbb4f5e5566e875e765dce9a234eda2", // 66
+        "be87bdc117e0b3ce632b620749f093545ea2ad01e84aeee7d7fddfdf40d7ae6e", // 67
+        "dc44494b392c15063a2499591e41bb534c32ee6f3950b745c33fff1d6bcb16ce", // 68
+        "a74d54e574ec981fbbcdf6dd591ac91911724c081d655c8c72e9a5c19137815c", // 69
+        "61c751afc0a5b46f231203c502efff6132bdc6c8d23b2ca8770e2065edaf8c50", // 70
+        "654bb8c15cc0454f6a80c1e9f6fc51cd5ed0180f505659c6e1ce0f75a0cb6456", // 71
+        "f934219cdc1fa83368bc9b4f1247b605bd10f2d27e2930e638d4c3da58fe4267", // 72
+        "3dedaec1771133e56e375425e7d2f7b75e74c040814a10079e5a678fc6cddb0a", // 73
+        "492b86a66aa7aa6084e5684f69879d93ed3bafc16e149540221447ff77112f15", // 74
+        "e260409b5d49c54593f1cae76d795ce9f71b16fd26b704492cb301f69469d758", // 75
+        "0089e21a6e079b7ddc0e3925ca017e096888fda20940fb88c07c0a29ff3a9478", // 76
+        "369316f10163cf6e4b7383b57c040151e0443efb58a29127ce83b9ec693e426b", // 77
+        "4fbba2dd0ef44772e867081c459bf53150d180f5fbe4e6215b0d7bfe2d4e2c52", // 78
+        "4436e60ae0b80330010baa0139f5063f2cf8adfa003c0f815d821567ff384dda", // 79
+        "5fa014a9d653ce6d809a4bde60aa028662e23a1d81138a0494b22f6691386e33", // 80
+        "d63d1442c646e1c92b329e7a1072390f241d6b657faee253bad8223acdcc1633", // 81
+        "501ec562a20c79e2d08c65bf2b53e9881212b2d851845cd2afa845cca0f80d24", // 82
+        "3589d787a89b8cb88b86acacf50cebb5404ceca62de194294f3233f0bda74871", // 83
+        "e2b85108bf41f7036108bb18223a8c028ddaebc1966781d1d14f4cf7108ffeeb", // 84
+        "517223cf7f518c1e96ebf94a3a0f1760ffcc50bc81f2a844091dfa8ea39da562", // 85
+        "fe7757634b5b615f98d6f1c1b009f9a82e2b6b4b8c13b6d654fd006d7c3c4d68", // 86
+        "b10f852a1e68a10c1ce93ecb4ef15aebe69d098be8aaff878ec1621ffb4d12d4", // 87
+        "464917c66263c539222c1728009574fa67a65a56ab68c36339f9b9a03416bd4c", // 88
+        "489609ee86444cc12819d458f9acaf6439a11159c9cb44fdd74b0d6fdc266c2b", // 89
+        "85d58c54db26b87bb06b47f8b7a5d8052f9e91fe15ebacad5fdd39e40821b4cb", // 90
+        "e84f9133f44b0591b108cc4b088c40c2230b8aa351b49034cec4562346ac53eb", // 91
+        "80f45c403b26c97340b2079d5c1899ce07728eae7d85d92d6a22dd9c05624ac6", // 92
+        "580f61462271ceed4c3b5592340ccc3acc0f5970ea5a82ff2e965eda6751b4a0", // 93
+        "2a71718ad7d51cf2604ab3cd6b1429475c23b02f7df6438460ce385f095d34f3", // 94
+        "88a7e3e2e8154aedc2e54f80b65940817ae330971d272d71b1cd5d02046e8c7e", // 95
+        "7f8de78d6c2c4a4d0dc15ae0db6abfbb1bc7c3c4af60c93fd8acb75d22cc63fe", // 96
+        "b0af67b3fa8b317bdf55d0ebc18463ba45aa0fb2df01c1e4cc7ef73e6e78caa8", // 97
+        "a2d6c29b02a5014edd5d00f57d3f289e5966aa390610183d8048fa258980a95b", // 98
+        "f6494bf87f65712b1fa8c8b0f6962cea17e638c1ace5996642c8e1eeaee1e4ad", // 99
+        "d0904418000efa9c78ee3d04d13970ba308d12de6b8b59fd1fcf2b36718fcc7b", // 100
+        "b9dffd8462a40a3741b6ab8601121518edde889522e0d771ee3ca3ee802f8efd", // 101
+        "63de6357578790dbe51096c28b961b19d8441324be95502429fc0f1982781bb2", // 102
+        "d6a85d3abc02c0249f42dca23adec713ee4a0a5d78b83f426889e02bf2e8e07e", // 103
+        "15daf6c15543aaf6fbe2daaadc6559b53962267b4427cdbddacdfa34126935cf", // 104
+        "586bcbd4e99b476524651e27ff12ecc6d84969179cdb2f683cab14575be7779a", // 105
+        "cb91a203afd135a8189fb3a70271fabc6108d080be536052c8cf4c1d50681717", // 106
+        "ab2f566c10c6559d5edef9e2e67ac341f79c016d044b335e50f5d50a77e3d711", // 107
+        "0388a7be651a658b2bf5bd6bc01ef998e9a0fa0388d4f65619e0362798b91224", // 108
+        "f3df3fcb400234d241ebfa8ed22bcd5b427d5bbec0dac46a92a24336fd1aa40f", // 109
+        "b9384fa38dc52ef0ec1926eb182713710d44500c8ff0a4f3b5efac9e18f58bb4", // 110
+        "482097077bf3d82046297b622f9645968f90dd8ede1928a5a33fdccd3465386d", // 111
+        "6f9c610e8232d4a24aa67c549053448116add0c322b72eeba2ba457a6dc8f3ef", // 112
+        "2ffdb1d48c85c5a99a05fe397770ce6fe092d1daf556f599b471ffd80486ba65", // 113
+        "e6ece3d527006b7eca8e90f419d8f8f094268aaadd8394741457c3d58dc22c7d", // 114
+        "4026b87ce56271cbfb25a9fc27464710dc52569e6193b32dd2b59e095b8cd357", // 115
+        "bc501e69665e7e0e731b8918328fea994ce7555bdf2877ee472fbbc6c8372327", // 116
+        "246825279af9ad7de39226a0745cd97455fe24d75b767a6b29edbeaeccc2f5cd", // 117
+        "70cda508d2f1e5bd38125a89aa378fb8a893ae541adbecde7f86a69ab014d543", // 118
+        "c6c669691900a515e085e998d2c69acaa8039661ad91249d6b4035d5ec43171e", // 119
+        "0eb7b5fd0517ec3f3837b4190b2efb1e212f9e6b5beffa55eb6c866c56812418", // 120
+        "e613d5724eec724eb49d8a31f52faf8565c9835765e2c0668c8c6598f1ec14dd", // 121
+        "9a017579135ca8a568c59572826e252e63d73f15030c1133c184d55331b96c95", // 122
+        "5ea361a30a43a5699e239c826e185aed0b21435aa6460dc459a61827a625d153", // 123
+        "15d35cccb9372c1a28bcae5545bac6cd82160da35736cb0bcdaa485cd2ef54aa", // 124
+        "f2ecc9782a8abba415490c7c054fbdf4ed6b79ec1e9b3c0d0a8565305dd46e06", // 125
+        "715fac46e77bd1aba84991d1d6e4af98df6e8c2841976e2f155e95f8bc3f87aa", // 126
+        "6cba69cb1c204c8e7d47ee97639598bd3f92826135d2961919b84c046ff3f6a7", // 127
+        "0c343895469a8d6b05cf9e4a81a25f5e0e374bcdb3795f4236f17a94107f90a2", // 128
+        "5b2c3b17b47a9a03ab863f5e1723df2d8c511a204610f6b6572e28a17216342c", // 129
+        "96389ce103bf900cf0a8f783fea57c660a63693f565ced1c405c610f29652eea", // 130
+        "6cd6eb14c612e0b2163929e77a7ee88dd3bf6cbb6502a0c29213bc4237e85295", // 131
+        "6061181ff29057809e66efd2db6eb02f2f6938f87aaf0f45a80642c49870e03b", // 132
+        "5578cafc7fb0c6ed4f6a3b8f89f84fec846b4223eb17264bfd1606dc879b27c4", // 133
+        "69ada61ff6d8f745af3b006caf452cd62f2dd7291523bedbd536f0530dbfd7ad", // 134
+        "07ab07acb0966242a9ea5a348be76a583d5175cf62a05ae5063f76a72ba55132", // 135
+        "6ba8a904820065fea9301a39f9fa34660826e0808d50037935730897b37032f2", // 136
+        "8fa7118386efb5873f10ef0b452ac43321d03365eecd6d433a4d5f069f5d5afb", // 137
+        "20657ce65ea02b46673cce99258a049c1e11a86f9299a9df1a3d6c3fc73c0405", // 138
+        "8f2b05163ad99af4228c88d6ca5246ae1ffe35831486faaaf50099d7ef0653f2", // 139
+        "cc4c425b231ec39dbf6b260315adfdee3a69ded5c35d6603f033385a1b0ce38b", // 140
+        "88da9b45c2be808e1564ca194b0a8b5a2508a15ada15e9aaa0a211dab455ee64", // 141
+        "6f2bdb71b7142db8ed790414cacb5dc4a0d0c69fd5a98cfd4c74b8a26bf4e2d3", // 142
+        "0f32aac323304f7e0d028ee36c69d60953be1f94e11f35d4ad5553a849dbc0d7", // 143
+        "eeb84ac08f0d273888f0937892c021a63d2fe4ca4e323a6dbd0e32b477392979", // 144
+        "fed6f4a06aa203da6df4c1deeaa74be8737457170cbffb1e06b6b32857e5486d", // 145
+        "450f138a2b38f9945f3d2ce7e7fb499aeec9ad8c8a1fd35e46034982fd2b306b", // 146
+        "c06e1427cc7c55b5a873bc6e2da74ecac173cbf98c15e4fc60593677ceb3bf72", // 147
+        "a2312f9fa987533ba6dbfba2d11e445bbac820f356b6034ffc4059d6cc10a716", // 148
+        "0d5e8f295a1de6865c0e188702cd9057222693359d40d5bc3d3b9210b00d155e", // 149
+        "b7ffbe636860c6e35f69fbdf9eb7e693a78f2c5dea82749169d55a3d7f6beec8", // 150
+        "00187846f16cfaeeb097e85299a1a9aff8ca1dbacbd9d03afc0cf51a9bf39395", // 151
+        "f94687471960ee757c38aece4c6c8fb82598119e0c701b4c662e3b2407534a6c", // 152
+        "ae896346e3a3fc6b6b43f8ab72b7d450fba5474ea41a72a20d8d00c10ad8e585", // 153
+        "bbe12fe5cbd68325586fb32f56a6cd364f2df2abdb3f917422c3da4c3c38aa83", // 154
+        "9a1292b0c830767511cf374422810f87184e959ea7ad66615501c1015fa6eec6", // 155
+        "d443773a39c4290f83bb1cdd85eabbf09951c815689e13eb70c1ff538680c21d", // 156
+        "106ae35e1a0ca27e825794d1a990d4fe5d59a55ed1ea99bc8d11acf56dd937b3", // 157
+        "4cd0ec4c514aefd0d2ad240e5f9854a990077be6034b500bd2fad4c272cf74f0", // 158
+        "2cab4ad2d5a712f9aeaffcc47a62338a265c9b743960e38170aa15290863857a", // 159
+        "01850b00fa0a774094ebb383b1df1042988a327553aed27bae598ac29a4c0b53", // 160
+        "f2f5fd020e490d566fc4327684dbfb4d42667ef713b9b3108766680988ac6f6e", // 161
+        "9348eaff6e920e6a130618b65a718354493a0d58d28841333ae9d6b210c35c23", // 162
+        "365d0147255c58522e97ab9f4004d91599ea4b90094aa26cd0cbba0dc656987d", // 163
+        "52f5bf856bbede235b643ae94902021b9e17089e32f52b444b11e463688b8c04", // 164
+        "1c0e7b0757f7c1dd31d86ceff09661db490381c249f54f73697f251261a8ea8d", // 165
+        "2fb1d40f996dcc6eb3cc159cd5df1bb8e8f6c1d9ef52bef3cce7514bda99c695", // 166
+        "5a604ed631c20bf3a8c59ccb9eb17a4e57a2f3ab20308e77e8a4033468dca7be", // 167
+        "f286c2faaac9400aeff0a7088d508d5f900c780cafa77fad1d7b4c489de62372", // 168
+        "ffdb814322d4d28b47c1d1a8954c9a7e53532dca5697e34c828b5762420bb571", // 169
+        "d29e13714eb29979b93ec22b361b07642bbb74e47ef5b226b6d99d78a7fb799b", // 170
+        "9e3140bbe588a61e4a9a3af3af4c9f1ea2ddf175451145e51238e2562c4b37aa", // 171
+        "22d7cbcdfeb1c9dda1d6718fa3af879a332fadcfb5030b454559df0ce0637228", // 172
+        "069f673812226bd1d633ef5b09f88052143d9b689ab6ddb66f0568098920c07d", // 173
+        "2aa4d64eb91b3703d715613bac790135cc5b27a86274b82a8393f837da7fef8b", // 174
+        "6bf86cc353764d076a150294404b07ba5381b194c5c4067bb516aa86ffbd1422", // 175
+        "a06961aa3738105fd5b60611923b407296e42dbb4d1515b98716cf44cb35d1bc", // 176
+        "a395976ce1e3f205c81d6beb47487e655c350115333b6f593d41615a8a8255bd", // 177
+        "901383b663b89468ffafeb938dbeee12afece988b3dfe9b6b2bb8f5133fbb404", // 178
+        "476c06eee64e17fd22af72a74bec9e4ee0df06e0754b4ac98dc11fba57272607", // 179
+        "bd4a215860105be1e978d081c88b87beb46a8dade3de0ae73af36f528cbabb1a", // 180
+        "d0e4ebe8b79e2c34f5dd727619977dd973f2e08a0385d101159ccce6dd692f63", // 181
+        "b0fbb9e7f4ddcf05e0755706e3d105bedaaebab0d481194a75d64bdd9772c913", // 182
+        "936dbd5993f2452976715f1bafdbbb5c9407b6ed03c4a6e8cd29bff03cfc4ebe", // 183
+        "cc80e2b1a593f97f30bdc7942db7b6043dcd7e6a7bd037a8b33626646447e18a", // 184
+        "3f8e8e6e8904c03bcd7ed625503737b93b70ae4dd28d106bb984de6acf29f430", // 185
+        "3472b82efcaa8dbc711f1cdf5d3d5a3c8bab990a031e29b4adf46f2caf8c2bcf", // 186
+        "b912878c1d79b9eb869ecea45f9f78086c334c316dfe346c0a9e196dcf73157d", // 187
+        "09a50d697cd04e750c5042927a3d1f60de920f2bb607f83099d6057c9a9b2300", // 188
+        "b8c9e827d8a3098dc0b93d8d55e62d4a1697f98b3007fdc16e174ebe7ee480df", // 189
+        "5ecca5f8f6e72cd8dc5d20e05e41998b8c8363a311c4f025f94bc0fb64e4fded", // 190
+        "7d5fb3c0caed6d6957fc6a3a7bad033a3528e33f2a31e870d03ea986936a2fd8", // 191
+        "c69a17c0f01f2bafc51270ff254614fbcd4032a49da3d15423b552008161b07b", // 192
+        "23f5e8f54beae3ca116383b0dcb862adb32a1d14c4e377bd19f820e68acab01a", // 193
+        "344449e7c226d8a38c9c12c7f9ade8ed9ae59797caa55efde818cf8152a8ddf9", // 194
+        "466db1e1d6420d9499938ccdc6b65cbfc836e05e1368c81be501ea35a5465f34", // 195
+        "ad210ebe8216755a8bac665a2cf5feb33f3763f912a630ab06125784289e89ac", // 196
+        "26bb188846f716f49280a3cf52f42985be4ca9840e7d0d6c9854c051d2695714", // 197
+        "6ebbcb35516c67d8c28abeed8e2c998f764d11a1b228dedf65ae773289a98f49", // 198
+        "d96a64388a9009b81c97e78602ceeb735488a8772ef3f4720bb866dd7f1a31df", // 199
+        "baa2bce8014b13bd4f94188da176f75a27dcc31c3bd118e5bc8c16ea6da22035", // 200
+        "a48b974e8a2f3a7289bf413ad4a25694d4eac7980406d95be17a07fd9841ff73", // 201
+        "353767d9b7cb8b1ef2ea6d69208a73b6f0c663958d5ca0d09334bc7f4875de1a", // 202
+        "806feb635c182616d250620c3711437643e85c797fa4036a1e369d8afed66c0b", // 203
+        "39d26ba1de2429136a46380f706665ef09b4b9858b8e459544abe41324e463fa", // 204
+        "870de0ec717c1e25fa6907e251738dba8c16be3c93833b93d85520b8ff9d8a43", // 205
+        "2f540a0894ca495df40907665854ad9bdf1c2b5d9915d4d856ee6facd8dd2cd1", // 206
+        "2a179006ec04aa3597f8d87a4da2913cddfbf0751bf384c567090099b9afe0e7", // 207
+        "d2257c7b381fa71636428aee321f80fb14746ac02988243b63b0ad49dc57bd82", // 208
+        "e96ecc406a95c27096cbd9403f4f88fed7fb1b1b5d478d59abb36d656c3148dd", // 209
+        "0278c5f16beb5de66f3d656b9339f779e6ac43d5e8e71ad8528615e9eda414de", // 210
+        "a4c8cdef612bd446cb3bc12cfecaf10adced2a7c96999bc88474962bc233cdfe", // 211
+        "16163904b7995f15783e0eb5ecf53bd244909a71070e96a5fe8dd1ddf44e56e3", // 212
+        "1c5a348a988ca5c25fcd328c826f33fe737c23088455353bdae0a9106265e95f", // 213
+        "534f9d79f33511686522590a90b45790ddf5cb90e70dd0f8d9a830fd14cc707c", // 214
+        "d5e0b45535120c23fbafe603633278ed4a1417344fefe11c07c604416b512342", // 215
+        "bfed95605977783d3794b5fd2b808804b954b981476624a8666e518a46e2bfd8", // 216
+        "ac2b7aeadf5e9b1af27a8ce78b935b8add4456c54f8e5445a46e9a2ee91a2589", // 217
+        "23ee7b682ec7efed84ef700584e9c7c9851878fd3a1bbb75f69873f117539039", // 218
+        "0ed80c23adc13ee3f8d0e4115ca54d05da4911179a6d6a50caf22caeb992d962", // 219
+        "b77294b1ab8cb00eb7bf514f1f359281a508dd5710eb12d6f2895ff336e6f98f", // 220
+        "e64a53d256471f543c920ebf2d4e214d4e1f8754bceebee046674fc1c3ba06ec", // 221
+        "79f9e975fc9c00b8ba224cef26a4ac4b6c341f84be9af1b0b70a9722e6780de5", // 222
+        "5cf0162bfc109fa85c5211f33ce7e81a2e4b6cc4259815e7cc9ae588e527cb10", // 223
+        "63ce222abd3794a3598601c9d811cc3ddffc99cd9085ef5feefcc24aaba2a4ec", // 224
+        "80027f96b360d44868b7cb836d1d552c536528298d98163ab92c0bd4b4c309ea", // 225
+        "7872837e911f99bc795b120b1a7c1393eff541058415f41ac4c3e4f2930da8bb", // 226
+        "eeb6060e0d145f7bfafce3701b992b24bbd5b8c20887be969e01bfdca99537eb", // 227
+        "3e44811d486237a517732535650ae489268fe33bac4c9be77b94db863fd596f0", // 228
+        "56ea683c2446a0b96b0496ffbe4458cb63517dc208f3bddbdad96a6175ba06e0", // 229
+        "6a9a99d5d30f3577c480d7c989a55e652fdd9692461dbb36016c738cd83c43fb", // 230
+        "fad725693f631252000eb48cc9a14683ee75fedcb69d1997fb369acd97259310", // 231
+        "260514e7b4d46dca67ee3075e780159ec000ee8a2f21033dc495172524aa9e88", // 232
+        "254d262087e9b137e8d87c2e0d979811d4b0bfac7eddee439ea098bab40ccf49", // 233
+        "f7b395d8a8c1fd80f23110d97a2f73197280e84998c16929fc565ae68d651150", // 234
+        "9acff4ab5936e130f168b80248fb85ae475cb62ce6628b5d24018c71dc4580bb", // 235
+        "a6702c76561e34543a547a51257a05af2e1273091d9242b41e25f6c7812aef07", // 236
+        "81307553e27649b2c7733bb62700566308fd179724542cefe0ead11b148f0562", // 237
+        "4283f86e2b301df81e0db9b548ef68dcd2aa8dae809beb44cd0c8c07db13f59e", // 238
+        "e2b5ba097801e34465369c7e5bb9b5fe84ca5ed7d4b47e7bab00a07f990c98d9", // 239
+        "610d9a177c001fb707f74b2bd5004f0c68d44437662772c37161a8b2930f452f", // 240
+        "9f84f3234a056c9922689618e77abd065beafdd786b33dae4c99bc614f48b573", // 241
+        "1650434c60b72256c9524d8c54093a1f4a33ccf36f4b65f848aa257ed58f5143", // 242
+        "a1d640d99f354b6099c4e3094d6adb977216d4e27b3bffab281a2849501e4178", // 243
+        "a84938e8df90ec8e65bf843b2cd2194f2450972420c66c2a1918f59673d36ffc", // 244
+        "c13711764681f6de60f814888b0eec3130d33092d19adf308faa38c6aa691ff6", // 245
+        "5d872e34167f8997e2ddcbcf421e27658b5c69544a68e0c5d387371e076c1fca", // 246
+        "872ee11d1901da37e363525a3f6b60822f6acb44478a85f7cf37cf11f487424b", // 247
+        "687b32a1bffd762cab502a2feb8cadced355a89542fed47005b283793e3c16c0", // 248
+        "7dea0a37e30d3bd22a969ce2eb2f5c03cbe3da50bdf6af9b6e608d68f7104d98", // 249
+        "0cc0e09f3949269aa62ebeb68af579cdfb9d0a1fbf3b35ed2b3646cb9c0d438c", // 250
+        "ef81cd5560fed54ec5f70f9e8404265429401782c3e7443d32315f96b333a400", // 251
+        "9e225f3a60df705d3119c4105d90f04a98c9e7f69e975cacd5fe55ff4a2e07fe", // 252
+        "84465ce46807244bdb7b7da17ba24296bbac97fd02068a343102801bcfb77db0", // 253
+        "9bf88d06c1e83548636f12c702fda364624cee1af59a24c5302a4d72634da545", // 254
+        "134f2e3688a9a4909796fad016f9ab51dfeed0debf4d5473e26d4d30dcefc03a", // 255
+        "d6b449e391af4ad8579999c4ddf7ed98ecdb1cf7ee8b15a04ea5bf350ba21281", // 256
+        "0a4b31b9ec047f5f6c8a499a17a35b9e39d7def2856e121ec4cbc8027a058a68", // 257
+        "d541f6bafb3cd4a8d7159531182ef4d2751d17857beda92c406a6cd77b723e7d", // 258
+        "c4c8b5cb7b3126a0590e54e991c49bda5dafa58a8bfe2b3ec14feae7eaee32e8", // 259
+        "1ad0c19093e26aecb42e54b3b6c44aa0cdafe2d2ec073442fadfdd9453554000", // 260
+        "5e11ade32fb51e70512e7828c44b7dedefd58f68732ace391b3404b7fbf33e44", // 261
+        "7f06618322b4dd262b8f334818b4b630a179f277c3073f2c4237821413ac7b89", // 262
+        "4473b747fdfce8256df8151634177d1bf19a4e3010bd9544968a1532a483aaaf", // 263
+        "d05e07ad49c735ca142a9c73a9d5d4fcb92243fc0dbbc14817a14473d239c7f4", // 264
+        "3e747fe0840979c760511207579c6a707ac9750cb5aef455ec44b160d37a7837", // 265
+        "1061cc9de094431eea3d15270342a63bd1793a38af6ab3be3034576b066704f1", // 266
+        "49e125a174c9a8fa56d9f4d0a8f16c30d38dc4b63ca0d3f88fc12fb0445929c0", // 267
+        "439a2b3e2f5b1d86c27db22f656eec4eb5397d3aeffdcc251931d7190be86a2c", // 268
+        "9e4ebe153e0763364239b1220e1e70261fd1b6e3c62ca41108d814a9283818e3", // 269
+        "4c08fea30f3d727450c551f952d7eed5393e3ed181a3146b2f0ee6654b34725c", // 270
+        "1acfc304759e2f7397e9db24e89898f33fbeff01a2e36298f241e1ddeb1bfd95", // 271
+        "fdda1ed8beedee1b2140d28c211409d48a14bc46695c5ada9d7d382c61437e35", // 272
+        "c093f444a63a61db55c58c02d3154c8bcf2c6fb4d7a3c5cd9acc1106acac8819", // 273
+        "58a71db81cedfbda17c251a973f5ec33368afaabc9de09cec029b92219c87e13", // 274
+        "aa08943a71fb0253b49917d6ee1ec5f84af217b8214194ea2e5d2ba7d2151930", // 275
+        "1c2980031a40f6fbc4d648d6f3da1ea2833c23c64db4bdc0d8058e30c2b74953", // 276
+        "34dd27b39a3ddddf98569af0c47944a64bdee2fb133b4402d49047f57a9383ef", // 277
+        "f34cf4fa73089ba7d9c4c69c254b4cce408f7e37db543d49a884e96adb4d9eee", // 278
+        "6128ea8b3f0ed8b875563d26078968cdc95d1e3fcd84294d52188c25ca05a470", // 279
+        "91ec3eafaa1a36178ff765e850df3153382de1b3ae01d9edee284bf402635241", // 280
+        "f315b9b39d4a3a22a8dd260f8a8e9a8b7e52b28643aae38811ae48c5f567c60a", // 281
+        "134b41bc308ac6a1ae056014fef9b380638c5a82b0d27c34630f80ee9c37b9c7", // 282
+        "3c23a3a1fefde2aa8a48cc6ee878122acf4400d4b08a6935031170316ce7a925", // 283
+        "2cdd5339bf229d27b6b6a157648022560d8b5704a067e3a7b9272f9d56f61a3e", // 284
+        "f7db5df302c73e49f4497773d577c403424dbe573996472e1a5e692a22c6b461", // 285
+        "7d2868bc7c8fe029b9a0bb761b27397dcf3feb9eb37aeeae89a28ee4cc5ae093", // 286
+        "0cc9accc016c100ba810abc2556426c813893f9ac0c070d8b405fb91a431d53a", // 287
+        "86aaa4bbe73e9b3a2b7b102e7f02115c1acd59b09bbb9963fe91e2753a926e12", // 288
+        "b9cb5359e6b0a7799708baa6a95c2ffc497328f926b10da6bebe949fb8ef1c08", // 289
+        "487ea1163c0910b4d7594a9d40fb1effa1e64a9bba34d3272224b22032db752b", // 290
+        "31b7d21adf4a942d818964e104892290f04acbb154bf089ac899dbc093a6a71d", // 291
+        "5331fb7284873cac6b33a4fa1e78646db3cb3d8c5661d10d3aa7e3c721d9686f", // 292
+        "1e50615faede0f9b1ee8835c47b80d76758780863bb6869ab51bd0c964a35535", // 293
+        "b780ad8d1d6d690fae4c4b7e1be9fd38917f5527da5cd8cfb36b8eee6ca370c4", // 294
+        "b35ae33e0d93b7e166b92893342d1c9f5f9a1018f56760cab89385354d02c75f", // 295
+        "f34cb94a244d9b5c4f675989da58a6b09712363ab9ff82224e7f42677cbf33e4", // 296
+        "12df77db67adb443aeaaed45cca227fde431cf605927a62f6c4316f502da721c", // 297
+        "dac275ea9041b17f7b7b5da4c65c3da50a41e4a14800efe70cb066cdd7a496c8", // 298
+        "1ef1d381e5fbe5e836e7b69d6aafefdf23f90759a62896285f3ed428510cca24", // 299
+        "3ba8bc4dcf21bb47533f8d17167155cc7be2d760f141871b2a38f5e4815af91a", // 300
+        "b06c368391337b9b48ed56ae52fb8dc944b7acf722b8a026a9b1489c56216617", // 301
+        "63b736ce72a2431babd2cc1a3a1999fd037e78822bc6c05550091a2d88247166", // 302
+        "40856a43e0407464be8919139e6ea516bae2324e708a9fb7986bf803d773c966", // 303
+        "9fdd2373989383b09a7794f23c9ad43520513e26297131881a71b4071ec9e69f", // 304
+        "b152a3000712fa1bba6d3555294fa225128d85ae27cc24af18ad3ef033a1b8c9", // 305
+        "0a0e1b0f363994acbae3e857f82f56589f2c60c861acd302c68165ecdff10228", // 306
+        "f5835a478772f383d34e4903fc9b4ef5371ee36a5d74ff227f25fcfc7e6255ea", // 307
+        "b35968e465d45481ed3a77b8a5687bd11167b3cdc588a51988478ce410d5e0eb", // 308
+        "382cab66fe5f98c9fca68b0c90c24d5f2bfc632c7349461e8ad8bf08b9399336", // 309
+        "1c317b050ea50beef3376f25f1a2900e3181f18c83dbf0033b3c33daa4a20a2d", // 310
+        "3e9372ce6d1bdc641080c410d55b9096744bc954d05704e304ab0fdef16fe121", // 311
+        "c02f6e9334cfa2edbfbcdf2dba6e886a0871e612d2c9ac27921abd20838b4912", // 312
+        "22d4db4606d04d11d6ccfc1f8ba0f47560bcac6f03133c3ba8ac87044b3f7924", // 313
+        "3ab8ef6549c6097d5b629f5454b4be250d2c4b10dafe8caf17a622995e44fe7a", // 314
+        "04bd86376093f6b2cc4d53231bcb8b310926b5d80f80a4359fa2e8ffb6bbe6a4", // 315
+        "ef682a5f458a6a4debd4e284014154fbc9bc3d3cbae4e2a6d27acb5dfe4ae74a", // 316
+        "7a8e6f7c227b50d63481c9b5abfe76d676d537c6a6aad80b27c0e553b1aa5e4a", // 317
+        "eef1893265f347c070b9b7e370e61107846f16b7c9aa1e4345d0a5405386b4ae", // 318
+        "6566c66a49f8bcca2c920b137b6d5dca0e0beda5a674c60ea2743d66944164d3", // 319
+        "6c3e2464adf38d5f413a82b1f3d623a1b7d8dc8795b41b2769c64a0a5f4e80ca", // 320
+        "53cf542c3ab8b3227a56c035e42f2a7d2bdb9c55f99ef70c921f98badd0fc838", // 321
+        "44baff061bfa2c43cc5fdcac2d574a712383981572e0339a1f5473d9f8bc01df", // 322
+        "1a8c51f8f2daeb0cd98f26732a95e668c556a575d9a452bea2e3d198446bd6bf", // 323
+        "c99647962fe27261cf6f52c27900e004121b347d1bbb92d069187e309b0f5cef", // 324
+        "352951b10511a21d27eac37f319ef9b1b59c08b2af447beb893e964865e2ee74", // 325
+        "5e6fbd3f8fc3e698b66148b530eb7b3cd7fd35dabc6b509c0aca30b6ff0a8618", // 326
+        "437b6eb1001baf3f8b484884a9b077385bffa04eeba9163d3a6e12485079d52d", // 327
+        "92ea824de66cf1d2cefbe1a4db3fb911ad8d16f1b5335e5d89a976506741488a", // 328
+        "f63a96aecfceccacdc2099f359bf580c3168e2e43fc40f24dcfb3f0c98fba3ce", // 329
+        "ee7ac122752ba450e998014108f5571cbb864aabb6f5d3d2410b8481606b592e", // 330
+    ],
+    [
+        // string 7
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", // 0
+        "5a0ec31daa84fa27666da56af259b9351086bba0b9ab4aa6007e3e6fb1866b47", // 1
+        "22070bc07976af3556a3a1202d573e9d83b7e7703a3720afa4e7826d8c066373", // 2
+        "fc8ee1f97f3b670018ed6405fb37af5c39ab7c2068fb6497efda3431a14a122b", // 3
+        "6349baabc3d408b8d453db37f47f5e890cd904754ef52ea6a3d20f073ff4f9b1", // 4
+        "9bb4ac42ae79266ba36216b535f12dec05576e0efc489f9482dd102794370b06", // 5
+        "129eea9bc91235bf9ff28888d900414300623b5f40e9a0515f9852758d2daa7c", // 6
+        "565b5415a4f621d52e9b385b253d3eb20b5ac3fd55793b274a1139fa5172777e", // 7
+        "8ee4970ec086cbfd87abb6491225fc7f463792d78955ec5d84e0203afffe0d1f", // 8
+        "c376b2b5b1fdc3150db72650b1a9a388c6b77a967c314843d4975740dec90704", // 9
+        "cd7a51b54a620abd66c551cdbabc8a919c015069e918bf6a4a02c85fe23f0a67", // 10
+        "164abb113559d5dabb6ceb262654b0a88c28e15cab48a43649c8908851d2d0c1", // 11
+        "6941fa1ea1a1fc28c333e577242c2314d69686d7481ff1be1a310b52c06bd0a4", // 12
+        "c6643d7b37d9b936f10573366757b814d8fa694133bcc723925fb42bde2532e5", // 13
+        "5f70b862c458f4d4b875cb7c9c881f6c651c235c4fc3260d6675a640317678ac", // 14
+        "13dde90e0c7b0b1072199eba715ae67b1a059703a336540b84cf0a7acbacc78d", // 15
+        "cb6ffbfe0d235c766fb7ff2cfffa78a5ddca0d4f1d24cfd9b0fff59aa36e7053", // 16
+        "8e97b7a4ff21edfc6b8a5e2e89b35df0b626fd3827586699a92a40fc4b6474b2", // 17
+        "30d4bc286ebc1e500404fef0481530289927e098e3781e16e8a551e2f39e5bde", // 18
+        "88936e02f2e67a408841c5ef950d166123765b146f31f479e7e409e8ad7ca419", // 19
+        "eaabdefb10bda16428fdc3de4aefbff10dab42ab30a47f8926ac59af4c8dd7e4", // 20
+        "1a17d3893b1e48e3634075fa00dd8262ea267d66599072aff329fed5eacfccd5", // 21
+        "1e3bc13f845120e1198ea80dfb05a24a0bb245f1648eddb145bfd3199dfe6bc0", // 22
+        "b584eda4527b66f8fbc502589774bbf1d18cb457d3a76d249f9710b0d4bb04df", // 23
+        "94a552b93ec4cef8c5108a9f9c794dd3e1d52c43811330df176de6c7f7950124", // 24
+        "c363ddf85c9d4904c17495188d5961fb433e79f50341c2ca0a49511b4aceef65", // 25
+        "2f8592c230b22a7b6b50934ec67fdf96d673c67ec178ff399705abb0f046b7a1", // 26
+        "0915e3b3d79e8aea5e36ad3272d5fb3e703ff6ac0a02a7046ddb50dd706d9b57", // 27
+        "80cb9d88ef1806f5b40913c91ff92e6e1fd98e9ed2e6f795a8f4bb6bbc38b36c", // 28
+        "80b72265f7939286adacb09f99b7430a9c2bf7e44588852b67355d6f933799d7", // 29
+        "a6f06390f65c47e28c821234d100efa8b172e82401f3966fe1ba201fb670099b", // 30
+        "7511c9bfa1aeb3a6ce2637f3077b72a3ee3ba3f76a3982f4d30a7fe6d78f7cce", // 31
+        "2b50891641f9d283c126509c89911881c1cdaeb144a55ab69e7d6df172b0c172", // 32
+        "5ef5c814dd0fe88fd9ddc256a6b82260c0f885780004e7b2414fdbc97133e120", // 33
+        "e35006a934cdea0ca4d9b296474ab01b3dca6ae58143b28d9ac9ffe516949e3d", // 34
+        "d70d5360d656caf6e969938afcc84b81aa66d41b1243281e13028cd6f469bf7f", // 35
+        "9edde0693234ea70fc83dde82683a77d0235f1c6a0b519e92083b2b736c3b3c2", // 36
+        "4244a2238a625e0461872549992959743734780f1f3f353b8f010b941478712a", // 37
+        "6ec6309e19c5246443221439bc0fc8b0a3c5b7c2654735776421f421b2d57c6d", // 38
+        "74d5aaa8e396dab266a6cc3866d3df64291d6abba7b779785db2fe8990d34f48", // 39
+        "033ae47492fbba117ad66693454da028139fc66806bcb195ae50acefdb214b07", // 40
+        "2afbaba70a0c655f86b3bed7d1a2b490cb192c9020dfc5c98f2cb45293c67a0e", // 41
+        "0d0860e91e2c73460ecad0d67a98326cd9aec7245154101053032ab61306d2b3", // 42
+        "ccbbe6f0a732a68467756ebdbd1993a45c171f9a6dca1ce12f4f71cf34630cc2", // 43
+        "122d0a58a9497542d592a22bb7d90fc55e4bc5ab2712c52f61c986a273019dc1", // 44
+        "51b206c65d6e533fe3c798a5cc0c1ee003e78952a5b791e748db6ad060e543e3", // 45
+        "ba67f160cf205d5b57dfe0203d81e3ecf2780f63ad61c22be0f32d21af0a4428", // 46
+        "a85879ccb5c0a544e9591622d8079db6f1b1c599ed1d557eb70fe9e8ce5338f3", // 47
+        "daa8dcb3820d6b7d8eacb01e94db89b3b9f697f25f3386bc780c56e726434dab", // 48
+        "6b2e584c8fb3f4b57e5557f6db2bdc40e5eeaed1a2c0774038f82fdbe834834c", // 49
+        "fbfd447d7389f8f81ba191b160452ee6615c7760bdaa700a8f40f3f6da5c121a", // 50
+        "1761664da58f65e50b7b23e510c17d6651318270a38195888c2cd17e16e549cf", // 51
+        "e3f33e793a15ccf54c9dd43352300c46610a218438f1330693780dfe48c12656", // 52
+        "4281d481486c513523d341bb9f0d9ef32649dc9b3a76421b537e94e70c681355", // 53
+        "05bd24cebf04a33b0562d3528b446d11a93d5a99c9f0c55b5a7a0f4709256c4e", // 54
+        "45d5cc6fc76e11503409c94a0d0df611d8af83d98c42d5e653214b9e52893ffc", // 55
+        "d90523e1527db71c84706a2e92914e9ccc8bee7bf156dbd17715d8808558c172", // 56
+        "111e8a72b093c812f325d93f943c9a9ee373deb4fb12bc8c37304e6123d7d931", // 57
+        "bc990ba052490de9ea27aedd79b0c85b480999b099611ba1e4880c680d06113f", // 58
+        "8d80a65996b09438de6001dfcac8baa2a0132f45729851348e0c98c9c550a74d", // 59
+        "843c4597e9a12121e473b6dffecc3e31aea2446d5f193974383c61a64d55959c", // 60
+        "ab2855c6d7e16533c1a6eaa865209c634fa5edf61704c9c3f9ef611d09a25b2a", // 61
+        "dcb4eb6dfcd5fac027755fae59eff3167e77eb2ddedc2439857c270ae6fc4498", // 62
+        "6bad39ca1b012428acab4b21ac43de3e94136ad192e1c9d7d15a79aecbab2179", // 63
+        "38ecb4bc07584c7b14c107b2c1bdf4a7d1bd95d5f5a700af61b043dd4c45b777", // 64
+        "ab3ce5d47321470ce5a837ecaf24e07e2ab15ee5dfeb41d3f51842966cf6d4b1", // 65
+        "db9efe10359d196a50c275487c07042cdc6b0bffad4e5c038afbb4f8be07e955", // 66
+        "868aec4649abcf05747c5d5a3de40a6260c86edca10e0f452748588f9f1a28ed", // 67
+        "23789167c3700b138274f67256088482b8a704bfcbc14dbc6f2f24eb5421d60e", // 68
+        "e1ee1e1a2ac92b668ff9bb447feb75dfe195333ab5c75f1bb07d05e0ffa340fc", // 69
+        "387e9e9c6bc8e381fe8b8659ae3b08b0cf83933decc2f9bda97bea38cbcc5744", // 70
+        "bca9d148ac405b34dbbd894b18ad7678d0d1f0fa1cb469debd259619eeeb78c5", // 71
+        "b46ae462d8f1430a079d94376559c3419e811fa071d854d3580f92ce7e82e649", // 72
+        "992b6fc8a8dfc619a79622248e97fe5e7dd1f06669c7eeb418dee5c5eafb4fb9", // 73
+        "729420f58c7e7ffee1420b4043c0869b8bbd636364433f598a83fb80237a4ccd", // 74
+        "0c194fbb97c6a3424597f4c985f918733a31fe212b923332a4bbac9e538e4bbb", // 75
+        "7e03f6fde4a30c5abf8197694c754794c267998b6b957585d83774a09e9c26a4", // 76
+        "3711cf6a41b1441710f44776f6e13c15a4dcaabc3c41c9fa04cfe081edb31f9a", // 77
+        "06ffb76f4d011bc5e40a99c6a84cad8aa52609611a9ca7033b1dcc30fab45403", // 78
+        "175acae73d220e50192eee24e48e174e977259a4248a6642d10b60ac3107cd36", // 79
+        "183f305c2499cb939d3f2abb32a4aadd4c8873ad672401029c500c68c5c1f408", // 80
+        "d5a677ccb2f70f04abe6a38b6f339d72ef5c43646ca3684d4f0154eb4e2297fe", // 81
+        "1b5e08b5cb5260dac0837f0d8eb524a3d0b6abc1ba14caaa2486ec1e9bb6040e", // 82
+        "ab61bd858d2736d356c36a91c1d3aa5be1ad56a47da56fe826bbc97c851d2de2", // 83
+        "576860a27c053ec0bbe5144f065c22027e94852301b0342b4214570e77826883", // 84
+        "f2668dd07091abfbeffb932e59a0cff89bb694f02eabfb09880b4163e2c6bf9d", // 85
+        "f29c1b1bb65d90e1e08f1d3d894d9efe2aee78b1b7b81a8b77690f7d888a1de7", // 86
+        "36a6a20527998e904c279a44dfeefee8cbdc2f703040d0fd10216276e1eb9c2b", // 87
+        "52d88963057a5a8080da28c66bd1091b03485380df5b6e9731ffc3d5c25c1876", // 88
+        "7e97949816a4618d5c47c3a4683edd56c078d655cdc0c86411194fac1594304d", // 89
+        "890467f029748e14df37676ffc3ff458c316da3c0e6bf1a94b01a2bc4ff3df8a", // 90
+        "c116aa7b368aca40485434619d3b428397b7ff7e11c21e58525fe620e223aa35", // 91
+        "42201f8ea602d28edc974a19d89cb321079d095d6d86aa0c30aac9cec289860d", // 92
+        "20f3bbf93204c817ae22fc617347cac41943dcc321a46ad1912e2ef8b7baf4b0", // 93
+        "dc737c098e13ad903cb4ebcee83ad9680286050cc6f775fd7cc8cfe8bd6ab2b2", // 94
+        "78a19413f84d41a98ab3d83b49a00b71193a47ec78fe097dc2d868d2c58ab72b", // 95
+        "d38f2c1737bebf60f6591c048cccbe87bccb34720ea2c399017874113e86a766", // 96
+        "de2b7e9fd988f53c6e387f7840d084ad15eb79c764c73cec5dac59f18637b821", // 97
+        "2013c05fa8c0b58ba933750d02773367af58512c5a8c653e9b89a4526235f8c2", // 98
+        "8d3a17c3953d9dd6f8901efc9adf9ab6f6540942a3c5f621c2fb1b704ee0cdb3", // 99
+        "a644fb482e6424ed0d7832db325fd07d1931cae2161657aa4f13e63ceedce12a", // 100
+        "d2050116352021e12688878ae6a4bf91d784bf359444a9131f060347bff6feb4", // 101
+        "e58ec643bbc2199255c7208950b5774be9320a06d76e68a1158982673f7a259d", // 102
+        "8e8a32df22a61593a9d17c2ab42ca5adc3e3be2cb826723f3a37602de4ba071e", // 103
+        "84d34968ce3103c7fc00393e73eb52fc26704af226cad821ca1ae88ee72b5168", // 104
+        "da0b6cb3178559b4ecc7061e07d849bb9a840911640dc95d18d7d24ac851efe4", // 105
+        "7cb1cfb24adec71fe8d8d734d280ec2232c8cb2d88e826d5e5e484c225ad197a", // 106
+        "b3982e09a87c2836b18bdaad3c1948679afd306e2fbaba5628f42895ba256c66", // 107
+        "15fdfcf2f7144e728127cff840ad0d783a4385b41fac931ddd7e0e92b3572f62", // 108
+        "08e50ea3625d678c3a6b175febcb809b2ef068c1689efb0e2ba0410b77c20721", // 109
+        "ca0b047c66382b7c26b92fcce8ee399d3d3718da1b50027b52fe87f5a7d16346", // 110
+        "b0c0a1238c0f8ab92c9f5c37dcc1376de7ca32b1d0d269449734dbf18604e7f9", // 111
+        "efcc0813e69664e0b5104f0bd30ce4bbf201efeedcdce2a02874a2215066cef3", // 112
+        "0123c2bf18f6fb0a99e1e3d45dfbace9bf983d2ceedf11bd7a7320aa2238df7a", // 113
+        "3c29cbc6dd897c7ab8d158337a104f717ea19cbe05bd1b8ab3e131ec53c1cfaa", // 114
+        "9d189e428b7eea89385e6a1d4523bb7b5353ab7968bd615da0bda732899a3452", // 115
+        "865c04741d56087f73c90fb82a60f86d74db32736d89d5739825b27c98a65022", // 116
+        "f9dfff903f05bb5feef9f211e591c0f126ffbda289d1b11cac29cdb8d3da9121", // 117
+        "5cbcab9b7c893847a12d3e4c9e6eb421a5ca24365cf2186d8fe7168b3acf9d16", // 118
+        "4caa021457d38f62c8d7abeed981fa1614fba19e4e11294592af7f7c46d35f24", // 119
+        "51f0ef54b6d7444adf44bc1b2e69b32dd47df4ca1f225ba13e59d4cdeb2e480f", // 120
+        "6dea1261784487f901a96c80afd3ed8b78d2b4071f8e5d801503c6eb42b22c18", // 121
+        "7db99d3d9085a713e473a3c0dd6b16213375d6f24f7bb51a4ea37b826c640371", // 122
+        "e64a50581c1750a9abf108b3c5960c3c3de62cf16f44bc1db3d99304c1075c9a", // 123
+        "bc16856d85c001922498b42fd3fffe6a7e39f9e2ff6aa4b3ba31d0f256eaf410", // 124
+        "50dc7053c3d49ff846239770919971d801a225a1649cd46bc15fc2c7db02b2be", // 125
+        "47df9d6579e3ff51600fb81b6cd0f006baccd38396aec51a387ab133df13056d", // 126
+        "af40e70452d1b566e41b7e6d6d5149a82159447fd61117f875c532f47cae61e3", // 127
+        "f7826e38efaa470e24bf08613e515eda987bd266ae96f3feda2dcf5716179256", // 128
+        "a055370ea70d367762f61a7437533ed105a728f0a0e3b30e3f736d27056ef6d2", // 129
+        "36ce813215359f8f0fb143b55905215b4e431ac62e67dcf27a02104de2e4a8be", // 130
+        "a1bc072ab66881c21b852c854bc67ca3762f4ab24789c2a5a963e5bf639ecc19", // 131
+        "a50a422b43046ea3063b9a78840b66f8981b1ba8595cea3800d2f3173ece8fe8", // 132
+        "6c0d758446422d9247510198c9ae071ffc32fe44f36b29bdfe84e47b445d9b49", // 133
+        "ac5240f3ac8c17003d35778cf875c02a07864da5c16895c4687dd09e065d04ab", // 134
+        "fc0852ed82ff7a004785e24050ad0f8f06a6e74a56a351bc80a0fd6b52fb963e", // 135
+        "86a2594f752dfad0e9d0c3f75d5873650e1c53502ade972920cf1f74fe3965fc", // 136
+        "7bd7d0d7ea1db6509b70fb47858e03b856d644d7d05835dc71262e25a83e7da5", // 137
+        "55890eece14bcca1e1fd47da1e4c5c377239756de02c0d235975f823ee569cc1", // 138
+        "d18a9a86cc60fad40a11e46c16b818975111241cc18096fc5664ba0f3c9d3b4c", // 139
+        "ee820a8dc0839203cfe62eda3af0fab5c0b938dc61830fbb7a114d28a0ce68a0", // 140
+        "97da4b3d4e88c179096e67a2e75b47b729c0a3cd6884752de314b56db32821ff", // 141
+        "56d6f16bc76e407bafa863586995ddf331fb80a3693d414e81d6fc5fc9b65c04", // 142
+        "225c76fe21705b2ffa4db9ba7c1306bf16c2772d1f1fbbdc792d1e771d4f50f9", // 143
+        "0e71de0af5d0bcfc8d9f6ed02bf66623dd089c1b2c2e9a5dfb74ad224533fd80", // 144
+        "5d424c49aa7176396d8d6623008910726785429c75b5642fb5d56e20ecba7cb8", // 145
+        "6f850f3d6238500f5b18ddc4942fc1497606c1e5051a1f4714ad8dae0702e37b", // 146
+        "ddc52cce54fc8ca4d61759268ab79b5e4c4ab938c3f63ff5b6261acac3642662", // 147
+        "9f7b67d42fd7b4038a3a7066c9da713cf44a834c367f23a859bd7177bba9a3f3", // 148
+        "66d6b37c9f36d088262c3ef31109fe7dc20703656ffefaf79216bb612ac0e6ba", // 149
+        "302448d351ca618b9e6dfd0a51b170bb17c922adb8296d62d1fc0cb46d02f669", // 150
+        "ef9c3859296e557aad89af5ee2e899d018477f0bf819fb062daf9d2497c4178a", // 151
+        "9c873ff974e869453de302c720ff5669782962d5baded192dfc604674d2964be", // 152
+        "3dfe5f450b32ee6d64a3802484e243077f1d4ddf57f5c4a7143cd675575bcd62", // 153
+        "6da2f62e7920771c6e06e1692ec90f216f69d3c7627cffc3cbad4342d0983575", // 154
+        "0b94c5830e69bc15d5abd6c8bdd53d57046b89c55adb30c684afdd4e19d09021", // 155
+        "4d392781499fc564334ef6e3de45fcb59b842406d754ad6a98308492d747fdb9", // 156
+        "9b15ec323b6156e90d877b1b0916c26e251974abfc008f420a3fff8ff0c2fb93", // 157
+        "d36519dc72a5b7578b7269f6d8edfe8a4da783daaeac0347aca851c89932abb9", // 158
+        "8755642c33a6a72970c45f8a4c85d1809cb5a9a548dc336ef1034fe4d863659c", // 159
+        "42cca6842d513d90f900754f356ebc7768e625da204a3413b0d8e21fefc1a465", // 160
+        "f862f68e1e79ce5b410069f65166ae864cd382053fe9c0bf9d3a41142ca63259", // 161
+        "c62019266d66b56420bfa5565236fd4f6d1359a83acf9a68712e3e1f3feac5d7", // 162
+        "343395e61a3afa09ec41afe6dc70cee07bd7cf6bdb32f8751e6bfb22a781cadc", // 163
+        "986a95b0394742d12a93c57521fcfe54ebd8a961dfcf74eb5acbaca038d4b7f9", // 164
+        "cb1b28339f7e230d1ac07ffa71e6af4674a0ee9ba68902d22f13810ec288bf02", // 165
+        "1013df37435b1735a86e659aa5cb2b0864832f9372ae121106fbe116a54eba47", // 166
+        "c067712ccbaf8c21dad43fd039d1d1e5f4ce1513a9ea1d6ad913fb7ad2f89d93", // 167
+        "b1d51e59193e993f2a67e12fa6fd1c301ba85e66e0f36af75af09f4e6ce7f881", // 168
+        "90098e30bb07ce91d6ec99a3b506bf18ba482090a85a219415ccdba9e6e1abf5", // 169
+        "6d3c322c097732cf450b1ab94575a37be99a99dc367e149a96a9fa07442351aa", // 170
+        "8afd723aae7cef2465e4464f67f46bc5043ec8de164805499fbe26ce8435eb26", // 171
+        "23bf2fb83604e54b06ec41196e6965e47aa39a9c9fb940684dfdda46ac9a5a25", // 172
+        "238027247e164f2c171256309b773014ebf760b86d27e81a410e746b569cf279", // 173
+        "68ad053449d99c72dbdf95bc3afc4fda52bd764a8f8d91c67199be5f8202ef7d", // 174
+        "5f52d3b31ffa399adb3cd49b3ef68b28f749207da84b2838e395d59d24004ce8", // 175
+        "b3a14d8ed2b16840f032fb2f2c8920e301fc770a20f9dc332b41bfde4c8e5116", // 176
+        "e356bfa61233c06b9f3029eb3b07365467369a04a32eb090c0b0c7600978dad9", // 177
+        "e90ad0d86e4386231eaa6984a0155a13d35213ba8ff0d5f73862f4c63f293707", // 178
+        "72e3ef258859a6bb2be026cf791ac6ae22bc401108142f6207757d9bf3b64dcb", // 179
+        "4a9b8ceeed2e4f49de294f1e44b72698da906ece85bb10ad195bf049385662d6", // 180
+        "aaf0c4a9fb9fb98eb14fd7196625ec47e96471d2c40736eba0712ea1d4049978", // 181
+        "c8ec207f470bded4339e06b9431ad88e7cde1a1a30e7eefbf1aac202e997ab61", // 182
+        "75bed388d78f2a285c7403f7d527e1ca1ef4bd3d6ce4d8fbecf662bb030a8266", // 183
+        "dfd9fd60236a5be774c884158f4534b224ed763b721a93bada16f22a69f84119", // 184
+        "cf945f37e3ee10b7d50483dd2522fe87b83b7dbef64ca46deaedc0d6da01b18a", // 185
+        "7405433600f026b7d1e4d430a9dcc043f8c5065b83c56289ea11c58b0ddb932c", // 186
+        "88c15fa48881ec8a31517f1ed141ee562a0df4880731069f9ada566ea015b7dc", // 187
+        "e4e49d64a406c4d39866f696219c5e498dec8be08685708514ca1ef408811e9d", // 188
+        "8985b7d6e6a34dcae2c173e06a012228dda87ef8b846f463b04a65f81e24bf07", // 189
+        "a49ea7613f7fc2902df733ffd5510f28a4147f0b1fafa1b6e95635f3b885b083", // 190
+        "554a3d1d121fd35ba19b244099461118a43285a522148fbd89379c5dca04abfb", // 191
+        "918c3fc35ac3bd153173f077e24127b1ea5d5bbf01ef6e9d91b6e822dc63d413", // 192
+        "8c65c716edf0c1a60691aa59f66300a2a956a410c2e97eb839c38ade53df7237", // 193
+        "ebacc0292fba5910ea6adf4f5527ea58b180a196300c6468efdbbc212d51f617", // 194
+        "7314ad4002e9358f4f032ab50a8627cb713cad3f00167fee735812f42fee3e7c", // 195
+        "c4f512b6c8f931cc97b8eec8ddd5e88356a1abca3cb5a66bf538f211ff139cc1", // 196
+        "d0938c59388e3dd750889ec4e1c582b8533d76ac695a64cc93bb357437b85450", // 197
+        "7668e084fadfd27b5ef283b25949bfec749002619448018d62af357cb6f31f01", // 198
+        "3ac4762f4d764bc4926d3a18a15d0bcd58f48de0e4b47994851286cb1393f3c5", // 199
+        "29aec3feb22eda520ed965e6f63811cf7dc4e48b0e6915f87747fc8e843314b0", // 200
+        "ed7c73f1a77db1d0ec52d1a7417a1e0502a9659e29e4a9be1301e8957ce5ef36", // 201
+        "f393f00e948ee0c9708c30b0b9aafd1bbe03ca20d829b940fb17dfe7374b36bb", // 202
+        "61cb2df8f12e7b8d8a1e4a869eab7241f2bfce441cdf4b6c0e54184916021496", // 203
+        "2450737ad95c62bbb7ca23d3d3e0bd8a0764afdea3e57626fd812006a55e89f0", // 204
+        "e171a46feef0b4d6d2011298dfcec94b1196193ca1ab06f042bebd9016ce9b5f", // 205
+        "8434da3662ef70605ccfd95db4d1307a682828739e3c3c19d3e80e6b5c033527", // 206
+        "c03d12a9448435121df862c02dd38921c00db9127281da1b06d0e41b9b9ff271", // 207
+        "9f351bbabf01f261925c80efb1f08215355772d285f39662ea5524b24b6f7a37", // 208
+        "b5311963f4af594158237cc3463c04606db1c5f8ab8f563d69f8e69135aa82f1", // 209
+        "fd052eac095eeba1dd1b18b76c731c848cc876e1031a0eb5e6f9cbf5c72890e7", // 210
+        "bbb67f8b0ae87802e3a94b5d7d856f1e549f27d7a26224266874397f2b7847a4", // 211
+        "cf7560c90595909bfee0e3b5f5e9bc016b94ca9a460649099f4736d105d56efd", // 212
+        "4efe13e34c1f1b5abd61ae45656156ba247848ee214ab651c42ccbd9b9b6f59e", // 213
+        "ca8d2dffbfb770ad599081cb6eaeabaaa82d4745231a310ee258d84e26abd32f", // 214
+        "342f7cc9129a4275baf0b2aa99814722da3f0e2247e5b823e7e323b5dfc5f99a", // 215
+        "5c13d373676aa5ebeb02394886201526a6c59f40938a64af91480967d1951db6", // 216
+        "d0e23fc17b2d52d74695c01efcebd7851763762f2f7ac79c09197085d26a36bd", // 217
+        "1e85768cd0c236f42d7e6491d07bbb6f4002e870f081f144f87521fd5595bd59", // 218
+        "bc528c65b17fc969625b185a49786d85e1a095a55d36d937db63531ccbc48b1f", // 219
+        "3be6b649a2c3a1a4514faf2dc212db4a4ee1736b923678322baea2779b85bcb5", // 220
+        "cf23453ef1fbdad49d1e071f330b2a43c6ee8c3724ac614ab906d2c5374a28be", // 221
+        "5222f9f30a524ca64131139486f2796b73c2c477adcb32b5a01850355e7e5384", // 222
+        "577f992c69bc59052745f9b4544d4266f4cd5424316c23063b2ad7f47c6616e9", // 223
+        "964137370e8f92c11c87def8b5b28d7977920e4b76474e273e049edf24da37cf", // 224
+        "36beeaee04520d41bfe8487bd832ba9b3cfebcd61df24dbe58b3c7c8a08bfbe4", // 225
+        "376f85503dae1f132997d1c8c92081247ae887afc6f3c70924fbe50bba84a275", // 226
+        "a43b9d80b10a2e750be472d343f63a088bfbde8f3fc1e5942e62dc7b58438983", // 227
+        "84c3fab81f0ec5aac69ecd53d82497981d747c41f6fea8784bf66e9ea06b9fce", // 228
+        "e5d7de87f5d0fef6f4c622e876bdeabb07ab26a3749e63516fd8b38befeb7199", // 229
+        "3091742f3901d47b9d0344abf0d7892fd5da837190bbfc7264767ae654426465", // 230
+        "cf35264b7388ef891f4149e9c68fa4f83223b3bc8851ae739b2c2039d00c023f", // 231
+        "4b4f37a4525498b9bb35774626ae253ea283b77b5a2716547bf07678a86e0e54", // 232
+        "e9170f35212ea5db121b023bac41ffebd80433671c90ddfbf27d341cccfcf34d", // 233
+        "971776de333a3a78cbe2c57255bfd7621678ec3e3dfbc1ab227711ea4e023c0e", // 234
+        "263c3cc96c032c67192d34ff1856121765cee0ef024c1494ba9256b276b33cc4", // 235
+        "2faeed963a2814a1e2df575565b8b45da46c2364989eb67797ff1783b86c6dc0", // 236
+        "e95f0b8fdcb70a19b9bc978d97de6035c6c8dbcb84c5fc539c0d9e8330cd3c50", // 237
+        "7f4f853b5d564d942d3c0156ad7adcc185b7f66506c90737eeb47fc7db023be7", // 238
+        "df71653fe9eca97cadff2eb9895b9bc5a093086c467c1d402cea1dad92889b42", // 239
+        "7dca19fd3432687440403fd7ffa93b94958e75f521a0d339b47d3c8a8df53920", // 240
+        "965396dcc660a2cf0189ecbcbf6a8dd329c358ddb7f8c349fc32e0d9774cc968", // 241
+        "4cc318a243e78dba6863a409720ba2072f0c8bd64f43200c0ff1aa41c6a75ae7", // 242
+        "d262b92eca64b3085de457bd73e501fbc239c8f5a3bfa65450f54e820e0b38c9", // 243
+        "5413df6067eaf896a394d899264b25e782e6ba1d947bcfb17b0180056046e78f", // 244
+        "f8eba36d6784615988d48513f7973ce7e6e15655983b28a5a044011077931571", // 245
+        "15f5b50a74da0a9ec5f0067444f721bda4bd4f74f846812a7e7fd9052cc79d55", // 246
+        "2866b429a7576457645740c8be2fc7f56aecab8fe906dbc078e2cde6c8412ead", // 247
+        "772e726c006ef2840f2cbfb2bba348701618aa1e962537193e4bc0d778a00c44", // 248
+        "3c3655fc6c9c3f77393b26663ca2d3945dc36a7e82631f5e8cb77fe482c09ad8", // 249
+        "a3edcad7d71abaf844b4b505f17945706aedec36d44b89fb468762114a04403f", // 250
+        "de0bcba680994cf8e0324635362e3e40547c44be3095b6e9f89b090454e472dc", // 251
+        "567fa04bf65584a4c843074b2db78e49eeb8a3f83a7fbe186f0116b3f7d8a078", // 252
+        "bf2ca4262194e772444e517b95fe1fcc70427b40869dae5a7c83079b56970c5e", // 253
+        "d9f15deb13bce4494f00a08afbe52bc94fa5228d94fca923c4aa7e9955a089ba", // 254
+        "0179d555c63bdedfa046d9fe15a234a5760bc158c62ff011629f49ed289e4ccc", // 255
+        "cb586278b6e52ee4f917138a0ff0288b18d406e9eb34b908775e7a654c86c1aa", // 256
+        "c741a0ceb6f71cb8a0dd7ae5a263e7839db7c41ef2fce98e6ee106db1bdc160d", // 257
+        "20359059c970ef08bafe824b9f8679875e2e15a674ba9c97e79254d029f247c6", // 258
+        "e4da665998722f54e75a9360d1bdec73aa7574f4463c53fc4017e69dd911bc7a", // 259
+        "020dea2a97f27eb72ae50ddbc3a901f3b46b66288f091dfd1c4189f008b43a2b", // 260
+        "226deb8d038a99247e2fd4b48872181c3ae6aa1608033870aead805cf2e71270", // 261
+        "bb289abcd6166a1f5bc15723374c5bc53e76210b0eb57e7ebf90feb4da185205", // 262
+        "dc1cf0b956e196bece14a6ab9a8ec8d349728bfbfc5f46a97060f34c71552226", // 263
+        "8668040cb17994494eb7962508dde64f7b0a40c63ad402896a2bdbb210bfac8a", // 264
+        "252bf9aa39e135b67c9cb5fb456fc5aee89d94a3b694309b5e767b2aa003c99d", // 265
+        "ddb687c6dc8ede4f7bda1c7804edf1b7c55209f5bc86495b5b94431dc151f40b", // 266
+        "fd1f1948be984c4038db446f4857506c5c90e7bbc3a2d29db15eabf470d6fbb4", // 267
+        "29017919a38c02fa9c6b76cdcaad3b77b160c2f7223016a84205427035d415e9", // 268
+        "4a4554292186557efa9cd5cc7a7270618922ec3d49ea2efe159a306c887ef44a", // 269
+        "fe9aa746e8d29d9109ab2942e47e20866288383d07d9a1e28de8753d0477e99c", // 270
+        "7b278b9419f0c349d8f15afad21fbaf89d525eeadacdc30f09527dc02e3f9596", // 271
+        "607c1cf19138164c3f6fe185203d2942f88d6cc9041ae24ceed2692cdfef7f56", // 272
+        "fc1cf7746d073375b4277d2e1cd61a33157f50999076be4a570bfe78fd244610", // 273
+        "4877d9bedbff9eba852b4d6fde146ec10c9d634ff4bf08c6d55fa87d9aa4c943", // 274
+        "bc609900760190e0237c4e0ab051cf90cf9bf57910e7740bc302dfb5d4cacb14", // 275
+        "883b5dce0bf03f4270bb58be869cea1aa487bf9dc652ed037855e414cbad42aa", // 276
+        "4f87be49a98dc1df3129e6fb9faa4d4378232b9b74dd68a34f3bcd3c679c8ab2", // 277
+        "97cdf4d3b4cbcf2dfba5c745beacdbac123ddf23030820a691c1d51e11e859d6", // 278
+        "036ff6ffdcd6d22138b631fa8e13903bf94efafbca17a2d618e54c9ab6dbdc21", // 279
+        "7fa7fcfb74d01438577a69cc4b597b2c4a27a3451f359b13ac3c236fd60ef725", // 280
+        "72169c58ffae7ada71e04f400a28ab390537a7513aacde8861fc622999bdd267", // 281
+        "fea66d93a947ed8c2fa5ea24ec198bc87aade69a4ec79c67707294cb50f454cb", // 282
+        "56e31ed23cfdbe1ea2021ed5148d5505610f3859ba17a44688e98a18fef7f20c", // 283
+        "4b890457fcae9be5f52a98ac8d6164ccc69884f7f70d02512f5e283cd5aca918", // 284
+        "8408ff4af3d022692d776150fd8046f06c158ad779b053275d9a380b5c1b2f7e", // 285
+        "ef9b822093ad72f971d183bbb2ad1128e5bf2f6f31dd6924d9a3b377a2346a66", // 286
+        "08888003d97fc7c0977458087369ce512c540098c11b0180368a8dfa1c7928a4", // 287
+        "67f2ae0460e981aa4a37fb0bf698d283d048766bd94bb789453bfd2eddf17fa6", // 288
+        "4662c5b487ce6e164e999a40d58d37cb0bd6b329d96e5a2f5b18ae1370bad17f", // 289
+        "26211f8ebfed170e844ff22216a04ec7e803263076cc407a1031c08218a3330f", // 290
+        "f6eb6a14a52e4609947513c183f3c57e17943c48a40b97286bfc29511eae21bb", // 291
+        "84081eb98bd9a1c3097f89bd9cae70458d56174bd186fcfc052aaf2436e7f682", // 292
+        "ba5bed2fe053e69b2d3eeab6d4765f6b038d728846cd8fa88b10028fcceb89df", // 293
+        "ec95e23ccb9f04754180600918a63d061b2dc1738653dfddf8a6088bcad6cc64", // 294
+        "4b39c19de0647a4d41a6e74037ee475dd177ed38ed469117328da379b18dadb0", // 295
+        "e9c64e1af5c73c3b40c8ee91c49740659202866018064c39f107fd2db2b63261", // 296
+        "394295cdb260a2de76cc6e114dcd989cab95723cc4f0499236313c0aca7a20eb", // 297
+        "8c3036fe8bfc8766a125bc117c29d4b15bed11cc49dce8950334b79dd352bd40", // 298
+        "c17cbd1f921b7193510425a961576f810c511c59abd8afe628c9e9462e138f94", // 299
+        "a4d1e3ff6e6e570636921e28f256e8e9d82e5b96b93e6080121314fe8a3b52b1", // 300
+        "b516b8c4a624e0d776a9460e5a4f23c26cda1772be19d4052c1320f70014a649", // 301
+        "182897503e7bd063277348f6a6ca67b11ec9835fabec256453142887faf54c58", // 302
+        "db9c120735c9424d65156efcd7624f998667c5904010fd857565bd9ae4e1e112", // 303
+        "3743c98a2935dd9fa70082af2691c651580746070a5d3f95abaef8573842afb2", // 304
+        "a9b9b32b8428697e5184db490cf4ca989e9e976448b0f5e9bdab40a42a3c133c", // 305
+        "bc7a5a590fdb64f96fb8e6c99cc796f4b677d463c117b18e6953890555ea2b7a", // 306
+        "c5c4d11c39cba11641eac30cb8f05dcc1dcc1f702a563f66dbef7f4c8187c0bc", // 307
+        "44bdb170c95e769ab17b347c78c55af300cc9583fbceec6058999bf29bec1a88", // 308
+        "66878a23ae837944cb4ee8f040ffb1af91cb2e9aaae0d9a74b295fa6eb0b6072", // 309
+        "1caedbb059a8fe45f40b4d1147f715c60f882669a325e36bd5976f5b797e550e", // 310
+        "6428eb0616f620c343e36b5ba4cf806fba66fa12090321d3767b0ca0c97712cc", // 311
+        "caf4df5cc1a953efbf432d9a9890f88d5dd6563b8686bb783efef0476a0e7983", // 312
+        "106af075e879fd12c7a34963eadf26307c023a8665533eaab836dd632bd164b9", // 313
+        "8e58f205972746d3197cdca954b5450b43746f34815a43ec8d1a5e290653a64a", // 314
+        "6fd8d40ba447fd84b97b1fcd804894283f99642376a562ca8baf7b4bfd104fec", // 315
+        "64de3f97c1748528c72d2a363eab68a62b8ac04ceff4a2ccebe08752eca49271", // 316
+        "15dc49da24ce95aa6db03628422fc32b383b05bf245a2a700d46acbec4c6abbe", // 317
+        "805202c87f4f2d40e16ffefb1194b4a1d8701dd13c1c77bb0e91d93f7715180d", // 318
+        "542797907f6bf093c1b5498a3ece9b3719a0e58cf92310eb156c28ffa639d889", // 319
+        "81bef0be0c29301979efecd2121c15360921fb20f4fa6ffd4e8d5cae406357fa", // 320
+        "d79fd7efafb4959c05741ff24a127b7143946e5cae190ea4c09d843fa42d85f7", // 321
+        "f6413f90adcc68a17d5c0cd7a5410626e2771492bb828b8fc90f76fe1bb6d224", // 322
+        "bfc1b769e8d964b511d7e0041e3530c7a61857d84e8418e199193e182d4d7f8f", // 323
+        "2ee98e83863b906c61e704c372912183f6710e55fd91bfc1074d2921dd4bc7e1", // 324
+        "3f9aae7b9e0c7f3ae844bc054e525580b071b470d9dff79bb69337bed172ce8a", // 325
+        "905a0d5ea8642ddb745a8c4c49cb96dfd2c36f81aa093ebca5109fc4a2f62d7e", // 326
+        "6f5e7d01c10599cb010571a372706731a207f1285f38d5c5a5588f9aff4a4e6f", // 327
+        "91b90846dca716a393295e578606a372f6d64f891e1d9b17c9d54d2809c8eccc", // 328
+        "9a67b87cfd0e148ce5fa0d746be157dba7e2b4b4338b8169dd00a447e222c149", // 329
+        "f2108414f88fd70a8e4bd1f78a82c2739e5c72b97f76476924da21910143d45f", // 330
+    ],
+];
@@ -0,0 +1,171 @@
+//! Packing short ASCII names into integer keys.
+//!
+//! Many puzzles identify nodes using short names such as `aa` or `xyz`. Packing names into
+//! integers avoids hashing strings, and for alphanumeric names allows replacing hash maps with
+//! dense [`Vec`] or array indexing.
+
+use std::fmt;
+
+/// Pack up to four bytes into a [`u32`] key.
+///
+/// Bytes are packed big-endian, so keys for names of the same length sort in the same order as the
+/// names. Use [`unpack`] to recover the name.
+///
+/// # Panics
+///
+/// Panics if the name is longer than four bytes or contains a zero byte. When evaluated in a const
+/// context these are compile errors.
+///
+/// # Examples
+/// ```
+/// # use utils::short_key::pack_u32;
+/// const AA: u32 = pack_u32(b"AA");
+/// assert_eq!(AA, 0x4141);
+/// assert_eq!(pack_u32(b"abcd"), 0x6162_6364);
+/// assert!(pack_u32(b"ab") < pack_u32(b"ba"));
+/// ```
+#[inline]
+#[must_use]
+#[expect(clippy::cast_possible_truncation)]
+pub const fn pack_u32(name: &[u8]) -> u32 {
+    assert!(name.len() <= 4, "name longer than 4 bytes");
+    pack_u64(name) as u32
+}
+
+/// Pack up to eight bytes into a [`u64`] key.
+///
+/// See [`pack_u32`].
+///
+/// # Panics
+///
+/// Panics if the name is longer than eight bytes or contains a zero byte. When evaluated in a const
+/// context these are compile errors.
+///
+/// # Examples
+/// ```
+/// # use utils::short_key::pack_u64;
+/// assert_eq!(pack_u64(b"broadcas"), 0x6272_6f61_6463_6173);
+/// ```
+#[inline]
+#[must_use]
+pub const fn pack_u64(name: &[u8]) -> u64 {
+    assert!(name.len() <= 8, "name longer than 8 bytes");
+
+    let mut key = 0;
+    let mut i = 0;
+    while i < name.len() {
+        assert!(name[i] != 0, "name contains zero byte");
+        key = (key << 8) | name[i] as u64;
+        i += 1;
+    }
+    key
+}
+
+/// Returns a [`Display`](fmt::Display) wrapper which prints the name packed into a key by
+/// [`pack_u32`] or [`pack_u64`].
+///
+/// # Examples
+/// ```
+/// # use utils::short_key::{pack_u32, pack_u64, unpack};
+/// assert_eq!(unpack(pack_u32(b"xyz")).to_string(), "xyz");
+/// assert_eq!(unpack(pack_u64(b"rx")).to_string(), "rx");
+/// assert_eq!(unpack(0u32).to_string(), "");
+/// ```
+#[inline]
+#[must_use]
+pub fn unpack(key: impl Into<u64>) -> Unpacked {
+    Unpacked(key.into())
+}
+
+/// Name packed into an integer key, returned by [`unpack`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Unpacked(u64);
+
+impl Unpacked {
+    /// Returns the bytes of the name.
+    pub fn bytes(self) -> impl Iterator<Item = u8> {
+        self.0.to_be_bytes().into_iter().skip_while(|&b| b == 0)
+    }
+}
+
+impl fmt::Display for Unpacked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in self.bytes() {
+            fmt::Write::write_char(f, b as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the dense index for a name made up of digits and lowercase letters.
+///
+/// Each byte is treated as a base 36 digit, so every name of length `n` maps to a unique index
+/// less than [`alphanumeric_count(n)`](alphanumeric_count), suitable for indexing into a [`Vec`]
+/// or array instead of using a hash map. Returns [`None`] if the name contains any other bytes.
+///
+/// # Examples
+/// ```
+/// # use utils::short_key::{alphanumeric_count, alphanumeric_index};
+/// const XYZ: usize = alphanumeric_index(b"xyz").unwrap();
+/// assert_eq!(XYZ, 33 * 36 * 36 + 34 * 36 + 35);
+/// assert_eq!(alphanumeric_index(b"000"), Some(0));
+/// assert_eq!(alphanumeric_index(b"zzz"), Some(alphanumeric_count(3) - 1));
+/// assert_eq!(alphanumeric_index(b"aB"), None);
+/// ```
+#[inline]
+#[must_use]
+pub const fn alphanumeric_index(name: &[u8]) -> Option<usize> {
+    let mut index = 0;
+    let mut i = 0;
+    while i < name.len() {
+        let digit = match name[i] {
+            b'0'..=b'9' => name[i] - b'0',
+            b'a'..=b'z' => name[i] - b'a' + 10,
+            _ => return None,
+        };
+        index = index * 36 + digit as usize;
+        i += 1;
+    }
+    Some(index)
+}
+
+/// Returns the number of distinct alphanumeric names of the provided length.
+///
+/// See [`alphanumeric_index`].
+#[inline]
+#[must_use]
+pub const fn alphanumeric_count(len: u32) -> usize {
+    36usize.pow(len)
+}
+
+/// Returns the name of length `N` for an index returned by [`alphanumeric_index`].
+///
+/// # Panics
+///
+/// Panics if the index is too large for a name of length `N`.
+///
+/// # Examples
+/// ```
+/// # use utils::short_key::{alphanumeric_index, alphanumeric_name};
+/// assert_eq!(alphanumeric_name(alphanumeric_index(b"z09").unwrap()), *b"z09");
+/// assert_eq!(alphanumeric_name::<4>(0), *b"0000");
+/// ```
+#[inline]
+#[must_use]
+#[expect(clippy::cast_possible_truncation)]
+pub const fn alphanumeric_name<const N: usize>(mut index: usize) -> [u8; N] {
+    let mut name = [0; N];
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let digit = (index % 36) as u8;
+        name[i] = if digit < 10 {
+            b'0' + digit
+        } else {
+            b'a' + digit - 10
+        };
+        index /= 36;
+    }
+    assert!(index == 0, "index too large for name length");
+    name
+}
@@ -0,0 +1,390 @@
+//! Helpers for step-by-step simulations.
+
+use crate::grid::DIRECTIONS_8;
+use std::collections::VecDeque;
+
+/// Ring buffer of simulation state snapshots, allowing the state at an earlier step to be
+/// recovered.
+///
+/// A snapshot is stored every `interval` steps, keeping at most `capacity` snapshots. The state at
+/// any step after the oldest snapshot can be recreated using [`Recorder::replay_to`], which
+/// restores the nearest earlier snapshot and re-runs the simulation from there.
+///
+/// Recording can be disabled by using [`Recorder::disabled`], which makes [`Recorder::record`] a
+/// no-op, so the recorder can be left in solutions behind a debug flag.
+///
+/// # Examples
+/// ```
+/// # use utils::sim::Recorder;
+/// let step = |state: &mut (u64, u64)| *state = (state.1, state.0 + state.1);
+///
+/// let mut recorder = Recorder::new(10, 3);
+/// let mut state = (0u64, 1u64);
+/// for i in 0..50 {
+///     recorder.record(i, &state);
+///     step(&mut state);
+/// }
+///
+/// // Only the last 3 snapshots are kept, taken before steps 20, 30 and 40
+/// assert_eq!(recorder.oldest_step(), Some(20));
+/// assert_eq!(recorder.restore(37), Some((30, (832_040, 1_346_269))));
+/// assert_eq!(recorder.replay_to(37, step), Some((24_157_817, 39_088_169)));
+/// assert_eq!(recorder.replay_to(19, step), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Recorder<S> {
+    interval: usize,
+    capacity: usize,
+    snapshots: VecDeque<(usize, S)>,
+}
+
+impl<S: Clone> Recorder<S> {
+    /// Create a new recorder storing a snapshot every `interval` steps, keeping at most `capacity`
+    /// snapshots.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `interval` is zero.
+    #[must_use]
+    pub fn new(interval: usize, capacity: usize) -> Self {
+        assert!(interval > 0, "interval must be non-zero");
+        Self {
+            interval,
+            capacity,
+            snapshots: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Create a recorder which never stores snapshots.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::new(1, 0)
+    }
+
+    /// Returns true if this recorder stores snapshots.
+    #[inline]
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Record the state before the provided step is simulated.
+    ///
+    /// The state is only stored if `step` is a multiple of the interval, evicting the oldest
+    /// snapshot if the recorder is full. Steps should be recorded in increasing order.
+    #[inline]
+    pub fn record(&mut self, step: usize, state: &S) {
+        if self.capacity == 0 || step % self.interval != 0 {
+            return;
+        }
+
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back((step, state.clone()));
+    }
+
+    /// Returns the step of the oldest stored snapshot.
+    #[must_use]
+    pub fn oldest_step(&self) -> Option<usize> {
+        self.snapshots.front().map(|&(step, _)| step)
+    }
+
+    /// Returns the step of the newest stored snapshot.
+    #[must_use]
+    pub fn newest_step(&self) -> Option<usize> {
+        self.snapshots.back().map(|&(step, _)| step)
+    }
+
+    /// Returns a copy of the latest snapshot at or before the provided step, along with the step it
+    /// was recorded at.
+    #[must_use]
+    pub fn restore(&self, step: usize) -> Option<(usize, S)> {
+        let index = self.snapshots.partition_point(|&(s, _)| s <= step);
+        let (snapshot_step, state) = self.snapshots.get(index.checked_sub(1)?)?;
+        Some((*snapshot_step, state.clone()))
+    }
+
+    /// Recreate the state before the provided step by restoring the nearest earlier snapshot and
+    /// calling `advance` once per step until the target step is reached.
+    ///
+    /// Returns [`None`] if the step is before the oldest stored snapshot.
+    #[must_use]
+    pub fn replay_to(&self, step: usize, mut advance: impl FnMut(&mut S)) -> Option<S> {
+        let (snapshot_step, mut state) = self.restore(step)?;
+        for _ in snapshot_step..step {
+            advance(&mut state);
+        }
+        Some(state)
+    }
+
+    /// Remove all stored snapshots.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+/// Pair of grid buffers for simulations where every cell is updated simultaneously.
+///
+/// Each step computes the next generation from the current one into a second buffer, then swaps
+/// the buffers, avoiding both aliasing and reallocating the grid every step. The indices of cells
+/// which changed in the last step are tracked, which allows [`DoubleBuffer::step_active`] to only
+/// revisit the neighborhoods of changed cells, greatly accelerating sparse automata.
+///
+/// Grids use the same `(rows, cols, data)` layout as [`grid::from_str`](crate::grid::from_str).
+///
+/// # Examples
+/// ```
+/// # use utils::grid::DIRECTIONS_8;
+/// # use utils::sim::DoubleBuffer;
+/// // Blinker oscillating between horizontal and vertical
+/// let mut buffer = DoubleBuffer::new(5, 5, b".....\
+///     ..#..\
+///     ..#..\
+///     ..#..\
+///     .....".iter().map(|&b| b == b'#').collect());
+///
+/// let life = |grid: &[bool], index: usize| {
+///     let (row, col) = (index / 5, index % 5);
+///     let neighbors = DIRECTIONS_8
+///         .iter()
+///         .filter(|&&(dr, dc)| {
+///             let (r, c) = (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc));
+///             r < 5 && c < 5 && grid[r * 5 + c]
+///         })
+///         .count();
+///     matches!((grid[index], neighbors), (true, 2) | (_, 3))
+/// };
+///
+/// assert_eq!(buffer.step(life), 4);
+/// assert_eq!(buffer.changed(), [7, 11, 13, 17]);
+/// assert_eq!(buffer.current()[10..15], [false, true, true, true, false]);
+///
+/// // Only the neighborhoods of the 4 changed cells are evaluated
+/// assert_eq!(buffer.step_active(life), 4);
+/// assert_eq!(buffer.current()[10..15], [false, false, true, false, false]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DoubleBuffer<T> {
+    rows: usize,
+    cols: usize,
+    current: Vec<T>,
+    next: Vec<T>,
+    changed: Vec<usize>,
+    // True until the first step, when every cell must be evaluated
+    all_active: bool,
+    // True when next is a copy of current
+    synced: bool,
+    active: Vec<usize>,
+    queued: Vec<bool>,
+}
+
+impl<T: Clone + PartialEq> DoubleBuffer<T> {
+    /// Create a new double buffer from a grid with the provided dimensions.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the grid's length isn't `rows * cols`.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize, grid: Vec<T>) -> Self {
+        assert_eq!(
+            grid.len(),
+            rows * cols,
+            "grid length must equal rows * cols"
+        );
+        Self {
+            rows,
+            cols,
+            next: grid.clone(),
+            current: grid,
+            changed: Vec::new(),
+            all_active: true,
+            synced: true,
+            active: Vec::new(),
+            queued: Vec::new(),
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the current generation.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> &[T] {
+        &self.current
+    }
+
+    /// Consume the double buffer, returning the current generation.
+    #[must_use]
+    pub fn into_current(self) -> Vec<T> {
+        self.current
+    }
+
+    /// Returns the indices of the cells changed by the last step, in ascending order.
+    #[inline]
+    #[must_use]
+    pub fn changed(&self) -> &[usize] {
+        &self.changed
+    }
+
+    /// Returns the current generation and a buffer for the next generation, initialized as a
+    /// copy of the current generation.
+    ///
+    /// This allows implementing custom update logic, such as writing to multiple cells per
+    /// evaluated cell. Call [`DoubleBuffer::swap`] once the next generation is complete.
+    pub fn split_mut(&mut self) -> (&[T], &mut [T]) {
+        self.sync();
+        self.synced = false;
+        (&self.current, &mut self.next)
+    }
+
+    /// Swap the buffers, making the next generation current.
+    ///
+    /// Changed cells are found by comparing every cell. Returns the number of changed cells.
+    pub fn swap(&mut self) -> usize {
+        self.changed.clear();
+        self.changed.extend(
+            self.current
+                .iter()
+                .zip(&self.next)
+                .enumerate()
+                .filter(|(_, (a, b))| a != b)
+                .map(|(i, _)| i),
+        );
+        self.finish_step()
+    }
+
+    /// Advance one generation, calling `f` with the current generation and the index of each
+    /// cell to compute its next value.
+    ///
+    /// Returns the number of changed cells.
+    pub fn step(&mut self, mut f: impl FnMut(&[T], usize) -> T) -> usize {
+        self.changed.clear();
+        for (i, next) in self.next.iter_mut().enumerate() {
+            let value = f(&self.current, i);
+            if value != self.current[i] {
+                self.changed.push(i);
+            }
+            *next = value;
+        }
+        self.finish_step()
+    }
+
+    /// Advance one generation, only calling `f` for cells in the neighborhoods of the cells
+    /// changed in the last step.
+    ///
+    /// `f` must only depend on the cell itself and its 8 neighbors, as cells which aren't
+    /// evaluated keep their current value. Every cell is evaluated on the first step.
+    ///
+    /// Returns the number of changed cells.
+    pub fn step_active(&mut self, mut f: impl FnMut(&[T], usize) -> T) -> usize {
+        if self.all_active {
+            return self.step(f);
+        }
+
+        self.sync();
+        self.collect_active();
+
+        self.changed.clear();
+        for &i in &self.active {
+            let value = f(&self.current, i);
+            if value != self.current[i] {
+                self.changed.push(i);
+                self.next[i] = value;
+            }
+        }
+        self.changed.sort_unstable();
+        self.finish_step()
+    }
+
+    fn finish_step(&mut self) -> usize {
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.all_active = false;
+        // The buffers now only differ at the changed cells
+        self.synced = self.changed.is_empty();
+        self.changed.len()
+    }
+
+    fn sync(&mut self) {
+        if !self.synced {
+            for &i in &self.changed {
+                self.next[i].clone_from(&self.current[i]);
+            }
+            self.synced = true;
+        }
+    }
+
+    fn collect_active(&mut self) {
+        self.queued.resize(self.current.len(), false);
+        self.active.clear();
+
+        for &i in &self.changed {
+            let (row, col) = (i / self.cols, i % self.cols);
+            for (dr, dc) in [(0, 0)].into_iter().chain(DIRECTIONS_8) {
+                let (r, c) = (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc));
+                if r < self.rows && c < self.cols {
+                    let index = r * self.cols + c;
+                    if !self.queued[index] {
+                        self.queued[index] = true;
+                        self.active.push(index);
+                    }
+                }
+            }
+        }
+
+        for &i in &self.active {
+            self.queued[i] = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_active_matches_step() {
+        const SIZE: usize = 24;
+        let life = |grid: &[bool], index: usize| {
+            let (row, col) = (index / SIZE, index % SIZE);
+            let neighbors = DIRECTIONS_8
+                .iter()
+                .filter(|&&(dr, dc)| {
+                    let (r, c) = (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc));
+                    r < SIZE && c < SIZE && grid[r * SIZE + c]
+                })
+                .count();
+            matches!((grid[index], neighbors), (true, 2) | (_, 3))
+        };
+
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let grid: Vec<bool> = (0..SIZE * SIZE)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % 3 == 0
+            })
+            .collect();
+
+        let mut full = DoubleBuffer::new(SIZE, SIZE, grid.clone());
+        let mut active = DoubleBuffer::new(SIZE, SIZE, grid);
+        for _ in 0..50 {
+            assert_eq!(full.step(life), active.step_active(life));
+            assert_eq!(full.current(), active.current());
+            assert_eq!(full.changed(), active.changed());
+        }
+    }
+}
@@ -7,7 +7,7 @@
 #![allow(clippy::inline_always)]
 
 use std::array::from_fn;
-use std::ops::{Add, BitAnd, BitOr, BitXor, Not};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Not, Shr};
 
 #[derive(Clone, Copy)]
 #[repr(transparent)]
@@ -72,6 +72,15 @@ impl<const N: usize> Not for U32Vector<N> {
     }
 }
 
+impl<const N: usize> Shr<u32> for U32Vector<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        Self(from_fn(|i| self.0[i] >> rhs))
+    }
+}
+
 impl<const N: usize> U32Vector<N> {
     pub const LANES: usize = N;
 
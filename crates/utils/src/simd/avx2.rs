@@ -1,13 +1,13 @@
 //! AVX2 vector implementations.
 
-use std::ops::{Add, BitAnd, BitOr, BitXor, Not};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Not, Shr};
 
 #[cfg(target_arch = "x86_64")]
-#[expect(clippy::wildcard_imports)]
+#[allow(clippy::allow_attributes, clippy::wildcard_imports)]
 use std::arch::x86_64::*;
 
 #[cfg(target_arch = "x86")]
-#[expect(clippy::wildcard_imports)]
+#[allow(clippy::allow_attributes, clippy::wildcard_imports)]
 use std::arch::x86::*;
 
 /// AVX2 vector with eight [u32] lanes.
@@ -88,6 +88,18 @@ impl Not for U32Vector {
     }
 }
 
+impl Shr<u32> for U32Vector {
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        Self(unsafe {
+            #[expect(clippy::cast_possible_wrap)]
+            _mm256_srl_epi32(self.0, _mm_cvtsi32_si128(rhs as i32))
+        })
+    }
+}
+
 impl U32Vector {
     pub const LANES: usize = 8;
 
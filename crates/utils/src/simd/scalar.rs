@@ -3,7 +3,7 @@
 // #[inline(always)] significantly improves the performance of dev builds
 #![allow(clippy::inline_always)]
 
-use std::ops::{Add, BitAnd, BitOr, BitXor, Not};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Not, Shr};
 
 /// Scalar vector with a single [u32] lane.
 #[derive(Clone, Copy)]
@@ -69,6 +69,15 @@ impl Not for U32Vector {
     }
 }
 
+impl Shr<u32> for U32Vector {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        Self(self.0 >> rhs)
+    }
+}
+
 impl U32Vector {
     pub const LANES: usize = 1;
 
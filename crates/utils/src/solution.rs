@@ -0,0 +1,107 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// Error returned when a solution panics at runtime, for example because no solution was found.
+///
+/// Created by [`catch_panic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionError {
+    message: String,
+    location: Option<String>,
+}
+
+impl SolutionError {
+    /// Returns the panic message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the source location of the panic, if known.
+    ///
+    /// The location is unknown when the panic was propagated from another thread.
+    #[must_use]
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "solution panicked at {location}: {}", self.message)
+        } else {
+            write!(f, "solution panicked: {}", self.message)
+        }
+    }
+}
+
+impl Error for SolutionError {}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<SolutionError>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Run the provided function, converting panics into [`SolutionError`]s.
+///
+/// Panics on the current thread are recorded with their location instead of being printed by the
+/// panic hook. Panics on other threads are printed as usual, and when propagated to the current
+/// thread are returned without a location.
+///
+/// Panics can only be caught when compiled with `panic=unwind`. With `panic=abort`, which is the
+/// default for `wasm32-unknown-unknown`, the program still aborts.
+///
+/// # Examples
+/// ```
+/// # use utils::catch_panic;
+/// assert_eq!(catch_panic(|| 1 + 2), Ok(3));
+///
+/// let err = catch_panic(|| -> u32 { panic!("no solution found") }).unwrap_err();
+/// assert_eq!(err.message(), "no solution found");
+/// assert!(err.location().unwrap().contains(".rs:"));
+/// ```
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, SolutionError> {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                CAUGHT.set(Some(SolutionError {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    CAUGHT.take();
+    let was_catching = CATCHING.replace(true);
+    // Solution state is discarded after a panic, so any broken invariants are never observed
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| SolutionError {
+            message: payload_message(&*payload),
+            location: None,
+        })
+    })
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
@@ -0,0 +1,83 @@
+//! Utilities for avoiding stack overflows in deeply recursive solutions.
+//!
+//! Recursion depth often scales with the input size, for example when flood filling or searching
+//! large graphs. Native main threads usually have 8 MiB stacks, but WebAssembly stacks are much
+//! smaller, so solutions which work natively may overflow in the browser. Prefer rewriting the
+//! recursion using [`dfs`], and only use [`with_larger_stack`] when that is impractical.
+
+/// Depth-first traversal using an explicit stack.
+///
+/// `visit` is called with each node popped from the stack, and can push further nodes to visit.
+/// Nodes are visited in last in, first out order, matching the order recursive calls would be
+/// made in if `visit` pushes them in reverse. Any visited tracking must be done by `visit`.
+///
+/// # Examples
+/// ```
+/// # use utils::stack::dfs;
+/// // Flood fill from the top left corner
+/// let grid = ["..#..", ".##..", "..#..", "###.."];
+/// let mut filled = vec![vec![false; 5]; 4];
+/// dfs([(0, 0)], |(r, c): (usize, usize), stack| {
+///     if grid[r].as_bytes()[c] == b'#' || filled[r][c] {
+///         return;
+///     }
+///     filled[r][c] = true;
+///     if r > 0 { stack.push((r - 1, c)); }
+///     if r < 3 { stack.push((r + 1, c)); }
+///     if c > 0 { stack.push((r, c - 1)); }
+///     if c < 4 { stack.push((r, c + 1)); }
+/// });
+/// assert_eq!(filled.iter().flatten().filter(|&&b| b).count(), 5);
+/// ```
+pub fn dfs<N>(roots: impl IntoIterator<Item = N>, mut visit: impl FnMut(N, &mut Vec<N>)) {
+    let mut stack: Vec<N> = roots.into_iter().collect();
+    stack.reverse();
+    while let Some(node) = stack.pop() {
+        visit(node, &mut stack);
+    }
+}
+
+/// Run the provided function with a stack of at least `stack_size` bytes.
+///
+/// On native targets, the function is run on a new thread with the requested stack size, and
+/// panics are propagated to the calling thread.
+///
+/// On WebAssembly, the stack size is fixed when the module is linked, so the function is run
+/// directly on the current stack. Larger stacks can be configured using the
+/// `-C link-arg=-zstack-size=<bytes>` rustc flag.
+///
+/// # Examples
+/// ```
+/// # use utils::stack::with_larger_stack;
+/// fn depth(n: u64) -> u64 {
+///     if n == 0 { 0 } else { 1 + depth(n - 1) }
+/// }
+///
+/// assert_eq!(with_larger_stack(64 * 1024 * 1024, || depth(100_000)), 100_000);
+/// ```
+#[cfg(not(target_family = "wasm"))]
+pub fn with_larger_stack<T: Send>(stack_size: usize, f: impl FnOnce() -> T + Send) -> T {
+    std::thread::scope(|scope| {
+        let handle = std::thread::Builder::new()
+            .stack_size(stack_size)
+            .spawn_scoped(scope, f)
+            .expect("failed to spawn thread");
+        match handle.join() {
+            Ok(v) => v,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    })
+}
+
+/// Run the provided function with a stack of at least `stack_size` bytes.
+///
+/// On native targets, the function is run on a new thread with the requested stack size, and
+/// panics are propagated to the calling thread.
+///
+/// On WebAssembly, the stack size is fixed when the module is linked, so the function is run
+/// directly on the current stack. Larger stacks can be configured using the
+/// `-C link-arg=-zstack-size=<bytes>` rustc flag.
+#[cfg(target_family = "wasm")]
+pub fn with_larger_stack<T: Send>(_stack_size: usize, f: impl FnOnce() -> T + Send) -> T {
+    f()
+}
@@ -2,6 +2,9 @@
 
 use std::num::NonZeroUsize;
 
+pub use crate::reduce::{par_map_reduce, tree_reduce};
+pub use crate::scratch::with_scratch;
+
 #[must_use]
 pub fn get_thread_count() -> NonZeroUsize {
     NonZeroUsize::new(1).unwrap()
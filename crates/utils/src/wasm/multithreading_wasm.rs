@@ -3,6 +3,9 @@
 use super::scoped_tasks::{scope, worker_count};
 use std::num::{NonZero, NonZeroUsize};
 
+pub use crate::reduce::{par_map_reduce, tree_reduce};
+pub use crate::scratch::with_scratch;
+
 #[must_use]
 pub fn get_thread_count() -> NonZeroUsize {
     // If there are no workers, `scoped_task` will fall back to running tasks on the current thread.
@@ -54,11 +54,12 @@
 //! });
 //! ```
 
+use crate::scheduling::{TaskQueue, Workers};
 use std::any::Any;
-use std::collections::VecDeque;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
-use std::sync::mpsc::{SyncSender, TrySendError};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 
 #[cfg(not(all(
@@ -68,19 +69,37 @@ use std::sync::{Arc, Condvar, Mutex};
 )))]
 compile_error!("Required target features not enabled");
 
+pub use crate::scheduling::SchedulingPolicy;
+
 /// Create a scope for spawning scoped tasks.
 ///
 /// Scoped tasks may borrow non-`static` data, and may run in parallel depending on thread pool
 /// worker availability.
+///
+/// Tasks spawned with [`Scope::spawn_with_priority`] are run in [`SchedulingPolicy::Fifo`] order
+/// within each priority. Use [`scope_with_policy`] to select a different policy.
 #[inline(never)]
 pub fn scope<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+{
+    scope_with_policy(SchedulingPolicy::Fifo, f)
+}
+
+/// Create a scope for spawning scoped tasks, using the provided policy to order queued tasks with
+/// the same priority.
+///
+/// See [`scope`] and [`Scope::spawn_with_priority`].
+#[inline(never)]
+pub fn scope_with_policy<'env, F, T>(policy: SchedulingPolicy, f: F) -> T
 where
     F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
 {
     let scope = Scope {
         data: Arc::new(ScopeData {
-            mutex: Mutex::new((0, false)),
+            mutex: Mutex::new(ScopeState::default()),
             condvar: Condvar::new(),
+            policy,
         }),
         _scope: PhantomData,
         _env: PhantomData,
@@ -88,15 +107,23 @@ where
 
     let result = catch_unwind(AssertUnwindSafe(|| f(&scope)));
 
-    // Wait for tasks to finish
+    // Wait for tasks to finish, running any queued tasks on this thread
     let mut guard = scope.data.mutex.lock().unwrap();
-    while guard.0 > 0 {
-        guard = scope.data.condvar.wait(guard).unwrap();
+    loop {
+        if let Some(task) = scope.data.pop_next(&mut guard) {
+            drop(guard);
+            task();
+            guard = scope.data.mutex.lock().unwrap();
+        } else if guard.running > 0 {
+            guard = scope.data.condvar.wait(guard).unwrap();
+        } else {
+            break;
+        }
     }
 
     match result {
         Err(e) => resume_unwind(e),
-        Ok(_) if guard.1 => panic!("scoped task panicked"),
+        Ok(_) if guard.panicked => panic!("scoped task panicked"),
         Ok(x) => x,
     }
 }
@@ -124,6 +151,119 @@ impl<'scope, 'env> Scope<'scope, 'env> {
     /// If no workers within the thread pool are available, the task will be executed on the current
     /// thread.
     pub fn spawn<F, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let (handle, task) = self.task(f);
+        scoped_task(task);
+        handle
+    }
+
+    /// Try to spawn a new task within the scope on an idle worker.
+    ///
+    /// Unlike [`spawn`](Self::spawn), the closure is returned if no workers within the thread pool
+    /// are available, allowing the caller to decide how to run it.
+    pub fn try_spawn<F, T>(&'scope self, f: F) -> Result<ScopedJoinHandle<'scope, T>, F>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        // Store the closure outside the task so it can be recovered if the task isn't accepted
+        let slot = Arc::new(Mutex::new(Some(f)));
+        let task_slot = slot.clone();
+        let (handle, task) = self.task(move || {
+            let f = task_slot.lock().unwrap().take().unwrap();
+            f()
+        });
+
+        match try_scoped_task(task) {
+            Ok(()) => Ok(handle),
+            Err(task) => {
+                // The task will never run, so drop it and mark it as finished
+                drop(task);
+                self.data.task_end(false);
+                Err(slot.lock().unwrap().take().unwrap())
+            }
+        }
+    }
+
+    /// Call `f` on chunks of the provided range, splitting the range across idle workers.
+    ///
+    /// The range is recursively halved and the second half handed to an idle worker until chunks
+    /// would be smaller than `min_chunk`. When no workers are available, the next `min_chunk`
+    /// indices are processed on the current thread before trying to split the remaining range
+    /// again.
+    ///
+    /// This function returns once the chunks assigned to the current thread are complete. Chunks
+    /// processed by other workers are complete by the time the scope ends.
+    pub fn for_each_chunk<F>(&'scope self, range: Range<usize>, min_chunk: usize, f: F)
+    where
+        F: Fn(Range<usize>) + Send + Sync + 'scope,
+    {
+        self.split_chunks(range, min_chunk.max(1), &Arc::new(f));
+    }
+
+    fn split_chunks<F>(&'scope self, mut range: Range<usize>, min_chunk: usize, f: &Arc<F>)
+    where
+        F: Fn(Range<usize>) + Send + Sync + 'scope,
+    {
+        while range.len() >= min_chunk * 2 {
+            let mid = range.start + range.len() / 2;
+            let (right, right_f) = (mid..range.end, f.clone());
+            if self
+                .try_spawn(move || self.split_chunks(right, min_chunk, &right_f))
+                .is_ok()
+            {
+                range.end = mid;
+            } else {
+                let chunk_end = range.start + min_chunk;
+                f(range.start..chunk_end);
+                range.start = chunk_end;
+            }
+        }
+
+        if !range.is_empty() {
+            f(range);
+        }
+    }
+
+    /// Queue a new task within the scope with the provided priority.
+    ///
+    /// Unlike [`spawn`](Self::spawn), the task is never run immediately on the current thread.
+    /// Queued tasks are run by idle workers, by threads waiting in [`ScopedJoinHandle::join`], and
+    /// by the thread which created the scope once the scope closure returns. Higher priority tasks
+    /// are run first, with tasks of the same priority ordered by the scope's [`SchedulingPolicy`].
+    ///
+    /// This allows long-running tasks, such as the largest chunk of an uneven workload, to be
+    /// started first to improve load balancing.
+    pub fn spawn_with_priority<F, T>(
+        &'scope self,
+        priority: i32,
+        f: F,
+    ) -> ScopedJoinHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        let (handle, task) = self.task(f);
+
+        {
+            let mut guard = self.data.mutex.lock().unwrap();
+            guard.queue.push(priority, task);
+            self.data.condvar.notify_all();
+        }
+
+        // Try to wake an idle worker to run queued tasks. If all workers are busy, the task will be
+        // run later by a waiting thread.
+        let scope_data = self.data.clone();
+        let _ = try_scoped_task(Box::new(move || scope_data.run_queued()));
+
+        handle
+    }
+
+    /// Wrap the provided closure into a task which stores the result in the returned handle.
+    fn task<F, T>(&'scope self, f: F) -> (ScopedJoinHandle<'scope, T>, Task)
     where
         F: FnOnce() -> T + Send + 'scope,
         T: Send + 'scope,
@@ -135,6 +275,7 @@ impl<'scope, 'env> Scope<'scope, 'env> {
                 mutex: Mutex::new(None),
                 condvar: Condvar::new(),
             }),
+            scope: self.data.clone(),
             _scope: PhantomData,
         };
 
@@ -157,12 +298,14 @@ impl<'scope, 'env> Scope<'scope, 'env> {
 
         // SAFETY: The `scope` function ensures all closures are finished before returning
         let closure = unsafe {
-            #[expect(clippy::unnecessary_cast, reason = "casting lifetimes")]
-            Box::from_raw(Box::into_raw(closure) as *mut (dyn FnOnce() -> bool + Send + 'static))
+            std::mem::transmute::<
+                Box<dyn FnOnce() -> bool + Send + 'scope>,
+                Box<dyn FnOnce() -> bool + Send + 'static>,
+            >(closure)
         };
 
         let scope_data = self.data.clone();
-        scoped_task(Box::new(
+        let task: Task = Box::new(
             #[inline(never)]
             move || {
                 // Use a second closure to ensure that the closure which borrows from 'scope is
@@ -170,27 +313,36 @@ impl<'scope, 'env> Scope<'scope, 'env> {
                 // returning too soon, while the closures still exist, which causes UB as detected
                 // by Miri.
                 let panicked = closure();
-                dbg!("finished");
                 scope_data.task_end(panicked);
             },
-        ));
+        );
 
-        handle
+        (handle, task)
     }
 }
 
-// Stores the number of currently running tasks, and if a panic occurred.
+type Task = Box<dyn FnOnce() + Send>;
+
 #[derive(Debug)]
 struct ScopeData {
-    mutex: Mutex<(usize, bool)>,
+    mutex: Mutex<ScopeState>,
     condvar: Condvar,
+    policy: SchedulingPolicy,
+}
+
+// Stores the number of unfinished tasks, if a panic occurred, and tasks waiting to be run.
+#[derive(Debug, Default)]
+struct ScopeState {
+    running: usize,
+    panicked: bool,
+    queue: TaskQueue<Task>,
 }
 
 impl ScopeData {
     fn task_start(&self) {
         let mut guard = self.mutex.lock().unwrap();
-        if let Some(new_running) = guard.0.checked_add(1) {
-            guard.0 = new_running;
+        if let Some(new_running) = guard.running.checked_add(1) {
+            guard.running = new_running;
         } else {
             panic!("too many running tasks in scope");
         }
@@ -198,9 +350,9 @@ impl ScopeData {
 
     fn task_end(&self, panicked: bool) {
         let mut guard = self.mutex.lock().unwrap();
-        guard.1 |= panicked;
-        if let Some(new_running) = guard.0.checked_sub(1) {
-            guard.0 = new_running;
+        guard.panicked |= panicked;
+        if let Some(new_running) = guard.running.checked_sub(1) {
+            guard.running = new_running;
             if new_running == 0 {
                 self.condvar.notify_all();
             }
@@ -208,12 +360,28 @@ impl ScopeData {
             panic!("more tasks finished than started?")
         }
     }
+
+    /// Remove the next queued task to run according to priority and scheduling policy.
+    fn pop_next(&self, state: &mut ScopeState) -> Option<Task> {
+        state.queue.pop(self.policy)
+    }
+
+    /// Run queued tasks on the current thread until the queue is empty.
+    fn run_queued(&self) {
+        loop {
+            let Some(task) = self.pop_next(&mut self.mutex.lock().unwrap()) else {
+                return;
+            };
+            task();
+        }
+    }
 }
 
 /// Handle to block on a task's termination.
 #[derive(Debug)]
 pub struct ScopedJoinHandle<'scope, T> {
     data: Arc<HandleData<T>>,
+    scope: Arc<ScopeData>,
     _scope: PhantomData<&'scope mut &'scope ()>,
 }
 
@@ -228,8 +396,22 @@ impl<'scope, T> ScopedJoinHandle<'scope, T> {
     // pub fn thread(&self) -> &Thread {}
 
     /// Wait for the task to finish.
+    ///
+    /// While waiting, tasks queued in the scope by [`Scope::spawn_with_priority`] are run on the
+    /// current thread, which ensures the task being waited for makes progress.
     pub fn join(self) -> Result<T, Box<dyn Any + Send + 'static>> {
         let HandleData { mutex, condvar } = self.data.as_ref();
+        loop {
+            if let Some(result) = mutex.lock().unwrap().take() {
+                return result;
+            }
+
+            let Some(task) = self.scope.pop_next(&mut self.scope.mutex.lock().unwrap()) else {
+                break;
+            };
+            task();
+        }
+
         let mut guard = mutex.lock().unwrap();
         while guard.is_none() {
             guard = condvar.wait(guard).unwrap();
@@ -244,54 +426,56 @@ impl<'scope, T> ScopedJoinHandle<'scope, T> {
     }
 }
 
-#[expect(clippy::type_complexity)]
-static WORKERS: Mutex<VecDeque<SyncSender<Box<dyn FnOnce() + Send>>>> = Mutex::new(VecDeque::new());
-
-fn scoped_task(mut closure: Box<dyn FnOnce() + Send>) {
-    let mut guard = WORKERS.lock().unwrap();
-    let queue = &mut *guard;
-
-    for _ in 0..queue.len() {
-        let Some(sender) = queue.pop_front() else {
-            break;
-        };
+static WORKERS: Workers<Task> = Workers::new();
+static TASKS_EXECUTED: AtomicUsize = AtomicUsize::new(0);
+static INLINE_FALLBACKS: AtomicUsize = AtomicUsize::new(0);
 
-        match sender.try_send(closure) {
-            Ok(()) => {
-                queue.push_back(sender);
-                return;
-            }
-            Err(TrySendError::Full(v)) => {
-                closure = v;
-                queue.push_back(sender);
-            }
-            Err(TrySendError::Disconnected(v)) => {
-                closure = v;
-                // Worker thread gone, discard sender
-            }
-        }
+fn scoped_task(closure: Task) {
+    if let Err(closure) = try_scoped_task(closure) {
+        // Fall back to run the closure on this thread
+        INLINE_FALLBACKS.fetch_add(1, Ordering::Relaxed);
+        closure();
     }
-    drop(guard);
+}
 
-    // Fall back to run the closure on this thread
-    closure();
+/// Send the closure to an idle worker, returning it if no workers are idle.
+fn try_scoped_task(closure: Task) -> Result<(), Task> {
+    WORKERS.try_send(closure)
 }
 
 /// Use this thread as a worker in the thread pool for scoped tasks.
 pub fn worker() {
-    let (tx, rx) = std::sync::mpsc::sync_channel(0);
-
-    {
-        let mut guard = WORKERS.lock().unwrap();
-        guard.push_front(tx);
-    }
-
-    for closure in rx {
+    for closure in WORKERS.register() {
+        TASKS_EXECUTED.fetch_add(1, Ordering::Relaxed);
         closure();
     }
 }
 
 /// Returns the current number of workers for scoped tasks.
 pub fn worker_count() -> usize {
-    WORKERS.lock().unwrap().len()
+    WORKERS.len()
+}
+
+/// Snapshot of the worker pool returned by [`worker_status`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkerStatus {
+    /// Number of workers registered with the pool.
+    pub workers: usize,
+    /// Total number of tasks run on workers.
+    pub tasks_executed: usize,
+    /// Total number of tasks spawned with [`Scope::spawn`] that ran on the spawning thread as no
+    /// workers were available.
+    pub inline_fallbacks: usize,
+}
+
+/// Returns the current status of the worker pool.
+///
+/// This allows the host to detect when workers failed to start and scoped tasks are only being run
+/// on a single thread.
+pub fn worker_status() -> WorkerStatus {
+    WorkerStatus {
+        workers: worker_count(),
+        tasks_executed: TASKS_EXECUTED.load(Ordering::Relaxed),
+        inline_fallbacks: INLINE_FALLBACKS.load(Ordering::Relaxed),
+    }
 }
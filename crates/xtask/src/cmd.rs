@@ -1,5 +1,12 @@
+pub(crate) mod check_days;
+pub(crate) mod corpus;
 pub(crate) mod input;
 pub(crate) mod new;
+pub(crate) mod reduce;
+pub(crate) mod size_report;
+pub(crate) mod test;
+pub(crate) mod todo;
+pub(crate) mod unsafe_audit;
 pub(crate) mod update;
 pub(crate) mod wait;
 pub(crate) mod web;
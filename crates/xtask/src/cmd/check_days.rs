@@ -0,0 +1,176 @@
+use crate::cmd::update::{find_days, find_years};
+use crate::common::{crate_dir_path, day_mod_name, repo_dir_path, year_create_name};
+use std::error::Error;
+use std::fs::read_to_string;
+
+/// Comment prefix used to mark hot functions and suppress findings.
+const MARKER: &str = "// check-days:";
+
+/// Check day modules for common pitfalls.
+///
+/// Usage: `check-days [$year [$day]]`
+///
+/// Scans the source of each day module, reporting:
+/// - `missing-examples`: no `examples!` invocation.
+/// - `missing-must-use`: `part1` or `part2` functions without `#[must_use]`.
+/// - `unwrap-in-new`: `.unwrap()` inside `new`, which should return an `InputError` instead of
+///   panicking on invalid input.
+/// - `std-hash-in-hot-path`: `HashMap` or `HashSet` inside a function marked with a
+///   `// check-days: hot` comment.
+///
+/// Findings can be suppressed with a `// check-days: allow($check)` comment on the same line or
+/// the line before. This is plain source analysis, so it only understands rustfmt formatted code.
+/// Returns an error if anything is found.
+pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut args = args.peekable();
+    let year = if args.peek().is_some() {
+        Some(crate::year_arg(&mut args)?)
+    } else {
+        None
+    };
+    let day = if args.peek().is_some() {
+        Some(crate::day_arg(&mut args)?)
+    } else {
+        None
+    };
+    crate::ensure_no_args(args)?;
+
+    let mut count = 0;
+    let crates_dir = crate_dir_path();
+    for y in find_years(&crates_dir)? {
+        if year.is_some_and(|year| year != y) {
+            continue;
+        }
+
+        let src_dir = crates_dir.join(year_create_name(y)).join("src");
+        for (d, _) in find_days(&src_dir)? {
+            if day.is_some_and(|day| day != d) {
+                continue;
+            }
+
+            let path = src_dir.join(day_mod_name(d)).with_extension("rs");
+            let display = path
+                .strip_prefix(repo_dir_path())
+                .unwrap_or(&path)
+                .display();
+            for (line, check, message) in check_source(&read_to_string(&path)?) {
+                println!("{display}:{line}: {check}: {message}");
+                count += 1;
+            }
+        }
+    }
+
+    if count > 0 {
+        return Err(format!("{count} problems found").into());
+    }
+    println!("no problems found");
+    Ok(())
+}
+
+/// Returns the 1-indexed line, check name and message for each finding in the source.
+fn check_source(source: &str) -> Vec<(usize, &'static str, String)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut findings = Vec::new();
+
+    if !lines.iter().any(|l| l.starts_with("examples!(")) {
+        findings.push((1, "missing-examples", "no examples! block".to_string()));
+    }
+
+    for (start, end) in functions(&lines) {
+        let name = fn_name(lines[start]);
+        let attributes = preceding(&lines, start);
+
+        if matches!(name, "part1" | "part2")
+            && !attributes.iter().any(|l| l.trim() == "#[must_use]")
+        {
+            findings.push((
+                start,
+                "missing-must-use",
+                format!("{name} is missing #[must_use]"),
+            ));
+        }
+
+        let hot = attributes
+            .iter()
+            .any(|l| l.trim() == format!("{MARKER} hot"));
+        for (i, line) in lines.iter().enumerate().take(end + 1).skip(start) {
+            if name == "new" && line.contains(".unwrap()") {
+                findings.push((
+                    i,
+                    "unwrap-in-new",
+                    "unwrap in constructor, return an InputError instead".to_string(),
+                ));
+            }
+            if hot && (line.contains("HashMap") || line.contains("HashSet")) {
+                findings.push((
+                    i,
+                    "std-hash-in-hot-path",
+                    format!("std hash collection in hot function {name}"),
+                ));
+            }
+        }
+    }
+
+    findings.retain(|&(i, check, _)| {
+        let allow = format!("{MARKER} allow({check})");
+        !(lines.get(i).is_some_and(|l| l.contains(&allow))
+            || (i > 0 && lines[i - 1].trim() == allow))
+    });
+    findings.sort_by_key(|&(i, _, _)| i);
+    findings
+        .into_iter()
+        .map(|(i, check, message)| (i + 1, check, message))
+        .collect()
+}
+
+/// Returns the first and last line index of each function.
+///
+/// The end of a function is the first following line closing a block at the same indentation.
+fn functions(lines: &[&str]) -> Vec<(usize, usize)> {
+    let mut functions = Vec::new();
+    for (start, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let after_vis = trimmed
+            .strip_prefix("pub ")
+            .or_else(|| trimmed.strip_prefix("pub(crate) "))
+            .unwrap_or(trimmed);
+        if !after_vis.starts_with("fn ") {
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        let end = if line.trim_end().ends_with('}') {
+            start
+        } else {
+            lines[start + 1..]
+                .iter()
+                .position(|l| l.strip_prefix(indent).is_some_and(|l| l.starts_with('}')))
+                .map_or(lines.len() - 1, |i| start + 1 + i)
+        };
+        functions.push((start, end));
+    }
+    functions
+}
+
+fn fn_name(line: &str) -> &str {
+    let (_, rest) = line
+        .split_once("fn ")
+        .expect("function line should contain fn");
+    let end = rest
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    &rest[..end]
+}
+
+/// Returns the attribute and comment lines directly before a line.
+fn preceding<'a>(lines: &[&'a str], index: usize) -> Vec<&'a str> {
+    lines[..index]
+        .iter()
+        .rev()
+        .take_while(|l| {
+            let l = l.trim_start();
+            l.starts_with("#[") || l.starts_with("//")
+        })
+        .copied()
+        .collect()
+}
@@ -0,0 +1,71 @@
+use crate::common::{crate_dir_path, create_dir, day_mod_name, write_file, year_create_name};
+use std::error::Error;
+use std::fmt::Write;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    match args.next().as_deref() {
+        Some("add") => add(args),
+        Some(_) => Err("unknown corpus subcommand".into()),
+        None => Err("expected corpus subcommand".into()),
+    }
+}
+
+/// Add an input and its expected answers to a year crate's regression corpus.
+///
+/// Usage: `corpus add $year $day $file $part1 $part2`, where `-` skips checking a part.
+///
+/// Corpus cases are stored in `crates/$year/corpus/$day/` as `$name.txt` and `$name.answers`,
+/// using the input file's name, and are run by the aoc crate's tests.
+fn add(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let year = crate::year_arg(&mut args)?;
+    let day = crate::day_arg(&mut args)?;
+    let file = args.next().ok_or("expected file argument")?;
+    let part1 = args.next().ok_or("expected part 1 answer argument")?;
+    let part2 = args.next().ok_or("expected part 2 answer argument")?;
+    crate::ensure_no_args(args)?;
+
+    let crate_dir = crate_dir_path().join(year_create_name(year));
+    if !crate_dir.is_dir() {
+        return Err(format!("{} crate does not exist", year_create_name(year)).into());
+    }
+
+    let file = Path::new(&file);
+    let name = file
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| {
+            !s.is_empty()
+                && s.bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        })
+        .ok_or("file name must only contain ASCII letters, digits, '-' and '_'")?;
+    let input = read_to_string(file)?.replace("\r\n", "\n");
+
+    let mut answers = String::new();
+    for (part, answer) in [("part1", part1), ("part2", part2)] {
+        if answer.contains('\n') {
+            return Err(format!("{part} answer must be a single line").into());
+        }
+        if answer != "-" {
+            writeln!(answers, "{part}: {answer}")?;
+        }
+    }
+    if answers.is_empty() {
+        return Err("at least one answer must be provided".into());
+    }
+
+    let corpus_dir = crate_dir.join("corpus").join(day_mod_name(day));
+    if !corpus_dir.is_dir() {
+        create_dir(&corpus_dir)?;
+    }
+
+    let input_path = corpus_dir.join(name).with_extension("txt");
+    if input_path.exists() {
+        return Err(format!("corpus case {name:?} already exists").into());
+    }
+
+    write_file(input_path, input)?;
+    write_file(corpus_dir.join(name).with_extension("answers"), answers)
+}
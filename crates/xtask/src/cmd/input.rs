@@ -36,7 +36,11 @@ pub fn download(year: Year, day: Day) -> Result<(), Box<dyn Error>> {
         return Err("puzzle is not released yet".into());
     }
 
-    let input = fetch(year, day)?;
+    let input = fetch(&format!(
+        "https://adventofcode.com/{}/day/{}/input",
+        year.to_u16(),
+        day.to_u8(),
+    ))?;
 
     let year_inputs_dir = repo_dir_path().join("inputs").join(year_create_name(year));
     if !year_inputs_dir.is_dir() {
@@ -51,17 +55,43 @@ pub fn download(year: Year, day: Day) -> Result<(), Box<dyn Error>> {
     )
 }
 
-fn fetch(year: Year, day: Day) -> Result<String, Box<dyn Error>> {
+/// Fetch the answers already submitted for a puzzle from its description page.
+///
+/// Only answers which have been accepted are shown on the page, so the returned list may be
+/// shorter than the number of parts. This is read-only and never submits answers.
+pub fn fetch_answers(year: Year, day: Day) -> Result<Vec<String>, Box<dyn Error>> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+
+    let page = fetch(&format!(
+        "https://adventofcode.com/{}/day/{}",
+        year.to_u16(),
+        day.to_u8(),
+    ))?;
+
+    let mut answers = Vec::new();
+    let mut remaining = page.as_str();
+    while let Some(start) = remaining.find(PREFIX) {
+        remaining = &remaining[start + PREFIX.len()..];
+        let end = remaining.find("</code>").ok_or("unterminated answer")?;
+        answers.push(
+            remaining[..end]
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&amp;", "&"),
+        );
+        remaining = &remaining[end..];
+    }
+    Ok(answers)
+}
+
+fn fetch(url: &str) -> Result<String, Box<dyn Error>> {
     let token = read_session_token()?;
     if token.chars().any(|c| !c.is_ascii_alphanumeric()) {
         return Err("invalid session token".into());
     }
 
-    let url = format!(
-        "https://adventofcode.com/{}/day/{}/input",
-        year.to_u16(),
-        day.to_u8(),
-    );
     println!("fetching {url}");
 
     // Use config provided to stdin to avoid leaking cookies via cli arguments
@@ -3,6 +3,13 @@ use crate::common::{
 };
 use std::error::Error;
 
+/// Create a new day from the template, creating the year crate if required.
+///
+/// Usage: `new $year $day`
+///
+/// The template includes an [`examples!`](utils::examples!) block, a doc comment skeleton and a
+/// commented out [`PuzzleGenerator`](utils::PuzzleGenerator) placeholder for benchmarking, with
+/// `TODO` markers listed by the `todo` command.
 pub fn main(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
     let year = crate::year_arg(&mut args)?;
     let day = crate::day_arg(&mut args)?;
@@ -71,7 +78,9 @@ utils::year!({year:#} => {{}});
         format!(
             r#"use utils::prelude::*;
 
-/// TODO
+/// TODO: one line summary of the puzzle.
+///
+/// TODO: notes on the algorithm, and any assumptions made about the input.
 #[derive(Clone, Debug)]
 pub struct {struct_name} {{
     input: String,
@@ -79,21 +88,34 @@ pub struct {struct_name} {{
 
 impl {struct_name} {{
     pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {{
-        Ok(Self{{input: input.to_string()}})
+        // TODO: parse the input
+        Ok(Self {{
+            input: input.to_string(),
+        }})
     }}
 
     #[must_use]
     pub fn part1(&self) -> u64 {{
+        // TODO: solve part 1
         0
     }}
 
     #[must_use]
     pub fn part2(&self) -> u64 {{
+        // TODO: solve part 2
         0
     }}
 }}
 
+// TODO: implement PuzzleGenerator to allow benchmarking using generated inputs, or remove
+// impl utils::PuzzleGenerator for {struct_name} {{
+//     fn generate(seed: u64) -> String {{
+//         todo!()
+//     }}
+// }}
+
 examples!({struct_name} -> (u64, u64) [
+    // TODO: add the examples from the puzzle description
     {{input: "...", part1: 0, part2: 0}},
 ]);
 "#
@@ -0,0 +1,222 @@
+use crate::common::{repo_dir_path, run_cargo};
+use std::error::Error;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use utils::date::{Day, Year};
+
+/// Maximum time each candidate input is run for before it is treated as uninteresting.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Exit code used by Rust programs when the main thread panics.
+const PANIC_EXIT_CODE: i32 = 101;
+
+enum Predicate {
+    /// The solution panics.
+    Panic,
+    /// The provided command exits successfully when passed the candidate input path.
+    Command(Vec<String>),
+}
+
+struct Reducer {
+    year: Year,
+    day: Day,
+    binary: PathBuf,
+    predicate: Predicate,
+    work_dir: PathBuf,
+}
+
+/// Reduce an input to a minimal input which still triggers the provided predicate.
+///
+/// Usage: `reduce $year $day $input $output panic` or
+/// `reduce $year $day $input $output command $program [$args...]`.
+///
+/// Blank line separated sections are removed first, then individual lines, using delta debugging.
+/// Candidates which the solution rejects as invalid are skipped, so the result remains parseable.
+/// For `command` predicates, the candidate input path is appended to the arguments and the path to
+/// the aoc binary is provided in the `AOC_BINARY` environment variable, so scripts can compare its
+/// answers against a reference.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let year = crate::year_arg(&mut args)?;
+    let day = crate::day_arg(&mut args)?;
+    let input_path = args.next().ok_or("expected input argument")?;
+    let output_path = PathBuf::from(args.next().ok_or("expected output argument")?);
+    let predicate = match args.next().as_deref() {
+        Some("panic") => {
+            crate::ensure_no_args(args)?;
+            Predicate::Panic
+        }
+        Some("command") => {
+            let command: Vec<String> = args.collect();
+            if command.is_empty() {
+                return Err("expected command".into());
+            }
+            Predicate::Command(command)
+        }
+        Some(_) => return Err("unknown predicate, expected panic or command".into()),
+        None => return Err("expected predicate argument".into()),
+    };
+
+    let input = read_to_string(&input_path)?.replace("\r\n", "\n");
+    let input = input.trim_ascii_end();
+
+    let target_dir = repo_dir_path().join("target");
+    run_cargo(
+        &["build", "--release", "--package", "aoc", "--bin", "aoc"],
+        &[],
+    )?;
+
+    let reducer = Reducer {
+        year,
+        day,
+        binary: target_dir
+            .join("release")
+            .join("aoc")
+            .with_extension(std::env::consts::EXE_EXTENSION),
+        predicate,
+        work_dir: target_dir.join("reduce"),
+    };
+    create_dir_all(&reducer.work_dir)?;
+
+    if !reducer.is_interesting(input, 0)? {
+        return Err("the original input does not match the predicate".into());
+    }
+
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    let sections = reducer.ddmin(sections, "\n\n", &output_path)?;
+    let joined_sections = sections.join("\n\n");
+
+    let lines: Vec<&str> = joined_sections.lines().collect();
+    let lines = reducer.ddmin(lines, "\n", &output_path)?;
+
+    write(&output_path, lines.join("\n") + "\n")?;
+    println!(
+        "reduced {} lines to {} lines, written to {}",
+        input.lines().count(),
+        lines.len(),
+        output_path.to_string_lossy(),
+    );
+    Ok(())
+}
+
+impl Reducer {
+    /// Remove as many units as possible while the joined input remains interesting.
+    fn ddmin<'a>(
+        &self,
+        mut units: Vec<&'a str>,
+        separator: &str,
+        output_path: &Path,
+    ) -> Result<Vec<&'a str>, Box<dyn Error>> {
+        let mut granularity = 2;
+        while units.len() >= 2 {
+            let chunk_size = units.len().div_ceil(granularity);
+            let candidates: Vec<String> = (0..units.len())
+                .step_by(chunk_size)
+                .map(|start| {
+                    let end = (start + chunk_size).min(units.len());
+                    [&units[..start], &units[end..]].concat().join(separator)
+                })
+                .collect();
+
+            if let Some(index) = self.first_interesting(&candidates)? {
+                let start = index * chunk_size;
+                let end = (start + chunk_size).min(units.len());
+                units.drain(start..end);
+                granularity = (granularity - 1).max(2);
+
+                println!("reduced to {} units", units.len());
+                write(output_path, units.join(separator) + "\n")?;
+            } else if granularity >= units.len() {
+                break;
+            } else {
+                granularity = (granularity * 2).min(units.len());
+            }
+        }
+        Ok(units)
+    }
+
+    /// Test candidates concurrently, returning the index of the first interesting candidate.
+    fn first_interesting(&self, candidates: &[String]) -> Result<Option<usize>, Box<dyn Error>> {
+        let threads = thread::available_parallelism().map_or(4, NonZeroUsize::get);
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(vec![false; candidates.len()]);
+        let error = Mutex::new(None);
+
+        thread::scope(|scope| {
+            for worker in 0..threads.min(candidates.len()) {
+                let (next, results, error) = (&next, &results, &error);
+                scope.spawn(move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= candidates.len() {
+                        break;
+                    }
+                    match self.is_interesting(&candidates[index], worker) {
+                        Ok(interesting) => results.lock().unwrap()[index] = interesting,
+                        Err(err) => *error.lock().unwrap() = Some(err.to_string()),
+                    }
+                });
+            }
+        });
+
+        if let Some(err) = error.into_inner().unwrap() {
+            return Err(err.into());
+        }
+        Ok(results.into_inner().unwrap().iter().position(|&x| x))
+    }
+
+    fn is_interesting(&self, input: &str, worker: usize) -> Result<bool, Box<dyn Error>> {
+        let path = self.work_dir.join(format!("candidate{worker}.txt"));
+        write(&path, input)?;
+
+        let mut aoc = Command::new(&self.binary);
+        aoc.args(["--format", "quiet", "--input"])
+            .arg(&path)
+            .args([format!("{:#}", self.year), format!("{:#}", self.day)]);
+        let Some(status) = run_with_timeout(aoc)? else {
+            return Ok(false);
+        };
+
+        match &self.predicate {
+            Predicate::Panic => Ok(status.code() == Some(PANIC_EXIT_CODE)),
+            Predicate::Command(command) => {
+                // Skip inputs the solution rejects as invalid
+                if status.code() == Some(1) {
+                    return Ok(false);
+                }
+
+                let mut cmd = Command::new(&command[0]);
+                cmd.args(&command[1..])
+                    .arg(&path)
+                    .env("AOC_BINARY", &self.binary);
+                Ok(run_with_timeout(cmd)?.is_some_and(|s| s.success()))
+            }
+        }
+    }
+}
+
+/// Run a command with output discarded, returning [`None`] if it times out.
+fn run_with_timeout(mut command: Command) -> Result<Option<ExitStatus>, Box<dyn Error>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() > TIMEOUT {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+}
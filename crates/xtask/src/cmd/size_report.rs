@@ -0,0 +1,87 @@
+use crate::common::{copy_file, create_dir, repo_dir_path, run_cargo};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Compare the size of release builds with and without the `compact` feature.
+///
+/// Usage: `size-report [--wasm]`
+///
+/// Builds the native `aoc` binary in release mode twice, once with the default features and once
+/// also enabling `compact`, and prints the size of each. `--wasm` instead builds the WebAssembly
+/// module. Builds are copied to `target/size-report` so they can be inspected afterward.
+pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut wasm = false;
+    for arg in args {
+        match arg.as_str() {
+            "--wasm" => wasm = true,
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let output = repo_dir_path().join("target").join("size-report");
+    if !output.exists() {
+        create_dir(&output)?;
+    }
+
+    let mut sizes = Vec::new();
+    for (name, features) in [
+        ("default", &[][..]),
+        ("compact", &["--features", "compact"]),
+    ] {
+        let (mut args, built) = if wasm {
+            (
+                vec![
+                    "build",
+                    "-p",
+                    "aoc_wasm",
+                    "--lib",
+                    "--target=wasm32-unknown-unknown",
+                    "--release",
+                ],
+                target_path(&["wasm32-unknown-unknown", "release", "aoc_wasm.wasm"]),
+            )
+        } else {
+            (
+                vec!["build", "-p", "aoc", "--bin", "aoc", "--release"],
+                target_path(&["release", &format!("aoc{}", std::env::consts::EXE_SUFFIX)]),
+            )
+        };
+        args.extend_from_slice(features);
+        run_cargo(&args, &[])?;
+
+        let copy = output.join(format!(
+            "{name}-{}",
+            built.file_name().unwrap().to_string_lossy()
+        ));
+        copy_file(&built, &copy)?;
+        sizes.push((name, file_size(&copy)?));
+    }
+
+    println!();
+    let (_, baseline) = sizes[0];
+    for (name, size) in sizes {
+        println!(
+            "{name:<8} {size:>10} bytes {:>+10} bytes ({:+.1}%)",
+            i128::from(size) - i128::from(baseline),
+            percent_change(baseline, size),
+        );
+    }
+
+    Ok(())
+}
+
+fn target_path(components: &[&str]) -> PathBuf {
+    let mut path = repo_dir_path().join("target");
+    path.extend(components);
+    path
+}
+
+fn file_size(path: &Path) -> Result<u64, Box<dyn Error>> {
+    Ok(fs::metadata(path)?.len())
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn percent_change(baseline: u64, size: u64) -> f64 {
+    (size as f64 - baseline as f64) / baseline as f64 * 100.0
+}
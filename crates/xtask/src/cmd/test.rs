@@ -0,0 +1,514 @@
+use crate::cmd::input::fetch_answers;
+use crate::cmd::update::{find_days, find_years};
+use crate::common::{
+    crate_dir_path, day_mod_name, repo_dir_path, run_cargo, write_file, year_create_name,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs::{copy, create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::{Duration, Instant, SystemTime};
+use utils::answers::ExpectedAnswers;
+use utils::date::{Day, Year};
+use utils::parser::{self, JsonValue, Parser};
+
+const DEFAULT_COMMAND: &str =
+    "${BINARY} --format json ${REPLAY} ${ALLOC_PROFILE} --input ${INPUT} ${YEAR} ${DAY}";
+/// Prefix of the line printed to stderr by the aoc binary's `--alloc-profile` option with the peak
+/// heap usage.
+const PEAK_HEAP_PREFIX: &str = "Peak heap usage: ";
+const WASM_COMMAND: &str = "crates/aoc_wasm/web/cli.mjs ${BINARY} ${YEAR} ${DAY} ${INPUT}";
+
+#[derive(Default)]
+struct Options {
+    runner: Vec<String>,
+    command: Option<String>,
+    binary: Option<PathBuf>,
+    wasm: bool,
+    online: bool,
+    artifacts: Option<PathBuf>,
+    memory_limits: Option<PathBuf>,
+    year: Option<Year>,
+    day: Option<Day>,
+}
+
+/// Run solutions against the expected answers stored alongside inputs.
+///
+/// Usage: `test [--runner $prefix] [--command $template] [--binary $path] [--wasm] [--online]
+/// [--artifacts $dir] [--memory-limits $path] [$year [$day]]`
+///
+/// Each input `inputs/$year/$day.txt` with a matching `$day.answers` file, using the same format as
+/// the corpus described in [`utils::answers`], is run using the command template. The template is
+/// split on whitespace and supports `${BINARY}`, `${INPUT}`, `${YEAR}` and `${DAY}` placeholders,
+/// and must print the aoc binary's `--format json` output. A `${REPLAY}` argument expands to
+/// `--replay $path` when a matching `$day.replay` file exists, and is removed otherwise. The runner
+/// prefix is prepended to the command, which allows testing cross-compiled binaries using emulators
+/// such as `qemu-aarch64`.
+///
+/// By default, the native release binary is built and tested. `--wasm` instead builds the
+/// WebAssembly module and runs it using the bundled node shim.
+///
+/// With `--online`, inputs without an answers file are checked against the answers shown on the
+/// puzzle page on adventofcode.com, using the same session token as the `input` command. Once every
+/// part has been accepted, the answers are saved to the answers file so later runs can verify them
+/// offline. Until then, the accepted parts are checked and the answers are fetched again next run.
+///
+/// With `--artifacts`, the input, command, stdout, stderr, exit status and run time of each failing
+/// case are saved to `$dir/$timestamp/$year-$day`, so failures in CI or other environments can be
+/// investigated after the run. The directory is only created if a case fails, and its path is
+/// printed in the summary.
+///
+/// With `--memory-limits`, days listed in the limits file fail if the peak heap usage of the
+/// solution exceeds the limit. Each line of the file is `$year $day: $limit`, where the limit is a
+/// number of bytes with an optional `KiB`, `MiB` or `GiB` suffix, and lines starting with `#` are
+/// ignored. The native binary is built with the `alloc-profile` feature, and an `${ALLOC_PROFILE}`
+/// argument expands to `--alloc-profile` for days with a limit, and is removed otherwise.
+pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut options = parse_args(args)?;
+    let memory_limits = match &options.memory_limits {
+        Some(path) => parse_memory_limits(&read_to_string(path)?)?,
+        None => HashMap::new(),
+    };
+
+    let (binary, command, runner) = resolve_command(&mut options)?;
+
+    let (mut passed, mut failed) = (0, 0);
+    let mut artifacts_dir = None;
+    let crates_dir = crate_dir_path();
+    for year in find_years(&crates_dir)? {
+        if options.year.is_some_and(|y| y != year) {
+            continue;
+        }
+
+        let src_dir = crates_dir.join(year_create_name(year)).join("src");
+        for (day, _) in find_days(&src_dir)? {
+            if options.day.is_some_and(|d| d != day) {
+                continue;
+            }
+
+            let input_path = repo_dir_path()
+                .join("inputs")
+                .join(year_create_name(year))
+                .join(day_mod_name(day))
+                .with_extension("txt");
+            let answers_path = input_path.with_extension("answers");
+            let expected = match read_to_string(&answers_path) {
+                Ok(expected) => expected,
+                Err(_) if options.online && input_path.exists() => {
+                    match online_answers(year, day, &answers_path) {
+                        Ok(Some(expected)) => expected,
+                        Ok(None) => {
+                            println!("{year:#} {day:#}: skipped, no answers submitted online");
+                            continue;
+                        }
+                        Err(err) => {
+                            println!("{year:#} {day:#}: FAILED: {err}");
+                            failed += 1;
+                            continue;
+                        }
+                    }
+                }
+                Err(_) => continue,
+            };
+
+            let memory_limit = memory_limits.get(&(year, day)).copied();
+            let args = expand_command(
+                &runner,
+                &command,
+                &binary,
+                &input_path,
+                year,
+                day,
+                memory_limit.is_some(),
+            );
+
+            let run = run_command(&args);
+            let result = match &run {
+                Ok(run) => check_output(
+                    &run.output,
+                    &expected,
+                    &input_path,
+                    &answers_path,
+                    memory_limit,
+                ),
+                Err(err) => Err(err.clone().into()),
+            };
+            match result {
+                Ok(()) => {
+                    println!("{year:#} {day:#}: ok");
+                    passed += 1;
+                }
+                Err(err) => {
+                    println!("{year:#} {day:#}: FAILED: {err}");
+                    failed += 1;
+
+                    if let Some(root) = &options.artifacts {
+                        let dir = artifacts_dir.get_or_insert_with(|| {
+                            let timestamp = SystemTime::now()
+                                .duration_since(SystemTime::UNIX_EPOCH)
+                                .unwrap_or_default()
+                                .as_secs();
+                            root.join(timestamp.to_string())
+                        });
+                        let case_dir = dir.join(format!("{year:#}-{day:#}"));
+                        save_artifacts(&case_dir, &args, &input_path, run.as_ref().ok(), &*err)
+                            .map_err(|e| format!("failed to save artifacts: {e}"))?;
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed");
+    if let Some(dir) = artifacts_dir {
+        println!("failure artifacts saved to {}", dir.display());
+    }
+    if failed > 0 {
+        Err(format!("{failed} tests failed").into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Expand the placeholders in the command template, prepending the runner.
+fn expand_command(
+    runner: &[String],
+    command: &str,
+    binary: &Path,
+    input_path: &Path,
+    year: Year,
+    day: Day,
+    alloc_profile: bool,
+) -> Vec<String> {
+    let replay_path = input_path.with_extension("replay");
+    runner
+        .iter()
+        .cloned()
+        .chain(command.split_whitespace().flat_map(|arg| {
+            if arg == "${REPLAY}" {
+                return if replay_path.exists() {
+                    vec![
+                        "--replay".to_string(),
+                        replay_path.to_string_lossy().to_string(),
+                    ]
+                } else {
+                    Vec::new()
+                };
+            }
+            if arg == "${ALLOC_PROFILE}" {
+                return if alloc_profile {
+                    vec!["--alloc-profile".to_string()]
+                } else {
+                    Vec::new()
+                };
+            }
+            vec![arg
+                .replace("${BINARY}", &binary.to_string_lossy())
+                .replace("${INPUT}", &input_path.to_string_lossy())
+                .replace("${YEAR}", &format!("{year:#}"))
+                .replace("${DAY}", &format!("{day:#}"))]
+        }))
+        .collect()
+}
+
+/// Build the binary to test if required, returning the binary path, command template and runner.
+fn resolve_command(
+    options: &mut Options,
+) -> Result<(PathBuf, String, Vec<String>), Box<dyn Error>> {
+    if options.wasm && options.memory_limits.is_some() {
+        return Err("--memory-limits cannot be used with --wasm".into());
+    }
+
+    let resolved = if options.wasm {
+        let binary = if let Some(binary) = options.binary.take() {
+            binary
+        } else {
+            run_cargo(
+                &[
+                    "build",
+                    "--release",
+                    "--package",
+                    "aoc_wasm",
+                    "--lib",
+                    "--target=wasm32-unknown-unknown",
+                ],
+                &[],
+            )?;
+            repo_dir_path()
+                .join("target")
+                .join("wasm32-unknown-unknown")
+                .join("release")
+                .join("aoc_wasm.wasm")
+        };
+        let runner = if options.runner.is_empty() {
+            vec!["node".to_string()]
+        } else {
+            std::mem::take(&mut options.runner)
+        };
+        (
+            binary,
+            options.command.take().unwrap_or(WASM_COMMAND.to_string()),
+            runner,
+        )
+    } else {
+        let binary = if let Some(binary) = options.binary.take() {
+            binary
+        } else {
+            let mut args = vec!["build", "--release", "--package", "aoc", "--bin", "aoc"];
+            if options.memory_limits.is_some() {
+                args.extend(["--features", "alloc-profile"]);
+            }
+            run_cargo(&args, &[])?;
+            repo_dir_path()
+                .join("target")
+                .join("release")
+                .join("aoc")
+                .with_extension(std::env::consts::EXE_EXTENSION)
+        };
+        (
+            binary,
+            options
+                .command
+                .take()
+                .unwrap_or(DEFAULT_COMMAND.to_string()),
+            std::mem::take(&mut options.runner),
+        )
+    };
+    Ok(resolved)
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, Box<dyn Error>> {
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runner" => {
+                let runner = args.next().ok_or("--runner requires an argument")?;
+                options.runner = runner.split_whitespace().map(String::from).collect();
+            }
+            "--command" => {
+                options.command = Some(args.next().ok_or("--command requires an argument")?);
+            }
+            "--binary" => {
+                options.binary = Some(args.next().ok_or("--binary requires an argument")?.into());
+            }
+            "--wasm" => options.wasm = true,
+            "--online" => options.online = true,
+            "--memory-limits" => {
+                options.memory_limits = Some(
+                    args.next()
+                        .ok_or("--memory-limits requires an argument")?
+                        .into(),
+                );
+            }
+            "--artifacts" => {
+                options.artifacts = Some(
+                    args.next()
+                        .ok_or("--artifacts requires an argument")?
+                        .into(),
+                );
+            }
+            _ if options.year.is_none() => options.year = Some(arg.parse()?),
+            _ if options.day.is_none() => options.day = Some(arg.parse()?),
+            _ => return Err("unexpected extra arguments".into()),
+        }
+    }
+    Ok(options)
+}
+
+/// Fetch the accepted answers for a puzzle, caching them in the answers file.
+fn online_answers(year: Year, day: Day, path: &Path) -> Result<Option<String>, Box<dyn Error>> {
+    let answers = fetch_answers(year, day)?;
+    if answers.is_empty() {
+        return Ok(None);
+    }
+
+    let mut expected = String::new();
+    for (i, answer) in answers.iter().enumerate() {
+        writeln!(expected, "part{}: {answer}", i + 1)?;
+    }
+
+    // Day 25 only has one answer. For other days, only save the answers once both parts have been
+    // solved, as existing answers files are never fetched again
+    let parts = if day.to_u8() == 25 { 1 } else { 2 };
+    if answers.len() >= parts {
+        write_file(path, expected.clone())?;
+    }
+    Ok(Some(expected))
+}
+
+struct Run {
+    output: Output,
+    elapsed: Duration,
+}
+
+fn run_command(args: &[String]) -> Result<Run, String> {
+    let start = Instant::now();
+    let output = Command::new(&args[0])
+        .args(&args[1..])
+        .current_dir(repo_dir_path())
+        .output()
+        .map_err(|err| format!("failed to run {:?}: {err}", args[0]))?;
+    Ok(Run {
+        output,
+        elapsed: start.elapsed(),
+    })
+}
+
+fn check_output(
+    output: &Output,
+    expected: &str,
+    input_path: &Path,
+    answers_path: &Path,
+    memory_limit: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    let expected = ExpectedAnswers::parse(expected, answers_path.parent().unwrap())?;
+
+    let stdout = std::str::from_utf8(&output.stdout)?;
+
+    let (line, result) = stdout
+        .lines()
+        .filter_map(|l| Some((l, parse_result(l)?)))
+        .next_back()
+        .ok_or_else(|| format!("no JSON output, command exited with {}", output.status))?;
+    if let Some(error) = string_member(&result, "error") {
+        return Err(error.into());
+    }
+
+    for (part, expected) in [("part1", &expected.part1), ("part2", &expected.part2)] {
+        if expected.is_empty() {
+            continue;
+        }
+        let actual = string_member(&result, part)
+            .ok_or_else(|| format!("{part} missing from output {line:?}"))?;
+        expected
+            .check(&actual, input_path)
+            .map_err(|err| format!("{part} {err}"))?;
+    }
+
+    if let Some(limit) = memory_limit {
+        let peak: u64 = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .find_map(|l| {
+                l.strip_prefix(PEAK_HEAP_PREFIX)?
+                    .split_once(' ')?
+                    .0
+                    .parse()
+                    .ok()
+            })
+            .ok_or("no peak heap usage output, is the binary built with alloc-profile?")?;
+        if peak > limit {
+            return Err(
+                format!("peak heap usage {peak} bytes exceeds limit of {limit} bytes").into(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a memory limits file into the limit in bytes for each day.
+fn parse_memory_limits(contents: &str) -> Result<HashMap<(Year, Day), u64>, Box<dyn Error>> {
+    let mut limits = HashMap::new();
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("invalid memory limit line {line:?}");
+        let (date, limit) = line.split_once(':').ok_or_else(invalid)?;
+        let (year, day) = date.trim().split_once(' ').ok_or_else(invalid)?;
+        let limit = limit.trim();
+        let (number, multiplier) = [("GiB", 1 << 30), ("MiB", 1 << 20), ("KiB", 1 << 10)]
+            .into_iter()
+            .find_map(|(suffix, multiplier)| Some((limit.strip_suffix(suffix)?, multiplier)))
+            .unwrap_or((limit, 1));
+        let bytes = number
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(multiplier))
+            .ok_or_else(invalid)?;
+
+        if limits
+            .insert((year.parse()?, day.parse()?), bytes)
+            .is_some()
+        {
+            return Err(format!("duplicate memory limit for {date:?}").into());
+        }
+    }
+    Ok(limits)
+}
+
+/// Save the details of a failing case to the provided directory.
+fn save_artifacts(
+    dir: &Path,
+    args: &[String],
+    input_path: &Path,
+    run: Option<&Run>,
+    error: &dyn Error,
+) -> Result<(), Box<dyn Error>> {
+    create_dir_all(dir)?;
+    copy(input_path, dir.join("input.txt"))?;
+
+    let mut summary = String::new();
+    writeln!(summary, "command: {args:?}")?;
+    writeln!(summary, "error: {error}")?;
+    if let Some(run) = run {
+        writeln!(summary, "status: {}", run.output.status)?;
+        writeln!(summary, "elapsed: {:?}", run.elapsed)?;
+        write(dir.join("stdout.txt"), &run.output.stdout)?;
+        write(dir.join("stderr.txt"), &run.output.stderr)?;
+    }
+    write(dir.join("summary.txt"), summary)?;
+
+    Ok(())
+}
+
+/// Parse a line of `--format json` output, returning the members of puzzle result objects.
+///
+/// Results are told apart from the final line containing the environment by the `year` key, so the
+/// order of fields doesn't matter.
+fn parse_result(line: &str) -> Option<Vec<(&str, JsonValue<'_>)>> {
+    match parser::json().parse_complete(line) {
+        Ok(JsonValue::Object(members)) if members.iter().any(|&(key, _)| key == "year") => {
+            Some(members)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the decoded value of a string member.
+fn string_member(members: &[(&str, JsonValue)], key: &str) -> Option<String> {
+    members.iter().find_map(|(k, v)| match v {
+        JsonValue::String(raw) if *k == key => unescape(raw),
+        _ => None,
+    })
+}
+
+/// Decode the escape sequences in a string returned by the JSON parser.
+fn unescape(raw: &str) -> Option<String> {
+    let mut result = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        result.push(match chars.next()? {
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+            }
+            c => c,
+        });
+    }
+    Some(result)
+}
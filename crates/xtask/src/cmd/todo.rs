@@ -0,0 +1,56 @@
+use crate::cmd::update::{find_days, find_years};
+use crate::common::{crate_dir_path, day_mod_name, repo_dir_path, year_create_name};
+use std::error::Error;
+use std::fs::read_to_string;
+
+/// List `TODO` comments left in solutions.
+///
+/// Usage: `todo [$year [$day]]`
+///
+/// Prints each comment line containing `TODO` in the day files of each year crate, including the
+/// markers generated by the `new` command's template.
+pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut args = args.peekable();
+    let year = if args.peek().is_some() {
+        Some(crate::year_arg(&mut args)?)
+    } else {
+        None
+    };
+    let day = if args.peek().is_some() {
+        Some(crate::day_arg(&mut args)?)
+    } else {
+        None
+    };
+    crate::ensure_no_args(args)?;
+
+    let mut count = 0;
+    let crates_dir = crate_dir_path();
+    for y in find_years(&crates_dir)? {
+        if year.is_some_and(|year| year != y) {
+            continue;
+        }
+
+        let src_dir = crates_dir.join(year_create_name(y)).join("src");
+        for (d, _) in find_days(&src_dir)? {
+            if day.is_some_and(|day| day != d) {
+                continue;
+            }
+
+            let path = src_dir.join(day_mod_name(d)).with_extension("rs");
+            let display = path
+                .strip_prefix(repo_dir_path())
+                .unwrap_or(&path)
+                .display();
+            for (i, line) in read_to_string(&path)?.lines().enumerate() {
+                let line = line.trim_start();
+                if line.starts_with("//") && line.contains("TODO") {
+                    println!("{display}:{}: {line}", i + 1);
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    println!("{count} TODOs");
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use crate::cmd::update::{find_days, find_years};
+use crate::common::{crate_dir_path, day_mod_name, repo_dir_path, run_cargo, year_create_name};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use utils::date::{Day, Year};
+use utils::number::median;
+
+/// Number of times each solution is run with each binary, reporting the median time.
+const RUNS: usize = 5;
+
+#[derive(Default)]
+struct Measurement {
+    time: Duration,
+    answers: String,
+    versions: Vec<(String, String)>,
+}
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let year_filter = args.next().map(|y| y.parse::<Year>()).transpose()?;
+    let day_filter = args.next().map(|d| d.parse::<Day>()).transpose()?;
+    crate::ensure_no_args(args)?;
+
+    let target_dir = repo_dir_path().join("target").join("unsafe-audit");
+    let unsafe_bin = build(&target_dir.join("unsafe"), &[])?;
+    let safe_bin = build(
+        &target_dir.join("safe"),
+        &["--no-default-features", "--features", "all-years"],
+    )?;
+
+    println!();
+    println!(
+        "Puzzle  │ Safe versions        │ Unsafe versions      │ Safe time  │ Unsafe time │ Delta  "
+    );
+    println!(
+        "────────┼──────────────────────┼──────────────────────┼────────────┼─────────────┼────────"
+    );
+
+    let crates_dir = crate_dir_path();
+    let mut modules: BTreeMap<String, (Duration, Duration, usize)> = BTreeMap::new();
+    for year in find_years(&crates_dir)? {
+        if year_filter.is_some_and(|y| y != year) {
+            continue;
+        }
+
+        let src_dir = crates_dir.join(year_create_name(year)).join("src");
+        for (day, _) in find_days(&src_dir)? {
+            if day_filter.is_some_and(|d| d != day) {
+                continue;
+            }
+
+            let input_path = repo_dir_path()
+                .join("inputs")
+                .join(year_create_name(year))
+                .join(day_mod_name(day))
+                .with_extension("txt");
+            if !input_path.is_file() {
+                continue;
+            }
+
+            let safe = measure(&safe_bin, year, day)?;
+            let unsafe_ = measure(&unsafe_bin, year, day)?;
+            if safe.answers != unsafe_.answers {
+                return Err(format!("{year:#} {day:#}: answers differ between builds").into());
+            }
+
+            println!(
+                "{year:#} {day:#} │ {:<20} │ {:<20} │ {:>10} │ {:>11} │ {:>6}",
+                format_versions(&safe.versions),
+                format_versions(&unsafe_.versions),
+                format_duration(safe.time),
+                format_duration(unsafe_.time),
+                format_delta(safe.time, unsafe_.time),
+            );
+
+            for (module, _) in &unsafe_.versions {
+                let entry = modules.entry(module.clone()).or_default();
+                entry.0 += safe.time;
+                entry.1 += unsafe_.time;
+                entry.2 += 1;
+            }
+        }
+    }
+
+    if !modules.is_empty() {
+        println!();
+        println!("Totals for solutions using each multiversioned module:");
+        for (module, (safe, unsafe_, count)) in modules {
+            println!(
+                "  {module}: {count} solutions, {} safe, {} unsafe ({})",
+                format_duration(safe).trim(),
+                format_duration(unsafe_).trim(),
+                format_delta(safe, unsafe_),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn build(target_dir: &Path, extra_args: &[&str]) -> Result<PathBuf, Box<dyn Error>> {
+    let target_dir_str = target_dir.to_string_lossy();
+    let mut args = vec![
+        "build",
+        "--release",
+        "--package",
+        "aoc",
+        "--bin",
+        "aoc",
+        "--target-dir",
+        &target_dir_str,
+    ];
+    args.extend_from_slice(extra_args);
+    run_cargo(&args, &[])?;
+
+    Ok(target_dir
+        .join("release")
+        .join("aoc")
+        .with_extension(std::env::consts::EXE_EXTENSION))
+}
+
+fn measure(binary: &Path, year: Year, day: Day) -> Result<Measurement, Box<dyn Error>> {
+    let mut result = Measurement::default();
+    let mut times = Vec::with_capacity(RUNS);
+    for _ in 0..RUNS {
+        let output = Command::new(binary)
+            .args(["--verbose", &format!("{year:#}"), &format!("{day:#}")])
+            .current_dir(repo_dir_path())
+            .output()?;
+        if !output.status.success() {
+            return Err(format!(
+                "{year:#} {day:#}: {} exited with {}",
+                binary.to_string_lossy(),
+                output.status
+            )
+            .into());
+        }
+
+        let stdout = String::from_utf8(output.stdout)?;
+        let stderr = String::from_utf8(output.stderr)?;
+
+        let prefix = format!("{year:#} {day:#} │");
+        let row = stdout
+            .lines()
+            .find(|l| l.starts_with(&prefix))
+            .ok_or_else(|| format!("{year:#} {day:#}: result row not found"))?;
+        let (answers, time) = row
+            .rsplit_once('│')
+            .ok_or_else(|| format!("{year:#} {day:#}: invalid result row"))?;
+        let time = parse_duration(time.trim())
+            .ok_or_else(|| format!("{year:#} {day:#}: invalid time {time:?}"))?;
+
+        let versions = stderr
+            .lines()
+            .filter_map(|l| l.split_once(": multiversion ")?.1.split_once(" selected "))
+            .map(|(module, version)| (module.to_string(), version.to_string()))
+            .collect();
+
+        times.push(time);
+        result.answers = answers.to_string();
+        result.versions = versions;
+    }
+    result.time = median(&mut times).unwrap_or_default();
+    Ok(result)
+}
+
+fn parse_duration(s: &str) -> Option<Duration> {
+    let (value, unit) = s.split_once(' ')?;
+    let value: f64 = value.trim().parse().ok()?;
+    let multiplier = match unit {
+        "µs" => 1e-6,
+        "ms" => 1e-3,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(value * multiplier))
+}
+
+fn format_versions(versions: &[(String, String)]) -> String {
+    if versions.is_empty() {
+        return "-".to_string();
+    }
+    versions
+        .iter()
+        .map(|(_, version)| version.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:7.3} ms", d.as_secs_f64() * 1000.)
+}
+
+fn format_delta(safe: Duration, unsafe_: Duration) -> String {
+    if safe.is_zero() {
+        return "-".to_string();
+    }
+    let delta = (unsafe_.as_secs_f64() - safe.as_secs_f64()) / safe.as_secs_f64();
+    format!("{:+.1}%", delta * 100.)
+}
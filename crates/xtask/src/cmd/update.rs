@@ -128,7 +128,7 @@ pub mod {crate_name} {{
     Ok(())
 }
 
-fn find_years(crates_dir: &Path) -> Result<Vec<Year>, Box<dyn Error>> {
+pub(crate) fn find_years(crates_dir: &Path) -> Result<Vec<Year>, Box<dyn Error>> {
     let mut years = Vec::new();
     for entry in read_dir(crates_dir)? {
         if let Some(year_num) = entry?
@@ -144,7 +144,7 @@ fn find_years(crates_dir: &Path) -> Result<Vec<Year>, Box<dyn Error>> {
     Ok(years)
 }
 
-fn find_days(src_dir: &Path) -> Result<Vec<(Day, bool)>, Box<dyn Error>> {
+pub(crate) fn find_days(src_dir: &Path) -> Result<Vec<(Day, bool)>, Box<dyn Error>> {
     let mut days = Vec::new();
     for entry in read_dir(src_dir)? {
         let path = entry?.path();
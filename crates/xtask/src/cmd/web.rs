@@ -32,12 +32,13 @@ pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
         }),
     )];
 
-    for (env, extra_args, name) in [
-        (&[][..], &[][..], "aoc.wasm"),
+    for (env, extra_args, name, features) in [
+        (&[][..], &[][..], "aoc.wasm", &["answer-images"][..]),
         (
             &[("RUSTFLAGS", "-C target_feature=+simd128")],
             &[],
             "aoc-simd128.wasm",
+            &["simd128", "answer-images"],
         ),
         // Experimental wasm threads support. This relies on a number of unstable and/or
         // undocumented features, including `target_feature=+`atomics`, `-Z build-std` and
@@ -58,6 +59,7 @@ pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
                 "build-std=panic_abort,std",
             ],
             "aoc-threads.wasm",
+            &["simd128", "multithreading", "answer-images"],
         ),
     ] {
         let mut args = vec![
@@ -82,6 +84,14 @@ pub fn main(args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
             &output_wasm,
         )?;
 
+        let wasm = fs::read(&output_wasm)?;
+        let built = wasm_features(&wasm)?;
+        if built != features {
+            return Err(
+                format!("{name} was built with features {built:?}, expected {features:?}").into(),
+            );
+        }
+
         add_rewrite(&mut rewrites, &output_wasm)?;
     }
 
@@ -132,6 +142,47 @@ fn add_rewrite(rewrites: &mut Vec<(String, String)>, path: &Path) -> Result<(),
     Ok(())
 }
 
+/// Read the enabled features from the `aoc_features` custom section of a wasm module.
+fn wasm_features(wasm: &[u8]) -> Result<Vec<&str>, Box<dyn Error>> {
+    let mut bytes = wasm
+        .strip_prefix(b"\0asm\x01\0\0\0")
+        .ok_or("invalid wasm module header")?;
+
+    while let Some((&id, rest)) = bytes.split_first() {
+        bytes = rest;
+        let size = read_leb128(&mut bytes)?;
+        if size > bytes.len() {
+            return Err("truncated wasm section".into());
+        }
+        let (mut section, rest) = bytes.split_at(size);
+        bytes = rest;
+
+        // Custom sections have ID 0 and start with their name
+        if id == 0 {
+            let name_len = read_leb128(&mut section)?;
+            if section.get(..name_len) == Some(b"aoc_features") {
+                let features = str::from_utf8(&section[name_len..])?;
+                return Ok(features.split(',').filter(|f| !f.is_empty()).collect());
+            }
+        }
+    }
+
+    Err("missing aoc_features custom section".into())
+}
+
+fn read_leb128(bytes: &mut &[u8]) -> Result<usize, Box<dyn Error>> {
+    let mut result = 0;
+    for shift in (0..32).step_by(7) {
+        let (&byte, rest) = bytes.split_first().ok_or("unexpected end of wasm module")?;
+        *bytes = rest;
+        result |= usize::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err("invalid LEB128 integer".into())
+}
+
 fn get_commit_commit() -> Result<String, Box<dyn Error>> {
     let hash = {
         let output = Command::new("git").args(["rev-parse", "HEAD"]).output()?;
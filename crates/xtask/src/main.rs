@@ -9,8 +9,15 @@ fn main() {
     let mut args = env::args().skip(1);
     let subcommand = args.next().expect("expected subcommand");
     if let Err(e) = match subcommand.as_str() {
+        "check-days" => cmd::check_days::main(args),
+        "corpus" => cmd::corpus::main(args),
         "input" => cmd::input::main(args),
         "new" => cmd::new::main(args),
+        "reduce" => cmd::reduce::main(args),
+        "size-report" => cmd::size_report::main(args),
+        "test" => cmd::test::main(args),
+        "todo" => cmd::todo::main(args),
+        "unsafe-audit" => cmd::unsafe_audit::main(args),
         "update" => cmd::update::main(args),
         "wait" => cmd::wait::main(args),
         "web" => cmd::web::main(args),
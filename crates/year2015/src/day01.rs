@@ -52,4 +52,5 @@ examples!(Day01 -> (i32, usize) [
     {input: ")())())", part1: -3},
     {input: ")", part2: 1},
     {input: "()())", part2: 5},
+    {input: "(()x", should_error: "expected bracket"},
 ]);
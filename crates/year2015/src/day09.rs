@@ -9,8 +9,9 @@ use utils::prelude::*;
 /// ending at different locations is then the shortest/longest loop minus the longest/shortest edge.
 #[derive(Clone, Debug)]
 pub struct Day09 {
-    part1: u32,
-    part2: u32,
+    locations: usize,
+    matrix: Vec<u32>,
+    routes: Lazy<(u32, u32)>,
 }
 
 type Visited = u32;
@@ -44,32 +45,42 @@ impl Day09 {
             matrix[indexes.len() * end + start] = dist;
         });
 
-        let (mut part1, mut part2) = (u32::MAX, 0);
-        explore_hamiltonian_paths(
-            indexes.len() as u32,
-            0,
-            (0, u32::MAX, 0),
-            |a, b| matrix[a as usize * locations + b as usize],
-            |(total, min_edge, max_edge), edge| {
-                (total + edge, min_edge.min(edge), max_edge.max(edge))
-            },
-            |(total, min_edge, max_edge), loop_edge| {
-                part1 = part1.min(total + loop_edge - max_edge.max(loop_edge));
-                part2 = part2.max(total + loop_edge - min_edge.min(loop_edge))
-            },
-        );
+        Ok(Self {
+            locations,
+            matrix,
+            routes: Lazy::new(),
+        })
+    }
 
-        Ok(Self { part1, part2 })
+    lazy! {
+        /// Shortest and longest route, computed together as both require exploring every path.
+        fn routes(&self) -> (u32, u32) {
+            let (mut shortest, mut longest) = (u32::MAX, 0);
+            explore_hamiltonian_paths(
+                self.locations as u32,
+                0,
+                (0, u32::MAX, 0),
+                |a, b| self.matrix[a as usize * self.locations + b as usize],
+                |(total, min_edge, max_edge), edge| {
+                    (total + edge, min_edge.min(edge), max_edge.max(edge))
+                },
+                |(total, min_edge, max_edge), loop_edge| {
+                    shortest = shortest.min(total + loop_edge - max_edge.max(loop_edge));
+                    longest = longest.max(total + loop_edge - min_edge.min(loop_edge))
+                },
+            );
+            (shortest, longest)
+        }
     }
 
     #[must_use]
     pub fn part1(&self) -> u32 {
-        self.part1
+        self.routes().0
     }
 
     #[must_use]
     pub fn part2(&self) -> u32 {
-        self.part2
+        self.routes().1
     }
 }
 
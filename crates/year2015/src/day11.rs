@@ -13,6 +13,7 @@ impl Day11 {
             return Err(InputError::new(input, 0, "expected 8 lowercase letters"));
         }
 
+        // check-days: allow(unwrap-in-new)
         let input: [u8; 8] = input.as_bytes().try_into().unwrap();
         let part1 = Self::next_password(input);
         let part2 = Self::next_password(part1);
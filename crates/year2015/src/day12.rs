@@ -1,102 +1,53 @@
-use utils::parser::{ParseError, ParseResult};
+use utils::parser::JsonValue;
 use utils::prelude::*;
 
 /// JSON document numbers.
 #[derive(Clone, Debug)]
 pub struct Day12 {
-    part1: i32,
-    part2: i32,
+    part1: i64,
+    part2: i64,
 }
 
 impl Day12 {
     pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
-        let (part1, part2) = Self::parse.parse_complete(input)?;
-        Ok(Self { part1, part2 })
+        let value = parser::json().parse_complete(input)?;
+        Ok(Self {
+            part1: Self::sum(&value, false),
+            part2: Self::sum(&value, true),
+        })
+    }
+
+    fn sum(value: &JsonValue, ignore_red: bool) -> i64 {
+        match value {
+            JsonValue::Number(n) => *n,
+            JsonValue::Array(values) => values.iter().map(|v| Self::sum(v, ignore_red)).sum(),
+            JsonValue::Object(members) => {
+                if ignore_red
+                    && members
+                        .iter()
+                        .any(|(_, v)| matches!(v, JsonValue::String("red")))
+                {
+                    0
+                } else {
+                    members.iter().map(|(_, v)| Self::sum(v, ignore_red)).sum()
+                }
+            }
+            _ => 0,
+        }
     }
 
     #[must_use]
-    pub fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i64 {
         self.part1
     }
 
     #[must_use]
-    pub fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i64 {
         self.part2
     }
-
-    fn parse(input: &[u8]) -> ParseResult<(i32, i32)> {
-        match input {
-            [b'{', ..] => Self::parse_object(&input[1..]),
-            [b'[', ..] => Self::parse_array(&input[1..]),
-            _ => Err((ParseError::Expected("'{' or '['"), input)),
-        }
-    }
-
-    fn parse_object(mut input: &[u8]) -> ParseResult<(i32, i32)> {
-        let (mut part1, mut part2) = (0, 0);
-        let mut red = false;
-        loop {
-            match input {
-                [b'1'..=b'9', ..] | [b'-', b'1'..=b'9', ..] => {
-                    let (num, remaining) = parser::i32().parse(input)?;
-                    input = remaining;
-                    part1 += num;
-                    part2 += num;
-                }
-                [b'"', b'r', b'e', b'd', b'"', ..] => {
-                    input = &input[5..];
-                    red = true;
-                }
-                [b'{', ..] => {
-                    let (result, remaining) = Self::parse_object(&input[1..])?;
-                    input = remaining;
-                    part1 += result.0;
-                    part2 += result.1;
-                }
-                [b'[', ..] => {
-                    let (result, remaining) = Self::parse_array(&input[1..])?;
-                    input = remaining;
-                    part1 += result.0;
-                    part2 += result.1;
-                }
-                [b'}', ..] => return Ok(((part1, if red { 0 } else { part2 }), &input[1..])),
-                [] => return Err((ParseError::ExpectedByte(b']'), input)),
-                _ => input = &input[1..],
-            }
-        }
-    }
-
-    fn parse_array(mut input: &[u8]) -> ParseResult<(i32, i32)> {
-        let (mut part1, mut part2) = (0, 0);
-        loop {
-            match input {
-                [b'1'..=b'9', ..] | [b'-', b'1'..=b'9', ..] => {
-                    let (num, remaining) = parser::i32().parse(input)?;
-                    input = remaining;
-                    part1 += num;
-                    part2 += num;
-                }
-                [b'{', ..] => {
-                    let (result, remaining) = Self::parse_object(&input[1..])?;
-                    input = remaining;
-                    part1 += result.0;
-                    part2 += result.1;
-                }
-                [b'[', ..] => {
-                    let (result, remaining) = Self::parse_array(&input[1..])?;
-                    input = remaining;
-                    part1 += result.0;
-                    part2 += result.1;
-                }
-                [b']', ..] => return Ok(((part1, part2), &input[1..])),
-                [] => return Err((ParseError::ExpectedByte(b']'), input)),
-                _ => input = &input[1..],
-            }
-        }
-    }
 }
 
-examples!(Day12 -> (i32, i32) [
+examples!(Day12 -> (i64, i64) [
     {input: r#"[1,2,3]"#, part1: 6, part2: 6},
     {input: r#"{"a":2,"b":4}"#, part1: 6},
     {input: r#"[[[3]]]"#, part1: 3},
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use utils::array::Array2;
 use utils::graph::explore_hamiltonian_paths;
 use utils::prelude::*;
 
@@ -39,9 +40,9 @@ impl Day13 {
         }
 
         let people = indexes.len();
-        let mut matrix = vec![0; people * people];
+        let mut matrix = Array2::new([people, people], 0);
         parsed.iter().for_each(|&(person1, change, person2)| {
-            matrix[indexes[person1] * people + indexes[person2]] = change;
+            matrix[[indexes[person1], indexes[person2]]] = change;
         });
 
         let (mut part1, mut part2) = (i32::MIN, i32::MIN);
@@ -49,9 +50,7 @@ impl Day13 {
             people as u32,
             0,
             (0, i32::MAX),
-            |a, b| {
-                matrix[a as usize * people + b as usize] + matrix[b as usize * people + a as usize]
-            },
+            |a, b| matrix[[a as usize, b as usize]] + matrix[[b as usize, a as usize]],
             |(total, min_edge), edge| (total + edge, min_edge.min(edge)),
             |(total, min_edge), loop_edge| {
                 part1 = part1.max(total + loop_edge);
@@ -1,24 +1,23 @@
-use utils::grid::from_str_padded;
+use utils::grid::PaddedGrid;
 use utils::prelude::*;
 
 /// Game of Life.
 #[derive(Clone, Debug)]
 pub struct Day18 {
-    data: Vec<bool>,
-    size: usize,
+    grid: PaddedGrid<bool>,
     part1_steps: u32,
     part2_steps: u32,
 }
 
 impl Day18 {
     pub fn new(input: &str, input_type: InputType) -> Result<Self, InputError> {
-        let (rows, columns, data) = from_str_padded(input, 1, false, |c| match c {
+        let grid = PaddedGrid::from_str(input, 1, false, |c| match c {
             b'#' => Some(true),
             b'.' => Some(false),
             _ => None,
         })?;
 
-        if rows != columns {
+        if grid.rows() != grid.cols() {
             return Err(InputError::new(input, input, "expected square grid"));
         }
 
@@ -28,8 +27,7 @@ impl Day18 {
         };
 
         Ok(Self {
-            size: rows,
-            data,
+            grid,
             part1_steps,
             part2_steps,
         })
@@ -42,10 +40,11 @@ impl Day18 {
 
     #[must_use]
     pub fn part2(&self) -> u32 {
-        let top_left = self.size + 1;
-        let top_right = self.size + (self.size - 2);
-        let bottom_left = (self.size - 2) * self.size + 1;
-        let bottom_right = (self.size - 2) * self.size + (self.size - 2);
+        let last = self.grid.rows() as isize - 1;
+        let top_left = self.grid.index(0, 0);
+        let top_right = self.grid.index(0, last);
+        let bottom_left = self.grid.index(last, 0);
+        let bottom_right = self.grid.index(last, last);
 
         self.count_lights(self.part2_steps, |grid| {
             grid[top_left] = true;
@@ -56,7 +55,7 @@ impl Day18 {
     }
 
     fn count_lights(&self, steps: u32, callback: impl Fn(&mut [bool])) -> u32 {
-        let mut grid = self.data.clone();
+        let mut grid = self.grid.data().to_vec();
         let mut grid2 = vec![false; grid.len()];
 
         callback(&mut grid);
@@ -71,14 +70,16 @@ impl Day18 {
     }
 
     fn advance(&self, input: &[bool], output: &mut [bool]) {
+        let size = self.grid.padded_cols();
+
         // Avoids bounds checks, allowing the inner loop to be vectorized
         for (((above, row), below), out) in input
-            .chunks_exact(self.size)
-            .zip(input.chunks_exact(self.size).skip(1))
-            .zip(input.chunks_exact(self.size).skip(2))
-            .zip(output.chunks_exact_mut(self.size).skip(1))
+            .chunks_exact(size)
+            .zip(input.chunks_exact(size).skip(1))
+            .zip(input.chunks_exact(size).skip(2))
+            .zip(output.chunks_exact_mut(size).skip(1))
         {
-            for i in 1..self.size - 1 {
+            for i in 1..size - 1 {
                 let neighbours = u8::from(above[i - 1])
                     + u8::from(above[i])
                     + u8::from(above[i + 1])
@@ -33,7 +33,7 @@ impl<'a> Day04<'a> {
                     .enumerate()
                     .rev()
                     .max_by_key(|&(_, &c)| c)
-                    .unwrap();
+                    .unwrap(); // check-days: allow(unwrap-in-new)
 
                 if c != b'a' + letter as u8 {
                     return false;
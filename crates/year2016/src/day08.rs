@@ -1,3 +1,4 @@
+use utils::ocr::Letters;
 use utils::prelude::*;
 
 /// Converting pixels to text.
@@ -61,63 +62,8 @@ impl Day08 {
     }
 
     #[must_use]
-    pub fn part2(&self) -> String {
-        let mut output = String::with_capacity(10);
-
-        for i in (0..50).step_by(5) {
-            let mut letter = 0;
-            for row in self.grid {
-                for &b in &row[i..i + 5] {
-                    letter <<= 1;
-                    if b {
-                        letter |= 1;
-                    }
-                }
-            }
-            output.push(Self::ocr(letter));
-        }
-
-        output
-    }
-
-    fn ocr(letter: u32) -> char {
-        //  ##  ###   ##  #### ####  ##  #  #  ###   ## #  # #     ##  ###  ###   ### #  # #   #####
-        // #  # #  # #  # #    #    #  # #  #   #     # # #  #    #  # #  # #  # #    #  # #   #   #
-        // #  # ###  #    ###  ###  #    ####   #     # ##   #    #  # #  # #  # #    #  #  # #   #
-        // #### #  # #    #    #    # ## #  #   #     # # #  #    #  # ###  ###   ##  #  #   #   #
-        // #  # #  # #  # #    #    #  # #  #   #  #  # # #  #    #  # #    # #     # #  #   #  #
-        // #  # ###   ##  #### #     ### #  #  ###  ##  #  # ####  ##  #    #  # ###   ##    #  ####
-        match letter {
-            //111112222233333444445555566666
-            0b011001001010010111101001010010 => 'A',
-            0b111001001011100100101001011100 => 'B',
-            0b011001001010000100001001001100 => 'C',
-            0b111101000011100100001000011110 => 'E',
-            0b111101000011100100001000010000 => 'F',
-            0b011001001010000101101001001110 => 'G',
-            0b100101001011110100101001010010 => 'H',
-            0b011100010000100001000010001110 => 'I',
-            0b001100001000010000101001001100 => 'J',
-            0b100101010011000101001010010010 => 'K',
-            0b100001000010000100001000011110 => 'L',
-            0b011001001010010100101001001100 => 'O',
-            0b111001001010010111001000010000 => 'P',
-            0b111001001010010111001010010010 => 'R',
-            0b011101000010000011000001011100 => 'S',
-            0b100101001010010100101001001100 => 'U',
-            0b100011000101010001000010000100 => 'Y',
-            0b111100001000100010001000011110 => 'Z',
-            _ => {
-                let mut display = String::new();
-                for b in (0..30).rev() {
-                    display.push(if letter & (1 << b) == 0 { ' ' } else { '#' });
-                    if b % 5 == 0 {
-                        display.push('\n');
-                    }
-                }
-                panic!("unknown letter {letter:#032b}:\n{display}");
-            }
-        }
+    pub fn part2(&self) -> Letters {
+        Letters::new(50, 6, self.grid.as_flattened().iter().copied())
     }
 }
 
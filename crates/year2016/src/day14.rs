@@ -36,7 +36,7 @@ impl<'a> Day14<'a> {
         let mutex = Mutex::new((BTreeSet::new(), BTreeMap::new(), BTreeMap::new()));
 
         md5::find_hash_with_appended_count(self.prefix, additional, |i, [a, b, c, d]: [u32; 4]| {
-            // Spread each nibble into bytes like utils::md5::u32_to_hex, but without the
+            // Spread each nibble into bytes like utils::md5::to_hex, but without the
             // unnecessary mapping to ASCII hex
             let mut nibble_bytes = [0u8; 32];
             nibble_bytes[0..8].copy_from_slice(&spread_nibbles(a).to_be_bytes());
@@ -49,8 +49,12 @@ impl Day22 {
             .with_suffix("%")
             .parse_lines(input.trim_ascii_start())?;
 
-        let max_x = nodes.iter().map(|n| n.x).max().unwrap();
-        let max_y = nodes.iter().map(|n| n.y).max().unwrap();
+        let (Some(max_x), Some(max_y)) = (
+            nodes.iter().map(|n| n.x).max(),
+            nodes.iter().map(|n| n.y).max(),
+        ) else {
+            return Err(InputError::new(input, 0, "expected at least one node"));
+        };
         if ((max_x + 1) * (max_y + 1)) as usize != nodes.len() {
             return Err(InputError::new(input, 0, "expected rectangular grid"));
         }
@@ -82,7 +86,7 @@ impl Day22 {
                 || non_empty.windows(2).any(|w| w[0].x + 1 != w[1].x)
                 || non_empty[0].x == 0
                 || non_empty[0].y < 2
-                || non_empty.last().unwrap().x != max_x
+                || non_empty.last().unwrap().x != max_x // check-days: allow(unwrap-in-new)
             {
                 return Err(InputError::new(
                     input,
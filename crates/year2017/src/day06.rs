@@ -16,6 +16,9 @@ impl Day06 {
             .with_suffix(parser::one_of((b' ', b'\t', parser::eof())))
             .parse_all(input)?;
 
+        explain!("Initial banks: {banks:?}");
+
+        let section = explain::section(format_args!("Finding the cycle length (lambda):"));
         let (mut power, mut lambda) = (1, 1);
         let mut tortoise = banks.clone();
         let mut hare = banks.clone();
@@ -26,23 +29,34 @@ impl Day06 {
                 tortoise.copy_from_slice(hare.as_slice());
                 power *= 2;
                 lambda = 0;
+                explain!("Tortoise teleports to {tortoise:?}, next teleport after {power} steps");
             }
             Self::next(&mut hare);
             lambda += 1;
+            explain!("Hare moves to {hare:?}");
         }
+        explain!("Hare reached the tortoise after {lambda} steps, so lambda = {lambda}");
+        drop(section);
 
+        let section = explain::section(format_args!("Finding the cycle start (mu):"));
         tortoise.copy_from_slice(banks.as_slice());
         hare.copy_from_slice(banks.as_slice());
         for _ in 0..lambda {
             Self::next(&mut hare);
         }
+        explain!("Hare starts {lambda} steps ahead at {hare:?}");
 
         let mut mu = 0;
         while tortoise != hare {
             Self::next(&mut tortoise);
             Self::next(&mut hare);
             mu += 1;
+            explain!("Step {mu}: tortoise {tortoise:?}, hare {hare:?}");
         }
+        explain!("Both reached the first repeated state after {mu} steps, so mu = {mu}");
+        drop(section);
+
+        explain!("Part 1 is mu + lambda = {}, part 2 is lambda = {lambda}", mu + lambda);
 
         Ok(Self {
             part1: mu + lambda,
@@ -55,6 +55,7 @@ impl<'a> Day07<'a> {
                         Err(InputError::new(
                             input,
                             0,
+                            // check-days: allow(unwrap-in-new)
                             format!("program {:?} missing on LHS", str::from_utf8(name).unwrap()),
                         ))
                     }
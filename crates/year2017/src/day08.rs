@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use utils::collections::DeterministicHashMap;
 use utils::prelude::*;
 
 /// Evaluating conditional add instructions.
@@ -35,7 +35,7 @@ impl Day08 {
             .with_suffix(parser::eol())
             .parse_iterator(input);
 
-        let mut registers = HashMap::new();
+        let mut registers = DeterministicHashMap::default();
         let mut max = 0;
         for item in parse_iterator {
             let (reg, value, cond_reg, comparison, cond_value) = item?;
@@ -1,4 +1,4 @@
-use crate::knot_hash::{knot_hash_hex, knot_rounds};
+use utils::knot_hash;
 use utils::prelude::*;
 
 /// Implementing a custom hash function.
@@ -20,14 +20,14 @@ impl<'a> Day10<'a> {
             .parse_all(self.input)
             .expect("input invalid for part 1");
 
-        let list = knot_rounds(lengths.iter().copied(), 1);
+        let list = knot_hash::rounds(&lengths, 1);
 
         list[0] as u32 * list[1] as u32
     }
 
     #[must_use]
     pub fn part2(&self) -> String {
-        let hex = knot_hash_hex(self.input.bytes());
+        let hex = knot_hash::to_hex(knot_hash::hash(self.input.as_bytes()));
 
         String::from_utf8(hex.to_vec()).unwrap()
     }
@@ -1,4 +1,4 @@
-use utils::array::ArrayVec;
+use utils::graph::{self, CsrGraph};
 use utils::prelude::*;
 
 /// Finding connected components in a graph.
@@ -11,15 +11,37 @@ pub struct Day12 {
 impl Day12 {
     pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
         let programs = parser::u32()
-            .repeat_arrayvec(", ", 1)
+            .repeat_arrayvec::<8, _>(", ", 1)
             .with_prefix(parser::take_while1(u8::is_ascii_digit).with_suffix(" <-> "))
             .parse_lines(input)?;
 
+        let graph = CsrGraph::from_edges(
+            programs.len(),
+            programs.iter().enumerate().flat_map(|(i, connected)| {
+                connected.iter().map(move |&c| (i, c as usize))
+            }),
+        );
+
+        graph::dump(|| {
+            // Each pipe is listed from both ends, so only include each edge once
+            let adjacency: Vec<Vec<usize>> = (0..graph.vertex_count())
+                .map(|i| {
+                    graph
+                        .neighbors(i)
+                        .iter()
+                        .map(|&c| c as usize)
+                        .filter(|&c| c >= i)
+                        .collect()
+                })
+                .collect();
+            graph::to_dot(&adjacency, &[] as &[u32])
+        });
+
         let mut groups = Vec::new();
-        let mut visited = vec![false; programs.len()];
-        for i in 0..programs.len() {
+        let mut visited = vec![false; graph.vertex_count()];
+        for i in 0..graph.vertex_count() {
             if !visited[i] {
-                groups.push(Self::connect(&programs, &mut visited, i));
+                groups.push(Self::connect(&graph, &mut visited, i));
             }
         }
 
@@ -29,13 +51,13 @@ impl Day12 {
         })
     }
 
-    fn connect(programs: &[ArrayVec<u32, 8>], visited: &mut [bool], program: usize) -> u32 {
+    fn connect(graph: &CsrGraph, visited: &mut [bool], program: usize) -> u32 {
         visited[program] = true;
 
         let mut group_len = 1;
-        for &connected in &programs[program] {
+        for &connected in graph.neighbors(program) {
             if !visited[connected as usize] {
-                group_len += Self::connect(programs, visited, connected as usize);
+                group_len += Self::connect(graph, visited, connected as usize);
             }
         }
 
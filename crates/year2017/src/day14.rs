@@ -1,5 +1,5 @@
-use crate::knot_hash::knot_hash;
 use utils::bit::BitIterator;
+use utils::knot_hash;
 use utils::prelude::*;
 
 /// Finding connected regions in a hash-derived grid.
@@ -14,26 +14,11 @@ pub struct Day14 {
 
 impl Day14 {
     pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
-        let mut grid = [0u128; 128];
-
-        let mut buf = Vec::with_capacity(input.len() + 4);
-        buf.extend_from_slice(input.as_bytes());
-        buf.push(b'-');
+        let rows: Vec<String> = (0..128).map(|i| format!("{input}-{i}")).collect();
+        let rows: Vec<&[u8]> = rows.iter().map(String::as_bytes).collect();
 
-        for (i, row) in grid.iter_mut().enumerate() {
-            buf.truncate(input.len() + 1);
-            if i < 10 {
-                buf.push(b'0' + (i as u8));
-            } else if i < 100 {
-                buf.push(b'0' + ((i / 10) as u8));
-                buf.push(b'0' + ((i % 10) as u8));
-            } else {
-                buf.push(b'0' + ((i / 100) as u8));
-                buf.push(b'0' + (((i / 10) % 10) as u8));
-                buf.push(b'0' + ((i % 10) as u8));
-            }
-
-            let hash = knot_hash(buf.iter().copied());
+        let mut grid = [0u128; 128];
+        for (row, hash) in grid.iter_mut().zip(knot_hash::hash_many(&rows)) {
             *row = u128::from_be_bytes(hash);
         }
 
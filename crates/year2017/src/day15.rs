@@ -2,8 +2,9 @@ use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Mutex;
 use utils::multithreading;
-use utils::number::mod_pow;
+use utils::number::{mod_pow, Barrett};
 use utils::prelude::*;
+use utils::PuzzleGenerator;
 
 /// Comparing numbers from two simple random number generators.
 ///
@@ -23,6 +24,7 @@ struct BatchResults {
 const FACTOR_A: u64 = 16807;
 const FACTOR_B: u64 = 48271;
 const MODULUS: u64 = 2147483647;
+const REDUCER: Barrett = Barrett::new(MODULUS);
 
 const PART1_PAIRS: u32 = 40_000_000;
 const PART2_PAIRS: u32 = 5_000_000;
@@ -53,7 +55,7 @@ impl Day15 {
         });
 
         Ok(Self {
-            batches: mutex.into_inner().unwrap(),
+            batches: mutex.into_inner().unwrap(), // check-days: allow(unwrap-in-new)
         })
     }
 
@@ -103,11 +105,11 @@ impl Day15 {
             let mut part2_a_values = Vec::with_capacity(if part2_a_finished { 0 } else { 65536 });
             let mut part2_b_values = Vec::with_capacity(if part2_b_finished { 0 } else { 32768 });
 
-            let mut a = start_a * mod_pow(FACTOR_A, start_index as u64, MODULUS);
-            let mut b = start_b * mod_pow(FACTOR_B, start_index as u64, MODULUS);
+            let mut a = REDUCER.mul_mod(start_a, mod_pow(FACTOR_A, start_index as u64, MODULUS));
+            let mut b = REDUCER.mul_mod(start_b, mod_pow(FACTOR_B, start_index as u64, MODULUS));
             for _ in 0..BATCH_SIZE {
-                a = (a * FACTOR_A) % MODULUS;
-                b = (b * FACTOR_B) % MODULUS;
+                a = REDUCER.mul_mod(a, FACTOR_A);
+                b = REDUCER.mul_mod(b, FACTOR_B);
 
                 if a as u16 == b as u16 {
                     part1_matches += 1;
@@ -136,6 +138,26 @@ impl Day15 {
     }
 }
 
+impl PuzzleGenerator for Day15 {
+    fn generate(seed: u64) -> String {
+        // SplitMix64, mapping each output to a valid non-zero generator state
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            (z ^ (z >> 31)) % (MODULUS - 1) + 1
+        };
+
+        format!(
+            "Generator A starts with {}\nGenerator B starts with {}",
+            next(),
+            next()
+        )
+    }
+}
+
 examples!(Day15 -> (u32, u32) [
     {input: "Generator A starts with 65\nGenerator B starts with 8921", part1: 588, part2: 309},
 ]);
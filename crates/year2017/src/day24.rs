@@ -0,0 +1,71 @@
+use utils::prelude::*;
+
+/// Building the strongest bridge from components.
+///
+/// Uses a depth-first search over the components, tracking the used components in a bitmask.
+/// Components with the same number of pins on both ports are always used as soon as possible, as
+/// they add strength and length without changing the port.
+#[derive(Clone, Debug)]
+pub struct Day24 {
+    strongest: u32,
+    longest: (u32, u32),
+}
+
+impl Day24 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let components = parser::u32()
+            .with_suffix(b'/')
+            .then(parser::u32())
+            .parse_lines(input)?;
+        if components.len() > 64 {
+            return Err(InputError::new(input, 0, "expected at most 64 components"));
+        }
+
+        let mut result = Self {
+            strongest: 0,
+            longest: (0, 0),
+        };
+        result.search(&components, 0, 0, 0, 0);
+        Ok(result)
+    }
+
+    fn search(&mut self, components: &[(u32, u32)], port: u32, used: u64, length: u32, strength: u32) {
+        self.strongest = self.strongest.max(strength);
+        self.longest = self.longest.max((length, strength));
+
+        if let Some(i) =
+            (0..components.len()).find(|&i| used & (1 << i) == 0 && components[i] == (port, port))
+        {
+            self.search(components, port, used | (1 << i), length + 1, strength + 2 * port);
+            return;
+        }
+
+        for (i, &(a, b)) in components.iter().enumerate() {
+            if used & (1 << i) != 0 || (a != port && b != port) {
+                continue;
+            }
+            let other = if a == port { b } else { a };
+            self.search(
+                components,
+                other,
+                used | (1 << i),
+                length + 1,
+                strength + a + b,
+            );
+        }
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.strongest
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.longest.1
+    }
+}
+
+examples!(Day24 -> (u32, u32) [
+    {input: "0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10", part1: 31, part2: 19},
+]);
@@ -0,0 +1,122 @@
+use utils::prelude::*;
+
+/// Simulating a Turing machine.
+#[derive(Clone, Debug)]
+pub struct Day25 {
+    start: usize,
+    steps: u32,
+    // (write, move right, next state) for each state and current value
+    rules: Vec<[(bool, bool, usize); 2]>,
+}
+
+impl Day25 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut blocks = input.trim_ascii_end().split("\n\n");
+        let state = parser::byte_range(b'A'..=b'Z').map(|b| (b - b'A') as usize);
+        let value = parser::literal_map!("0" => false, "1" => true);
+
+        let (start, steps) = state
+            .with_prefix("Begin in state ")
+            .with_suffix(".")
+            .with_suffix(parser::eol())
+            .then(
+                parser::u32()
+                    .with_prefix("Perform a diagnostic checksum after ")
+                    .with_suffix(" steps."),
+            )
+            .parse_complete(blocks.next().unwrap_or_default())?;
+
+        let branch = value
+            .with_prefix("  If the current value is ")
+            .with_suffix(":")
+            .with_suffix(parser::eol())
+            .then(
+                value
+                    .with_prefix("    - Write the value ")
+                    .with_suffix(".")
+                    .with_suffix(parser::eol()),
+            )
+            .then(
+                parser::literal_map!("right" => true, "left" => false)
+                    .with_prefix("    - Move one slot to the ")
+                    .with_suffix(".")
+                    .with_suffix(parser::eol()),
+            )
+            .then(
+                state
+                    .with_prefix("    - Continue with state ")
+                    .with_suffix("."),
+            );
+        let rule = state
+            .with_prefix("In state ")
+            .with_suffix(":")
+            .with_suffix(parser::eol())
+            .then(branch.with_suffix(parser::eol()))
+            .then(branch);
+
+        let mut rules = Vec::new();
+        for block in blocks {
+            let (index, (zero, write0, right0, next0), (one, write1, right1, next1)) =
+                rule.parse_complete(block)?;
+            if index != rules.len() {
+                return Err(InputError::new(input, block, "expected states in order"));
+            }
+            if zero || !one {
+                return Err(InputError::new(input, block, "expected rules for 0 then 1"));
+            }
+            rules.push([(write0, right0, next0), (write1, right1, next1)]);
+        }
+
+        if start >= rules.len()
+            || rules
+                .iter()
+                .flatten()
+                .any(|&(_, _, next)| next >= rules.len())
+        {
+            return Err(InputError::new(input, 0, "unknown state"));
+        }
+
+        Ok(Self {
+            start,
+            steps,
+            rules,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        let mut tape = vec![false; 1024];
+        let mut position = tape.len() / 2;
+        let mut state = self.start;
+
+        for _ in 0..self.steps {
+            let (write, right, next) = self.rules[state][usize::from(tape[position])];
+            tape[position] = write;
+            state = next;
+
+            if right {
+                position += 1;
+                if position == tape.len() {
+                    tape.resize(tape.len() * 2, false);
+                }
+            } else if position == 0 {
+                let len = tape.len();
+                tape.splice(0..0, std::iter::repeat_n(false, len));
+                position = len - 1;
+            } else {
+                position -= 1;
+            }
+        }
+
+        tape.iter().filter(|&&b| b).count()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> &'static str {
+        "🎄"
+    }
+}
+
+examples!(Day25 -> (usize, &'static str) [
+    {file: "day25_example0.txt", part1: 3},
+]);
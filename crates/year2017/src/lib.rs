@@ -1,7 +1,7 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "unsafe"), forbid(unsafe_code))]
 
-mod knot_hash;
+pub use utils::knot_hash;
 
 utils::year!(2017 => year2017, ${
     1 => day01::Day01<'_>,
@@ -27,4 +27,6 @@ utils::year!(2017 => year2017, ${
     21 => day21::Day21,
     22 => day22::Day22,
     23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
 });
@@ -0,0 +1,59 @@
+use utils::prelude::*;
+
+/// Finding entries which sum to 2020.
+#[derive(Clone, Debug)]
+pub struct Day01 {
+    entries: Vec<u32>,
+    present: Vec<bool>,
+}
+
+const TARGET: u32 = 2020;
+
+impl Day01 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut entries = parser::number_range(0..=TARGET).parse_lines(input)?;
+        entries.sort_unstable();
+
+        let mut present = vec![false; TARGET as usize + 1];
+        for &e in &entries {
+            present[e as usize] = true;
+        }
+
+        Ok(Self { entries, present })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.entries
+            .iter()
+            .enumerate()
+            .find(|&(i, &a)| {
+                let b = TARGET - a;
+                // Avoid using the same entry twice unless it is duplicated
+                self.present[b as usize] && (a != b || self.entries.get(i + 1) == Some(&b))
+            })
+            .map(|(_, &a)| a * (TARGET - a))
+            .expect("no solution found")
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        // Entries are sorted, so use two pointers for the remaining pair after each first entry
+        for (i, &a) in self.entries.iter().enumerate() {
+            let (mut lo, mut hi) = (i + 1, self.entries.len() - 1);
+            while lo < hi {
+                let sum = a + self.entries[lo] + self.entries[hi];
+                match sum.cmp(&TARGET) {
+                    std::cmp::Ordering::Less => lo += 1,
+                    std::cmp::Ordering::Greater => hi -= 1,
+                    std::cmp::Ordering::Equal => return a * self.entries[lo] * self.entries[hi],
+                }
+            }
+        }
+        panic!("no solution found");
+    }
+}
+
+examples!(Day01 -> (u32, u32) [
+    {input: "1721\n979\n366\n299\n675\n1456", part1: 514579, part2: 241861950},
+]);
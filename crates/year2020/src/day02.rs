@@ -0,0 +1,53 @@
+use utils::prelude::*;
+
+/// Validating passwords against policies.
+#[derive(Clone, Debug)]
+pub struct Day02<'a> {
+    passwords: Vec<((u32, u32), u8, &'a [u8])>,
+}
+
+impl<'a> Day02<'a> {
+    pub fn new(input: &'a str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            passwords: parser::number_range(1..=99)
+                .then(parser::number_range(1..=99).with_prefix(b'-'))
+                .map_res(|(min, max)| {
+                    if min <= max {
+                        Ok((min, max))
+                    } else {
+                        Err("expected min <= max")
+                    }
+                })
+                .then(parser::byte_range(b'a'..=b'z').with_prefix(b' '))
+                .then(parser::take_while1(u8::is_ascii_lowercase).with_prefix(": "))
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.passwords
+            .iter()
+            .filter(|&&((min, max), letter, password)| {
+                let count = password.iter().filter(|&&b| b == letter).count();
+                (min as usize..=max as usize).contains(&count)
+            })
+            .count()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.passwords
+            .iter()
+            .filter(|&&((a, b), letter, password)| {
+                // Positions are 1-indexed in the puzzle
+                let at = |i: u32| password.get(i as usize - 1) == Some(&letter);
+                at(a) != at(b)
+            })
+            .count()
+    }
+}
+
+examples!(Day02<'_> -> (usize, usize) [
+    {input: "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc", part1: 2, part2: 1},
+]);
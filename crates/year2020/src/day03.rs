@@ -0,0 +1,46 @@
+use utils::grid::Grid;
+use utils::point::Point2D;
+use utils::prelude::*;
+
+/// Counting trees on slopes through a repeating grid.
+#[derive(Clone, Debug)]
+pub struct Day03 {
+    grid: Grid<bool>,
+}
+
+impl Day03 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            grid: Grid::from_str(input, |b| match b {
+                b'#' => Some(true),
+                b'.' => Some(false),
+                _ => None,
+            })?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.trees(3, 1)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .into_iter()
+            .map(|(right, down)| self.trees(right, down))
+            .product()
+    }
+
+    fn trees(&self, right: usize, down: usize) -> u64 {
+        (0..self.grid.rows())
+            .step_by(down)
+            .enumerate()
+            .filter(|&(i, row)| self.grid[Point2D::new((i * right) % self.grid.cols(), row)])
+            .count() as u64
+    }
+}
+
+examples!(Day03 -> (u64, u64) [
+    {file: "day03_example0.txt", part1: 7, part2: 336},
+]);
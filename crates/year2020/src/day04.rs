@@ -0,0 +1,85 @@
+use utils::prelude::*;
+
+/// Validating passport fields.
+#[derive(Clone, Debug)]
+pub struct Day04<'a> {
+    passports: Vec<[&'a str; 7]>,
+}
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+impl<'a> Day04<'a> {
+    pub fn new(input: &'a str, _: InputType) -> Result<Self, InputError> {
+        let mut passports = Vec::new();
+        for passport in input.split("\n\n") {
+            let mut fields = [""; 7];
+            let mut present = 0;
+            for field in passport.split_ascii_whitespace() {
+                let Some((key, value)) = field.split_once(':') else {
+                    return Err(InputError::new(input, field, "expected key:value field"));
+                };
+                if key == "cid" {
+                    continue;
+                }
+                let Some(i) = REQUIRED.iter().position(|&k| k == key) else {
+                    return Err(InputError::new(input, field, "unknown field"));
+                };
+                if fields[i].is_empty() {
+                    present += 1;
+                }
+                fields[i] = value;
+            }
+
+            // Only complete passports are relevant to either part
+            if present == REQUIRED.len() {
+                passports.push(fields);
+            }
+        }
+
+        Ok(Self { passports })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.passports.len()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.passports
+            .iter()
+            .filter(|&&[byr, iyr, eyr, hgt, hcl, ecl, pid]| {
+                year_in(byr, 1920, 2002)
+                    && year_in(iyr, 2010, 2020)
+                    && year_in(eyr, 2020, 2030)
+                    && valid_height(hgt)
+                    && hcl.strip_prefix('#').is_some_and(|h| {
+                        h.len() == 6 && h.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+                    })
+                    && matches!(ecl, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth")
+                    && pid.len() == 9
+                    && pid.bytes().all(|b| b.is_ascii_digit())
+            })
+            .count()
+    }
+}
+
+fn year_in(s: &str, min: u32, max: u32) -> bool {
+    s.len() == 4 && s.parse().is_ok_and(|y: u32| (min..=max).contains(&y))
+}
+
+fn valid_height(s: &str) -> bool {
+    if let Some(cm) = s.strip_suffix("cm") {
+        cm.parse().is_ok_and(|h: u32| (150..=193).contains(&h))
+    } else if let Some(inches) = s.strip_suffix("in") {
+        inches.parse().is_ok_and(|h: u32| (59..=76).contains(&h))
+    } else {
+        false
+    }
+}
+
+examples!(Day04<'_> -> (usize, usize) [
+    {file: "day04_example0.txt", part1: 2},
+    {file: "day04_example1.txt", part2: 0},
+    {file: "day04_example2.txt", part2: 4},
+]);
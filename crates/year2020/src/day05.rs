@@ -0,0 +1,41 @@
+use utils::prelude::*;
+
+/// Decoding binary space partitioned seat IDs.
+///
+/// Each boarding pass is a 10-bit binary number, with `B` and `R` as ones.
+#[derive(Clone, Debug)]
+pub struct Day05 {
+    ids: Vec<u32>,
+}
+
+impl Day05 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            ids: parser::literal_map!("F" => 0u32, "B" => 1)
+                .repeat_n::<7, _>(parser::noop())
+                .then(parser::literal_map!("L" => 0u32, "R" => 1).repeat_n::<3, _>(parser::noop()))
+                .map(|(row, col)| row.into_iter().chain(col).fold(0, |id, bit| (id << 1) | bit))
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.ids.iter().copied().max().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        // XOR every ID in the full range with every present ID, leaving the missing seat
+        let min = self.ids.iter().copied().min().unwrap_or(0);
+        let max = self.ids.iter().copied().max().unwrap_or(0);
+        self.ids.iter().fold((min..=max).fold(0, |acc, id| acc ^ id), |acc, id| acc ^ id)
+    }
+}
+
+examples!(Day05 -> (u32, u32) [
+    {input: "FBFBBFFRLR", part1: 357},
+    {input: "BFFFBBFRRR", part1: 567},
+    {input: "FFFBBBFRRR", part1: 119},
+    {input: "BBFFBBFRLL", part1: 820},
+]);
@@ -0,0 +1,41 @@
+use utils::prelude::*;
+
+/// Combining sets of answers within groups.
+///
+/// Each person's answers are stored as a bitmask of the letters a-z.
+#[derive(Clone, Debug)]
+pub struct Day06 {
+    groups: Vec<Vec<u32>>,
+}
+
+impl Day06 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            groups: parser::take_while1(u8::is_ascii_lowercase)
+                .map(|answers| answers.iter().fold(0u32, |acc, b| acc | 1 << (b - b'a')))
+                .repeat(parser::eol(), 1)
+                .repeat(parser::eol(), 1)
+                .parse_complete(input.trim_ascii_end())?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.groups
+            .iter()
+            .map(|g| g.iter().fold(0, |acc, &p| acc | p).count_ones())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.groups
+            .iter()
+            .map(|g| g.iter().fold(u32::MAX, |acc, &p| acc & p).count_ones())
+            .sum()
+    }
+}
+
+examples!(Day06 -> (u32, u32) [
+    {input: "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb", part1: 11, part2: 6},
+]);
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use utils::prelude::*;
+
+/// Counting nested bags.
+///
+/// Bag colors are mapped to indices, with the rules stored as a list of `(count, color)` contents
+/// for each color.
+#[derive(Clone, Debug)]
+pub struct Day07 {
+    contents: Vec<Vec<(u32, usize)>>,
+    target: usize,
+}
+
+impl Day07 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let color = parser::take_while1(u8::is_ascii_lowercase)
+            .then(parser::take_while1(u8::is_ascii_lowercase).with_prefix(b' '))
+            .with_consumed();
+        let bags = parser::u32()
+            .then(color.with_prefix(b' '))
+            .with_suffix(" bags".or(" bag"))
+            .repeat(", ", 0)
+            .with_suffix("no other bags".optional());
+        let rules = color
+            .with_suffix(" bags contain ")
+            .then(bags)
+            .with_suffix(b'.')
+            .parse_lines(input)?;
+
+        let mut indexes = HashMap::with_capacity(rules.len());
+        for (i, &((_, name), _)) in rules.iter().enumerate() {
+            if indexes.insert(name, i).is_some() {
+                return Err(InputError::new(input, name, "duplicate bag color"));
+            }
+        }
+
+        let contents = rules
+            .iter()
+            .map(|(_, contents)| {
+                contents
+                    .iter()
+                    .map(|&(count, (_, name))| match indexes.get(name) {
+                        Some(&i) => Ok((count, i)),
+                        None => Err(InputError::new(input, name, "unknown bag color")),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<_>>, _>>()?;
+
+        let Some(&target) = indexes.get(&b"shiny gold"[..]) else {
+            return Err(InputError::new(input, 0, "missing shiny gold bag rule"));
+        };
+
+        Ok(Self { contents, target })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        let mut parents = vec![Vec::new(); self.contents.len()];
+        for (parent, contents) in self.contents.iter().enumerate() {
+            for &(_, child) in contents {
+                parents[child].push(parent);
+            }
+        }
+
+        let mut seen = vec![false; self.contents.len()];
+        let mut stack = vec![self.target];
+        let mut count = 0;
+        while let Some(bag) = stack.pop() {
+            for &parent in &parents[bag] {
+                if !seen[parent] {
+                    seen[parent] = true;
+                    count += 1;
+                    stack.push(parent);
+                }
+            }
+        }
+        count
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let mut cache = vec![None; self.contents.len()];
+        self.bags_inside(self.target, &mut cache)
+    }
+
+    fn bags_inside(&self, bag: usize, cache: &mut [Option<u64>]) -> u64 {
+        if let Some(count) = cache[bag] {
+            return count;
+        }
+
+        let count = self.contents[bag]
+            .iter()
+            .map(|&(n, child)| u64::from(n) * (1 + self.bags_inside(child, cache)))
+            .sum();
+        cache[bag] = Some(count);
+        count
+    }
+}
+
+examples!(Day07 -> (usize, u64) [
+    {file: "day07_example0.txt", part1: 4, part2: 32},
+    {file: "day07_example1.txt", part2: 126},
+]);
@@ -0,0 +1,103 @@
+use utils::prelude::*;
+
+/// Fixing an infinite loop in a simple instruction set.
+///
+/// Part 2 flips each `jmp` or `nop` reached from the start in turn, reusing the set of already
+/// visited instructions as every path through them is known to loop.
+#[derive(Clone, Debug)]
+pub struct Day08 {
+    instructions: Vec<Instruction>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+impl Day08 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            instructions: parser::literal_map!(
+                "acc " => Instruction::Acc as fn(i32) -> Instruction,
+                "jmp " => Instruction::Jmp,
+                "nop " => Instruction::Nop,
+            )
+            .then(parser::i32())
+            .map(|(f, n)| f(n))
+            .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i32 {
+        let mut visited = vec![false; self.instructions.len()];
+        match self.run(0, 0, &mut visited) {
+            Err(acc) => acc,
+            Ok(_) => panic!("program terminated"),
+        }
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> i32 {
+        let mut visited = vec![false; self.instructions.len()];
+        let (mut pc, mut acc) = (0, 0);
+        // The original path can't be longer than the program without looping
+        for _ in 0..self.instructions.len() {
+            let Some(&instruction) = self.instructions.get(pc) else {
+                panic!("program terminated without changing an instruction");
+            };
+            visited[pc] = true;
+
+            let flipped = match instruction {
+                Instruction::Acc(n) => {
+                    acc += n;
+                    pc += 1;
+                    continue;
+                }
+                Instruction::Jmp(_) => pc + 1,
+                Instruction::Nop(n) => pc.wrapping_add_signed(n as isize),
+            };
+            // Visited flags are left set after a failed attempt, as any path reaching them loops
+            if let Ok(acc) = self.run(flipped, acc, &mut visited) {
+                return acc;
+            }
+
+            pc = match instruction {
+                Instruction::Jmp(n) => pc.wrapping_add_signed(n as isize),
+                _ => pc + 1,
+            };
+        }
+        panic!("no instruction change terminates");
+    }
+
+    /// Run from `pc` until the program terminates, returning [`Ok`], or an instruction is repeated,
+    /// returning [`Err`], with the accumulator value.
+    fn run(&self, mut pc: usize, mut acc: i32, visited: &mut [bool]) -> Result<i32, i32> {
+        while pc != self.instructions.len() {
+            if visited.get(pc).copied().unwrap_or(true) {
+                return Err(acc);
+            }
+            visited[pc] = true;
+
+            match self.instructions[pc] {
+                Instruction::Acc(n) => {
+                    acc += n;
+                    pc += 1;
+                }
+                Instruction::Jmp(n) => pc = pc.wrapping_add_signed(n as isize),
+                Instruction::Nop(_) => pc += 1,
+            }
+        }
+        Ok(acc)
+    }
+}
+
+examples!(Day08 -> (i32, i32) [
+    {
+        input: "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6",
+        part1: 5,
+        part2: 8,
+    },
+]);
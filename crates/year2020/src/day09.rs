@@ -0,0 +1,70 @@
+use utils::prelude::*;
+
+/// Finding a number which isn't the sum of a pair in the previous window.
+///
+/// The window is 25 numbers for real inputs and 5 for the example.
+#[derive(Clone, Debug)]
+pub struct Day09 {
+    numbers: Vec<u64>,
+    window: usize,
+}
+
+impl Day09 {
+    pub fn new(input: &str, input_type: InputType) -> Result<Self, InputError> {
+        let window = match input_type {
+            InputType::Example => 5,
+            InputType::Real => 25,
+        };
+
+        let numbers = parser::u64().parse_lines(input)?;
+        if numbers.len() <= window {
+            return Err(InputError::new(input, 0, "expected more numbers than the window"));
+        }
+
+        Ok(Self { numbers, window })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.numbers
+            .windows(self.window + 1)
+            .find(|w| {
+                let (&target, previous) = w.split_last().unwrap();
+                !previous
+                    .iter()
+                    .enumerate()
+                    .any(|(i, &a)| previous[i + 1..].iter().any(|&b| a != b && a + b == target))
+            })
+            .map(|w| w[self.window])
+            .expect("no invalid number found")
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let target = self.part1();
+
+        // Sliding window over the contiguous range, as all the numbers are positive
+        let (mut start, mut sum) = (0, 0);
+        for end in 0..self.numbers.len() {
+            sum += self.numbers[end];
+            while sum > target && start < end {
+                sum -= self.numbers[start];
+                start += 1;
+            }
+
+            if sum == target && end > start {
+                let range = &self.numbers[start..=end];
+                return range.iter().min().unwrap() + range.iter().max().unwrap();
+            }
+        }
+        panic!("no contiguous range found");
+    }
+}
+
+examples!(Day09 -> (u64, u64) [
+    {
+        input: "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576",
+        part1: 127,
+        part2: 62,
+    },
+]);
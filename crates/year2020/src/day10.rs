@@ -0,0 +1,57 @@
+use utils::prelude::*;
+
+/// Counting chains of adapters.
+#[derive(Clone, Debug)]
+pub struct Day10 {
+    joltages: Vec<u32>,
+}
+
+impl Day10 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut joltages = parser::u32().parse_lines(input)?;
+        joltages.push(0);
+        joltages.sort_unstable();
+
+        if joltages.windows(2).any(|w| w[1] - w[0] > 3 || w[0] == w[1]) {
+            return Err(InputError::new(
+                input,
+                0,
+                "expected distinct adapters with gaps of at most 3",
+            ));
+        }
+
+        // Add the device's built-in adapter
+        joltages.push(joltages[joltages.len() - 1] + 3);
+
+        Ok(Self { joltages })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let mut counts = [0; 4];
+        for w in self.joltages.windows(2) {
+            counts[(w[1] - w[0]) as usize] += 1;
+        }
+        counts[1] * counts[3]
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        // Number of ways to reach each of the previous three adapters
+        let mut ways = [(0, 0), (0, 0), (0, 1u64)];
+        for &joltage in &self.joltages[1..] {
+            let total = ways
+                .iter()
+                .filter(|&&(j, _)| joltage - j <= 3)
+                .map(|&(_, w)| w)
+                .sum();
+            ways = [ways[1], ways[2], (joltage, total)];
+        }
+        ways[2].1
+    }
+}
+
+examples!(Day10 -> (u32, u64) [
+    {input: "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4", part1: 35, part2: 8},
+    {file: "day10_example1.txt", part1: 220, part2: 19208},
+]);
@@ -0,0 +1,99 @@
+use utils::grid::{Grid, DIRECTIONS_8};
+use utils::point::Point2D;
+use utils::prelude::*;
+
+/// Simulating seat occupancy until it stabilizes.
+///
+/// The neighboring seats for each seat are precomputed, so each round only has to check a list of
+/// seat indices instead of searching the grid.
+#[derive(Clone, Debug)]
+pub struct Day11 {
+    grid: Grid<bool>,
+    seats: Vec<usize>,
+}
+
+impl Day11 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let grid = Grid::from_str(input, |b| match b {
+            b'L' | b'#' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        })?;
+        let seats = (0..grid.data().len()).filter(|&i| grid.data()[i]).collect();
+        Ok(Self { grid, seats })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.simulate(4, |p, d| {
+            let n = step(p, d)?;
+            (*self.grid.get(n)?).then_some(n)
+        })
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.simulate(5, |mut p, d| loop {
+            p = step(p, d)?;
+            if *self.grid.get(p)? {
+                return Some(p);
+            }
+        })
+    }
+
+    fn simulate(
+        &self,
+        threshold: usize,
+        neighbor: impl Fn(Point2D<usize>, (isize, isize)) -> Option<Point2D<usize>>,
+    ) -> usize {
+        // Map each seat to a dense index to keep the occupied list compact
+        let mut dense = vec![usize::MAX; self.grid.data().len()];
+        for (i, &seat) in self.seats.iter().enumerate() {
+            dense[seat] = i;
+        }
+
+        let neighbors: Vec<Vec<usize>> = self
+            .seats
+            .iter()
+            .map(|&seat| {
+                let p = self.grid.position(seat);
+                DIRECTIONS_8
+                    .iter()
+                    .filter_map(|&d| neighbor(p, d))
+                    .map(|n| dense[self.grid.index_of(n).expect("neighbor should be in grid")])
+                    .collect()
+            })
+            .collect();
+
+        let mut occupied = vec![false; self.seats.len()];
+        let mut next = occupied.clone();
+        loop {
+            let mut changed = false;
+            for (i, neighbors) in neighbors.iter().enumerate() {
+                let count = neighbors.iter().filter(|&&n| occupied[n]).count();
+                next[i] = if occupied[i] {
+                    count < threshold
+                } else {
+                    count == 0
+                };
+                changed |= next[i] != occupied[i];
+            }
+
+            std::mem::swap(&mut occupied, &mut next);
+            if !changed {
+                return occupied.iter().filter(|&&o| o).count();
+            }
+        }
+    }
+}
+
+fn step(p: Point2D<usize>, (dr, dc): (isize, isize)) -> Option<Point2D<usize>> {
+    Some(Point2D::new(
+        p.x.checked_add_signed(dc)?,
+        p.y.checked_add_signed(dr)?,
+    ))
+}
+
+examples!(Day11 -> (usize, usize) [
+    {file: "day11_example0.txt", part1: 37, part2: 26},
+]);
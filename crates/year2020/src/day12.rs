@@ -0,0 +1,69 @@
+use utils::point::Point2D;
+use utils::prelude::*;
+
+/// Following navigation instructions, either moving the ship or a waypoint.
+#[derive(Clone, Debug)]
+pub struct Day12 {
+    instructions: Vec<Instruction>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Instruction {
+    Move(Point2D<i32>),
+    Right(i32),
+    Forward(i32),
+}
+
+impl Day12 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            instructions: parser::byte()
+                .then(parser::number_range(0i32..=100_000))
+                .map_res(|(action, value)| {
+                    Ok(match action {
+                        b'N' => Instruction::Move(Point2D::UP * value),
+                        b'E' => Instruction::Move(Point2D::RIGHT * value),
+                        b'S' => Instruction::Move(Point2D::DOWN * value),
+                        b'W' => Instruction::Move(Point2D::LEFT * value),
+                        b'L' | b'R' if value % 90 != 0 => return Err("expected multiple of 90"),
+                        b'L' => Instruction::Right(4 - (value / 90) % 4),
+                        b'R' => Instruction::Right((value / 90) % 4),
+                        b'F' => Instruction::Forward(value),
+                        _ => return Err("expected one of 'N', 'E', 'S', 'W', 'L', 'R', 'F'"),
+                    })
+                })
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.navigate(Point2D::RIGHT, false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.navigate(Point2D::new(10, 1), true)
+    }
+
+    fn navigate(&self, mut direction: Point2D<i32>, move_waypoint: bool) -> u32 {
+        let mut ship = Point2D::ORIGIN;
+        for &instruction in &self.instructions {
+            match instruction {
+                Instruction::Move(v) if move_waypoint => direction += v,
+                Instruction::Move(v) => ship += v,
+                Instruction::Right(turns) => {
+                    for _ in 0..turns {
+                        direction = direction.turn_right();
+                    }
+                }
+                Instruction::Forward(n) => ship += direction * n,
+            }
+        }
+        ship.manhattan_distance()
+    }
+}
+
+examples!(Day12 -> (u32, u32) [
+    {input: "F10\nN3\nF7\nR90\nF11", part1: 25, part2: 286},
+]);
@@ -0,0 +1,92 @@
+use utils::number::egcd;
+use utils::prelude::*;
+
+/// Finding bus departure times.
+///
+/// Part 2 requires the bus IDs to be pairwise coprime, which holds for the inputs as they are all
+/// prime, and sieves the timestamp one bus at a time.
+#[derive(Clone, Debug)]
+pub struct Day13 {
+    earliest: u64,
+    buses: Vec<(u64, u64)>,
+}
+
+impl Day13 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (earliest, ids) = parser::u64()
+            .with_suffix(parser::eol())
+            .then(
+                parser::number_range(1..=u64::from(u32::MAX))
+                    .map(Some)
+                    .or(b'x'.map(|()| None))
+                    .repeat(b',', 1),
+            )
+            .parse_complete(input.trim_ascii_end())?;
+
+        let buses: Vec<(u64, u64)> = ids
+            .into_iter()
+            .enumerate()
+            .filter_map(|(offset, id)| Some((offset as u64 % id?, id?)))
+            .collect();
+        if buses.is_empty() {
+            return Err(InputError::new(input, 0, "expected at least one bus"));
+        }
+
+        // The sieve in part 2 never finishes if two IDs share a factor, and the step overflows if
+        // the product of the IDs doesn't fit in a u64
+        let ids_line = input.lines().nth(1).unwrap_or(input);
+        let mut product = 1u64;
+        for (i, &(_, id)) in buses.iter().enumerate() {
+            if buses[..i]
+                .iter()
+                .any(|&(_, prev)| egcd(prev as i64, id as i64).0 != 1)
+            {
+                return Err(InputError::new(
+                    input,
+                    ids_line,
+                    "expected bus IDs to be pairwise coprime",
+                ));
+            }
+            product = product.checked_mul(id).ok_or_else(|| {
+                InputError::new(input, ids_line, "product of bus IDs is too large")
+            })?;
+        }
+
+        Ok(Self { earliest, buses })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        let (wait, id) = self
+            .buses
+            .iter()
+            .map(|&(_, id)| ((id - self.earliest % id) % id, id))
+            .min()
+            .unwrap();
+        wait * id
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let (mut timestamp, mut step) = (0, 1);
+        for &(offset, id) in &self.buses {
+            let remainder = (id - offset) % id;
+            while timestamp % id != remainder {
+                timestamp += step;
+            }
+            step *= id;
+        }
+        timestamp
+    }
+}
+
+examples!(Day13 -> (u64, u64) [
+    {input: "939\n7,13,x,x,59,x,31,19", part1: 295, part2: 1068781},
+    {input: "0\n17,x,13,19", part2: 3417},
+    {input: "0\n67,7,59,61", part2: 754018},
+    {input: "0\n67,x,7,59,61", part2: 779210},
+    {input: "0\n67,7,x,59,61", part2: 1261476},
+    {input: "0\n1789,37,47,1889", part2: 1202161486},
+    {input: "0\n2,4", should_error: "expected bus IDs to be pairwise coprime"},
+    {input: "0\n4294967291,4294967279,4294967231", should_error: "product of bus IDs is too large"},
+]);
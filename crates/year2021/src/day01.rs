@@ -0,0 +1,40 @@
+use utils::prelude::*;
+
+/// Counting depth increases.
+///
+/// Comparing sums of sliding windows is equivalent to comparing the values entering and leaving
+/// the window, as the other values are shared.
+#[derive(Clone, Debug)]
+pub struct Day01 {
+    depths: Vec<u32>,
+}
+
+impl Day01 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            depths: parser::u32().parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.increases(1)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.increases(3)
+    }
+
+    fn increases(&self, window: usize) -> usize {
+        self.depths
+            .iter()
+            .zip(self.depths.iter().skip(window))
+            .filter(|(a, b)| b > a)
+            .count()
+    }
+}
+
+examples!(Day01 -> (usize, usize) [
+    {input: "199\n200\n208\n210\n200\n207\n240\n269\n260\n263", part1: 7, part2: 5},
+]);
@@ -0,0 +1,61 @@
+use utils::prelude::*;
+
+/// Following submarine movement commands.
+#[derive(Clone, Debug)]
+pub struct Day02 {
+    commands: Vec<(Direction, u32)>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+impl Day02 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            commands: parser::literal_map!(
+                "forward " => Direction::Forward,
+                "down " => Direction::Down,
+                "up " => Direction::Up,
+            )
+            .then(parser::u32())
+            .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        let (mut horizontal, mut depth) = (0u64, 0u64);
+        for &(direction, n) in &self.commands {
+            match direction {
+                Direction::Forward => horizontal += u64::from(n),
+                Direction::Down => depth += u64::from(n),
+                Direction::Up => depth = depth.saturating_sub(u64::from(n)),
+            }
+        }
+        horizontal * depth
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let (mut horizontal, mut depth, mut aim) = (0u64, 0u64, 0i64);
+        for &(direction, n) in &self.commands {
+            match direction {
+                Direction::Forward => {
+                    horizontal += u64::from(n);
+                    depth = depth.saturating_add_signed(aim * i64::from(n));
+                }
+                Direction::Down => aim += i64::from(n),
+                Direction::Up => aim -= i64::from(n),
+            }
+        }
+        horizontal * depth
+    }
+}
+
+examples!(Day02 -> (u64, u64) [
+    {input: "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2", part1: 150, part2: 900},
+]);
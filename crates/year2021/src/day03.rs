@@ -0,0 +1,70 @@
+use utils::prelude::*;
+
+/// Filtering binary numbers by the most and least common bits.
+#[derive(Clone, Debug)]
+pub struct Day03 {
+    numbers: Vec<u32>,
+    bits: u32,
+}
+
+impl Day03 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let lines = parser::take_while1(|&b| b == b'0' || b == b'1').parse_lines(input)?;
+        let Some(bits) = lines.first().map(|l| l.len()) else {
+            return Err(InputError::new(input, 0, "expected at least one number"));
+        };
+        if bits > 31 {
+            return Err(InputError::new(input, 0, "expected at most 31 bits"));
+        }
+        if let Some(line) = lines.iter().find(|l| l.len() != bits) {
+            return Err(InputError::new(input, *line, "expected numbers of the same length"));
+        }
+
+        Ok(Self {
+            numbers: lines
+                .iter()
+                .map(|l| l.iter().fold(0, |acc, &b| (acc << 1) | u32::from(b - b'0')))
+                .collect(),
+            bits: bits as u32,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let gamma = (0..self.bits)
+            .filter(|&b| 2 * self.ones(&self.numbers, b) >= self.numbers.len())
+            .fold(0, |acc, b| acc | (1 << b));
+        let epsilon = !gamma & ((1 << self.bits) - 1);
+        gamma * epsilon
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.rating(true) * self.rating(false)
+    }
+
+    fn ones(&self, numbers: &[u32], bit: u32) -> usize {
+        numbers.iter().filter(|&&n| n & (1 << bit) != 0).count()
+    }
+
+    fn rating(&self, most_common: bool) -> u32 {
+        let mut numbers = self.numbers.clone();
+        for bit in (0..self.bits).rev() {
+            if numbers.len() == 1 {
+                break;
+            }
+            let ones_common = 2 * self.ones(&numbers, bit) >= numbers.len();
+            let keep = u32::from(ones_common == most_common) << bit;
+            numbers.retain(|&n| n & (1 << bit) == keep);
+        }
+        numbers[0]
+    }
+}
+
+examples!(Day03 -> (u32, u32) [
+    {
+        input: "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010",
+        part1: 198,
+        part2: 230,
+    },
+]);
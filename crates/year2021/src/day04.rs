@@ -0,0 +1,70 @@
+use utils::prelude::*;
+
+/// Finding the first and last bingo boards to win.
+///
+/// Each board's winning turn is calculated directly by finding the earliest turn at which every
+/// number in a row or column has been drawn.
+#[derive(Clone, Debug)]
+pub struct Day04 {
+    // (winning turn, score) for each board
+    results: Vec<(usize, u32)>,
+}
+
+impl Day04 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let board = parser::u8()
+            .with_prefix(parser::take_while(|&b| b == b' '))
+            .repeat_n::<5, _>(parser::noop())
+            .repeat_n::<5, _>(parser::eol());
+        let (draws, boards) = parser::u8()
+            .repeat(b',', 1)
+            .with_suffix("\n\n")
+            .then(board.repeat("\n\n", 1))
+            .parse_complete(input.trim_ascii_end())?;
+
+        let mut turn = [usize::MAX; 256];
+        for (i, &n) in draws.iter().enumerate().rev() {
+            turn[n as usize] = i;
+        }
+
+        let results = boards
+            .iter()
+            .map(|board: &[[u8; 5]; 5]| {
+                let rows = board.iter().map(|r| r.iter().map(|&n| turn[n as usize]).max());
+                let cols = (0..5).map(|c| board.iter().map(|r| turn[r[c] as usize]).max());
+                let won = rows.chain(cols).flatten().min().unwrap_or(usize::MAX);
+                if won == usize::MAX {
+                    return (won, 0);
+                }
+
+                let unmarked: u32 = board
+                    .as_flattened()
+                    .iter()
+                    .filter(|&&n| turn[n as usize] > won)
+                    .map(|&n| u32::from(n))
+                    .sum();
+                (won, unmarked * u32::from(draws[won]))
+            })
+            .filter(|&(won, _)| won != usize::MAX)
+            .collect::<Vec<_>>();
+        if results.is_empty() {
+            return Err(InputError::new(input, 0, "expected at least one board to win"));
+        }
+
+        Ok(Self { results })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.results.iter().min().unwrap().1
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.results.iter().max().unwrap().1
+    }
+}
+
+examples!(Day04 -> (u32, u32) [
+    {file: "day04_example0.txt", part1: 4512, part2: 1924},
+]);
@@ -0,0 +1,72 @@
+use utils::prelude::*;
+
+/// Counting overlapping points on horizontal, vertical and diagonal lines.
+#[derive(Clone, Debug)]
+pub struct Day05 {
+    lines: Vec<[u16; 4]>,
+}
+
+const SIZE: usize = 1000;
+
+impl Day05 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let coord = parser::number_range(0..=(SIZE - 1) as u16);
+        Ok(Self {
+            lines: coord
+                .then(coord.with_prefix(b','))
+                .then(coord.with_prefix(" -> "))
+                .then(coord.with_prefix(b','))
+                .map_res(|(x1, y1, x2, y2)| {
+                    if x1 == x2 || y1 == y2 || x1.abs_diff(x2) == y1.abs_diff(y2) {
+                        Ok([x1, y1, x2, y2])
+                    } else {
+                        Err("expected horizontal, vertical or diagonal line")
+                    }
+                })
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.overlaps(false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.overlaps(true)
+    }
+
+    fn overlaps(&self, diagonals: bool) -> usize {
+        let mut grid = vec![0u8; SIZE * SIZE];
+        let mut overlaps = 0;
+        for &[x1, y1, x2, y2] in &self.lines {
+            if !diagonals && x1 != x2 && y1 != y2 {
+                continue;
+            }
+
+            let (dx, dy) = (x2.cmp(&x1) as isize, y2.cmp(&y1) as isize);
+            let steps = x1.abs_diff(x2).max(y1.abs_diff(y2)) as usize;
+            let (mut x, mut y) = (x1 as usize, y1 as usize);
+            for _ in 0..=steps {
+                let cell = &mut grid[y * SIZE + x];
+                *cell = cell.saturating_add(1);
+                if *cell == 2 {
+                    overlaps += 1;
+                }
+                x = x.wrapping_add_signed(dx);
+                y = y.wrapping_add_signed(dy);
+            }
+        }
+        overlaps
+    }
+}
+
+examples!(Day05 -> (usize, usize) [
+    {
+        input: "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n\
+            6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2",
+        part1: 5,
+        part2: 12,
+    },
+]);
@@ -0,0 +1,45 @@
+use utils::prelude::*;
+
+/// Simulating exponential population growth.
+///
+/// Fish are grouped by their timer value, so each day is a rotation of nine counts.
+#[derive(Clone, Debug)]
+pub struct Day06 {
+    counts: [u64; 9],
+}
+
+impl Day06 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut counts = [0; 9];
+        for timer in parser::number_range(0..=8u8)
+            .repeat(b',', 1)
+            .parse_complete(input.trim_ascii_end())?
+        {
+            counts[timer as usize] += 1;
+        }
+        Ok(Self { counts })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.simulate(80)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.simulate(256)
+    }
+
+    fn simulate(&self, days: usize) -> u64 {
+        let mut counts = self.counts;
+        for _ in 0..days {
+            counts.rotate_left(1);
+            counts[6] += counts[8];
+        }
+        counts.iter().sum()
+    }
+}
+
+examples!(Day06 -> (u64, u64) [
+    {input: "3,4,3,1,2", part1: 5934, part2: 26984457539},
+]);
@@ -0,0 +1,48 @@
+use utils::prelude::*;
+
+/// Aligning crabs with the minimum fuel cost.
+///
+/// For linear costs the optimal position is the median. For triangular costs the optimal position
+/// is within 0.5 of the mean, so only the integers either side of it need checking.
+#[derive(Clone, Debug)]
+pub struct Day07 {
+    positions: Vec<u32>,
+}
+
+impl Day07 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut positions = parser::number_range(0..=9999)
+            .repeat(b',', 1)
+            .parse_complete(input.trim_ascii_end())?;
+        positions.sort_unstable();
+        Ok(Self { positions })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let median = self.positions[self.positions.len() / 2];
+        self.positions.iter().map(|&p| p.abs_diff(median)).sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mean = self.positions.iter().sum::<u32>() / self.positions.len() as u32;
+        [mean, mean + 1]
+            .into_iter()
+            .map(|target| {
+                self.positions
+                    .iter()
+                    .map(|&p| {
+                        let n = p.abs_diff(target);
+                        n * (n + 1) / 2
+                    })
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+}
+
+examples!(Day07 -> (u32, u32) [
+    {input: "16,1,2,0,4,2,7,1,2,14", part1: 37, part2: 168},
+]);
@@ -0,0 +1,71 @@
+use utils::prelude::*;
+
+/// Decoding scrambled seven-segment displays.
+///
+/// Across the ten unique patterns each segment appears a fixed number of times (a: 8, b: 6, c: 8,
+/// d: 7, e: 4, f: 9, g: 7). Summing these counts for each segment lit in a digit gives a different
+/// total for every digit, which identifies it without working out the wiring.
+#[derive(Clone, Debug)]
+pub struct Day08 {
+    outputs: Vec<[u8; 4]>,
+}
+
+const DIGIT_SCORES: [u32; 10] = [42, 17, 34, 39, 30, 37, 41, 25, 49, 45];
+
+impl Day08 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let pattern = parser::take_while1(|b| (b'a'..=b'g').contains(b))
+            .map(|s| s.iter().fold(0u8, |acc, &b| acc | (1 << (b - b'a'))));
+        Ok(Self {
+            outputs: pattern
+                .repeat_n::<10, _>(b' ')
+                .with_suffix(" | ")
+                .then(pattern.repeat_n::<4, _>(b' '))
+                .map_res(|(patterns, output)| {
+                    let mut counts = [0u32; 7];
+                    for p in patterns {
+                        for (i, c) in counts.iter_mut().enumerate() {
+                            *c += u32::from(p >> i) & 1;
+                        }
+                    }
+
+                    let mut digits = [0; 4];
+                    for (d, o) in digits.iter_mut().zip(output) {
+                        let score = (0..7).filter(|&i| o & (1 << i) != 0).map(|i| counts[i]);
+                        let score = score.sum();
+                        let Some(digit) = DIGIT_SCORES.iter().position(|&s| s == score) else {
+                            return Err("unable to decode output");
+                        };
+                        *d = digit as u8;
+                    }
+                    Ok(digits)
+                })
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.outputs
+            .as_flattened()
+            .iter()
+            .filter(|&&d| matches!(d, 1 | 4 | 7 | 8))
+            .count()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.outputs
+            .iter()
+            .map(|o| o.iter().fold(0, |acc, &d| acc * 10 + u32::from(d)))
+            .sum()
+    }
+}
+
+examples!(Day08 -> (usize, u32) [
+    {
+        input: "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        part2: 5353,
+    },
+    {file: "day08_example1.txt", part1: 26, part2: 61229},
+]);
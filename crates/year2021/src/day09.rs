@@ -0,0 +1,64 @@
+use utils::grid::Grid;
+use utils::prelude::*;
+
+/// Finding low points and basins in a height map.
+#[derive(Clone, Debug)]
+pub struct Day09 {
+    grid: Grid<u8>,
+}
+
+impl Day09 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            grid: Grid::from_str(input, |b| b.is_ascii_digit().then_some(b - b'0'))?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.low_points()
+            .map(|i| u32::from(self.grid.data()[i]) + 1)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mut visited = vec![false; self.grid.data().len()];
+        let mut stack = Vec::new();
+        let mut sizes = self
+            .low_points()
+            .map(|start| {
+                let mut size = 0;
+                visited[start] = true;
+                stack.push(self.grid.position(start));
+                while let Some(point) = stack.pop() {
+                    size += 1;
+                    for next in self.grid.neighbors4(point) {
+                        let i = self.grid.index_of(next).unwrap();
+                        if !visited[i] && self.grid.data()[i] != 9 {
+                            visited[i] = true;
+                            stack.push(next);
+                        }
+                    }
+                }
+                size
+            })
+            .collect::<Vec<u32>>();
+
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.iter().take(3).product()
+    }
+
+    fn low_points(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.grid.data().len()).filter(|&i| {
+            let height = self.grid.data()[i];
+            self.grid
+                .neighbors4(self.grid.position(i))
+                .all(|p| self.grid[p] > height)
+        })
+    }
+}
+
+examples!(Day09 -> (u32, u32) [
+    {input: "2199943210\n3987894921\n9856789892\n8767896789\n9899965678", part1: 15, part2: 1134},
+]);
@@ -0,0 +1,78 @@
+use utils::prelude::*;
+
+/// Scoring corrupted and incomplete bracket sequences.
+#[derive(Clone, Debug)]
+pub struct Day10 {
+    corrupted: u64,
+    incomplete: Vec<u64>,
+}
+
+impl Day10 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let lines = parser::take_while1(|b| b"()[]{}<>".contains(b)).parse_lines(input)?;
+
+        let mut corrupted = 0;
+        let mut incomplete = Vec::new();
+        let mut stack = Vec::new();
+        'lines: for line in lines {
+            stack.clear();
+            for &b in line {
+                let (close, score) = match b {
+                    b'(' | b'[' | b'{' | b'<' => {
+                        stack.push(b);
+                        continue;
+                    }
+                    b')' => (b'(', 3),
+                    b']' => (b'[', 57),
+                    b'}' => (b'{', 1197),
+                    b'>' => (b'<', 25137),
+                    _ => unreachable!(),
+                };
+                if stack.pop() != Some(close) {
+                    corrupted += score;
+                    continue 'lines;
+                }
+            }
+
+            if !stack.is_empty() {
+                incomplete.push(stack.iter().rev().fold(0, |acc, b| {
+                    acc * 5
+                        + match b {
+                            b'(' => 1,
+                            b'[' => 2,
+                            b'{' => 3,
+                            _ => 4,
+                        }
+                }));
+            }
+        }
+
+        if incomplete.len() % 2 == 0 {
+            return Err(InputError::new(
+                input,
+                0,
+                "expected an odd number of incomplete lines",
+            ));
+        }
+        incomplete.sort_unstable();
+
+        Ok(Self {
+            corrupted,
+            incomplete,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.corrupted
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.incomplete[self.incomplete.len() / 2]
+    }
+}
+
+examples!(Day10 -> (u64, u64) [
+    {file: "day10_example0.txt", part1: 26397, part2: 288957},
+]);
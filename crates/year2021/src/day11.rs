@@ -0,0 +1,76 @@
+use utils::prelude::*;
+
+/// Simulating flashing octopuses.
+#[derive(Clone, Debug)]
+pub struct Day11 {
+    grid: [[u8; 10]; 10],
+}
+
+impl Day11 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            grid: parser::byte_range(b'0'..=b'9')
+                .map(|b| b - b'0')
+                .repeat_n::<10, _>(parser::noop())
+                .repeat_n::<10, _>(parser::eol())
+                .parse_complete(input.trim_ascii_end())?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let mut grid = self.grid;
+        (0..100).map(|_| Self::step(&mut grid)).sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mut grid = self.grid;
+        let mut steps = 1;
+        while Self::step(&mut grid) != 100 {
+            steps += 1;
+        }
+        steps
+    }
+
+    fn step(grid: &mut [[u8; 10]; 10]) -> u32 {
+        let mut stack = Vec::new();
+        for (r, row) in grid.iter_mut().enumerate() {
+            for (c, energy) in row.iter_mut().enumerate() {
+                *energy += 1;
+                if *energy == 10 {
+                    stack.push((r, c));
+                }
+            }
+        }
+
+        let mut flashes = 0;
+        while let Some((r, c)) = stack.pop() {
+            flashes += 1;
+            for (nr, row) in grid.iter_mut().enumerate().take(r + 2).skip(r.saturating_sub(1)) {
+                for (nc, energy) in row.iter_mut().enumerate().take(c + 2).skip(c.saturating_sub(1)) {
+                    *energy += 1;
+                    if *energy == 10 {
+                        stack.push((nr, nc));
+                    }
+                }
+            }
+        }
+
+        for energy in grid.as_flattened_mut() {
+            if *energy > 9 {
+                *energy = 0;
+            }
+        }
+        flashes
+    }
+}
+
+examples!(Day11 -> (u32, u32) [
+    {
+        input: "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n\
+            4167524645\n2176841721\n6882881134\n4846848554\n5283751526",
+        part1: 1656,
+        part2: 195,
+    },
+]);
@@ -0,0 +1,131 @@
+use utils::prelude::*;
+
+/// Counting paths through a cave system.
+///
+/// Large caves are never directly connected to each other, so they can be removed by adding an edge
+/// between every pair of small caves connected through each large cave. Paths are then counted by
+/// a depth-first search with a bitmask of visited small caves.
+#[derive(Clone, Debug)]
+pub struct Day12 {
+    // Number of edges between each pair of small caves
+    edges: Vec<Vec<(usize, u64)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Day12 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let cave = parser::take_while1(u8::is_ascii_alphabetic);
+        let lines = cave
+            .with_suffix(b'-')
+            .then(cave)
+            .parse_lines(input)?;
+
+        let mut names: Vec<&[u8]> = Vec::new();
+        let mut edges = Vec::with_capacity(lines.len());
+        for &(a, b) in &lines {
+            let [a, b] = [a, b].map(|name| {
+                names.iter().position(|&n| n == name).unwrap_or_else(|| {
+                    names.push(name);
+                    names.len() - 1
+                })
+            });
+            edges.push((a, b));
+        }
+
+        if names.len() > 64 {
+            return Err(InputError::new(input, 0, "expected at most 64 caves"));
+        }
+        let is_large = |i: usize| names[i][0].is_ascii_uppercase();
+        if let Some(&(a, _)) = lines
+            .iter()
+            .find(|&&(a, b)| a[0].is_ascii_uppercase() && b[0].is_ascii_uppercase())
+        {
+            return Err(InputError::new(input, a, "large caves can't be connected"));
+        }
+
+        let mut adjacent = vec![vec![]; names.len()];
+        for &(a, b) in &edges {
+            adjacent[a].push(b);
+            adjacent[b].push(a);
+        }
+
+        let mut counts = vec![vec![0u64; names.len()]; names.len()];
+        for (from, neighbors) in adjacent.iter().enumerate() {
+            if is_large(from) {
+                continue;
+            }
+            for &n in neighbors {
+                if is_large(n) {
+                    for &to in &adjacent[n] {
+                        counts[from][to] += 1;
+                    }
+                } else {
+                    counts[from][n] += 1;
+                }
+            }
+        }
+
+        let Some(start) = names.iter().position(|&n| n == b"start") else {
+            return Err(InputError::new(input, 0, "expected start cave"));
+        };
+        let Some(end) = names.iter().position(|&n| n == b"end") else {
+            return Err(InputError::new(input, 0, "expected end cave"));
+        };
+
+        Ok(Self {
+            edges: counts
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .enumerate()
+                        .filter(|&(_, count)| count > 0)
+                        .collect()
+                })
+                .collect(),
+            start,
+            end,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.paths(self.start, 1 << self.start, false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.paths(self.start, 1 << self.start, true)
+    }
+
+    fn paths(&self, cave: usize, visited: u64, revisit: bool) -> u64 {
+        if cave == self.end {
+            return 1;
+        }
+
+        let mut total = 0;
+        for &(next, count) in &self.edges[cave] {
+            if visited & (1 << next) == 0 {
+                total += count * self.paths(next, visited | (1 << next), revisit);
+            } else if revisit && next != self.start {
+                total += count * self.paths(next, visited, false);
+            }
+        }
+        total
+    }
+}
+
+examples!(Day12 -> (u64, u64) [
+    {input: "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end", part1: 10, part2: 36},
+    {
+        input: "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        part1: 19,
+        part2: 103,
+    },
+    {
+        input: "fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\n\
+            pj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW",
+        part1: 226,
+        part2: 3509,
+    },
+]);
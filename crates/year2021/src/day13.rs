@@ -0,0 +1,90 @@
+use utils::ocr::Letters;
+use utils::prelude::*;
+
+/// Folding transparent paper to reveal letters.
+#[derive(Clone, Debug)]
+pub struct Day13 {
+    dots: Vec<(u32, u32)>,
+    folds: Vec<Fold>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Fold {
+    X(u32),
+    Y(u32),
+}
+
+impl Day13 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let Some((dots, folds)) = input.split_once("\n\n") else {
+            return Err(InputError::new(input, 0, "expected dots and folds"));
+        };
+
+        let dots = parser::u32()
+            .with_suffix(b',')
+            .then(parser::u32())
+            .parse_lines(dots)?;
+        let folds = parser::literal_map!("fold along x=" => true, "fold along y=" => false)
+            .then(parser::u32())
+            .map(|(x, n)| if x { Fold::X(n) } else { Fold::Y(n) })
+        .parse_lines(folds)?;
+        if !folds.iter().any(|f| matches!(f, Fold::X(_)))
+            || !folds.iter().any(|f| matches!(f, Fold::Y(_)))
+        {
+            return Err(InputError::new(input, 0, "expected folds along both axes"));
+        }
+
+        Ok(Self { dots, folds })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        let mut dots = self.fold(&self.folds[..1]);
+        dots.sort_unstable();
+        dots.dedup();
+        dots.len()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> Letters {
+        let (width, height) = Self::size(&self.folds);
+        let mut grid = vec![false; (width * height) as usize];
+        for (x, y) in self.fold(&self.folds) {
+            grid[(y * width + x) as usize] = true;
+        }
+        Letters::new(width as usize, height as usize, grid)
+    }
+
+    fn fold(&self, folds: &[Fold]) -> Vec<(u32, u32)> {
+        self.dots
+            .iter()
+            .map(|&(mut x, mut y)| {
+                for &fold in folds {
+                    match fold {
+                        Fold::X(f) if x > f => x = 2 * f - x,
+                        Fold::Y(f) if y > f => y = 2 * f - y,
+                        _ => {}
+                    }
+                }
+                (x, y)
+            })
+            .collect()
+    }
+
+    fn size(folds: &[Fold]) -> (u32, u32) {
+        folds
+            .iter()
+            .fold((u32::MAX, u32::MAX), |(w, h), &fold| match fold {
+                Fold::X(f) => (w.min(f), h),
+                Fold::Y(f) => (w, h.min(f)),
+            })
+    }
+}
+
+examples!(Day13 -> (usize, &'static str) [
+    {
+        file: "day13_example0.txt",
+        part1: 17,
+        part2: "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....",
+    },
+]);
@@ -0,0 +1,80 @@
+use utils::prelude::*;
+
+/// Counting elements after repeated pair insertion.
+///
+/// Only the number of each pair of adjacent elements matters, as each pair is expanded
+/// independently.
+#[derive(Clone, Debug)]
+pub struct Day14 {
+    pairs: [u64; 26 * 26],
+    last: usize,
+    rules: Vec<(usize, usize, usize)>,
+}
+
+impl Day14 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let element = parser::byte_range(b'A'..=b'Z').map(|b| (b - b'A') as usize);
+        let (template, rules) = parser::take_while1(u8::is_ascii_uppercase)
+            .with_suffix(parser::eol())
+            .with_suffix(parser::eol())
+            .then(
+                element
+                    .then(element.with_suffix(" -> "))
+                    .then(element)
+                    .map(|(a, b, c)| (a * 26 + b, a * 26 + c, c * 26 + b))
+                    .repeat(parser::eol(), 1),
+            )
+            .parse_complete(input.trim_ascii_end())?;
+
+        if let Some(&b) = template.iter().find(|&&b| !b.is_ascii_uppercase()) {
+            return Err(InputError::new(input, b as char, "expected uppercase letter"));
+        }
+
+        let mut pairs = [0; 26 * 26];
+        for w in template.windows(2) {
+            pairs[(w[0] - b'A') as usize * 26 + (w[1] - b'A') as usize] += 1;
+        }
+
+        Ok(Self {
+            pairs,
+            last: (template[template.len() - 1] - b'A') as usize,
+            rules,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.simulate(10)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.simulate(40)
+    }
+
+    fn simulate(&self, steps: usize) -> u64 {
+        let mut pairs = self.pairs;
+        for _ in 0..steps {
+            let mut next = pairs;
+            for &(pair, left, right) in &self.rules {
+                next[pair] -= pairs[pair];
+                next[left] += pairs[pair];
+                next[right] += pairs[pair];
+            }
+            pairs = next;
+        }
+
+        let mut counts = [0; 26];
+        counts[self.last] += 1;
+        for (pair, &count) in pairs.iter().enumerate() {
+            counts[pair / 26] += count;
+        }
+
+        let present = counts.iter().filter(|&&c| c > 0);
+        present.clone().max().unwrap() - present.min().unwrap()
+    }
+}
+
+examples!(Day14 -> (u64, u64) [
+    {file: "day14_example0.txt", part1: 1588, part2: 2188189693529},
+]);
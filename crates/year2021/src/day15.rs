@@ -0,0 +1,67 @@
+use utils::graph::dijkstra_buckets;
+use utils::grid;
+use utils::prelude::*;
+
+/// Finding the lowest risk path through a grid.
+#[derive(Clone, Debug)]
+pub struct Day15 {
+    rows: usize,
+    cols: usize,
+    risks: Vec<u8>,
+}
+
+impl Day15 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, risks) = grid::from_str(input, |b| match b {
+            b'1'..=b'9' => Some(b - b'0'),
+            _ => None,
+        })?;
+        Ok(Self { rows, cols, risks })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.lowest_risk(1)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.lowest_risk(5)
+    }
+
+    fn lowest_risk(&self, tiles: usize) -> u32 {
+        let (rows, cols) = (self.rows * tiles, self.cols * tiles);
+        let risk = |r: usize, c: usize| {
+            let base = self.risks[(r % self.rows) * self.cols + (c % self.cols)];
+            let risk = base as usize + r / self.rows + c / self.cols;
+            ((risk - 1) % 9 + 1) as u32
+        };
+
+        dijkstra_buckets(
+            (0, 0),
+            |&(r, c): &(usize, usize)| {
+                [
+                    (r.wrapping_sub(1), c),
+                    (r + 1, c),
+                    (r, c.wrapping_sub(1)),
+                    (r, c + 1),
+                ]
+                .into_iter()
+                .filter(|&(r, c)| r < rows && c < cols)
+                .map(|(r, c)| ((r, c), risk(r, c)))
+            },
+            |&pos| pos == (rows - 1, cols - 1),
+        )
+        .expect("bottom right should be reachable")
+        .0
+    }
+}
+
+examples!(Day15 -> (u32, u32) [
+    {
+        input: "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n\
+            1319128137\n1359912421\n3125421639\n1293138521\n2311944581",
+        part1: 40,
+        part2: 315,
+    },
+]);
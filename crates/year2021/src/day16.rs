@@ -0,0 +1,134 @@
+use utils::prelude::*;
+
+/// Decoding nested binary packets.
+#[derive(Clone, Debug)]
+pub struct Day16 {
+    version_sum: u32,
+    value: u64,
+}
+
+struct Reader<'a> {
+    bits: &'a [bool],
+    pos: usize,
+    version_sum: u32,
+}
+
+impl Day16 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let input = input.trim_ascii_end();
+        let mut bits = Vec::with_capacity(input.len() * 4);
+        for b in input.bytes() {
+            let Some(n) = (b as char).to_digit(16) else {
+                return Err(InputError::new(input, b as char, "expected hex digit"));
+            };
+            bits.extend((0..4).rev().map(|i| n & (1 << i) != 0));
+        }
+
+        let mut reader = Reader {
+            bits: &bits,
+            pos: 0,
+            version_sum: 0,
+        };
+        let value = reader
+            .packet()
+            .map_err(|e| InputError::new(input, 0, e))?;
+
+        Ok(Self {
+            version_sum: reader.version_sum,
+            value,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.version_sum
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.value
+    }
+}
+
+impl Reader<'_> {
+    fn read(&mut self, len: usize) -> Result<u64, &'static str> {
+        let Some(bits) = self.bits.get(self.pos..self.pos + len) else {
+            return Err("unexpected end of packet");
+        };
+        self.pos += len;
+        Ok(bits.iter().fold(0, |acc, &b| (acc << 1) | u64::from(b)))
+    }
+
+    fn packet(&mut self) -> Result<u64, &'static str> {
+        self.version_sum += self.read(3)? as u32;
+        let type_id = self.read(3)?;
+
+        if type_id == 4 {
+            let mut value = 0u64;
+            loop {
+                let group = self.read(5)?;
+                if value.leading_zeros() < 4 {
+                    return Err("literal value too large");
+                }
+                value = (value << 4) | (group & 0xF);
+                if group & 0x10 == 0 {
+                    return Ok(value);
+                }
+            }
+        }
+
+        let mut values = Vec::new();
+        if self.read(1)? == 0 {
+            let len = self.read(15)? as usize;
+            let end = self.pos + len;
+            while self.pos < end {
+                values.push(self.packet()?);
+            }
+            if self.pos != end {
+                return Err("sub-packets exceed length");
+            }
+        } else {
+            for _ in 0..self.read(11)? {
+                values.push(self.packet()?);
+            }
+        }
+
+        if values.is_empty() {
+            return Err("expected at least one sub-packet");
+        }
+        Ok(match type_id {
+            0 => values.iter().sum(),
+            1 => values.iter().product(),
+            2 => *values.iter().min().unwrap(),
+            3 => *values.iter().max().unwrap(),
+            _ => {
+                let [a, b] = values[..] else {
+                    return Err("expected two sub-packets for comparison");
+                };
+                u64::from(match type_id {
+                    5 => a > b,
+                    6 => a < b,
+                    _ => a == b,
+                })
+            }
+        })
+    }
+}
+
+examples!(Day16 -> (u32, u64) [
+    {input: "D2FE28", part1: 6, part2: 2021},
+    {input: "38006F45291200", part1: 9, part2: 1},
+    {input: "EE00D40C823060", part1: 14, part2: 3},
+    {input: "8A004A801A8002F478", part1: 16},
+    {input: "620080001611562C8802118E34", part1: 12},
+    {input: "C0015000016115A2E0802F182340", part1: 23},
+    {input: "A0016C880162017C3686B18A3D4780", part1: 31},
+    {input: "C200B40A82", part2: 3},
+    {input: "04005AC33890", part2: 54},
+    {input: "880086C3E88112", part2: 7},
+    {input: "CE00C43D881120", part2: 9},
+    {input: "D8005AC2A8F0", part2: 1},
+    {input: "F600BC2D8F", part2: 0},
+    {input: "9C005AC2F8F0", part2: 0},
+    {input: "9C0141080250320F1802104A08", part2: 1},
+]);
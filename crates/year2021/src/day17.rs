@@ -0,0 +1,73 @@
+use utils::prelude::*;
+
+/// Finding launch velocities which hit a target area.
+///
+/// The probe always returns to `y = 0` with the negated launch velocity, so the highest launch
+/// which still hits the target has an initial y velocity one less than the target's depth.
+#[derive(Clone, Debug)]
+pub struct Day17 {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+impl Day17 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (x_min, x_max, y_min, y_max) = parser::i32()
+            .with_prefix("target area: x=")
+            .then(parser::i32().with_prefix(".."))
+            .then(parser::i32().with_prefix(", y="))
+            .then(parser::i32().with_prefix(".."))
+            .parse_complete(input.trim_ascii_end())?;
+
+        if x_min <= 0 || x_min > x_max {
+            return Err(InputError::new(input, 0, "expected positive x range"));
+        }
+        if y_max >= 0 || y_min > y_max {
+            return Err(InputError::new(input, 0, "expected negative y range"));
+        }
+
+        Ok(Self {
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i32 {
+        let vy = -self.y_min - 1;
+        vy * (vy + 1) / 2
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        (1..=self.x_max)
+            .flat_map(|vx| (self.y_min..-self.y_min).map(move |vy| (vx, vy)))
+            .filter(|&(vx, vy)| self.hits(vx, vy))
+            .count()
+    }
+
+    fn hits(&self, mut vx: i32, mut vy: i32) -> bool {
+        let (mut x, mut y) = (0, 0);
+        while x <= self.x_max && y >= self.y_min {
+            if x >= self.x_min && y <= self.y_max {
+                return true;
+            }
+            if vx == 0 && x < self.x_min {
+                return false;
+            }
+            x += vx;
+            y += vy;
+            vx -= vx.signum();
+            vy -= 1;
+        }
+        false
+    }
+}
+
+examples!(Day17 -> (i32, usize) [
+    {input: "target area: x=20..30, y=-10..-5", part1: 45, part2: 112},
+]);
@@ -0,0 +1,141 @@
+use utils::prelude::*;
+
+/// Adding and reducing nested pairs.
+///
+/// Each snailfish number is stored as a flat list of regular numbers and their nesting depth,
+/// which makes finding the neighbors of an exploding pair trivial.
+#[derive(Clone, Debug)]
+pub struct Day18 {
+    numbers: Vec<Vec<(u32, u32)>>,
+}
+
+impl Day18 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let numbers = input
+            .lines()
+            .map(|line| {
+                let mut number = Vec::new();
+                let mut bytes = line.bytes();
+                Self::parse(&mut bytes, 0, &mut number)
+                    .and_then(|()| match bytes.next() {
+                        None => Ok(()),
+                        Some(_) => Err("expected end of line"),
+                    })
+                    .map_err(|e| InputError::new(input, line, e))?;
+                Ok(number)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if numbers.is_empty() {
+            return Err(InputError::new(input, 0, "expected at least one number"));
+        }
+
+        Ok(Self { numbers })
+    }
+
+    fn parse(
+        bytes: &mut impl Iterator<Item = u8>,
+        depth: u32,
+        number: &mut Vec<(u32, u32)>,
+    ) -> Result<(), &'static str> {
+        match bytes.next() {
+            Some(b @ b'0'..=b'9') if depth > 0 => number.push((u32::from(b - b'0'), depth)),
+            Some(b'[') if depth < 4 => {
+                Self::parse(bytes, depth + 1, number)?;
+                if bytes.next() != Some(b',') {
+                    return Err("expected ','");
+                }
+                Self::parse(bytes, depth + 1, number)?;
+                if bytes.next() != Some(b']') {
+                    return Err("expected ']'");
+                }
+            }
+            Some(b'[') => return Err("expected at most 4 levels of nesting"),
+            Some(b'0'..=b'9') => return Err("expected pair"),
+            _ => return Err("expected digit or '['"),
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let sum = self.numbers[1..]
+            .iter()
+            .fold(self.numbers[0].clone(), |acc, n| Self::add(&acc, n));
+        Self::magnitude(&sum)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mut max = 0;
+        for (i, a) in self.numbers.iter().enumerate() {
+            for (j, b) in self.numbers.iter().enumerate() {
+                if i != j {
+                    max = max.max(Self::magnitude(&Self::add(a, b)));
+                }
+            }
+        }
+        max
+    }
+
+    fn add(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)> {
+        let mut number: Vec<_> = a.iter().chain(b).map(|&(v, d)| (v, d + 1)).collect();
+
+        // Adding two reduced numbers can only create pairs nested 5 deep, so explode all of them
+        // first, after which splits only ever create a single pair to explode
+        let mut i = 0;
+        while i < number.len() {
+            if number[i].1 == 5 {
+                Self::explode(&mut number, i);
+            }
+            i += 1;
+        }
+
+        while let Some(i) = number.iter().position(|&(v, _)| v >= 10) {
+            let (v, d) = number[i];
+            number[i] = (v / 2, d + 1);
+            number.insert(i + 1, (v.div_ceil(2), d + 1));
+            if d == 4 {
+                Self::explode(&mut number, i);
+            }
+        }
+
+        number
+    }
+
+    fn explode(number: &mut Vec<(u32, u32)>, i: usize) {
+        let (left, right) = (number[i].0, number[i + 1].0);
+        if i > 0 {
+            number[i - 1].0 += left;
+        }
+        if i + 2 < number.len() {
+            number[i + 2].0 += right;
+        }
+        number[i] = (0, number[i].1 - 1);
+        number.remove(i + 1);
+    }
+
+    fn magnitude(number: &[(u32, u32)]) -> u32 {
+        let mut stack: Vec<(u32, u32)> = Vec::with_capacity(5);
+        for &(mut value, mut depth) in number {
+            while let Some(&(left, d)) = stack.last() {
+                if d != depth {
+                    break;
+                }
+                stack.pop();
+                value = 3 * left + 2 * value;
+                depth -= 1;
+            }
+            stack.push((value, depth));
+        }
+        stack[0].0
+    }
+}
+
+examples!(Day18 -> (u32, u32) [
+    {input: "[[1,2],[[3,4],5]]", part1: 143},
+    {input: "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", part1: 1384},
+    {input: "[[[[5,0],[7,4]],[5,5]],[6,6]]", part1: 1137},
+    {input: "[[[[4,3],4],4],[7,[[8,4],9]]]\n[1,1]", part1: 1384},
+    {input: "[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]", part1: 1137},
+    {file: "day18_example5.txt", part1: 4140, part2: 3993},
+]);
@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use utils::prelude::*;
+
+/// Aligning overlapping 3D scans.
+///
+/// Squared distances between pairs of beacons are unaffected by rotation and translation, so
+/// scanners which share at least 12 beacons must share at least 66 pairwise distances. Only
+/// scanners passing this check are then aligned by trying each rotation and counting the most
+/// common offset between beacons.
+#[derive(Clone, Debug)]
+pub struct Day19 {
+    beacons: usize,
+    max_distance: i32,
+}
+
+type Point = [i32; 3];
+
+impl Day19 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let scans = input
+            .trim_ascii_end()
+            .split("\n\n")
+            .enumerate()
+            .map(|(i, block)| {
+                let Some((header, beacons)) = block.split_once('\n') else {
+                    return Err(InputError::new(input, block, "expected scanner beacons"));
+                };
+                if header != format!("--- scanner {i} ---") {
+                    return Err(InputError::new(input, header, "expected scanner header"));
+                }
+                parser::i32()
+                    .repeat_n::<3, _>(b',')
+                    .parse_lines(beacons)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let distances: Vec<Vec<i32>> = scans
+            .iter()
+            .map(|beacons| {
+                let mut distances = Vec::with_capacity(beacons.len() * beacons.len() / 2);
+                for (i, a) in beacons.iter().enumerate() {
+                    for b in &beacons[i + 1..] {
+                        distances.push((0..3).map(|k| (a[k] - b[k]).pow(2)).sum());
+                    }
+                }
+                distances.sort_unstable();
+                distances
+            })
+            .collect();
+
+        let rotations = Self::rotations();
+        let mut aligned: Vec<Option<(Vec<Point>, Point)>> = vec![None; scans.len()];
+        aligned[0] = Some((scans[0].clone(), [0; 3]));
+        let mut queue = vec![0];
+        while let Some(known) = queue.pop() {
+            for other in 0..scans.len() {
+                if aligned[other].is_some()
+                    || Self::common(&distances[known], &distances[other]) < 66
+                {
+                    continue;
+                }
+
+                let (known_beacons, _) = aligned[known]
+                    .as_ref()
+                    .unwrap(); // check-days: allow(unwrap-in-new)
+                if let Some(result) = Self::align(known_beacons, &scans[other], &rotations) {
+                    aligned[other] = Some(result);
+                    queue.push(other);
+                }
+            }
+        }
+
+        if let Some(unaligned) = aligned.iter().position(Option::is_none) {
+            return Err(InputError::new(
+                input,
+                0,
+                format!("unable to align scanner {unaligned}"),
+            ));
+        }
+        let aligned: Vec<_> = aligned.into_iter().flatten().collect();
+
+        let mut beacons: Vec<Point> = aligned.iter().flat_map(|(b, _)| b).copied().collect();
+        beacons.sort_unstable();
+        beacons.dedup();
+
+        let mut max_distance = 0;
+        for (_, a) in &aligned {
+            for (_, b) in &aligned {
+                max_distance = max_distance.max((0..3).map(|k| (a[k] - b[k]).abs()).sum());
+            }
+        }
+
+        Ok(Self {
+            beacons: beacons.len(),
+            max_distance,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.beacons
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> i32 {
+        self.max_distance
+    }
+
+    fn rotations() -> Vec<[[i32; 3]; 3]> {
+        let mut rotations = Vec::with_capacity(24);
+        for perm in [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]] {
+            // Odd permutations need an odd number of negated axes to avoid reflections
+            let odd = perm == [0, 2, 1] || perm == [1, 0, 2] || perm == [2, 1, 0];
+            for signs in 0..8u32 {
+                if (signs.count_ones() % 2 == 1) != odd {
+                    continue;
+                }
+                let mut matrix = [[0; 3]; 3];
+                for (i, row) in matrix.iter_mut().enumerate() {
+                    row[perm[i]] = if signs & (1 << i) == 0 { 1 } else { -1 };
+                }
+                rotations.push(matrix);
+            }
+        }
+        rotations
+    }
+
+    fn common(a: &[i32], b: &[i32]) -> usize {
+        let (mut i, mut j, mut count) = (0, 0, 0);
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    count += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn align(
+        known: &[Point],
+        other: &[Point],
+        rotations: &[[[i32; 3]; 3]],
+    ) -> Option<(Vec<Point>, Point)> {
+        let mut offsets = HashMap::new();
+        for rotation in rotations {
+            let rotated: Vec<Point> = other
+                .iter()
+                .map(|p| rotation.map(|row| row[0] * p[0] + row[1] * p[1] + row[2] * p[2]))
+                .collect();
+
+            offsets.clear();
+            for a in known {
+                for b in &rotated {
+                    let offset = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+                    let count = offsets.entry(offset).or_insert(0);
+                    *count += 1;
+                    if *count >= 12 {
+                        let beacons = rotated
+                            .iter()
+                            .map(|b| [b[0] + offset[0], b[1] + offset[1], b[2] + offset[2]])
+                            .collect();
+                        return Some((beacons, offset));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+examples!(Day19 -> (usize, i32) [
+    {file: "day19_example0.txt", part1: 63, part2: 2370},
+]);
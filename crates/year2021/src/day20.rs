@@ -0,0 +1,94 @@
+use utils::grid;
+use utils::prelude::*;
+
+/// Enhancing an infinite image.
+///
+/// Pixels outside the image are all the same, so only the background value needs tracking. If the
+/// algorithm maps an empty neighborhood to a lit pixel, the background flips on every step.
+#[derive(Clone, Debug)]
+pub struct Day20 {
+    algorithm: [bool; 512],
+    rows: usize,
+    cols: usize,
+    image: Vec<bool>,
+}
+
+impl Day20 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let Some((algorithm, image)) = input.split_once("\n\n") else {
+            return Err(InputError::new(input, 0, "expected algorithm and image"));
+        };
+
+        let algorithm = parser::literal_map!("." => false, "#" => true)
+            .repeat_n::<512, _>(parser::noop())
+            .parse_complete(algorithm)?;
+        if algorithm[0] && algorithm[511] {
+            return Err(InputError::new(input, 0, "expected background to stay finite"));
+        }
+
+        let (rows, cols, image) = grid::from_str(image, |b| match b {
+            b'.' => Some(false),
+            b'#' => Some(true),
+            _ => None,
+        })?;
+
+        Ok(Self {
+            algorithm,
+            rows,
+            cols,
+            image,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.enhance(2)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.enhance(50)
+    }
+
+    fn enhance(&self, steps: usize) -> usize {
+        let (mut rows, mut cols) = (self.rows, self.cols);
+        let mut image = self.image.clone();
+        let mut background = false;
+
+        for _ in 0..steps {
+            let pixel = |r: usize, c: usize| {
+                // r and c are offset by two, so the new border's neighbors are never negative
+                if r < 2 || c < 2 || r - 2 >= rows || c - 2 >= cols {
+                    background
+                } else {
+                    image[(r - 2) * cols + (c - 2)]
+                }
+            };
+
+            let mut next = Vec::with_capacity((rows + 2) * (cols + 2));
+            for r in 0..rows + 2 {
+                for c in 0..cols + 2 {
+                    let mut index = 0;
+                    for nr in r..r + 3 {
+                        for nc in c..c + 3 {
+                            index = (index << 1) | usize::from(pixel(nr, nc));
+                        }
+                    }
+                    next.push(self.algorithm[index]);
+                }
+            }
+
+            background = self.algorithm[if background { 511 } else { 0 }];
+            image = next;
+            rows += 2;
+            cols += 2;
+        }
+
+        image.iter().filter(|&&b| b).count()
+    }
+}
+
+examples!(Day20 -> (usize, usize) [
+    {file: "day20_example0.txt", part1: 55, part2: 4162},
+    {file: "day20_example1.txt", part1: 55, part2: 3314},
+]);
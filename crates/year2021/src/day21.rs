@@ -0,0 +1,86 @@
+use utils::prelude::*;
+
+/// Playing dice games with deterministic and quantum dice.
+///
+/// Quantum games are counted using memoized recursion over both positions and scores, swapping the
+/// players after each turn so the current player is always first.
+#[derive(Clone, Debug)]
+pub struct Day21 {
+    start: [u32; 2],
+}
+
+// Number of ways to roll each total with three three-sided dice
+const QUANTUM_ROLLS: [(u32, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+impl Day21 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (p1, p2) = parser::number_range(1..=10)
+            .with_prefix("Player 1 starting position: ")
+            .with_suffix(parser::eol())
+            .then(parser::number_range(1..=10).with_prefix("Player 2 starting position: "))
+            .parse_complete(input.trim_ascii_end())?;
+        Ok(Self { start: [p1, p2] })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let mut positions = self.start;
+        let mut scores = [0; 2];
+        let mut rolls = 0;
+        for player in (0..2).cycle() {
+            let total = 3 * (rolls % 100) + 6;
+            rolls += 3;
+            positions[player] = (positions[player] + total - 1) % 10 + 1;
+            scores[player] += positions[player];
+            if scores[player] >= 1000 {
+                return scores[1 - player] * rolls;
+            }
+        }
+        unreachable!()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let mut cache = vec![None; 10 * 10 * 21 * 21];
+        let (a, b) = Self::quantum(self.start[0], self.start[1], 0, 0, &mut cache);
+        a.max(b)
+    }
+
+    fn quantum(
+        pos: u32,
+        other_pos: u32,
+        score: u32,
+        other_score: u32,
+        cache: &mut [Option<(u64, u64)>],
+    ) -> (u64, u64) {
+        let index = ((((pos - 1) * 10 + other_pos - 1) * 21 + score) * 21 + other_score) as usize;
+        if let Some(result) = cache[index] {
+            return result;
+        }
+
+        let (mut wins, mut losses) = (0, 0);
+        for (roll, ways) in QUANTUM_ROLLS {
+            let next_pos = (pos + roll - 1) % 10 + 1;
+            let next_score = score + next_pos;
+            if next_score >= 21 {
+                wins += ways;
+            } else {
+                let (other_wins, other_losses) =
+                    Self::quantum(other_pos, next_pos, other_score, next_score, cache);
+                wins += ways * other_losses;
+                losses += ways * other_wins;
+            }
+        }
+
+        cache[index] = Some((wins, losses));
+        (wins, losses)
+    }
+}
+
+examples!(Day21 -> (u32, u64) [
+    {
+        input: "Player 1 starting position: 4\nPlayer 2 starting position: 8",
+        part1: 739785,
+        part2: 444356092776315,
+    },
+]);
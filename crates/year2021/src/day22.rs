@@ -0,0 +1,94 @@
+use utils::prelude::*;
+
+/// Calculating the volume of overlapping cuboids.
+///
+/// Each cuboid is stored with a sign. Adding a cuboid adds the negated intersection with every
+/// existing cuboid, which cancels out any double counting, and then the cuboid itself if turning
+/// cubes on.
+#[derive(Clone, Debug)]
+pub struct Day22 {
+    steps: Vec<(bool, Cuboid)>,
+}
+
+type Cuboid = [[i32; 2]; 3];
+
+impl Day22 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let range = parser::i32()
+            .with_suffix("..")
+            .then(parser::i32())
+            .map_res(|(min, max)| {
+                if min <= max {
+                    Ok([min, max])
+                } else {
+                    Err("expected range minimum to be less than maximum")
+                }
+            });
+        Ok(Self {
+            steps: parser::literal_map!("on" => true, "off" => false)
+                .then(range.with_prefix(" x="))
+                .then(range.with_prefix(",y="))
+                .then(range.with_prefix(",z="))
+                .map(|(on, x, y, z)| (on, [x, y, z]))
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i64 {
+        let region = [[-50, 50]; 3];
+        Self::volume(
+            self.steps
+                .iter()
+                .filter_map(|&(on, cuboid)| Some((on, Self::intersect(&cuboid, &region)?))),
+        )
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> i64 {
+        Self::volume(self.steps.iter().copied())
+    }
+
+    fn volume(steps: impl Iterator<Item = (bool, Cuboid)>) -> i64 {
+        let mut cuboids: Vec<(Cuboid, i64)> = Vec::new();
+        for (on, cuboid) in steps {
+            for i in 0..cuboids.len() {
+                let (existing, sign) = cuboids[i];
+                if let Some(intersection) = Self::intersect(&existing, &cuboid) {
+                    cuboids.push((intersection, -sign));
+                }
+            }
+            if on {
+                cuboids.push((cuboid, 1));
+            }
+        }
+
+        cuboids
+            .iter()
+            .map(|(c, sign)| sign * c.iter().map(|[a, b]| i64::from(b - a + 1)).product::<i64>())
+            .sum()
+    }
+
+    fn intersect(a: &Cuboid, b: &Cuboid) -> Option<Cuboid> {
+        let mut result = [[0; 2]; 3];
+        for (r, (a, b)) in result.iter_mut().zip(a.iter().zip(b)) {
+            *r = [a[0].max(b[0]), a[1].min(b[1])];
+            if r[0] > r[1] {
+                return None;
+            }
+        }
+        Some(result)
+    }
+}
+
+examples!(Day22 -> (i64, i64) [
+    {
+        input: "on x=10..12,y=10..12,z=10..12\n\
+            on x=11..13,y=11..13,z=11..13\n\
+            off x=9..11,y=9..11,z=9..11\n\
+            on x=10..10,y=10..10,z=10..10",
+        part1: 39,
+        part2: 39,
+    },
+    {file: "day22_example1.txt", part1: 12338, part2: 93147859588035},
+]);
@@ -0,0 +1,165 @@
+use utils::graph::dijkstra;
+use utils::prelude::*;
+
+/// Finding the cheapest way to sort amphipods into rooms.
+///
+/// Amphipods only ever move from a room into the hallway, or from the hallway into their own room,
+/// as moving directly between rooms costs the same as stopping in the hallway in between.
+#[derive(Clone, Debug)]
+pub struct Day23 {
+    rooms: [[u8; 2]; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State<const D: usize> {
+    hallway: [u8; 11],
+    rooms: [[u8; D]; 4],
+}
+
+const EMPTY: u8 = 0;
+const STOPS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+const ENERGY: [u32; 4] = [1, 10, 100, 1000];
+
+impl Day23 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let amphipod = parser::literal_map!("A" => 1u8, "B" => 2, "C" => 3, "D" => 4);
+        let row = amphipod.repeat_n::<4, _>(b'#');
+        let (top, bottom) = row
+            .with_prefix("#############\n#...........#\n###")
+            .with_suffix("###\n  #")
+            .then(row.with_suffix("#\n  #########"))
+            .parse_complete(input.trim_ascii_end())?;
+
+        for amphipod in 1..=4 {
+            if top.iter().chain(&bottom).filter(|&&a| a == amphipod).count() != 2 {
+                return Err(InputError::new(
+                    input,
+                    0,
+                    "expected two amphipods of each type",
+                ));
+            }
+        }
+
+        Ok(Self {
+            rooms: [0, 1, 2, 3].map(|i| [top[i], bottom[i]]),
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        Self::organize(self.rooms)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        //   #D#C#B#A#
+        //   #D#B#A#C#
+        let inserted = [[4, 4], [3, 2], [2, 1], [1, 3]];
+        Self::organize([0, 1, 2, 3].map(|i| {
+            [
+                self.rooms[i][0],
+                inserted[i][0],
+                inserted[i][1],
+                self.rooms[i][1],
+            ]
+        }))
+    }
+
+    fn organize<const D: usize>(rooms: [[u8; D]; 4]) -> u32 {
+        let start = State {
+            hallway: [EMPTY; 11],
+            rooms,
+        };
+        dijkstra(start, State::moves, State::is_organized)
+            .expect("amphipods should be able to organize")
+            .0
+    }
+}
+
+impl<const D: usize> State<D> {
+    fn is_organized(&self) -> bool {
+        self.rooms
+            .iter()
+            .enumerate()
+            .all(|(i, room)| room.iter().all(|&a| a == i as u8 + 1))
+    }
+
+    fn moves(&self) -> Vec<(Self, u32)> {
+        let mut moves = Vec::new();
+
+        // Hallway to own room
+        for h in STOPS {
+            let amphipod = self.hallway[h];
+            if amphipod == EMPTY {
+                continue;
+            }
+            let room = (amphipod - 1) as usize;
+            let door = 2 + 2 * room;
+            if !self.rooms[room].iter().all(|&a| a == EMPTY || a == amphipod)
+                || !self.hallway_clear(h, door)
+            {
+                continue;
+            }
+
+            let depth = self.rooms[room].iter().rposition(|&a| a == EMPTY).unwrap();
+            let mut next = self.clone();
+            next.hallway[h] = EMPTY;
+            next.rooms[room][depth] = amphipod;
+            let steps = (h.abs_diff(door) + depth + 1) as u32;
+            moves.push((next, steps * ENERGY[room]));
+        }
+
+        // Moving directly into a room is always optimal, so skip other moves
+        if !moves.is_empty() {
+            moves.truncate(1);
+            return moves;
+        }
+
+        // Room to hallway
+        for (room, slots) in self.rooms.iter().enumerate() {
+            let Some(depth) = slots.iter().position(|&a| a != EMPTY) else {
+                continue;
+            };
+            if slots[depth..].iter().all(|&a| a == room as u8 + 1) {
+                continue;
+            }
+
+            let amphipod = slots[depth];
+            let door = 2 + 2 * room;
+            for h in STOPS {
+                if self.hallway[h] != EMPTY || !self.hallway_clear(door, h) {
+                    continue;
+                }
+                let mut next = self.clone();
+                next.rooms[room][depth] = EMPTY;
+                next.hallway[h] = amphipod;
+                let steps = (h.abs_diff(door) + depth + 1) as u32;
+                moves.push((next, steps * ENERGY[(amphipod - 1) as usize]));
+            }
+        }
+
+        moves
+    }
+
+    // Check the hallway between from (exclusive) and to (inclusive) is empty
+    fn hallway_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[range].iter().all(|&a| a == EMPTY)
+    }
+}
+
+examples!(Day23 -> (u32, u32) [
+    {
+        input: "#############\n\
+            #...........#\n\
+            ###B#C#B#D###\n  \
+              #A#D#C#A#\n  \
+              #########",
+        part1: 12521,
+        part2: 44169,
+    },
+]);
@@ -0,0 +1,77 @@
+use utils::prelude::*;
+
+/// Finding valid model numbers by analysing the program.
+///
+/// The program is 14 near-identical blocks which use `z` as a base 26 stack. Blocks dividing by 1
+/// push `w + b`, and blocks dividing by 26 pop a value and only avoid pushing again if the
+/// popped value plus `a` equals their input digit. For `z` to end as zero every pop must match,
+/// which pairs each pushing digit `w[i]` with a popping digit `w[j] = w[i] + b[i] + a[j]`. Each pair
+/// can then be maximized or minimized independently.
+#[derive(Clone, Debug)]
+pub struct Day24 {
+    // (push digit, pop digit, offset) where w[pop] = w[push] + offset
+    pairs: Vec<(usize, usize, i32)>,
+}
+
+impl Day24 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let blocks = parser::literal_map!("26" => true, "1" => false)
+            .with_prefix("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z ")
+            .then(parser::i32().with_prefix("\nadd x "))
+            .then(
+                parser::i32()
+                    .with_prefix(
+                        "\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\n\
+                        mul y 0\nadd y w\nadd y ",
+                    )
+                    .with_suffix("\nmul y x\nadd z y"),
+            )
+            .repeat_n::<14, _>(parser::eol())
+            .parse_complete(input.trim_ascii_end())?;
+
+        let mut stack: Vec<(usize, i32)> = Vec::new();
+        let mut pairs = Vec::new();
+        for (i, &(pop, a, b)) in blocks.iter().enumerate() {
+            if pop {
+                let Some((push, offset)) = stack.pop() else {
+                    return Err(InputError::new(input, 0, "expected push before each pop"));
+                };
+                let offset = offset + a;
+                if offset.abs() > 8 {
+                    return Err(InputError::new(input, 0, "expected satisfiable digit pairs"));
+                }
+                pairs.push((push, i, offset));
+            } else {
+                if a < 10 {
+                    return Err(InputError::new(input, 0, "expected push blocks to never match"));
+                }
+                stack.push((i, b));
+            }
+        }
+        if !stack.is_empty() {
+            return Err(InputError::new(input, 0, "expected balanced pushes and pops"));
+        }
+
+        Ok(Self { pairs })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.model_number(|offset| (9 - offset.max(0), 9 + offset.min(0)))
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.model_number(|offset| (1 - offset.min(0), 1 + offset.max(0)))
+    }
+
+    fn model_number(&self, digits: impl Fn(i32) -> (i32, i32)) -> u64 {
+        let mut number = [0; 14];
+        for &(push, pop, offset) in &self.pairs {
+            (number[push], number[pop]) = digits(offset);
+        }
+        number.iter().fold(0, |acc, &d| acc * 10 + d as u64)
+    }
+}
+
+examples!(Day24 -> (u64, u64) []);
@@ -0,0 +1,73 @@
+use utils::grid;
+use utils::prelude::*;
+
+/// Simulating two herds of sea cucumbers.
+#[derive(Clone, Debug)]
+pub struct Day25 {
+    rows: usize,
+    cols: usize,
+    grid: Vec<u8>,
+}
+
+impl Day25 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, grid) = grid::from_str(input, |b| match b {
+            b'.' | b'>' | b'v' => Some(b),
+            _ => None,
+        })?;
+        Ok(Self { rows, cols, grid })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        let mut grid = self.grid.clone();
+        let mut next = grid.clone();
+        let mut steps = 1;
+        loop {
+            let moved_east = self.step(&grid, &mut next, b'>', |r, c| (r, (c + 1) % self.cols));
+            let moved_south = self.step(&next, &mut grid, b'v', |r, c| ((r + 1) % self.rows, c));
+            if !moved_east && !moved_south {
+                return steps;
+            }
+            steps += 1;
+        }
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> &'static str {
+        "🎄"
+    }
+
+    fn step(
+        &self,
+        from: &[u8],
+        to: &mut [u8],
+        herd: u8,
+        target: impl Fn(usize, usize) -> (usize, usize),
+    ) -> bool {
+        to.copy_from_slice(from);
+        let mut moved = false;
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                if from[r * self.cols + c] != herd {
+                    continue;
+                }
+                let (tr, tc) = target(r, c);
+                if from[tr * self.cols + tc] == b'.' {
+                    to[r * self.cols + c] = b'.';
+                    to[tr * self.cols + tc] = herd;
+                    moved = true;
+                }
+            }
+        }
+        moved
+    }
+}
+
+examples!(Day25 -> (u32, &'static str) [
+    {
+        input: "v...>>.vv>\n.vv>>.vv..\n>>.>v>...v\n>>v>>.>.v.\nv>v.vv.v..\n\
+            >.>>..v...\n.vv..>.>v.\nv.v..>>v.v\n....v..v.>",
+        part1: 58,
+    },
+]);
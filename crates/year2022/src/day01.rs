@@ -0,0 +1,41 @@
+use utils::prelude::*;
+
+/// Summing the calories carried by the top elves.
+#[derive(Clone, Debug)]
+pub struct Day01 {
+    // Sorted in descending order
+    totals: Vec<u32>,
+}
+
+impl Day01 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut totals = input
+            .trim_ascii_end()
+            .split("\n\n")
+            .map(|elf| Ok(parser::u32().parse_lines(elf)?.iter().sum()))
+            .collect::<Result<Vec<u32>, InputError>>()?;
+        if totals.len() < 3 {
+            return Err(InputError::new(input, 0, "expected at least 3 elves"));
+        }
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(Self { totals })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.totals[0]
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.totals[..3].iter().sum()
+    }
+}
+
+examples!(Day01 -> (u32, u32) [
+    {
+        input: "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000",
+        part1: 24000,
+        part2: 45000,
+    },
+]);
@@ -0,0 +1,46 @@
+use utils::prelude::*;
+
+/// Scoring rock paper scissors strategies.
+///
+/// Shapes are numbered 0 to 2, so the shape each shape beats is one less modulo 3.
+#[derive(Clone, Debug)]
+pub struct Day02 {
+    rounds: Vec<(u32, u32)>,
+}
+
+impl Day02 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            rounds: parser::literal_map!("A" => 0, "B" => 1, "C" => 2)
+                .with_suffix(b' ')
+                .then(parser::literal_map!("X" => 0, "Y" => 1, "Z" => 2))
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.rounds
+            .iter()
+            .map(|&(theirs, ours)| Self::score(theirs, ours))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.rounds
+            .iter()
+            .map(|&(theirs, outcome)| Self::score(theirs, (theirs + outcome + 2) % 3))
+            .sum()
+    }
+
+    fn score(theirs: u32, ours: u32) -> u32 {
+        // 0 = loss, 1 = draw, 2 = win
+        let outcome = (ours + 4 - theirs) % 3;
+        ours + 1 + outcome * 3
+    }
+}
+
+examples!(Day02 -> (u32, u32) [
+    {input: "A Y\nB X\nC Z", part1: 15, part2: 12},
+]);
@@ -0,0 +1,80 @@
+use utils::prelude::*;
+
+/// Finding items shared between rucksack compartments and groups.
+///
+/// Each set of items is stored as a bitmask of priorities, so finding shared items is a bitwise
+/// AND.
+#[derive(Clone, Debug)]
+pub struct Day03 {
+    rucksacks: Vec<(u64, u64)>,
+}
+
+impl Day03 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let rucksacks = parser::take_while1(u8::is_ascii_alphabetic)
+            .map_res(|items| {
+                if items.len() % 2 != 0 {
+                    return Err("expected even number of items");
+                }
+                let (a, b) = items.split_at(items.len() / 2);
+                Ok((Self::mask(a), Self::mask(b)))
+            })
+            .parse_lines(input)?;
+        if rucksacks.len() % 3 != 0 {
+            return Err(InputError::new(
+                input,
+                0,
+                "expected number of rucksacks to be a multiple of 3",
+            ));
+        }
+        Ok(Self { rucksacks })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.rucksacks
+            .iter()
+            .map(|&(a, b)| Self::priority(a & b))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.rucksacks
+            .chunks_exact(3)
+            .map(|group| {
+                let shared = group.iter().map(|&(a, b)| a | b).fold(!0, |acc, m| acc & m);
+                Self::priority(shared)
+            })
+            .sum()
+    }
+
+    fn mask(items: &[u8]) -> u64 {
+        items.iter().fold(0, |acc, &b| {
+            let priority = if b.is_ascii_lowercase() {
+                b - b'a' + 1
+            } else {
+                b - b'A' + 27
+            };
+            acc | (1 << priority)
+        })
+    }
+
+    fn priority(mask: u64) -> u32 {
+        // Sum in case there are multiple shared items
+        (0..64).filter(|&i| mask & (1 << i) != 0).sum()
+    }
+}
+
+examples!(Day03 -> (u32, u32) [
+    {
+        input: "vJrwpWtwJgWrhcsFMMfFFhFp\n\
+            jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n\
+            PmmdzqPrVvPwwTWBwg\n\
+            wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n\
+            ttgJtRGJQctTZtZT\n\
+            CrZsJsPPZsGzwwsLwLmpwMDw",
+        part1: 157,
+        part2: 70,
+    },
+]);
@@ -0,0 +1,49 @@
+use utils::prelude::*;
+
+/// Counting overlapping section assignments.
+#[derive(Clone, Debug)]
+pub struct Day04 {
+    pairs: Vec<[u32; 4]>,
+}
+
+impl Day04 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let range = parser::u32()
+            .with_suffix(b'-')
+            .then(parser::u32())
+            .map_res(|(a, b)| {
+                if a <= b {
+                    Ok([a, b])
+                } else {
+                    Err("expected range start to be less than end")
+                }
+            });
+        Ok(Self {
+            pairs: range
+                .with_suffix(b',')
+                .then(range)
+                .map(|([a, b], [c, d])| [a, b, c, d])
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|&&[a, b, c, d]| (a <= c && d <= b) || (c <= a && b <= d))
+            .count()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|&&[a, b, c, d]| a <= d && c <= b)
+            .count()
+    }
+}
+
+examples!(Day04 -> (usize, usize) [
+    {input: "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8", part1: 2, part2: 4},
+]);
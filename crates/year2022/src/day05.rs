@@ -0,0 +1,75 @@
+use utils::prelude::*;
+
+/// Rearranging stacks of crates.
+#[derive(Clone, Debug)]
+pub struct Day05 {
+    stacks: Vec<Vec<u8>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+impl Day05 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let Some((drawing, moves)) = input.split_once("\n\n") else {
+            return Err(InputError::new(input, 0, "expected drawing and moves"));
+        };
+
+        let mut lines = drawing.lines().rev();
+        let Some(labels) = lines.next() else {
+            return Err(InputError::new(input, 0, "expected stack labels"));
+        };
+        let count = labels.split_ascii_whitespace().count();
+        if count == 0 || count > 9 {
+            return Err(InputError::new(input, labels, "expected 1-9 stacks"));
+        }
+
+        let mut stacks = vec![Vec::new(); count];
+        for line in lines {
+            for (i, &b) in line.as_bytes().iter().skip(1).step_by(4).enumerate() {
+                match b {
+                    b' ' => {}
+                    b'A'..=b'Z' if i < count => stacks[i].push(b),
+                    _ => return Err(InputError::new(input, line, "invalid crate")),
+                }
+            }
+        }
+
+        let stack = parser::number_range(1..=count).map(|i| i - 1);
+        let moves = parser::number_range(1..=usize::MAX)
+            .with_prefix("move ")
+            .then(stack.with_prefix(" from "))
+            .then(stack.with_prefix(" to "))
+            .parse_lines(moves)?;
+
+        Ok(Self { stacks, moves })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> String {
+        self.rearrange(true)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> String {
+        self.rearrange(false)
+    }
+
+    fn rearrange(&self, reverse: bool) -> String {
+        let mut stacks = self.stacks.clone();
+        for &(n, from, to) in &self.moves {
+            let split = stacks[from].len().saturating_sub(n);
+            let mut crates = stacks[from].split_off(split);
+            if reverse {
+                crates.reverse();
+            }
+            stacks[to].extend(crates);
+        }
+        stacks
+            .iter()
+            .filter_map(|s| s.last().map(|&b| b as char))
+            .collect()
+    }
+}
+
+examples!(Day05 -> (&'static str, &'static str) [
+    {file: "day05_example0.txt", part1: "CMZ", part2: "MCD"},
+]);
@@ -0,0 +1,54 @@
+use utils::prelude::*;
+
+/// Finding the first run of distinct characters.
+///
+/// Tracks the last position each character was seen, so when a repeat is found the window can skip
+/// straight past the previous occurrence.
+#[derive(Clone, Debug)]
+pub struct Day06<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Day06<'a> {
+    pub fn new(input: &'a str, _: InputType) -> Result<Self, InputError> {
+        let input = input.trim_ascii_end();
+        if let Some(b) = input.bytes().find(|b| !b.is_ascii_lowercase()) {
+            return Err(InputError::new(input, b as char, "expected lowercase letter"));
+        }
+        Ok(Self {
+            input: input.as_bytes(),
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.marker(4)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.marker(14)
+    }
+
+    fn marker(&self, length: usize) -> usize {
+        let mut last_seen = [0usize; 26];
+        let mut start = 0;
+        for (i, &b) in self.input.iter().enumerate() {
+            let last = &mut last_seen[(b - b'a') as usize];
+            start = start.max(*last);
+            *last = i + 1;
+            if i + 1 - start == length {
+                return i + 1;
+            }
+        }
+        panic!("no marker found");
+    }
+}
+
+examples!(Day06<'_> -> (usize, usize) [
+    {input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb", part1: 7, part2: 19},
+    {input: "bvwbjplbgvbhsrlpgdmjqwftvncz", part1: 5, part2: 23},
+    {input: "nppdvjthqldpwncqszvftbrmjlhg", part1: 6, part2: 23},
+    {input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", part1: 10, part2: 29},
+    {input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", part1: 11, part2: 26},
+]);
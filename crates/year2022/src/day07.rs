@@ -0,0 +1,71 @@
+use utils::prelude::*;
+
+/// Calculating directory sizes from terminal output.
+///
+/// Assumes each directory is only listed once, and only traversed using `cd` to a child, `cd ..`
+/// or `cd /`. This means directory sizes can be calculated using a stack of the current path's
+/// sizes, without storing the tree.
+#[derive(Clone, Debug)]
+pub struct Day07 {
+    sizes: Vec<u32>,
+}
+
+impl Day07 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let file = parser::u32()
+            .with_suffix(b' ')
+            .with_suffix(parser::take_while1(|&b| b != b'\n' && b != b'\r'));
+
+        let mut sizes = Vec::new();
+        let mut parents = Vec::new();
+        let mut current = 0;
+        for line in input.lines() {
+            if line == "$ cd /" {
+                while let Some(parent) = parents.pop() {
+                    sizes.push(current);
+                    current += parent;
+                }
+            } else if line == "$ cd .." {
+                let Some(parent) = parents.pop() else {
+                    return Err(InputError::new(input, line, "expected directory"));
+                };
+                sizes.push(current);
+                current += parent;
+            } else if line.starts_with("$ cd ") {
+                parents.push(current);
+                current = 0;
+            } else if line != "$ ls" && !line.starts_with("dir ") {
+                current += file.parse_complete(line)?;
+            }
+        }
+        while let Some(parent) = parents.pop() {
+            sizes.push(current);
+            current += parent;
+        }
+        sizes.push(current);
+
+        Ok(Self { sizes })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.sizes.iter().filter(|&&s| s <= 100_000).sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        // The root directory is always last
+        let used = self.sizes[self.sizes.len() - 1];
+        let required = (used + 30_000_000).saturating_sub(70_000_000);
+        self.sizes
+            .iter()
+            .copied()
+            .filter(|&s| s >= required)
+            .min()
+            .unwrap()
+    }
+}
+
+examples!(Day07 -> (u32, u32) [
+    {file: "day07_example0.txt", part1: 95437, part2: 24933642},
+]);
@@ -0,0 +1,70 @@
+use utils::grid;
+use utils::prelude::*;
+
+/// Finding visible trees and the best scenic score.
+#[derive(Clone, Debug)]
+pub struct Day08 {
+    rows: usize,
+    cols: usize,
+    heights: Vec<u8>,
+}
+
+impl Day08 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, heights) = grid::from_str(input, |b| match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            _ => None,
+        })?;
+        Ok(Self {
+            rows,
+            cols,
+            heights,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        (0..self.heights.len())
+            .filter(|&i| self.views(i).any(|(_, visible)| visible))
+            .count()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        (0..self.heights.len())
+            .map(|i| self.views(i).map(|(distance, _)| distance).product())
+            .max()
+            .unwrap()
+    }
+
+    // Returns the viewing distance in each direction, and whether the tree is visible from the edge
+    fn views(&self, index: usize) -> impl Iterator<Item = (usize, bool)> + '_ {
+        let (r, c) = (index / self.cols, index % self.cols);
+        let height = self.heights[index];
+        [(-1isize, 0isize), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .map(move |(dr, dc)| {
+                let (mut r, mut c) = (r, c);
+                let mut distance = 0;
+                loop {
+                    let (Some(nr), Some(nc)) =
+                        (r.checked_add_signed(dr), c.checked_add_signed(dc))
+                    else {
+                        return (distance, true);
+                    };
+                    if nr >= self.rows || nc >= self.cols {
+                        return (distance, true);
+                    }
+                    distance += 1;
+                    if self.heights[nr * self.cols + nc] >= height {
+                        return (distance, false);
+                    }
+                    (r, c) = (nr, nc);
+                }
+            })
+    }
+}
+
+examples!(Day08 -> (usize, usize) [
+    {input: "30373\n25512\n65332\n33549\n35390", part1: 21, part2: 8},
+]);
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+use utils::point::Point2D;
+use utils::prelude::*;
+
+/// Simulating a rope with multiple knots.
+#[derive(Clone, Debug)]
+pub struct Day09 {
+    moves: Vec<(Point2D<i32>, u32)>,
+}
+
+impl Day09 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            moves: parser::literal_map!(
+                "U " => Point2D::UP,
+                "D " => Point2D::DOWN,
+                "L " => Point2D::LEFT,
+                "R " => Point2D::RIGHT,
+            )
+            .then(parser::u32())
+            .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.simulate::<2>()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.simulate::<10>()
+    }
+
+    fn simulate<const N: usize>(&self) -> usize {
+        let mut knots = [Point2D::ORIGIN; N];
+        let mut visited = HashSet::new();
+        visited.insert(Point2D::ORIGIN);
+
+        for &(direction, steps) in &self.moves {
+            for _ in 0..steps {
+                knots[0] += direction;
+                for i in 1..N {
+                    let diff = knots[i - 1] - knots[i];
+                    if diff.x.abs() <= 1 && diff.y.abs() <= 1 {
+                        break;
+                    }
+                    knots[i] += Point2D::new(diff.x.signum(), diff.y.signum());
+                }
+                visited.insert(knots[N - 1]);
+            }
+        }
+
+        visited.len()
+    }
+}
+
+examples!(Day09 -> (usize, usize) [
+    {input: "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2", part1: 13, part2: 1},
+    {input: "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20", part2: 36},
+]);
@@ -0,0 +1,71 @@
+use utils::ocr::Letters;
+use utils::prelude::*;
+
+/// Simulating a CPU driving a CRT display.
+#[derive(Clone, Debug)]
+pub struct Day10 {
+    // Value of the X register during each of the 240 cycles
+    x: [i32; 240],
+}
+
+impl Day10 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut x = [0; 240];
+        let mut cycle = 0;
+        let mut register = 1;
+        for item in parser::i32()
+            .with_prefix("addx ")
+            .map(Some)
+            .or(parser::literal_map!("noop" => None))
+            .with_suffix(parser::eol())
+            .parse_iterator(input)
+        {
+            if cycle >= 240 {
+                break;
+            }
+            match item? {
+                Some(v) => {
+                    x[cycle] = register;
+                    if cycle + 1 < 240 {
+                        x[cycle + 1] = register;
+                    }
+                    cycle += 2;
+                    register += v;
+                }
+                None => {
+                    x[cycle] = register;
+                    cycle += 1;
+                }
+            }
+        }
+        if cycle < 240 {
+            return Err(InputError::new(input, 0, "expected at least 240 cycles"));
+        }
+
+        Ok(Self { x })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i32 {
+        (20..=220)
+            .step_by(40)
+            .map(|cycle| cycle as i32 * self.x[cycle - 1])
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> Letters {
+        Letters::new(
+            40,
+            6,
+            self.x
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| ((i % 40) as i32 - x).abs() <= 1),
+        )
+    }
+}
+
+examples!(Day10 -> (i32, &'static str) [
+    {file: "day10_example0.txt", part1: 24020},
+]);
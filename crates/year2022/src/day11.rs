@@ -0,0 +1,115 @@
+use utils::prelude::*;
+
+/// Simulating monkeys throwing items.
+///
+/// Every test divides the worry level, so worry levels can be kept modulo the product of the
+/// divisors without affecting where items are thrown.
+#[derive(Clone, Debug)]
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+#[derive(Clone, Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+impl Day11 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let operation = parser::one_of((
+            "* old".map(|()| Operation::Square),
+            parser::u64().with_prefix("* ").map(Operation::Mul),
+            parser::u64().with_prefix("+ ").map(Operation::Add),
+        ));
+        let monkeys = parser::u32()
+            .with_prefix("Monkey ")
+            .with_suffix(":\n  Starting items: ")
+            .then(parser::u64().repeat(", ", 0))
+            .then(operation.with_prefix("\n  Operation: new = old "))
+            .then(parser::u64().with_prefix("\n  Test: divisible by "))
+            .then(parser::u32().with_prefix("\n    If true: throw to monkey "))
+            .then(parser::u32().with_prefix("\n    If false: throw to monkey "))
+            .repeat("\n\n", 2)
+            .parse_complete(input.trim_ascii_end())?;
+
+        let count = monkeys.len();
+        Ok(Self {
+            monkeys: monkeys
+                .into_iter()
+                .enumerate()
+                .map(|(i, (index, items, operation, divisor, if_true, if_false))| {
+                    let (if_true, if_false) = (if_true as usize, if_false as usize);
+                    if index as usize != i {
+                        Err(InputError::new(input, 0, format!("expected monkey {i}")))
+                    } else if divisor == 0 {
+                        Err(InputError::new(input, 0, "expected non-zero divisor"))
+                    } else if if_true >= count || if_false >= count || if_true == i || if_false == i
+                    {
+                        Err(InputError::new(input, 0, "invalid monkey target"))
+                    } else {
+                        Ok(Monkey {
+                            items,
+                            operation,
+                            divisor,
+                            if_true,
+                            if_false,
+                        })
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.simulate(20, |w| w / 3)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let modulus: u64 = self.monkeys.iter().map(|m| m.divisor).product();
+        self.simulate(10000, |w| w % modulus)
+    }
+
+    fn simulate(&self, rounds: usize, relief: impl Fn(u64) -> u64) -> u64 {
+        let mut items: Vec<Vec<u64>> = self.monkeys.iter().map(|m| m.items.clone()).collect();
+        let mut inspected = vec![0u64; self.monkeys.len()];
+
+        for _ in 0..rounds {
+            for (i, monkey) in self.monkeys.iter().enumerate() {
+                inspected[i] += items[i].len() as u64;
+                for worry in std::mem::take(&mut items[i]) {
+                    let worry = relief(match monkey.operation {
+                        Operation::Add(n) => worry + n,
+                        Operation::Mul(n) => worry * n,
+                        Operation::Square => worry * worry,
+                    });
+                    let target = if worry % monkey.divisor == 0 {
+                        monkey.if_true
+                    } else {
+                        monkey.if_false
+                    };
+                    items[target].push(worry);
+                }
+            }
+        }
+
+        inspected.sort_unstable_by(|a, b| b.cmp(a));
+        inspected[0] * inspected[1]
+    }
+}
+
+examples!(Day11 -> (u64, u64) [
+    {file: "day11_example0.txt", part1: 10605, part2: 2713310158},
+]);
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+use utils::grid;
+use utils::prelude::*;
+
+/// Finding the shortest path up a hill.
+///
+/// Searches backwards from the end, so both parts can use the same breadth-first search.
+#[derive(Clone, Debug)]
+pub struct Day12 {
+    rows: usize,
+    cols: usize,
+    heights: Vec<u8>,
+    start: usize,
+    end: usize,
+}
+
+impl Day12 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, mut heights) = grid::from_str(input, |b| match b {
+            b'a'..=b'z' | b'S' | b'E' => Some(b),
+            _ => None,
+        })?;
+
+        let Some(start) = heights.iter().position(|&b| b == b'S') else {
+            return Err(InputError::new(input, 0, "expected start"));
+        };
+        let Some(end) = heights.iter().position(|&b| b == b'E') else {
+            return Err(InputError::new(input, 0, "expected end"));
+        };
+        heights[start] = b'a';
+        heights[end] = b'z';
+        if heights.contains(&b'S') || heights.contains(&b'E') {
+            return Err(InputError::new(input, 0, "expected one start and end"));
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            heights,
+            start,
+            end,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.shortest(|i| i == self.start)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.shortest(|i| self.heights[i] == b'a')
+    }
+
+    fn shortest(&self, is_goal: impl Fn(usize) -> bool) -> u32 {
+        let mut visited = vec![false; self.heights.len()];
+        let mut queue = VecDeque::new();
+        visited[self.end] = true;
+        queue.push_back((self.end, 0));
+
+        while let Some((i, steps)) = queue.pop_front() {
+            if is_goal(i) {
+                return steps;
+            }
+
+            let (r, c) = (i / self.cols, i % self.cols);
+            let neighbors = [
+                (r > 0).then(|| i - self.cols),
+                (r + 1 < self.rows).then(|| i + self.cols),
+                (c > 0).then(|| i - 1),
+                (c + 1 < self.cols).then(|| i + 1),
+            ];
+            for next in neighbors.into_iter().flatten() {
+                if !visited[next] && self.heights[next] + 1 >= self.heights[i] {
+                    visited[next] = true;
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        panic!("no path found");
+    }
+}
+
+examples!(Day12 -> (u32, u32) [
+    {input: "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi", part1: 31, part2: 29},
+]);
@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+use utils::prelude::*;
+
+/// Comparing nested lists.
+///
+/// Part 2 only needs the positions of the divider packets, which can be found by counting the
+/// packets that sort before each divider rather than sorting the list.
+#[derive(Clone, Debug)]
+pub struct Day13 {
+    packets: Vec<Packet>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Day13 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut packets = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            if i % 3 == 2 {
+                if !line.is_empty() {
+                    return Err(InputError::new(input, line, "expected blank line"));
+                }
+                continue;
+            }
+
+            let mut bytes = line.as_bytes();
+            let packet = Packet::parse(&mut bytes).map_err(|e| InputError::new(input, line, e))?;
+            if !matches!(packet, Packet::List(_)) || !bytes.is_empty() {
+                return Err(InputError::new(input, line, "expected single list"));
+            }
+            packets.push(packet);
+        }
+        if packets.len() % 2 != 0 {
+            return Err(InputError::new(input, 0, "expected pairs of packets"));
+        }
+
+        Ok(Self { packets })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.packets
+            .chunks_exact(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Integer(n)])]);
+        let (first, second) = (divider(2), divider(6));
+        let before_first = self.packets.iter().filter(|&p| p < &first).count();
+        let before_second = self.packets.iter().filter(|&p| p < &second).count();
+        (before_first + 1) * (before_second + 2)
+    }
+}
+
+impl Packet {
+    fn parse(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+        match bytes {
+            [b'[', b']', rest @ ..] => {
+                *bytes = rest;
+                Ok(Packet::List(Vec::new()))
+            }
+            [b'[', rest @ ..] => {
+                *bytes = rest;
+                let mut list = Vec::new();
+                loop {
+                    list.push(Self::parse(bytes)?);
+                    match bytes {
+                        [b',', rest @ ..] => *bytes = rest,
+                        [b']', rest @ ..] => {
+                            *bytes = rest;
+                            return Ok(Packet::List(list));
+                        }
+                        _ => return Err("expected ',' or ']'"),
+                    }
+                }
+            }
+            [b'0'..=b'9', ..] => {
+                let mut value = 0u32;
+                while let [b @ b'0'..=b'9', rest @ ..] = bytes {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(u32::from(*b - b'0')))
+                        .ok_or("integer too large")?;
+                    *bytes = rest;
+                }
+                Ok(Packet::Integer(value))
+            }
+            _ => Err("expected integer or list"),
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Integer(a), Packet::List(b)) => [Packet::Integer(*a)].as_slice().cmp(b),
+            (Packet::List(a), Packet::Integer(b)) => a.as_slice().cmp(&[Packet::Integer(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+examples!(Day13 -> (usize, usize) [
+    {file: "day13_example0.txt", part1: 13, part2: 140},
+]);
@@ -0,0 +1,87 @@
+use utils::prelude::*;
+
+/// Simulating falling sand.
+///
+/// Each grain follows the same path as the previous grain until the point it came to rest, so
+/// the path is kept as a stack and each new grain starts from the end of it.
+#[derive(Clone, Debug)]
+pub struct Day14 {
+    blocked: Vec<bool>,
+    max_y: usize,
+}
+
+const WIDTH: usize = 1000;
+const SOURCE_X: usize = 500;
+
+impl Day14 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let paths = parser::number_range(0..=WIDTH - 1)
+            .with_suffix(b',')
+            .then(parser::number_range(0..=WIDTH / 2 - 3))
+            .repeat(" -> ", 1)
+            .parse_lines(input)?;
+
+        let Some(max_y) = paths.iter().flatten().map(|&(_, y)| y).max() else {
+            return Err(InputError::new(input, 0, "expected rock"));
+        };
+
+        let mut blocked = vec![false; WIDTH * (max_y + 2)];
+        for path in &paths {
+            for pair in path.windows(2) {
+                let [(x1, y1), (x2, y2)] = [pair[0], pair[1]];
+                if x1 != x2 && y1 != y2 {
+                    return Err(InputError::new(input, 0, "expected straight lines"));
+                }
+                for y in y1.min(y2)..=y1.max(y2) {
+                    for x in x1.min(x2)..=x1.max(x2) {
+                        blocked[y * WIDTH + x] = true;
+                    }
+                }
+            }
+        }
+
+        Ok(Self { blocked, max_y })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.simulate(false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> usize {
+        self.simulate(true)
+    }
+
+    fn simulate(&self, floor: bool) -> usize {
+        let mut blocked = self.blocked.clone();
+        let mut path = vec![SOURCE_X];
+        let mut count = 0;
+
+        'grains: while let Some(&i) = path.last() {
+            let y = i / WIDTH;
+            if y == self.max_y + 1 {
+                if !floor {
+                    break;
+                }
+            } else {
+                for next in [i + WIDTH, i + WIDTH - 1, i + WIDTH + 1] {
+                    if !blocked[next] {
+                        path.push(next);
+                        continue 'grains;
+                    }
+                }
+            }
+
+            blocked[i] = true;
+            path.pop();
+            count += 1;
+        }
+
+        count
+    }
+}
+
+examples!(Day14 -> (usize, usize) [
+    {input: "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9", part1: 24, part2: 93},
+]);
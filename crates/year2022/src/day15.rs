@@ -0,0 +1,116 @@
+use utils::prelude::*;
+
+/// Finding the one position not covered by any sensor.
+///
+/// Part 2 assumes the distress beacon isn't on the edge of the search area. It must then be just
+/// outside the range of several sensors, which places it on the intersection of two diagonal
+/// boundary lines, so only those intersections need checking.
+#[derive(Clone, Debug)]
+pub struct Day15 {
+    sensors: Vec<Sensor>,
+    row: i64,
+    max: i64,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Sensor {
+    x: i64,
+    y: i64,
+    beacon_x: i64,
+    beacon_y: i64,
+    range: i64,
+}
+
+impl Day15 {
+    pub fn new(input: &str, input_type: InputType) -> Result<Self, InputError> {
+        let sensors = parser::i64()
+            .with_prefix("Sensor at x=")
+            .then(parser::i64().with_prefix(", y="))
+            .then(parser::i64().with_prefix(": closest beacon is at x="))
+            .then(parser::i64().with_prefix(", y="))
+            .map(|(x, y, beacon_x, beacon_y)| Sensor {
+                x,
+                y,
+                beacon_x,
+                beacon_y,
+                range: (x - beacon_x).abs() + (y - beacon_y).abs(),
+            })
+            .parse_lines(input)?;
+
+        let (row, max) = match input_type {
+            InputType::Example => (10, 20),
+            InputType::Real => (2_000_000, 4_000_000),
+        };
+
+        Ok(Self { sensors, row, max })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i64 {
+        let mut ranges: Vec<(i64, i64)> = self
+            .sensors
+            .iter()
+            .filter_map(|s| {
+                let width = s.range - (s.y - self.row).abs();
+                (width >= 0).then_some((s.x - width, s.x + width))
+            })
+            .collect();
+        ranges.sort_unstable();
+
+        let mut covered = 0;
+        let mut end = i64::MIN;
+        for (start, stop) in ranges {
+            let start = start.max(end + 1);
+            if stop >= start {
+                covered += stop - start + 1;
+                end = stop;
+            }
+        }
+
+        let mut beacons: Vec<i64> = self
+            .sensors
+            .iter()
+            .filter(|s| s.beacon_y == self.row)
+            .map(|s| s.beacon_x)
+            .collect();
+        beacons.sort_unstable();
+        beacons.dedup();
+
+        covered - beacons.len() as i64
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> i64 {
+        // Boundary lines just outside each sensor's range, as x + y = c and x - y = c
+        let mut sums = Vec::new();
+        let mut diffs = Vec::new();
+        for s in &self.sensors {
+            sums.extend([s.x + s.y - s.range - 1, s.x + s.y + s.range + 1]);
+            diffs.extend([s.x - s.y - s.range - 1, s.x - s.y + s.range + 1]);
+        }
+
+        for &sum in &sums {
+            for &diff in &diffs {
+                if (sum - diff) % 2 != 0 {
+                    continue;
+                }
+                let (x, y) = ((sum + diff) / 2, (sum - diff) / 2);
+                if (0..=self.max).contains(&x)
+                    && (0..=self.max).contains(&y)
+                    && self
+                        .sensors
+                        .iter()
+                        .all(|s| (s.x - x).abs() + (s.y - y).abs() > s.range)
+                {
+                    return x * 4_000_000 + y;
+                }
+            }
+        }
+
+        panic!("no solution found");
+    }
+}
+
+examples!(Day15 -> (i64, i64) [
+    {file: "day15_example0.txt", part1: 26, part2: 56000011},
+]);
@@ -0,0 +1,130 @@
+use std::collections::VecDeque;
+use utils::prelude::*;
+
+/// Opening valves to release the most pressure.
+///
+/// Valves without any flow are removed by calculating the distances between the remaining valves.
+/// A depth-first search then records the most pressure released for each set of opened valves.
+/// For part 2, the two sets of valves opened by you and the elephant must be disjoint, so the
+/// answer is the best combination of a set and the best subset of its complement.
+#[derive(Clone, Debug)]
+pub struct Day16 {
+    flows: Vec<u32>,
+    // Distances between valves with flow, with the starting valve last
+    distances: Vec<Vec<u32>>,
+}
+
+impl Day16 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let name = parser::take_while1(u8::is_ascii_uppercase);
+        let valves = name
+            .with_prefix("Valve ")
+            .then(parser::u32().with_prefix(" has flow rate="))
+            .then(
+                name.repeat(", ", 1).with_prefix(parser::literal_map!(
+                    "; tunnels lead to valves " => (),
+                    "; tunnel leads to valve " => (),
+                )),
+            )
+            .parse_lines(input)?;
+
+        let index = |n: &[u8]| valves.iter().position(|&(name, _, _)| name == n);
+        let Some(start) = index(b"AA") else {
+            return Err(InputError::new(input, 0, "expected valve AA"));
+        };
+        let mut adjacent = Vec::with_capacity(valves.len());
+        for (_, _, tunnels) in &valves {
+            adjacent.push(
+                tunnels
+                    .iter()
+                    .map(|&t| index(t).ok_or_else(|| InputError::new(input, t, "unknown valve")))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+
+        let mut useful: Vec<usize> = (0..valves.len()).filter(|&i| valves[i].1 > 0).collect();
+        if useful.len() > 16 {
+            return Err(InputError::new(input, 0, "expected at most 16 valves with flow"));
+        }
+        useful.push(start);
+
+        let distances = useful
+            .iter()
+            .map(|&from| {
+                let mut distance = vec![u32::MAX; valves.len()];
+                let mut queue = VecDeque::from([from]);
+                distance[from] = 0;
+                while let Some(v) = queue.pop_front() {
+                    for &next in &adjacent[v] {
+                        if distance[next] == u32::MAX {
+                            distance[next] = distance[v] + 1;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+                useful.iter().map(|&to| distance[to]).collect()
+            })
+            .collect();
+
+        Ok(Self {
+            flows: useful[..useful.len() - 1]
+                .iter()
+                .map(|&i| valves[i].1)
+                .collect(),
+            distances,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.best_by_set(30).into_iter().max().unwrap()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mut best = self.best_by_set(26);
+
+        // Extend each entry to the best of any subset
+        for bit in 0..self.flows.len() {
+            for set in 0..best.len() {
+                if set & (1 << bit) != 0 {
+                    best[set] = best[set].max(best[set ^ (1 << bit)]);
+                }
+            }
+        }
+
+        let all = best.len() - 1;
+        (0..best.len())
+            .map(|set| best[set] + best[all ^ set])
+            .max()
+            .unwrap()
+    }
+
+    fn best_by_set(&self, time: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flows.len()];
+        self.search(self.flows.len(), time, 0, 0, &mut best);
+        best
+    }
+
+    fn search(&self, valve: usize, time: u32, opened: usize, pressure: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(pressure);
+        for (next, &flow) in self.flows.iter().enumerate() {
+            let distance = self.distances[valve][next];
+            if opened & (1 << next) != 0 || distance >= time {
+                continue;
+            }
+            let remaining = time - distance - 1;
+            self.search(
+                next,
+                remaining,
+                opened | (1 << next),
+                pressure + remaining * flow,
+                best,
+            );
+        }
+    }
+}
+
+examples!(Day16 -> (u32, u32) [
+    {file: "day16_example0.txt", part1: 1651, part2: 1707},
+]);
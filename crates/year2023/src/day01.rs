@@ -0,0 +1,63 @@
+use utils::prelude::*;
+
+/// Finding the first and last digits on each line.
+#[derive(Clone, Debug)]
+pub struct Day01<'a> {
+    lines: Vec<&'a [u8]>,
+}
+
+const WORDS: [&[u8]; 9] = [
+    b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
+];
+
+impl<'a> Day01<'a> {
+    pub fn new(input: &'a str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            lines: parser::take_while1(u8::is_ascii_alphanumeric).parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.calibration(false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.calibration(true)
+    }
+
+    fn calibration(&self, words: bool) -> u32 {
+        self.lines
+            .iter()
+            .map(|line| {
+                let mut digits = (0..line.len()).filter_map(|i| Self::digit(&line[i..], words));
+                let first = digits.next().expect("line should contain a digit");
+                let last = digits.next_back().unwrap_or(first);
+                first * 10 + last
+            })
+            .sum()
+    }
+
+    fn digit(s: &[u8], words: bool) -> Option<u32> {
+        if s[0].is_ascii_digit() {
+            return Some(u32::from(s[0] - b'0'));
+        }
+        if words {
+            return WORDS
+                .iter()
+                .position(|w| s.starts_with(w))
+                .map(|i| i as u32 + 1);
+        }
+        None
+    }
+}
+
+examples!(Day01<'_> -> (u32, u32) [
+    {input: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", part1: 142},
+    {
+        input: "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\n\
+            zoneight234\n7pqrstsixteen",
+        part2: 281,
+    },
+]);
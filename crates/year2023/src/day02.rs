@@ -0,0 +1,71 @@
+use utils::prelude::*;
+
+/// Finding the minimum cubes needed for each game.
+///
+/// Only the maximum number of each color seen in a game matters for both parts.
+#[derive(Clone, Debug)]
+pub struct Day02 {
+    // Maximum [red, green, blue] cubes seen in each game
+    games: Vec<[u32; 3]>,
+}
+
+impl Day02 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let cubes = parser::u32().with_suffix(b' ').then(parser::literal_map!(
+            "red" => 0,
+            "green" => 1,
+            "blue" => 2,
+        ));
+        let game = parser::u32()
+            .with_prefix("Game ")
+            .with_suffix(": ")
+            .then(cubes.repeat(", ", 1).repeat("; ", 1));
+
+        let mut games = Vec::new();
+        for item in game.with_suffix(parser::eol()).parse_iterator(input) {
+            let (id, sets) = item?;
+            if id as usize != games.len() + 1 {
+                return Err(InputError::new(
+                    input,
+                    0,
+                    format!("expected game {}", games.len() + 1),
+                ));
+            }
+
+            let mut max = [0; 3];
+            for (count, color) in sets.into_iter().flatten() {
+                max[color] = max[color].max(count);
+            }
+            games.push(max);
+        }
+
+        Ok(Self { games })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> usize {
+        self.games
+            .iter()
+            .enumerate()
+            .filter(|(_, &[r, g, b])| r <= 12 && g <= 13 && b <= 14)
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.games.iter().map(|m| m.iter().product::<u32>()).sum()
+    }
+}
+
+examples!(Day02 -> (usize, u32) [
+    {
+        input: "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        part1: 8,
+        part2: 2286,
+    },
+]);
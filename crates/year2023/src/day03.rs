@@ -0,0 +1,77 @@
+use utils::grid;
+use utils::prelude::*;
+
+/// Finding numbers adjacent to symbols in a grid.
+#[derive(Clone, Debug)]
+pub struct Day03 {
+    part_sum: u32,
+    gear_ratio_sum: u32,
+}
+
+impl Day03 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, grid) = grid::from_str(input, |b| b.is_ascii_graphic().then_some(b))?;
+        let is_symbol = |b: u8| b != b'.' && !b.is_ascii_digit();
+
+        let mut part_sum = 0;
+        // Count and product of the numbers adjacent to each gear
+        let mut gears = vec![(0u32, 1u32); grid.len()];
+
+        for r in 0..rows {
+            let mut c = 0;
+            while c < cols {
+                if !grid[r * cols + c].is_ascii_digit() {
+                    c += 1;
+                    continue;
+                }
+
+                let start = c;
+                let mut number = 0;
+                while c < cols && grid[r * cols + c].is_ascii_digit() {
+                    number = number * 10 + u32::from(grid[r * cols + c] - b'0');
+                    c += 1;
+                }
+
+                let mut is_part = false;
+                for nr in r.saturating_sub(1)..=(r + 1).min(rows - 1) {
+                    for nc in start.saturating_sub(1)..=c.min(cols - 1) {
+                        let i = nr * cols + nc;
+                        if is_symbol(grid[i]) {
+                            is_part = true;
+                        }
+                        if grid[i] == b'*' {
+                            gears[i].0 += 1;
+                            gears[i].1 *= number;
+                        }
+                    }
+                }
+                if is_part {
+                    part_sum += number;
+                }
+            }
+        }
+
+        Ok(Self {
+            part_sum,
+            gear_ratio_sum: gears
+                .iter()
+                .filter(|&&(count, _)| count == 2)
+                .map(|&(_, product)| product)
+                .sum(),
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.part_sum
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.gear_ratio_sum
+    }
+}
+
+examples!(Day03 -> (u32, u32) [
+    {file: "day03_example0.txt", part1: 4361, part2: 467835},
+]);
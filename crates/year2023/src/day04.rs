@@ -0,0 +1,63 @@
+use utils::prelude::*;
+
+/// Counting winning numbers on scratchcards.
+#[derive(Clone, Debug)]
+pub struct Day04 {
+    matches: Vec<u32>,
+}
+
+impl Day04 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let spaces = parser::take_while1(|&b| b == b' ');
+        let numbers = parser::number_range(0..=127u8)
+            .with_prefix(spaces)
+            .repeat(parser::noop(), 1)
+            .map(|numbers| numbers.iter().fold(0u128, |acc, &n| acc | (1 << n)));
+
+        let cards = parser::u32()
+            .with_prefix(spaces)
+            .with_prefix("Card")
+            .with_suffix(b':')
+            .then(numbers)
+            .then(numbers.with_prefix(" |"))
+            .parse_lines(input)?;
+
+        let mut matches = Vec::with_capacity(cards.len());
+        for (i, &(id, winning, have)) in cards.iter().enumerate() {
+            if id as usize != i + 1 {
+                return Err(InputError::new(input, 0, format!("expected card {}", i + 1)));
+            }
+            let count = (winning & have).count_ones();
+            if i + count as usize >= cards.len() {
+                return Err(InputError::new(input, 0, "card wins cards past the end"));
+            }
+            matches.push(count);
+        }
+
+        Ok(Self { matches })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.matches
+            .iter()
+            .filter(|&&m| m > 0)
+            .map(|&m| 1 << (m - 1))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        let mut copies = vec![1; self.matches.len()];
+        for (i, &m) in self.matches.iter().enumerate() {
+            for j in i + 1..=i + m as usize {
+                copies[j] += copies[i];
+            }
+        }
+        copies.iter().sum()
+    }
+}
+
+examples!(Day04 -> (u32, u32) [
+    {file: "day04_example0.txt", part1: 13, part2: 30},
+]);
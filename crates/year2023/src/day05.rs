@@ -0,0 +1,106 @@
+use utils::prelude::*;
+
+/// Mapping ranges of seeds through multiple stages.
+///
+/// Part 2 maps whole ranges at once, splitting them whenever they cross the edge of a mapping.
+#[derive(Clone, Debug)]
+pub struct Day05 {
+    seeds: Vec<u64>,
+    // (source start, source end, destination start) for each stage, sorted by source start
+    stages: Vec<Vec<(u64, u64, u64)>>,
+}
+
+impl Day05 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let mut blocks = input.trim_ascii_end().split("\n\n");
+        let seeds = parser::u64()
+            .repeat(b' ', 1)
+            .with_prefix("seeds: ")
+            .parse_complete(blocks.next().unwrap_or_default())?;
+        if seeds.len() % 2 != 0 {
+            return Err(InputError::new(input, 0, "expected pairs of seed numbers"));
+        }
+
+        let stages = blocks
+            .map(|block| {
+                let Some((header, mappings)) = block.split_once('\n') else {
+                    return Err(InputError::new(input, block, "expected mappings"));
+                };
+                if !header.ends_with(" map:") {
+                    return Err(InputError::new(input, header, "expected map header"));
+                }
+
+                let mut stage = parser::u64()
+                    .with_suffix(b' ')
+                    .then(parser::u64().with_suffix(b' '))
+                    .then(parser::u64())
+                    .map(|(destination, source, length)| (source, source + length, destination))
+                    .parse_lines(mappings)?;
+                stage.sort_unstable();
+                Ok(stage)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { seeds, stages })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.lowest_location(self.seeds.iter().map(|&s| (s, s + 1)).collect())
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.lowest_location(
+            self.seeds
+                .chunks_exact(2)
+                .map(|c| (c[0], c[0] + c[1]))
+                .collect(),
+        )
+    }
+
+    fn lowest_location(&self, mut ranges: Vec<(u64, u64)>) -> u64 {
+        let mut next = Vec::new();
+        for stage in &self.stages {
+            for &(mut start, end) in &ranges {
+                for &(source_start, source_end, destination) in stage {
+                    if start >= end {
+                        break;
+                    }
+                    if source_end <= start {
+                        continue;
+                    }
+                    if source_start >= end {
+                        break;
+                    }
+                    if start < source_start {
+                        next.push((start, source_start));
+                        start = source_start;
+                    }
+                    let overlap_end = end.min(source_end);
+                    next.push((
+                        destination + (start - source_start),
+                        destination + (overlap_end - source_start),
+                    ));
+                    start = overlap_end;
+                }
+                if start < end {
+                    next.push((start, end));
+                }
+            }
+            ranges.clear();
+            std::mem::swap(&mut ranges, &mut next);
+        }
+
+        ranges
+            .iter()
+            .filter(|&&(start, end)| start < end)
+            .map(|&(start, _)| start)
+            .min()
+            .unwrap()
+    }
+}
+
+examples!(Day05 -> (u64, u64) [
+    {file: "day05_example0.txt", part1: 35, part2: 46},
+]);
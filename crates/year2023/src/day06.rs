@@ -0,0 +1,70 @@
+use utils::prelude::*;
+
+/// Counting the ways to win boat races.
+///
+/// Holding the button for `h` milliseconds travels `h * (t - h)`, so the winning hold times are
+/// between the roots of `h^2 - th + d = 0`. The roots are calculated using floating point, then
+/// corrected using integer arithmetic.
+#[derive(Clone, Debug)]
+pub struct Day06 {
+    races: Vec<(u64, u64)>,
+}
+
+impl Day06 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let numbers = parser::u64()
+            .with_prefix(parser::take_while1(|&b| b == b' '))
+            .repeat(parser::noop(), 1);
+        let (times, distances) = numbers
+            .with_prefix("Time:")
+            .with_suffix(parser::eol())
+            .then(numbers.with_prefix("Distance:"))
+            .parse_complete(input.trim_ascii_end())?;
+        if times.len() != distances.len() {
+            return Err(InputError::new(input, 0, "expected same number of times and distances"));
+        }
+
+        Ok(Self {
+            races: times.into_iter().zip(distances).collect(),
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        self.races.iter().map(|&(t, d)| Self::ways(t, d)).product()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        let concat = |a: u64, b: u64| a * 10u64.pow(b.checked_ilog10().unwrap_or(0) + 1) + b;
+        let (time, distance) = self
+            .races
+            .iter()
+            .fold((0, 0), |(t, d), &(rt, rd)| (concat(t, rt), concat(d, rd)));
+        Self::ways(time, distance)
+    }
+
+    fn ways(time: u64, distance: u64) -> u64 {
+        let wins = |hold: u64| hold * (time - hold) > distance;
+
+        let discriminant = (time * time).saturating_sub(4 * distance) as f64;
+        let mut min = ((time as f64 - discriminant.sqrt()) / 2.0).max(0.0) as u64;
+        while min > 0 && wins(min - 1) {
+            min -= 1;
+        }
+        while min <= time / 2 && !wins(min) {
+            min += 1;
+        }
+
+        if min > time / 2 {
+            0
+        } else {
+            // Winning times are symmetric around time / 2
+            time - 2 * min + 1
+        }
+    }
+}
+
+examples!(Day06 -> (u64, u64) [
+    {input: "Time:      7  15   30\nDistance:  9  40  200", part1: 288, part2: 71503},
+]);
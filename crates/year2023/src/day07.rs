@@ -0,0 +1,86 @@
+use utils::prelude::*;
+
+/// Ranking hands of cards.
+///
+/// Each hand is converted to a single sortable key, made up of the hand type followed by each
+/// card's value.
+#[derive(Clone, Debug)]
+pub struct Day07 {
+    hands: Vec<([u8; 5], u32)>,
+}
+
+const JOKER: u8 = 11;
+
+impl Day07 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let card = parser::literal_map!(
+            "2" => 2, "3" => 3, "4" => 4, "5" => 5, "6" => 6, "7" => 7, "8" => 8, "9" => 9,
+            "T" => 10, "J" => JOKER, "Q" => 12, "K" => 13, "A" => 14,
+        );
+        Ok(Self {
+            hands: card
+                .repeat_n::<5, _>(parser::noop())
+                .with_suffix(b' ')
+                .then(parser::u32())
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.winnings(false)
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.winnings(true)
+    }
+
+    fn winnings(&self, jokers: bool) -> u32 {
+        let mut hands: Vec<(u32, u32)> = self
+            .hands
+            .iter()
+            .map(|&(cards, bid)| (Self::key(cards, jokers), bid))
+            .collect();
+        hands.sort_unstable();
+        hands
+            .iter()
+            .enumerate()
+            .map(|(rank, &(_, bid))| (rank as u32 + 1) * bid)
+            .sum()
+    }
+
+    fn key(cards: [u8; 5], jokers: bool) -> u32 {
+        let mut counts = [0u8; 15];
+        for &c in &cards {
+            counts[c as usize] += 1;
+        }
+
+        let joker_count = if jokers {
+            std::mem::take(&mut counts[JOKER as usize])
+        } else {
+            0
+        };
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts[0] += joker_count;
+
+        let hand_type = match (counts[0], counts[1]) {
+            (5, _) => 6,
+            (4, _) => 5,
+            (3, 2) => 4,
+            (3, _) => 3,
+            (2, 2) => 2,
+            (2, _) => 1,
+            _ => 0,
+        };
+
+        cards.iter().fold(hand_type, |acc, &c| {
+            let value = if jokers && c == JOKER { 1 } else { c };
+            (acc << 4) | u32::from(value)
+        })
+    }
+}
+
+examples!(Day07 -> (u32, u32) [
+    {input: "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483", part1: 6440, part2: 5905},
+]);
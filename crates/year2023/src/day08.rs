@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use utils::number::lcm;
+use utils::prelude::*;
+
+/// Following left/right instructions through a network.
+///
+/// Part 2 assumes each ghost's path loops back to the start of its cycle at the same point it
+/// reaches its only end node, so the answer is the lowest common multiple of the path lengths.
+#[derive(Clone, Debug)]
+pub struct Day08 {
+    directions: Vec<usize>,
+    nodes: Vec<[usize; 2]>,
+    names: Vec<[u8; 3]>,
+}
+
+impl Day08 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let Some((directions, network)) = input.split_once("\n\n") else {
+            return Err(InputError::new(input, 0, "expected directions and network"));
+        };
+
+        let directions = parser::literal_map!("L" => 0, "R" => 1)
+            .repeat(parser::noop(), 1)
+            .parse_complete(directions)?;
+
+        let name = parser::byte_range(b'0'..=b'Z').repeat_n::<3, _>(parser::noop());
+        let lines = name
+            .with_suffix(" = (")
+            .then(name.with_suffix(", "))
+            .then(name.with_suffix(")"))
+            .parse_lines(network)?;
+
+        let indexes: HashMap<[u8; 3], usize> = lines
+            .iter()
+            .enumerate()
+            .map(|(i, &(name, _, _))| (name, i))
+            .collect();
+        let nodes = lines
+            .iter()
+            .map(|(_, left, right)| {
+                [left, right].map(|n| {
+                    indexes.get(n).copied().ok_or_else(|| {
+                        InputError::new(input, 0, format!("unknown node {}", n.escape_ascii()))
+                    })
+                })
+            })
+            .map(|[left, right]| Ok([left?, right?]))
+            .collect::<Result<Vec<_>, InputError>>()?;
+
+        Ok(Self {
+            directions,
+            nodes,
+            names: lines.iter().map(|&(name, _, _)| name).collect(),
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u64 {
+        let start = self
+            .names
+            .iter()
+            .position(|n| n == b"AAA")
+            .expect("network should contain AAA");
+        self.steps(start, |n| n == b"ZZZ")
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u64 {
+        self.names
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n[2] == b'A')
+            .map(|(i, _)| self.steps(i, |n| n[2] == b'Z') as i64)
+            .fold(1, lcm) as u64
+    }
+
+    fn steps(&self, mut node: usize, is_end: impl Fn(&[u8; 3]) -> bool) -> u64 {
+        for (steps, &direction) in self.directions.iter().cycle().enumerate() {
+            if is_end(&self.names[node]) {
+                return steps as u64;
+            }
+            node = self.nodes[node][direction];
+        }
+        unreachable!()
+    }
+}
+
+examples!(Day08 -> (u64, u64) [
+    {
+        input: "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\n\
+            EEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)",
+        part1: 2,
+    },
+    {input: "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)", part1: 6},
+    {
+        input: "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n\
+            22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)",
+        part2: 6,
+    },
+]);
@@ -0,0 +1,48 @@
+use utils::prelude::*;
+
+/// Extrapolating sequences using repeated differences.
+#[derive(Clone, Debug)]
+pub struct Day09 {
+    // (next, previous) extrapolated values for each sequence
+    extrapolated: Vec<(i64, i64)>,
+}
+
+impl Day09 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        Ok(Self {
+            extrapolated: parser::i64()
+                .repeat(b' ', 1)
+                .map(Self::extrapolate)
+                .parse_lines(input)?,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> i64 {
+        self.extrapolated.iter().map(|&(next, _)| next).sum()
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> i64 {
+        self.extrapolated.iter().map(|&(_, previous)| previous).sum()
+    }
+
+    fn extrapolate(mut values: Vec<i64>) -> (i64, i64) {
+        let (mut next, mut previous, mut sign) = (0, 0, 1);
+        while values.iter().any(|&v| v != 0) {
+            next += values[values.len() - 1];
+            previous += sign * values[0];
+            sign = -sign;
+
+            for i in 0..values.len() - 1 {
+                values[i] = values[i + 1] - values[i];
+            }
+            values.pop();
+        }
+        (next, previous)
+    }
+}
+
+examples!(Day09 -> (i64, i64) [
+    {input: "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45", part1: 114, part2: 2},
+]);
@@ -0,0 +1,108 @@
+use utils::grid;
+use utils::prelude::*;
+
+/// Finding the length and enclosed area of a pipe loop.
+///
+/// The enclosed area is calculated with the shoelace formula, and Pick's theorem then gives the
+/// number of enclosed tiles from the area and the number of tiles on the loop.
+#[derive(Clone, Debug)]
+pub struct Day10 {
+    length: u32,
+    enclosed: u32,
+}
+
+// Bitmask of connections for each tile
+const NORTH: u8 = 1;
+const SOUTH: u8 = 2;
+const WEST: u8 = 4;
+const EAST: u8 = 8;
+const START: u8 = 16;
+
+impl Day10 {
+    pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
+        let (rows, cols, mut grid) = grid::from_str(input, |b| match b {
+            b'|' => Some(NORTH | SOUTH),
+            b'-' => Some(WEST | EAST),
+            b'L' => Some(NORTH | EAST),
+            b'J' => Some(NORTH | WEST),
+            b'7' => Some(SOUTH | WEST),
+            b'F' => Some(SOUTH | EAST),
+            b'.' => Some(0),
+            b'S' => Some(START),
+            _ => None,
+        })?;
+
+        let Some(start) = grid.iter().position(|&t| t == START) else {
+            return Err(InputError::new(input, 0, "expected start"));
+        };
+        let (sr, sc) = (start / cols, start % cols);
+        let mut connections = 0;
+        if sr > 0 && grid[start - cols] & SOUTH != 0 {
+            connections |= NORTH;
+        }
+        if sr + 1 < rows && grid[start + cols] & NORTH != 0 {
+            connections |= SOUTH;
+        }
+        if sc > 0 && grid[start - 1] & EAST != 0 {
+            connections |= WEST;
+        }
+        if sc + 1 < cols && grid[start + 1] & WEST != 0 {
+            connections |= EAST;
+        }
+        if connections.count_ones() != 2 {
+            return Err(InputError::new(input, 0, "expected start to connect to two pipes"));
+        }
+        grid[start] = connections;
+
+        let (mut r, mut c) = (sr as i64, sc as i64);
+        let mut direction = 1 << connections.trailing_zeros();
+        let mut length = 0;
+        let mut area = 0;
+        loop {
+            let (dr, dc, opposite) = match direction {
+                NORTH => (-1, 0, SOUTH),
+                SOUTH => (1, 0, NORTH),
+                WEST => (0, -1, EAST),
+                _ => (0, 1, WEST),
+            };
+            let (nr, nc) = (r + dr, c + dc);
+            if nr < 0 || nc < 0 || nr >= rows as i64 || nc >= cols as i64 {
+                return Err(InputError::new(input, 0, "expected pipes to form a loop"));
+            }
+            area += c * nr - nc * r;
+            length += 1;
+            (r, c) = (nr, nc);
+
+            if (r as usize, c as usize) == (sr, sc) {
+                break;
+            }
+            let tile = grid[r as usize * cols + c as usize];
+            if tile & opposite == 0 {
+                return Err(InputError::new(input, 0, "expected pipes to form a loop"));
+            }
+            direction = tile & !opposite;
+        }
+
+        let area = (area.abs() / 2) as u32;
+        Ok(Self {
+            length: length / 2,
+            enclosed: area + 1 - length / 2,
+        })
+    }
+
+    #[must_use]
+    pub fn part1(&self) -> u32 {
+        self.length
+    }
+
+    #[must_use]
+    pub fn part2(&self) -> u32 {
+        self.enclosed
+    }
+}
+
+examples!(Day10 -> (u32, u32) [
+    {input: "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF", part1: 4, part2: 1},
+    {input: "7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ", part1: 8, part2: 1},
+    {file: "day10_example2.txt", part1: 23, part2: 4},
+]);
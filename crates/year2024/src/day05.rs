@@ -27,7 +27,7 @@ impl Day05 {
             .parse_complete(input)?;
 
         let mut before: Rules = [[false; RANGE]; RANGE];
-        for (a, b) in rule_list {
+        for &(a, b) in &rule_list {
             if before[a as usize - MIN_NUM][b as usize - MIN_NUM] {
                 return Err(InputError::new(input, 0, "duplicate rule"));
             } else if before[b as usize - MIN_NUM][a as usize - MIN_NUM] {
@@ -37,10 +37,17 @@ impl Day05 {
             before[a as usize - MIN_NUM][b as usize - MIN_NUM] = true;
         }
 
-        let (sorted, unsorted) = updates.into_iter().partition(|update| {
+        let (sorted, unsorted): (Vec<_>, Vec<_>) = updates.into_iter().partition(|update| {
             update.is_sorted_by(|&a, &b| before[a as usize - MIN_NUM][b as usize - MIN_NUM])
         });
 
+        explain!(
+            "{} rules, {} updates already in order, {} updates out of order",
+            rule_list.len(),
+            sorted.len(),
+            unsorted.len(),
+        );
+
         Ok(Self {
             before,
             sorted,
@@ -50,14 +57,22 @@ impl Day05 {
 
     #[must_use]
     pub fn part1(&self) -> u32 {
+        let _section = explain::section(format_args!("Part 1, sum the middle of sorted updates:"));
         self.sorted
             .iter()
-            .map(|update| update[update.len() / 2])
+            .map(|update| {
+                let middle = update[update.len() / 2];
+                explain!("{update:?} is sorted, middle is {middle}");
+                middle
+            })
             .sum()
     }
 
     #[must_use]
     pub fn part2(&self) -> u32 {
+        let _section = explain::section(format_args!(
+            "Part 2, sum the middle of unsorted updates without fully sorting them:"
+        ));
         self.unsorted
             .iter()
             .cloned()
@@ -73,7 +88,9 @@ impl Day05 {
                         Ordering::Greater
                     }
                 });
-                *middle
+                let middle = *middle;
+                explain!("Partitioned around the middle: {update:?}, middle is {middle}");
+                middle
             })
             .sum()
     }
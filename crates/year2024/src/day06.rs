@@ -20,7 +20,9 @@ impl Day06 {
             _ => None,
         })?;
 
-        let start_index = grid.iter().position(|&c| c == b'^').unwrap();
+        let Some(start_index) = grid.iter().position(|&c| c == b'^') else {
+            return Err(InputError::new(input, 0, "expected guard"));
+        };
         let start = Point2D::new(start_index % cols, start_index / cols);
         grid[start_index] = b'.';
 
@@ -1,3 +1,4 @@
+use utils::geometry::torus_step;
 use utils::number::chinese_remainder;
 use utils::point::Point2D;
 use utils::prelude::*;
@@ -16,6 +17,7 @@ struct Robot {
 
 const WIDTH: i32 = 101;
 const HEIGHT: i32 = 103;
+const BOUNDS: Point2D<i32> = Point2D::new(WIDTH, HEIGHT);
 
 impl Day14 {
     pub fn new(input: &str, _: InputType) -> Result<Self, InputError> {
@@ -36,20 +38,18 @@ impl Day14 {
     #[must_use]
     pub fn part1(&self) -> u64 {
         let mut counts = [0; 4];
-        for &(mut r) in self.robots.iter() {
-            r.position += r.velocity * 100;
-            r.position.x = r.position.x.rem_euclid(WIDTH);
-            r.position.y = r.position.y.rem_euclid(HEIGHT);
+        for r in &self.robots {
+            let position = torus_step(r.position, r.velocity, 100, BOUNDS);
 
-            if r.position.x == WIDTH / 2 || r.position.y == HEIGHT / 2 {
+            if position.x == WIDTH / 2 || position.y == HEIGHT / 2 {
                 continue;
             }
 
             let mut quadrant = 0;
-            if r.position.x > WIDTH / 2 {
+            if position.x > WIDTH / 2 {
                 quadrant += 2;
             }
-            if r.position.y > HEIGHT / 2 {
+            if position.y > HEIGHT / 2 {
                 quadrant += 1;
             }
             counts[quadrant] += 1;
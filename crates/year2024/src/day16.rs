@@ -2,6 +2,7 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use utils::grid;
 use utils::prelude::*;
+use utils::stack::dfs;
 
 /// Finding the shortest paths through a maze.
 #[derive(Clone, Debug)]
@@ -168,56 +169,55 @@ impl Day16 {
         let mut on_best = vec![false; self.grid.len()];
         on_best[self.start] = true;
         on_best[self.end] = true;
-        for d in 0..4 {
-            if self.cheapest[self.end][d] == self.part1 {
-                let prev = self.end.wrapping_add_signed(-self.offsets[d]);
-                self.reverse(prev, d, self.part1 - 1, &mut on_best);
-            }
-        }
-        on_best.iter().filter(|&&b| b).count() as u32
-    }
 
-    fn reverse(&self, index: usize, dir: usize, score: u32, on_best: &mut [bool]) {
-        if on_best[index] {
-            return;
-        }
-        on_best[index] = true;
-
-        let mut count = 0;
-        let mut next_index = 0;
-        let mut next_dir = 0;
-        for d in [dir, (dir + 1) % 4, (dir + 3) % 4] {
-            let i = index.wrapping_add_signed(-self.offsets[d]);
-            if self.grid[i] == b'.' {
-                count += 1;
-                next_index = i;
-                next_dir = d;
+        // Walk backwards along the best paths using an explicit stack, as paths can be long enough
+        // to overflow smaller stacks when recursing
+        let roots = (0..4)
+            .filter(|&d| self.cheapest[self.end][d] == self.part1)
+            .map(|d| (self.end.wrapping_add_signed(-self.offsets[d]), d, self.part1 - 1));
+        dfs(roots, |(index, dir, score), stack| {
+            if on_best[index] {
+                return;
             }
-        }
-        assert!(count > 0);
+            on_best[index] = true;
 
-        if count == 1 {
-            let next_score = score - if dir == next_dir { 1 } else { 1001 };
-            self.reverse(next_index, next_dir, next_score, on_best);
-        } else {
-            // At a branch, only continue down directions where the cheapest seen score matches
-            for (next_dir, next_score) in [
-                (dir, score),
-                ((dir + 1) % 4, score - 1000),
-                ((dir + 3) % 4, score - 1000),
-            ] {
-                if self.cheapest[index][next_dir] == next_score
-                    && self.turned[index] & 1 << next_dir == 0
-                {
-                    self.reverse(
-                        index.wrapping_add_signed(-self.offsets[next_dir]),
-                        next_dir,
-                        next_score - 1,
-                        on_best,
-                    );
+            let mut count = 0;
+            let mut next_index = 0;
+            let mut next_dir = 0;
+            for d in [dir, (dir + 1) % 4, (dir + 3) % 4] {
+                let i = index.wrapping_add_signed(-self.offsets[d]);
+                if self.grid[i] == b'.' {
+                    count += 1;
+                    next_index = i;
+                    next_dir = d;
                 }
             }
-        }
+            assert!(count > 0);
+
+            if count == 1 {
+                let next_score = score - if dir == next_dir { 1 } else { 1001 };
+                stack.push((next_index, next_dir, next_score));
+            } else {
+                // At a branch, only continue down directions where the cheapest seen score matches
+                for (next_dir, next_score) in [
+                    (dir, score),
+                    ((dir + 1) % 4, score - 1000),
+                    ((dir + 3) % 4, score - 1000),
+                ] {
+                    if self.cheapest[index][next_dir] == next_score
+                        && self.turned[index] & 1 << next_dir == 0
+                    {
+                        stack.push((
+                            index.wrapping_add_signed(-self.offsets[next_dir]),
+                            next_dir,
+                            next_score - 1,
+                        ));
+                    }
+                }
+            }
+        });
+
+        on_best.iter().filter(|&&b| b).count() as u32
     }
 }
 
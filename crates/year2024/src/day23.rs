@@ -1,4 +1,4 @@
-use utils::bit::BitIterator;
+use utils::bit::{count_ones_slice, BitIterator};
 use utils::prelude::*;
 
 /// Finding the largest clique in a graph.
@@ -7,7 +7,7 @@ use utils::prelude::*;
 #[derive(Clone, Debug)]
 pub struct Day23 {
     nodes: [[u64; (26usize * 26).div_ceil(64)]; 26 * 26],
-    degree: u32,
+    degree: usize,
 }
 
 impl Day23 {
@@ -33,9 +33,9 @@ impl Day23 {
             return Err(InputError::new(input, 0, "expected non-empty graph"));
         };
 
-        let degree = first_node.iter().map(|&n| n.count_ones()).sum::<u32>();
-        if nodes.iter().any(|&b| {
-            let d = b.iter().map(|&n| n.count_ones()).sum::<u32>();
+        let degree = count_ones_slice(first_node);
+        if nodes.iter().any(|b| {
+            let d = count_ones_slice(b);
             d != 0 && d != degree
         }) {
             return Err(InputError::new(
@@ -93,7 +93,7 @@ impl Day23 {
                     connected = Self::intersect(connected, self.nodes[n]);
                     connected[n / 64] |= 1 << (n % 64);
 
-                    if connected.iter().map(|&n| n.count_ones()).sum::<u32>() != self.degree {
+                    if connected.iter().map(|&n| n.count_ones() as usize).sum::<usize>() != self.degree {
                         continue 'sets;
                     }
                 }
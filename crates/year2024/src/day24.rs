@@ -1,6 +1,6 @@
-use std::collections::HashMap;
 use std::ops::ControlFlow;
 use utils::prelude::*;
+use utils::short_key::{alphanumeric_count, alphanumeric_index};
 
 /// Finding swapped logic gates in an adder circuit.
 #[derive(Clone, Debug)]
@@ -29,7 +29,7 @@ impl Day24 {
 
         let mut wires = Vec::new();
         let mut wire_names = Vec::new();
-        let mut indexes = HashMap::new();
+        let mut indexes = vec![usize::MAX; alphanumeric_count(3)];
         let mut x_initial = 0;
         let mut y_initial = 0;
         let mut input_bits = 64;
@@ -62,8 +62,10 @@ impl Day24 {
                 y_initial |= u64::from(b == b'1') << n;
                 wires.push(Wire::Y(n));
             }
-            wire_names.push(wire.into());
-            indexes.insert(wire.into(), wires.len() - 1);
+            let name: [u8; 3] = wire.into();
+            wire_names.push(name);
+            // check-days: allow(unwrap-in-new)
+            indexes[alphanumeric_index(&name).unwrap()] = wires.len() - 1;
 
             if n == input_bits - 1 {
                 next = (b'?', 0);
@@ -73,7 +75,13 @@ impl Day24 {
         }
 
         let mut z_indexes = vec![usize::MAX; input_bits + 1];
-        let wire = parser::byte().repeat_n::<3, _>(parser::noop());
+        let wire = parser::byte()
+            .repeat_n::<3, _>(parser::noop())
+            .map_res(|n| {
+                alphanumeric_index(&n)
+                    .map(|i| (n, i))
+                    .ok_or("expected lowercase alphanumeric wire name")
+            });
         for item in wire
             .then(parser::literal_map!(
                 " AND " => Wire::And as fn(usize, usize) -> Wire,
@@ -87,12 +95,13 @@ impl Day24 {
         {
             let (in1, gate, in2, out) = item?;
 
-            let mut index_of = |n| {
-                *indexes.entry(n).or_insert_with(|| {
+            let mut index_of = |(n, i): ([u8; 3], usize)| {
+                if indexes[i] == usize::MAX {
                     wires.push(Wire::X(usize::MAX)); // Placeholder
                     wire_names.push(n);
-                    wires.len() - 1
-                })
+                    indexes[i] = wires.len() - 1;
+                }
+                indexes[i]
             };
 
             let in1_index = index_of(in1);
@@ -102,6 +111,7 @@ impl Day24 {
             if wires[out_index] != Wire::X(usize::MAX) {
                 return Err(InputError::new(input, 0, "duplicate wire definition"));
             }
+            let out = out.0;
             if out[0] == b'z' {
                 let index = ((out[1] - b'0') * 10 + (out[2] - b'0')) as usize;
                 if index < z_indexes.len() {